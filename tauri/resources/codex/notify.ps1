@@ -0,0 +1,38 @@
+# Codex notify script installed by AI Toolbox.
+# Codex runs `notify` with the event JSON as the last argument.
+
+$payload = $args | Select-Object -Last 1
+try {
+    $data = $payload | ConvertFrom-Json
+} catch {
+    exit 0
+}
+
+if ($data.type -ne 'agent-turn-complete') {
+    exit 0
+}
+
+$message = [string]$data.'last-assistant-message'
+if ([string]::IsNullOrWhiteSpace($message)) {
+    $message = 'Turn complete'
+}
+$message = ($message -replace '\s+', ' ').Trim()
+if ($message.Length -gt 200) {
+    $message = $message.Substring(0, 200)
+}
+
+try {
+    [Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] | Out-Null
+    $template = [Windows.UI.Notifications.ToastNotificationManager]::GetTemplateContent(
+        [Windows.UI.Notifications.ToastTemplateType]::ToastText02
+    )
+    $texts = $template.GetElementsByTagName('text')
+    $texts.Item(0).AppendChild($template.CreateTextNode('Codex')) | Out-Null
+    $texts.Item(1).AppendChild($template.CreateTextNode($message)) | Out-Null
+    $toast = [Windows.UI.Notifications.ToastNotification]::new($template)
+    # Use the PowerShell AppUserModelID so the toast shows without registering our own app id
+    $appId = '{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\WindowsPowerShell\v1.0\powershell.exe'
+    [Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier($appId).Show($toast)
+} catch {
+    exit 0
+}
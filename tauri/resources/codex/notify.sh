@@ -0,0 +1,32 @@
+#!/bin/sh
+# Codex notify script installed by AI Toolbox.
+# Codex runs `notify` with the event JSON as the last argument.
+
+payload=""
+for arg in "$@"; do
+  payload="$arg"
+done
+
+case "$payload" in
+  *'"type":"agent-turn-complete"'*) ;;
+  *) exit 0 ;;
+esac
+
+message=$(printf '%s' "$payload" \
+  | sed -nE 's/.*"last-assistant-message":"(([^"\\]|\\.)*)".*/\1/p' \
+  | sed -e 's/\\n/ /g' -e 's/\\"/"/g' \
+  | cut -c1-200)
+[ -n "$message" ] || message="Turn complete"
+title="Codex"
+
+if command -v osascript >/dev/null 2>&1; then
+  osascript \
+    -e 'on run argv' \
+    -e 'display notification (item 2 of argv) with title (item 1 of argv)' \
+    -e 'end run' \
+    "$title" "$message" >/dev/null 2>&1
+elif command -v notify-send >/dev/null 2>&1; then
+  notify-send "$title" "$message" >/dev/null 2>&1
+fi
+
+exit 0
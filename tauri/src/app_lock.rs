@@ -0,0 +1,631 @@
+//! App Lock Module
+//!
+//! Optional master password for shared machines:
+//! - The password is stretched with Argon2id into an AES-256-GCM key that only lives in
+//!   memory while the app is unlocked
+//! - Sensitive settings (WebDAV password, S3 keys) and the API keys of Claude Code,
+//!   Codex and Aider providers are encrypted at rest with that key. The gateway API key is not,
+//!   the local gateway has to check it while the app is locked
+//! - While locked, Tauri commands other than the lock screen's are rejected, and backend
+//!   tasks (tray, hotkeys, scheduled backups) refuse to use or write encrypted credentials
+//! - The app locks itself again after `auto_lock_minutes` without any command
+
+use crate::coding::{db_extract_id, db_record_id};
+use crate::db::DbState;
+use crate::db_timestamp::with_datetimes;
+use crate::settings::{self, adapter};
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::ipc::Invoke;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+/// Prefix of values encrypted with the app lock key
+const ENCRYPTED_PREFIX: &str = "enc:v1:";
+/// Known plaintext stored encrypted to check the password
+const VERIFIER_PLAINTEXT: &str = "ai-toolbox-app-lock";
+const NONCE_LEN: usize = 12;
+/// Emitted when the app is locked (idle timeout or "lock now")
+const APP_LOCKED_EVENT: &str = "app-locked";
+/// Error for anything that needs the key while the app is locked
+const LOCKED_ERROR: &str = "App is locked";
+
+/// API keys in the `settings_config` of Claude Code providers (JSON pointers)
+pub const CLAUDE_PROVIDER_SECRETS: [&str; 2] =
+    ["/env/ANTHROPIC_AUTH_TOKEN", "/env/ANTHROPIC_API_KEY"];
+/// API keys in the `settings_config` of Codex providers (JSON pointers)
+pub const CODEX_PROVIDER_SECRETS: [&str; 1] = ["/auth/OPENAI_API_KEY"];
+/// Provider tables whose `settings_config` is re-encrypted when the lock is turned on or off
+const PROVIDER_SECRET_TABLES: [(&str, &[&str]); 2] = [
+    ("claude_provider", &CLAUDE_PROVIDER_SECRETS),
+    ("codex_provider", &CODEX_PROVIDER_SECRETS),
+];
+/// Provider tables keeping their API key in a plain field, as (table, field)
+const PROVIDER_KEY_FIELDS: [(&str, &str); 1] = [("aider_provider", "settings.apiKey")];
+
+/// Commands the lock screen needs while the app is locked
+const ALLOWED_WHILE_LOCKED: [&str; 4] = [
+    "get_app_lock_status",
+    "unlock_app",
+    "get_settings",
+    "set_window_background_color",
+];
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static AUTO_LOCK_MINUTES: AtomicU32 = AtomicU32::new(0);
+/// Derived key, present only while unlocked
+static KEY: Mutex<Option<[u8; 32]>> = Mutex::new(None);
+static LAST_ACTIVITY: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Argon2id cost parameters the key was derived with
+///
+/// Stored with the lock, so a change of the crate defaults can't derive a different key
+/// from the same password.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct KdfParams {
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+}
+
+impl KdfParams {
+    /// Parameters for a newly enabled lock
+    fn current() -> Self {
+        Self {
+            m_cost: Params::DEFAULT_M_COST,
+            t_cost: Params::DEFAULT_T_COST,
+            p_cost: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+/// App lock record stored in `app_lock:`config``
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LockConfig {
+    /// Base64 Argon2 salt
+    salt: String,
+    /// `VERIFIER_PLAINTEXT` encrypted with the derived key
+    verifier: String,
+    #[serde(default)]
+    auto_lock_minutes: u32,
+    kdf: KdfParams,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AppLockStatus {
+    pub enabled: bool,
+    pub locked: bool,
+    /// 0 = never auto-lock
+    pub auto_lock_minutes: u32,
+}
+
+fn b64() -> base64::engine::GeneralPurpose {
+    base64::engine::general_purpose::STANDARD
+}
+
+fn derive_key(password: &str, salt: &[u8], kdf: &KdfParams) -> Result<[u8; 32], String> {
+    let params = Params::new(kdf.m_cost, kdf.t_cost, kdf.p_cost, None)
+        .map_err(|e| format!("Invalid key derivation parameters: {}", e))?;
+    let mut key = [0u8; 32];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("Failed to derive key: {}", e))?;
+    Ok(key)
+}
+
+fn encrypt_with(key: &[u8; 32], plaintext: &str) -> Result<String, String> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_bytes())
+        .map_err(|e| format!("Failed to encrypt: {}", e))?;
+
+    let mut payload = nonce.to_vec();
+    payload.extend_from_slice(&ciphertext);
+    Ok(format!("{}{}", ENCRYPTED_PREFIX, b64().encode(payload)))
+}
+
+fn decrypt_with(key: &[u8; 32], value: &str) -> Result<String, String> {
+    let encoded = value
+        .strip_prefix(ENCRYPTED_PREFIX)
+        .ok_or_else(|| "Value is not encrypted".to_string())?;
+    let payload = b64()
+        .decode(encoded)
+        .map_err(|e| format!("Invalid encrypted value: {}", e))?;
+    if payload.len() < NONCE_LEN {
+        return Err("Invalid encrypted value".to_string());
+    }
+
+    let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Failed to decrypt value".to_string())?;
+    String::from_utf8(plaintext).map_err(|e| format!("Invalid decrypted value: {}", e))
+}
+
+fn current_key() -> Option<[u8; 32]> {
+    KEY.lock().ok().and_then(|guard| *guard)
+}
+
+fn set_key(key: Option<[u8; 32]>) {
+    if let Ok(mut guard) = KEY.lock() {
+        if let Some(old) = guard.as_mut() {
+            old.fill(0);
+        }
+        *guard = key;
+    }
+}
+
+fn touch() {
+    if let Ok(mut guard) = LAST_ACTIVITY.lock() {
+        *guard = Some(Instant::now());
+    }
+}
+
+/// Whether the app lock is enabled and the key is not in memory
+pub fn is_locked() -> bool {
+    ENABLED.load(Ordering::SeqCst) && current_key().is_none()
+}
+
+/// Encrypt `value` with `key` unless it is empty or already encrypted
+fn encrypt_value(key: &[u8; 32], value: &str) -> Result<String, String> {
+    if value.is_empty() || value.starts_with(ENCRYPTED_PREFIX) {
+        return Ok(value.to_string());
+    }
+    encrypt_with(key, value)
+}
+
+/// Decrypt `value` with `key` when it is encrypted
+fn decrypt_value(key: &[u8; 32], value: &str) -> Result<String, String> {
+    if value.starts_with(ENCRYPTED_PREFIX) {
+        decrypt_with(key, value)
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+/// Encrypt a sensitive value before it is written to the database
+///
+/// Values are left as-is when the app lock is disabled, the value is empty or already
+/// encrypted (e.g. settings read while locked and saved back). A new value can't be
+/// written while the app is locked, it would end up in plaintext.
+pub fn encrypt_field(value: &str) -> Result<String, String> {
+    if !ENABLED.load(Ordering::SeqCst) || value.is_empty() || value.starts_with(ENCRYPTED_PREFIX) {
+        return Ok(value.to_string());
+    }
+    let key = current_key().ok_or_else(|| LOCKED_ERROR.to_string())?;
+    encrypt_with(&key, value)
+}
+
+/// Decrypt a sensitive value read from the database
+///
+/// Encrypted values stay encrypted while the app is locked.
+pub fn decrypt_field(value: &str) -> String {
+    if !value.starts_with(ENCRYPTED_PREFIX) {
+        return value.to_string();
+    }
+    match current_key().map(|key| decrypt_with(&key, value)) {
+        Some(Ok(plaintext)) => plaintext,
+        Some(Err(e)) => {
+            log::warn!("Failed to decrypt sensitive value: {}", e);
+            value.to_string()
+        }
+        None => value.to_string(),
+    }
+}
+
+/// Fail when `value` still holds encrypted credentials, i.e. it was read while the app is
+/// locked. Backend tasks (tray / hotkey applies) check this instead of writing the ciphertext.
+pub fn ensure_decrypted(value: &str) -> Result<(), String> {
+    if value.contains(ENCRYPTED_PREFIX) {
+        Err(LOCKED_ERROR.to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// Pass the string values at `pointers` of a JSON document through `map`
+///
+/// Documents that aren't valid JSON have no fields to find and are returned unchanged,
+/// as are documents where `map` changed nothing.
+fn map_json_secrets(
+    document: &str,
+    pointers: &[&str],
+    map: impl Fn(&str) -> Result<String, String>,
+) -> Result<String, String> {
+    let Ok(mut root) = serde_json::from_str::<Value>(document) else {
+        return Ok(document.to_string());
+    };
+    let mut changed = false;
+    for pointer in pointers {
+        if let Some(Value::String(value)) = root.pointer_mut(pointer) {
+            let mapped = map(value.as_str())?;
+            if mapped != *value {
+                *value = mapped;
+                changed = true;
+            }
+        }
+    }
+    if !changed {
+        return Ok(document.to_string());
+    }
+    serde_json::to_string(&root).map_err(|e| format!("Failed to serialize config: {}", e))
+}
+
+/// Encrypt the API keys in a provider `settings_config` before it is written
+pub fn encrypt_json_secrets(document: &str, pointers: &[&str]) -> Result<String, String> {
+    map_json_secrets(document, pointers, encrypt_field)
+}
+
+/// Decrypt the API keys in a provider `settings_config` read from the database
+pub fn decrypt_json_secrets(document: &str, pointers: &[&str]) -> String {
+    map_json_secrets(document, pointers, |value| Ok(decrypt_field(value)))
+        .unwrap_or_else(|_| document.to_string())
+}
+
+/// Wrap the app's invoke handler: commands are rejected while locked, and every
+/// accepted command counts as activity for the auto-lock timer
+pub fn guarded<R, H>(handler: H) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static
+where
+    R: Runtime,
+    H: Fn(Invoke<R>) -> bool + Send + Sync + 'static,
+{
+    move |invoke| {
+        if is_locked() && !ALLOWED_WHILE_LOCKED.contains(&invoke.message.command()) {
+            invoke.resolver.reject(LOCKED_ERROR);
+            return true;
+        }
+        touch();
+        handler(invoke)
+    }
+}
+
+async fn load_config(db_state: &DbState) -> Result<Option<LockConfig>, String> {
+    let db = db_state.db();
+    let mut result = db
+        .query("SELECT * OMIT id FROM app_lock:`config` LIMIT 1")
+        .await
+        .map_err(|e| format!("Failed to query app lock: {}", e))?;
+    let records: Vec<serde_json::Value> = result
+        .take(0)
+        .map_err(|e| format!("Failed to parse app lock: {}", e))?;
+    Ok(records
+        .into_iter()
+        .next()
+        .and_then(|record| serde_json::from_value(record).ok()))
+}
+
+async fn save_config(db_state: &DbState, config: &LockConfig) -> Result<(), String> {
+    let db = db_state.db();
+    let data =
+        serde_json::to_value(config).map_err(|e| format!("Failed to serialize app lock: {}", e))?;
+    db.query("UPSERT app_lock:`config` CONTENT $data")
+        .bind(("data", data))
+        .await
+        .map_err(|e| format!("Failed to save app lock: {}", e))?;
+    Ok(())
+}
+
+/// Check `password` against the stored verifier and return the derived key
+fn unlock_key(config: &LockConfig, password: &str) -> Result<[u8; 32], String> {
+    let salt = b64()
+        .decode(&config.salt)
+        .map_err(|e| format!("Invalid app lock salt: {}", e))?;
+    let key = derive_key(password, &salt, &config.kdf)?;
+    match decrypt_with(&key, &config.verifier) {
+        Ok(plaintext) if plaintext == VERIFIER_PLAINTEXT => Ok(key),
+        _ => Err("Incorrect password".to_string()),
+    }
+}
+
+/// Provider fields that change when their API keys go through `map`,
+/// as (record id, field, new value)
+async fn map_provider_secrets(
+    db_state: &DbState,
+    map: impl Fn(&str) -> Result<String, String>,
+) -> Result<Vec<(String, &'static str, String)>, String> {
+    let db = db_state.db();
+    let mut updates = Vec::new();
+    for (table, pointers) in PROVIDER_SECRET_TABLES {
+        let records: Vec<Value> = db
+            .query(format!(
+                "SELECT type::string(id) as id, settings_config FROM {}",
+                table
+            ))
+            .await
+            .map_err(|e| format!("Failed to query {}: {}", table, e))?
+            .take(0)
+            .map_err(|e| format!("Failed to parse {}: {}", table, e))?;
+        for record in records {
+            let Some(config) = record.get("settings_config").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let mapped = map_json_secrets(config, pointers, &map)?;
+            if mapped != config {
+                updates.push((
+                    db_record_id(table, &db_extract_id(&record)),
+                    "settings_config",
+                    mapped,
+                ));
+            }
+        }
+    }
+    for (table, field) in PROVIDER_KEY_FIELDS {
+        let records: Vec<Value> = db
+            .query(format!(
+                "SELECT type::string(id) as id, {} as value FROM {}",
+                field, table
+            ))
+            .await
+            .map_err(|e| format!("Failed to query {}: {}", table, e))?
+            .take(0)
+            .map_err(|e| format!("Failed to parse {}: {}", table, e))?;
+        for record in records {
+            let Some(value) = record.get("value").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let mapped = map(value)?;
+            if mapped != value {
+                updates.push((db_record_id(table, &db_extract_id(&record)), field, mapped));
+            }
+        }
+    }
+    Ok(updates)
+}
+
+/// Write the re-encrypted settings and provider fields together with the lock record
+/// (deleted when `lock` is None) in one transaction
+async fn commit_credentials(
+    db_state: &DbState,
+    settings_value: Value,
+    providers: Vec<(String, &'static str, String)>,
+    lock: Option<&LockConfig>,
+) -> Result<(), String> {
+    let mut query = String::from("BEGIN TRANSACTION;\nUPSERT settings:`app` CONTENT $settings;\n");
+    for (index, (record, field, _)) in providers.iter().enumerate() {
+        query.push_str(&format!(
+            "UPDATE {} SET {} = $config{};\n",
+            record, field, index
+        ));
+    }
+    query.push_str(if lock.is_some() {
+        "UPSERT app_lock:`config` CONTENT $lock;\n"
+    } else {
+        "DELETE app_lock:`config`;\n"
+    });
+    query.push_str("COMMIT TRANSACTION;");
+
+    let db = db_state.db();
+    let mut request = db
+        .query(query)
+        .bind(("settings", with_datetimes(settings_value)));
+    for (index, (_, _, config)) in providers.into_iter().enumerate() {
+        request = request.bind((format!("config{}", index), config));
+    }
+    if let Some(lock) = lock {
+        let data = serde_json::to_value(lock)
+            .map_err(|e| format!("Failed to serialize app lock: {}", e))?;
+        request = request.bind(("lock", data));
+    }
+    request
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to save encrypted credentials: {}", e))?;
+    Ok(())
+}
+
+fn status() -> AppLockStatus {
+    AppLockStatus {
+        enabled: ENABLED.load(Ordering::SeqCst),
+        locked: is_locked(),
+        auto_lock_minutes: AUTO_LOCK_MINUTES.load(Ordering::SeqCst),
+    }
+}
+
+/// Load the app lock state on startup; the app starts locked when enabled
+pub async fn init_app_lock<R: Runtime>(app: &AppHandle<R>) {
+    let state = app.state::<DbState>();
+    match load_config(&state).await {
+        Ok(Some(config)) => {
+            AUTO_LOCK_MINUTES.store(config.auto_lock_minutes, Ordering::SeqCst);
+            ENABLED.store(true, Ordering::SeqCst);
+        }
+        Ok(None) => {}
+        Err(e) => log::warn!("Failed to load app lock: {}", e),
+    }
+}
+
+/// Lock the app after `auto_lock_minutes` without any command
+pub fn start_auto_lock_timer<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(15)).await;
+
+            let minutes = AUTO_LOCK_MINUTES.load(Ordering::SeqCst);
+            if minutes == 0 || is_locked() || !ENABLED.load(Ordering::SeqCst) {
+                continue;
+            }
+            let idle = LAST_ACTIVITY
+                .lock()
+                .ok()
+                .and_then(|guard| guard.map(|last| last.elapsed()))
+                .unwrap_or_default();
+            if idle >= Duration::from_secs(u64::from(minutes) * 60) {
+                set_key(None);
+                log::info!("App locked after {} minutes of inactivity", minutes);
+                let _ = app.emit(APP_LOCKED_EVENT, ());
+            }
+        }
+    });
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+#[tauri::command]
+pub fn get_app_lock_status() -> AppLockStatus {
+    status()
+}
+
+/// Unlock the app with the master password
+#[tauri::command]
+pub async fn unlock_app(
+    state: tauri::State<'_, DbState>,
+    password: String,
+) -> Result<AppLockStatus, String> {
+    let config = load_config(&state)
+        .await?
+        .ok_or_else(|| "App lock is not enabled".to_string())?;
+    set_key(Some(unlock_key(&config, &password)?));
+    touch();
+    Ok(status())
+}
+
+/// Lock the app now
+#[tauri::command]
+pub fn lock_app(app: AppHandle) -> Result<AppLockStatus, String> {
+    if !ENABLED.load(Ordering::SeqCst) {
+        return Err("App lock is not enabled".to_string());
+    }
+    set_key(None);
+    let _ = app.emit(APP_LOCKED_EVENT, ());
+    Ok(status())
+}
+
+/// Enable the app lock and encrypt sensitive settings with the new password
+#[tauri::command]
+pub async fn enable_app_lock(
+    state: tauri::State<'_, DbState>,
+    password: String,
+    auto_lock_minutes: u32,
+) -> Result<AppLockStatus, String> {
+    if ENABLED.load(Ordering::SeqCst) {
+        return Err("App lock is already enabled".to_string());
+    }
+    if password.is_empty() {
+        return Err("Password is required".to_string());
+    }
+
+    let current = settings::get_settings(state.clone()).await?;
+
+    let mut salt = [0u8; 16];
+    OsRng.fill_bytes(&mut salt);
+    let kdf = KdfParams::current();
+    let key = derive_key(&password, &salt, &kdf)?;
+    let config = LockConfig {
+        salt: b64().encode(salt),
+        verifier: encrypt_with(&key, VERIFIER_PLAINTEXT)?,
+        auto_lock_minutes,
+        kdf,
+    };
+
+    let settings_value = adapter::to_db_value_with(&current, |value| encrypt_value(&key, value))?;
+    let providers = map_provider_secrets(&state, |value| encrypt_value(&key, value)).await?;
+    commit_credentials(&state, settings_value, providers, Some(&config)).await?;
+
+    set_key(Some(key));
+    AUTO_LOCK_MINUTES.store(auto_lock_minutes, Ordering::SeqCst);
+    ENABLED.store(true, Ordering::SeqCst);
+    touch();
+    Ok(status())
+}
+
+/// Disable the app lock and store sensitive settings unencrypted again
+#[tauri::command]
+pub async fn disable_app_lock(
+    state: tauri::State<'_, DbState>,
+    password: String,
+) -> Result<AppLockStatus, String> {
+    let config = load_config(&state)
+        .await?
+        .ok_or_else(|| "App lock is not enabled".to_string())?;
+    let key = unlock_key(&config, &password)?;
+    set_key(Some(key));
+
+    // Decrypt everything and write it in plaintext; the lock stays on if that fails
+    let current = settings::get_settings(state.clone()).await?;
+    let settings_value = adapter::to_db_value_with(&current, |value| decrypt_value(&key, value))?;
+    let providers = map_provider_secrets(&state, |value| decrypt_value(&key, value)).await?;
+    commit_credentials(&state, settings_value, providers, None).await?;
+
+    ENABLED.store(false, Ordering::SeqCst);
+    set_key(None);
+    AUTO_LOCK_MINUTES.store(0, Ordering::SeqCst);
+    Ok(status())
+}
+
+/// Change the idle timeout (0 = never auto-lock)
+#[tauri::command]
+pub async fn set_app_lock_timeout(
+    state: tauri::State<'_, DbState>,
+    auto_lock_minutes: u32,
+) -> Result<AppLockStatus, String> {
+    let mut config = load_config(&state)
+        .await?
+        .ok_or_else(|| "App lock is not enabled".to_string())?;
+    config.auto_lock_minutes = auto_lock_minutes;
+    save_config(&state, &config).await?;
+    AUTO_LOCK_MINUTES.store(auto_lock_minutes, Ordering::SeqCst);
+    Ok(status())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypted_values_round_trip_only_with_the_same_key() {
+        let kdf = KdfParams::current();
+        let key = derive_key("correct horse", b"0123456789abcdef", &kdf).unwrap();
+        let encrypted = encrypt_with(&key, "webdav-secret").unwrap();
+        assert!(encrypted.starts_with(ENCRYPTED_PREFIX));
+        assert_eq!(decrypt_with(&key, &encrypted).unwrap(), "webdav-secret");
+
+        let other = derive_key("wrong", b"0123456789abcdef", &kdf).unwrap();
+        assert!(decrypt_with(&other, &encrypted).is_err());
+    }
+
+    #[test]
+    fn verifier_rejects_wrong_password() {
+        let salt = b"0123456789abcdef";
+        let kdf = KdfParams::current();
+        let key = derive_key("secret", salt, &kdf).unwrap();
+        let config = LockConfig {
+            salt: b64().encode(salt),
+            verifier: encrypt_with(&key, VERIFIER_PLAINTEXT).unwrap(),
+            auto_lock_minutes: 0,
+            kdf,
+        };
+        assert!(unlock_key(&config, "secret").is_ok());
+        assert!(unlock_key(&config, "guess").is_err());
+    }
+
+    #[test]
+    fn provider_api_keys_are_mapped_in_place() {
+        let key = derive_key("secret", b"0123456789abcdef", &KdfParams::current()).unwrap();
+        let config =
+            r#"{"env":{"ANTHROPIC_AUTH_TOKEN":"sk-ant","ANTHROPIC_BASE_URL":"https://x"}}"#;
+
+        let encrypted =
+            map_json_secrets(config, &CLAUDE_PROVIDER_SECRETS, |v| encrypt_value(&key, v)).unwrap();
+        assert!(!encrypted.contains("sk-ant"));
+        assert!(encrypted.contains("https://x"));
+        assert!(ensure_decrypted(&encrypted).is_err());
+
+        let decrypted = map_json_secrets(&encrypted, &CLAUDE_PROVIDER_SECRETS, |v| {
+            decrypt_value(&key, v)
+        })
+        .unwrap();
+        assert_eq!(decrypted, config);
+        assert!(ensure_decrypted(&decrypted).is_ok());
+    }
+}
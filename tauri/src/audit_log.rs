@@ -0,0 +1,415 @@
+//! Audit Log Module
+//!
+//! Mutating commands (provider CRUD, config applies, syncs, restores, settings changes)
+//! and config changes made from the tray are appended to the `audit_log` table with a
+//! timestamp, the command name and a summarized payload. It answers questions like
+//! "what changed my settings.json last night".
+//!
+//! - Recording happens in the invoke handler wrapper ([`audited`]), so new commands are
+//!   covered as long as their names use the verbs in [`MUTATING_VERBS`]
+//! - Credentials are redacted and long values are summarized before anything is stored
+//! - Entries older than `audit_log_retention_days` are purged at startup and whenever the
+//!   setting is saved; a retention of 0 turns recording off
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use tauri::ipc::{Invoke, InvokeBody};
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::DbState;
+
+pub const DEFAULT_RETENTION_DAYS: u32 = 30;
+const DEFAULT_QUERY_LIMIT: usize = 200;
+const MAX_QUERY_LIMIT: usize = 2000;
+const MAX_STRING_LEN: usize = 200;
+const MAX_PAYLOAD_LEN: usize = 2000;
+const REDACTED: &str = "<redacted>";
+
+/// Verbs that mark a command as mutating
+const MUTATING_VERBS: &[&str] = &[
+    "add",
+    "apply",
+    "backup",
+    "bootstrap",
+    "clear",
+    "create",
+    "delete",
+    "disable",
+    "enable",
+    "exec",
+    "fix",
+    "forget",
+    "import",
+    "init",
+    "install",
+    "logout",
+    "pull",
+    "remove",
+    "rename",
+    "reorder",
+    "repair",
+    "reset",
+    "restore",
+    "resync",
+    "rollback",
+    "save",
+    "select",
+    "set",
+    "skip",
+    "sync",
+    "toggle",
+    "trust",
+    "uninstall",
+    "unsync",
+    "update",
+    "upgrade",
+    "upsert",
+];
+/// Verbs of read-only commands; the first known verb in a command name decides
+const READ_ONLY_VERBS: &[&str] = &[
+    "browse", "check", "detect", "export", "extract", "fetch", "get", "has", "hide", "is", "lint",
+    "list", "load", "open", "preview", "read", "resolve", "reveal", "scan", "search", "take",
+    "test", "validate",
+];
+/// Mutating by name but too frequent or too trivial to be worth recording
+const SKIPPED_COMMANDS: &[&str] = &["set_window_background_color"];
+/// Key fragments (lowercase, without `_`/`-`) whose values are never stored
+const SENSITIVE_KEY_PARTS: &[&str] = &[
+    "password",
+    "passphrase",
+    "secret",
+    "token",
+    "apikey",
+    "accesskey",
+    "privatekey",
+    "credential",
+    "authorization",
+    "cookie",
+];
+
+/// Retention in days, mirrored from the settings; 0 disables recording
+static RETENTION_DAYS: AtomicU32 = AtomicU32::new(DEFAULT_RETENTION_DAYS);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    pub timestamp: String,
+    pub command: String,
+    /// Redacted, summarized JSON of the command arguments
+    pub payload: String,
+    /// Window label the command came from, or "tray"
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogFilter {
+    /// Substring of the command name
+    pub command: Option<String>,
+    /// Only entries at or after this RFC 3339 time
+    pub since: Option<String>,
+    /// Maximum number of entries, newest first (default: 200)
+    pub limit: Option<usize>,
+}
+
+/// Whether a command changes state and should be recorded
+pub fn is_mutating_command(command: &str) -> bool {
+    if SKIPPED_COMMANDS.contains(&command) {
+        return false;
+    }
+    for word in command.split('_') {
+        if READ_ONLY_VERBS.contains(&word) {
+            return false;
+        }
+        if MUTATING_VERBS.contains(&word) {
+            return true;
+        }
+    }
+    false
+}
+
+fn is_sensitive_key(key: &str) -> bool {
+    let normalized: String = key
+        .chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect();
+    SENSITIVE_KEY_PARTS
+        .iter()
+        .any(|part| normalized.contains(part))
+}
+
+fn summarize_value(value: &Value, redact_all: bool) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| {
+                    let value = if is_sensitive_key(key)
+                        && matches!(value, Value::String(_) | Value::Object(_) | Value::Array(_))
+                    {
+                        json!(REDACTED)
+                    } else {
+                        summarize_value(value, redact_all)
+                    };
+                    (key.clone(), value)
+                })
+                .collect::<Map<String, Value>>(),
+        ),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| summarize_value(item, redact_all))
+                .collect(),
+        ),
+        Value::String(text) => summarize_string(text, redact_all),
+        other => other.clone(),
+    }
+}
+
+fn summarize_string(text: &str, redact_all: bool) -> Value {
+    if redact_all && !text.is_empty() {
+        return json!(REDACTED);
+    }
+    // Provider configs arrive as JSON strings; redact inside them too
+    if text.starts_with('{') || text.starts_with('[') {
+        if let Ok(nested) = serde_json::from_str::<Value>(text) {
+            return json!(summarize_value(&nested, redact_all).to_string());
+        }
+    }
+    // File contents, prompts and TOML are recorded by size only
+    if text.contains('\n') || text.chars().count() > MAX_STRING_LEN {
+        return json!(format!("<{} chars>", text.chars().count()));
+    }
+    json!(text)
+}
+
+/// Redacted, size-limited JSON of a command payload
+pub fn summarize_payload(command: &str, payload: &Value) -> String {
+    // Secret and lock commands carry the credential itself in plain argument names
+    let redact_all = ["secret", "credential", "lock"]
+        .iter()
+        .any(|word| command.split('_').any(|part| part == *word));
+    let mut text = summarize_value(payload, redact_all).to_string();
+    if text.len() > MAX_PAYLOAD_LEN {
+        let mut end = MAX_PAYLOAD_LEN;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+        text.push_str("...");
+    }
+    text
+}
+
+fn now_timestamp() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Timestamps are stored in UTC so they compare correctly as strings
+fn normalize_timestamp(value: &str) -> Result<String, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| {
+            time.with_timezone(&Utc)
+                .to_rfc3339_opts(SecondsFormat::Millis, true)
+        })
+        .map_err(|e| format!("Invalid time '{}': {}", value, e))
+}
+
+/// Append an entry in the background
+pub fn record<R: Runtime>(app: &AppHandle<R>, command: &str, source: &str, payload: &Value) {
+    if RETENTION_DAYS.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+    let entry = AuditLogEntry {
+        timestamp: now_timestamp(),
+        command: command.to_string(),
+        payload: summarize_payload(command, payload),
+        source: source.to_string(),
+    };
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let Some(state) = app.try_state::<DbState>() else {
+            return;
+        };
+        let data = serde_json::to_value(&entry).unwrap_or_default();
+        if let Err(e) = state
+            .db()
+            .query("CREATE audit_log CONTENT $data")
+            .bind(("data", data))
+            .await
+        {
+            log::warn!("Failed to write audit log entry: {}", e);
+        }
+    });
+}
+
+/// Wrap the app's invoke handler so mutating commands are recorded
+pub fn audited<R, H>(handler: H) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static
+where
+    R: Runtime,
+    H: Fn(Invoke<R>) -> bool + Send + Sync + 'static,
+{
+    move |invoke| {
+        let command = invoke.message.command();
+        if is_mutating_command(command) {
+            let payload = match invoke.message.payload() {
+                InvokeBody::Json(value) => value.clone(),
+                InvokeBody::Raw(bytes) => json!(format!("<{} bytes>", bytes.len())),
+            };
+            let webview = invoke.message.webview_ref();
+            record(webview.app_handle(), command, webview.label(), &payload);
+        }
+        handler(invoke)
+    }
+}
+
+/// Apply the retention setting and drop entries older than it
+pub async fn set_retention_days(db_state: &DbState, days: u32) {
+    RETENTION_DAYS.store(days, Ordering::Relaxed);
+
+    // 0 turns recording off; keep existing entries in case it is turned back on
+    if days == 0 {
+        return;
+    }
+    let cutoff = (Utc::now() - chrono::Duration::days(days as i64))
+        .to_rfc3339_opts(SecondsFormat::Millis, true);
+    if let Err(e) = db_state
+        .db()
+        .query("DELETE audit_log WHERE timestamp < $cutoff")
+        .bind(("cutoff", cutoff))
+        .await
+    {
+        log::warn!("Failed to purge audit log: {}", e);
+    }
+}
+
+/// Load the retention setting and purge old entries (called at startup)
+pub async fn init<R: Runtime>(app: &AppHandle<R>) {
+    let state = app.state::<DbState>();
+    let days = match crate::settings::get_settings(state.clone()).await {
+        Ok(settings) => settings.audit_log_retention_days,
+        Err(_) => DEFAULT_RETENTION_DAYS,
+    };
+    set_retention_days(&state, days).await;
+}
+
+/// Query the audit log, newest first
+#[tauri::command]
+pub async fn get_audit_log(
+    state: tauri::State<'_, DbState>,
+    filter: Option<AuditLogFilter>,
+) -> Result<Vec<AuditLogEntry>, String> {
+    let filter = filter.unwrap_or_default();
+    let command = filter
+        .command
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty());
+    let since = filter
+        .since
+        .as_deref()
+        .map(normalize_timestamp)
+        .transpose()?;
+    let limit = filter
+        .limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT)
+        .clamp(1, MAX_QUERY_LIMIT);
+
+    let mut conditions = Vec::new();
+    if command.is_some() {
+        conditions.push("string::contains(command, $command)");
+    }
+    if since.is_some() {
+        conditions.push("timestamp >= $since");
+    }
+    let where_clause = if conditions.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", conditions.join(" AND "))
+    };
+    let query = format!(
+        "SELECT * OMIT id FROM audit_log{} ORDER BY timestamp DESC LIMIT {}",
+        where_clause, limit
+    );
+
+    let mut result = state
+        .db()
+        .query(query)
+        .bind(("command", command.unwrap_or_default()))
+        .bind(("since", since.unwrap_or_default()))
+        .await
+        .map_err(|e| format!("Failed to query audit log: {}", e))?;
+    let records: Vec<Value> = result
+        .take(0)
+        .map_err(|e| format!("Failed to parse audit log: {}", e))?;
+    Ok(records
+        .into_iter()
+        .filter_map(|record| serde_json::from_value(record).ok())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_commands_by_their_first_verb() {
+        for command in [
+            "create_claude_provider",
+            "apply_codex_config",
+            "restore_from_webdav",
+            "save_settings",
+            "wsl_sync",
+            "skills_install_git",
+            "mcp_set_secret",
+            "ssh_pull_apply",
+        ] {
+            assert!(is_mutating_command(command), "{}", command);
+        }
+        for command in [
+            "get_settings",
+            "list_claude_providers",
+            "wsl_get_sync_history",
+            "skills_check_updates",
+            "export_settings",
+            "set_window_background_color",
+            "refresh_tray_menu",
+        ] {
+            assert!(!is_mutating_command(command), "{}", command);
+        }
+    }
+
+    #[test]
+    fn redacts_credentials_including_nested_config_strings() {
+        let payload = json!({
+            "settings": {
+                "webdav": { "url": "https://dav.example.com", "password": "hunter2" },
+                "proxy_url": "http://proxy:8080"
+            },
+            "provider": {
+                "name": "Team",
+                "settingsConfig": "{\"env\":{\"ANTHROPIC_AUTH_TOKEN\":\"sk-live\",\"ANTHROPIC_BASE_URL\":\"https://api\"}}"
+            }
+        });
+        let summary = summarize_payload("save_settings", &payload);
+        assert!(!summary.contains("hunter2"));
+        assert!(!summary.contains("sk-live"));
+        assert!(summary.contains("dav.example.com"));
+        assert!(summary.contains("ANTHROPIC_BASE_URL"));
+    }
+
+    #[test]
+    fn redacts_every_value_of_secret_commands_and_limits_size() {
+        let summary = summarize_payload("set_secret", &json!({ "name": "KEY", "value": "v" }));
+        assert!(!summary.contains("\"v\""));
+
+        let content = "line\n".repeat(1000);
+        let summary = summarize_payload("save_opencode_config", &json!({ "content": content }));
+        assert!(summary.contains("<5000 chars>"));
+
+        let many: Vec<String> = (0..500).map(|i| format!("item-{}", i)).collect();
+        let summary = summarize_payload("skills_reorder", &json!({ "ids": many }));
+        assert!(summary.len() <= MAX_PAYLOAD_LEN + 3);
+    }
+}
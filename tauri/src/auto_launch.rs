@@ -4,9 +4,15 @@
 //! - Windows: Registry (HKCU\Software\Microsoft\Windows\CurrentVersion\Run)
 //! - macOS: LaunchAgent or AppleScript Login Item
 //! - Linux: XDG autostart (~/.config/autostart/)
+//!
+//! The autostart entry passes [`AUTOSTART_ARG`] so a launch at login can start hidden in
+//! the tray while a manual launch always shows the window.
 
 use thiserror::Error;
 
+/// Argument added to the autostart entry to tell login launches apart
+pub const AUTOSTART_ARG: &str = "--autostart";
+
 #[derive(Error, Debug)]
 pub enum AutoLaunchError {
     #[error("Failed to get executable path: {0}")]
@@ -55,6 +61,7 @@ fn get_auto_launch() -> Result<auto_launch::AutoLaunch, AutoLaunchError> {
     AutoLaunchBuilder::new()
         .set_app_name(app_name)
         .set_app_path(&app_path.to_string_lossy())
+        .set_args(&[AUTOSTART_ARG])
         .build()
         .map_err(|e| AutoLaunchError::Build(e.to_string()))
 }
@@ -85,6 +92,33 @@ pub fn is_auto_launch_enabled() -> Result<bool, AutoLaunchError> {
         .map_err(|e| AutoLaunchError::Check(e.to_string()))
 }
 
+/// Make the autostart entry match the setting
+///
+/// Enabling rewrites the entry so it follows a moved executable and carries the current
+/// arguments; disabling only touches the system when an entry exists.
+pub fn sync_auto_launch(enabled: bool) -> Result<(), AutoLaunchError> {
+    if enabled {
+        enable_auto_launch()
+    } else if is_auto_launch_enabled()? {
+        disable_auto_launch()
+    } else {
+        Ok(())
+    }
+}
+
+/// Whether this process was started by the autostart entry
+pub fn launched_by_autostart() -> bool {
+    std::env::args().skip(1).any(|arg| arg == AUTOSTART_ARG)
+}
+
+/// Whether the main window should stay hidden in the tray at startup
+///
+/// macOS login items (AppleScript) cannot pass arguments, so there the setting applies to
+/// every launch.
+pub fn should_start_minimized(start_minimized: bool) -> bool {
+    start_minimized && (cfg!(target_os = "macos") || launched_by_autostart())
+}
+
 #[cfg(all(test, target_os = "macos"))]
 mod tests {
     use super::get_macos_app_bundle_path;
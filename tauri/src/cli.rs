@@ -0,0 +1,259 @@
+//! Headless CLI Module
+//!
+//! Runs one action without creating a window, prints the result as JSON on stdout
+//! and exits with 0 on success or 1 on failure:
+//! - `--apply-claude-provider <id>`: write a Claude Code provider to settings.json
+//! - `--ssh-sync`: sync everything to the active SSH connection
+//! - `--backup [dir]`: back up to the configured destination, or as a zip into `dir`
+//!
+//! Other arguments (`--portable`, deep links) are left to the normal startup. The
+//! database can only be opened by one process, so these fail while the app is running.
+
+use serde_json::{json, Value};
+use tauri::Manager;
+
+use crate::coding;
+use crate::db::{self, DbState};
+use crate::portable;
+use crate::settings;
+
+const APPLY_CLAUDE_PROVIDER_FLAG: &str = "--apply-claude-provider";
+const SSH_SYNC_FLAG: &str = "--ssh-sync";
+const BACKUP_FLAG: &str = "--backup";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    ApplyClaudeProvider(String),
+    SshSync,
+    /// Back up into the given directory, or to the configured destination
+    Backup(Option<String>),
+}
+
+impl CliCommand {
+    fn action(&self) -> &'static str {
+        match self {
+            Self::ApplyClaudeProvider(_) => "apply_claude_provider",
+            Self::SshSync => "ssh_sync",
+            Self::Backup(_) => "backup",
+        }
+    }
+}
+
+/// Find a headless action in the command line arguments (without the program name).
+///
+/// Returns `Ok(None)` when the app should start normally.
+pub fn parse_args<I>(args: I) -> Result<Option<CliCommand>, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter().peekable();
+    let mut command = None;
+
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+        let parsed = match flag.as_str() {
+            APPLY_CLAUDE_PROVIDER_FLAG => {
+                let id = inline_value
+                    .or_else(|| args.next_if(|next| !next.starts_with("--")))
+                    .filter(|id| !id.trim().is_empty())
+                    .ok_or_else(|| {
+                        format!("{} requires a provider id", APPLY_CLAUDE_PROVIDER_FLAG)
+                    })?;
+                CliCommand::ApplyClaudeProvider(id.trim().to_string())
+            }
+            SSH_SYNC_FLAG => CliCommand::SshSync,
+            BACKUP_FLAG => CliCommand::Backup(
+                inline_value
+                    .or_else(|| args.next_if(|next| !next.starts_with("--")))
+                    .filter(|dir| !dir.trim().is_empty()),
+            ),
+            _ => continue,
+        };
+        if command.is_some() {
+            return Err("Only one action can be run at a time".to_string());
+        }
+        command = Some(parsed);
+    }
+
+    Ok(command)
+}
+
+/// Release builds use the GUI subsystem on Windows and have no console to print to
+#[cfg(target_os = "windows")]
+fn attach_parent_console() {
+    use windows_sys::Win32::System::Console::{AttachConsole, ATTACH_PARENT_PROCESS};
+    // Fails harmlessly when there is no parent console or one is already attached
+    unsafe {
+        AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
+fn print_json(value: &Value) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string())
+    );
+}
+
+/// Print a command line error as JSON; returns the exit code
+pub fn report_error(error: &str) -> i32 {
+    #[cfg(target_os = "windows")]
+    attach_parent_console();
+    print_json(&json!({ "ok": false, "error": error }));
+    1
+}
+
+/// Run `command` headless and print its result; returns the exit code
+pub fn run(command: CliCommand, context: tauri::Context<tauri::Wry>) -> i32 {
+    #[cfg(target_os = "windows")]
+    attach_parent_console();
+
+    log::info!("命令行模式: {:?}", command);
+    let action = command.action();
+
+    #[allow(unused_mut)]
+    let mut app = match tauri::Builder::default().build(context) {
+        Ok(app) => app,
+        Err(e) => {
+            print_json(&json!({ "ok": false, "action": action, "error": e.to_string() }));
+            return 1;
+        }
+    };
+    // No Dock icon for a run that never shows a window
+    #[cfg(target_os = "macos")]
+    app.set_activation_policy(tauri::ActivationPolicy::Accessory);
+
+    let app_handle = app.handle().clone();
+    let result = tauri::async_runtime::block_on(async move {
+        init_state(&app_handle).await?;
+        execute(&app_handle, command).await
+    });
+
+    match result {
+        Ok((success, result)) => {
+            print_json(&json!({ "ok": success, "action": action, "result": result }));
+            if success {
+                0
+            } else {
+                1
+            }
+        }
+        Err(e) => {
+            log::error!("命令行操作失败: {}", e);
+            print_json(&json!({ "ok": false, "action": action, "error": e }));
+            1
+        }
+    }
+}
+
+/// Register the state the commands below rely on
+async fn init_state(app_handle: &tauri::AppHandle) -> Result<(), String> {
+    let db_path = portable::app_data_dir(app_handle)
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?
+        .join("database");
+    let db = db::open_database(&db_path)
+        .await
+        .map_err(|e| format!("{} (is AI Toolbox already running?)", e))?;
+
+    app_handle.manage(DbState(db));
+    app_handle.manage(coding::ssh::SshSessionState(std::sync::Arc::new(
+        tokio::sync::Mutex::new(coding::ssh::SshSession::new()),
+    )));
+
+    // Sync results are reported in the UI language
+    let behavior = settings::window_behavior::load_window_behavior(app_handle).await;
+    crate::locale::set_language(&behavior.language);
+    Ok(())
+}
+
+/// Run the action; returns whether it succeeded along with its JSON result
+async fn execute(
+    app_handle: &tauri::AppHandle,
+    command: CliCommand,
+) -> Result<(bool, Value), String> {
+    let db_state = app_handle.state::<DbState>();
+
+    match command {
+        CliCommand::ApplyClaudeProvider(id) => {
+            let db = db_state.db();
+            coding::claude_code::apply_config_internal(&db, app_handle, &id, false).await?;
+            Ok((true, json!({ "provider_id": id })))
+        }
+        CliCommand::SshSync => {
+            let config = coding::ssh::get_ssh_config_internal(&db_state.db(), true).await?;
+            if config.enabled && !config.active_connection_id.is_empty() {
+                let connection = config
+                    .connections
+                    .iter()
+                    .find(|c| c.id == config.active_connection_id)
+                    .ok_or_else(|| "The active SSH connection no longer exists".to_string())?;
+                let session_state = app_handle.state::<coding::ssh::SshSessionState>();
+                session_state.0.lock().await.connect(connection).await?;
+            }
+
+            let result = coding::ssh::ssh_sync(
+                db_state,
+                app_handle.state(),
+                app_handle.clone(),
+                None,
+                None,
+                None,
+            )
+            .await?;
+            let success = result.success;
+            let value = serde_json::to_value(result)
+                .map_err(|e| format!("Failed to serialize sync result: {}", e))?;
+            Ok((success, value))
+        }
+        CliCommand::Backup(Some(dir)) => {
+            let path = settings::backup::backup_database(app_handle.clone(), dir).await?;
+            Ok((true, json!({ "path": path })))
+        }
+        CliCommand::Backup(None) => {
+            let location = settings::backup::auto_backup::backup_now(app_handle).await?;
+            Ok((true, json!({ "path": location })))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Option<CliCommand>, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_actions() {
+        assert_eq!(parse(&[]), Ok(None));
+        assert_eq!(parse(&["--portable", "--autostart"]), Ok(None));
+        assert_eq!(
+            parse(&["--apply-claude-provider", "abc"]),
+            Ok(Some(CliCommand::ApplyClaudeProvider("abc".to_string())))
+        );
+        assert_eq!(
+            parse(&["--portable", "--apply-claude-provider=abc"]),
+            Ok(Some(CliCommand::ApplyClaudeProvider("abc".to_string())))
+        );
+        assert_eq!(parse(&["--ssh-sync"]), Ok(Some(CliCommand::SshSync)));
+        assert_eq!(
+            parse(&["--backup", "--portable"]),
+            Ok(Some(CliCommand::Backup(None)))
+        );
+        assert_eq!(
+            parse(&["--backup", "/tmp/backups"]),
+            Ok(Some(CliCommand::Backup(Some("/tmp/backups".to_string()))))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_actions() {
+        assert!(parse(&["--apply-claude-provider"]).is_err());
+        assert!(parse(&["--apply-claude-provider", "--ssh-sync"]).is_err());
+        assert!(parse(&["--ssh-sync", "--backup"]).is_err());
+    }
+}
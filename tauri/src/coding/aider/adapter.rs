@@ -0,0 +1,65 @@
+use serde_json::{json, Value};
+
+use super::types::{
+    AiderProvider, AiderProviderContent, AiderProviderSettings, DEFAULT_API_KEY_ENV,
+};
+use crate::app_lock;
+use crate::coding::db_extract_id;
+use crate::db_timestamp::Timestamp;
+
+/// Convert a database record to AiderProvider; unreadable settings fall back to an empty model
+pub fn from_db_value(value: Value) -> AiderProvider {
+    let mut settings = value
+        .get("settings")
+        .cloned()
+        .and_then(|settings| serde_json::from_value::<AiderProviderSettings>(settings).ok())
+        .unwrap_or_else(|| AiderProviderSettings {
+            model: String::new(),
+            weak_model: None,
+            editor_model: None,
+            api_key_env: DEFAULT_API_KEY_ENV.to_string(),
+            api_key: None,
+            api_base: None,
+        });
+    settings.api_key = settings
+        .api_key
+        .map(|api_key| app_lock::decrypt_field(&api_key));
+
+    AiderProvider {
+        id: db_extract_id(&value),
+        name: value
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        settings,
+        notes: value
+            .get("notes")
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+            .map(String::from),
+        sort_index: value
+            .get("sort_index")
+            .and_then(|v| v.as_i64())
+            .map(|v| v as i32),
+        is_applied: value
+            .get("is_applied")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        created_at: Timestamp::from_db(&value, "created_at"),
+        updated_at: Timestamp::from_db(&value, "updated_at"),
+    }
+}
+
+/// Convert AiderProviderContent to a database record
+/// The API key is encrypted when the app lock is enabled
+pub fn to_db_value(content: &AiderProviderContent) -> Result<Value, String> {
+    let mut value = serde_json::to_value(content).unwrap_or_else(|e| {
+        log::error!("Failed to serialize Aider provider: {}", e);
+        json!({})
+    });
+    if let Some(api_key) = &content.settings.api_key {
+        value["settings"]["apiKey"] = Value::String(app_lock::encrypt_field(api_key)?);
+    }
+    Ok(value)
+}
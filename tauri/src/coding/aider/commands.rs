@@ -0,0 +1,360 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use tauri::Emitter;
+
+use super::adapter;
+use super::config_file::{set_env_vars, set_yaml_keys};
+use super::types::{
+    AiderPaths, AiderPreferences, AiderProvider, AiderProviderContent, AiderProviderInput,
+    AiderProviderSettings,
+};
+use crate::coding::{db_mark_applied_query, db_new_id, db_record_id, db_reorder_query};
+use crate::db_timestamp::{with_datetimes, Timestamp};
+use crate::{app_lock, secrets, DbState};
+
+const TABLE: &str = "aider_provider";
+const CONFIG_FILE_NAME: &str = ".aider.conf.yml";
+const ENV_FILE_NAME: &str = ".env";
+const API_BASE_ENV: &str = "OPENAI_API_BASE";
+
+/// Config file keys that would override the key and endpoint written to `.env`
+const SHADOWING_CONFIG_KEYS: &[&str] = &["openai-api-key", "openai-api-base"];
+
+fn home_dir() -> Result<PathBuf, String> {
+    dirs::home_dir().ok_or_else(|| "Failed to get home directory".to_string())
+}
+
+fn config_path() -> Result<PathBuf, String> {
+    Ok(home_dir()?.join(CONFIG_FILE_NAME))
+}
+
+fn env_path() -> Result<PathBuf, String> {
+    Ok(home_dir()?.join(ENV_FILE_NAME))
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn is_env_name(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| !c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Trim the settings and check what Aider needs to start
+fn normalize_settings(settings: AiderProviderSettings) -> Result<AiderProviderSettings, String> {
+    let model = settings.model.trim().to_string();
+    if model.is_empty() {
+        return Err("A model is required".to_string());
+    }
+    let api_key_env = settings.api_key_env.trim().to_string();
+    if !is_env_name(&api_key_env) {
+        return Err(format!(
+            "'{}' is not a valid environment variable name",
+            api_key_env
+        ));
+    }
+
+    Ok(AiderProviderSettings {
+        model,
+        weak_model: non_empty(settings.weak_model),
+        editor_model: non_empty(settings.editor_model),
+        api_key_env,
+        api_key: non_empty(settings.api_key),
+        api_base: non_empty(settings.api_base),
+    })
+}
+
+fn read_optional(path: &Path) -> Result<String, String> {
+    if !path.exists() {
+        return Ok(String::new());
+    }
+    fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+/// Write the provider's models to `.aider.conf.yml` and its key / endpoint to `.env`
+///
+/// Vault secret references in the key and endpoint are substituted only in the written files.
+fn write_settings_to_files(settings: &AiderProviderSettings) -> Result<(), String> {
+    let api_key = match &settings.api_key {
+        Some(api_key) => {
+            app_lock::ensure_decrypted(api_key)?;
+            Some(secrets::resolve_text(api_key)?)
+        }
+        None => None,
+    };
+    let api_base = settings
+        .api_base
+        .as_deref()
+        .map(secrets::resolve_text)
+        .transpose()?;
+
+    let config_path = config_path()?;
+    let mut entries = vec![
+        ("model", Some(settings.model.clone())),
+        ("weak-model", settings.weak_model.clone()),
+        ("editor-model", settings.editor_model.clone()),
+    ];
+    entries.extend(SHADOWING_CONFIG_KEYS.iter().map(|key| (*key, None)));
+    let config = set_yaml_keys(&read_optional(&config_path)?, &entries);
+    fs::write(&config_path, config)
+        .map_err(|e| format!("Failed to write {}: {}", config_path.display(), e))?;
+
+    let env_path = env_path()?;
+    let current_env = read_optional(&env_path)?;
+    let mut env_entries = vec![(API_BASE_ENV, api_base)];
+    if let Some(api_key) = api_key {
+        env_entries.push((settings.api_key_env.as_str(), Some(api_key)));
+    }
+    let env = set_env_vars(&current_env, &env_entries);
+    if env != current_env {
+        fs::write(&env_path, env)
+            .map_err(|e| format!("Failed to write {}: {}", env_path.display(), e))?;
+    }
+
+    Ok(())
+}
+
+async fn get_provider(
+    db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
+    id: &str,
+) -> Result<AiderProvider, String> {
+    let records: Vec<Value> = db
+        .query(format!(
+            "SELECT *, type::string(id) as id FROM {} LIMIT 1",
+            db_record_id(TABLE, id)
+        ))
+        .await
+        .map_err(|e| format!("Failed to query Aider provider: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to query Aider provider: {}", e))?;
+
+    records
+        .into_iter()
+        .next()
+        .map(adapter::from_db_value)
+        .ok_or_else(|| format!("Aider provider '{}' not found", id))
+}
+
+#[tauri::command]
+pub fn get_aider_paths() -> Result<AiderPaths, String> {
+    let config_path = config_path()?;
+    Ok(AiderPaths {
+        config_exists: config_path.exists(),
+        config_path: config_path.to_string_lossy().to_string(),
+        env_path: env_path()?.to_string_lossy().to_string(),
+    })
+}
+
+#[tauri::command]
+pub async fn list_aider_providers(
+    state: tauri::State<'_, DbState>,
+) -> Result<Vec<AiderProvider>, String> {
+    let records: Vec<Value> = state
+        .db()
+        .query("SELECT *, type::string(id) as id FROM aider_provider ORDER BY sort_index ASC, name ASC")
+        .await
+        .map_err(|e| format!("Failed to query Aider providers: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to query Aider providers: {}", e))?;
+
+    Ok(records
+        .into_iter()
+        .map(adapter::from_db_value)
+        .filter(|provider| !provider.id.is_empty())
+        .collect())
+}
+
+#[tauri::command]
+pub async fn create_aider_provider(
+    state: tauri::State<'_, DbState>,
+    app: tauri::AppHandle,
+    provider: AiderProviderInput,
+) -> Result<AiderProvider, String> {
+    let db = state.db();
+    let now = Timestamp::now();
+
+    let sort_records: Vec<Value> = db
+        .query("SELECT sort_index FROM aider_provider ORDER BY sort_index DESC LIMIT 1")
+        .await
+        .map_err(|e| format!("Failed to query Aider provider sort index: {}", e))?
+        .take(0)
+        .unwrap_or_default();
+    let next_sort_index = sort_records
+        .first()
+        .and_then(|record| record.get("sort_index").and_then(|v| v.as_i64()))
+        .map(|v| v as i32 + 1)
+        .unwrap_or(0);
+
+    let content = AiderProviderContent {
+        name: provider.name.trim().to_string(),
+        settings: normalize_settings(provider.settings)?,
+        notes: non_empty(provider.notes),
+        sort_index: Some(next_sort_index),
+        is_applied: false,
+        created_at: now,
+        updated_at: now,
+    };
+    let id = db_new_id();
+
+    db.query(format!("CREATE {} CONTENT $data", db_record_id(TABLE, &id)))
+        .bind(("data", with_datetimes(adapter::to_db_value(&content)?)))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to create Aider provider: {}", e))?;
+
+    let _ = app.emit("config-changed", "window");
+    get_provider(&db, &id).await
+}
+
+/// Update a provider; the files are rewritten when it is the applied one
+#[tauri::command]
+pub async fn update_aider_provider(
+    state: tauri::State<'_, DbState>,
+    app: tauri::AppHandle,
+    provider: AiderProviderInput,
+) -> Result<AiderProvider, String> {
+    let db = state.db();
+    let id = provider
+        .id
+        .clone()
+        .ok_or_else(|| "ID is required for update".to_string())?;
+    let existing = get_provider(&db, &id).await?;
+
+    let content = AiderProviderContent {
+        name: provider.name.trim().to_string(),
+        settings: normalize_settings(provider.settings)?,
+        notes: non_empty(provider.notes),
+        sort_index: existing.sort_index,
+        is_applied: existing.is_applied,
+        created_at: existing.created_at.unwrap_or_else(Timestamp::now),
+        updated_at: Timestamp::now(),
+    };
+
+    db.query(format!("UPDATE {} CONTENT $data", db_record_id(TABLE, &id)))
+        .bind(("data", with_datetimes(adapter::to_db_value(&content)?)))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to update Aider provider: {}", e))?;
+
+    if content.is_applied {
+        write_settings_to_files(&content.settings)?;
+    }
+
+    let _ = app.emit("config-changed", "window");
+    get_provider(&db, &id).await
+}
+
+#[tauri::command]
+pub async fn delete_aider_provider(
+    state: tauri::State<'_, DbState>,
+    app: tauri::AppHandle,
+    id: String,
+) -> Result<(), String> {
+    state
+        .db()
+        .query(format!("DELETE {}", db_record_id(TABLE, &id)))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to delete Aider provider: {}", e))?;
+
+    let _ = app.emit("config-changed", "window");
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn reorder_aider_providers(
+    state: tauri::State<'_, DbState>,
+    app: tauri::AppHandle,
+    ids: Vec<String>,
+) -> Result<(), String> {
+    state
+        .db()
+        .query(db_reorder_query(TABLE, &ids, false))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to reorder Aider providers: {}", e))?;
+
+    let _ = app.emit("config-changed", "window");
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn apply_aider_provider(
+    state: tauri::State<'_, DbState>,
+    app: tauri::AppHandle,
+    provider_id: String,
+) -> Result<(), String> {
+    let db = state.db();
+    apply_provider_internal(&db, &app, &provider_id, false).await
+}
+
+/// Write a provider to Aider's files and mark it as the applied one
+pub async fn apply_provider_internal<R: tauri::Runtime>(
+    db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
+    app: &tauri::AppHandle<R>,
+    provider_id: &str,
+    from_tray: bool,
+) -> Result<(), String> {
+    let provider = get_provider(db, provider_id).await?;
+    write_settings_to_files(&provider.settings)?;
+
+    db.query(db_mark_applied_query(TABLE, provider_id))
+        .bind(("now", Timestamp::now().to_db()))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to set applied status: {}", e))?;
+
+    log::info!("Applied Aider provider {}", provider.name);
+    let payload = if from_tray { "tray" } else { "window" };
+    let _ = app.emit("config-changed", payload);
+    Ok(())
+}
+
+pub async fn get_aider_preferences(state: &DbState) -> Result<AiderPreferences, String> {
+    let records: Vec<Value> = state
+        .db()
+        .query("SELECT show_in_tray FROM aider_preferences:`default` LIMIT 1")
+        .await
+        .map_err(|e| format!("Failed to query Aider preferences: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to query Aider preferences: {}", e))?;
+
+    Ok(records
+        .into_iter()
+        .next()
+        .and_then(|record| serde_json::from_value(record).ok())
+        .unwrap_or_default())
+}
+
+#[tauri::command]
+pub async fn get_aider_show_in_tray(state: tauri::State<'_, DbState>) -> Result<bool, String> {
+    Ok(get_aider_preferences(&state).await?.show_in_tray)
+}
+
+/// Show or hide the Aider section of the tray menu
+#[tauri::command]
+pub async fn set_aider_show_in_tray(
+    state: tauri::State<'_, DbState>,
+    app: tauri::AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut prefs = get_aider_preferences(&state).await?;
+    prefs.show_in_tray = enabled;
+    let payload = serde_json::to_value(&prefs).map_err(|e| e.to_string())?;
+
+    state
+        .db()
+        .query("UPSERT aider_preferences:`default` CONTENT $data")
+        .bind(("data", payload))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to save Aider preferences: {}", e))?;
+
+    let _ = app.emit("config-changed", "window");
+    Ok(())
+}
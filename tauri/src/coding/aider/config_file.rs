@@ -0,0 +1,227 @@
+//! Line based edits of `.aider.conf.yml` and `.env`.
+//!
+//! Only the lines of the keys being set are touched, so comments, blank lines and the
+//! order of everything else stay as the user wrote them.
+
+/// Plain YAML scalars that would be read back as something other than a string
+const YAML_RESERVED: &[&str] = &["true", "false", "yes", "no", "on", "off", "null", "~"];
+
+/// A YAML scalar for `value`: plain when that reads back the same, double-quoted otherwise
+fn yaml_scalar(value: &str) -> String {
+    let plain = value
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic())
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./@+".contains(c))
+        && !YAML_RESERVED.contains(&value.to_ascii_lowercase().as_str());
+    if plain {
+        value.to_string()
+    } else {
+        // JSON string escaping is valid YAML
+        serde_json::to_string(value).unwrap_or_else(|_| format!("\"{}\"", value))
+    }
+}
+
+/// The value part of `line` when it is the top-level `key:` entry
+fn yaml_key_value<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    line.strip_prefix(key)?.trim_start().strip_prefix(':')
+}
+
+/// A trailing `# comment` after an unquoted value
+fn inline_comment(value: &str) -> Option<&str> {
+    let value = value.trim_start();
+    if value.starts_with('"') || value.starts_with('\'') {
+        return None;
+    }
+    if value.starts_with('#') {
+        return Some(value);
+    }
+    value.find(" #").map(|index| value[index..].trim_start())
+}
+
+/// Lines after a key line that still belong to its value (nested or list entries)
+fn yaml_value_end(lines: &[String], start: usize) -> usize {
+    let mut end = start + 1;
+    while end < lines.len() {
+        let line = &lines[end];
+        if line.trim().is_empty() || !(line.starts_with([' ', '\t']) || line.starts_with("- ")) {
+            break;
+        }
+        end += 1;
+    }
+    end
+}
+
+fn join_lines(lines: Vec<String>) -> String {
+    if lines.is_empty() {
+        return String::new();
+    }
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
+}
+
+/// Set top-level YAML keys, or remove them when the value is `None`.
+///
+/// An existing entry is replaced in place, keeping a trailing comment. A new key goes
+/// right after a commented-out example of it (as in Aider's sample config), or at the end.
+pub fn set_yaml_keys(content: &str, entries: &[(&str, Option<String>)]) -> String {
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+
+    for (key, value) in entries {
+        let existing = lines
+            .iter()
+            .position(|line| yaml_key_value(line, key).is_some());
+        match (existing, value) {
+            (Some(start), value) => {
+                let end = yaml_value_end(&lines, start);
+                let replacement = value.as_ref().map(|value| {
+                    let comment = yaml_key_value(&lines[start], key)
+                        .and_then(inline_comment)
+                        .map(|comment| format!(" {}", comment))
+                        .unwrap_or_default();
+                    format!("{}: {}{}", key, yaml_scalar(value), comment)
+                });
+                lines.drain(start..end);
+                if let Some(line) = replacement {
+                    lines.insert(start, line);
+                }
+            }
+            (None, Some(value)) => {
+                let line = format!("{}: {}", key, yaml_scalar(value));
+                let example = lines.iter().position(|line| {
+                    line.trim_start()
+                        .strip_prefix('#')
+                        .is_some_and(|rest| yaml_key_value(rest.trim_start(), key).is_some())
+                });
+                match example {
+                    Some(index) => lines.insert(index + 1, line),
+                    None => lines.push(line),
+                }
+            }
+            (None, None) => {}
+        }
+    }
+
+    join_lines(lines)
+}
+
+/// A `.env` value, quoted when it has characters dotenv would otherwise interpret
+fn env_value(value: &str) -> String {
+    let plain = !value.is_empty()
+        && !value
+            .chars()
+            .any(|c| c.is_whitespace() || "#\"'$\\`".contains(c));
+    if plain {
+        value.to_string()
+    } else {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// The `export ` prefix of `line` when it assigns `key`
+fn env_assignment_prefix(line: &str, key: &str) -> Option<&'static str> {
+    let trimmed = line.trim_start();
+    let body = trimmed.strip_prefix("export ").unwrap_or(trimmed);
+    body.strip_prefix(key)?.trim_start().strip_prefix('=')?;
+    Some(if body.len() < trimmed.len() {
+        "export "
+    } else {
+        ""
+    })
+}
+
+/// Set `.env` variables, or remove them when the value is `None`.
+///
+/// An existing assignment is replaced in place (keeping `export`); new ones are appended.
+pub fn set_env_vars(content: &str, entries: &[(&str, Option<String>)]) -> String {
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+
+    for (key, value) in entries {
+        let existing = lines
+            .iter()
+            .enumerate()
+            .find_map(|(index, line)| env_assignment_prefix(line, key).map(|p| (index, p)));
+        match (existing, value) {
+            (Some((index, prefix)), Some(value)) => {
+                lines[index] = format!("{}{}={}", prefix, key, env_value(value));
+            }
+            (Some((index, _)), None) => {
+                lines.remove(index);
+            }
+            (None, Some(value)) => lines.push(format!("{}={}", key, env_value(value))),
+            (None, None) => {}
+        }
+    }
+
+    join_lines(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn some(value: &str) -> Option<String> {
+        Some(value.to_string())
+    }
+
+    #[test]
+    fn replaces_yaml_keys_keeping_comments() {
+        let content = "# Aider settings\nmodel: gpt-4o # main model\n\nweak-model: gpt-4o-mini\nread:\n  - CONVENTIONS.md\ndark-mode: true\n";
+        let updated = set_yaml_keys(
+            content,
+            &[
+                ("model", some("anthropic/claude-sonnet-4-5")),
+                ("weak-model", None),
+                ("read", some("NOTES.md")),
+            ],
+        );
+        assert_eq!(
+            updated,
+            "# Aider settings\nmodel: anthropic/claude-sonnet-4-5 # main model\n\nread: NOTES.md\ndark-mode: true\n"
+        );
+    }
+
+    #[test]
+    fn inserts_yaml_keys_after_commented_examples() {
+        let content =
+            "## Specify the model to use for the main chat\n#model: xxx\n\n#editor-model: xxx\n";
+        let updated = set_yaml_keys(
+            content,
+            &[
+                ("model", some("openai/deepseek-chat")),
+                ("weak-model", some("yes")),
+            ],
+        );
+        assert_eq!(
+            updated,
+            "## Specify the model to use for the main chat\n#model: xxx\nmodel: openai/deepseek-chat\n\n#editor-model: xxx\nweak-model: \"yes\"\n"
+        );
+        // `model-settings-file` is not the `model` key
+        assert_eq!(
+            set_yaml_keys("model-settings-file: a.yml\n", &[("model", None)]),
+            "model-settings-file: a.yml\n"
+        );
+    }
+
+    #[test]
+    fn updates_env_vars_in_place() {
+        let content =
+            "# keys\nexport OPENAI_API_KEY=old\nOPENAI_API_BASE=https://old.example.com\nOTHER=1\n";
+        let updated = set_env_vars(
+            content,
+            &[
+                ("OPENAI_API_KEY", some("sk-new")),
+                ("OPENAI_API_BASE", None),
+                ("ANTHROPIC_API_KEY", some("a b#c")),
+            ],
+        );
+        assert_eq!(
+            updated,
+            "# keys\nexport OPENAI_API_KEY=sk-new\nOTHER=1\nANTHROPIC_API_KEY=\"a b#c\"\n"
+        );
+        assert_eq!(set_env_vars("", &[("OPENAI_API_BASE", None)]), "");
+    }
+}
@@ -0,0 +1,15 @@
+//! Aider Module
+//!
+//! Aider providers are stored in the `aider_provider` table. Applying one writes its
+//! models to `~/.aider.conf.yml` and its API key / base URL to `~/.env`, which Aider
+//! loads from the home directory. Both files are edited line by line, so comments and
+//! unrelated settings survive. The tray section is opt-in.
+
+pub mod adapter;
+pub mod commands;
+pub mod config_file;
+pub mod tray_support;
+pub mod types;
+
+pub use commands::*;
+pub use types::*;
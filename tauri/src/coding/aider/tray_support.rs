@@ -0,0 +1,67 @@
+//! Aider Tray Support Module
+//!
+//! Provider switching in the tray menu; the section is only shown when enabled in
+//! the Aider preferences.
+
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::commands::{apply_provider_internal, get_aider_preferences, list_aider_providers};
+use crate::db::DbState;
+
+/// Item for provider selection in tray menu
+#[derive(Debug, Clone)]
+pub struct TrayProviderItem {
+    /// Provider ID (used in event handling)
+    pub id: String,
+    /// Display name in menu
+    pub display_name: String,
+    /// Whether this provider is currently applied
+    pub is_selected: bool,
+}
+
+/// Data for provider submenu
+#[derive(Debug, Clone)]
+pub struct TrayProviderData {
+    /// Title of the section
+    pub title: String,
+    /// Items for selection
+    pub items: Vec<TrayProviderItem>,
+}
+
+/// Get tray provider data for Aider, in the manual order
+pub async fn get_aider_tray_data<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<TrayProviderData, String> {
+    let providers = list_aider_providers(app.state()).await?;
+
+    Ok(TrayProviderData {
+        title: "Aider".to_string(),
+        items: providers
+            .into_iter()
+            .map(|provider| TrayProviderItem {
+                id: provider.id,
+                display_name: provider.name,
+                is_selected: provider.is_applied,
+            })
+            .collect(),
+    })
+}
+
+/// Apply provider selection from tray menu
+pub async fn apply_aider_provider<R: Runtime>(
+    app: &AppHandle<R>,
+    provider_id: &str,
+) -> Result<(), String> {
+    let state = app.state::<DbState>();
+    let db = state.db();
+    apply_provider_internal(&db, app, provider_id, true).await
+}
+
+/// Check if Aider should be shown in tray menu (opt-in)
+pub async fn is_enabled_for_tray<R: Runtime>(app: &AppHandle<R>) -> bool {
+    let state = app.state::<DbState>();
+    get_aider_preferences(&state)
+        .await
+        .map(|prefs| prefs.show_in_tray)
+        .unwrap_or(false)
+}
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+use crate::db_timestamp::Timestamp;
+
+pub const DEFAULT_API_KEY_ENV: &str = "OPENAI_API_KEY";
+
+fn default_api_key_env() -> String {
+    DEFAULT_API_KEY_ENV.to_string()
+}
+
+/// What applying a provider writes to Aider's files
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiderProviderSettings {
+    /// Main model, e.g. `openai/gpt-4.1` or `anthropic/claude-sonnet-4-5`
+    pub model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weak_model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor_model: Option<String>,
+    /// Environment variable the key is written to, e.g. `ANTHROPIC_API_KEY`
+    #[serde(default = "default_api_key_env")]
+    pub api_key_env: String,
+    /// Left out of `.env` when empty, keeping whatever key is set there.
+    /// Encrypted at rest while the app lock is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// OpenAI compatible endpoint, written as `OPENAI_API_BASE`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_base: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiderProvider {
+    pub id: String,
+    pub name: String,
+    pub settings: AiderProviderSettings,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_index: Option<i32>,
+    pub is_applied: bool,
+    #[serde(default)]
+    pub created_at: Option<Timestamp>,
+    #[serde(default)]
+    pub updated_at: Option<Timestamp>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiderProviderContent {
+    pub name: String,
+    pub settings: AiderProviderSettings,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_index: Option<i32>,
+    pub is_applied: bool,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiderProviderInput {
+    #[serde(default)]
+    pub id: Option<String>,
+    pub name: String,
+    pub settings: AiderProviderSettings,
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiderPaths {
+    pub config_path: String,
+    pub env_path: String,
+    /// Whether `.aider.conf.yml` exists
+    pub config_exists: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AiderPreferences {
+    #[serde(default)]
+    pub show_in_tray: bool,
+}
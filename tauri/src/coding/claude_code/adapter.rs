@@ -2,8 +2,9 @@ use super::types::{
     ClaudeCodeProvider, ClaudeCodeProviderContent, ClaudeCommonConfig, ClaudePromptConfig,
     ClaudePromptConfigContent,
 };
+use crate::app_lock;
 use crate::coding::db_id::db_extract_id;
-use chrono::Local;
+use crate::db_timestamp::Timestamp;
 use serde_json::{json, Value};
 
 // ============================================================================
@@ -29,6 +30,11 @@ fn get_opt_str_compat(value: &Value, snake_key: &str, camel_key: &str) -> Option
         .map(String::from)
 }
 
+/// Helper function to get a timestamp with backward compatibility
+fn get_timestamp_compat(value: &Value, snake_key: &str, camel_key: &str) -> Option<Timestamp> {
+    Timestamp::from_db(value, snake_key).or_else(|| Timestamp::from_db(value, camel_key))
+}
+
 /// Helper function to get i64 with backward compatibility
 fn get_i64_compat(value: &Value, snake_key: &str, camel_key: &str) -> Option<i32> {
     value
@@ -57,7 +63,10 @@ pub fn from_db_value_provider(value: Value) -> ClaudeCodeProvider {
         id,
         name: get_str_compat(&value, "name", "name", "Unnamed Provider"),
         category: get_str_compat(&value, "category", "category", "other"),
-        settings_config: get_str_compat(&value, "settings_config", "settingsConfig", "{}"),
+        settings_config: app_lock::decrypt_json_secrets(
+            &get_str_compat(&value, "settings_config", "settingsConfig", "{}"),
+            &app_lock::CLAUDE_PROVIDER_SECRETS,
+        ),
         source_provider_id: get_opt_str_compat(&value, "source_provider_id", "sourceProviderId"),
         website_url: get_opt_str_compat(&value, "website_url", "websiteUrl"),
         notes: get_opt_str_compat(&value, "notes", "notes"),
@@ -66,17 +75,25 @@ pub fn from_db_value_provider(value: Value) -> ClaudeCodeProvider {
         sort_index: get_i64_compat(&value, "sort_index", "sortIndex"),
         is_applied: get_bool_compat(&value, "is_applied", "isApplied", false),
         is_disabled: get_bool_compat(&value, "is_disabled", "isDisabled", false),
-        created_at: get_str_compat(&value, "created_at", "createdAt", ""),
-        updated_at: get_str_compat(&value, "updated_at", "updatedAt", ""),
+        created_at: get_timestamp_compat(&value, "created_at", "createdAt"),
+        updated_at: get_timestamp_compat(&value, "updated_at", "updatedAt"),
     }
 }
 
 /// Convert ClaudeCodeProviderContent to database Value
-pub fn to_db_value_provider(content: &ClaudeCodeProviderContent) -> Value {
-    serde_json::to_value(content).unwrap_or_else(|e| {
+/// API keys are encrypted when the app lock is enabled
+pub fn to_db_value_provider(content: &ClaudeCodeProviderContent) -> Result<Value, String> {
+    let mut value = serde_json::to_value(content).unwrap_or_else(|e| {
         eprintln!("Failed to serialize provider content: {}", e);
         json!({})
-    })
+    });
+    if value.is_object() {
+        value["settings_config"] = json!(app_lock::encrypt_json_secrets(
+            &content.settings_config,
+            &app_lock::CLAUDE_PROVIDER_SECRETS,
+        )?);
+    }
+    Ok(value)
 }
 
 // ============================================================================
@@ -97,24 +114,16 @@ pub fn from_db_value_common(value: Value) -> ClaudeCommonConfig {
             .or_else(|| value.get("rootDir"))
             .and_then(|v| v.as_str())
             .map(|v| v.to_string()),
-        updated_at: value
-            .get("updated_at")
-            .or_else(|| value.get("updatedAt"))
-            .and_then(|v| v.as_str())
-            .unwrap_or_else(|| {
-                let now = Local::now().to_rfc3339();
-                Box::leak(now.into_boxed_str())
-            })
-            .to_string(),
+        updated_at: get_timestamp_compat(&value, "updated_at", "updatedAt")
+            .unwrap_or_else(Timestamp::now),
     }
 }
 
 /// Convert common config to database Value
 pub fn to_db_value_common(config: &str, root_dir: Option<&str>) -> Value {
-    let now = Local::now().to_rfc3339();
     let mut value = json!({
         "config": config,
-        "updated_at": now
+        "updated_at": Timestamp::now()
     });
 
     if let Some(root_dir) = root_dir.filter(|dir| !dir.trim().is_empty()) {
@@ -135,8 +144,8 @@ pub fn from_db_value_prompt(value: Value) -> ClaudePromptConfig {
         content: get_str_compat(&value, "content", "content", ""),
         is_applied: get_bool_compat(&value, "is_applied", "isApplied", false),
         sort_index: get_i64_compat(&value, "sort_index", "sortIndex"),
-        created_at: get_opt_str_compat(&value, "created_at", "createdAt"),
-        updated_at: get_opt_str_compat(&value, "updated_at", "updatedAt"),
+        created_at: get_timestamp_compat(&value, "created_at", "createdAt"),
+        updated_at: get_timestamp_compat(&value, "updated_at", "updatedAt"),
     }
 }
 
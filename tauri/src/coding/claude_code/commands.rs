@@ -1,4 +1,3 @@
-use chrono::Local;
 use serde_json::Value;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -12,13 +11,16 @@ use super::plugin_types::{
 };
 use super::settings_merge;
 use super::types::*;
+use crate::app_lock;
 use crate::coding::all_api_hub;
-use crate::coding::db_id::{db_new_id, db_record_id};
+use crate::coding::db_id::{db_mark_applied_query, db_new_id, db_record_id, db_reorder_query};
 use crate::coding::open_code::shell_env;
 use crate::coding::prompt_file::{read_prompt_content_file, write_prompt_content_file};
 use crate::coding::runtime_location;
 use crate::coding::skills::commands::resync_all_skills_if_tool_path_changed;
 use crate::db::DbState;
+use crate::db_timestamp::{with_datetimes, Timestamp};
+use crate::secrets;
 use tauri::Emitter;
 
 const KNOWN_ENV_FIELDS: [&str; 8] = [
@@ -247,7 +249,7 @@ async fn load_temp_provider_from_file_with_db(
 
     let inferred_category = infer_claude_provider_category_from_settings(&provider_settings);
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     Ok(ClaudeCodeProvider {
         id: "__local__".to_string(),
         name: "default".to_string(),
@@ -262,8 +264,8 @@ async fn load_temp_provider_from_file_with_db(
         sort_index: Some(0),
         is_applied: true,
         is_disabled: false,
-        created_at: now.clone(),
-        updated_at: now,
+        created_at: Some(now),
+        updated_at: Some(now),
     })
 }
 
@@ -310,7 +312,7 @@ async fn load_temp_common_config_from_file_with_db(
         &settings_value,
         &KNOWN_ENV_FIELDS,
     )?;
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     Ok(ClaudeCommonConfig {
         config: serde_json::to_string(&common_settings)
             .map_err(|error| format!("Failed to serialize common config: {}", error))?,
@@ -391,14 +393,14 @@ async fn get_local_prompt_config(
         return Ok(None);
     };
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     Ok(Some(ClaudePromptConfig {
         id: "__local__".to_string(),
         name: "default".to_string(),
         content: prompt_content,
         is_applied: true,
         sort_index: None,
-        created_at: Some(now.clone()),
+        created_at: Some(now),
         updated_at: Some(now),
     }))
 }
@@ -480,7 +482,7 @@ pub async fn create_claude_provider(
     let normalized_settings_config =
         normalize_provider_settings_for_storage(&db, &provider.settings_config, None).await?;
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let content = ClaudeCodeProviderContent {
         name: provider.name,
         category: provider.category,
@@ -493,15 +495,15 @@ pub async fn create_claude_provider(
         sort_index: provider.sort_index,
         is_applied: false,
         is_disabled: false,
-        created_at: now.clone(),
+        created_at: now,
         updated_at: now,
     };
 
-    let json_data = adapter::to_db_value_provider(&content);
+    let json_data = adapter::to_db_value_provider(&content)?;
 
     // Create new provider - SurrealDB auto-generates record ID
     db.query("CREATE claude_provider CONTENT $data")
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to create provider: {}", e))?;
 
@@ -540,7 +542,7 @@ pub async fn update_claude_provider(
 
     // Use the id from frontend (pure string id without table prefix)
     let id = provider.id.clone();
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
     // Get existing record to preserve created_at
     let record_id = db_record_id("claude_provider", &id);
@@ -558,15 +560,11 @@ pub async fn update_claude_provider(
     }
 
     // Get created_at and is_disabled from existing record
-    let (created_at, existing_is_disabled) = if !provider.created_at.is_empty() {
-        (provider.created_at, false)
+    let (created_at, existing_is_disabled) = if let Some(created_at) = provider.created_at {
+        (created_at, false)
     } else if let Ok(records) = &existing_result {
         if let Some(record) = records.first() {
-            let created = record
-                .get("created_at")
-                .and_then(|v| v.as_str())
-                .unwrap_or(&now)
-                .to_string();
+            let created = Timestamp::from_db(record, "created_at").unwrap_or(now);
             let is_disabled = record
                 .get("is_disabled")
                 .or_else(|| record.get("isDisabled"))
@@ -574,10 +572,10 @@ pub async fn update_claude_provider(
                 .unwrap_or(false);
             (created, is_disabled)
         } else {
-            (now.clone(), false)
+            (now, false)
         }
     } else {
-        (now.clone(), false)
+        (now, false)
     };
 
     let content = ClaudeCodeProviderContent {
@@ -596,11 +594,11 @@ pub async fn update_claude_provider(
         updated_at: now,
     };
 
-    let json_data = adapter::to_db_value_provider(&content);
+    let json_data = adapter::to_db_value_provider(&content)?;
 
     // Use database id for update
     db.query(format!("UPDATE claude_provider:`{}` CONTENT $data", id))
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to update provider: {}", e))?;
 
@@ -628,8 +626,8 @@ pub async fn update_claude_provider(
         sort_index: content.sort_index,
         is_applied: content.is_applied,
         is_disabled: content.is_disabled,
-        created_at: content.created_at,
-        updated_at: content.updated_at,
+        created_at: Some(content.created_at),
+        updated_at: Some(content.updated_at),
     })
 }
 
@@ -659,19 +657,13 @@ pub async fn reorder_claude_providers(
     ids: Vec<String>,
 ) -> Result<(), String> {
     let db = state.db();
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
-    for (index, id) in ids.iter().enumerate() {
-        let record_id = db_record_id("claude_provider", id);
-        db.query(&format!(
-            "UPDATE {} SET sort_index = $index, updated_at = $now",
-            record_id
-        ))
-        .bind(("index", index as i32))
-        .bind(("now", now.clone()))
+    db.query(db_reorder_query("claude_provider", &ids, true))
+        .bind(("now", now.to_db()))
         .await
-        .map_err(|e| format!("Failed to update provider {}: {}", id, e))?;
-    }
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to reorder providers: {}", e))?;
 
     Ok(())
 }
@@ -686,25 +678,13 @@ pub async fn select_claude_provider(
 ) -> Result<(), String> {
     let db = state.db();
 
-    let now = Local::now().to_rfc3339();
-
-    // Mark all providers as not applied (only update the currently applied one)
-    db.query(
-        "UPDATE claude_provider SET is_applied = false, updated_at = $now WHERE is_applied = true",
-    )
-    .bind(("now", now.clone()))
-    .await
-    .map_err(|e| format!("Failed to reset applied status: {}", e))?;
+    let now = Timestamp::now();
 
-    // Mark target provider as applied
-    let record_id = db_record_id("claude_provider", &id);
-    db.query(&format!(
-        "UPDATE {} SET is_applied = true, updated_at = $now",
-        record_id
-    ))
-    .bind(("now", now))
-    .await
-    .map_err(|e| format!("Failed to set applied status: {}", e))?;
+    db.query(db_mark_applied_query("claude_provider", &id))
+        .bind(("now", now.to_db()))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to set applied status: {}", e))?;
 
     // Notify frontend to refresh
     let _ = app.emit("config-changed", "window");
@@ -842,6 +822,8 @@ async fn apply_config_to_file_with_previous_common_config(
         ));
     }
 
+    app_lock::ensure_decrypted(&provider.settings_config)?;
+
     // Parse provider settings_config
     let provider_config: serde_json::Value = serde_json::from_str(&provider.settings_config)
         .map_err(|e| format!("Failed to parse provider config: {}", e))?;
@@ -866,6 +848,12 @@ async fn apply_config_to_file_with_previous_common_config(
         Err(_) => serde_json::json!({}),
     };
 
+    // Vault secret references are substituted only in the written settings.json
+    let provider_config = secrets::resolve_value(&provider_config)?;
+    let common_config = secrets::resolve_value(&common_config)?;
+    let previous_common_config =
+        previous_common_config.map(|config| secrets::resolve_value(&config).unwrap_or(config));
+
     let current_settings = read_current_claude_settings_value_async(db).await?;
     let merged_settings = settings_merge::merge_claude_settings_for_provider(
         current_settings.as_ref(),
@@ -895,13 +883,13 @@ pub async fn toggle_claude_code_provider_disabled(
     let db = state.db();
 
     // Update is_disabled field in database
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     db.query(format!(
         "UPDATE claude_provider:`{}` SET is_disabled = $is_disabled, updated_at = $now",
         provider_id
     ))
     .bind(("is_disabled", is_disabled))
-    .bind(("now", now))
+    .bind(("now", now.to_db()))
     .await
     .map_err(|e| format!("Failed to toggle provider disabled status: {}", e))?;
 
@@ -956,25 +944,13 @@ pub async fn apply_config_internal<R: tauri::Runtime>(
     apply_config_to_file(db, provider_id).await?;
 
     // Update provider's is_applied status
-    let now = Local::now().to_rfc3339();
-
-    // Mark all providers as not applied (only update the currently applied one)
-    db.query(
-        "UPDATE claude_provider SET is_applied = false, updated_at = $now WHERE is_applied = true",
-    )
-    .bind(("now", now.clone()))
-    .await
-    .map_err(|e| format!("Failed to reset applied status: {}", e))?;
+    let now = Timestamp::now();
 
-    // Mark target provider as applied
-    let apply_record_id = db_record_id("claude_provider", provider_id);
-    db.query(&format!(
-        "UPDATE {} SET is_applied = true, updated_at = $now",
-        apply_record_id
-    ))
-    .bind(("now", now))
-    .await
-    .map_err(|e| format!("Failed to set applied status: {}", e))?;
+    db.query(db_mark_applied_query("claude_provider", provider_id))
+        .bind(("now", now.to_db()))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to set applied status: {}", e))?;
 
     // Notify based on source
     let payload = if from_tray { "tray" } else { "window" };
@@ -1043,7 +1019,7 @@ pub async fn create_claude_prompt_config(
     input: ClaudePromptConfigInput,
 ) -> Result<ClaudePromptConfig, String> {
     let db = state.db();
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
     let sort_index_result: Result<Vec<Value>, _> = db
         .query("SELECT sort_index FROM claude_prompt_config ORDER BY sort_index DESC LIMIT 1")
@@ -1063,7 +1039,7 @@ pub async fn create_claude_prompt_config(
         content: input.content,
         is_applied: false,
         sort_index: Some(next_sort_index),
-        created_at: now.clone(),
+        created_at: now,
         updated_at: now,
     };
 
@@ -1072,7 +1048,7 @@ pub async fn create_claude_prompt_config(
     let record_id = db_record_id("claude_prompt_config", &prompt_id);
 
     db.query(&format!("CREATE {} CONTENT $data", record_id))
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to create prompt config: {}", e))?;
 
@@ -1129,11 +1105,8 @@ pub async fn update_claude_prompt_config(
     let (created_at, is_applied, sort_index) = match existing_result {
         Ok(records) => {
             if let Some(record) = records.first() {
-                let created_at = record
-                    .get("created_at")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or_else(|| Box::leak(Local::now().to_rfc3339().into_boxed_str()))
-                    .to_string();
+                let created_at =
+                    Timestamp::from_db(record, "created_at").unwrap_or_else(Timestamp::now);
                 let is_applied = record
                     .get("is_applied")
                     .or_else(|| record.get("isApplied"))
@@ -1152,19 +1125,19 @@ pub async fn update_claude_prompt_config(
         Err(e) => return Err(format!("Failed to deserialize prompt config: {}", e)),
     };
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let content = ClaudePromptConfigContent {
         name: input.name,
         content: input.content.clone(),
         is_applied,
         sort_index,
         created_at,
-        updated_at: now.clone(),
+        updated_at: now,
     };
     let json_data = adapter::to_db_value_prompt(&content);
 
     db.query(&format!("UPDATE {} CONTENT $data", record_id))
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to update prompt config: {}", e))?;
 
@@ -1246,20 +1219,13 @@ pub async fn apply_prompt_config_internal<R: tauri::Runtime>(
         Err(e) => return Err(format!("Failed to deserialize prompt config: {}", e)),
     };
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
-    db.query("UPDATE claude_prompt_config SET is_applied = false, updated_at = $now WHERE is_applied = true")
-        .bind(("now", now.clone()))
+    db.query(db_mark_applied_query("claude_prompt_config", config_id))
+        .bind(("now", now.to_db()))
         .await
-        .map_err(|e| format!("Failed to clear prompt applied flags: {}", e))?;
-
-    db.query(&format!(
-        "UPDATE {} SET is_applied = true, updated_at = $now",
-        record_id
-    ))
-    .bind(("now", now))
-    .await
-    .map_err(|e| format!("Failed to set prompt applied flag: {}", e))?;
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to set prompt applied flag: {}", e))?;
 
     drop(db);
 
@@ -1290,13 +1256,10 @@ pub async fn reorder_claude_prompt_configs(
 ) -> Result<(), String> {
     let db = state.db();
 
-    for (index, id) in ids.iter().enumerate() {
-        let record_id = db_record_id("claude_prompt_config", id);
-        db.query(&format!("UPDATE {} SET sort_index = $index", record_id))
-            .bind(("index", index as i32))
-            .await
-            .map_err(|e| format!("Failed to update prompt sort index: {}", e))?;
-    }
+    db.query(db_reorder_query("claude_prompt_config", &ids, false))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to update prompt sort index: {}", e))?;
 
     drop(db);
     let _ = app.emit("config-changed", "window");
@@ -1391,13 +1354,7 @@ pub async fn get_claude_common_config(
             if let Ok(temp_common) = load_temp_common_config_from_file_with_db(&db).await {
                 Ok(Some(temp_common))
             } else {
-                // 反序列化失败，删除旧数据以修复版本冲突
-                eprintln!(
-                    "⚠️ Claude common config has incompatible format, cleaning up: {}",
-                    e
-                );
-                let _ = db.query("DELETE claude_common_config:`common`").await;
-                Ok(None)
+                Err(format!("Failed to parse common config: {}", e))
             }
         }
     }
@@ -1448,7 +1405,7 @@ pub async fn save_claude_common_config(
 
     // Use UPSERT to handle both update and create
     db.query("UPSERT claude_common_config:`common` CONTENT $data")
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to save common config: {}", e))?;
 
@@ -1552,7 +1509,7 @@ pub async fn save_claude_local_config(
         .transpose()?;
     let next_common_config_value = parse_optional_common_config_value(Some(&common_config))?;
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let normalized_provider_settings_config =
         normalize_provider_settings_for_storage(
             &db,
@@ -1572,13 +1529,13 @@ pub async fn save_claude_local_config(
         sort_index: provider_sort_index,
         is_applied: true,
         is_disabled: false,
-        created_at: now.clone(),
+        created_at: now,
         updated_at: now,
     };
 
-    let provider_json = adapter::to_db_value_provider(&provider_content);
+    let provider_json = adapter::to_db_value_provider(&provider_content)?;
     db.query("CREATE claude_provider CONTENT $data")
-        .bind(("data", provider_json))
+        .bind(("data", with_datetimes(provider_json)))
         .await
         .map_err(|e| format!("Failed to create provider: {}", e))?;
 
@@ -1601,7 +1558,7 @@ pub async fn save_claude_local_config(
     };
     let common_json = adapter::to_db_value_common(&common_config, root_dir.as_deref());
     db.query("UPSERT claude_common_config:`common` CONTENT $data")
-        .bind(("data", common_json))
+        .bind(("data", with_datetimes(common_json)))
         .await
         .map_err(|e| format!("Failed to save common config: {}", e))?;
 
@@ -2026,13 +1983,13 @@ pub async fn init_claude_provider_from_settings(
 
         // Use UPSERT to create if not exists, update if exists
         db.query("UPSERT claude_common_config:`common` CONTENT $data")
-            .bind(("data", common_db_data))
+            .bind(("data", with_datetimes(common_db_data)))
             .await
             .map_err(|e| format!("Failed to save common config: {}", e))?;
     }
 
     // Create default provider
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let provider_name = "默认配置";
 
     let content = ClaudeCodeProviderContent {
@@ -2048,15 +2005,15 @@ pub async fn init_claude_provider_from_settings(
         sort_index: Some(0),
         is_applied: true,
         is_disabled: false,
-        created_at: now.clone(),
+        created_at: now,
         updated_at: now,
     };
 
-    let json_data = adapter::to_db_value_provider(&content);
+    let json_data = adapter::to_db_value_provider(&content)?;
 
     // Create new provider with auto-generated random ID
     db.query("CREATE claude_provider CONTENT $data")
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to create default provider: {}", e))?;
 
@@ -3,7 +3,8 @@ use std::path::PathBuf;
 use crate::coding::runtime_location::{RuntimeLocationInfo, RuntimeLocationMode};
 use tokio::process::Command;
 
-fn resolve_claude_binary_path() -> PathBuf {
+/// Resolve the claude binary, preferring well-known install locations over PATH lookup
+pub(crate) fn resolve_claude_binary_path() -> PathBuf {
     let mut candidate_paths = Vec::new();
 
     if let Some(home_dir) = dirs::home_dir() {
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use surrealdb::sql::Thing;
 
+use crate::db_timestamp::Timestamp;
+
 // ============================================================================
 // ClaudeCode Provider Types
 // ============================================================================
@@ -26,8 +28,10 @@ pub struct ClaudeCodeProviderRecord {
     pub sort_index: Option<i32>,
     pub is_applied: bool,
     pub is_disabled: bool,
-    pub created_at: String,
-    pub updated_at: String,
+    #[serde(default)]
+    pub created_at: Option<Timestamp>,
+    #[serde(default)]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// ClaudeCodeProvider - API response
@@ -52,8 +56,10 @@ pub struct ClaudeCodeProvider {
     pub sort_index: Option<i32>,
     pub is_applied: bool,
     pub is_disabled: bool,
-    pub created_at: String,
-    pub updated_at: String,
+    #[serde(default, deserialize_with = "crate::db_timestamp::lenient")]
+    pub created_at: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::db_timestamp::lenient")]
+    pub updated_at: Option<Timestamp>,
 }
 
 impl From<ClaudeCodeProviderRecord> for ClaudeCodeProvider {
@@ -97,8 +103,8 @@ pub struct ClaudeCodeProviderContent {
     pub sort_index: Option<i32>,
     pub is_applied: bool,
     pub is_disabled: bool,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
 }
 
 /// ClaudeCodeProvider - Input from frontend (for create operation)
@@ -136,7 +142,7 @@ pub struct ClaudeCommonConfigRecord {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub root_dir: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
 }
 
 /// ClaudeCommonConfig - API response
@@ -146,7 +152,7 @@ pub struct ClaudeCommonConfig {
     pub config: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub root_dir: Option<String>,
-    pub updated_at: String,
+    pub updated_at: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -224,10 +230,18 @@ pub struct ClaudePromptConfig {
     pub is_applied: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_index: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::db_timestamp::lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub created_at: Option<Timestamp>,
+    #[serde(
+        default,
+        deserialize_with = "crate::db_timestamp::lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub updated_at: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -237,8 +251,8 @@ pub struct ClaudePromptConfigContent {
     pub is_applied: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_index: Option<i32>,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
 }
 
 // ============================================================================
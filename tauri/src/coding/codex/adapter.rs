@@ -1,4 +1,3 @@
-use chrono::Local;
 use serde_json::Value;
 
 use super::commands::infer_codex_provider_category_from_settings;
@@ -6,7 +5,9 @@ use super::types::{
     CodexCommonConfig, CodexPromptConfig, CodexPromptConfigContent, CodexProvider,
     CodexProviderContent,
 };
+use crate::app_lock;
 use crate::coding::db_id::db_extract_id;
+use crate::db_timestamp::Timestamp;
 
 // ============================================================================
 // Provider Adapter Functions
@@ -16,11 +17,13 @@ use crate::coding::db_id::db_extract_id;
 pub fn from_db_value_provider(value: Value) -> CodexProvider {
     // Use common utility to extract and clean the record ID
     let id = db_extract_id(&value);
-    let settings_config = value
-        .get("settings_config")
-        .and_then(|v| v.as_str())
-        .unwrap_or("{}")
-        .to_string();
+    let settings_config = app_lock::decrypt_json_secrets(
+        value
+            .get("settings_config")
+            .and_then(|v| v.as_str())
+            .unwrap_or("{}"),
+        &app_lock::CODEX_PROVIDER_SECRETS,
+    );
     let inferred_category = serde_json::from_str::<Value>(&settings_config)
         .map(|parsed| infer_codex_provider_category_from_settings(&parsed))
         .unwrap_or_else(|_| "custom".to_string());
@@ -80,21 +83,14 @@ pub fn from_db_value_provider(value: Value) -> CodexProvider {
             .or_else(|| value.get("isDisabled"))
             .and_then(|v| v.as_bool())
             .unwrap_or(false),
-        created_at: value
-            .get("created_at")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string(),
-        updated_at: value
-            .get("updated_at")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string(),
+        created_at: Timestamp::from_db(&value, "created_at"),
+        updated_at: Timestamp::from_db(&value, "updated_at"),
     }
 }
 
 /// Convert CodexProviderContent to database value
-pub fn to_db_value_provider(content: &CodexProviderContent) -> Value {
+/// API keys are encrypted when the app lock is enabled
+pub fn to_db_value_provider(content: &CodexProviderContent) -> Result<Value, String> {
     let mut map = serde_json::Map::new();
     map.insert("name".to_string(), Value::String(content.name.clone()));
     map.insert(
@@ -103,7 +99,10 @@ pub fn to_db_value_provider(content: &CodexProviderContent) -> Value {
     );
     map.insert(
         "settings_config".to_string(),
-        Value::String(content.settings_config.clone()),
+        Value::String(app_lock::encrypt_json_secrets(
+            &content.settings_config,
+            &app_lock::CODEX_PROVIDER_SECRETS,
+        )?),
     );
 
     if let Some(ref source_id) = content.source_provider_id {
@@ -132,14 +131,14 @@ pub fn to_db_value_provider(content: &CodexProviderContent) -> Value {
     map.insert("is_disabled".to_string(), Value::Bool(content.is_disabled));
     map.insert(
         "created_at".to_string(),
-        Value::String(content.created_at.clone()),
+        Value::String(content.created_at.to_string()),
     );
     map.insert(
         "updated_at".to_string(),
-        Value::String(content.updated_at.clone()),
+        Value::String(content.updated_at.to_string()),
     );
 
-    Value::Object(map)
+    Ok(Value::Object(map))
 }
 
 // ============================================================================
@@ -148,11 +147,6 @@ pub fn to_db_value_provider(content: &CodexProviderContent) -> Value {
 
 /// Convert database value to CodexCommonConfig
 pub fn from_db_value_common(value: Value) -> CodexCommonConfig {
-    let updated_at_value = value
-        .get("updated_at")
-        .and_then(|v| v.as_str())
-        .map(|s| s.to_string());
-
     CodexCommonConfig {
         config: value
             .get("config")
@@ -164,7 +158,7 @@ pub fn from_db_value_common(value: Value) -> CodexCommonConfig {
             .or_else(|| value.get("rootDir"))
             .and_then(|v| v.as_str())
             .map(|v| v.to_string()),
-        updated_at: updated_at_value.unwrap_or_else(|| Local::now().to_rfc3339()),
+        updated_at: Timestamp::from_db(&value, "updated_at").unwrap_or_else(Timestamp::now),
     }
 }
 
@@ -177,7 +171,7 @@ pub fn to_db_value_common(config: &str, root_dir: Option<&str>) -> Value {
     }
     map.insert(
         "updated_at".to_string(),
-        Value::String(Local::now().to_rfc3339()),
+        Value::String(Timestamp::now().to_string()),
     );
     Value::Object(map)
 }
@@ -211,16 +205,10 @@ pub fn from_db_value_prompt(value: Value) -> CodexPromptConfig {
             .or_else(|| value.get("sortIndex"))
             .and_then(|v| v.as_i64())
             .map(|n| n as i32),
-        created_at: value
-            .get("created_at")
-            .or_else(|| value.get("createdAt"))
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
-        updated_at: value
-            .get("updated_at")
-            .or_else(|| value.get("updatedAt"))
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string()),
+        created_at: Timestamp::from_db(&value, "created_at")
+            .or_else(|| Timestamp::from_db(&value, "createdAt")),
+        updated_at: Timestamp::from_db(&value, "updated_at")
+            .or_else(|| Timestamp::from_db(&value, "updatedAt")),
     }
 }
 
@@ -0,0 +1,296 @@
+//! Codex auth.json inspection and logout
+//!
+//! auth.json is owned by the Codex runtime (`codex login`). AI Toolbox only manages
+//! `OPENAI_API_KEY` in it, so this module reads the OAuth state without touching it,
+//! except for the explicit `codex_logout` command.
+
+use base64::Engine;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use tauri::Emitter;
+
+use super::commands::{get_codex_auth_path_from_db_async, get_codex_config_path_from_db_async};
+use super::types::CodexAuthStatus;
+use crate::db::DbState;
+
+/// Runtime-owned OAuth fields removed on logout
+const OAUTH_AUTH_FIELDS: [&str; 3] = ["tokens", "last_refresh", "auth_mode"];
+
+/// Decode the payload section of a JWT without verifying the signature
+fn decode_jwt_payload(token: &str) -> Option<Value> {
+    let payload = token.split('.').nth(1)?;
+    let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+fn non_empty_str(value: Option<&Value>) -> Option<String> {
+    value
+        .and_then(|v| v.as_str())
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+}
+
+fn read_json_file(path: &Path) -> Result<Option<Value>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read auth.json: {}", e))?;
+    if content.trim().is_empty() {
+        return Ok(None);
+    }
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse auth.json: {}", e))
+}
+
+/// Read `model_provider`, `preferred_auth_method` and whether the active provider
+/// goes through OpenAI auth from config.toml
+fn read_config_auth_settings(path: &Path) -> (Option<String>, Option<String>, bool) {
+    let Some(config) = fs::read_to_string(path)
+        .ok()
+        .and_then(|content| content.parse::<toml::Table>().ok())
+    else {
+        return (None, None, true);
+    };
+
+    let model_provider = config
+        .get("model_provider")
+        .and_then(|v| v.as_str())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty());
+    let preferred_auth_method = config
+        .get("preferred_auth_method")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+
+    let uses_openai_auth = match model_provider.as_deref() {
+        None | Some("openai") => true,
+        Some(provider_id) => config
+            .get("model_providers")
+            .and_then(|providers| providers.get(provider_id))
+            .and_then(|provider| provider.get("requires_openai_auth"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+    };
+
+    (model_provider, preferred_auth_method, uses_openai_auth)
+}
+
+fn build_auth_status(
+    auth_path: &Path,
+    auth: Option<&Value>,
+    model_provider: Option<String>,
+    preferred_auth_method: Option<String>,
+    uses_openai_auth: bool,
+    now_ts: i64,
+) -> CodexAuthStatus {
+    let has_api_key = non_empty_str(auth.and_then(|a| a.get("OPENAI_API_KEY"))).is_some();
+    let tokens = auth.and_then(|a| a.get("tokens")).filter(|t| t.is_object());
+    let has_chatgpt_tokens = tokens
+        .and_then(|t| {
+            non_empty_str(t.get("access_token")).or_else(|| non_empty_str(t.get("refresh_token")))
+        })
+        .is_some();
+
+    let id_claims = tokens
+        .and_then(|t| t.get("id_token"))
+        .and_then(|v| v.as_str())
+        .and_then(decode_jwt_payload);
+    let openai_claims = id_claims
+        .as_ref()
+        .and_then(|claims| claims.get("https://api.openai.com/auth"));
+
+    let email = non_empty_str(id_claims.as_ref().and_then(|c| c.get("email")));
+    let plan_type = non_empty_str(openai_claims.and_then(|c| c.get("chatgpt_plan_type")));
+    let account_id = non_empty_str(tokens.and_then(|t| t.get("account_id")))
+        .or_else(|| non_empty_str(openai_claims.and_then(|c| c.get("chatgpt_account_id"))));
+
+    let expires_ts = tokens
+        .and_then(|t| t.get("access_token"))
+        .and_then(|v| v.as_str())
+        .and_then(decode_jwt_payload)
+        .and_then(|claims| claims.get("exp").and_then(|v| v.as_i64()));
+    let token_expires_at = expires_ts
+        .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+        .map(|dt| dt.to_rfc3339());
+    let token_expired = expires_ts.is_some_and(|ts| ts <= now_ts);
+
+    let auth_mode = non_empty_str(auth.and_then(|a| a.get("auth_mode"))).or_else(|| {
+        if has_chatgpt_tokens {
+            Some("chatgpt".to_string())
+        } else if has_api_key {
+            Some("apikey".to_string())
+        } else {
+            None
+        }
+    });
+
+    let effective_auth_mode = if !uses_openai_auth {
+        "provider"
+    } else if has_api_key
+        && (preferred_auth_method.as_deref() == Some("apikey")
+            || auth_mode.as_deref() == Some("apikey"))
+    {
+        "apikey"
+    } else if has_chatgpt_tokens {
+        "chatgpt"
+    } else if has_api_key {
+        "apikey"
+    } else {
+        "none"
+    };
+
+    CodexAuthStatus {
+        auth_path: auth_path.to_string_lossy().to_string(),
+        auth_file_exists: auth_path.exists(),
+        auth_mode,
+        has_api_key,
+        has_chatgpt_tokens,
+        email,
+        plan_type,
+        account_id,
+        token_expires_at,
+        token_expired,
+        last_refresh: non_empty_str(auth.and_then(|a| a.get("last_refresh"))),
+        model_provider,
+        preferred_auth_method,
+        effective_auth_mode: effective_auth_mode.to_string(),
+    }
+}
+
+/// Get Codex auth status (ChatGPT OAuth vs API key, token expiry, effective mode)
+#[tauri::command]
+pub async fn get_codex_auth_status(
+    state: tauri::State<'_, DbState>,
+) -> Result<CodexAuthStatus, String> {
+    let db = state.db();
+    let auth_path = get_codex_auth_path_from_db_async(&db).await?;
+    let config_path = get_codex_config_path_from_db_async(&db).await?;
+
+    let auth = read_json_file(&auth_path)?;
+    let (model_provider, preferred_auth_method, uses_openai_auth) =
+        read_config_auth_settings(&config_path);
+
+    Ok(build_auth_status(
+        &auth_path,
+        auth.as_ref(),
+        model_provider,
+        preferred_auth_method,
+        uses_openai_auth,
+        chrono::Utc::now().timestamp(),
+    ))
+}
+
+/// Log out of the ChatGPT account used by Codex
+///
+/// Removes the OAuth login state from auth.json but keeps the AI Toolbox managed
+/// `OPENAI_API_KEY`; the file is deleted when nothing else is left in it.
+#[tauri::command]
+pub async fn codex_logout(
+    state: tauri::State<'_, DbState>,
+    app: tauri::AppHandle,
+) -> Result<CodexAuthStatus, String> {
+    let db = state.db();
+    let auth_path = get_codex_auth_path_from_db_async(&db).await?;
+
+    if let Some(auth) = read_json_file(&auth_path)? {
+        let mut auth_map = auth.as_object().cloned().unwrap_or_default();
+        for field in OAUTH_AUTH_FIELDS {
+            auth_map.remove(field);
+        }
+
+        let has_remaining_data = auth_map.values().any(|v| !v.is_null());
+        if has_remaining_data {
+            let content = serde_json::to_string_pretty(&Value::Object(auth_map))
+                .map_err(|e| format!("Failed to serialize auth.json: {}", e))?;
+            fs::write(&auth_path, format!("{}\n", content))
+                .map_err(|e| format!("Failed to write auth.json: {}", e))?;
+        } else {
+            fs::remove_file(&auth_path)
+                .map_err(|e| format!("Failed to remove auth.json: {}", e))?;
+        }
+
+        log::info!("Cleared Codex OAuth login state in {:?}", auth_path);
+
+        let _ = app.emit("config-changed", "window");
+
+        #[cfg(target_os = "windows")]
+        let _ = app.emit("wsl-sync-request-codex", ());
+    }
+
+    get_codex_auth_status(state).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn fake_jwt(payload: Value) -> String {
+        let encoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .encode(serde_json::to_vec(&payload).unwrap());
+        format!("eyJhbGciOiJub25lIn0.{}.sig", encoded)
+    }
+
+    #[test]
+    fn chatgpt_login_reports_claims_and_expiry() {
+        let auth = json!({
+            "OPENAI_API_KEY": null,
+            "tokens": {
+                "id_token": fake_jwt(json!({
+                    "email": "dev@example.com",
+                    "https://api.openai.com/auth": {
+                        "chatgpt_plan_type": "plus",
+                        "chatgpt_account_id": "acc-1"
+                    }
+                })),
+                "access_token": fake_jwt(json!({ "exp": 1_000 })),
+                "refresh_token": "rt"
+            },
+            "last_refresh": "2025-01-01T00:00:00Z"
+        });
+
+        let status = build_auth_status(
+            Path::new("/tmp/auth.json"),
+            Some(&auth),
+            None,
+            None,
+            true,
+            2_000,
+        );
+
+        assert_eq!(status.auth_mode.as_deref(), Some("chatgpt"));
+        assert_eq!(status.effective_auth_mode, "chatgpt");
+        assert_eq!(status.email.as_deref(), Some("dev@example.com"));
+        assert_eq!(status.plan_type.as_deref(), Some("plus"));
+        assert_eq!(status.account_id.as_deref(), Some("acc-1"));
+        assert!(status.token_expired);
+        assert!(!status.has_api_key);
+    }
+
+    #[test]
+    fn effective_mode_follows_preference_and_provider() {
+        let auth = json!({
+            "OPENAI_API_KEY": "sk-test",
+            "tokens": { "access_token": "at", "refresh_token": "rt" }
+        });
+        let path = Path::new("/tmp/auth.json");
+
+        let status = build_auth_status(path, Some(&auth), None, None, true, 0);
+        assert_eq!(status.effective_auth_mode, "chatgpt");
+
+        let status = build_auth_status(path, Some(&auth), None, Some("apikey".into()), true, 0);
+        assert_eq!(status.effective_auth_mode, "apikey");
+
+        let status = build_auth_status(path, Some(&auth), Some("custom".into()), None, false, 0);
+        assert_eq!(status.effective_auth_mode, "provider");
+
+        let status = build_auth_status(path, None, None, None, true, 0);
+        assert_eq!(status.effective_auth_mode, "none");
+    }
+}
@@ -11,17 +11,19 @@ use super::plugin_types::{
 };
 use super::plugin_workspace;
 use super::types::*;
+use crate::app_lock;
 use crate::coding::all_api_hub;
-use crate::coding::db_id::{db_new_id, db_record_id};
+use crate::coding::db_id::{db_mark_applied_query, db_new_id, db_record_id, db_reorder_query};
 use crate::coding::open_code::shell_env;
 use crate::coding::prompt_file::{read_prompt_content_file, write_prompt_content_file};
 use crate::coding::runtime_location;
 use crate::coding::skills::commands::resync_all_skills_if_tool_path_changed;
 use crate::db::DbState;
-use chrono::Local;
+use crate::db_timestamp::{with_datetimes, Timestamp};
+use crate::secrets;
 use tauri::Emitter;
 
-const PROTECTED_TOP_LEVEL_TOML_KEYS: [&str; 3] = ["mcp_servers", "features", "plugins"];
+const PROTECTED_TOP_LEVEL_TOML_KEYS: [&str; 4] = ["mcp_servers", "features", "plugins", "notify"];
 
 // ============================================================================
 // Codex Config Path Commands
@@ -162,7 +164,7 @@ async fn get_codex_config_dir_from_db_async(
     get_codex_root_dir_from_db_async(db).await
 }
 
-async fn get_codex_auth_path_from_db_async(
+pub(super) async fn get_codex_auth_path_from_db_async(
     db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
 ) -> Result<std::path::PathBuf, String> {
     Ok(get_codex_config_dir_from_db_async(db)
@@ -170,7 +172,7 @@ async fn get_codex_auth_path_from_db_async(
         .join("auth.json"))
 }
 
-async fn get_codex_config_path_from_db_async(
+pub(super) async fn get_codex_config_path_from_db_async(
     db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
 ) -> Result<std::path::PathBuf, String> {
     Ok(get_codex_config_dir_from_db_async(db)
@@ -202,14 +204,14 @@ async fn get_local_prompt_config(
         return Ok(None);
     };
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     Ok(Some(CodexPromptConfig {
         id: "__local__".to_string(),
         name: "default".to_string(),
         content: prompt_content,
         is_applied: true,
         sort_index: None,
-        created_at: Some(now.clone()),
+        created_at: Some(now),
         updated_at: Some(now),
     }))
 }
@@ -551,7 +553,7 @@ async fn load_temp_provider_from_files_with_db(
         extract_provider_settings_for_storage(&settings, stored_common_toml.as_deref())?;
     let category = infer_codex_provider_category_from_settings(&provider_settings);
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     Ok(CodexProvider {
         id: "__local__".to_string(), // Special ID to indicate this is from local files
         name: "default".to_string(),
@@ -565,12 +567,12 @@ async fn load_temp_provider_from_files_with_db(
         sort_index: Some(0),
         is_applied: true,
         is_disabled: false,
-        created_at: now.clone(),
-        updated_at: now,
+        created_at: Some(now),
+        updated_at: Some(now),
     })
 }
 
-async fn get_codex_common_toml(
+pub(super) async fn get_codex_common_toml(
     db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
 ) -> Result<Option<String>, String> {
     let common_config_result: Result<Vec<Value>, _> = db
@@ -614,7 +616,7 @@ async fn extract_codex_common_config_from_current_files_with_db(
     let settings = read_codex_settings_from_disk(Some(db)).await?;
     let config_toml = settings.config.unwrap_or_default();
     let common_toml = extract_codex_common_config_from_settings_toml(&config_toml)?;
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
     Ok(CodexCommonConfig {
         config: common_toml,
@@ -1051,7 +1053,7 @@ fn render_codex_config_document(document: &toml_edit::DocumentMut) -> String {
     }
 }
 
-fn build_written_codex_config_toml(
+pub(super) fn build_written_codex_config_toml(
     existing_config_toml: &str,
     previous_managed_config_toml: Option<&str>,
     next_managed_config_toml: &str,
@@ -1080,7 +1082,7 @@ fn build_written_codex_config_toml(
     Ok(render_codex_config_document(&current_document))
 }
 
-fn build_managed_codex_config(
+pub(super) fn build_managed_codex_config(
     provider_settings_config: &str,
     common_toml: Option<&str>,
 ) -> Result<String, String> {
@@ -1113,7 +1115,7 @@ async fn get_managed_codex_config_for_provider(
     build_managed_codex_config(provider_settings_config, common_toml.as_deref())
 }
 
-async fn get_current_applied_managed_codex_config(
+pub(super) async fn get_current_applied_managed_codex_config(
     db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
 ) -> Result<Option<String>, String> {
     let Some(applied_provider) = get_applied_codex_provider(db).await? else {
@@ -1149,7 +1151,7 @@ pub async fn create_codex_provider(
     let normalized_settings_config =
         normalize_provider_settings_for_storage(&db, &provider.settings_config, None).await?;
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let content = CodexProviderContent {
         name: provider.name,
         category: provider.category,
@@ -1162,15 +1164,15 @@ pub async fn create_codex_provider(
         sort_index: provider.sort_index,
         is_applied: false,
         is_disabled: provider.is_disabled.unwrap_or(false),
-        created_at: now.clone(),
+        created_at: now,
         updated_at: now,
     };
 
-    let json_data = adapter::to_db_value_provider(&content);
+    let json_data = adapter::to_db_value_provider(&content)?;
 
     // Create new provider - SurrealDB auto-generates record ID
     db.query("CREATE codex_provider CONTENT $data")
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to create provider: {}", e))?;
 
@@ -1211,7 +1213,7 @@ pub async fn update_codex_provider(
 
     // Use the id from frontend (pure string id without table prefix)
     let id = provider.id.clone();
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
     // Get existing record to preserve created_at
     let record_id = db_record_id("codex_provider", &id);
@@ -1229,15 +1231,11 @@ pub async fn update_codex_provider(
     }
 
     // Get created_at and is_disabled from existing record
-    let (created_at, existing_is_disabled) = if !provider.created_at.is_empty() {
-        (provider.created_at, false)
+    let (created_at, existing_is_disabled) = if let Some(created_at) = provider.created_at {
+        (created_at, false)
     } else if let Ok(records) = &existing_result {
         if let Some(record) = records.first() {
-            let created = record
-                .get("created_at")
-                .and_then(|v| v.as_str())
-                .unwrap_or(&now)
-                .to_string();
+            let created = Timestamp::from_db(record, "created_at").unwrap_or(now);
             let is_disabled = record
                 .get("is_disabled")
                 .or_else(|| record.get("isDisabled"))
@@ -1245,10 +1243,10 @@ pub async fn update_codex_provider(
                 .unwrap_or(false);
             (created, is_disabled)
         } else {
-            (now.clone(), false)
+            (now, false)
         }
     } else {
-        (now.clone(), false)
+        (now, false)
     };
 
     let previous_managed_config_toml = if provider.is_applied {
@@ -1288,11 +1286,11 @@ pub async fn update_codex_provider(
         updated_at: now,
     };
 
-    let json_data = adapter::to_db_value_provider(&content);
+    let json_data = adapter::to_db_value_provider(&content)?;
 
     // Use database id for update
     db.query(format!("UPDATE codex_provider:`{}` CONTENT $data", id))
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to update provider: {}", e))?;
 
@@ -1328,8 +1326,8 @@ pub async fn update_codex_provider(
         sort_index: content.sort_index,
         is_applied: content.is_applied,
         is_disabled: content.is_disabled,
-        created_at: content.created_at,
-        updated_at: content.updated_at,
+        created_at: Some(content.created_at),
+        updated_at: Some(content.updated_at),
     })
 }
 
@@ -1351,94 +1349,19 @@ pub async fn delete_codex_provider(
 }
 
 /// Reorder Codex providers
-/// 使用 DELETE + CREATE 模式避免 SurrealDB MVCC 版本控制问题
 #[tauri::command]
 pub async fn reorder_codex_providers(
     state: tauri::State<'_, DbState>,
     ids: Vec<String>,
 ) -> Result<(), String> {
     let db = state.db();
-    let now = Local::now().to_rfc3339();
-
-    for (index, id) in ids.iter().enumerate() {
-        // 首先获取现有记录
-        let record_id = db_record_id("codex_provider", id);
-        let existing_result: Result<Vec<Value>, _> = db
-            .query(&format!(
-                "SELECT *, type::string(id) as id FROM {} LIMIT 1",
-                record_id
-            ))
-            .await
-            .map_err(|e| format!("Failed to query provider {}: {}", id, e))?
-            .take(0);
+    let now = Timestamp::now();
 
-        if let Ok(records) = existing_result {
-            if let Some(record) = records.first() {
-                // 构建更新后的内容
-                let content = CodexProviderContent {
-                    name: record
-                        .get("name")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("")
-                        .to_string(),
-                    category: record
-                        .get("category")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("")
-                        .to_string(),
-                    settings_config: record
-                        .get("settings_config")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("{}")
-                        .to_string(),
-                    source_provider_id: record
-                        .get("source_provider_id")
-                        .and_then(|v| v.as_str())
-                        .map(|s| s.to_string()),
-                    website_url: record
-                        .get("website_url")
-                        .and_then(|v| v.as_str())
-                        .map(|s| s.to_string()),
-                    notes: record
-                        .get("notes")
-                        .and_then(|v| v.as_str())
-                        .map(|s| s.to_string()),
-                    icon: record
-                        .get("icon")
-                        .and_then(|v| v.as_str())
-                        .map(|s| s.to_string()),
-                    icon_color: record
-                        .get("icon_color")
-                        .and_then(|v| v.as_str())
-                        .map(|s| s.to_string()),
-                    sort_index: Some(index as i32),
-                    is_applied: record
-                        .get("is_applied")
-                        .and_then(|v| v.as_bool())
-                        .unwrap_or(false),
-                    is_disabled: record
-                        .get("is_disabled")
-                        .or_else(|| record.get("isDisabled"))
-                        .and_then(|v| v.as_bool())
-                        .unwrap_or(false),
-                    created_at: record
-                        .get("created_at")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or(&now)
-                        .to_string(),
-                    updated_at: now.clone(),
-                };
-
-                let json_data = adapter::to_db_value_provider(&content);
-
-                // Use Blind Write pattern with native ID format
-                db.query(format!("UPDATE codex_provider:`{}` CONTENT $data", id))
-                    .bind(("data", json_data))
-                    .await
-                    .map_err(|e| format!("Failed to update provider {}: {}", id, e))?;
-            }
-        }
-    }
+    db.query(db_reorder_query("codex_provider", &ids, true))
+        .bind(("now", now.to_db()))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to reorder providers: {}", e))?;
 
     Ok(())
 }
@@ -1461,26 +1384,14 @@ async fn update_is_applied_status(
     db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
     target_id: &str,
 ) -> Result<(), String> {
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let target_id = target_id.to_string(); // Clone for bind
 
-    // Clear current applied status (only update the currently applied one)
-    db.query(
-        "UPDATE codex_provider SET is_applied = false, updated_at = $now WHERE is_applied = true",
-    )
-    .bind(("now", now.clone()))
-    .await
-    .map_err(|e| format!("Failed to clear applied status: {}", e))?;
-
-    // Set target provider as applied
-    let record_id = db_record_id("codex_provider", &target_id);
-    db.query(&format!(
-        "UPDATE {} SET is_applied = true, updated_at = $now",
-        record_id
-    ))
-    .bind(("now", now))
-    .await
-    .map_err(|e| format!("Failed to set applied status: {}", e))?;
+    db.query(db_mark_applied_query("codex_provider", &target_id))
+        .bind(("now", now.to_db()))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to set applied status: {}", e))?;
 
     Ok(())
 }
@@ -1545,15 +1456,18 @@ async fn apply_config_to_file_with_previous_managed_config(
         ));
     }
 
+    app_lock::ensure_decrypted(&provider.settings_config)?;
+
     // Parse provider settings_config
     let provider_config = parse_codex_settings_config(&provider.settings_config)?;
 
     let common_toml = get_codex_common_toml(db).await?;
 
-    // Extract auth and config
+    // Extract auth and config; vault secret references are substituted in auth.json only
     let auth = provider_config
         .get("auth")
-        .cloned()
+        .map(secrets::resolve_value)
+        .transpose()?
         .unwrap_or(serde_json::json!({}));
     let final_config =
         build_managed_codex_config(&provider.settings_config, common_toml.as_deref())?;
@@ -1662,13 +1576,13 @@ pub async fn toggle_codex_provider_disabled(
     let db = state.db();
 
     // Update is_disabled field in database
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     db.query(format!(
         "UPDATE codex_provider:`{}` SET is_disabled = $is_disabled, updated_at = $now",
         provider_id
     ))
     .bind(("is_disabled", is_disabled))
-    .bind(("now", now))
+    .bind(("now", now.to_db()))
     .await
     .map_err(|e| format!("Failed to toggle provider disabled status: {}", e))?;
 
@@ -1780,7 +1694,7 @@ pub async fn create_codex_prompt_config(
     input: CodexPromptConfigInput,
 ) -> Result<CodexPromptConfig, String> {
     let db = state.db();
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
     let sort_index_result: Result<Vec<Value>, _> = db
         .query("SELECT sort_index FROM codex_prompt_config ORDER BY sort_index DESC LIMIT 1")
@@ -1800,7 +1714,7 @@ pub async fn create_codex_prompt_config(
         content: input.content,
         is_applied: false,
         sort_index: Some(next_sort_index),
-        created_at: now.clone(),
+        created_at: now,
         updated_at: now,
     };
 
@@ -1809,7 +1723,7 @@ pub async fn create_codex_prompt_config(
     let record_id = db_record_id("codex_prompt_config", &prompt_id);
 
     db.query(&format!("CREATE {} CONTENT $data", record_id))
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to create prompt config: {}", e))?;
 
@@ -1866,11 +1780,8 @@ pub async fn update_codex_prompt_config(
     let (created_at, is_applied, sort_index) = match existing_result {
         Ok(records) => {
             if let Some(record) = records.first() {
-                let created_at = record
-                    .get("created_at")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or_else(|| Box::leak(Local::now().to_rfc3339().into_boxed_str()))
-                    .to_string();
+                let created_at =
+                    Timestamp::from_db(record, "created_at").unwrap_or_else(Timestamp::now);
                 let is_applied = record
                     .get("is_applied")
                     .or_else(|| record.get("isApplied"))
@@ -1889,19 +1800,19 @@ pub async fn update_codex_prompt_config(
         Err(e) => return Err(format!("Failed to deserialize prompt config: {}", e)),
     };
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let content = CodexPromptConfigContent {
         name: input.name,
         content: input.content.clone(),
         is_applied,
         sort_index,
         created_at,
-        updated_at: now.clone(),
+        updated_at: now,
     };
     let json_data = adapter::to_db_value_prompt(&content);
 
     db.query(&format!("UPDATE {} CONTENT $data", record_id))
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to update prompt config: {}", e))?;
 
@@ -1983,20 +1894,13 @@ pub async fn apply_prompt_config_internal<R: tauri::Runtime>(
         Err(e) => return Err(format!("Failed to deserialize prompt config: {}", e)),
     };
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
-    db.query("UPDATE codex_prompt_config SET is_applied = false, updated_at = $now WHERE is_applied = true")
-        .bind(("now", now.clone()))
+    db.query(db_mark_applied_query("codex_prompt_config", config_id))
+        .bind(("now", now.to_db()))
         .await
-        .map_err(|e| format!("Failed to clear prompt applied flags: {}", e))?;
-
-    db.query(&format!(
-        "UPDATE {} SET is_applied = true, updated_at = $now",
-        record_id
-    ))
-    .bind(("now", now))
-    .await
-    .map_err(|e| format!("Failed to set prompt applied flag: {}", e))?;
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to set prompt applied flag: {}", e))?;
     write_prompt_content_to_file(Some(&db), Some(prompt_config.content.as_str())).await?;
 
     let payload = if from_tray { "tray" } else { "window" };
@@ -2023,13 +1927,10 @@ pub async fn reorder_codex_prompt_configs(
 ) -> Result<(), String> {
     let db = state.db();
 
-    for (index, id) in ids.iter().enumerate() {
-        let record_id = db_record_id("codex_prompt_config", id);
-        db.query(&format!("UPDATE {} SET sort_index = $index", record_id))
-            .bind(("index", index as i32))
-            .await
-            .map_err(|e| format!("Failed to update prompt sort index: {}", e))?;
-    }
+    db.query(db_reorder_query("codex_prompt_config", &ids, false))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to update prompt sort index: {}", e))?;
 
     drop(db);
     let _ = app.emit("config-changed", "window");
@@ -2582,15 +2483,7 @@ pub async fn get_codex_common_config(
                 Ok(None)
             }
         }
-        Err(e) => {
-            // 反序列化失败，删除旧数据以修复版本冲突
-            eprintln!(
-                "⚠️ Codex common config has incompatible format, cleaning up: {}",
-                e
-            );
-            let _ = db.query("DELETE codex_common_config:`common`").await;
-            Ok(None)
-        }
+        Err(e) => Err(format!("Failed to parse common config: {}", e)),
     }
 }
 
@@ -2635,7 +2528,7 @@ pub async fn save_codex_common_config(
 
     // Use UPSERT to handle both update and create
     db.query("UPSERT codex_common_config:`common` CONTENT $data")
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to save config: {}", e))?;
 
@@ -2739,7 +2632,7 @@ pub async fn save_codex_local_config(
 
     let common_config = input.common_config.unwrap_or_default();
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let normalized_provider_settings_config =
         normalize_provider_settings_for_storage(&db, &provider_settings_config, Some(&common_config))
             .await?;
@@ -2755,13 +2648,13 @@ pub async fn save_codex_local_config(
         sort_index: provider_sort_index,
         is_applied: true,
         is_disabled: provider_is_disabled,
-        created_at: now.clone(),
+        created_at: now,
         updated_at: now,
     };
 
-    let provider_json = adapter::to_db_value_provider(&provider_content);
+    let provider_json = adapter::to_db_value_provider(&provider_content)?;
     db.query("CREATE codex_provider CONTENT $data")
-        .bind(("data", provider_json))
+        .bind(("data", with_datetimes(provider_json)))
         .await
         .map_err(|e| format!("Failed to create provider: {}", e))?;
 
@@ -2784,7 +2677,7 @@ pub async fn save_codex_local_config(
     };
     let common_json = adapter::to_db_value_common(&common_config, root_dir.as_deref());
     db.query("UPSERT codex_common_config:`common` CONTENT $data")
-        .bind(("data", common_json))
+        .bind(("data", with_datetimes(common_json)))
         .await
         .map_err(|e| format!("Failed to save common config: {}", e))?;
 
@@ -2884,7 +2777,7 @@ pub async fn init_codex_provider_from_settings(
     let provider_settings =
         extract_provider_settings_for_storage(&settings, common_toml.as_deref())?;
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let content = CodexProviderContent {
         name: "默认配置".to_string(),
         category: infer_codex_provider_category_from_settings(&provider_settings),
@@ -2897,15 +2790,15 @@ pub async fn init_codex_provider_from_settings(
         sort_index: Some(0),
         is_applied: true,
         is_disabled: false,
-        created_at: now.clone(),
+        created_at: now,
         updated_at: now,
     };
 
-    let json_data = adapter::to_db_value_provider(&content);
+    let json_data = adapter::to_db_value_provider(&content)?;
 
     // Create new provider with auto-generated random ID
     db.query("CREATE codex_provider CONTENT $data")
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to create provider: {}", e))?;
 
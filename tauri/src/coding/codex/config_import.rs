@@ -0,0 +1,267 @@
+//! Import existing Codex config.toml entries as managed providers
+//!
+//! Every `[model_providers.<id>]` entry and every `[profiles.<name>]` becomes one provider
+//! whose config TOML selects it at the top level, so applying it later reproduces what
+//! `codex --profile <name>` / `model_provider = "<id>"` would have done.
+
+use std::collections::HashSet;
+use std::fs;
+
+use serde_json::Value;
+use toml::Table;
+
+use super::commands::{
+    create_codex_provider, get_codex_auth_path_from_db_async, get_codex_config_path_from_db_async,
+    infer_codex_provider_category_from_settings,
+};
+use super::types::{CodexImportResult, CodexProviderInput};
+use crate::db::DbState;
+
+struct ImportCandidate {
+    name: String,
+    settings: Value,
+}
+
+fn provider_settings(config: &Table, api_key: Option<&str>) -> Result<Value, String> {
+    let config_toml =
+        toml::to_string(config).map_err(|e| format!("Failed to serialize config: {}", e))?;
+    let auth = match api_key {
+        Some(key) => serde_json::json!({ "OPENAI_API_KEY": key }),
+        None => serde_json::json!({}),
+    };
+    Ok(serde_json::json!({ "auth": auth, "config": config_toml }))
+}
+
+fn copy_model_provider(source: &Table, provider_id: &str, target: &mut Table) {
+    let Some(provider) = source
+        .get("model_providers")
+        .and_then(|providers| providers.get(provider_id))
+    else {
+        return;
+    };
+    let mut providers = Table::new();
+    providers.insert(provider_id.to_string(), provider.clone());
+    target.insert("model_providers".to_string(), toml::Value::Table(providers));
+}
+
+/// Build one provider config per model provider entry and per profile
+fn build_import_candidates(
+    config: &Table,
+    api_key: Option<&str>,
+) -> Result<Vec<ImportCandidate>, String> {
+    let active_provider = config.get("model_provider").and_then(|v| v.as_str());
+    let profiles = config.get("profiles").and_then(|v| v.as_table());
+    let mut candidates = Vec::new();
+
+    if let Some(model_providers) = config.get("model_providers").and_then(|v| v.as_table()) {
+        for (provider_id, provider) in model_providers {
+            let is_active = active_provider == Some(provider_id.as_str());
+            let model = if is_active {
+                config.get("model").cloned()
+            } else {
+                profiles.and_then(|profiles| {
+                    profiles.values().find_map(|profile| {
+                        (profile.get("model_provider").and_then(|v| v.as_str())
+                            == Some(provider_id.as_str()))
+                        .then(|| profile.get("model").cloned())
+                        .flatten()
+                    })
+                })
+            };
+
+            let mut provider_config = Table::new();
+            if let Some(model) = model {
+                provider_config.insert("model".to_string(), model);
+            }
+            provider_config.insert(
+                "model_provider".to_string(),
+                toml::Value::String(provider_id.clone()),
+            );
+            copy_model_provider(config, provider_id, &mut provider_config);
+
+            // auth.json only carries one key, and it belongs to the provider in use right now
+            let requires_openai_auth = provider
+                .get("requires_openai_auth")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            let key = (is_active && requires_openai_auth)
+                .then_some(api_key)
+                .flatten();
+
+            let name = provider
+                .get("name")
+                .and_then(|v| v.as_str())
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .unwrap_or(provider_id)
+                .to_string();
+            candidates.push(ImportCandidate {
+                name,
+                settings: provider_settings(&provider_config, key)?,
+            });
+        }
+    }
+
+    if let Some(profiles) = profiles {
+        for (profile_name, profile) in profiles {
+            let Some(profile) = profile.as_table() else {
+                continue;
+            };
+            let mut provider_config = profile.clone();
+            if let Some(provider_id) = profile.get("model_provider").and_then(|v| v.as_str()) {
+                copy_model_provider(config, provider_id, &mut provider_config);
+            }
+            candidates.push(ImportCandidate {
+                name: profile_name.clone(),
+                settings: provider_settings(&provider_config, None)?,
+            });
+        }
+    }
+
+    Ok(candidates)
+}
+
+/// Import `model_providers` entries and profiles from config.toml as Codex providers
+///
+/// Entries whose name matches an existing provider are skipped, so running it twice is safe.
+#[tauri::command]
+pub async fn import_codex_config_as_providers(
+    state: tauri::State<'_, DbState>,
+    app: tauri::AppHandle,
+) -> Result<CodexImportResult, String> {
+    let db = state.db();
+    let config_path = get_codex_config_path_from_db_async(&db).await?;
+    if !config_path.exists() {
+        return Err(format!(
+            "Codex config not found: {}",
+            config_path.to_string_lossy()
+        ));
+    }
+
+    let content = fs::read_to_string(&config_path)
+        .map_err(|e| format!("Failed to read config.toml: {}", e))?;
+    let config: Table = content
+        .parse()
+        .map_err(|e| format!("Failed to parse config.toml: {}", e))?;
+
+    let auth_path = get_codex_auth_path_from_db_async(&db).await?;
+    let api_key = fs::read_to_string(&auth_path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+        .and_then(|auth| {
+            auth.get("OPENAI_API_KEY")
+                .and_then(|v| v.as_str())
+                .map(str::trim)
+                .filter(|key| !key.is_empty())
+                .map(str::to_string)
+        });
+
+    let existing: Vec<Value> = db
+        .query("SELECT name, sort_index FROM codex_provider")
+        .await
+        .map_err(|e| format!("Failed to query providers: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to deserialize providers: {}", e))?;
+    let mut existing_names: HashSet<String> = existing
+        .iter()
+        .filter_map(|record| record.get("name").and_then(|v| v.as_str()))
+        .map(|name| name.trim().to_lowercase())
+        .collect();
+    let mut next_sort_index = existing
+        .iter()
+        .filter_map(|record| record.get("sort_index").and_then(|v| v.as_i64()))
+        .max()
+        .map(|max| max as i32 + 1)
+        .unwrap_or(0);
+
+    let mut result = CodexImportResult {
+        imported: Vec::new(),
+        skipped: Vec::new(),
+    };
+
+    for candidate in build_import_candidates(&config, api_key.as_deref())? {
+        if !existing_names.insert(candidate.name.trim().to_lowercase()) {
+            result.skipped.push(candidate.name);
+            continue;
+        }
+
+        let settings_config = serde_json::to_string(&candidate.settings)
+            .map_err(|e| format!("Failed to serialize provider config: {}", e))?;
+        let input = CodexProviderInput {
+            id: None,
+            name: candidate.name,
+            category: infer_codex_provider_category_from_settings(&candidate.settings),
+            settings_config,
+            source_provider_id: None,
+            website_url: None,
+            notes: Some(format!("Imported from {}", config_path.to_string_lossy())),
+            icon: None,
+            icon_color: None,
+            sort_index: Some(next_sort_index),
+            is_disabled: None,
+        };
+        next_sort_index += 1;
+
+        let provider = create_codex_provider(state.clone(), app.clone(), input).await?;
+        result.imported.push(provider);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_candidates_from_providers_and_profiles() {
+        let config: Table = r#"
+model = "gpt-5"
+model_provider = "relay"
+
+[model_providers.relay]
+name = "Relay"
+base_url = "https://relay.example.com/v1"
+requires_openai_auth = true
+
+[model_providers.local]
+base_url = "http://localhost:8080/v1"
+
+[profiles.fast]
+model = "gpt-5-mini"
+model_provider = "local"
+model_reasoning_effort = "low"
+"#
+        .parse()
+        .unwrap();
+
+        let candidates = build_import_candidates(&config, Some("sk-test")).unwrap();
+        let names: Vec<&str> = candidates.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Relay", "local", "fast"]);
+
+        let relay = &candidates[0].settings;
+        assert_eq!(relay["auth"]["OPENAI_API_KEY"], "sk-test");
+        let relay_config: Table = relay["config"].as_str().unwrap().parse().unwrap();
+        assert_eq!(relay_config["model"].as_str(), Some("gpt-5"));
+        assert!(relay_config["model_providers"].get("relay").is_some());
+
+        let local_config: Table = candidates[1].settings["config"]
+            .as_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(local_config["model"].as_str(), Some("gpt-5-mini"));
+        assert!(candidates[1].settings["auth"]
+            .as_object()
+            .unwrap()
+            .is_empty());
+
+        let fast_config: Table = candidates[2].settings["config"]
+            .as_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(fast_config["model_reasoning_effort"].as_str(), Some("low"));
+        assert!(fast_config["model_providers"].get("local").is_some());
+    }
+}
@@ -0,0 +1,472 @@
+//! Preview of the config.toml a Codex provider apply would write
+//!
+//! Runs the same merge as `apply_config_to_file` without touching disk, then reports a
+//! key-level diff against the current file and validation issues in the result.
+
+use std::fs;
+
+use toml::{Table, Value};
+
+use super::commands::{
+    build_managed_codex_config, build_written_codex_config_toml, get_codex_common_toml,
+    get_codex_config_path_from_db_async, get_current_applied_managed_codex_config,
+};
+use super::runtime_settings::{
+    load_provider, APPROVAL_POLICY_VALUES, REASONING_EFFORT_VALUES, REASONING_SUMMARY_VALUES,
+    SANDBOX_MODE_VALUES,
+};
+use super::types::{CodexConfigDiffEntry, CodexConfigIssue, CodexConfigPreview};
+use crate::db::DbState;
+
+/// Top-level keys Codex understands; anything else is reported as a warning
+const KNOWN_TOP_LEVEL_KEYS: &[&str] = &[
+    "model",
+    "review_model",
+    "model_provider",
+    "model_providers",
+    "model_context_window",
+    "model_max_output_tokens",
+    "model_auto_compact_token_limit",
+    "model_reasoning_effort",
+    "model_reasoning_summary",
+    "model_verbosity",
+    "model_supports_reasoning_summaries",
+    "model_reasoning_summary_format",
+    "approval_policy",
+    "sandbox_mode",
+    "sandbox_workspace_write",
+    "shell_environment_policy",
+    "disable_response_storage",
+    "preferred_auth_method",
+    "forced_login_method",
+    "forced_chatgpt_workspace_id",
+    "chatgpt_base_url",
+    "base_url",
+    "instructions",
+    "base_instructions",
+    "developer_instructions",
+    "model_instructions_file",
+    "project_doc_max_bytes",
+    "project_doc_fallback_filenames",
+    "profile",
+    "profiles",
+    "projects",
+    "history",
+    "file_opener",
+    "hide_agent_reasoning",
+    "show_raw_agent_reasoning",
+    "check_for_update_on_startup",
+    "disable_paste_burst",
+    "windows_wsl_setup_acknowledged",
+    "web_search",
+    "tools",
+    "tui",
+    "notice",
+    "otel",
+    "oss_provider",
+    "mcp_oauth_credentials_store",
+    "cli_auth_credentials_store",
+    "mcp_servers",
+    "features",
+    "plugins",
+    "notify",
+];
+
+/// Providers Codex ships with that need no `[model_providers.<id>]` entry
+const BUILTIN_MODEL_PROVIDERS: &[&str] = &["openai", "oss", "ollama", "lmstudio"];
+const MODEL_VERBOSITY_VALUES: &[&str] = &["low", "medium", "high"];
+const WIRE_API_VALUES: &[&str] = &["chat", "responses"];
+
+fn issue(path: impl Into<String>, severity: &str, message: impl Into<String>) -> CodexConfigIssue {
+    CodexConfigIssue {
+        path: path.into(),
+        severity: severity.to_string(),
+        message: message.into(),
+        pre_existing: false,
+    }
+}
+
+/// Flag the issues the current config.toml already has
+fn mark_pre_existing(issues: &mut [CodexConfigIssue], current_table: &Table) {
+    let current_issues = validate_codex_config(current_table);
+    for issue in issues {
+        issue.pre_existing = current_issues.iter().any(|current| {
+            current.path == issue.path
+                && current.severity == issue.severity
+                && current.message == issue.message
+        });
+    }
+}
+
+fn parse_table(content: &str, context: &str) -> Result<Table, String> {
+    if content.trim().is_empty() {
+        return Ok(Table::new());
+    }
+    content
+        .parse::<Table>()
+        .map_err(|e| format!("Failed to parse {}: {}", context, e))
+}
+
+/// Flatten nested tables into `(dotted.path, rendered value)` pairs, keeping document order
+fn flatten_table(table: &Table, prefix: &str, out: &mut Vec<(String, String)>) {
+    for (key, value) in table {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match value {
+            Value::Table(child) => flatten_table(child, &path, out),
+            other => out.push((path, other.to_string())),
+        }
+    }
+}
+
+/// Key-level diff between two TOML documents
+pub fn diff_toml_tables(before: &Table, after: &Table) -> Vec<CodexConfigDiffEntry> {
+    let mut before_entries = Vec::new();
+    let mut after_entries = Vec::new();
+    flatten_table(before, "", &mut before_entries);
+    flatten_table(after, "", &mut after_entries);
+
+    let before_map: std::collections::HashMap<&str, &str> = before_entries
+        .iter()
+        .map(|(path, value)| (path.as_str(), value.as_str()))
+        .collect();
+    let after_map: std::collections::HashMap<&str, &str> = after_entries
+        .iter()
+        .map(|(path, value)| (path.as_str(), value.as_str()))
+        .collect();
+
+    let mut diff = Vec::new();
+    for (path, after_value) in &after_entries {
+        match before_map.get(path.as_str()) {
+            None => diff.push(CodexConfigDiffEntry {
+                path: path.clone(),
+                kind: "added".to_string(),
+                before: None,
+                after: Some(after_value.clone()),
+            }),
+            Some(before_value) if *before_value != after_value.as_str() => {
+                diff.push(CodexConfigDiffEntry {
+                    path: path.clone(),
+                    kind: "changed".to_string(),
+                    before: Some(before_value.to_string()),
+                    after: Some(after_value.clone()),
+                })
+            }
+            _ => {}
+        }
+    }
+    for (path, before_value) in &before_entries {
+        if !after_map.contains_key(path.as_str()) {
+            diff.push(CodexConfigDiffEntry {
+                path: path.clone(),
+                kind: "removed".to_string(),
+                before: Some(before_value.clone()),
+                after: None,
+            });
+        }
+    }
+
+    diff
+}
+
+fn check_enum(
+    table: &Table,
+    prefix: &str,
+    key: &str,
+    allowed: &[&str],
+    issues: &mut Vec<CodexConfigIssue>,
+) {
+    let Some(value) = table.get(key) else {
+        return;
+    };
+    let path = format!("{}{}", prefix, key);
+    match value.as_str() {
+        Some(text) if allowed.contains(&text) => {}
+        Some(text) => issues.push(issue(
+            path,
+            "error",
+            format!(
+                "Invalid value '{}', expected one of: {}",
+                text,
+                allowed.join(", ")
+            ),
+        )),
+        None => issues.push(issue(path, "error", "Expected a string value")),
+    }
+}
+
+fn check_runtime_enums(table: &Table, prefix: &str, issues: &mut Vec<CodexConfigIssue>) {
+    check_enum(
+        table,
+        prefix,
+        "model_reasoning_effort",
+        REASONING_EFFORT_VALUES,
+        issues,
+    );
+    check_enum(
+        table,
+        prefix,
+        "model_reasoning_summary",
+        REASONING_SUMMARY_VALUES,
+        issues,
+    );
+    check_enum(
+        table,
+        prefix,
+        "model_verbosity",
+        MODEL_VERBOSITY_VALUES,
+        issues,
+    );
+    check_enum(
+        table,
+        prefix,
+        "approval_policy",
+        APPROVAL_POLICY_VALUES,
+        issues,
+    );
+    check_enum(table, prefix, "sandbox_mode", SANDBOX_MODE_VALUES, issues);
+}
+
+fn check_model_provider_reference(
+    table: &Table,
+    prefix: &str,
+    model_providers: Option<&Table>,
+    issues: &mut Vec<CodexConfigIssue>,
+) {
+    let Some(provider_id) = table.get("model_provider").and_then(|v| v.as_str()) else {
+        return;
+    };
+    let defined = model_providers.is_some_and(|providers| providers.contains_key(provider_id));
+    if !defined && !BUILTIN_MODEL_PROVIDERS.contains(&provider_id) {
+        issues.push(issue(
+            format!("{}model_provider", prefix),
+            "error",
+            format!(
+                "Model provider '{}' is not defined in [model_providers]",
+                provider_id
+            ),
+        ));
+    }
+}
+
+/// Validate a full config.toml the way Codex would read it
+pub fn validate_codex_config(config: &Table) -> Vec<CodexConfigIssue> {
+    let mut issues = Vec::new();
+
+    for key in config.keys() {
+        if !KNOWN_TOP_LEVEL_KEYS.contains(&key.as_str()) && !key.starts_with("experimental_") {
+            issues.push(issue(key.clone(), "warning", "Unknown top-level key"));
+        }
+    }
+
+    check_runtime_enums(config, "", &mut issues);
+
+    let model_providers = config.get("model_providers").and_then(|v| v.as_table());
+    check_model_provider_reference(config, "", model_providers, &mut issues);
+
+    if let Some(providers) = model_providers {
+        for (provider_id, provider) in providers {
+            let prefix = format!("model_providers.{}.", provider_id);
+            let Some(provider) = provider.as_table() else {
+                issues.push(issue(
+                    format!("model_providers.{}", provider_id),
+                    "error",
+                    "Provider entry must be a table",
+                ));
+                continue;
+            };
+            if provider
+                .get("base_url")
+                .and_then(|v| v.as_str())
+                .is_none_or(|url| url.trim().is_empty())
+            {
+                issues.push(issue(
+                    format!("{}base_url", prefix),
+                    "warning",
+                    "Provider has no base_url",
+                ));
+            }
+            check_enum(provider, &prefix, "wire_api", WIRE_API_VALUES, &mut issues);
+        }
+    }
+
+    if let Some(profiles) = config.get("profiles").and_then(|v| v.as_table()) {
+        for (profile_name, profile) in profiles {
+            let Some(profile) = profile.as_table() else {
+                continue;
+            };
+            let prefix = format!("profiles.{}.", profile_name);
+            check_runtime_enums(profile, &prefix, &mut issues);
+            check_model_provider_reference(profile, &prefix, model_providers, &mut issues);
+        }
+    }
+
+    if let Some(profile_name) = config.get("profile").and_then(|v| v.as_str()) {
+        let exists = config
+            .get("profiles")
+            .and_then(|v| v.as_table())
+            .is_some_and(|profiles| profiles.contains_key(profile_name));
+        if !exists {
+            issues.push(issue(
+                "profile",
+                "error",
+                format!("Profile '{}' is not defined in [profiles]", profile_name),
+            ));
+        }
+    }
+
+    issues
+}
+
+/// Build the preview for applying a provider, without writing any file
+pub async fn build_codex_config_preview(
+    db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
+    provider_id: &str,
+) -> Result<CodexConfigPreview, String> {
+    let provider = load_provider(db, provider_id).await?;
+    let common_toml = get_codex_common_toml(db).await?;
+    let next_managed_config =
+        build_managed_codex_config(&provider.settings_config, common_toml.as_deref())?;
+    let previous_managed_config = get_current_applied_managed_codex_config(db).await?;
+
+    let config_path = get_codex_config_path_from_db_async(db).await?;
+    let current_config = if config_path.exists() {
+        fs::read_to_string(&config_path)
+            .map_err(|e| format!("Failed to read config.toml: {}", e))?
+    } else {
+        String::new()
+    };
+
+    let mut issues = Vec::new();
+    let next_config = match build_written_codex_config_toml(
+        &current_config,
+        previous_managed_config.as_deref(),
+        &next_managed_config,
+    ) {
+        Ok(content) => content,
+        Err(e) => {
+            issues.push(issue("", "error", e));
+            String::new()
+        }
+    };
+
+    let current_table = parse_table(&current_config, "config.toml").unwrap_or_default();
+    let diff = match parse_table(&next_config, "new config.toml") {
+        Ok(next_table) => {
+            let mut next_issues = validate_codex_config(&next_table);
+            mark_pre_existing(&mut next_issues, &current_table);
+            issues.extend(next_issues);
+            diff_toml_tables(&current_table, &next_table)
+        }
+        Err(e) => {
+            issues.push(issue("", "error", e));
+            Vec::new()
+        }
+    };
+
+    if provider.is_disabled {
+        issues.push(issue(
+            "",
+            "error",
+            format!("Provider '{}' is disabled", provider.name),
+        ));
+    }
+
+    let has_errors = issues.iter().any(|i| i.severity == "error");
+    Ok(CodexConfigPreview {
+        provider_id: provider.id,
+        provider_name: provider.name,
+        config_path: config_path.to_string_lossy().to_string(),
+        current_config,
+        next_config,
+        diff,
+        issues,
+        has_errors,
+    })
+}
+
+/// Preview the config.toml diff and validation issues of applying a Codex provider
+#[tauri::command]
+pub async fn preview_codex_config(
+    state: tauri::State<'_, DbState>,
+    provider_id: String,
+) -> Result<CodexConfigPreview, String> {
+    let db = state.db();
+    build_codex_config_preview(&db, &provider_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_reports_added_changed_and_removed_keys() {
+        let before = parse_table(
+            "model = \"gpt-5\"\nold = 1\n[model_providers.a]\nbase_url = \"https://a\"\n",
+            "before",
+        )
+        .unwrap();
+        let after = parse_table(
+            "model = \"gpt-5.1\"\n[model_providers.a]\nbase_url = \"https://a\"\nname = \"A\"\n",
+            "after",
+        )
+        .unwrap();
+
+        let diff = diff_toml_tables(&before, &after);
+        let summary: Vec<(&str, &str)> = diff
+            .iter()
+            .map(|entry| (entry.path.as_str(), entry.kind.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("model", "changed"),
+                ("model_providers.a.name", "added"),
+                ("old", "removed"),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_flags_bad_enums_and_missing_provider() {
+        let config = parse_table(
+            "model_provider = \"custom\"\nsandbox_mode = \"yolo\"\nmystery = true\n\
+             [profiles.fast]\nmodel_reasoning_effort = \"low\"\n",
+            "config",
+        )
+        .unwrap();
+
+        let issues = validate_codex_config(&config);
+        let errors: Vec<&str> = issues
+            .iter()
+            .filter(|i| i.severity == "error")
+            .map(|i| i.path.as_str())
+            .collect();
+        assert_eq!(errors, vec!["sandbox_mode", "model_provider"]);
+        assert!(issues
+            .iter()
+            .any(|i| i.path == "mystery" && i.severity == "warning"));
+    }
+
+    #[test]
+    fn marks_errors_the_current_config_already_has() {
+        let current = parse_table("sandbox_mode = \"yolo\"\n", "current").unwrap();
+        let next = parse_table(
+            "sandbox_mode = \"yolo\"\nmodel_provider = \"custom\"\n",
+            "next",
+        )
+        .unwrap();
+
+        let mut issues = validate_codex_config(&next);
+        mark_pre_existing(&mut issues, &current);
+        let new_errors: Vec<&str> = issues
+            .iter()
+            .filter(|i| i.severity == "error" && !i.pre_existing)
+            .map(|i| i.path.as_str())
+            .collect();
+        assert_eq!(new_errors, vec!["model_provider"]);
+    }
+}
@@ -1,13 +1,23 @@
 pub mod adapter;
+pub mod auth_status;
 pub mod commands;
+pub mod config_import;
+pub mod config_preview;
+pub mod notify;
 pub mod plugin_ops;
 pub mod plugin_state;
 pub mod plugin_toml;
 pub mod plugin_types;
 pub mod plugin_workspace;
+pub mod runtime_settings;
 pub mod tray_support;
 pub mod types;
 
+pub use auth_status::*;
 pub use commands::*;
+pub use config_import::*;
+pub use config_preview::*;
+pub use notify::*;
 pub use plugin_types::*;
+pub use runtime_settings::*;
 pub use types::*;
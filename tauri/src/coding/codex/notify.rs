@@ -0,0 +1,296 @@
+//! Codex `notify` program management
+//!
+//! `notify` is a top-level key in config.toml holding the argv Codex runs after each turn
+//! (the event JSON is appended as the last argument). It is written straight into the runtime
+//! config.toml and listed in the protected top-level keys, so provider switches keep it.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tauri::Emitter;
+use toml_edit::{Array, DocumentMut, Item, Table};
+
+use crate::coding::runtime_location;
+use crate::db::DbState;
+
+const NOTIFY_SCRIPT_SH: &str = include_str!("../../../resources/codex/notify.sh");
+const NOTIFY_SCRIPT_PS1: &str = include_str!("../../../resources/codex/notify.ps1");
+const NOTIFY_SCRIPT_DIR: &str = "ai-toolbox";
+
+/// Codex notify configuration as read from config.toml
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexNotifyStatus {
+    /// Current `notify` argv, None when not configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<Vec<String>>,
+    /// `[tui] notifications`, None when not configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tui_notifications: Option<bool>,
+    /// Whether `notify` points at the script bundled with AI Toolbox
+    pub uses_bundled_script: bool,
+    pub bundled_script_path: String,
+    pub bundled_script_installed: bool,
+}
+
+/// Input for saving notify settings; `None` / empty values remove the key
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexNotifyConfigInput {
+    #[serde(default)]
+    pub command: Option<Vec<String>>,
+    #[serde(default)]
+    pub tui_notifications: Option<bool>,
+}
+
+fn bundled_script_path(config_path: &Path) -> PathBuf {
+    let file_name = if cfg!(target_os = "windows") {
+        "notify.ps1"
+    } else {
+        "notify.sh"
+    };
+    config_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default()
+        .join(NOTIFY_SCRIPT_DIR)
+        .join(file_name)
+}
+
+fn bundled_script_command(script_path: &Path) -> Vec<String> {
+    let script = script_path.to_string_lossy().to_string();
+    if cfg!(target_os = "windows") {
+        vec![
+            "powershell.exe".to_string(),
+            "-NoProfile".to_string(),
+            "-ExecutionPolicy".to_string(),
+            "Bypass".to_string(),
+            "-File".to_string(),
+            script,
+        ]
+    } else {
+        vec!["sh".to_string(), script]
+    }
+}
+
+fn read_document(config_path: &Path) -> Result<DocumentMut, String> {
+    if !config_path.exists() {
+        return Ok(DocumentMut::new());
+    }
+
+    let content = fs::read_to_string(config_path)
+        .map_err(|error| format!("Failed to read {}: {}", config_path.display(), error))?;
+    content
+        .parse::<DocumentMut>()
+        .map_err(|error| format!("Failed to parse {}: {}", config_path.display(), error))
+}
+
+fn write_document(config_path: &Path, document: &DocumentMut) -> Result<(), String> {
+    if let Some(parent_dir) = config_path.parent() {
+        fs::create_dir_all(parent_dir)
+            .map_err(|error| format!("Failed to create {}: {}", parent_dir.display(), error))?;
+    }
+
+    fs::write(config_path, document.to_string())
+        .map_err(|error| format!("Failed to write {}: {}", config_path.display(), error))
+}
+
+fn read_notify_command(document: &DocumentMut) -> Option<Vec<String>> {
+    let array = document.get("notify")?.as_array()?;
+    let command: Vec<String> = array
+        .iter()
+        .filter_map(|value| value.as_str().map(str::to_string))
+        .collect();
+    (!command.is_empty()).then_some(command)
+}
+
+fn read_tui_notifications(document: &DocumentMut) -> Option<bool> {
+    document
+        .get("tui")
+        .and_then(|tui| tui.get("notifications"))
+        .and_then(|item| item.as_bool())
+}
+
+fn apply_notify_config(document: &mut DocumentMut, input: &CodexNotifyConfigInput) {
+    let command: Vec<&str> = input
+        .command
+        .iter()
+        .flatten()
+        .map(|arg| arg.as_str())
+        .filter(|arg| !arg.trim().is_empty())
+        .collect();
+
+    if command.is_empty() {
+        document.remove("notify");
+    } else {
+        let mut array = Array::new();
+        for arg in command {
+            array.push(arg);
+        }
+        document.insert("notify", toml_edit::value(array));
+    }
+
+    match input.tui_notifications {
+        Some(enabled) => {
+            let tui = document
+                .entry("tui")
+                .or_insert_with(|| Item::Table(Table::new()));
+            if !tui.is_table_like() {
+                *tui = Item::Table(Table::new());
+            }
+            if let Some(table) = tui.as_table_like_mut() {
+                table.insert("notifications", toml_edit::value(enabled));
+            }
+        }
+        None => {
+            let remove_tui = match document.get_mut("tui").and_then(|t| t.as_table_like_mut()) {
+                Some(table) => {
+                    table.remove("notifications");
+                    table.is_empty()
+                }
+                None => false,
+            };
+            if remove_tui {
+                document.remove("tui");
+            }
+        }
+    }
+}
+
+fn build_status(config_path: &Path, document: &DocumentMut) -> CodexNotifyStatus {
+    let script_path = bundled_script_path(config_path);
+    let command = read_notify_command(document);
+    let uses_bundled_script = command
+        .as_ref()
+        .is_some_and(|cmd| *cmd == bundled_script_command(&script_path));
+
+    CodexNotifyStatus {
+        command,
+        tui_notifications: read_tui_notifications(document),
+        uses_bundled_script,
+        bundled_script_path: script_path.to_string_lossy().to_string(),
+        bundled_script_installed: script_path.is_file(),
+    }
+}
+
+fn install_bundled_script(script_path: &Path) -> Result<(), String> {
+    if let Some(parent_dir) = script_path.parent() {
+        fs::create_dir_all(parent_dir)
+            .map_err(|error| format!("Failed to create {}: {}", parent_dir.display(), error))?;
+    }
+
+    let content = if cfg!(target_os = "windows") {
+        NOTIFY_SCRIPT_PS1
+    } else {
+        NOTIFY_SCRIPT_SH
+    };
+    fs::write(script_path, content)
+        .map_err(|error| format!("Failed to write {}: {}", script_path.display(), error))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(script_path, fs::Permissions::from_mode(0o755))
+            .map_err(|error| format!("Failed to chmod {}: {}", script_path.display(), error))?;
+    }
+
+    Ok(())
+}
+
+fn emit_notify_config_changed<R: tauri::Runtime>(app: &tauri::AppHandle<R>) {
+    let _ = app.emit("config-changed", "window");
+
+    #[cfg(target_os = "windows")]
+    let _ = app.emit("wsl-sync-request-codex", ());
+
+    let _ = app.emit("ssh-sync-request-codex", ());
+}
+
+/// Get Codex notify settings from config.toml
+#[tauri::command]
+pub async fn get_codex_notify_status(
+    state: tauri::State<'_, DbState>,
+) -> Result<CodexNotifyStatus, String> {
+    let db = state.db();
+    let config_path = runtime_location::get_codex_config_path_async(&db).await?;
+    let document = read_document(&config_path)?;
+    Ok(build_status(&config_path, &document))
+}
+
+/// Save the `notify` program and `[tui] notifications` in config.toml
+#[tauri::command]
+pub async fn save_codex_notify_config(
+    state: tauri::State<'_, DbState>,
+    app: tauri::AppHandle,
+    input: CodexNotifyConfigInput,
+) -> Result<CodexNotifyStatus, String> {
+    let db = state.db();
+    let config_path = runtime_location::get_codex_config_path_async(&db).await?;
+    let mut document = read_document(&config_path)?;
+    apply_notify_config(&mut document, &input);
+    write_document(&config_path, &document)?;
+
+    emit_notify_config_changed(&app);
+    Ok(build_status(&config_path, &document))
+}
+
+/// Install the bundled notify script next to config.toml and point `notify` at it
+#[tauri::command]
+pub async fn enable_codex_bundled_notify(
+    state: tauri::State<'_, DbState>,
+    app: tauri::AppHandle,
+) -> Result<CodexNotifyStatus, String> {
+    let db = state.db();
+    let config_path = runtime_location::get_codex_config_path_async(&db).await?;
+    let script_path = bundled_script_path(&config_path);
+    install_bundled_script(&script_path)?;
+
+    let mut document = read_document(&config_path)?;
+    let input = CodexNotifyConfigInput {
+        command: Some(bundled_script_command(&script_path)),
+        tui_notifications: read_tui_notifications(&document),
+    };
+    apply_notify_config(&mut document, &input);
+    write_document(&config_path, &document)?;
+
+    emit_notify_config_changed(&app);
+    Ok(build_status(&config_path, &document))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_notify_config_sets_and_clears_keys() {
+        let mut document: DocumentMut = "model = \"gpt-5\"\n\n[tui]\nalt = 1\n".parse().unwrap();
+
+        apply_notify_config(
+            &mut document,
+            &CodexNotifyConfigInput {
+                command: Some(vec!["sh".to_string(), "/tmp/notify.sh".to_string()]),
+                tui_notifications: Some(true),
+            },
+        );
+        assert_eq!(
+            read_notify_command(&document),
+            Some(vec!["sh".to_string(), "/tmp/notify.sh".to_string()])
+        );
+        assert_eq!(read_tui_notifications(&document), Some(true));
+        let rendered = document.to_string();
+        assert!(rendered.find("notify").unwrap() < rendered.find("[tui]").unwrap());
+
+        apply_notify_config(
+            &mut document,
+            &CodexNotifyConfigInput {
+                command: Some(vec![]),
+                tui_notifications: None,
+            },
+        );
+        assert_eq!(read_notify_command(&document), None);
+        assert_eq!(read_tui_notifications(&document), None);
+        assert!(document.get("tui").is_some());
+        assert!(document.get("model").is_some());
+    }
+}
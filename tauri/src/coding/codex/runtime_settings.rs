@@ -0,0 +1,300 @@
+//! Typed editing of Codex reasoning / approval / sandbox settings
+//!
+//! These keys live in the provider's config TOML, either at the top level or under
+//! `[profiles.<name>]`. Edits go through `toml_edit` so comments and unrelated keys survive,
+//! and then through `update_codex_provider` so storage normalization and re-apply stay identical
+//! to a manual edit.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::adapter;
+use super::commands::update_codex_provider;
+use super::types::CodexProvider;
+use crate::coding::db_id::db_record_id;
+use crate::db::DbState;
+
+pub const REASONING_EFFORT_VALUES: &[&str] = &["none", "minimal", "low", "medium", "high", "xhigh"];
+pub const REASONING_SUMMARY_VALUES: &[&str] = &["auto", "concise", "detailed", "none"];
+pub const APPROVAL_POLICY_VALUES: &[&str] = &["untrusted", "on-failure", "on-request", "never"];
+pub const SANDBOX_MODE_VALUES: &[&str] = &["read-only", "workspace-write", "danger-full-access"];
+
+/// Structured view of the Codex runtime keys; `None` means the key is not set
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexRuntimeSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_reasoning_effort: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_reasoning_summary: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approval_policy: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox_mode: Option<String>,
+}
+
+impl CodexRuntimeSettings {
+    fn entries(&self) -> [(&'static str, Option<&str>, &'static [&'static str]); 4] {
+        [
+            (
+                "model_reasoning_effort",
+                self.model_reasoning_effort.as_deref(),
+                REASONING_EFFORT_VALUES,
+            ),
+            (
+                "model_reasoning_summary",
+                self.model_reasoning_summary.as_deref(),
+                REASONING_SUMMARY_VALUES,
+            ),
+            (
+                "approval_policy",
+                self.approval_policy.as_deref(),
+                APPROVAL_POLICY_VALUES,
+            ),
+            (
+                "sandbox_mode",
+                self.sandbox_mode.as_deref(),
+                SANDBOX_MODE_VALUES,
+            ),
+        ]
+    }
+}
+
+/// Validate settings against the values Codex accepts, returning one message per problem
+pub fn validate_runtime_settings(settings: &CodexRuntimeSettings) -> Vec<String> {
+    settings
+        .entries()
+        .into_iter()
+        .filter_map(|(key, value, allowed)| {
+            let value = value?.trim();
+            if value.is_empty() || allowed.contains(&value) {
+                None
+            } else {
+                Some(format!(
+                    "Invalid {} '{}', expected one of: {}",
+                    key,
+                    value,
+                    allowed.join(", ")
+                ))
+            }
+        })
+        .collect()
+}
+
+fn normalize_profile(profile: Option<String>) -> Option<String> {
+    profile
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+}
+
+fn parse_document(config_toml: &str) -> Result<toml_edit::DocumentMut, String> {
+    if config_toml.trim().is_empty() {
+        return Ok(toml_edit::DocumentMut::new());
+    }
+    config_toml
+        .parse::<toml_edit::DocumentMut>()
+        .map_err(|e| format!("Failed to parse provider config: {}", e))
+}
+
+/// Read runtime settings from a config TOML (top level, or `[profiles.<profile>]`)
+pub fn read_runtime_settings_from_toml(
+    config_toml: &str,
+    profile: Option<&str>,
+) -> Result<CodexRuntimeSettings, String> {
+    let document = parse_document(config_toml)?;
+    let table = match profile {
+        Some(name) => document
+            .get("profiles")
+            .and_then(|profiles| profiles.get(name))
+            .and_then(|item| item.as_table_like()),
+        None => Some(document.as_table() as &dyn toml_edit::TableLike),
+    };
+
+    let get = |key: &str| {
+        table
+            .and_then(|t| t.get(key))
+            .and_then(|item| item.as_str())
+            .map(|s| s.to_string())
+    };
+
+    Ok(CodexRuntimeSettings {
+        model_reasoning_effort: get("model_reasoning_effort"),
+        model_reasoning_summary: get("model_reasoning_summary"),
+        approval_policy: get("approval_policy"),
+        sandbox_mode: get("sandbox_mode"),
+    })
+}
+
+/// Write runtime settings into a config TOML, removing keys whose value is `None` or empty
+pub fn apply_runtime_settings_to_toml(
+    config_toml: &str,
+    profile: Option<&str>,
+    settings: &CodexRuntimeSettings,
+) -> Result<String, String> {
+    let mut document = parse_document(config_toml)?;
+
+    let table: &mut toml_edit::Table = match profile {
+        Some(name) => {
+            let profiles = document
+                .entry("profiles")
+                .or_insert_with(|| {
+                    let mut table = toml_edit::Table::new();
+                    table.set_implicit(true);
+                    toml_edit::Item::Table(table)
+                })
+                .as_table_mut()
+                .ok_or_else(|| "`profiles` in provider config is not a table".to_string())?;
+            profiles
+                .entry(name)
+                .or_insert_with(|| toml_edit::Item::Table(toml_edit::Table::new()))
+                .as_table_mut()
+                .ok_or_else(|| format!("`profiles.{}` in provider config is not a table", name))?
+        }
+        None => document.as_table_mut(),
+    };
+
+    for (key, value, _) in settings.entries() {
+        match value.map(str::trim).filter(|v| !v.is_empty()) {
+            Some(value) => {
+                table.insert(key, toml_edit::value(value));
+            }
+            None => {
+                table.remove(key);
+            }
+        }
+    }
+
+    Ok(document.to_string())
+}
+
+pub(super) async fn load_provider(
+    db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
+    provider_id: &str,
+) -> Result<CodexProvider, String> {
+    let record_id = db_record_id("codex_provider", provider_id);
+    let records: Vec<Value> = db
+        .query(&format!(
+            "SELECT *, type::string(id) as id FROM {} LIMIT 1",
+            record_id
+        ))
+        .await
+        .map_err(|e| format!("Failed to query provider: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to deserialize provider: {}", e))?;
+
+    records
+        .into_iter()
+        .next()
+        .map(adapter::from_db_value_provider)
+        .ok_or_else(|| format!("Codex provider with ID '{}' not found", provider_id))
+}
+
+fn provider_config_toml(provider: &CodexProvider) -> Result<(Value, String), String> {
+    let settings: Value = serde_json::from_str(&provider.settings_config)
+        .map_err(|e| format!("Failed to parse provider config: {}", e))?;
+    let config_toml = settings
+        .get("config")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+    Ok((settings, config_toml))
+}
+
+/// Get reasoning / approval / sandbox settings of a provider (optionally for a profile)
+#[tauri::command]
+pub async fn get_codex_provider_runtime_settings(
+    state: tauri::State<'_, DbState>,
+    provider_id: String,
+    profile: Option<String>,
+) -> Result<CodexRuntimeSettings, String> {
+    let db = state.db();
+    let provider = load_provider(&db, &provider_id).await?;
+    let (_, config_toml) = provider_config_toml(&provider)?;
+    read_runtime_settings_from_toml(&config_toml, normalize_profile(profile).as_deref())
+}
+
+/// Update reasoning / approval / sandbox settings of a provider (optionally for a profile)
+///
+/// The provider is saved through `update_codex_provider`, so an applied provider is
+/// re-applied to config.toml right away.
+#[tauri::command]
+pub async fn update_codex_provider_runtime_settings(
+    state: tauri::State<'_, DbState>,
+    app: tauri::AppHandle,
+    provider_id: String,
+    profile: Option<String>,
+    settings: CodexRuntimeSettings,
+) -> Result<CodexProvider, String> {
+    let errors = validate_runtime_settings(&settings);
+    if !errors.is_empty() {
+        return Err(errors.join("; "));
+    }
+
+    let db = state.db();
+    let mut provider = load_provider(&db, &provider_id).await?;
+    let (mut settings_value, config_toml) = provider_config_toml(&provider)?;
+
+    let next_config_toml = apply_runtime_settings_to_toml(
+        &config_toml,
+        normalize_profile(profile).as_deref(),
+        &settings,
+    )?;
+
+    let settings_object = settings_value
+        .as_object_mut()
+        .ok_or_else(|| "Codex settings must be a JSON object".to_string())?;
+    settings_object.insert("config".to_string(), Value::String(next_config_toml));
+    provider.settings_config = serde_json::to_string(&settings_value)
+        .map_err(|e| format!("Failed to serialize provider config: {}", e))?;
+
+    update_codex_provider(state, app, provider).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_rejects_unknown_values() {
+        let settings = CodexRuntimeSettings {
+            model_reasoning_effort: Some("high".to_string()),
+            approval_policy: Some("sometimes".to_string()),
+            sandbox_mode: Some("".to_string()),
+            ..Default::default()
+        };
+        let errors = validate_runtime_settings(&settings);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("approval_policy"));
+    }
+
+    #[test]
+    fn apply_updates_top_level_and_profile_keys() {
+        let original = "# keep me\nmodel = \"gpt-5\"\nsandbox_mode = \"read-only\"\n";
+        let settings = CodexRuntimeSettings {
+            model_reasoning_effort: Some("high".to_string()),
+            approval_policy: Some("on-request".to_string()),
+            ..Default::default()
+        };
+
+        let updated = apply_runtime_settings_to_toml(original, None, &settings).unwrap();
+        assert!(updated.contains("# keep me"));
+        assert!(updated.contains("model = \"gpt-5\""));
+        assert!(!updated.contains("sandbox_mode"));
+        assert_eq!(
+            read_runtime_settings_from_toml(&updated, None).unwrap(),
+            settings
+        );
+
+        let with_profile =
+            apply_runtime_settings_to_toml(&updated, Some("deep"), &settings).unwrap();
+        assert!(with_profile.contains("[profiles.deep]"));
+        assert_eq!(
+            read_runtime_settings_from_toml(&with_profile, Some("deep")).unwrap(),
+            settings
+        );
+        assert_eq!(
+            read_runtime_settings_from_toml(&with_profile, Some("missing")).unwrap(),
+            CodexRuntimeSettings::default()
+        );
+    }
+}
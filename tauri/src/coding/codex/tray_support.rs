@@ -84,6 +84,23 @@ pub async fn apply_codex_provider<R: Runtime>(
     let state = app.state::<DbState>();
     let db = state.db();
 
+    // The tray has no room to show a diff, so only refuse configs Codex would reject because
+    // of this provider; errors already in the user's config.toml don't block switching
+    let preview = super::config_preview::build_codex_config_preview(&db, provider_id).await?;
+    let errors: Vec<String> = preview
+        .issues
+        .iter()
+        .filter(|issue| issue.severity == "error" && !issue.pre_existing)
+        .map(|issue| format!("{}: {}", issue.path, issue.message))
+        .collect();
+    if !errors.is_empty() {
+        return Err(format!(
+            "Codex config for '{}' is invalid: {}",
+            preview.provider_name,
+            errors.join("; ")
+        ));
+    }
+
     apply_config_internal(&db, app, provider_id, true).await
 }
 
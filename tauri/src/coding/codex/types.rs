@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use surrealdb::sql::Thing;
 
+use crate::db_timestamp::Timestamp;
+
 // ============================================================================
 // Codex Provider Types
 // ============================================================================
@@ -26,8 +28,10 @@ pub struct CodexProviderRecord {
     pub sort_index: Option<i32>,
     pub is_applied: bool,
     pub is_disabled: bool,
-    pub created_at: String,
-    pub updated_at: String,
+    #[serde(default)]
+    pub created_at: Option<Timestamp>,
+    #[serde(default)]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// CodexProvider - API response
@@ -52,8 +56,10 @@ pub struct CodexProvider {
     pub sort_index: Option<i32>,
     pub is_applied: bool,
     pub is_disabled: bool,
-    pub created_at: String,
-    pub updated_at: String,
+    #[serde(default, deserialize_with = "crate::db_timestamp::lenient")]
+    pub created_at: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::db_timestamp::lenient")]
+    pub updated_at: Option<Timestamp>,
 }
 
 impl From<CodexProviderRecord> for CodexProvider {
@@ -97,8 +103,8 @@ pub struct CodexProviderContent {
     pub sort_index: Option<i32>,
     pub is_applied: bool,
     pub is_disabled: bool,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
 }
 
 /// CodexProvider - Input from frontend (for create operation)
@@ -138,7 +144,7 @@ pub struct CodexCommonConfigRecord {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub root_dir: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<Timestamp>,
 }
 
 /// CodexCommonConfig - API response
@@ -148,7 +154,7 @@ pub struct CodexCommonConfig {
     pub config: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub root_dir: Option<String>,
-    pub updated_at: String,
+    pub updated_at: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -213,10 +219,18 @@ pub struct CodexPromptConfig {
     pub is_applied: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_index: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::db_timestamp::lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub created_at: Option<Timestamp>,
+    #[serde(
+        default,
+        deserialize_with = "crate::db_timestamp::lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub updated_at: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -226,8 +240,8 @@ pub struct CodexPromptConfigContent {
     pub is_applied: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_index: Option<i32>,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
 }
 
 // ============================================================================
@@ -277,3 +291,91 @@ pub struct CodexAllApiHubProvidersResult {
 pub struct ResolveCodexAllApiHubProvidersRequest {
     pub provider_ids: Vec<String>,
 }
+
+// ============================================================================
+// Codex Auth Status Types
+// ============================================================================
+
+/// Parsed view of Codex auth.json plus the auth mode Codex will actually use
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexAuthStatus {
+    pub auth_path: String,
+    pub auth_file_exists: bool,
+    /// `auth_mode` stored in auth.json, or inferred from its content ("chatgpt" / "apikey")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_mode: Option<String>,
+    pub has_api_key: bool,
+    pub has_chatgpt_tokens: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    /// RFC3339 expiry of the ChatGPT access token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_expires_at: Option<String>,
+    pub token_expired: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_refresh: Option<String>,
+    /// `model_provider` from config.toml (None means the built-in "openai" provider)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_provider: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_auth_method: Option<String>,
+    /// Auth Codex will use with the current config: "chatgpt", "apikey", "provider" or "none"
+    pub effective_auth_mode: String,
+}
+
+// ============================================================================
+// Codex Config Preview Types
+// ============================================================================
+
+/// One key-level change between the current config.toml and the one an apply would write
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexConfigDiffEntry {
+    /// Dotted key path, e.g. `model_providers.foo.base_url`
+    pub path: String,
+    /// "added", "removed" or "changed"
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexConfigIssue {
+    pub path: String,
+    /// "error" blocks the apply from the tray unless pre-existing, "warning" is informational
+    pub severity: String,
+    pub message: String,
+    /// Also reported for the current config.toml, so not caused by this provider
+    #[serde(default)]
+    pub pre_existing: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexConfigPreview {
+    pub provider_id: String,
+    pub provider_name: String,
+    pub config_path: String,
+    pub current_config: String,
+    pub next_config: String,
+    pub diff: Vec<CodexConfigDiffEntry>,
+    pub issues: Vec<CodexConfigIssue>,
+    pub has_errors: bool,
+}
+
+/// Result of importing config.toml entries as providers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexImportResult {
+    pub imported: Vec<CodexProvider>,
+    /// Names skipped because a provider with the same name already exists
+    pub skipped: Vec<String>,
+}
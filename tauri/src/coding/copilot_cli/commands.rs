@@ -0,0 +1,94 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde_json::{Map, Value};
+
+use super::types::CopilotCliPaths;
+use crate::coding::tools::{builtin_tool_by_key, resolve_storage_path};
+
+const TOOL_KEY: &str = "github_copilot_cli";
+const MODEL_KEY: &str = "model";
+
+/// The CLI's config directory (`~/.copilot`), as declared by its built-in tool entry
+fn config_dir() -> Result<PathBuf, String> {
+    builtin_tool_by_key(TOOL_KEY)
+        .and_then(|tool| tool.relative_detect_dir)
+        .and_then(resolve_storage_path)
+        .ok_or_else(|| "Failed to resolve the GitHub Copilot CLI config directory".to_string())
+}
+
+fn config_path() -> Result<PathBuf, String> {
+    Ok(config_dir()?.join("config.json"))
+}
+
+fn storage_path(path: Option<&'static str>) -> String {
+    path.and_then(resolve_storage_path)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// The settings object of `config.json`, empty when the file does not exist yet
+fn read_config() -> Result<Map<String, Value>, String> {
+    let path = config_path()?;
+    if !path.exists() {
+        return Ok(Map::new());
+    }
+    let text = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    if text.trim().is_empty() {
+        return Ok(Map::new());
+    }
+    match serde_json::from_str::<Value>(&text)
+        .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?
+    {
+        Value::Object(map) => Ok(map),
+        _ => Err(format!("{} is not a JSON object", path.display())),
+    }
+}
+
+#[tauri::command]
+pub fn get_copilot_cli_paths() -> Result<CopilotCliPaths, String> {
+    let tool = builtin_tool_by_key(TOOL_KEY)
+        .ok_or_else(|| "GitHub Copilot CLI is not a known tool".to_string())?;
+    let config_dir = config_dir()?;
+    Ok(CopilotCliPaths {
+        installed: config_dir.exists(),
+        config_dir: config_dir.to_string_lossy().to_string(),
+        config_path: config_path()?.to_string_lossy().to_string(),
+        mcp_config_path: storage_path(tool.mcp_config_path),
+        skills_dir: storage_path(tool.relative_skills_dir),
+    })
+}
+
+/// The model the CLI starts with, or `None` for its default
+#[tauri::command]
+pub fn get_copilot_cli_model() -> Result<Option<String>, String> {
+    Ok(read_config()?
+        .get(MODEL_KEY)
+        .and_then(|v| v.as_str())
+        .map(String::from))
+}
+
+/// Set the model the CLI starts with; an empty value restores its default.
+/// Other settings in `config.json` are kept.
+#[tauri::command]
+pub fn set_copilot_cli_model(model: Option<String>) -> Result<(), String> {
+    let mut config = read_config()?;
+    let model = model
+        .map(|m| m.trim().to_string())
+        .filter(|m| !m.is_empty());
+    if let Some(model) = model {
+        config.insert(MODEL_KEY.to_string(), Value::String(model));
+    } else {
+        config.remove(MODEL_KEY);
+    }
+
+    let dir = config_dir()?;
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create directory {}: {}", dir.display(), e))?;
+    let path = config_path()?;
+    let text = serde_json::to_string_pretty(&Value::Object(config))
+        .map_err(|e| format!("Failed to serialize {}: {}", path.display(), e))?;
+    fs::write(&path, format!("{}\n", text))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
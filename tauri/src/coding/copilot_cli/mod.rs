@@ -0,0 +1,11 @@
+//! GitHub Copilot CLI Module
+//!
+//! The CLI's MCP servers (`~/.copilot/mcp-config.json`) and skills (`~/.copilot/skills`)
+//! are handled by the shared MCP and Skills modules through its built-in tool entry.
+//! This module reads and sets the model selected in `~/.copilot/config.json`.
+
+pub mod commands;
+pub mod types;
+
+pub use commands::*;
+pub use types::*;
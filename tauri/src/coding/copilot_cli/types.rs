@@ -0,0 +1,13 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopilotCliPaths {
+    pub config_dir: String,
+    /// Settings file holding the selected model
+    pub config_path: String,
+    pub mcp_config_path: String,
+    pub skills_dir: String,
+    /// Whether the config directory exists
+    pub installed: bool,
+}
@@ -0,0 +1,123 @@
+use std::fs;
+use std::path::PathBuf;
+
+use super::rules::{normalize_rule_name, parse_rule, render_rule, RULE_EXTENSION};
+use super::types::{CursorPaths, CursorRule, CursorRuleInput};
+use crate::coding::tools::{builtin_tool_by_key, resolve_storage_path};
+
+const TOOL_KEY: &str = "cursor";
+
+/// Cursor's config directory (`~/.cursor`), as declared by its built-in tool entry
+fn config_dir() -> Result<PathBuf, String> {
+    builtin_tool_by_key(TOOL_KEY)
+        .and_then(|tool| tool.relative_detect_dir)
+        .and_then(resolve_storage_path)
+        .ok_or_else(|| "Failed to resolve the Cursor config directory".to_string())
+}
+
+fn rules_dir() -> Result<PathBuf, String> {
+    Ok(config_dir()?.join("rules"))
+}
+
+fn rule_path(name: &str) -> Result<PathBuf, String> {
+    Ok(rules_dir()?.join(format!("{}.{}", name, RULE_EXTENSION)))
+}
+
+fn storage_path(path: Option<&'static str>) -> String {
+    path.and_then(resolve_storage_path)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+#[tauri::command]
+pub fn get_cursor_paths() -> Result<CursorPaths, String> {
+    let tool =
+        builtin_tool_by_key(TOOL_KEY).ok_or_else(|| "Cursor is not a known tool".to_string())?;
+    let config_dir = config_dir()?;
+    Ok(CursorPaths {
+        installed: config_dir.exists(),
+        config_dir: config_dir.to_string_lossy().to_string(),
+        mcp_config_path: storage_path(tool.mcp_config_path),
+        skills_dir: storage_path(tool.relative_skills_dir),
+        rules_dir: rules_dir()?.to_string_lossy().to_string(),
+    })
+}
+
+/// List the `.mdc` rules in the Cursor rules directory, sorted by name
+#[tauri::command]
+pub fn list_cursor_rules() -> Result<Vec<CursorRule>, String> {
+    let dir = rules_dir()?;
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries =
+        fs::read_dir(&dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+    let mut rules = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_file() || path.extension().is_none_or(|ext| ext != RULE_EXTENSION) {
+            continue;
+        }
+        let Some(name) = path.file_stem().map(|s| s.to_string_lossy().to_string()) else {
+            continue;
+        };
+        match fs::read_to_string(&path) {
+            Ok(text) => rules.push(parse_rule(&name, &text)),
+            Err(e) => log::warn!("Skipped Cursor rule {}: {}", path.display(), e),
+        }
+    }
+    rules.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    Ok(rules)
+}
+
+/// Create or update a rule; renaming moves the file when `original_name` differs
+#[tauri::command]
+pub fn save_cursor_rule(input: CursorRuleInput) -> Result<CursorRule, String> {
+    let name = normalize_rule_name(&input.name)?;
+    let original_name = input
+        .original_name
+        .as_deref()
+        .map(normalize_rule_name)
+        .transpose()?;
+    let path = rule_path(&name)?;
+
+    let renamed = original_name
+        .as_ref()
+        .is_some_and(|original| original != &name);
+    if (original_name.is_none() || renamed) && path.exists() {
+        return Err(format!("A rule named '{}' already exists", name));
+    }
+
+    let rule = CursorRule {
+        name,
+        description: input.description.filter(|d| !d.trim().is_empty()),
+        globs: input.globs.filter(|g| !g.trim().is_empty()),
+        always_apply: input.always_apply,
+        content: input.content,
+    };
+    let dir = rules_dir()?;
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create directory {}: {}", dir.display(), e))?;
+    fs::write(&path, render_rule(&rule))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    if let Some(original) = original_name.filter(|_| renamed) {
+        let original_path = rule_path(&original)?;
+        if original_path.exists() {
+            fs::remove_file(&original_path)
+                .map_err(|e| format!("Failed to remove {}: {}", original_path.display(), e))?;
+        }
+    }
+    Ok(rule)
+}
+
+#[tauri::command]
+pub fn delete_cursor_rule(name: String) -> Result<(), String> {
+    let path = rule_path(&normalize_rule_name(&name)?)?;
+    if path.exists() {
+        fs::remove_file(&path)
+            .map_err(|e| format!("Failed to remove {}: {}", path.display(), e))?;
+    }
+    Ok(())
+}
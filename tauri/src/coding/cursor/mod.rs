@@ -0,0 +1,12 @@
+//! Cursor Module
+//!
+//! Cursor's MCP servers (`~/.cursor/mcp.json`) and skills (`~/.cursor/skills`) are handled
+//! by the shared MCP and Skills modules through its built-in tool entry. This module adds
+//! the user rules directory (`~/.cursor/rules/*.mdc`) and reports the paths in use.
+
+pub mod commands;
+pub mod rules;
+pub mod types;
+
+pub use commands::*;
+pub use types::*;
@@ -0,0 +1,143 @@
+//! Reading and writing Cursor `.mdc` rule files
+//!
+//! A rule file starts with a small frontmatter block that Cursor writes itself:
+//! `description`, `globs` (comma separated, unquoted) and `alwaysApply`.
+
+use super::types::CursorRule;
+
+pub const RULE_EXTENSION: &str = "mdc";
+
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    let quoted = value.len() >= 2
+        && ((value.starts_with('"') && value.ends_with('"'))
+            || (value.starts_with('\'') && value.ends_with('\'')));
+    if quoted {
+        value[1..value.len() - 1].to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Validate a rule name and return it without the extension
+pub fn normalize_rule_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    let name = name
+        .strip_suffix(&format!(".{}", RULE_EXTENSION))
+        .unwrap_or(name)
+        .trim();
+    if name.is_empty() {
+        return Err("Rule name is required".to_string());
+    }
+    if name.starts_with('.') || name.contains(['/', '\\', ':']) {
+        return Err(format!("Invalid rule name: {}", name));
+    }
+    Ok(name.to_string())
+}
+
+/// Parse a rule file; files without frontmatter are taken as plain content
+pub fn parse_rule(name: &str, text: &str) -> CursorRule {
+    let mut rule = CursorRule {
+        name: name.to_string(),
+        description: None,
+        globs: None,
+        always_apply: false,
+        content: text.to_string(),
+    };
+
+    let mut lines = text.split_inclusive('\n');
+    if lines.next().map(str::trim) != Some("---") {
+        return rule;
+    }
+    let mut consumed = text.find('\n').map_or(text.len(), |i| i + 1);
+    let mut closed = false;
+    for line in lines {
+        consumed += line.len();
+        if line.trim() == "---" {
+            closed = true;
+            break;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        match key.trim() {
+            "description" => rule.description = non_empty(Some(unquote(value))),
+            "globs" => rule.globs = non_empty(Some(unquote(value))),
+            "alwaysApply" => rule.always_apply = value.trim().eq_ignore_ascii_case("true"),
+            _ => {}
+        }
+    }
+    if !closed {
+        return CursorRule {
+            description: None,
+            globs: None,
+            always_apply: false,
+            ..rule
+        };
+    }
+
+    rule.content = text[consumed..]
+        .trim_start_matches(['\r', '\n'])
+        .to_string();
+    rule
+}
+
+/// File content for a rule, in the layout Cursor writes
+pub fn render_rule(rule: &CursorRule) -> String {
+    let single_line = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(|v| v.split_whitespace().collect::<Vec<_>>().join(" "))
+            .unwrap_or_default()
+    };
+    format!(
+        "---\ndescription: {}\nglobs: {}\nalwaysApply: {}\n---\n\n{}\n",
+        single_line(&rule.description),
+        single_line(&rule.globs),
+        rule.always_apply,
+        rule.content.trim_end()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rule_frontmatter() {
+        let rule = parse_rule(
+            "rust",
+            "---\ndescription: Rust style\nglobs: *.rs,Cargo.toml\nalwaysApply: false\n---\n\nUse `?` over unwrap.\n",
+        );
+        assert_eq!(rule.description.as_deref(), Some("Rust style"));
+        assert_eq!(rule.globs.as_deref(), Some("*.rs,Cargo.toml"));
+        assert!(!rule.always_apply);
+        assert_eq!(rule.content, "Use `?` over unwrap.\n");
+        assert_eq!(parse_rule("rust", &render_rule(&rule)), rule);
+    }
+
+    #[test]
+    fn keeps_files_without_frontmatter() {
+        let rule = parse_rule("plain", "Always answer briefly.");
+        assert_eq!(rule.content, "Always answer briefly.");
+        assert_eq!(rule.description, None);
+
+        let unclosed = parse_rule("broken", "---\ndescription: x\nbody");
+        assert_eq!(unclosed.content, "---\ndescription: x\nbody");
+        assert_eq!(unclosed.description, None);
+    }
+
+    #[test]
+    fn validates_rule_names() {
+        assert_eq!(normalize_rule_name(" style.mdc "), Ok("style".to_string()));
+        assert!(normalize_rule_name("").is_err());
+        assert!(normalize_rule_name("../x").is_err());
+        assert!(normalize_rule_name(".hidden").is_err());
+    }
+}
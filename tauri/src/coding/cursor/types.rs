@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+/// A rule file in the Cursor rules directory
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CursorRule {
+    /// File name without the `.mdc` extension
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Comma separated patterns of the files the rule is attached to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub globs: Option<String>,
+    pub always_apply: bool,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CursorRuleInput {
+    pub name: String,
+    /// Current name when renaming an existing rule
+    #[serde(default)]
+    pub original_name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub globs: Option<String>,
+    #[serde(default)]
+    pub always_apply: bool,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CursorPaths {
+    pub config_dir: String,
+    pub mcp_config_path: String,
+    pub skills_dir: String,
+    pub rules_dir: String,
+    /// Whether the config directory exists
+    pub installed: bool,
+}
@@ -120,3 +120,45 @@ pub fn db_record_id(table: &str, id: &str) -> String {
         .collect();
     format!("{}:`{}`", table, clean)
 }
+
+/// Build one transaction that sets `sort_index` to each id's position in `ids`.
+///
+/// Drag-and-drop reorders are applied in a single round trip and either fully or
+/// not at all. With `touch_updated_at` the query expects a `$now` binding.
+///
+/// # Example
+/// ```rust
+/// let query = db_reorder_query("skill", &ids, false);
+/// db.query(query).await?.check()?;
+/// ```
+pub fn db_reorder_query(table: &str, ids: &[String], touch_updated_at: bool) -> String {
+    let mut query = String::from("BEGIN TRANSACTION;\n");
+    for (index, id) in ids.iter().enumerate() {
+        query.push_str(&format!(
+            "UPDATE {} SET sort_index = {}",
+            db_record_id(table, id),
+            index
+        ));
+        if touch_updated_at {
+            query.push_str(", updated_at = $now");
+        }
+        query.push_str(";\n");
+    }
+    query.push_str("COMMIT TRANSACTION;");
+    query
+}
+
+/// Build one transaction that clears `is_applied` on the table and sets it on `id`.
+///
+/// Running both updates together means a failure never leaves the table with no
+/// applied record. The query expects a `$now` binding.
+pub fn db_mark_applied_query(table: &str, id: &str) -> String {
+    format!(
+        "BEGIN TRANSACTION;\n\
+         UPDATE {} SET is_applied = false, updated_at = $now WHERE is_applied = true;\n\
+         UPDATE {} SET is_applied = true, updated_at = $now;\n\
+         COMMIT TRANSACTION;",
+        table,
+        db_record_id(table, id)
+    )
+}
@@ -4,7 +4,9 @@
 
 use serde_json::Value;
 
-use super::types::{FavoriteMcp, McpPreferences, McpServer, McpSyncDetail, McpSyncDetailDto};
+use super::types::{
+    FavoriteMcp, McpPreferences, McpProfile, McpServer, McpSyncDetail, McpSyncDetailDto,
+};
 use crate::coding::db_extract_id;
 
 /// Convert database record to McpServer struct
@@ -29,6 +31,16 @@ pub fn from_db_mcp_server(value: Value) -> McpServer {
         })
         .unwrap_or_default();
 
+    let project_paths: Vec<String> = value
+        .get("project_paths")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|item| item.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
     let sync_details = value.get("sync_details").cloned().filter(|v| !v.is_null());
 
     McpServer {
@@ -55,6 +67,11 @@ pub fn from_db_mcp_server(value: Value) -> McpServer {
             .map(|s| s.to_string()),
         tags,
         timeout: value.get("timeout").and_then(|v| v.as_i64()),
+        project_paths,
+        project_shared: value
+            .get("project_shared")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
         sort_index: value
             .get("sort_index")
             .and_then(|v| v.as_i64())
@@ -81,6 +98,8 @@ pub fn to_clean_mcp_server_payload(server: &McpServer) -> Value {
         "description": server.description,
         "tags": server.tags,
         "timeout": server.timeout,
+        "project_paths": server.project_paths,
+        "project_shared": server.project_shared,
         "sort_index": server.sort_index,
         "created_at": server.created_at,
         "updated_at": server.updated_at,
@@ -244,3 +263,37 @@ pub fn from_db_favorite_mcp(value: Value) -> FavoriteMcp {
             .unwrap_or(0),
     }
 }
+
+fn string_list(value: &Value, key: &str) -> Vec<String> {
+    value
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|item| item.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Convert database record to McpProfile struct
+pub fn from_db_mcp_profile(value: Value) -> McpProfile {
+    McpProfile {
+        id: db_extract_id(&value),
+        name: value
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        server_ids: string_list(&value, "server_ids"),
+        tool_keys: string_list(&value, "tool_keys"),
+        created_at: value
+            .get("created_at")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(0),
+        updated_at: value
+            .get("updated_at")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(0),
+    }
+}
@@ -4,17 +4,22 @@
 
 use tauri::{AppHandle, Emitter, Runtime, State};
 
-use super::adapter::parse_sync_details_dto;
+use super::adapter::{parse_sync_details_dto, remove_sync_detail, set_sync_detail};
 use super::config_sync::{
-    import_servers_from_path, import_servers_from_plugin_mcp_json, import_servers_from_tool_async,
-    remove_server_from_tool_async, sync_server_to_tool_async,
-    sync_server_to_tool_with_enabled_async,
+    apply_servers_to_path, import_servers_from_path, import_servers_from_plugin_mcp_json,
+    import_servers_from_tool_async, preview_export_to_path, remove_server_from_tool_async,
+    sync_server_to_tool_async, sync_server_to_tool_with_enabled_async, CLAUDE_CODE_TOOL_KEY,
 };
+use super::health;
 use super::mcp_store;
+use super::registry;
+use super::secrets;
+use super::server_logs;
 use super::types::{
     now_ms, CreateMcpServerInput, FavoriteMcp, FavoriteMcpDto, FavoriteMcpInput,
-    McpDiscoveredServerDto, McpImportResultDto, McpScanResultDto, McpServer, McpServerDto,
-    McpSyncDetail, McpSyncResultDto, UpdateMcpServerInput,
+    McpDiscoveredServerDto, McpExportPreviewDto, McpImportResultDto, McpProfile, McpProfileInput,
+    McpRegistryPageDto, McpScanResultDto, McpSecretDto, McpServer, McpServerDto,
+    McpServerLogLineDto, McpSyncDetail, McpSyncResultDto, McpTestResultDto, UpdateMcpServerInput,
 };
 use crate::coding::tools::{
     custom_store, get_mcp_runtime_tools, is_tool_installed_with_db_async,
@@ -42,6 +47,8 @@ pub async fn mcp_list_servers(state: State<'_, DbState>) -> Result<Vec<McpServer
             description: s.description.clone(),
             tags: s.tags.clone(),
             timeout: s.timeout,
+            project_paths: s.project_paths.clone(),
+            project_shared: s.project_shared,
             sort_index: s.sort_index,
             created_at: s.created_at,
             updated_at: s.updated_at,
@@ -68,6 +75,8 @@ pub async fn mcp_create_server<R: Runtime>(
         description: input.description,
         tags: input.tags,
         timeout: input.timeout,
+        project_paths: input.project_paths,
+        project_shared: input.project_shared,
         sort_index: 0, // Will be assigned by upsert
         created_at: now,
         updated_at: now,
@@ -124,6 +133,8 @@ pub async fn mcp_create_server<R: Runtime>(
         description: created.description,
         tags: created.tags,
         timeout: created.timeout,
+        project_paths: created.project_paths,
+        project_shared: created.project_shared,
         sort_index: created.sort_index,
         created_at: created.created_at,
         updated_at: created.updated_at,
@@ -143,6 +154,7 @@ pub async fn mcp_update_server<R: Runtime>(
     let mut server = mcp_store::get_mcp_server_by_id(&state, &serverId)
         .await?
         .ok_or_else(|| format!("MCP server not found: {}", serverId))?;
+    let previous = server.clone();
 
     // Apply updates
     if let Some(name) = input.name {
@@ -164,6 +176,12 @@ pub async fn mcp_update_server<R: Runtime>(
         server.tags = tags;
     }
     server.timeout = input.timeout;
+    if let Some(project_paths) = input.project_paths {
+        server.project_paths = project_paths;
+    }
+    if let Some(project_shared) = input.project_shared {
+        server.project_shared = project_shared;
+    }
     server.updated_at = now_ms();
 
     mcp_store::upsert_mcp_server(&state, &server).await?;
@@ -173,6 +191,20 @@ pub async fn mcp_update_server<R: Runtime>(
         .await
         .unwrap_or_default();
     let db = state.db();
+
+    // Drop the entries of the previous project scope; the re-sync below writes the new ones
+    let scope_changed = previous.project_paths != server.project_paths
+        || previous.project_shared != server.project_shared;
+    if scope_changed
+        && previous
+            .enabled_tools
+            .iter()
+            .any(|t| t == CLAUDE_CODE_TOOL_KEY)
+    {
+        if let Some(tool) = runtime_tool_by_key(CLAUDE_CODE_TOOL_KEY, &custom_tools) {
+            let _ = remove_server_from_tool_async(&db, &previous, &tool).await;
+        }
+    }
     for tool_key in &server.enabled_tools {
         if let Some(tool) = runtime_tool_by_key(tool_key, &custom_tools) {
             if is_tool_installed_with_db_async(&db, &tool).await {
@@ -217,6 +249,8 @@ pub async fn mcp_update_server<R: Runtime>(
         description: updated.description,
         tags: updated.tags,
         timeout: updated.timeout,
+        project_paths: updated.project_paths,
+        project_shared: updated.project_shared,
         sort_index: updated.sort_index,
         created_at: updated.created_at,
         updated_at: updated.updated_at,
@@ -240,7 +274,7 @@ pub async fn mcp_delete_server<R: Runtime>(
         let db = state.db();
         for tool_key in &server.enabled_tools {
             if let Some(tool) = runtime_tool_by_key(tool_key, &custom_tools) {
-                let _ = remove_server_from_tool_async(&db, &server.name, &tool).await;
+                let _ = remove_server_from_tool_async(&db, &server, &tool).await;
             }
         }
         // Also remove from opencode if sync_disabled is ON
@@ -248,6 +282,7 @@ pub async fn mcp_delete_server<R: Runtime>(
     }
 
     mcp_store::delete_mcp_server(&state, &serverId).await?;
+    server_logs::clear_server_logs(&serverId);
 
     // Emit mcp-changed for WSL sync
     let _ = app.emit("config-changed", "window");
@@ -256,6 +291,28 @@ pub async fn mcp_delete_server<R: Runtime>(
     Ok(())
 }
 
+/// Test an MCP server: run the initialize handshake and list its tools
+///
+/// Connection and protocol failures are returned in the result, not as an error.
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn mcp_test_server(
+    state: State<'_, DbState>,
+    serverId: String,
+) -> Result<McpTestResultDto, String> {
+    let server = mcp_store::get_mcp_server_by_id(&state, &serverId)
+        .await?
+        .ok_or_else(|| format!("MCP server not found: {}", serverId))?;
+    Ok(health::test_server(&state, &server).await)
+}
+
+/// Output captured from a stdio server during its latest test run
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn mcp_get_server_logs(serverId: String) -> Result<Vec<McpServerLogLineDto>, String> {
+    Ok(server_logs::get_server_logs(&serverId))
+}
+
 /// Toggle a tool's enabled state for an MCP server
 #[tauri::command]
 #[allow(non_snake_case)]
@@ -308,10 +365,10 @@ pub async fn mcp_toggle_tool<R: Runtime>(
                 // Write with enabled=false instead of removing
                 let _ = sync_server_to_tool_with_enabled_async(&db, &server, &tool, false).await;
             } else {
-                let _ = remove_server_from_tool_async(&db, &server.name, &tool).await;
+                let _ = remove_server_from_tool_async(&db, &server, &tool).await;
             }
         } else {
-            let _ = remove_server_from_tool_async(&db, &server.name, &tool).await;
+            let _ = remove_server_from_tool_async(&db, &server, &tool).await;
         }
         mcp_store::delete_sync_detail(&state, &serverId, &toolKey).await?;
     }
@@ -394,6 +451,43 @@ pub async fn mcp_sync_to_tool<R: Runtime>(
     Ok(results)
 }
 
+/// Preview what syncing all servers to a tool would change in its config file
+///
+/// Covers the same servers as `mcp_sync_to_tool` (plus the disabled ones written to
+/// OpenCode when that preference is on); nothing is written.
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn mcp_preview_export(
+    state: State<'_, DbState>,
+    toolKey: String,
+) -> Result<McpExportPreviewDto, String> {
+    let custom_tools = custom_store::get_custom_tools(&state)
+        .await
+        .unwrap_or_default();
+    let tool = runtime_tool_by_key(&toolKey, &custom_tools)
+        .ok_or_else(|| format!("Tool not found: {}", toolKey))?;
+    let db = state.db();
+    let config_path = resolve_mcp_config_path_with_db_async(&db, &tool)
+        .await
+        .ok_or_else(|| format!("Tool {} does not support MCP", toolKey))?;
+
+    let servers = mcp_store::get_mcp_servers(&state).await?;
+    let prefs = mcp_store::get_mcp_preferences(&state)
+        .await
+        .unwrap_or_default();
+    let include_disabled = toolKey == "opencode" && prefs.sync_disabled_to_opencode;
+    let all_server_names: Vec<String> = servers.iter().map(|s| s.name.clone()).collect();
+    let exported: Vec<(McpServer, bool)> = servers
+        .into_iter()
+        .filter_map(|server| {
+            let enabled = server.enabled_tools.contains(&toolKey);
+            (enabled || include_disabled).then_some((server, enabled))
+        })
+        .collect();
+
+    preview_export_to_path(&tool, &config_path, &exported, &all_server_names)
+}
+
 /// Sync all servers to all enabled tools
 #[tauri::command]
 pub async fn mcp_sync_all<R: Runtime>(
@@ -764,6 +858,39 @@ async fn mcp_scan_servers_inner(state: &DbState) -> Result<McpScanResultDto, Str
     Ok(scan_result)
 }
 
+// ==================== Registry ====================
+
+/// Search the public MCP registry; entries carry install options for `mcp_create_server`
+#[tauri::command]
+pub async fn mcp_browse_registry(
+    state: State<'_, DbState>,
+    query: String,
+    cursor: Option<String>,
+) -> Result<McpRegistryPageDto, String> {
+    registry::browse_registry(&state, &query, cursor.as_deref()).await
+}
+
+// ==================== Secrets ====================
+
+/// List the secrets referenced by server configs and whether the keychain holds them
+#[tauri::command]
+pub async fn mcp_list_secrets(state: State<'_, DbState>) -> Result<Vec<McpSecretDto>, String> {
+    let servers = mcp_store::get_mcp_servers(&state).await?;
+    Ok(secrets::list_secrets(&servers))
+}
+
+/// Store a secret in the OS keychain (an empty value removes it)
+///
+/// Configs already exported keep the old value until the servers are synced again.
+#[tauri::command]
+pub async fn mcp_set_secret(
+    state: State<'_, DbState>,
+    name: String,
+    value: String,
+) -> Result<(), String> {
+    secrets::set_secret(&state, &name, &value).await
+}
+
 // ==================== Preferences ====================
 
 /// Get MCP show in tray setting
@@ -876,7 +1003,7 @@ async fn maybe_remove_disabled_from_opencode(
     }
     if let Some(tool) = runtime_tool_by_key("opencode", custom_tools) {
         let db = state.db();
-        let _ = remove_server_from_tool_async(&db, &server.name, &tool).await;
+        let _ = remove_server_from_tool_async(&db, &server, &tool).await;
     }
 }
 
@@ -910,7 +1037,7 @@ async fn cleanup_opencode_disabled(
     };
     for server in servers {
         if !server.enabled_tools.contains(&"opencode".to_string()) {
-            let _ = remove_server_from_tool_async(db, &server.name, &tool).await;
+            let _ = remove_server_from_tool_async(db, server, &tool).await;
         }
     }
 }
@@ -1157,3 +1284,162 @@ pub async fn mcp_init_default_favorites(state: State<'_, DbState>) -> Result<usi
 
     Ok(presets.len())
 }
+
+// ==================== Profiles ====================
+
+/// List MCP profiles
+#[tauri::command]
+pub async fn mcp_list_profiles(state: State<'_, DbState>) -> Result<Vec<McpProfile>, String> {
+    mcp_store::get_mcp_profiles(&state).await
+}
+
+/// Create or update an MCP profile (matched by name)
+#[tauri::command]
+pub async fn mcp_save_profile(
+    state: State<'_, DbState>,
+    input: McpProfileInput,
+) -> Result<McpProfile, String> {
+    let name = input.name.trim().to_string();
+    if name.is_empty() {
+        return Err("Profile name must not be empty".to_string());
+    }
+    let now = now_ms();
+    let existing = mcp_store::get_mcp_profile_by_name(&state, &name).await?;
+
+    let mut profile = McpProfile {
+        id: existing.as_ref().map(|p| p.id.clone()).unwrap_or_default(),
+        name,
+        server_ids: input.server_ids,
+        tool_keys: input.tool_keys,
+        created_at: existing.map(|p| p.created_at).unwrap_or(now),
+        updated_at: now,
+    };
+    profile.id = mcp_store::upsert_mcp_profile(&state, &profile).await?;
+    Ok(profile)
+}
+
+/// Delete an MCP profile
+#[tauri::command]
+pub async fn mcp_delete_profile(state: State<'_, DbState>, name: String) -> Result<(), String> {
+    if let Some(profile) = mcp_store::get_mcp_profile_by_name(&state, &name).await? {
+        mcp_store::delete_mcp_profile(&state, &profile.id).await?;
+    }
+    Ok(())
+}
+
+/// Enable exactly the servers of a profile for each of its tools
+///
+/// Each tool's config file is written once: the profile's servers are added, the other
+/// servers enabled for the tool are removed (or written disabled to OpenCode when
+/// `sync_disabled_to_opencode` is on). Tools that aren't installed are skipped.
+#[tauri::command]
+pub async fn mcp_apply_profile<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, DbState>,
+    name: String,
+) -> Result<Vec<McpSyncResultDto>, String> {
+    let profile = mcp_store::get_mcp_profile_by_name(&state, &name)
+        .await?
+        .ok_or_else(|| format!("MCP profile not found: {}", name))?;
+    let custom_tools = custom_store::get_custom_tools(&state)
+        .await
+        .unwrap_or_default();
+    let prefs = mcp_store::get_mcp_preferences(&state)
+        .await
+        .unwrap_or_default();
+    let db = state.db();
+    let mut servers = mcp_store::get_mcp_servers(&state).await?;
+    let originals: Vec<(Vec<String>, Option<serde_json::Value>)> = servers
+        .iter()
+        .map(|s| (s.enabled_tools.clone(), s.sync_details.clone()))
+        .collect();
+    let mut results = Vec::new();
+
+    for tool_key in &profile.tool_keys {
+        let Some(tool) = runtime_tool_by_key(tool_key, &custom_tools) else {
+            continue;
+        };
+        if !is_tool_installed_with_db_async(&db, &tool).await {
+            continue;
+        }
+        let Some(config_path) = resolve_mcp_config_path_with_db_async(&db, &tool).await else {
+            continue;
+        };
+
+        let write_disabled = tool_key == "opencode" && prefs.sync_disabled_to_opencode;
+        let mut upserts = Vec::new();
+        let mut removals = Vec::new();
+        for server in &servers {
+            let in_profile = profile.server_ids.contains(&server.id);
+            if in_profile || write_disabled {
+                upserts.push((server.clone(), in_profile));
+            } else if server.enabled_tools.contains(tool_key) {
+                removals.push(server.clone());
+            }
+        }
+
+        let failed = match apply_servers_to_path(&tool, &config_path, &upserts, &removals) {
+            Ok(failed) => failed,
+            Err(e) => {
+                results.push(McpSyncResultDto {
+                    tool: tool_key.clone(),
+                    success: false,
+                    error_message: Some(e),
+                });
+                continue;
+            }
+        };
+
+        for server in servers.iter_mut() {
+            let in_profile = profile.server_ids.contains(&server.id);
+            let was_enabled = server.enabled_tools.contains(tool_key);
+            server.enabled_tools.retain(|t| t != tool_key);
+            if !in_profile {
+                if was_enabled {
+                    server.sync_details = Some(remove_sync_detail(&server.sync_details, tool_key));
+                }
+                continue;
+            }
+            server.enabled_tools.push(tool_key.clone());
+            let error = failed
+                .iter()
+                .find(|(id, _)| *id == server.id)
+                .map(|(_, e)| e.clone());
+            let detail = McpSyncDetail {
+                tool: tool_key.clone(),
+                status: if error.is_some() { "error" } else { "ok" }.to_string(),
+                synced_at: Some(now_ms()),
+                error_message: error,
+            };
+            server.sync_details = Some(set_sync_detail(&server.sync_details, tool_key, &detail));
+        }
+        results.push(McpSyncResultDto {
+            tool: tool_key.clone(),
+            success: failed.is_empty(),
+            error_message: (!failed.is_empty()).then(|| {
+                failed
+                    .iter()
+                    .map(|(_, e)| e.as_str())
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            }),
+        });
+    }
+
+    // Write every changed server at once so a failure does not leave the profile half applied
+    let changed: Vec<&McpServer> = servers
+        .iter()
+        .zip(&originals)
+        .filter(|(server, (tools, details))| {
+            server.enabled_tools != *tools || server.sync_details != *details
+        })
+        .map(|(server, _)| server)
+        .collect();
+    mcp_store::save_servers_sync_state(&state, &changed).await?;
+
+    // Emit config-changed and mcp-changed events
+    let _ = app.emit("config-changed", "window");
+    let _ = app.emit("mcp-changed", "window");
+
+    Ok(results)
+}
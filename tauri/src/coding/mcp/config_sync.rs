@@ -4,18 +4,22 @@
 //! Supports JSON/JSONC (unified with json5) and TOML formats.
 //! Also handles format conversion for tools like OpenCode that use different schemas.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use serde_json::Value;
 
 use super::command_normalize;
 use super::format_configs::get_format_config;
-use super::types::{now_ms, McpServer, McpSyncDetail};
+use super::secrets;
+use super::types::{now_ms, McpExportPreviewDto, McpServer, McpSyncDetail};
 use crate::coding::tools::{
     resolve_mcp_config_path_with_db, resolve_mcp_config_path_with_db_async, McpFormatConfig,
     RuntimeTool,
 };
 
+/// Claude Code is the only tool with project-level MCP entries
+pub const CLAUDE_CODE_TOOL_KEY: &str = "claude_code";
+
 /// Sync an MCP server to a specific tool's config file
 pub fn sync_server_to_tool(
     db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
@@ -58,25 +62,30 @@ pub async fn sync_server_to_tool_with_enabled_async(
 }
 
 /// Remove an MCP server from a specific tool's config file
+/// (for Claude Code also from the project entries of its project scope)
 pub fn remove_server_from_tool(
     db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
-    server_name: &str,
+    server: &McpServer,
     tool: &RuntimeTool,
 ) -> Result<(), String> {
     let config_path = resolve_mcp_config_path_with_db(db, tool)
         .ok_or_else(|| format!("Tool {} does not support MCP", tool.key))?;
-    remove_server_from_path(tool, &config_path, server_name)
+    remove_server_from_path(tool, &config_path, server)
 }
 
 pub async fn remove_server_from_tool_async(
     db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
-    server_name: &str,
+    server: &McpServer,
     tool: &RuntimeTool,
 ) -> Result<(), String> {
     let config_path = resolve_mcp_config_path_with_db_async(db, tool)
         .await
         .ok_or_else(|| format!("Tool {} does not support MCP", tool.key))?;
-    remove_server_from_path(tool, &config_path, server_name)
+    remove_server_from_path(tool, &config_path, server)
+}
+
+fn is_project_scoped(tool: &RuntimeTool, server: &McpServer) -> bool {
+    tool.key == CLAUDE_CODE_TOOL_KEY && !server.project_paths.is_empty()
 }
 
 fn sync_server_to_path(
@@ -88,8 +97,13 @@ fn sync_server_to_path(
     let format = tool.mcp_config_format.as_deref().unwrap_or("json");
     let field = tool.mcp_field.as_deref().unwrap_or("mcpServers");
     let format_config = get_format_config(&tool.key);
+    let server = secrets::resolve_server_secrets(server)?;
+    let server = server.as_ref();
 
     match format {
+        "json" | "jsonc" if is_project_scoped(tool, server) => {
+            sync_server_to_claude_projects(config_path, server, field, format_config, enabled)
+        }
         // json5 handles both standard JSON and JSONC (with comments, trailing commas)
         "json" | "jsonc" => sync_server_to_json(
             config_path,
@@ -114,19 +128,458 @@ fn sync_server_to_path(
 fn remove_server_from_path(
     tool: &RuntimeTool,
     config_path: &PathBuf,
-    server_name: &str,
+    server: &McpServer,
 ) -> Result<(), String> {
     let format = tool.mcp_config_format.as_deref().unwrap_or("json");
     let field = tool.mcp_field.as_deref().unwrap_or("mcpServers");
 
+    if is_project_scoped(tool, server) {
+        remove_server_from_claude_projects(config_path, &server.name, &server.project_paths)?;
+    }
     match format {
         // json5 handles both standard JSON and JSONC (with comments, trailing commas)
-        "json" | "jsonc" => remove_server_from_json(config_path, server_name, field),
-        "toml" => remove_server_from_toml(config_path, server_name, field),
+        "json" | "jsonc" => remove_server_from_json(config_path, &server.name, field),
+        "toml" => remove_server_from_toml(config_path, &server.name, field),
         _ => Err(format!("Unsupported config format: {}", format)),
     }
 }
 
+// ==================== Batch apply ====================
+
+/// Write several servers to a tool's config file and remove others, reading and writing
+/// the file once
+///
+/// `upserts` pairs each server with its enabled state. Servers that can't be written
+/// (e.g. a referenced secret is missing) are skipped and returned as `(server_id, error)`;
+/// file-level failures are returned as `Err`.
+pub fn apply_servers_to_path(
+    tool: &RuntimeTool,
+    config_path: &Path,
+    upserts: &[(McpServer, bool)],
+    removals: &[McpServer],
+) -> Result<Vec<(String, String)>, String> {
+    let format = tool.mcp_config_format.as_deref().unwrap_or("json");
+    let field = tool.mcp_field.as_deref().unwrap_or("mcpServers");
+    let format_config = get_format_config(&tool.key);
+    let mut failed = Vec::new();
+
+    match format {
+        "json" | "jsonc" => {
+            let mut config = read_json_config(config_path)?;
+            // Shared project entries live in other files, written after the main config
+            let mut project_writes = Vec::new();
+            for (server, enabled) in upserts {
+                let outcome = secrets::resolve_server_secrets(server).and_then(|resolved| {
+                    if is_project_scoped(tool, server) {
+                        let server_config = build_json_server_config(
+                            &resolved,
+                            format_config,
+                            *enabled,
+                            &tool.key,
+                        )?;
+                        apply_server_to_claude_projects(
+                            &mut config,
+                            &resolved,
+                            field,
+                            &server_config,
+                        )?;
+                        project_writes.push((server, server_config));
+                        Ok(())
+                    } else {
+                        apply_server_to_json(
+                            &mut config,
+                            &resolved,
+                            field,
+                            format_config,
+                            *enabled,
+                            &tool.key,
+                        )
+                    }
+                });
+                if let Err(e) = outcome {
+                    failed.push((server.id.clone(), e));
+                }
+            }
+            for server in removals {
+                if is_project_scoped(tool, server) {
+                    remove_server_from_claude_projects_config(
+                        &mut config,
+                        &server.name,
+                        &server.project_paths,
+                    );
+                }
+                if let Some(servers) = get_json_value_by_path_mut(&mut config, field)
+                    .and_then(|servers| servers.as_object_mut())
+                {
+                    servers.remove(&server.name);
+                }
+            }
+            write_json_config(config_path, &config)?;
+
+            for (server, server_config) in project_writes {
+                if let Err(e) = sync_server_to_project_mcp_json(server, &server_config) {
+                    failed.push((server.id.clone(), e));
+                }
+            }
+            for server in removals.iter().filter(|s| is_project_scoped(tool, s)) {
+                for project_path in &server.project_paths {
+                    let mcp_json = Path::new(project_path.trim()).join(".mcp.json");
+                    remove_server_from_mcp_json(&mcp_json, &server.name)?;
+                }
+            }
+        }
+        "toml" => {
+            let mut doc = if config_path.exists() {
+                let content = std::fs::read_to_string(config_path)
+                    .map_err(|e| format!("Failed to read config file: {}", e))?;
+                if content.trim().is_empty() {
+                    toml_edit::DocumentMut::new()
+                } else {
+                    content
+                        .parse::<toml_edit::DocumentMut>()
+                        .map_err(|e| format!("Failed to parse TOML config: {}", e))?
+                }
+            } else {
+                toml_edit::DocumentMut::new()
+            };
+            for (server, _) in upserts {
+                let outcome = secrets::resolve_server_secrets(server)
+                    .and_then(|resolved| apply_server_to_toml(&mut doc, &resolved, field));
+                if let Err(e) = outcome {
+                    failed.push((server.id.clone(), e));
+                }
+            }
+            if let Some(servers) = doc.get_mut(field).and_then(|s| s.as_table_mut()) {
+                for server in removals {
+                    servers.remove(&server.name);
+                }
+            }
+            if let Some(parent) = config_path.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create config directory: {}", e))?;
+            }
+            std::fs::write(config_path, doc.to_string())
+                .map_err(|e| format!("Failed to write config file: {}", e))?;
+        }
+        _ => return Err(format!("Unsupported config format: {}", format)),
+    }
+    Ok(failed)
+}
+
+// ==================== Export preview ====================
+
+/// Shown in export previews instead of secret values
+const SECRET_MASK: &str = "********";
+
+/// Diff of a tool's config file against what exporting `servers` would write
+///
+/// `servers` pairs each exported server with its enabled state; `all_server_names` are the
+/// servers the app manages, used to report entries of the file the export leaves alone.
+pub fn preview_export_to_path(
+    tool: &RuntimeTool,
+    config_path: &Path,
+    servers: &[(McpServer, bool)],
+    all_server_names: &[String],
+) -> Result<McpExportPreviewDto, String> {
+    let format = tool.mcp_config_format.as_deref().unwrap_or("json");
+    let field = tool.mcp_field.as_deref().unwrap_or("mcpServers");
+    let format_config = get_format_config(&tool.key);
+
+    let current = if config_path.exists() {
+        std::fs::read_to_string(config_path)
+            .map_err(|e| format!("Failed to read config file: {}", e))?
+    } else {
+        String::new()
+    };
+
+    let mut errors = Vec::new();
+    let mut masked_values = Vec::new();
+    let mut project_files = Vec::new();
+    let (exported, config_names) = match format {
+        "json" | "jsonc" => {
+            let mut config = read_json_config(config_path)?;
+            for (server, enabled) in servers {
+                let resolved = match secrets::resolve_server_secrets(server) {
+                    Ok(resolved) => resolved,
+                    Err(e) => {
+                        errors.push(e);
+                        continue;
+                    }
+                };
+                masked_values.extend(secrets::resolved_secret_values(&server.server_config));
+                let outcome = if is_project_scoped(tool, server) {
+                    if server.project_shared {
+                        project_files.extend(server.project_paths.iter().map(|path| {
+                            Path::new(path.trim())
+                                .join(".mcp.json")
+                                .to_string_lossy()
+                                .to_string()
+                        }));
+                    }
+                    build_json_server_config(&resolved, format_config, *enabled, &tool.key)
+                        .and_then(|server_config| {
+                            apply_server_to_claude_projects(
+                                &mut config,
+                                &resolved,
+                                field,
+                                &server_config,
+                            )
+                        })
+                } else {
+                    apply_server_to_json(
+                        &mut config,
+                        &resolved,
+                        field,
+                        format_config,
+                        *enabled,
+                        &tool.key,
+                    )
+                };
+                if let Err(e) = outcome {
+                    errors.push(format!("{}: {}", server.name, e));
+                }
+            }
+            let names: Vec<String> = get_json_value_by_path_mut(&mut config, field)
+                .and_then(|servers| servers.as_object())
+                .map(|servers| servers.keys().cloned().collect())
+                .unwrap_or_default();
+            let content = serde_json::to_string_pretty(&config)
+                .map_err(|e| format!("Failed to serialize config: {}", e))?;
+            (content, names)
+        }
+        "toml" => {
+            let mut doc = if current.trim().is_empty() {
+                toml_edit::DocumentMut::new()
+            } else {
+                current
+                    .parse::<toml_edit::DocumentMut>()
+                    .map_err(|e| format!("Failed to parse TOML config: {}", e))?
+            };
+            for (server, _) in servers {
+                let outcome = secrets::resolve_server_secrets(server)
+                    .and_then(|resolved| apply_server_to_toml(&mut doc, &resolved, field));
+                match outcome {
+                    Ok(()) => {
+                        masked_values.extend(secrets::resolved_secret_values(&server.server_config))
+                    }
+                    Err(e) => errors.push(format!("{}: {}", server.name, e)),
+                }
+            }
+            let names: Vec<String> = doc
+                .get(field)
+                .and_then(|servers| servers.as_table())
+                .map(|servers| servers.iter().map(|(name, _)| name.to_string()).collect())
+                .unwrap_or_default();
+            (doc.to_string(), names)
+        }
+        _ => return Err(format!("Unsupported config format: {}", format)),
+    };
+
+    let changed = current.trim_end() != exported.trim_end();
+    let mut diff = if changed {
+        crate::coding::line_diff(&current, &exported)
+    } else {
+        String::new()
+    };
+    for value in masked_values.iter().filter(|v| !v.is_empty()) {
+        diff = diff.replace(value.as_str(), SECRET_MASK);
+    }
+
+    Ok(McpExportPreviewDto {
+        tool: tool.key.clone(),
+        config_path: config_path.to_string_lossy().to_string(),
+        changed,
+        diff,
+        unmanaged_servers: config_names
+            .into_iter()
+            .filter(|name| !all_server_names.contains(name))
+            .collect(),
+        project_files,
+        errors,
+    })
+}
+
+// ==================== Claude Code project scope ====================
+
+/// Key of a project in `~/.claude.json` `projects` (Claude Code uses forward slashes)
+fn claude_project_key(project_path: &str) -> String {
+    let trimmed = project_path.trim().trim_end_matches(['/', '\\']);
+    if cfg!(windows) {
+        trimmed.replace('\\', "/")
+    } else {
+        trimmed.to_string()
+    }
+}
+
+fn read_json_config(config_path: &Path) -> Result<Value, String> {
+    if !config_path.exists() {
+        return Ok(serde_json::json!({}));
+    }
+    let content = std::fs::read_to_string(config_path)
+        .map_err(|e| format!("Failed to read config file: {}", e))?;
+    let content = content.trim();
+    if content.is_empty() {
+        return Ok(serde_json::json!({}));
+    }
+    json5::from_str(content).map_err(|e| format!("Failed to parse config file: {}", e))
+}
+
+fn write_json_config(config_path: &Path, config: &Value) -> Result<(), String> {
+    if let Some(parent) = config_path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    }
+    let content = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize config: {}", e))?;
+    std::fs::write(config_path, content).map_err(|e| format!("Failed to write config file: {}", e))
+}
+
+/// `projects.<key>.mcpServers` of `~/.claude.json`; project keys contain dots, so the
+/// dotted field helpers can't address it
+fn claude_project_servers<'a>(
+    config: &'a mut Value,
+    project_key: &str,
+) -> Result<&'a mut serde_json::Map<String, Value>, String> {
+    let projects = ensure_json_object_path(config, "projects")?;
+    let project = projects
+        .as_object_mut()
+        .ok_or("projects is not a JSON object")?
+        .entry(project_key.to_string())
+        .or_insert_with(|| serde_json::json!({}));
+    let servers = project
+        .as_object_mut()
+        .ok_or_else(|| format!("projects.{} is not a JSON object", project_key))?
+        .entry("mcpServers".to_string())
+        .or_insert_with(|| serde_json::json!({}));
+    servers
+        .as_object_mut()
+        .ok_or_else(|| format!("projects.{}.mcpServers is not a JSON object", project_key))
+}
+
+/// Write a project-scoped server to each of its projects and drop the global entry
+///
+/// Private entries go to `projects.<path>.mcpServers` in `~/.claude.json`, shared ones to
+/// `<path>/.mcp.json`; the entry in the other location is removed so switching is clean.
+fn sync_server_to_claude_projects(
+    config_path: &PathBuf,
+    server: &McpServer,
+    field: &str,
+    format_config: Option<&McpFormatConfig>,
+    enabled: bool,
+) -> Result<(), String> {
+    let server_config =
+        build_json_server_config(server, format_config, enabled, CLAUDE_CODE_TOOL_KEY)?;
+
+    let mut config = read_json_config(config_path)?;
+    apply_server_to_claude_projects(&mut config, server, field, &server_config)?;
+    write_json_config(config_path, &config)?;
+
+    sync_server_to_project_mcp_json(server, &server_config)
+}
+
+/// `.mcp.json` part of a project-scoped sync: write shared entries, drop private ones
+fn sync_server_to_project_mcp_json(
+    server: &McpServer,
+    server_config: &Value,
+) -> Result<(), String> {
+    for project_path in &server.project_paths {
+        let mcp_json = Path::new(project_path.trim()).join(".mcp.json");
+        if server.project_shared {
+            let mut project_config = read_json_config(&mcp_json)?;
+            ensure_json_object_path(&mut project_config, "mcpServers")?
+                .as_object_mut()
+                .ok_or("mcpServers is not a JSON object")?
+                .insert(server.name.clone(), server_config.clone());
+            write_json_config(&mcp_json, &project_config)?;
+        } else {
+            remove_server_from_mcp_json(&mcp_json, &server.name)?;
+        }
+    }
+    Ok(())
+}
+
+/// `~/.claude.json` part of a project-scoped sync: drop the global entry and set the
+/// private project entries
+fn apply_server_to_claude_projects(
+    config: &mut Value,
+    server: &McpServer,
+    field: &str,
+    server_config: &Value,
+) -> Result<(), String> {
+    if let Some(servers) =
+        get_json_value_by_path_mut(config, field).and_then(|servers| servers.as_object_mut())
+    {
+        servers.remove(&server.name);
+    }
+    for project_path in &server.project_paths {
+        let project_servers = claude_project_servers(config, &claude_project_key(project_path))?;
+        if server.project_shared {
+            project_servers.remove(&server.name);
+        } else {
+            project_servers.insert(server.name.clone(), server_config.clone());
+        }
+    }
+    Ok(())
+}
+
+/// Remove a server from a project's `.mcp.json`, leaving the file untouched if it isn't there
+fn remove_server_from_mcp_json(mcp_json: &Path, server_name: &str) -> Result<(), String> {
+    if !mcp_json.exists() {
+        return Ok(());
+    }
+    let mut config = read_json_config(mcp_json)?;
+    let removed = config
+        .get_mut("mcpServers")
+        .and_then(|servers| servers.as_object_mut())
+        .is_some_and(|servers| servers.remove(server_name).is_some());
+    if removed {
+        write_json_config(mcp_json, &config)?;
+    }
+    Ok(())
+}
+
+/// Remove a server from the private project entries of a parsed `~/.claude.json`;
+/// returns whether anything was removed
+fn remove_server_from_claude_projects_config(
+    config: &mut Value,
+    server_name: &str,
+    project_paths: &[String],
+) -> bool {
+    let mut changed = false;
+    if let Some(projects) = config.get_mut("projects").and_then(|p| p.as_object_mut()) {
+        for project_path in project_paths {
+            let servers = projects
+                .get_mut(&claude_project_key(project_path))
+                .and_then(|project| project.get_mut("mcpServers"))
+                .and_then(|servers| servers.as_object_mut());
+            if let Some(servers) = servers {
+                changed |= servers.remove(server_name).is_some();
+            }
+        }
+    }
+    changed
+}
+
+/// Remove a server from the private and shared entries of the given projects
+pub fn remove_server_from_claude_projects(
+    config_path: &PathBuf,
+    server_name: &str,
+    project_paths: &[String],
+) -> Result<(), String> {
+    if config_path.exists() {
+        let mut config = read_json_config(config_path)?;
+        if remove_server_from_claude_projects_config(&mut config, server_name, project_paths) {
+            write_json_config(config_path, &config)?;
+        }
+    }
+
+    for project_path in project_paths {
+        let mcp_json = Path::new(project_path.trim()).join(".mcp.json");
+        remove_server_from_mcp_json(&mcp_json, server_name)?;
+    }
+    Ok(())
+}
+
 /// Sync server to JSON/JSONC config file (using json5 for parsing)
 /// json5 is a superset of JSON that supports comments, trailing commas, etc.
 fn sync_server_to_json(
@@ -157,8 +610,30 @@ fn sync_server_to_json(
             .map_err(|e| format!("Failed to create config directory: {}", e))?;
     }
 
+    apply_server_to_json(&mut config, server, field, format_config, enabled, tool_key)?;
+
+    // Write back to file with pretty formatting
+    // Note: json5 crate doesn't have serialization, so we write standard JSON
+    // which is valid JSON5 (JSON is a subset of JSON5)
+    let content = serde_json::to_string_pretty(&config)
+        .map_err(|e| format!("Failed to serialize config: {}", e))?;
+    std::fs::write(config_path, content)
+        .map_err(|e| format!("Failed to write config file: {}", e))?;
+
+    Ok(())
+}
+
+/// Add or update a server in a parsed JSON config
+fn apply_server_to_json(
+    config: &mut Value,
+    server: &McpServer,
+    field: &str,
+    format_config: Option<&McpFormatConfig>,
+    enabled: bool,
+    tool_key: &str,
+) -> Result<(), String> {
     // Get or create the MCP servers field, supporting nested paths like `mcp.servers`.
-    let mcp_servers = ensure_json_object_path(&mut config, field)?;
+    let mcp_servers = ensure_json_object_path(config, field)?;
 
     // Build server config based on type and format config
     let server_config = build_json_server_config(server, format_config, enabled, tool_key)?;
@@ -168,15 +643,6 @@ fn sync_server_to_json(
         .as_object_mut()
         .ok_or(format!("{} is not a JSON object", field))?
         .insert(server.name.clone(), server_config);
-
-    // Write back to file with pretty formatting
-    // Note: json5 crate doesn't have serialization, so we write standard JSON
-    // which is valid JSON5 (JSON is a subset of JSON5)
-    let content = serde_json::to_string_pretty(&config)
-        .map_err(|e| format!("Failed to serialize config: {}", e))?;
-    std::fs::write(config_path, content)
-        .map_err(|e| format!("Failed to write config file: {}", e))?;
-
     Ok(())
 }
 
@@ -221,15 +687,6 @@ fn sync_server_to_toml(
     server: &McpServer,
     field: &str,
 ) -> Result<(), String> {
-    use toml_edit::Item;
-
-    if field.contains('.') {
-        return Err(format!(
-            "Nested TOML MCP field paths are not supported: {}",
-            field
-        ));
-    }
-
     // Ensure parent directory exists
     if let Some(parent) = config_path.parent() {
         std::fs::create_dir_all(parent)
@@ -251,6 +708,29 @@ fn sync_server_to_toml(
         toml_edit::DocumentMut::new()
     };
 
+    apply_server_to_toml(&mut doc, server, field)?;
+
+    // Write back to file
+    let content = doc.to_string();
+    std::fs::write(config_path, content)
+        .map_err(|e| format!("Failed to write config file: {}", e))?;
+
+    Ok(())
+}
+
+/// Add or update a server in a parsed TOML document
+fn apply_server_to_toml(
+    doc: &mut toml_edit::DocumentMut,
+    server: &McpServer,
+    field: &str,
+) -> Result<(), String> {
+    if field.contains('.') {
+        return Err(format!(
+            "Nested TOML MCP field paths are not supported: {}",
+            field
+        ));
+    }
+
     // Ensure the servers field exists
     if !doc.contains_key(field) {
         doc[field] = toml_edit::table();
@@ -260,13 +740,7 @@ fn sync_server_to_toml(
     let server_table = build_toml_edit_server_config(server)?;
 
     // Add/update server
-    doc[field][&server.name] = Item::Table(server_table);
-
-    // Write back to file
-    let content = doc.to_string();
-    std::fs::write(config_path, content)
-        .map_err(|e| format!("Failed to write config file: {}", e))?;
-
+    doc[field][&server.name] = toml_edit::Item::Table(server_table);
     Ok(())
 }
 
@@ -434,11 +908,22 @@ fn build_json_server_config(
     enabled: bool,
     tool_key: &str,
 ) -> Result<Value, String> {
-    match server.server_type.as_str() {
+    let mut config = match server.server_type.as_str() {
         "stdio" => build_stdio_config(server, format_config, enabled, tool_key),
         "http" | "sse" => build_http_config(server, format_config, enabled, tool_key),
         _ => Err(format!("Unknown server type: {}", server.server_type)),
+    }?;
+
+    // Copilot CLI only exposes the tools listed here; an entry without it offers none
+    if tool_key == "github_copilot_cli" {
+        if let Some(object) = config.as_object_mut() {
+            object
+                .entry("tools")
+                .or_insert_with(|| serde_json::json!(["*"]));
+        }
     }
+
+    Ok(config)
 }
 
 fn detect_server_type_with_format_config(
@@ -912,6 +1397,8 @@ fn parse_server_with_format_config(
         description: None,
         tags: vec![],
         timeout: None,
+        project_paths: vec![],
+        project_shared: false,
         sort_index: 0,
         created_at: now,
         updated_at: now,
@@ -952,6 +1439,8 @@ fn parse_standard_server_config(name: &str, server_config: &Value, now: i64) ->
         description: None,
         tags: vec![],
         timeout: None,
+        project_paths: vec![],
+        project_shared: false,
         sort_index: 0,
         created_at: now,
         updated_at: now,
@@ -1111,6 +1600,8 @@ fn import_servers_from_toml(config_path: &PathBuf, field: &str) -> Result<Vec<Mc
             description: None,
             tags: vec![],
             timeout: None,
+            project_paths: vec![],
+            project_shared: false,
             sort_index: 0,
             created_at: now,
             updated_at: now,
@@ -1183,6 +1674,23 @@ mod tests {
     use crate::coding::mcp::format_configs::get_format_config;
     use serde_json::json;
 
+    #[test]
+    fn claude_project_servers_handles_dotted_project_keys() {
+        let mut config = json!({ "projects": { "/work/app.v2": { "allowedTools": [] } } });
+        claude_project_servers(&mut config, "/work/app.v2")
+            .unwrap()
+            .insert("github".to_string(), json!({ "command": "npx" }));
+        assert_eq!(
+            config["projects"]["/work/app.v2"]["mcpServers"]["github"]["command"],
+            "npx"
+        );
+        assert_eq!(
+            config["projects"]["/work/app.v2"]["allowedTools"],
+            json!([])
+        );
+        assert_eq!(claude_project_key("/work/app.v2/ "), "/work/app.v2");
+    }
+
     fn build_openclaw_stdio_server() -> McpServer {
         McpServer {
             id: String::new(),
@@ -1197,6 +1705,8 @@ mod tests {
             description: None,
             tags: vec![],
             timeout: None,
+            project_paths: vec![],
+            project_shared: false,
             sort_index: 0,
             created_at: 0,
             updated_at: 0,
@@ -1219,6 +1729,8 @@ mod tests {
             description: None,
             tags: vec![],
             timeout: None,
+            project_paths: vec![],
+            project_shared: false,
             sort_index: 0,
             created_at: 0,
             updated_at: 0,
@@ -1283,6 +1795,85 @@ mod tests {
         assert!(config.get("httpUrl").is_none());
     }
 
+    #[test]
+    fn build_windsurf_http_config_uses_server_url() {
+        let server = build_http_server();
+        let format = get_format_config("windsurf").expect("windsurf format should exist");
+
+        let config = build_json_server_config(&server, Some(format), true, "windsurf").unwrap();
+
+        assert_eq!(config["serverUrl"], "https://example.com/mcp");
+        assert!(config.get("url").is_none());
+        assert_eq!(config["headers"]["Authorization"], "Bearer token");
+    }
+
+    #[test]
+    fn parse_windsurf_server_url_without_type_infers_http() {
+        let config = json!({
+            "mcpServers": {
+                "remote": { "serverUrl": "https://example.com/mcp" }
+            }
+        });
+        let format = get_format_config("windsurf").expect("windsurf format should exist");
+
+        let servers = parse_mcp_servers_from_value(&config, "mcpServers", Some(format)).unwrap();
+
+        assert_eq!(servers.len(), 1);
+        assert_eq!(servers[0].server_type, "http");
+        assert_eq!(servers[0].server_config["url"], "https://example.com/mcp");
+    }
+
+    #[test]
+    fn build_cline_http_config_uses_streamable_http_type() {
+        let server = build_http_server();
+        let format = get_format_config("cline").expect("cline format should exist");
+
+        let config = build_json_server_config(&server, Some(format), true, "cline").unwrap();
+
+        assert_eq!(config["type"], "streamableHttp");
+        assert_eq!(config["url"], "https://example.com/mcp");
+    }
+
+    #[test]
+    fn build_copilot_cli_stdio_config_exposes_all_tools() {
+        let mut server = build_http_server();
+        server.server_type = "stdio".to_string();
+        server.server_config = json!({ "command": "uvx", "args": ["mcp-server-git"] });
+        let format = get_format_config("github_copilot_cli")
+            .expect("github_copilot_cli format should exist");
+
+        let config =
+            build_json_server_config(&server, Some(format), true, "github_copilot_cli").unwrap();
+
+        assert_eq!(config["type"], "local");
+        assert_eq!(config["tools"], json!(["*"]));
+    }
+
+    #[test]
+    fn parse_roo_code_streamable_http_maps_to_http() {
+        let config = json!({
+            "mcpServers": {
+                "remote": {
+                    "type": "streamable-http",
+                    "url": "https://example.com/mcp"
+                },
+                "events": {
+                    "type": "sse",
+                    "url": "https://example.com/sse"
+                }
+            }
+        });
+        let format = get_format_config("roo_code").expect("roo_code format should exist");
+
+        let servers = parse_mcp_servers_from_value(&config, "mcpServers", Some(format)).unwrap();
+
+        let remote = servers.iter().find(|s| s.name == "remote").unwrap();
+        assert_eq!(remote.server_type, "http");
+        assert_eq!(remote.server_config["url"], "https://example.com/mcp");
+        let events = servers.iter().find(|s| s.name == "events").unwrap();
+        assert_eq!(events.server_type, "sse");
+    }
+
     #[test]
     fn parse_gemini_like_http_prefers_http_url() {
         let config = json!({
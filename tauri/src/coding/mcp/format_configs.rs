@@ -40,11 +40,66 @@ pub const GEMINI_LIKE_FORMAT: McpFormatConfig = McpFormatConfig {
     infer_remote_type_from_url_fields_when_type_missing: true,
 };
 
+/// Windsurf keeps the standard shape but reads remote servers from `serverUrl`
+/// (for both streamable HTTP and SSE). Entries typed `http` with a plain `url`
+/// are still read back.
+pub const WINDSURF_FORMAT: McpFormatConfig = McpFormatConfig {
+    type_mappings: &[],
+    merge_command_args: false,
+    env_field: "env",
+    requires_enabled: false,
+    default_tool_type: "stdio",
+    supports_timeout: false,
+    remote_url_field_mappings: &[("http", "serverUrl"), ("sse", "serverUrl")],
+    infer_remote_type_from_url_fields_when_type_missing: true,
+};
+
+/// Cline keeps the standard shape but types streamable HTTP servers as
+/// `streamableHttp`; SSE servers stay `sse`.
+pub const CLINE_FORMAT: McpFormatConfig = McpFormatConfig {
+    type_mappings: &[("http", "streamableHttp")],
+    merge_command_args: false,
+    env_field: "env",
+    requires_enabled: false,
+    default_tool_type: "stdio",
+    supports_timeout: false,
+    remote_url_field_mappings: &[],
+    infer_remote_type_from_url_fields_when_type_missing: false,
+};
+
+/// Roo Code and its fork Kilo Code type streamable HTTP servers as `streamable-http`.
+pub const ROO_CODE_FORMAT: McpFormatConfig = McpFormatConfig {
+    type_mappings: &[("http", "streamable-http")],
+    merge_command_args: false,
+    env_field: "env",
+    requires_enabled: false,
+    default_tool_type: "stdio",
+    supports_timeout: false,
+    remote_url_field_mappings: &[],
+    infer_remote_type_from_url_fields_when_type_missing: false,
+};
+
+/// GitHub Copilot CLI types stdio servers as `local` (`stdio` is read as the same)
+pub const COPILOT_CLI_FORMAT: McpFormatConfig = McpFormatConfig {
+    type_mappings: &[("stdio", "local")],
+    merge_command_args: false,
+    env_field: "env",
+    requires_enabled: false,
+    default_tool_type: "local",
+    supports_timeout: false,
+    remote_url_field_mappings: &[],
+    infer_remote_type_from_url_fields_when_type_missing: false,
+};
+
 /// Get the format config for a tool by key
 pub fn get_format_config(tool_key: &str) -> Option<&'static McpFormatConfig> {
     match tool_key {
         "opencode" => Some(&OPENCODE_FORMAT),
         "gemini_cli" | "qwen_code" | "antigravity" => Some(&GEMINI_LIKE_FORMAT),
+        "windsurf" => Some(&WINDSURF_FORMAT),
+        "cline" => Some(&CLINE_FORMAT),
+        "roo_code" | "kilo_code" => Some(&ROO_CODE_FORMAT),
+        "github_copilot_cli" => Some(&COPILOT_CLI_FORMAT),
         _ => None,
     }
 }
@@ -0,0 +1,656 @@
+//! MCP server health check
+//!
+//! `mcp_test_server` performs the MCP initialize handshake against a stored server and lists
+//! the tools it offers, so a typo'd command or a dead URL shows up in the app instead of
+//! inside the coding tool. stdio servers are spawned locally (with the `cmd /c` wrapper on
+//! Windows), http servers are tested over Streamable HTTP and sse servers over the legacy
+//! HTTP+SSE transport. The output of stdio servers is kept per server (see `server_logs`).
+
+use std::collections::VecDeque;
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE};
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::process::{Child, ChildStdin, ChildStdout};
+
+use super::command_normalize::wrap_cmd_c;
+use super::secrets;
+use super::server_logs::LogRun;
+use super::types::{McpServer, McpTestResultDto, McpToolInfoDto};
+use crate::http_client;
+use crate::DbState;
+
+/// Upper bound for the whole test (spawn + handshake + tools/list)
+const TEST_TIMEOUT: Duration = Duration::from_secs(30);
+/// Protocol version offered in `initialize`; servers answer with the one they speak
+const PROTOCOL_VERSION: &str = "2025-03-26";
+/// stderr lines of a stdio server kept for error reports
+const STDERR_TAIL_LINES: usize = 20;
+const MCP_SESSION_ID: &str = "mcp-session-id";
+
+const INITIALIZE_ID: u64 = 1;
+const TOOLS_LIST_ID: u64 = 2;
+
+fn initialize_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": INITIALIZE_ID,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": PROTOCOL_VERSION,
+            "capabilities": {},
+            "clientInfo": {
+                "name": "ai-toolbox",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+        },
+    })
+}
+
+fn initialized_notification() -> Value {
+    json!({ "jsonrpc": "2.0", "method": "notifications/initialized" })
+}
+
+fn tools_list_request() -> Value {
+    json!({ "jsonrpc": "2.0", "id": TOOLS_LIST_ID, "method": "tools/list", "params": {} })
+}
+
+/// The `result` of a JSON-RPC response, or its `error` as a message
+fn response_result(response: &Value) -> Result<&Value, String> {
+    if let Some(error) = response.get("error") {
+        let message = error
+            .get("message")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown error");
+        return match error.get("code").and_then(|v| v.as_i64()) {
+            Some(code) => Err(format!("{} (code {})", message, code)),
+            None => Err(message.to_string()),
+        };
+    }
+    response
+        .get("result")
+        .ok_or_else(|| "Response has neither result nor error".to_string())
+}
+
+fn is_response_to(message: &Value, id: u64) -> bool {
+    message.get("id").and_then(|v| v.as_u64()) == Some(id) && message.get("method").is_none()
+}
+
+fn parse_tools(result: &Value) -> Vec<McpToolInfoDto> {
+    result
+        .get("tools")
+        .and_then(|v| v.as_array())
+        .map(|tools| {
+            tools
+                .iter()
+                .filter_map(|tool| {
+                    Some(McpToolInfoDto {
+                        name: tool.get("name")?.as_str()?.to_string(),
+                        description: tool
+                            .get("description")
+                            .and_then(|v| v.as_str())
+                            .map(|s| s.to_string()),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// ==================== SSE parsing ====================
+
+struct SseEvent {
+    event: String,
+    data: String,
+}
+
+/// One SSE event block (without the blank separator line)
+fn parse_sse_event(block: &str) -> SseEvent {
+    let mut event = String::from("message");
+    let mut data = Vec::new();
+    for line in block.lines() {
+        if let Some(value) = line.strip_prefix("event:") {
+            event = value.trim().to_string();
+        } else if let Some(value) = line.strip_prefix("data:") {
+            data.push(value.strip_prefix(' ').unwrap_or(value));
+        }
+    }
+    SseEvent {
+        event,
+        data: data.join("\n"),
+    }
+}
+
+/// Incremental reader of a `text/event-stream` response body
+struct SseReader {
+    stream: BoxStream<'static, reqwest::Result<Vec<u8>>>,
+    buffer: Vec<u8>,
+}
+
+impl SseReader {
+    fn new(response: reqwest::Response) -> Self {
+        Self {
+            stream: response
+                .bytes_stream()
+                .map(|chunk| chunk.map(|bytes| bytes.to_vec()))
+                .boxed(),
+            buffer: Vec::new(),
+        }
+    }
+
+    async fn next_event(&mut self) -> Result<Option<SseEvent>, String> {
+        loop {
+            if let Some(pos) = self.buffer.windows(2).position(|w| w == b"\n\n") {
+                let block: Vec<u8> = self.buffer.drain(..pos + 2).collect();
+                let event = parse_sse_event(&String::from_utf8_lossy(&block));
+                if !event.data.is_empty() {
+                    return Ok(Some(event));
+                }
+                continue;
+            }
+            match self.stream.next().await {
+                Some(Ok(chunk)) => self
+                    .buffer
+                    .extend(chunk.into_iter().filter(|b| *b != b'\r')),
+                Some(Err(e)) => return Err(format!("Event stream failed: {}", e)),
+                None if self.buffer.iter().all(u8::is_ascii_whitespace) => return Ok(None),
+                None => {
+                    let block = std::mem::take(&mut self.buffer);
+                    return Ok(Some(parse_sse_event(&String::from_utf8_lossy(&block))));
+                }
+            }
+        }
+    }
+
+    /// Skip events until the JSON-RPC response with `id`
+    async fn wait_response(&mut self, id: u64) -> Result<Value, String> {
+        while let Some(event) = self.next_event().await? {
+            if event.event != "message" {
+                continue;
+            }
+            if let Ok(message) = serde_json::from_str::<Value>(&event.data) {
+                if is_response_to(&message, id) {
+                    return Ok(message);
+                }
+            }
+        }
+        Err("Event stream closed before the server responded".to_string())
+    }
+}
+
+// ==================== Transports ====================
+
+struct StdioTransport {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: Lines<BufReader<ChildStdout>>,
+    stderr_tail: Arc<Mutex<VecDeque<String>>>,
+    log: LogRun,
+}
+
+impl StdioTransport {
+    fn spawn(server_id: &str, server_config: &Value) -> Result<Self, String> {
+        let config = wrap_cmd_c(server_config);
+        let command = config
+            .get("command")
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.trim().is_empty())
+            .ok_or_else(|| "stdio server has no command".to_string())?;
+        let args: Vec<&str> = config
+            .get("args")
+            .and_then(|v| v.as_array())
+            .map(|args| args.iter().filter_map(|a| a.as_str()).collect())
+            .unwrap_or_default();
+
+        let mut cmd = tokio::process::Command::new(command);
+        cmd.args(&args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        if let Some(env) = config.get("env").and_then(|v| v.as_object()) {
+            for (key, value) in env {
+                if let Some(value) = value.as_str() {
+                    cmd.env(key, value);
+                }
+            }
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            const CREATE_NO_WINDOW: u32 = 0x08000000;
+            cmd.creation_flags(CREATE_NO_WINDOW);
+        }
+
+        // env is left out: it may hold resolved secrets
+        let log = LogRun::start(server_id);
+        log.push("info", format!("$ {} {}", command, args.join(" ")));
+        let mut child = cmd.spawn().map_err(|e| {
+            let message = format!("Failed to start '{}': {}", command, e);
+            log.push("info", message.clone());
+            message
+        })?;
+        let stdin = child.stdin.take().ok_or("Failed to open server stdin")?;
+        let stdout = child.stdout.take().ok_or("Failed to open server stdout")?;
+        let stderr = child.stderr.take().ok_or("Failed to open server stderr")?;
+
+        // Not awaited: a grandchild (npx -> node) may keep stderr open after the kill
+        let stderr_tail = Arc::new(Mutex::new(VecDeque::new()));
+        let tail = stderr_tail.clone();
+        let stderr_log = log.clone();
+        tauri::async_runtime::spawn(async move {
+            let mut lines = BufReader::new(stderr).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                stderr_log.push("stderr", line.clone());
+                if let Ok(mut tail) = tail.lock() {
+                    if tail.len() == STDERR_TAIL_LINES {
+                        tail.pop_front();
+                    }
+                    tail.push_back(line);
+                }
+            }
+        });
+
+        Ok(Self {
+            child,
+            stdin,
+            stdout: BufReader::new(stdout).lines(),
+            stderr_tail,
+            log,
+        })
+    }
+
+    async fn send(&mut self, message: &Value) -> Result<(), String> {
+        let mut line = message.to_string();
+        line.push('\n');
+        self.stdin
+            .write_all(line.as_bytes())
+            .await
+            .map_err(|e| format!("Failed to write to server stdin: {}", e))?;
+        self.stdin
+            .flush()
+            .await
+            .map_err(|e| format!("Failed to write to server stdin: {}", e))
+    }
+
+    async fn wait_response(&mut self, id: u64) -> Result<Value, String> {
+        loop {
+            let line = self
+                .stdout
+                .next_line()
+                .await
+                .map_err(|e| format!("Failed to read server stdout: {}", e))?;
+            let Some(line) = line else {
+                if let Ok(Some(status)) = self.child.try_wait() {
+                    self.log.push("info", format!("Process exited: {}", status));
+                }
+                return Err("Server exited before responding".to_string());
+            };
+            self.log.push("stdout", line.clone());
+            // Servers that log to stdout break the protocol, but the handshake can still pass
+            let Ok(message) = serde_json::from_str::<Value>(&line) else {
+                continue;
+            };
+            if is_response_to(&message, id) {
+                return Ok(message);
+            }
+        }
+    }
+
+    fn stderr_tail(&self) -> Vec<String> {
+        self.stderr_tail
+            .lock()
+            .map(|tail| tail.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+fn header_map(server_config: &Value) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let Some(map) = server_config.get("headers").and_then(|v| v.as_object()) {
+        for (key, value) in map {
+            let (Ok(name), Some(Ok(value))) = (
+                HeaderName::from_bytes(key.as_bytes()),
+                value.as_str().map(HeaderValue::from_str),
+            ) else {
+                log::warn!("MCP test skipped invalid header: {}", key);
+                continue;
+            };
+            headers.insert(name, value);
+        }
+    }
+    headers
+}
+
+fn server_url(server_config: &Value) -> Result<reqwest::Url, String> {
+    let url = server_config
+        .get("url")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.trim().is_empty())
+        .ok_or_else(|| "Server has no url".to_string())?;
+    reqwest::Url::parse(url.trim()).map_err(|e| format!("Invalid url '{}': {}", url, e))
+}
+
+fn check_status(response: &reqwest::Response) -> Result<(), String> {
+    let status = response.status();
+    if status.is_success() {
+        Ok(())
+    } else {
+        Err(format!("Server returned HTTP {}", status))
+    }
+}
+
+/// Streamable HTTP: every message is a POST, answered with JSON or a short event stream
+struct HttpTransport {
+    client: reqwest::Client,
+    url: reqwest::Url,
+    headers: HeaderMap,
+    session_id: Option<String>,
+}
+
+impl HttpTransport {
+    async fn post(&mut self, message: &Value) -> Result<reqwest::Response, String> {
+        let mut request = self
+            .client
+            .post(self.url.clone())
+            .headers(self.headers.clone())
+            .header(ACCEPT, "application/json, text/event-stream")
+            .json(message);
+        if let Some(session_id) = &self.session_id {
+            request = request.header(MCP_SESSION_ID, session_id);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+        check_status(&response)?;
+        if let Some(session_id) = response
+            .headers()
+            .get(MCP_SESSION_ID)
+            .and_then(|v| v.to_str().ok())
+        {
+            self.session_id = Some(session_id.to_string());
+        }
+        Ok(response)
+    }
+
+    async fn request(&mut self, message: &Value, id: u64) -> Result<Value, String> {
+        let response = self.post(message).await?;
+        let is_stream = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with("text/event-stream"));
+        if is_stream {
+            return SseReader::new(response).wait_response(id).await;
+        }
+        response
+            .json::<Value>()
+            .await
+            .map_err(|e| format!("Invalid JSON-RPC response: {}", e))
+    }
+
+    async fn notify(&mut self, message: &Value) -> Result<(), String> {
+        self.post(message).await.map(|_| ())
+    }
+}
+
+/// Legacy HTTP+SSE: responses arrive on the GET stream, messages are POSTed to its endpoint
+struct SseTransport {
+    client: reqwest::Client,
+    endpoint: reqwest::Url,
+    headers: HeaderMap,
+    events: SseReader,
+}
+
+impl SseTransport {
+    async fn connect(client: reqwest::Client, server_config: &Value) -> Result<Self, String> {
+        let url = server_url(server_config)?;
+        let headers = header_map(server_config);
+        let response = client
+            .get(url.clone())
+            .headers(headers.clone())
+            .header(ACCEPT, "text/event-stream")
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+        check_status(&response)?;
+
+        let mut events = SseReader::new(response);
+        let endpoint = loop {
+            match events.next_event().await? {
+                Some(event) if event.event == "endpoint" => break event.data,
+                Some(_) => continue,
+                None => return Err("Event stream closed before the endpoint event".to_string()),
+            }
+        };
+        let endpoint = url
+            .join(endpoint.trim())
+            .map_err(|e| format!("Invalid endpoint '{}': {}", endpoint, e))?;
+
+        Ok(Self {
+            client,
+            endpoint,
+            headers,
+            events,
+        })
+    }
+
+    async fn send(&self, message: &Value) -> Result<(), String> {
+        let response = self
+            .client
+            .post(self.endpoint.clone())
+            .headers(self.headers.clone())
+            .json(message)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+        check_status(&response)
+    }
+}
+
+enum Transport {
+    Stdio(StdioTransport),
+    Http(HttpTransport),
+    Sse(SseTransport),
+}
+
+impl Transport {
+    async fn open(state: &DbState, server: &McpServer) -> Result<Self, String> {
+        let server = secrets::resolve_server_secrets(server)?;
+        let config = &server.server_config;
+        match server.server_type.as_str() {
+            "http" => Ok(Transport::Http(HttpTransport {
+                client: http_client::client_with_timeout(state, TEST_TIMEOUT.as_secs()).await?,
+                url: server_url(config)?,
+                headers: header_map(config),
+                session_id: None,
+            })),
+            "sse" => {
+                let client =
+                    http_client::client_with_timeout(state, TEST_TIMEOUT.as_secs()).await?;
+                Ok(Transport::Sse(SseTransport::connect(client, config).await?))
+            }
+            _ => Ok(Transport::Stdio(StdioTransport::spawn(&server.id, config)?)),
+        }
+    }
+
+    async fn request(&mut self, message: &Value, id: u64) -> Result<Value, String> {
+        match self {
+            Transport::Stdio(t) => {
+                t.send(message).await?;
+                t.wait_response(id).await
+            }
+            Transport::Http(t) => t.request(message, id).await,
+            Transport::Sse(t) => {
+                t.send(message).await?;
+                t.events.wait_response(id).await
+            }
+        }
+    }
+
+    async fn notify(&mut self, message: &Value) -> Result<(), String> {
+        match self {
+            Transport::Stdio(t) => t.send(message).await,
+            Transport::Http(t) => t.notify(message).await,
+            Transport::Sse(t) => t.send(message).await,
+        }
+    }
+
+    async fn close(self) {
+        match self {
+            Transport::Stdio(mut t) => {
+                let _ = t.child.kill().await;
+            }
+            Transport::Http(t) => {
+                // Streamable HTTP sessions are ended with a DELETE
+                if let Some(session_id) = t.session_id {
+                    let _ = t
+                        .client
+                        .delete(t.url)
+                        .headers(t.headers)
+                        .header(MCP_SESSION_ID, session_id)
+                        .send()
+                        .await;
+                }
+            }
+            Transport::Sse(_) => {}
+        }
+    }
+}
+
+// ==================== Test ====================
+
+/// Handshake + tools/list, filling `result`
+async fn handshake(
+    transport: &mut Transport,
+    result: &mut McpTestResultDto,
+    started: Instant,
+) -> Result<(), String> {
+    let response = transport
+        .request(&initialize_request(), INITIALIZE_ID)
+        .await?;
+    result.latency_ms = started.elapsed().as_millis() as u64;
+    let init = response_result(&response)?;
+    let info = init.get("serverInfo");
+    let str_of = |value: Option<&Value>| value.and_then(|v| v.as_str()).map(|s| s.to_string());
+    result.server_name = str_of(info.and_then(|i| i.get("name")));
+    result.server_version = str_of(info.and_then(|i| i.get("version")));
+    result.protocol_version = str_of(init.get("protocolVersion"));
+
+    transport.notify(&initialized_notification()).await?;
+
+    // Servers without the tools capability have nothing to list
+    if init.pointer("/capabilities/tools").is_some() {
+        let response = transport
+            .request(&tools_list_request(), TOOLS_LIST_ID)
+            .await?;
+        result.tools = parse_tools(response_result(&response)?);
+    }
+    Ok(())
+}
+
+/// Run the MCP handshake against a server; failures are reported in the result
+pub async fn test_server(state: &DbState, server: &McpServer) -> McpTestResultDto {
+    let started = Instant::now();
+    let mut result = McpTestResultDto {
+        server_id: server.id.clone(),
+        success: false,
+        latency_ms: 0,
+        server_name: None,
+        server_version: None,
+        protocol_version: None,
+        tools: vec![],
+        error_message: None,
+    };
+
+    let mut transport =
+        match tokio::time::timeout(TEST_TIMEOUT, Transport::open(state, server)).await {
+            Ok(Ok(transport)) => transport,
+            Ok(Err(e)) => {
+                result.error_message = Some(e);
+                return result;
+            }
+            Err(_) => {
+                result.error_message = Some(format!("Timed out after {}s", TEST_TIMEOUT.as_secs()));
+                return result;
+            }
+        };
+
+    let remaining = TEST_TIMEOUT.saturating_sub(started.elapsed());
+    let outcome = match tokio::time::timeout(
+        remaining,
+        handshake(&mut transport, &mut result, started),
+    )
+    .await
+    {
+        Ok(outcome) => outcome,
+        Err(_) => Err(format!("Timed out after {}s", TEST_TIMEOUT.as_secs())),
+    };
+    if let Transport::Stdio(t) = &transport {
+        let status = match &outcome {
+            Ok(()) => "Test passed".to_string(),
+            Err(e) => format!("Test failed: {}", e),
+        };
+        t.log.push("info", status);
+    }
+    if let Err(e) = outcome {
+        let stderr = match &transport {
+            Transport::Stdio(t) => t.stderr_tail(),
+            _ => vec![],
+        };
+        result.error_message = Some(if stderr.is_empty() {
+            e
+        } else {
+            format!("{}\n{}", e, stderr.join("\n"))
+        });
+    } else {
+        result.success = true;
+    }
+    transport.close().await;
+
+    log::info!(
+        "MCP server test finished: name={}, success={}, latency_ms={}, tools={}",
+        server.name,
+        result.success,
+        result.latency_ms,
+        result.tools.len()
+    );
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sse_event_blocks() {
+        let event = parse_sse_event("event: endpoint\ndata: /messages?session=1");
+        assert_eq!(event.event, "endpoint");
+        assert_eq!(event.data, "/messages?session=1");
+
+        let event = parse_sse_event(": ping\ndata: {\"a\":\ndata: 1}");
+        assert_eq!(event.event, "message");
+        assert_eq!(event.data, "{\"a\":\n1}");
+    }
+
+    #[test]
+    fn reports_json_rpc_errors() {
+        let error = json!({"jsonrpc": "2.0", "id": 1, "error": {"code": -32601, "message": "Method not found"}});
+        assert_eq!(
+            response_result(&error),
+            Err("Method not found (code -32601)".to_string())
+        );
+        let ok = json!({"jsonrpc": "2.0", "id": 2, "result": {"tools": [{"name": "search"}]}});
+        let tools = parse_tools(response_result(&ok).unwrap());
+        assert_eq!(tools.len(), 1);
+        assert_eq!(tools[0].name, "search");
+        assert!(is_response_to(&ok, 2));
+        assert!(!is_response_to(&ok, 1));
+    }
+}
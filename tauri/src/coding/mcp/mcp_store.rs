@@ -7,12 +7,12 @@
 use serde_json::Value;
 
 use super::adapter::{
-    from_db_favorite_mcp, from_db_mcp_preferences, from_db_mcp_server, remove_sync_detail,
-    set_sync_detail, to_clean_mcp_server_payload, to_mcp_preferences_payload,
+    from_db_favorite_mcp, from_db_mcp_preferences, from_db_mcp_profile, from_db_mcp_server,
+    remove_sync_detail, set_sync_detail, to_clean_mcp_server_payload, to_mcp_preferences_payload,
 };
 use super::command_normalize;
-use super::types::{now_ms, FavoriteMcp, McpPreferences, McpServer, McpSyncDetail};
-use crate::coding::db_id::{db_new_id, db_record_id};
+use super::types::{now_ms, FavoriteMcp, McpPreferences, McpProfile, McpServer, McpSyncDetail};
+use crate::coding::db_id::{db_new_id, db_record_id, db_reorder_query};
 use crate::DbState;
 
 // ==================== MCP Server CRUD ====================
@@ -133,15 +133,12 @@ pub async fn delete_mcp_server(state: &DbState, server_id: &str) -> Result<(), S
 
 /// Reorder MCP servers by updating sort_index for each server
 pub async fn reorder_mcp_servers(state: &DbState, ids: &[String]) -> Result<(), String> {
-    let db = state.db();
-
-    for (index, id) in ids.iter().enumerate() {
-        let record_id = db_record_id("mcp_server", id);
-        db.query(&format!("UPDATE {} SET sort_index = $index", record_id))
-            .bind(("index", index as i32))
-            .await
-            .map_err(|e| format!("Failed to reorder MCP servers: {}", e))?;
-    }
+    state
+        .db()
+        .query(db_reorder_query("mcp_server", ids, false))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to reorder MCP servers: {}", e))?;
 
     Ok(())
 }
@@ -275,6 +272,45 @@ pub async fn toggle_tool_enabled(
     Ok(is_now_enabled)
 }
 
+/// Save the enabled tools and sync details of several servers in one transaction
+pub async fn save_servers_sync_state(
+    state: &DbState,
+    servers: &[&McpServer],
+) -> Result<(), String> {
+    if servers.is_empty() {
+        return Ok(());
+    }
+    let db = state.db();
+
+    let mut statement = String::from("BEGIN TRANSACTION;\n");
+    for (index, server) in servers.iter().enumerate() {
+        statement.push_str(&format!(
+            "UPDATE {} SET enabled_tools = $enabled_tools_{index}, sync_details = $sync_details_{index}, updated_at = $updated_at;\n",
+            db_record_id("mcp_server", &server.id)
+        ));
+    }
+    statement.push_str("COMMIT TRANSACTION;");
+
+    let mut query = db.query(statement).bind(("updated_at", now_ms()));
+    for (index, server) in servers.iter().enumerate() {
+        query = query
+            .bind((
+                format!("enabled_tools_{}", index),
+                server.enabled_tools.clone(),
+            ))
+            .bind((
+                format!("sync_details_{}", index),
+                server.sync_details.clone(),
+            ));
+    }
+    query
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to save MCP server sync state: {}", e))?;
+
+    Ok(())
+}
+
 // ==================== MCP Preferences ====================
 
 /// Get MCP preferences (singleton record)
@@ -382,3 +418,71 @@ pub async fn delete_favorite_mcp(state: &DbState, id: &str) -> Result<(), String
 
     Ok(())
 }
+
+// ==================== MCP Profile CRUD ====================
+
+/// Get all MCP profiles ordered by name
+pub async fn get_mcp_profiles(state: &DbState) -> Result<Vec<McpProfile>, String> {
+    let db = state.db();
+
+    let mut result = db
+        .query("SELECT *, type::string(id) as id FROM mcp_profile ORDER BY name ASC")
+        .await
+        .map_err(|e| format!("Failed to query MCP profiles: {}", e))?;
+
+    let records: Vec<Value> = result.take(0).map_err(|e| e.to_string())?;
+    Ok(records.into_iter().map(from_db_mcp_profile).collect())
+}
+
+/// Get an MCP profile by name
+pub async fn get_mcp_profile_by_name(
+    state: &DbState,
+    name: &str,
+) -> Result<Option<McpProfile>, String> {
+    let db = state.db();
+    let name_owned = name.to_string();
+
+    let mut result = db
+        .query("SELECT *, type::string(id) as id FROM mcp_profile WHERE name = $name LIMIT 1")
+        .bind(("name", name_owned))
+        .await
+        .map_err(|e| format!("Failed to query MCP profile by name: {}", e))?;
+
+    let records: Vec<Value> = result.take(0).map_err(|e| e.to_string())?;
+    Ok(records.first().map(|v| from_db_mcp_profile(v.clone())))
+}
+
+/// Create or update an MCP profile
+pub async fn upsert_mcp_profile(state: &DbState, profile: &McpProfile) -> Result<String, String> {
+    let db = state.db();
+
+    // Remove id field for database payload
+    let mut payload = serde_json::to_value(profile).map_err(|e| e.to_string())?;
+    if let Some(obj) = payload.as_object_mut() {
+        obj.remove("id");
+    }
+
+    let id = if profile.id.is_empty() {
+        db_new_id()
+    } else {
+        profile.id.clone()
+    };
+    let record_id = db_record_id("mcp_profile", &id);
+    db.query(&format!("UPSERT {} CONTENT $data", record_id))
+        .bind(("data", payload))
+        .await
+        .map_err(|e| format!("Failed to save MCP profile: {}", e))?;
+    Ok(id)
+}
+
+/// Delete an MCP profile
+pub async fn delete_mcp_profile(state: &DbState, id: &str) -> Result<(), String> {
+    let db = state.db();
+    let record_id = db_record_id("mcp_profile", id);
+
+    db.query(&format!("DELETE {}", record_id))
+        .await
+        .map_err(|e| format!("Failed to delete MCP profile: {}", e))?;
+
+    Ok(())
+}
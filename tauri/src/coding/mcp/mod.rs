@@ -8,8 +8,12 @@ pub mod command_normalize;
 pub mod commands;
 pub mod config_sync;
 pub mod format_configs;
+pub mod health;
 pub mod mcp_store;
 pub mod opencode_path;
+pub mod registry;
+pub mod secrets;
+pub mod server_logs;
 pub mod tray_support;
 pub mod types;
 
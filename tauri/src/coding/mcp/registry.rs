@@ -0,0 +1,302 @@
+//! MCP registry browsing
+//!
+//! Searches the official MCP registry (registry.modelcontextprotocol.io) and converts each
+//! entry's packages and remotes into install options shaped like `CreateMcpServerInput`, so
+//! the frontend can create the server with a single `mcp_create_server` call.
+
+use serde_json::{json, Map, Value};
+
+use super::types::{McpRegistryEntryDto, McpRegistryInstallOptionDto, McpRegistryPageDto};
+use crate::http_client;
+use crate::DbState;
+
+const REGISTRY_SERVERS_URL: &str = "https://registry.modelcontextprotocol.io/v0/servers";
+const PAGE_SIZE: u32 = 30;
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value
+        .get(key)
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+}
+
+/// Short server name for the local record: `io.github.acme/weather-mcp` -> `weather-mcp`
+fn suggested_name(registry_name: &str) -> String {
+    registry_name
+        .rsplit('/')
+        .next()
+        .unwrap_or(registry_name)
+        .to_string()
+}
+
+/// Declared environment variables as an `env` object (defaults filled in) plus required names
+fn env_from_package(package: &Value) -> (Map<String, Value>, Vec<String>) {
+    let mut env = Map::new();
+    let mut required = Vec::new();
+    let vars = package
+        .get("environmentVariables")
+        .or_else(|| package.get("environment_variables"))
+        .and_then(|v| v.as_array());
+    for var in vars.into_iter().flatten() {
+        let Some(name) = str_field(var, "name") else {
+            continue;
+        };
+        let default = str_field(var, "default")
+            .or_else(|| str_field(var, "value"))
+            .unwrap_or("");
+        env.insert(name.to_string(), json!(default));
+        let is_required = var
+            .get("isRequired")
+            .or_else(|| var.get("is_required"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if is_required && default.is_empty() {
+            required.push(name.to_string());
+        }
+    }
+    (env, required)
+}
+
+/// Positional package arguments with a fixed value (placeholders need user input)
+fn package_arguments(package: &Value) -> Vec<String> {
+    package
+        .get("packageArguments")
+        .or_else(|| package.get("package_arguments"))
+        .and_then(|v| v.as_array())
+        .map(|args| {
+            args.iter()
+                .filter(|arg| str_field(arg, "type") == Some("positional"))
+                .filter_map(|arg| str_field(arg, "value").or_else(|| str_field(arg, "default")))
+                .map(|s| s.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn option_from_package(package: &Value) -> Option<McpRegistryInstallOptionDto> {
+    let registry_type = str_field(package, "registryType")
+        .or_else(|| str_field(package, "registry_type"))
+        .or_else(|| str_field(package, "registry_name"))?;
+    let identifier = str_field(package, "identifier").or_else(|| str_field(package, "name"))?;
+    let transport = package
+        .pointer("/transport/type")
+        .and_then(|v| v.as_str())
+        .unwrap_or("stdio");
+    if transport != "stdio" {
+        return None;
+    }
+
+    let (command, mut args) = match registry_type {
+        "npm" => ("npx", vec!["-y".to_string(), identifier.to_string()]),
+        "pypi" => ("uvx", vec![identifier.to_string()]),
+        "oci" | "docker" => (
+            "docker",
+            vec![
+                "run".to_string(),
+                "-i".to_string(),
+                "--rm".to_string(),
+                identifier.to_string(),
+            ],
+        ),
+        "nuget" => ("dnx", vec![identifier.to_string(), "--yes".to_string()]),
+        _ => return None,
+    };
+    args.extend(package_arguments(package));
+
+    let (env, required_env) = env_from_package(package);
+    let mut server_config = json!({ "command": command, "args": args });
+    if !env.is_empty() {
+        server_config["env"] = Value::Object(env);
+    }
+    Some(McpRegistryInstallOptionDto {
+        label: format!("{} ({})", identifier, registry_type),
+        server_type: "stdio".to_string(),
+        server_config,
+        required_env,
+    })
+}
+
+fn option_from_remote(remote: &Value) -> Option<McpRegistryInstallOptionDto> {
+    let url = str_field(remote, "url")?;
+    let server_type = match str_field(remote, "type")
+        .or_else(|| str_field(remote, "transport_type"))
+        .unwrap_or("streamable-http")
+    {
+        "sse" => "sse",
+        _ => "http",
+    };
+
+    let mut headers = Map::new();
+    let mut required = Vec::new();
+    for header in remote
+        .get("headers")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+    {
+        let Some(name) = str_field(header, "name") else {
+            continue;
+        };
+        let value = str_field(header, "value").unwrap_or("");
+        headers.insert(name.to_string(), json!(value));
+        if value.is_empty() {
+            required.push(name.to_string());
+        }
+    }
+
+    let mut server_config = json!({ "url": url });
+    if !headers.is_empty() {
+        server_config["headers"] = Value::Object(headers);
+    }
+    Some(McpRegistryInstallOptionDto {
+        label: format!("{} ({})", url, server_type),
+        server_type: server_type.to_string(),
+        server_config,
+        required_env: required,
+    })
+}
+
+/// One registry entry; accepts both `{ server: {...} }` and flat server objects
+fn entry_from_registry_value(entry: &Value) -> Option<McpRegistryEntryDto> {
+    let server = entry.get("server").unwrap_or(entry);
+    let name = str_field(server, "name")?;
+
+    let mut install_options: Vec<McpRegistryInstallOptionDto> = server
+        .get("packages")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(option_from_package)
+        .collect();
+    install_options.extend(
+        server
+            .get("remotes")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(option_from_remote),
+    );
+
+    Some(McpRegistryEntryDto {
+        name: name.to_string(),
+        suggested_name: suggested_name(name),
+        title: str_field(server, "title").map(|s| s.to_string()),
+        description: str_field(server, "description").map(|s| s.to_string()),
+        version: str_field(server, "version")
+            .or_else(|| {
+                server
+                    .pointer("/version_detail/version")
+                    .and_then(|v| v.as_str())
+            })
+            .map(|s| s.to_string()),
+        repository_url: server
+            .pointer("/repository/url")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        website_url: str_field(server, "websiteUrl").map(|s| s.to_string()),
+        install_options,
+    })
+}
+
+/// Search the registry; `cursor` continues from the previous page's `next_cursor`
+pub async fn browse_registry(
+    state: &DbState,
+    query: &str,
+    cursor: Option<&str>,
+) -> Result<McpRegistryPageDto, String> {
+    let client = http_client::client(state).await?;
+    let limit = PAGE_SIZE.to_string();
+    let mut params = vec![("limit", limit.as_str()), ("version", "latest")];
+    let query = query.trim();
+    if !query.is_empty() {
+        params.push(("search", query));
+    }
+    if let Some(cursor) = cursor.filter(|c| !c.is_empty()) {
+        params.push(("cursor", cursor));
+    }
+
+    let response = client
+        .get(REGISTRY_SERVERS_URL)
+        .query(&params)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to query MCP registry: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("MCP registry returned HTTP {}", response.status()));
+    }
+    let body: Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse MCP registry response: {}", e))?;
+
+    let servers = body
+        .get("servers")
+        .and_then(|v| v.as_array())
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(entry_from_registry_value)
+                .collect()
+        })
+        .unwrap_or_default();
+    let next_cursor = body
+        .pointer("/metadata/nextCursor")
+        .or_else(|| body.pointer("/metadata/next_cursor"))
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string());
+
+    Ok(McpRegistryPageDto {
+        servers,
+        next_cursor,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_packages_and_remotes_to_install_options() {
+        let entry = json!({
+            "server": {
+                "name": "io.github.acme/weather-mcp",
+                "description": "Weather data",
+                "version": "1.2.0",
+                "packages": [{
+                    "registryType": "npm",
+                    "identifier": "@acme/weather-mcp",
+                    "transport": { "type": "stdio" },
+                    "environmentVariables": [
+                        { "name": "WEATHER_API_KEY", "isRequired": true, "isSecret": true },
+                        { "name": "UNITS", "default": "metric" }
+                    ]
+                }],
+                "remotes": [{
+                    "type": "streamable-http",
+                    "url": "https://weather.acme.dev/mcp",
+                    "headers": [{ "name": "Authorization" }]
+                }]
+            }
+        });
+
+        let dto = entry_from_registry_value(&entry).unwrap();
+        assert_eq!(dto.suggested_name, "weather-mcp");
+        assert_eq!(dto.install_options.len(), 2);
+
+        let npm = &dto.install_options[0];
+        assert_eq!(npm.server_type, "stdio");
+        assert_eq!(npm.server_config["command"], "npx");
+        assert_eq!(
+            npm.server_config["args"],
+            json!(["-y", "@acme/weather-mcp"])
+        );
+        assert_eq!(npm.server_config["env"]["UNITS"], "metric");
+        assert_eq!(npm.required_env, vec!["WEATHER_API_KEY".to_string()]);
+
+        let remote = &dto.install_options[1];
+        assert_eq!(remote.server_type, "http");
+        assert_eq!(remote.server_config["url"], "https://weather.acme.dev/mcp");
+        assert_eq!(remote.required_env, vec!["Authorization".to_string()]);
+    }
+}
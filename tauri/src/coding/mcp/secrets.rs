@@ -0,0 +1,139 @@
+//! Secret references in MCP server configs
+//!
+//! `env` and `headers` values may reference a vault secret as `{{secret:NAME}}` (also
+//! inside a longer value, e.g. `Bearer {{secret:GITHUB_TOKEN}}`). The database keeps the
+//! reference; the value lives in the secrets vault (`crate::secrets`) and is substituted
+//! only when a config is exported to a tool, a remote host or a test run.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use super::types::{McpSecretDto, McpServer};
+use crate::db::DbState;
+use crate::secrets::{self as vault, load_secret};
+
+/// Config objects whose string values may hold secret references
+const SECRET_FIELDS: [&str; 2] = ["env", "headers"];
+
+fn secret_values(server_config: &Value) -> impl Iterator<Item = &str> {
+    SECRET_FIELDS
+        .iter()
+        .filter_map(|field| server_config.get(*field).and_then(|v| v.as_object()))
+        .flat_map(|map| map.values())
+        .filter_map(|v| v.as_str())
+}
+
+/// Names of the secrets referenced by a server config
+pub fn referenced_secrets(server_config: &Value) -> Vec<String> {
+    let mut names: Vec<String> = secret_values(server_config)
+        .flat_map(vault::referenced_secrets)
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+fn resolve_with(
+    server_config: &Value,
+    lookup: &dyn Fn(&str) -> Result<Option<String>, String>,
+) -> Result<Value, String> {
+    let mut resolved = server_config.clone();
+    for field in SECRET_FIELDS {
+        let Some(map) = resolved.get_mut(field).and_then(|v| v.as_object_mut()) else {
+            continue;
+        };
+        for value in map.values_mut() {
+            if let Some(text) = value.as_str() {
+                *value = Value::String(vault::resolve_text_with(text, lookup)?);
+            }
+        }
+    }
+    Ok(resolved)
+}
+
+/// Server with its secret references replaced by the keychain values
+///
+/// Borrows the server unchanged when it references no secrets.
+pub fn resolve_server_secrets(server: &McpServer) -> Result<Cow<'_, McpServer>, String> {
+    if referenced_secrets(&server.server_config).is_empty() {
+        return Ok(Cow::Borrowed(server));
+    }
+    let server_config = resolve_with(&server.server_config, &load_secret)
+        .map_err(|e| format!("{} (referenced by MCP server {})", e, server.name))?;
+    Ok(Cow::Owned(McpServer {
+        server_config,
+        ..server.clone()
+    }))
+}
+
+/// Keychain values of the secrets a server config references (unset ones are skipped)
+pub fn resolved_secret_values(server_config: &Value) -> Vec<String> {
+    referenced_secrets(server_config)
+        .iter()
+        .filter_map(|name| load_secret(name).ok().flatten())
+        .collect()
+}
+
+/// Store a secret in the vault; an empty value removes it
+pub async fn set_secret(db_state: &DbState, name: &str, value: &str) -> Result<(), String> {
+    vault::store_secret(name, value)?;
+    if !value.is_empty() {
+        vault::register_secret(db_state, name, None).await?;
+    }
+    Ok(())
+}
+
+/// Secrets referenced by the given servers, with whether the keychain holds a value
+pub fn list_secrets(servers: &[McpServer]) -> Vec<McpSecretDto> {
+    let mut used_by: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for server in servers {
+        for name in referenced_secrets(&server.server_config) {
+            used_by.entry(name).or_default().push(server.name.clone());
+        }
+    }
+    used_by
+        .into_iter()
+        .map(|(name, servers)| McpSecretDto {
+            has_value: matches!(load_secret(&name), Ok(Some(_))),
+            name,
+            used_by: servers,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn resolves_references_in_env_and_headers() {
+        let config = json!({
+            "command": "npx",
+            "args": ["{{secret:GITHUB_TOKEN}}"],
+            "env": { "GITHUB_TOKEN": "{{secret:GITHUB_TOKEN}}", "MODE": "ro" },
+            "headers": { "Authorization": "Bearer {{ secret:API_KEY }}" }
+        });
+        assert_eq!(referenced_secrets(&config), vec!["API_KEY", "GITHUB_TOKEN"]);
+
+        let lookup =
+            |name: &str| -> Result<Option<String>, String> { Ok(Some(format!("<{}>", name))) };
+        let resolved = resolve_with(&config, &lookup).unwrap();
+        assert_eq!(resolved["env"]["GITHUB_TOKEN"], "<GITHUB_TOKEN>");
+        assert_eq!(resolved["env"]["MODE"], "ro");
+        assert_eq!(resolved["headers"]["Authorization"], "Bearer <API_KEY>");
+        // Only env and headers are templated
+        assert_eq!(resolved["args"][0], "{{secret:GITHUB_TOKEN}}");
+    }
+
+    #[test]
+    fn missing_secret_is_an_error() {
+        let config = json!({ "env": { "TOKEN": "{{secret:MISSING}}" } });
+        let lookup = |_: &str| -> Result<Option<String>, String> { Ok(None) };
+        assert!(resolve_with(&config, &lookup)
+            .unwrap_err()
+            .contains("MISSING"));
+    }
+}
@@ -0,0 +1,119 @@
+//! Output captured from stdio MCP servers during test runs
+//!
+//! Each server keeps the lines of its latest test run (stdout, stderr and a few notes from
+//! the app) in a bounded in-memory buffer, so a crashing server can be debugged from the
+//! app. Starting a new run replaces the previous one; lines still arriving from an older
+//! process are dropped.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use super::types::{now_ms, McpServerLogLineDto};
+
+/// Lines kept per server
+const MAX_LOG_LINES: usize = 500;
+
+struct ServerLog {
+    run: u64,
+    lines: VecDeque<McpServerLogLineDto>,
+}
+
+static SERVER_LOGS: Mutex<Option<HashMap<String, ServerLog>>> = Mutex::new(None);
+static NEXT_RUN: AtomicU64 = AtomicU64::new(1);
+
+/// Handle of one test run, used to append lines to the server's log
+#[derive(Clone)]
+pub struct LogRun {
+    server_id: String,
+    run: u64,
+}
+
+impl LogRun {
+    /// Start a new run for a server, discarding the lines of the previous one
+    pub fn start(server_id: &str) -> Self {
+        let run = NEXT_RUN.fetch_add(1, Ordering::SeqCst);
+        if let Ok(mut guard) = SERVER_LOGS.lock() {
+            guard.get_or_insert_with(HashMap::new).insert(
+                server_id.to_string(),
+                ServerLog {
+                    run,
+                    lines: VecDeque::new(),
+                },
+            );
+        }
+        Self {
+            server_id: server_id.to_string(),
+            run,
+        }
+    }
+
+    /// Append a line; `stream` is `stdout`, `stderr` or `info`
+    pub fn push(&self, stream: &str, line: impl Into<String>) {
+        let Ok(mut guard) = SERVER_LOGS.lock() else {
+            return;
+        };
+        let Some(log) = guard
+            .as_mut()
+            .and_then(|logs| logs.get_mut(&self.server_id))
+            .filter(|log| log.run == self.run)
+        else {
+            return;
+        };
+        if log.lines.len() == MAX_LOG_LINES {
+            log.lines.pop_front();
+        }
+        log.lines.push_back(McpServerLogLineDto {
+            timestamp: now_ms(),
+            stream: stream.to_string(),
+            line: line.into(),
+        });
+    }
+}
+
+/// Lines of a server's latest test run, oldest first
+pub fn get_server_logs(server_id: &str) -> Vec<McpServerLogLineDto> {
+    SERVER_LOGS
+        .lock()
+        .ok()
+        .and_then(|guard| {
+            guard
+                .as_ref()?
+                .get(server_id)
+                .map(|log| log.lines.iter().cloned().collect())
+        })
+        .unwrap_or_default()
+}
+
+/// Forget the captured output of a deleted server
+pub fn clear_server_logs(server_id: &str) {
+    if let Ok(mut guard) = SERVER_LOGS.lock() {
+        if let Some(logs) = guard.as_mut() {
+            logs.remove(server_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_only_the_latest_run_and_bounds_its_size() {
+        let old = LogRun::start("logs-test-server");
+        old.push("stderr", "old");
+        let run = LogRun::start("logs-test-server");
+        old.push("stderr", "late line of the old process");
+        for i in 0..MAX_LOG_LINES + 5 {
+            run.push("stdout", i.to_string());
+        }
+
+        let lines = get_server_logs("logs-test-server");
+        assert_eq!(lines.len(), MAX_LOG_LINES);
+        assert_eq!(lines[0].line, "5");
+        assert!(lines.iter().all(|l| l.stream == "stdout"));
+
+        clear_server_logs("logs-test-server");
+        assert!(get_server_logs("logs-test-server").is_empty());
+    }
+}
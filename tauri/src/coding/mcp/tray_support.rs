@@ -126,7 +126,7 @@ pub async fn apply_mcp_tool_toggle<R: Runtime>(
             }
         }
     } else {
-        let _ = super::config_sync::remove_server_from_tool_async(&db, &server.name, &tool).await;
+        let _ = super::config_sync::remove_server_from_tool_async(&db, &server, &tool).await;
         mcp_store::delete_sync_detail(&state, server_id, tool_key).await?;
     }
 
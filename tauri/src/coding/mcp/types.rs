@@ -72,6 +72,12 @@ pub struct McpServer {
     pub tags: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timeout: Option<i64>,
+    /// Project directories the server is scoped to (Claude Code); empty means global
+    #[serde(default)]
+    pub project_paths: Vec<String>,
+    /// Write project entries to the shared `<project>/.mcp.json` instead of `~/.claude.json`
+    #[serde(default)]
+    pub project_shared: bool,
     #[serde(default)]
     pub sort_index: i32,
     pub created_at: i64,
@@ -99,6 +105,8 @@ pub struct McpServerDto {
     pub description: Option<String>,
     pub tags: Vec<String>,
     pub timeout: Option<i64>,
+    pub project_paths: Vec<String>,
+    pub project_shared: bool,
     pub sort_index: i32,
     pub created_at: i64,
     pub updated_at: i64,
@@ -125,6 +133,10 @@ pub struct CreateMcpServerInput {
     #[serde(default)]
     pub tags: Vec<String>,
     pub timeout: Option<i64>,
+    #[serde(default)]
+    pub project_paths: Vec<String>,
+    #[serde(default)]
+    pub project_shared: bool,
 }
 
 /// Input for updating an MCP server
@@ -137,6 +149,8 @@ pub struct UpdateMcpServerInput {
     pub description: Option<String>,
     pub tags: Option<Vec<String>>,
     pub timeout: Option<i64>,
+    pub project_paths: Option<Vec<String>>,
+    pub project_shared: Option<bool>,
 }
 
 /// MCP preferences (singleton record)
@@ -201,6 +215,94 @@ pub struct McpScanResultDto {
     pub servers: Vec<McpDiscoveredServerDto>,
 }
 
+/// Tool offered by an MCP server (from `tools/list`)
+#[derive(Debug, Serialize)]
+pub struct McpToolInfoDto {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// Result of an MCP server test (initialize handshake + tools/list)
+#[derive(Debug, Serialize)]
+pub struct McpTestResultDto {
+    pub server_id: String,
+    pub success: bool,
+    /// Time until the initialize response, including process start for stdio servers
+    pub latency_ms: u64,
+    pub server_name: Option<String>,
+    pub server_version: Option<String>,
+    pub protocol_version: Option<String>,
+    pub tools: Vec<McpToolInfoDto>,
+    pub error_message: Option<String>,
+}
+
+/// Preview of exporting the MCP servers to one tool's config file
+#[derive(Debug, Serialize)]
+pub struct McpExportPreviewDto {
+    pub tool: String,
+    pub config_path: String,
+    pub changed: bool,
+    /// Line diff of the current file against the exported one (secret values masked)
+    pub diff: String,
+    /// Servers in the file that the app doesn't manage; the export leaves them as they are
+    pub unmanaged_servers: Vec<String>,
+    /// Project `.mcp.json` files the export also writes (Claude Code shared project scope)
+    pub project_files: Vec<String>,
+    /// Servers that can't be exported (e.g. a referenced secret is not set)
+    pub errors: Vec<String>,
+}
+
+/// Line of output captured from a stdio MCP server during a test run
+#[derive(Clone, Debug, Serialize)]
+pub struct McpServerLogLineDto {
+    pub timestamp: i64,
+    /// `stdout`, `stderr` or `info` (notes from the app, e.g. the command line)
+    pub stream: String,
+    pub line: String,
+}
+
+/// Secret referenced by MCP server configs as `{{secret:NAME}}`
+#[derive(Debug, Serialize)]
+pub struct McpSecretDto {
+    pub name: String,
+    /// Whether the OS keychain holds a value for the secret
+    pub has_value: bool,
+    /// Names of the servers referencing the secret
+    pub used_by: Vec<String>,
+}
+
+/// Install option of a registry entry, shaped like `CreateMcpServerInput`
+#[derive(Debug, Serialize)]
+pub struct McpRegistryInstallOptionDto {
+    pub label: String,
+    pub server_type: String,
+    pub server_config: Value,
+    /// Env vars (stdio) or headers (http/sse) the user still has to fill in
+    pub required_env: Vec<String>,
+}
+
+/// MCP registry search result entry
+#[derive(Debug, Serialize)]
+pub struct McpRegistryEntryDto {
+    /// Registry name, e.g. `io.github.acme/weather-mcp`
+    pub name: String,
+    /// Local server name derived from the registry name
+    pub suggested_name: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub version: Option<String>,
+    pub repository_url: Option<String>,
+    pub website_url: Option<String>,
+    pub install_options: Vec<McpRegistryInstallOptionDto>,
+}
+
+/// One page of MCP registry search results
+#[derive(Debug, Serialize)]
+pub struct McpRegistryPageDto {
+    pub servers: Vec<McpRegistryEntryDto>,
+    pub next_cursor: Option<String>,
+}
+
 /// Favorite MCP server (for quick select in add modal)
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FavoriteMcp {
@@ -244,6 +346,26 @@ pub struct FavoriteMcpInput {
     pub tags: Vec<String>,
 }
 
+/// Named set of MCP servers, applied to its tools in one go
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct McpProfile {
+    pub id: String,
+    pub name: String,
+    pub server_ids: Vec<String>,
+    /// Tools the profile is applied to
+    pub tool_keys: Vec<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// Input for creating/updating an MCP profile (matched by name)
+#[derive(Clone, Debug, Deserialize)]
+pub struct McpProfileInput {
+    pub name: String,
+    pub server_ids: Vec<String>,
+    pub tool_keys: Vec<String>,
+}
+
 /// Helper function to get current timestamp in milliseconds
 pub fn now_ms() -> i64 {
     let now = std::time::SystemTime::now()
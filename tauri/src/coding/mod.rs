@@ -1,24 +1,33 @@
+pub mod aider;
 pub mod all_api_hub;
 pub mod claude_code;
 pub mod codex;
+pub mod copilot_cli;
+pub mod cursor;
 pub mod mcp;
 pub mod oh_my_openagent;
 pub mod oh_my_opencode_slim;
 pub mod open_claw;
 pub mod open_code;
 pub mod preset_models;
+pub mod prompt_library;
 pub mod runtime_location;
 pub mod session_manager;
 pub mod skills;
 pub mod ssh;
 pub mod tools;
+pub mod usage;
 pub mod wsl;
 
 mod db_id;
 mod prompt_file;
 pub use db_id::{
     db_build_id, db_clean_id, db_extract_id, db_extract_id_opt, db_new_id, db_record_id,
+    db_mark_applied_query, db_reorder_query,
 };
 
 mod path_expand;
 pub use path_expand::expand_local_path;
+
+mod text_diff;
+pub use text_diff::line_diff;
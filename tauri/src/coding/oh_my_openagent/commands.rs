@@ -1,12 +1,12 @@
-use chrono::Local;
 use serde_json::Value;
 use std::fs;
 
 use super::adapter;
 use super::types::*;
-use crate::coding::db_id::db_record_id;
+use crate::coding::db_id::{db_mark_applied_query, db_record_id, db_reorder_query};
 use crate::coding::runtime_location;
 use crate::db::DbState;
+use crate::db_timestamp::{with_datetimes, Timestamp};
 use tauri::Emitter;
 
 pub const OH_MY_OPENAGENT_CONFIG_TABLE: &str = "oh_my_openagent_config";
@@ -197,7 +197,7 @@ async fn load_temp_config_from_file(
         Some(other_fields)
     };
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     Ok(OhMyOpenAgentAgentsProfile {
         id: "__local__".to_string(), // Special ID to indicate this is from local file
         name: "default".to_string(),
@@ -207,7 +207,7 @@ async fn load_temp_config_from_file(
         categories,
         other_fields: other_fields_value,
         sort_index: None,
-        created_at: Some(now.clone()),
+        created_at: Some(now),
         updated_at: Some(now),
     })
 }
@@ -313,7 +313,7 @@ async fn load_temp_global_config_from_file(
         Some(other_fields)
     };
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     Ok(OhMyOpenAgentGlobalConfig {
         id: "__local__".to_string(), // Special ID to indicate this is from local file
         schema,
@@ -341,7 +341,7 @@ pub async fn create_oh_my_openagent_config(
 ) -> Result<OhMyOpenAgentAgentsProfile, String> {
     let db = state.db();
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let content = OhMyOpenAgentAgentsProfileContent {
         name: input.name.clone(),
         is_applied: false,
@@ -350,8 +350,8 @@ pub async fn create_oh_my_openagent_config(
         categories: input.categories.clone(),
         other_fields: input.other_fields.clone(),
         sort_index: None,
-        created_at: now.clone(),
-        updated_at: now.clone(),
+        created_at: now,
+        updated_at: now,
     };
 
     let json_data = adapter::to_db_value(&content);
@@ -361,7 +361,7 @@ pub async fn create_oh_my_openagent_config(
         "CREATE {} CONTENT $data",
         OH_MY_OPENAGENT_CONFIG_TABLE
     ))
-    .bind(("data", json_data))
+    .bind(("data", with_datetimes(json_data)))
     .await
     .map_err(|e| format!("Failed to create config: {}", e))?;
 
@@ -422,7 +422,7 @@ pub async fn update_oh_my_openagent_config(
         }
     }
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
     // Get the existing config to preserve created_at and is_applied
     // Use direct ID format like ClaudeCode does to avoid type::thing serialization issues
@@ -451,11 +451,7 @@ pub async fn update_oh_my_openagent_config(
                     .or_else(|| record.get("isDisabled"))
                     .and_then(|v| v.as_bool())
                     .unwrap_or(false);
-                let created = record
-                    .get("created_at")
-                    .and_then(|v| v.as_str())
-                    .map(String::from)
-                    .unwrap_or_else(|| Local::now().to_rfc3339());
+                let created = Timestamp::from_db(record, "created_at").unwrap_or(now);
                 let sort_index = record
                     .get("sort_index")
                     .or_else(|| record.get("sortIndex"))
@@ -463,10 +459,10 @@ pub async fn update_oh_my_openagent_config(
                     .map(|v| v as i32);
                 (is_applied, is_disabled, created, sort_index)
             } else {
-                (false, false, Local::now().to_rfc3339(), None)
+                (false, false, now, None)
             }
         }
-        Err(_) => (false, false, Local::now().to_rfc3339(), None),
+        Err(_) => (false, false, now, None),
     };
 
     let content = OhMyOpenAgentAgentsProfileContent {
@@ -489,10 +485,17 @@ pub async fn update_oh_my_openagent_config(
         .map_err(|e| format!("Failed to serialize json_data: {}", e))?;
 
     db.query(format!(
-        "UPDATE {}:`{}` CONTENT {}",
-        OH_MY_OPENAGENT_CONFIG_TABLE, config_id, json_str
+        "BEGIN TRANSACTION; \
+         UPDATE {table}:`{id}` CONTENT {json_str}; \
+         UPDATE {table}:`{id}` SET created_at = $created_at, updated_at = $updated_at; \
+         COMMIT TRANSACTION;",
+        table = OH_MY_OPENAGENT_CONFIG_TABLE,
+        id = config_id,
     ))
+    .bind(("created_at", created_at.to_db()))
+    .bind(("updated_at", now.to_db()))
     .await
+    .and_then(|response| response.check())
     .map_err(|e| format!("Failed to update config: {}", e))?;
 
     // 如果该配置当前是应用状态，立即重新写入到配置文件
@@ -778,25 +781,15 @@ pub async fn apply_config_internal<R: tauri::Runtime>(
     apply_config_to_file(db, config_id).await?;
 
     // Update database - set all configs to not applied, then set this one to applied
-    let now = Local::now().to_rfc3339();
-
-    // Clear applied flag (only update the currently applied one)
-    db.query(format!(
-        "UPDATE {} SET is_applied = false, updated_at = $now WHERE is_applied = true",
-        OH_MY_OPENAGENT_CONFIG_TABLE
-    ))
-    .bind(("now", now.clone()))
-    .await
-    .map_err(|e| format!("Failed to clear applied flags: {}", e))?;
+    let now = Timestamp::now();
 
-    // Set this config as applied using backtick-escaped record ref
-    let record_id = db_record_id(OH_MY_OPENAGENT_CONFIG_TABLE, config_id);
-    db.query(&format!(
-        "UPDATE {} SET is_applied = true, updated_at = $now",
-        record_id
+    db.query(db_mark_applied_query(
+        OH_MY_OPENAGENT_CONFIG_TABLE,
+        config_id,
     ))
-    .bind(("now", now))
+    .bind(("now", now.to_db()))
     .await
+    .and_then(|response| response.check())
     .map_err(|e| format!("Failed to update applied flag: {}", e))?;
 
     // Notify based on source
@@ -818,15 +811,10 @@ pub async fn reorder_oh_my_openagent_configs(
 ) -> Result<(), String> {
     let db = state.db();
 
-    for (index, id) in ids.iter().enumerate() {
-        db.query(format!(
-            "UPDATE {}:`{}` SET sort_index = $index",
-            OH_MY_OPENAGENT_CONFIG_TABLE, id
-        ))
-        .bind(("index", index as i32))
+    db.query(db_reorder_query(OH_MY_OPENAGENT_CONFIG_TABLE, &ids, false))
         .await
+        .and_then(|response| response.check())
         .map_err(|e| format!("Failed to update sort index: {}", e))?;
-    }
 
     Ok(())
 }
@@ -842,13 +830,13 @@ pub async fn toggle_oh_my_openagent_config_disabled(
     let db = state.db();
 
     // Update is_disabled field in database
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     db.query(format!(
         "UPDATE {}:`{}` SET is_disabled = $is_disabled, updated_at = $now",
         OH_MY_OPENAGENT_CONFIG_TABLE, config_id
     ))
     .bind(("is_disabled", is_disabled))
-    .bind(("now", now))
+    .bind(("now", now.to_db()))
     .await
     .map_err(|e| format!("Failed to toggle config disabled status: {}", e))?;
 
@@ -987,7 +975,7 @@ pub async fn save_oh_my_openagent_global_config(
 ) -> Result<OhMyOpenAgentGlobalConfig, String> {
     let db = state.db();
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let content = OhMyOpenAgentGlobalConfigContent {
         schema: input.schema,
         sisyphus_agent: input.sisyphus_agent,
@@ -1001,7 +989,7 @@ pub async fn save_oh_my_openagent_global_config(
         browser_automation_engine: input.browser_automation_engine,
         claude_code: input.claude_code,
         other_fields: input.other_fields,
-        updated_at: now.clone(),
+        updated_at: now,
     };
 
     let json_data = adapter::global_config_to_db_value(&content);
@@ -1011,7 +999,7 @@ pub async fn save_oh_my_openagent_global_config(
         "UPSERT {}:`global` CONTENT $data",
         OH_MY_OPENAGENT_GLOBAL_CONFIG_TABLE
     ))
-    .bind(("data", json_data))
+    .bind(("data", with_datetimes(json_data)))
     .await
     .map_err(|e| format!("Failed to save global config: {}", e))?;
 
@@ -1074,7 +1062,7 @@ pub async fn save_oh_my_openagent_local_config(
     let base_config = load_temp_config_from_file(&db).await?;
     let base_global = load_temp_global_config_from_file(&db).await.ok();
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
     // Build Agents Profile content
     let config_input = input.config;
@@ -1103,8 +1091,8 @@ pub async fn save_oh_my_openagent_local_config(
         categories: config_categories,
         other_fields: config_other_fields,
         sort_index: None,
-        created_at: now.clone(),
-        updated_at: now.clone(),
+        created_at: now,
+        updated_at: now,
     };
 
     let config_json = adapter::to_db_value(&config_content);
@@ -1112,7 +1100,7 @@ pub async fn save_oh_my_openagent_local_config(
         "CREATE {} CONTENT $data",
         OH_MY_OPENAGENT_CONFIG_TABLE
     ))
-    .bind(("data", config_json))
+    .bind(("data", with_datetimes(config_json)))
     .await
     .map_err(|e| format!("Failed to create config: {}", e))?;
 
@@ -1192,7 +1180,7 @@ pub async fn save_oh_my_openagent_local_config(
         "UPSERT {}:`global` CONTENT $data",
         OH_MY_OPENAGENT_GLOBAL_CONFIG_TABLE
     ))
-    .bind(("data", global_json))
+    .bind(("data", with_datetimes(global_json)))
     .await
     .map_err(|e| format!("Failed to save global config: {}", e))?;
 
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::db_timestamp::Timestamp;
+
 /// Config path info
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -63,10 +65,18 @@ pub struct OhMyOpenAgentAgentsProfile {
     pub other_fields: Option<Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_index: Option<i32>, // For manual ordering
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::db_timestamp::lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub created_at: Option<Timestamp>,
+    #[serde(
+        default,
+        deserialize_with = "crate::db_timestamp::lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// Oh My OpenAgent Agents Profile content for database storage
@@ -82,8 +92,8 @@ pub struct OhMyOpenAgentAgentsProfileContent {
     pub other_fields: Option<Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_index: Option<i32>, // For manual ordering
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
 }
 
 /// Input type for Global Config
@@ -145,8 +155,12 @@ pub struct OhMyOpenAgentGlobalConfig {
     pub claude_code: Option<Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub other_fields: Option<Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::db_timestamp::lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// Oh My OpenAgent Global Config content for database storage
@@ -176,7 +190,7 @@ pub struct OhMyOpenAgentGlobalConfigContent {
     pub claude_code: Option<Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub other_fields: Option<Value>,
-    pub updated_at: String,
+    pub updated_at: Timestamp,
 }
 
 /// @deprecated 使用 OhMyOpenAgentAgentsProfileInput 代替
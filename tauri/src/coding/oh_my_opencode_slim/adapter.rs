@@ -3,6 +3,7 @@ use super::types::{
     OhMyOpenCodeSlimGlobalConfigContent,
 };
 use crate::coding::db_id::db_extract_id;
+use crate::db_timestamp::Timestamp;
 use serde_json::{json, Value};
 
 // ============================================================================
@@ -19,13 +20,9 @@ fn get_str_compat(value: &Value, snake_key: &str, camel_key: &str, default: &str
         .to_string()
 }
 
-/// Helper function to get optional string with backward compatibility
-fn get_opt_str_compat(value: &Value, snake_key: &str, camel_key: &str) -> Option<String> {
-    value
-        .get(snake_key)
-        .or_else(|| value.get(camel_key))
-        .and_then(|v| v.as_str())
-        .map(String::from)
+/// Helper function to get a timestamp with backward compatibility
+fn get_timestamp_compat(value: &Value, snake_key: &str, camel_key: &str) -> Option<Timestamp> {
+    Timestamp::from_db(value, snake_key).or_else(|| Timestamp::from_db(value, camel_key))
 }
 
 /// Helper function to get bool with backward compatibility
@@ -109,8 +106,8 @@ pub fn from_db_value(value: Value) -> OhMyOpenCodeSlimConfig {
         council: value.get("council").cloned().or(legacy_council),
         other_fields: cleaned_other_fields,
         sort_index,
-        created_at: get_opt_str_compat(&value, "created_at", "createdAt"),
-        updated_at: get_opt_str_compat(&value, "updated_at", "updatedAt"),
+        created_at: get_timestamp_compat(&value, "created_at", "createdAt"),
+        updated_at: get_timestamp_compat(&value, "updated_at", "updatedAt"),
     }
 }
 
@@ -200,7 +197,7 @@ pub fn global_config_from_db_value(value: Value) -> OhMyOpenCodeSlimGlobalConfig
         experimental: value.get("experimental").cloned(),
         council: value.get("council").cloned().or(legacy_council),
         other_fields: cleaned_other_fields,
-        updated_at: get_opt_str_compat(&value, "updated_at", "updatedAt"),
+        updated_at: get_timestamp_compat(&value, "updated_at", "updatedAt"),
     }
 }
 
@@ -1,12 +1,12 @@
-use chrono::Local;
 use serde_json::Value;
 use std::fs;
 
 use super::adapter;
 use super::types::*;
-use crate::coding::db_id::db_record_id;
+use crate::coding::db_id::{db_mark_applied_query, db_record_id, db_reorder_query};
 use crate::coding::runtime_location;
 use crate::db::DbState;
+use crate::db_timestamp::{with_datetimes, Timestamp};
 use tauri::Emitter;
 
 fn get_default_oh_my_opencode_slim_dir() -> Result<std::path::PathBuf, String> {
@@ -140,7 +140,7 @@ async fn load_temp_config_from_file(
         Some(other_fields)
     };
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     Ok(OhMyOpenCodeSlimConfig {
         id: "__local__".to_string(), // Special ID to indicate this is from local file
         name: "default".to_string(),
@@ -150,7 +150,7 @@ async fn load_temp_config_from_file(
         council,
         other_fields: other_fields_value,
         sort_index: None,
-        created_at: Some(now.clone()),
+        created_at: Some(now),
         updated_at: Some(now),
     })
 }
@@ -227,7 +227,7 @@ async fn load_temp_global_config_from_file(
         Some(other_fields)
     };
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     Ok(OhMyOpenCodeSlimGlobalConfig {
         id: "__local__".to_string(), // Special ID to indicate this is from local file
         sisyphus_agent,
@@ -251,7 +251,7 @@ pub async fn create_oh_my_opencode_slim_config(
 ) -> Result<OhMyOpenCodeSlimConfig, String> {
     let db = state.db();
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let content = OhMyOpenCodeSlimConfigContent {
         name: input.name.clone(),
         is_applied: false,
@@ -260,14 +260,14 @@ pub async fn create_oh_my_opencode_slim_config(
         council: input.council.clone(),
         other_fields: input.other_fields.clone(),
         sort_index: None,
-        created_at: now.clone(),
-        updated_at: now.clone(),
+        created_at: now,
+        updated_at: now,
     };
 
     let json_data = adapter::to_db_value(&content);
 
     db.query("CREATE oh_my_opencode_slim_config CONTENT $data")
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to create config: {}", e))?;
 
@@ -321,7 +321,7 @@ pub async fn update_oh_my_opencode_slim_config(
         }
     }
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
     let existing_result: Result<Vec<serde_json::Value>, _> = db
         .query(format!(
@@ -345,11 +345,7 @@ pub async fn update_oh_my_opencode_slim_config(
                     .or_else(|| record.get("isDisabled"))
                     .and_then(|v| v.as_bool())
                     .unwrap_or(false);
-                let created = record
-                    .get("created_at")
-                    .and_then(|v| v.as_str())
-                    .map(String::from)
-                    .unwrap_or_else(|| Local::now().to_rfc3339());
+                let created = Timestamp::from_db(record, "created_at").unwrap_or(now);
                 let sort_index = record
                     .get("sort_index")
                     .or_else(|| record.get("sortIndex"))
@@ -357,10 +353,10 @@ pub async fn update_oh_my_opencode_slim_config(
                     .map(|v| v as i32);
                 (is_applied, is_disabled, created, sort_index)
             } else {
-                (false, false, Local::now().to_rfc3339(), None)
+                (false, false, now, None)
             }
         }
-        Err(_) => (false, false, Local::now().to_rfc3339(), None),
+        Err(_) => (false, false, now, None),
     };
 
     let content = OhMyOpenCodeSlimConfigContent {
@@ -381,10 +377,16 @@ pub async fn update_oh_my_opencode_slim_config(
         .map_err(|e| format!("Failed to serialize json_data: {}", e))?;
 
     db.query(format!(
-        "UPDATE oh_my_opencode_slim_config:`{}` CONTENT {}",
-        config_id, json_str
+        "BEGIN TRANSACTION; \
+         UPDATE oh_my_opencode_slim_config:`{id}` CONTENT {json_str}; \
+         UPDATE oh_my_opencode_slim_config:`{id}` SET created_at = $created_at, updated_at = $updated_at; \
+         COMMIT TRANSACTION;",
+        id = config_id,
     ))
+    .bind(("created_at", created_at.to_db()))
+    .bind(("updated_at", now.to_db()))
     .await
+    .and_then(|response| response.check())
     .map_err(|e| format!("Failed to update config: {}", e))?;
 
     if is_applied_value {
@@ -615,20 +617,15 @@ pub async fn apply_config_internal<R: tauri::Runtime>(
 ) -> Result<(), String> {
     apply_config_to_file(db, config_id).await?;
 
-    let now = Local::now().to_rfc3339();
-
-    db.query("UPDATE oh_my_opencode_slim_config SET is_applied = false, updated_at = $now WHERE is_applied = true")
-        .bind(("now", now.clone()))
-        .await
-        .map_err(|e| format!("Failed to clear applied flags: {}", e))?;
+    let now = Timestamp::now();
 
-    let record_id = db_record_id("oh_my_opencode_slim_config", config_id);
-    db.query(&format!(
-        "UPDATE {} SET is_applied = true, updated_at = $now",
-        record_id
+    db.query(db_mark_applied_query(
+        "oh_my_opencode_slim_config",
+        config_id,
     ))
-    .bind(("now", now))
+    .bind(("now", now.to_db()))
     .await
+    .and_then(|response| response.check())
     .map_err(|e| format!("Failed to update applied flag: {}", e))?;
 
     let payload = if from_tray { "tray" } else { "window" };
@@ -648,15 +645,10 @@ pub async fn reorder_oh_my_opencode_slim_configs(
 ) -> Result<(), String> {
     let db = state.db();
 
-    for (index, id) in ids.iter().enumerate() {
-        db.query(format!(
-            "UPDATE oh_my_opencode_slim_config:`{}` SET sort_index = $index",
-            id
-        ))
-        .bind(("index", index as i32))
+    db.query(db_reorder_query("oh_my_opencode_slim_config", &ids, false))
         .await
+        .and_then(|response| response.check())
         .map_err(|e| format!("Failed to update sort index: {}", e))?;
-    }
 
     Ok(())
 }
@@ -761,7 +753,7 @@ pub async fn save_oh_my_opencode_slim_global_config(
 ) -> Result<OhMyOpenCodeSlimGlobalConfig, String> {
     let db = state.db();
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let content = OhMyOpenCodeSlimGlobalConfigContent {
         sisyphus_agent: input.sisyphus_agent,
         disabled_agents: input.disabled_agents,
@@ -771,13 +763,13 @@ pub async fn save_oh_my_opencode_slim_global_config(
         experimental: input.experimental,
         council: input.council,
         other_fields: input.other_fields,
-        updated_at: now.clone(),
+        updated_at: now,
     };
 
     let json_data = adapter::global_config_to_db_value(&content);
 
     db.query("UPSERT oh_my_opencode_slim_global_config:`global` CONTENT $data")
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to save global config: {}", e))?;
 
@@ -826,13 +818,13 @@ pub async fn toggle_oh_my_opencode_slim_config_disabled(
     let db = state.db();
 
     // Update is_disabled field in database
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
     db.query(format!(
         "UPDATE oh_my_opencode_slim_config:`{}` SET is_disabled = $is_disabled, updated_at = $now",
         config_id
     ))
     .bind(("is_disabled", is_disabled))
-    .bind(("now", now))
+    .bind(("now", now.to_db()))
     .await
     .map_err(|e| format!("Failed to toggle config disabled status: {}", e))?;
 
@@ -875,7 +867,7 @@ pub async fn save_oh_my_opencode_slim_local_config(
     let base_config = load_temp_config_from_file(&db).await?;
     let base_global = load_temp_global_config_from_file(&db).await.ok();
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
     // Build Agents Profile content
     let config_input = input.config;
@@ -907,13 +899,13 @@ pub async fn save_oh_my_opencode_slim_local_config(
         council: config_council,
         other_fields: config_other_fields,
         sort_index: None,
-        created_at: now.clone(),
-        updated_at: now.clone(),
+        created_at: now,
+        updated_at: now,
     };
 
     let config_json = adapter::to_db_value(&config_content);
     db.query("CREATE oh_my_opencode_slim_config CONTENT $data")
-        .bind(("data", config_json))
+        .bind(("data", with_datetimes(config_json)))
         .await
         .map_err(|e| format!("Failed to create config: {}", e))?;
 
@@ -988,7 +980,7 @@ pub async fn save_oh_my_opencode_slim_local_config(
 
     let global_json = adapter::global_config_to_db_value(&global_content);
     db.query("UPSERT oh_my_opencode_slim_global_config:`global` CONTENT $data")
-        .bind(("data", global_json))
+        .bind(("data", with_datetimes(global_json)))
         .await
         .map_err(|e| format!("Failed to save global config: {}", e))?;
 
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::db_timestamp::Timestamp;
+
 /// Config path info
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -38,10 +40,18 @@ pub struct OhMyOpenCodeSlimAgentsProfile {
     pub other_fields: Option<Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_index: Option<i32>, // For manual ordering
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::db_timestamp::lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub created_at: Option<Timestamp>,
+    #[serde(
+        default,
+        deserialize_with = "crate::db_timestamp::lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// Oh My OpenCode Slim Agents Profile content for database storage
@@ -57,8 +67,8 @@ pub struct OhMyOpenCodeSlimAgentsProfileContent {
     pub other_fields: Option<Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_index: Option<i32>, // For manual ordering
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
 }
 
 /// Input type for Global Config
@@ -104,8 +114,12 @@ pub struct OhMyOpenCodeSlimGlobalConfig {
     pub council: Option<Value>, // JSON, no specific structure
     #[serde(skip_serializing_if = "Option::is_none")]
     pub other_fields: Option<Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::db_timestamp::lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// Oh My OpenCode Slim Global Config content for database storage
@@ -127,7 +141,7 @@ pub struct OhMyOpenCodeSlimGlobalConfigContent {
     pub council: Option<Value>, // JSON, no specific structure
     #[serde(skip_serializing_if = "Option::is_none")]
     pub other_fields: Option<Value>,
-    pub updated_at: String,
+    pub updated_at: Timestamp,
 }
 
 /// @deprecated 使用 OhMyOpenCodeSlimAgentsProfileInput 代替
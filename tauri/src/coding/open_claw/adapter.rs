@@ -1,5 +1,5 @@
 use super::types::OpenClawCommonConfig;
-use chrono::Local;
+use crate::db_timestamp::Timestamp;
 use serde_json::{json, Value};
 
 /// Convert database Value to OpenClawCommonConfig with fault tolerance
@@ -10,15 +10,9 @@ pub fn from_db_value(value: Value) -> OpenClawCommonConfig {
             .or_else(|| value.get("configPath"))
             .and_then(|v| v.as_str())
             .map(String::from),
-        updated_at: value
-            .get("updated_at")
-            .or_else(|| value.get("updatedAt"))
-            .and_then(|v| v.as_str())
-            .unwrap_or_else(|| {
-                let now = Local::now().to_rfc3339();
-                Box::leak(now.into_boxed_str())
-            })
-            .to_string(),
+        updated_at: Timestamp::from_db(&value, "updated_at")
+            .or_else(|| Timestamp::from_db(&value, "updatedAt"))
+            .unwrap_or_else(Timestamp::now),
     }
 }
 
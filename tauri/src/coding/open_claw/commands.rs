@@ -9,6 +9,7 @@ use crate::coding::all_api_hub;
 use crate::coding::runtime_location;
 use crate::coding::skills::commands::resync_all_skills_if_tool_path_changed;
 use crate::db::DbState;
+use crate::db_timestamp::with_datetimes;
 
 // ============================================================================
 // Helper Functions
@@ -230,14 +231,7 @@ pub async fn get_openclaw_common_config(
                 Ok(None)
             }
         }
-        Err(e) => {
-            eprintln!(
-                "OpenClaw common config has incompatible format, cleaning up: {}",
-                e
-            );
-            let _ = db.query("DELETE openclaw_common_config:`common`").await;
-            Ok(None)
-        }
+        Err(e) => Err(format!("Failed to parse openclaw common config: {}", e)),
     }
 }
 
@@ -254,7 +248,7 @@ pub async fn save_openclaw_common_config(
     let json_data = adapter::to_db_value(&config);
 
     db.query("UPSERT openclaw_common_config:`common` CONTENT $data")
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to save openclaw common config: {}", e))?;
 
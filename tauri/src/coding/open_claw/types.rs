@@ -2,6 +2,8 @@ use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::db_timestamp::Timestamp;
+
 // ============================================================================
 // OpenClaw Config Types (mirrors ~/.openclaw/openclaw.json)
 // ============================================================================
@@ -188,7 +190,7 @@ pub enum ReadOpenClawConfigResult {
 pub struct OpenClawCommonConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub config_path: Option<String>,
-    pub updated_at: String,
+    pub updated_at: Timestamp,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
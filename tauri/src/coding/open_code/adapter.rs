@@ -3,7 +3,7 @@ use super::types::{
     OpenCodeFavoriteProvider, OpenCodePromptConfig, OpenCodePromptConfigContent, OpenCodeProvider,
 };
 use crate::coding::db_id::db_extract_id;
-use chrono::Local;
+use crate::db_timestamp::Timestamp;
 use serde_json::{json, Value};
 
 fn normalize_favorite_plugin_name(plugin_name: &str) -> String {
@@ -38,15 +38,9 @@ pub fn from_db_value(value: Value) -> OpenCodeCommonConfig {
             .or_else(|| value.get("showPluginsInMenu"))
             .and_then(|v| v.as_bool())
             .unwrap_or(false),
-        updated_at: value
-            .get("updated_at")
-            .or_else(|| value.get("updatedAt"))
-            .and_then(|v| v.as_str())
-            .unwrap_or_else(|| {
-                let now = Local::now().to_rfc3339();
-                Box::leak(now.into_boxed_str())
-            })
-            .to_string(),
+        updated_at: Timestamp::from_db(&value, "updated_at")
+            .or_else(|| Timestamp::from_db(&value, "updatedAt"))
+            .unwrap_or_else(Timestamp::now),
     }
 }
 
@@ -86,16 +80,10 @@ pub fn from_db_value_prompt_config(value: Value) -> OpenCodePromptConfig {
             .or_else(|| value.get("sortIndex"))
             .and_then(|v| v.as_i64())
             .map(|v| v as i32),
-        created_at: value
-            .get("created_at")
-            .or_else(|| value.get("createdAt"))
-            .and_then(|v| v.as_str())
-            .map(String::from),
-        updated_at: value
-            .get("updated_at")
-            .or_else(|| value.get("updatedAt"))
-            .and_then(|v| v.as_str())
-            .map(String::from),
+        created_at: Timestamp::from_db(&value, "created_at")
+            .or_else(|| Timestamp::from_db(&value, "createdAt")),
+        updated_at: Timestamp::from_db(&value, "updated_at")
+            .or_else(|| Timestamp::from_db(&value, "updatedAt")),
     }
 }
 
@@ -120,11 +108,7 @@ pub fn from_db_value_favorite_plugin(value: Value) -> OpenCodeFavoritePlugin {
             .and_then(|v| v.as_str())
             .map(normalize_favorite_plugin_name)
             .unwrap_or_default(),
-        created_at: value
-            .get("created_at")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string(),
+        created_at: Timestamp::from_db(&value, "created_at"),
     }
 }
 
@@ -151,16 +135,8 @@ pub fn from_db_value_favorite_provider(value: Value) -> Option<OpenCodeFavoriteP
     let diagnostics: Option<OpenCodeDiagnosticsConfig> = value
         .get("diagnostics")
         .and_then(|v| serde_json::from_value(v.clone()).ok());
-    let created_at = value
-        .get("created_at")
-        .and_then(|v| v.as_str())
-        .unwrap_or("")
-        .to_string();
-    let updated_at = value
-        .get("updated_at")
-        .and_then(|v| v.as_str())
-        .unwrap_or("")
-        .to_string();
+    let created_at = Timestamp::from_db(&value, "created_at");
+    let updated_at = Timestamp::from_db(&value, "updated_at");
 
     Some(OpenCodeFavoriteProvider {
         id,
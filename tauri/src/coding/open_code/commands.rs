@@ -2,16 +2,19 @@ use indexmap::IndexMap;
 use serde_json::Value;
 use std::fs;
 use std::path::Path;
+use std::sync::OnceLock;
 use tauri::Emitter;
+use tokio::sync::Mutex;
 
 use super::adapter;
 use super::types::*;
 use crate::coding::all_api_hub;
-use crate::coding::db_id::{db_new_id, db_record_id};
+use crate::coding::db_id::{db_mark_applied_query, db_new_id, db_record_id, db_reorder_query};
 use crate::coding::prompt_file::{read_prompt_content_file, write_prompt_content_file};
 use crate::coding::runtime_location;
 use crate::coding::skills::commands::resync_all_skills_if_tool_path_changed;
 use crate::db::DbState;
+use crate::db_timestamp::{with_datetimes, Timestamp};
 
 // ============================================================================
 // Helper Functions
@@ -21,6 +24,14 @@ const OMO_CANONICAL_PLUGIN: &str = "oh-my-openagent";
 const OMO_LEGACY_PLUGIN: &str = "oh-my-opencode";
 const OMO_SLIM_PLUGIN: &str = "oh-my-opencode-slim";
 
+/// Serializes writes to the OpenCode config file. The tray and the main window can both
+/// run read-modify-write cycles concurrently; without this, last writer wins and drops changes.
+static OPENCODE_CONFIG_WRITE_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+
+fn opencode_config_write_lock() -> &'static Mutex<()> {
+    OPENCODE_CONFIG_WRITE_LOCK.get_or_init(|| Mutex::new(()))
+}
+
 pub(crate) fn opencode_plugin_package_name(plugin_name: &str) -> &str {
     let trimmed_plugin_name = plugin_name.trim();
     if trimmed_plugin_name.is_empty() {
@@ -145,11 +156,8 @@ fn favorite_plugin_record_name(record: &Value) -> String {
         .to_string()
 }
 
-fn favorite_plugin_record_created_at(record: &Value) -> &str {
-    record
-        .get("created_at")
-        .and_then(|value| value.as_str())
-        .unwrap_or_default()
+fn favorite_plugin_record_created_at(record: &Value) -> Option<Timestamp> {
+    Timestamp::from_db(record, "created_at")
 }
 
 fn is_canonical_favorite_plugin_name(plugin_name: &str) -> bool {
@@ -219,6 +227,7 @@ async fn write_opencode_config_file(
 
     fs::write(config_path, json_content)
         .map_err(|e| format!("Failed to write config file: {}", e))?;
+    super::config_cache::invalidate_cached_config();
 
     Ok(())
 }
@@ -307,14 +316,14 @@ async fn get_local_prompt_config(
         return Ok(None);
     };
 
-    let now = chrono::Local::now().to_rfc3339();
+    let now = Timestamp::now();
     Ok(Some(OpenCodePromptConfig {
         id: "__local__".to_string(),
         name: "default".to_string(),
         content: prompt_content,
         is_applied: true,
         sort_index: None,
-        created_at: Some(now.clone()),
+        created_at: Some(now),
         updated_at: Some(now),
     }))
 }
@@ -450,12 +459,31 @@ pub async fn read_opencode_config(
         });
     }
 
-    let content = match fs::read_to_string(config_path) {
+    if let Some(cached) = super::config_cache::get_cached_config(config_path) {
+        return Ok(cached);
+    }
+
+    let fingerprint = super::config_cache::file_fingerprint(config_path);
+    let result = parse_opencode_config_file(&config_path_str);
+    let cacheable = matches!(
+        result,
+        ReadConfigResult::Success { .. } | ReadConfigResult::ParseError { .. }
+    );
+    if let (true, Some(fingerprint)) = (cacheable, fingerprint) {
+        super::config_cache::store_cached_config(config_path, fingerprint, &result);
+    }
+
+    Ok(result)
+}
+
+/// Read and parse the OpenCode config file, normalizing providers and plugins
+fn parse_opencode_config_file(config_path_str: &str) -> ReadConfigResult {
+    let content = match fs::read_to_string(config_path_str) {
         Ok(c) => c,
         Err(e) => {
-            return Ok(ReadConfigResult::Error {
+            return ReadConfigResult::Error {
                 error: format!("Failed to read config file: {}", e),
-            })
+            }
         }
     };
 
@@ -508,7 +536,7 @@ pub async fn read_opencode_config(
                 }
             }
 
-            Ok(ReadConfigResult::Success { config })
+            ReadConfigResult::Success { config }
         }
         Err(e) => {
             // Truncate content preview to first 500 chars
@@ -518,11 +546,11 @@ pub async fn read_opencode_config(
                 content
             };
 
-            Ok(ReadConfigResult::ParseError {
-                path: config_path_str,
+            ReadConfigResult::ParseError {
+                path: config_path_str.to_string(),
                 error: e.to_string(),
                 content_preview: Some(preview),
-            })
+            }
         }
     }
 }
@@ -545,6 +573,7 @@ pub async fn backup_opencode_config(state: tauri::State<'_, DbState>) -> Result<
     // Rename the file to backup
     fs::rename(config_path, backup_path)
         .map_err(|e| format!("Failed to backup config file: {}", e))?;
+    super::config_cache::invalidate_cached_config();
 
     Ok(backup_path_str.to_string())
 }
@@ -566,8 +595,50 @@ pub async fn apply_config_internal<R: tauri::Runtime>(
     config: OpenCodeConfig,
     from_tray: bool,
 ) -> Result<(), String> {
-    write_opencode_config_file(state.clone(), &config).await?;
+    {
+        let _write_guard = opencode_config_write_lock().lock().await;
+        write_opencode_config_file(state.clone(), &config).await?;
+    }
+
+    notify_config_applied(state, app, config, from_tray);
+
+    Ok(())
+}
+
+/// Read-modify-write the config file while holding the write lock
+///
+/// `update` receives the current read result and returns the config to write, so callers
+/// decide how to handle missing or unparsable files.
+pub async fn update_config_internal<R, F>(
+    state: tauri::State<'_, DbState>,
+    app: &tauri::AppHandle<R>,
+    from_tray: bool,
+    update: F,
+) -> Result<(), String>
+where
+    R: tauri::Runtime,
+    F: FnOnce(ReadConfigResult) -> Result<OpenCodeConfig, String>,
+{
+    let config = {
+        let _write_guard = opencode_config_write_lock().lock().await;
+        let current = read_opencode_config(state.clone()).await?;
+        let config = update(current)?;
+        write_opencode_config_file(state.clone(), &config).await?;
+        config
+    };
 
+    notify_config_applied(state, app, config, from_tray);
+
+    Ok(())
+}
+
+/// Emit change events and sync favorite providers after a config write
+fn notify_config_applied<R: tauri::Runtime>(
+    state: tauri::State<'_, DbState>,
+    app: &tauri::AppHandle<R>,
+    config: OpenCodeConfig,
+    from_tray: bool,
+) {
     // Notify based on source
     let payload = if from_tray { "tray" } else { "window" };
     let _ = app.emit("config-changed", payload);
@@ -583,8 +654,6 @@ pub async fn apply_config_internal<R: tauri::Runtime>(
             eprintln!("Background sync_providers_from_config failed: {}", e);
         }
     });
-
-    Ok(())
 }
 
 // ============================================================================
@@ -645,7 +714,7 @@ pub async fn create_opencode_prompt_config(
     input: OpenCodePromptConfigInput,
 ) -> Result<OpenCodePromptConfig, String> {
     let db = state.db();
-    let now = chrono::Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let sort_index_result: Result<Vec<Value>, _> = db
         .query("SELECT sort_index FROM opencode_prompt_config ORDER BY sort_index DESC LIMIT 1")
         .await
@@ -664,7 +733,7 @@ pub async fn create_opencode_prompt_config(
         content: input.content,
         is_applied: false,
         sort_index: Some(next_sort_index),
-        created_at: now.clone(),
+        created_at: now,
         updated_at: now,
     };
 
@@ -673,7 +742,7 @@ pub async fn create_opencode_prompt_config(
     let record_id = db_record_id("opencode_prompt_config", &prompt_id);
 
     db.query(&format!("CREATE {} CONTENT $data", record_id))
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to create prompt config: {}", e))?;
 
@@ -730,13 +799,8 @@ pub async fn update_opencode_prompt_config(
     let (created_at, is_applied, sort_index) = match existing_result {
         Ok(records) => {
             if let Some(record) = records.first() {
-                let created_at = record
-                    .get("created_at")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or_else(|| {
-                        Box::leak(chrono::Local::now().to_rfc3339().into_boxed_str())
-                    })
-                    .to_string();
+                let created_at =
+                    Timestamp::from_db(record, "created_at").unwrap_or_else(Timestamp::now);
                 let is_applied = record
                     .get("is_applied")
                     .or_else(|| record.get("isApplied"))
@@ -755,19 +819,19 @@ pub async fn update_opencode_prompt_config(
         Err(e) => return Err(format!("Failed to deserialize prompt config: {}", e)),
     };
 
-    let now = chrono::Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let content = OpenCodePromptConfigContent {
         name: input.name,
         content: input.content.clone(),
         is_applied,
         sort_index,
         created_at,
-        updated_at: now.clone(),
+        updated_at: now,
     };
     let json_data = adapter::to_db_value_prompt_config(&content);
 
     db.query(&format!("UPDATE {} CONTENT $data", record_id))
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to update prompt config: {}", e))?;
 
@@ -852,20 +916,13 @@ pub async fn apply_prompt_config_internal<R: tauri::Runtime>(
         Err(e) => return Err(format!("Failed to deserialize prompt config: {}", e)),
     };
 
-    let now = chrono::Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
-    db.query("UPDATE opencode_prompt_config SET is_applied = false, updated_at = $now WHERE is_applied = true")
-        .bind(("now", now.clone()))
+    db.query(db_mark_applied_query("opencode_prompt_config", config_id))
+        .bind(("now", now.to_db()))
         .await
-        .map_err(|e| format!("Failed to clear prompt applied flags: {}", e))?;
-
-    db.query(&format!(
-        "UPDATE {} SET is_applied = true, updated_at = $now",
-        record_id
-    ))
-    .bind(("now", now))
-    .await
-    .map_err(|e| format!("Failed to set prompt applied flag: {}", e))?;
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to set prompt applied flag: {}", e))?;
 
     drop(db);
 
@@ -895,13 +952,10 @@ pub async fn reorder_opencode_prompt_configs(
 ) -> Result<(), String> {
     let db = state.db();
 
-    for (index, id) in ids.iter().enumerate() {
-        let record_id = db_record_id("opencode_prompt_config", id);
-        db.query(&format!("UPDATE {} SET sort_index = $index", record_id))
-            .bind(("index", index as i32))
-            .await
-            .map_err(|e| format!("Failed to update prompt sort index: {}", e))?;
-    }
+    db.query(db_reorder_query("opencode_prompt_config", &ids, false))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to update prompt sort index: {}", e))?;
 
     drop(db);
     let _ = app.emit("config-changed", "window");
@@ -985,15 +1039,7 @@ pub async fn get_opencode_common_config(
                 Ok(None)
             }
         }
-        Err(e) => {
-            // 反序列化失败，删除旧数据以修复版本冲突
-            eprintln!(
-                "⚠️ OpenCode common config has incompatible format, cleaning up: {}",
-                e
-            );
-            let _ = db.query("DELETE opencode_common_config:`common`").await;
-            Ok(None)
-        }
+        Err(e) => Err(format!("Failed to parse opencode common config: {}", e)),
     }
 }
 
@@ -1011,7 +1057,7 @@ pub async fn save_opencode_common_config(
 
     // Use UPSERT to handle both update and create
     db.query("UPSERT opencode_common_config:`common` CONTENT $data")
-        .bind(("data", json_data))
+        .bind(("data", with_datetimes(json_data)))
         .await
         .map_err(|e| format!("Failed to save opencode common config: {}", e))?;
 
@@ -1124,7 +1170,7 @@ const DEFAULT_FAVORITE_PLUGINS: &[&str] = &[
 async fn init_default_favorite_plugins(
     db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
 ) -> Result<(), String> {
-    let now = chrono::Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
     for plugin_name in DEFAULT_FAVORITE_PLUGINS {
         let normalized_plugin_name = normalize_favorite_plugin_name(plugin_name);
@@ -1135,7 +1181,7 @@ async fn init_default_favorite_plugins(
         );
         db.query(&query)
             .bind(("plugin_name", normalized_plugin_name))
-            .bind(("created_at", now.clone()))
+            .bind(("created_at", now.to_db()))
             .await
             .map_err(|e| format!("Failed to initialize favorite plugin: {}", e))?;
     }
@@ -1202,7 +1248,7 @@ pub async fn add_opencode_favorite_plugin(
     plugin_name: String,
 ) -> Result<OpenCodeFavoritePlugin, String> {
     let db = state.db();
-    let now = chrono::Local::now().to_rfc3339();
+    let now = Timestamp::now();
     let normalized_plugin_name = normalize_favorite_plugin_name(&plugin_name);
     let plugin_aliases = favorite_plugin_aliases(&plugin_name);
 
@@ -1229,7 +1275,7 @@ pub async fn add_opencode_favorite_plugin(
     let query = format!("INSERT IGNORE INTO opencode_favorite_plugin {{ id: {}, plugin_name: $plugin_name, created_at: $created_at }}", record_id);
     db.query(&query)
         .bind(("plugin_name", normalized_plugin_name.clone()))
-        .bind(("created_at", now.clone()))
+        .bind(("created_at", now.to_db()))
         .await
         .map_err(|e| format!("Failed to add favorite plugin: {}", e))?;
 
@@ -1333,7 +1379,7 @@ async fn sync_providers_from_config(
         }
     }
 
-    let now = chrono::Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
     for (provider_id, provider_config) in providers.iter() {
         let npm = provider_config.npm.clone().unwrap_or_default();
@@ -1367,7 +1413,7 @@ async fn sync_providers_from_config(
             .bind(("npm", npm))
             .bind(("base_url", base_url))
             .bind(("provider_config", provider_config_json))
-            .bind(("updated_at", now.clone()))
+            .bind(("updated_at", now.to_db()))
             .await
             .map_err(|e| format!("Failed to update favorite provider: {}", e))?;
         } else {
@@ -1380,8 +1426,8 @@ async fn sync_providers_from_config(
             .bind(("npm", npm))
             .bind(("base_url", base_url))
             .bind(("provider_config", provider_config_json))
-            .bind(("created_at", now.clone()))
-            .bind(("updated_at", now.clone()))
+            .bind(("created_at", now.to_db()))
+            .bind(("updated_at", now.to_db()))
             .await
             .map_err(|e| format!("Failed to insert favorite provider: {}", e))?;
         }
@@ -1426,7 +1472,7 @@ pub async fn upsert_opencode_favorite_provider(
     diagnostics: Option<OpenCodeDiagnosticsConfig>,
 ) -> Result<OpenCodeFavoriteProvider, String> {
     let db = state.db();
-    let now = chrono::Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
     // Extract npm and base_url from provider_config
     let npm = provider_config.npm.clone().unwrap_or_default();
@@ -1454,8 +1500,8 @@ pub async fn upsert_opencode_favorite_provider(
     let has_existing = existing_record.is_some();
     let created_at = existing_record
         .as_ref()
-        .map(|record| record.created_at.clone())
-        .unwrap_or_else(|| now.clone());
+        .and_then(|record| record.created_at)
+        .unwrap_or(now);
     let diagnostics_to_save = diagnostics.or_else(|| {
         existing_record
             .as_ref()
@@ -1469,7 +1515,7 @@ pub async fn upsert_opencode_favorite_provider(
             .bind(("base_url", base_url))
             .bind(("provider_config", provider_config_json))
             .bind(("diagnostics", diagnostics_to_save))
-            .bind(("updated_at", now.clone()))
+            .bind(("updated_at", now.to_db()))
             .await
             .map_err(|e| format!("Failed to update favorite provider: {}", e))?;
     } else {
@@ -1480,8 +1526,8 @@ pub async fn upsert_opencode_favorite_provider(
             .bind(("base_url", base_url))
             .bind(("provider_config", provider_config_json))
             .bind(("diagnostics", diagnostics_to_save))
-            .bind(("created_at", created_at))
-            .bind(("updated_at", now.clone()))
+            .bind(("created_at", created_at.to_db()))
+            .bind(("updated_at", now.to_db()))
             .await
             .map_err(|e| format!("Failed to insert favorite provider: {}", e))?;
     }
@@ -0,0 +1,204 @@
+//! In-memory cache for the parsed OpenCode config file
+//!
+//! `read_opencode_config` is called several times per tray open (tray menu, unified models,
+//! auth providers). The cache keeps the last parse result keyed by path + mtime + size, and a
+//! file watcher on the config directory drops the entry as soon as the file changes on disk.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use super::types::ReadConfigResult;
+
+/// Modification time and size of a config file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigFingerprint {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+struct CachedConfig {
+    path: PathBuf,
+    fingerprint: ConfigFingerprint,
+    result: ReadConfigResult,
+}
+
+struct ConfigWatcher {
+    path: PathBuf,
+    _watcher: RecommendedWatcher,
+}
+
+static CONFIG_CACHE: Mutex<Option<CachedConfig>> = Mutex::new(None);
+static CONFIG_WATCHER: Mutex<Option<ConfigWatcher>> = Mutex::new(None);
+
+/// Fingerprint of the config file as it is now; take it before reading the file
+pub fn file_fingerprint(path: &Path) -> Option<ConfigFingerprint> {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some(ConfigFingerprint {
+        modified: metadata.modified().ok(),
+        len: metadata.len(),
+    })
+}
+
+/// Return the cached parse result if the file has not changed since it was cached
+pub fn get_cached_config(path: &Path) -> Option<ReadConfigResult> {
+    let fingerprint = file_fingerprint(path)?;
+    let guard = CONFIG_CACHE.lock().ok()?;
+    let cached = guard.as_ref()?;
+
+    if cached.path != path || cached.fingerprint != fingerprint {
+        return None;
+    }
+
+    Some(cached.result.clone())
+}
+
+/// Store a parse result for the given config file and make sure it is being watched
+///
+/// `fingerprint` is the one taken before the file was read. The result is discarded when
+/// the file changed since, as it may describe either version.
+pub fn store_cached_config(path: &Path, fingerprint: ConfigFingerprint, result: &ReadConfigResult) {
+    // Without a reliable mtime we cannot tell whether the file changed, so never cache it
+    if fingerprint.modified.is_none() || file_fingerprint(path) != Some(fingerprint) {
+        return;
+    }
+
+    if let Ok(mut guard) = CONFIG_CACHE.lock() {
+        *guard = Some(CachedConfig {
+            path: path.to_path_buf(),
+            fingerprint,
+            result: result.clone(),
+        });
+    }
+
+    ensure_watching(path);
+}
+
+/// Drop the cached config (called after writes and on file change events)
+pub fn invalidate_cached_config() {
+    if let Ok(mut guard) = CONFIG_CACHE.lock() {
+        *guard = None;
+    }
+}
+
+/// Watch the config file's parent directory (editors often replace the file via rename,
+/// which a watch on the file itself would miss). Re-arms when the config path changes.
+fn ensure_watching(path: &Path) {
+    let Ok(mut guard) = CONFIG_WATCHER.lock() else {
+        return;
+    };
+
+    if guard.as_ref().map(|w| w.path.as_path()) == Some(path) {
+        return;
+    }
+
+    let Some(watch_dir) = path.parent() else {
+        return;
+    };
+
+    let watched_path = path.to_path_buf();
+    let watcher_result = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        let Ok(event) = res else {
+            return;
+        };
+        if event.paths.iter().any(|p| p == &watched_path) {
+            invalidate_cached_config();
+        }
+    });
+
+    let mut watcher = match watcher_result {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            log::warn!("Failed to create OpenCode config watcher: {}", e);
+            *guard = None;
+            return;
+        }
+    };
+
+    if let Err(e) = watcher.watch(watch_dir, RecursiveMode::NonRecursive) {
+        log::warn!(
+            "Failed to watch OpenCode config directory {:?}: {}",
+            watch_dir,
+            e
+        );
+        *guard = None;
+        return;
+    }
+
+    *guard = Some(ConfigWatcher {
+        path: path.to_path_buf(),
+        _watcher: watcher,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The cache is process-wide, so tests touching it must not interleave
+    static TEST_LOCK: Mutex<()> = Mutex::new(());
+
+    fn parse_error(error: &str) -> ReadConfigResult {
+        ReadConfigResult::ParseError {
+            path: String::new(),
+            error: error.to_string(),
+            content_preview: None,
+        }
+    }
+
+    fn cached_error(path: &Path) -> Option<String> {
+        match get_cached_config(path)? {
+            ReadConfigResult::ParseError { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn serves_the_stored_result_until_the_file_changes() {
+        let _guard = TEST_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("opencode.json");
+        std::fs::write(&path, "{}").unwrap();
+
+        let fingerprint = file_fingerprint(&path).unwrap();
+        store_cached_config(&path, fingerprint, &parse_error("first"));
+        assert_eq!(cached_error(&path).as_deref(), Some("first"));
+        assert_eq!(cached_error(&dir.path().join("other.json")), None);
+
+        std::fs::write(&path, "{ \"model\": \"a/b\" }").unwrap();
+        assert_eq!(cached_error(&path), None);
+    }
+
+    #[test]
+    fn discards_results_read_from_an_older_version() {
+        let _guard = TEST_LOCK.lock().unwrap();
+        invalidate_cached_config();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("opencode.json");
+        std::fs::write(&path, "{}").unwrap();
+
+        let fingerprint = file_fingerprint(&path).unwrap();
+        std::fs::write(&path, "{ \"model\": \"a/b\" }").unwrap();
+        store_cached_config(&path, fingerprint, &parse_error("stale"));
+        assert_eq!(cached_error(&path), None);
+    }
+
+    #[test]
+    fn invalidation_drops_the_entry() {
+        let _guard = TEST_LOCK.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("opencode.json");
+        std::fs::write(&path, "{}").unwrap();
+
+        store_cached_config(
+            &path,
+            file_fingerprint(&path).unwrap(),
+            &parse_error("cached"),
+        );
+        assert_eq!(cached_error(&path).as_deref(), Some("cached"));
+        invalidate_cached_config();
+        assert_eq!(cached_error(&path), None);
+    }
+}
@@ -0,0 +1,304 @@
+use std::path::{Path, PathBuf};
+
+use super::commands::get_opencode_config_path;
+use crate::db::DbState;
+
+/// Resolve the opencode binary, preferring well-known install locations over PATH lookup
+pub(crate) fn resolve_opencode_binary_path() -> PathBuf {
+    let binary_name = if cfg!(target_os = "windows") {
+        "opencode.cmd"
+    } else {
+        "opencode"
+    };
+
+    // Installers put a native binary or an npm shim next to each other on Windows
+    let file_names: &[&str] = if cfg!(target_os = "windows") {
+        &["opencode.exe", "opencode.cmd"]
+    } else {
+        &["opencode"]
+    };
+
+    let mut candidate_dirs = Vec::new();
+
+    if let Some(home_dir) = dirs::home_dir() {
+        candidate_dirs.push(home_dir.join(".opencode").join("bin"));
+        candidate_dirs.push(home_dir.join(".local").join("bin"));
+        candidate_dirs.push(home_dir.join(".bun").join("bin"));
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        if let Some(app_data) = std::env::var_os("APPDATA") {
+            candidate_dirs.push(PathBuf::from(app_data).join("npm"));
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        candidate_dirs.push(PathBuf::from("/opt/homebrew/bin"));
+        candidate_dirs.push(PathBuf::from("/usr/local/bin"));
+    }
+
+    candidate_dirs
+        .iter()
+        .flat_map(|dir| file_names.iter().map(move |name| dir.join(name)))
+        .find(|path| path.is_file())
+        .unwrap_or_else(|| PathBuf::from(binary_name))
+}
+
+/// Quote a value for POSIX shells using single quotes
+fn posix_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// Quote a value for cmd.exe using double quotes
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn cmd_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+/// Build the POSIX shell command line that runs opencode inside the terminal
+#[cfg_attr(target_os = "windows", allow(dead_code))]
+fn build_posix_launch_script(
+    binary: &str,
+    config_path: &str,
+    project_path: &str,
+    model_override: Option<&str>,
+) -> String {
+    let mut script = format!(
+        "cd {} && OPENCODE_CONFIG={} {}",
+        posix_quote(project_path),
+        posix_quote(config_path),
+        posix_quote(binary)
+    );
+    if let Some(model) = model_override {
+        script.push_str(&format!(" --model {}", posix_quote(model)));
+    }
+    script
+}
+
+/// Build the cmd.exe command line that runs opencode inside the terminal
+///
+/// `cmd /k` strips the first and last quote of a command line that starts with one, so
+/// the whole script is wrapped in an extra pair that is stripped instead.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn build_cmd_launch_script(binary: &str, model_override: Option<&str>) -> String {
+    let mut script = cmd_quote(binary);
+    if let Some(model) = model_override {
+        script.push_str(&format!(" --model {}", cmd_quote(model)));
+    }
+    format!("\"{}\"", script)
+}
+
+/// Validate a model override in "provider_id/model_id" format
+fn normalize_model_override(model_override: Option<String>) -> Result<Option<String>, String> {
+    let Some(model) = model_override else {
+        return Ok(None);
+    };
+    let model = model.trim();
+    if model.is_empty() {
+        return Ok(None);
+    }
+
+    match model.split_once('/') {
+        Some((provider_id, model_id)) if !provider_id.is_empty() && !model_id.is_empty() => {
+            Ok(Some(model.to_string()))
+        }
+        _ => Err(format!(
+            "Invalid model '{}', expected format 'provider_id/model_id'",
+            model
+        )),
+    }
+}
+
+/// Resolve the working directory for the session, falling back to the home directory
+fn resolve_project_dir(project_path: Option<String>) -> Result<PathBuf, String> {
+    match project_path
+        .map(|path| path.trim().to_string())
+        .filter(|path| !path.is_empty())
+    {
+        Some(path) => {
+            let expanded = crate::coding::expand_local_path(&path)?;
+            let dir = PathBuf::from(expanded);
+            if !dir.is_dir() {
+                return Err(format!("Project directory does not exist: {}", path));
+            }
+            Ok(dir)
+        }
+        None => dirs::home_dir().ok_or_else(|| "Failed to get home directory".to_string()),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn spawn_terminal(
+    binary: &Path,
+    config_path: &str,
+    project_dir: &Path,
+    model_override: Option<&str>,
+) -> Result<(), String> {
+    use std::os::windows::process::CommandExt;
+    const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+    // `start` opens a new console window which inherits the environment and cwd set here
+    let script = build_cmd_launch_script(&binary.to_string_lossy(), model_override);
+    std::process::Command::new("cmd")
+        .args(["/c", "start", "", "cmd", "/k"])
+        .raw_arg(script)
+        .current_dir(project_dir)
+        .env("OPENCODE_CONFIG", config_path)
+        .creation_flags(CREATE_NO_WINDOW)
+        .spawn()
+        .map_err(|e| format!("Failed to open terminal: {}", e))?;
+
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn spawn_terminal(
+    binary: &Path,
+    config_path: &str,
+    project_dir: &Path,
+    model_override: Option<&str>,
+) -> Result<(), String> {
+    // Terminal.app does not inherit our environment, so everything goes into the script
+    let script = build_posix_launch_script(
+        &binary.to_string_lossy(),
+        config_path,
+        &project_dir.to_string_lossy(),
+        model_override,
+    );
+    let apple_script = format!(
+        "tell application \"Terminal\"\n activate\n do script \"{}\"\nend tell",
+        script.replace('\\', "\\\\").replace('"', "\\\"")
+    );
+
+    std::process::Command::new("osascript")
+        .args(["-e", &apple_script])
+        .spawn()
+        .map_err(|e| format!("Failed to open terminal: {}", e))?;
+
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn spawn_terminal(
+    binary: &Path,
+    config_path: &str,
+    project_dir: &Path,
+    model_override: Option<&str>,
+) -> Result<(), String> {
+    let script = build_posix_launch_script(
+        &binary.to_string_lossy(),
+        config_path,
+        &project_dir.to_string_lossy(),
+        model_override,
+    );
+    // Keep the terminal open after opencode exits so errors stay visible
+    let shell_script = format!("{}; exec \"${{SHELL:-sh}}\"", script);
+
+    let terminals: [(&str, &[&str]); 6] = [
+        ("x-terminal-emulator", &["-e"]),
+        ("gnome-terminal", &["--"]),
+        ("konsole", &["-e"]),
+        ("xfce4-terminal", &["-x"]),
+        ("alacritty", &["-e"]),
+        ("xterm", &["-e"]),
+    ];
+
+    let mut last_error: Option<String> = None;
+    for (terminal, exec_args) in terminals {
+        match std::process::Command::new(terminal)
+            .args(exec_args)
+            .args(["sh", "-c", &shell_script])
+            .current_dir(project_dir)
+            .spawn()
+        {
+            Ok(_) => return Ok(()),
+            Err(e) => last_error = Some(format!("{}: {}", terminal, e)),
+        }
+    }
+
+    Err(format!(
+        "Failed to open terminal: no supported terminal emulator found ({})",
+        last_error.unwrap_or_default()
+    ))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+fn spawn_terminal(
+    _binary: &Path,
+    _config_path: &str,
+    _project_dir: &Path,
+    _model_override: Option<&str>,
+) -> Result<(), String> {
+    Err("Launching OpenCode is not supported on this platform".to_string())
+}
+
+/// Launch an OpenCode session in the user's terminal
+///
+/// Uses the same config path resolution as the rest of the app (common config > env > shell > default)
+/// so the session always runs against the config currently managed by AI Toolbox.
+#[tauri::command]
+pub async fn launch_opencode(
+    state: tauri::State<'_, DbState>,
+    project_path: Option<String>,
+    model_override: Option<String>,
+) -> Result<(), String> {
+    let model_override = normalize_model_override(model_override)?;
+    let project_dir = resolve_project_dir(project_path)?;
+    let config_path = get_opencode_config_path(state).await?;
+    let binary = resolve_opencode_binary_path();
+
+    log::info!(
+        "Launching OpenCode in {:?} (config: {}, model: {:?})",
+        project_dir,
+        config_path,
+        model_override
+    );
+
+    spawn_terminal(
+        &binary,
+        &config_path,
+        &project_dir,
+        model_override.as_deref(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn posix_launch_script_quotes_paths_and_model() {
+        assert_eq!(
+            build_posix_launch_script(
+                "/usr/local/bin/opencode",
+                "/home/me/it's/opencode.json",
+                "/work/my project",
+                Some("openai/gpt-5"),
+            ),
+            "cd '/work/my project' && OPENCODE_CONFIG='/home/me/it'\\''s/opencode.json' '/usr/local/bin/opencode' --model 'openai/gpt-5'"
+        );
+    }
+
+    #[test]
+    fn cmd_launch_script_quotes_binary_and_model() {
+        assert_eq!(
+            build_cmd_launch_script(r"C:\Tools\opencode.cmd", Some("anthropic/claude")),
+            r#"""C:\Tools\opencode.cmd" --model "anthropic/claude"""#
+        );
+        assert_eq!(build_cmd_launch_script("opencode", None), r#"""opencode"""#);
+    }
+
+    #[test]
+    fn model_override_requires_provider_and_model() {
+        assert_eq!(normalize_model_override(None), Ok(None));
+        assert_eq!(normalize_model_override(Some("  ".to_string())), Ok(None));
+        assert_eq!(
+            normalize_model_override(Some(" openai/gpt-5 ".to_string())),
+            Ok(Some("openai/gpt-5".to_string()))
+        );
+        assert!(normalize_model_override(Some("gpt-5".to_string())).is_err());
+        assert!(normalize_model_override(Some("/gpt-5".to_string())).is_err());
+    }
+}
@@ -1,12 +1,19 @@
 pub mod adapter;
 pub mod commands;
+pub mod config_cache;
 pub mod free_models;
+pub mod launcher;
 pub mod models_api;
 pub mod shell_env;
 pub mod tray_support;
 pub mod types;
+pub mod update_check;
+pub mod variants;
 
 pub use commands::*;
 pub use free_models::*;
+pub use launcher::*;
 pub use models_api::*;
 pub use types::*;
+pub use update_check::*;
+pub use variants::*;
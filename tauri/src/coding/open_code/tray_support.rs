@@ -9,7 +9,9 @@ use crate::coding::open_code::commands::{
 use crate::coding::open_code::free_models;
 use crate::coding::open_code::types::{OpenCodeProvider, ReadConfigResult, UnifiedModelOption};
 use crate::coding::open_code::{read_opencode_config, OpenCodeConfig};
+use crate::db::DbState;
 use indexmap::IndexMap;
+use serde_json::json;
 use std::collections::HashSet;
 use tauri::{AppHandle, Manager, Runtime};
 
@@ -50,8 +52,13 @@ pub struct TrayModelData {
     pub current_display: String,
     /// List of available models
     pub items: Vec<TrayModelItem>,
+    /// Recently applied models, most recent first (subset of `items`)
+    pub recent: Vec<TrayModelItem>,
 }
 
+/// Number of recently applied models kept per model type
+const MAX_RECENT_MODELS: usize = 5;
+
 /// Get tray model data for both main and small models
 /// Uses the unified model fetching logic that combines custom providers and official auth providers
 pub async fn get_opencode_tray_model_data<R: Runtime>(
@@ -131,16 +138,21 @@ pub async fn get_opencode_tray_model_data<R: Runtime>(
     let main_display = find_model_display_name(&main_items, current_main);
     let small_display = find_model_display_name(&small_items, current_small);
 
+    let main_recent = recent_items(&main_items, &load_recent_models(app, "main").await);
+    let small_recent = recent_items(&small_items, &load_recent_models(app, "small").await);
+
     let main_data = TrayModelData {
         title: "主模型".to_string(),
         current_display: main_display,
         items: main_items,
+        recent: main_recent,
     };
 
     let small_data = TrayModelData {
         title: "小模型".to_string(),
         current_display: small_display,
         items: small_items,
+        recent: small_recent,
     };
 
     Ok((main_data, small_data))
@@ -188,28 +200,95 @@ pub async fn apply_opencode_model<R: Runtime>(
     let provider_id = parts[0];
     let model_id = parts[1];
 
-    // Read current config
-    let result = read_opencode_config(app.state()).await?;
-    let mut config = extract_config_or_default(result);
-
     // Build new config value: "provider_id/model_id" format
     let new_model_value = format!("{}/{}", provider_id, model_id);
 
-    // Update config
-    if model_type == "main" {
-        config.model = Some(new_model_value);
-    } else if model_type == "small" {
-        config.small_model = Some(new_model_value);
-    } else {
-        return Err(format!("Invalid model type: {}", model_type));
-    }
+    // Update config under the write lock and save from tray (will emit "tray" event)
+    super::commands::update_config_internal(app.state(), app, true, |result| {
+        let mut config = extract_config_or_default(result);
 
-    // Save config from tray (will emit "tray" event)
-    super::commands::apply_config_internal(app.state(), app, config, true).await?;
+        if model_type == "main" {
+            config.model = Some(new_model_value);
+        } else if model_type == "small" {
+            config.small_model = Some(new_model_value);
+        } else {
+            return Err(format!("Invalid model type: {}", model_type));
+        }
+
+        Ok(config)
+    })
+    .await?;
+
+    record_recent_model(app, model_type, item_id).await;
 
     Ok(())
 }
 
+/// Recently applied model ids for "main" or "small", most recent first
+async fn load_recent_models<R: Runtime>(app: &AppHandle<R>, model_type: &str) -> Vec<String> {
+    let state = app.state::<DbState>();
+    let db = state.db();
+    let records: Vec<serde_json::Value> = match db
+        .query(format!(
+            "SELECT items FROM opencode_recent_model:`{}` LIMIT 1",
+            model_type
+        ))
+        .await
+        .and_then(|mut result| result.take(0))
+    {
+        Ok(records) => records,
+        Err(e) => {
+            log::warn!("Failed to load recent OpenCode models: {}", e);
+            return Vec::new();
+        }
+    };
+
+    records
+        .first()
+        .and_then(|record| record.get("items"))
+        .and_then(|items| items.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Remember an applied model; failures only affect the "Recent" tray group
+async fn record_recent_model<R: Runtime>(app: &AppHandle<R>, model_type: &str, item_id: &str) {
+    let recent = push_recent(load_recent_models(app, model_type).await, item_id);
+    let state = app.state::<DbState>();
+    let db = state.db();
+    if let Err(e) = db
+        .query(format!(
+            "UPSERT opencode_recent_model:`{}` CONTENT $data",
+            model_type
+        ))
+        .bind(("data", json!({ "items": recent })))
+        .await
+    {
+        log::warn!("Failed to save recent OpenCode models: {}", e);
+    }
+}
+
+/// Move `item_id` to the front of the recent list, keeping at most `MAX_RECENT_MODELS`
+fn push_recent(mut recent: Vec<String>, item_id: &str) -> Vec<String> {
+    recent.retain(|id| id != item_id);
+    recent.insert(0, item_id.to_string());
+    recent.truncate(MAX_RECENT_MODELS);
+    recent
+}
+
+/// Recent ids resolved against the available models, skipping ones no longer listed
+fn recent_items(items: &[TrayModelItem], recent_ids: &[String]) -> Vec<TrayModelItem> {
+    recent_ids
+        .iter()
+        .filter_map(|id| items.iter().find(|item| &item.id == id).cloned())
+        .collect()
+}
+
 /// Check if OpenCode models should be shown in tray menu
 /// Returns true - OpenCode models are always visible as a core feature
 pub async fn is_enabled_for_tray<R: Runtime>(_app: &AppHandle<R>) -> bool {
@@ -398,37 +477,76 @@ pub async fn apply_opencode_plugin<R: Runtime>(
     app: &AppHandle<R>,
     plugin_name: &str,
 ) -> Result<(), String> {
-    // Read current config
-    let result = read_opencode_config(app.state()).await?;
-    let mut config = extract_config_or_default(result);
+    // Toggle under the write lock and save from tray (will emit "tray" event)
+    super::commands::update_config_internal(app.state(), app, true, |result| {
+        let mut config = extract_config_or_default(result);
 
-    // Get current plugins or create empty vector
-    let mut plugins = config.plugin.unwrap_or_default();
+        // Get current plugins or create empty vector
+        let mut plugins = config.plugin.unwrap_or_default();
 
-    // Toggle plugin selection
-    if plugins
-        .iter()
-        .any(|existing| is_opencode_plugin_equivalent(existing, plugin_name))
-    {
-        // Remove if already selected
-        plugins.retain(|existing| !is_opencode_plugin_equivalent(existing, plugin_name));
-    } else {
-        // Add if not selected
-        plugins.push(plugin_name.to_string());
-
-        // Handle mutual exclusivity - remove mutually exclusive plugins
-        for (exclusive_a, exclusive_b) in MUTUALLY_EXCLUSIVE_PLUGINS {
-            if is_opencode_plugin_equivalent(plugin_name, exclusive_a) {
-                plugins.retain(|existing| !is_opencode_plugin_equivalent(existing, exclusive_b));
+        // Toggle plugin selection
+        if plugins
+            .iter()
+            .any(|existing| is_opencode_plugin_equivalent(existing, plugin_name))
+        {
+            // Remove if already selected
+            plugins.retain(|existing| !is_opencode_plugin_equivalent(existing, plugin_name));
+        } else {
+            // Add if not selected
+            plugins.push(plugin_name.to_string());
+
+            // Handle mutual exclusivity - remove mutually exclusive plugins
+            for (exclusive_a, exclusive_b) in MUTUALLY_EXCLUSIVE_PLUGINS {
+                if is_opencode_plugin_equivalent(plugin_name, exclusive_a) {
+                    plugins
+                        .retain(|existing| !is_opencode_plugin_equivalent(existing, exclusive_b));
+                }
             }
         }
-    }
 
-    // Update config
-    config.plugin = Some(sanitize_opencode_plugin_list(&plugins));
+        // Update config
+        config.plugin = Some(sanitize_opencode_plugin_list(&plugins));
 
-    // Save config from tray (will emit "tray" event)
-    super::commands::apply_config_internal(app.state(), app, config, true).await?;
+        Ok(config)
+    })
+    .await?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_recent_moves_to_front_and_bounds_length() {
+        let recent: Vec<String> = ["a/1", "b/2", "c/3", "d/4", "e/5"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let recent = push_recent(recent, "c/3");
+        assert_eq!(recent, vec!["c/3", "a/1", "b/2", "d/4", "e/5"]);
+        let recent = push_recent(recent, "f/6");
+        assert_eq!(recent, vec!["f/6", "c/3", "a/1", "b/2", "d/4"]);
+    }
+
+    #[test]
+    fn recent_items_skip_unknown_models() {
+        let items = vec![
+            TrayModelItem {
+                id: "a/1".to_string(),
+                display_name: "A / 1".to_string(),
+                is_selected: false,
+            },
+            TrayModelItem {
+                id: "b/2".to_string(),
+                display_name: "B / 2".to_string(),
+                is_selected: true,
+            },
+        ];
+        let recent = recent_items(&items, &["b/2".to_string(), "gone/x".to_string()]);
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].id, "b/2");
+        assert!(recent[0].is_selected);
+    }
+}
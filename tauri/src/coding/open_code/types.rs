@@ -3,6 +3,8 @@ use serde::{Deserialize, Deserializer, Serialize};
 
 use surrealdb::sql::Thing;
 
+use crate::db_timestamp::Timestamp;
+
 /// Deserialize a JSON value, normalizing null and empty objects to None
 fn deserialize_nullable_value<'de, D>(
     deserializer: D,
@@ -38,7 +40,8 @@ pub struct OpenCodeCommonConfigRecord {
     pub id: Thing,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub config_path: Option<String>,
-    pub updated_at: String,
+    #[serde(default)]
+    pub updated_at: Option<Timestamp>,
 }
 
 /// OpenCodeCommonConfig - API response
@@ -50,7 +53,7 @@ pub struct OpenCodeCommonConfig {
     /// Whether to show plugins in tray/menu bar
     #[serde(default)]
     pub show_plugins_in_tray: bool,
-    pub updated_at: String,
+    pub updated_at: Timestamp,
 }
 
 // ============================================================================
@@ -228,10 +231,18 @@ pub struct OpenCodePromptConfig {
     pub is_applied: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_index: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::db_timestamp::lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub created_at: Option<Timestamp>,
+    #[serde(
+        default,
+        deserialize_with = "crate::db_timestamp::lenient",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub updated_at: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -241,8 +252,8 @@ pub struct OpenCodePromptConfigContent {
     pub is_applied: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_index: Option<i32>,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
 }
 
 // ============================================================================
@@ -315,7 +326,8 @@ pub struct UnifiedModelOption {
 pub struct OpenCodeFavoritePlugin {
     pub id: String,
     pub plugin_name: String,
-    pub created_at: String,
+    #[serde(default, deserialize_with = "crate::db_timestamp::lenient")]
+    pub created_at: Option<Timestamp>,
 }
 
 // ============================================================================
@@ -384,8 +396,10 @@ pub struct OpenCodeFavoriteProvider {
     /// Saved connectivity diagnostics parameters
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diagnostics: Option<OpenCodeDiagnosticsConfig>,
-    pub created_at: String,
-    pub updated_at: String,
+    #[serde(default, deserialize_with = "crate::db_timestamp::lenient")]
+    pub created_at: Option<Timestamp>,
+    #[serde(default, deserialize_with = "crate::db_timestamp::lenient")]
+    pub updated_at: Option<Timestamp>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
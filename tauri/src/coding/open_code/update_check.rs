@@ -0,0 +1,194 @@
+//! OpenCode release update checker
+//!
+//! Compares the locally installed opencode binary against the latest published release.
+//! The last result is kept in memory so the tray menu can show it without hitting the network.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::launcher::resolve_opencode_binary_path;
+use crate::db::DbState;
+use crate::http_client;
+
+const NPM_LATEST_URL: &str = "https://registry.npmjs.org/opencode-ai/latest";
+const GITHUB_LATEST_URL: &str = "https://api.github.com/repos/sst/opencode/releases/latest";
+const GITHUB_RELEASES_URL: &str = "https://github.com/sst/opencode/releases";
+const VERSION_COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// OpenCode update check result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenCodeUpdateCheckResult {
+    pub installed: bool,
+    pub has_update: bool,
+    pub current_version: Option<String>,
+    pub latest_version: String,
+    pub release_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmLatestResponse {
+    version: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubLatestResponse {
+    tag_name: String,
+}
+
+static LAST_UPDATE_CHECK: Mutex<Option<OpenCodeUpdateCheckResult>> = Mutex::new(None);
+
+/// Last update check result, used by the tray to show an "update available" hint
+pub fn cached_opencode_update() -> Option<OpenCodeUpdateCheckResult> {
+    LAST_UPDATE_CHECK.lock().ok()?.clone()
+}
+
+/// Extract a semver-like version from `opencode --version` output (e.g. "opencode 0.3.1" or "v0.3.1")
+fn parse_version_output(output: &str) -> Option<String> {
+    output
+        .split_whitespace()
+        .map(|token| token.trim_start_matches('v'))
+        .find(|token| {
+            token.contains('.')
+                && token.split('.').next().is_some_and(|major| {
+                    !major.is_empty() && major.chars().all(|c| c.is_ascii_digit())
+                })
+        })
+        .map(|token| token.to_string())
+}
+
+/// Run the installed opencode binary to get its version, None if not installed
+async fn get_installed_version() -> Option<String> {
+    let binary = resolve_opencode_binary_path();
+
+    let mut command = tokio::process::Command::new(&binary);
+    command.arg("--version").kill_on_drop(true);
+
+    #[cfg(target_os = "windows")]
+    {
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = match tokio::time::timeout(VERSION_COMMAND_TIMEOUT, command.output()).await {
+        Ok(Ok(output)) if output.status.success() => output,
+        Ok(Ok(output)) => {
+            log::warn!(
+                "opencode --version exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+            return None;
+        }
+        Ok(Err(e)) => {
+            log::info!("opencode binary not available ({:?}): {}", binary, e);
+            return None;
+        }
+        Err(_) => {
+            log::warn!("opencode --version timed out");
+            return None;
+        }
+    };
+
+    parse_version_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Fetch the latest published version, trying npm first and GitHub releases as fallback
+async fn fetch_latest_version(client: &reqwest::Client) -> Result<String, String> {
+    let npm_error = match client.get(NPM_LATEST_URL).send().await {
+        Ok(response) if response.status().is_success() => {
+            match response.json::<NpmLatestResponse>().await {
+                Ok(latest) => return Ok(latest.version.trim_start_matches('v').to_string()),
+                Err(e) => format!("Failed to parse npm response: {}", e),
+            }
+        }
+        Ok(response) => format!("npm registry returned HTTP {}", response.status()),
+        Err(e) => format!("Failed to query npm registry: {}", e),
+    };
+    log::warn!("{}, falling back to GitHub releases", npm_error);
+
+    let response = client
+        .get(GITHUB_LATEST_URL)
+        .header("User-Agent", "ai-toolbox")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch latest OpenCode release: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Failed to fetch latest OpenCode release: HTTP {}",
+            response.status()
+        ));
+    }
+
+    let release: GithubLatestResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse latest OpenCode release: {}", e))?;
+
+    Ok(release.tag_name.trim_start_matches('v').to_string())
+}
+
+/// Check whether a newer OpenCode release is available for the installed binary
+#[tauri::command]
+pub async fn check_opencode_update(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, DbState>,
+) -> Result<OpenCodeUpdateCheckResult, String> {
+    let client = http_client::client(&state).await?;
+    let (current_version, latest_version) =
+        tokio::join!(get_installed_version(), fetch_latest_version(&client));
+    let latest_version = latest_version?;
+
+    let has_update = current_version
+        .as_deref()
+        .is_some_and(|current| crate::update::compare_versions(&latest_version, current) > 0);
+
+    let result = OpenCodeUpdateCheckResult {
+        installed: current_version.is_some(),
+        has_update,
+        current_version,
+        release_url: format!("{}/tag/v{}", GITHUB_RELEASES_URL, latest_version),
+        latest_version,
+    };
+
+    let changed = match LAST_UPDATE_CHECK.lock() {
+        Ok(mut guard) => {
+            let changed = guard
+                .as_ref()
+                .map(|r| (r.has_update, r.latest_version.as_str()))
+                != Some((result.has_update, result.latest_version.as_str()));
+            *guard = Some(result.clone());
+            changed
+        }
+        Err(_) => false,
+    };
+
+    if changed {
+        let _ = crate::tray::refresh_tray_menus(&app).await;
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_version_output_handles_common_formats() {
+        assert_eq!(
+            parse_version_output("0.3.112\n"),
+            Some("0.3.112".to_string())
+        );
+        assert_eq!(
+            parse_version_output("opencode v1.0.2"),
+            Some("1.0.2".to_string())
+        );
+        assert_eq!(parse_version_output("opencode"), None);
+        assert_eq!(parse_version_output(""), None);
+    }
+}
@@ -0,0 +1,370 @@
+//! Typed editing API for per-model variants in opencode.json
+//!
+//! `OpenCodeModel.variants` stays an opaque JSON object on disk so unknown keys survive
+//! round-trips; these types give the UI a structured view of the well-known fields.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::commands::{read_opencode_config, update_config_internal};
+use super::types::{OpenCodeConfig, OpenCodeModel, ReadConfigResult};
+use crate::db::DbState;
+
+const REASONING_EFFORT_VALUES: &[&str] = &["none", "minimal", "low", "medium", "high", "xhigh"];
+const REASONING_SUMMARY_VALUES: &[&str] = &["auto", "concise", "detailed", "none"];
+const TEXT_VERBOSITY_VALUES: &[&str] = &["low", "medium", "high"];
+const EFFORT_VALUES: &[&str] = &["low", "medium", "high", "max"];
+const THINKING_TYPE_VALUES: &[&str] = &["enabled", "disabled"];
+const THINKING_LEVEL_VALUES: &[&str] = &["minimal", "low", "medium", "high"];
+
+/// Anthropic-style extended thinking (`thinking: { type, budgetTokens }`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenCodeThinkingVariant {
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub thinking_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_tokens: Option<i64>,
+}
+
+/// Google-style thinking config (`thinkingConfig: { includeThoughts, thinkingLevel, thinkingBudget }`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenCodeThinkingConfigVariant {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_thoughts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking_level: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking_budget: Option<i64>,
+}
+
+/// A single model variant (e.g. "high", "thinking")
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenCodeModelVariant {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasoning_effort: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasoning_summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_verbosity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effort: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking: Option<OpenCodeThinkingVariant>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking_config: Option<OpenCodeThinkingConfigVariant>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+    /// Provider-specific keys not covered by the typed fields
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+fn check_allowed(field: &str, value: Option<&str>, allowed: &[&str], errors: &mut Vec<String>) {
+    if let Some(value) = value {
+        if !allowed.contains(&value) {
+            errors.push(format!(
+                "Invalid {} '{}', expected one of: {}",
+                field,
+                value,
+                allowed.join(", ")
+            ));
+        }
+    }
+}
+
+/// Validate a variant, returning all problems found
+pub fn validate_variant(name: &str, variant: &OpenCodeModelVariant) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    if name.trim().is_empty() {
+        errors.push("Variant name cannot be empty".to_string());
+    }
+
+    check_allowed(
+        "reasoningEffort",
+        variant.reasoning_effort.as_deref(),
+        REASONING_EFFORT_VALUES,
+        &mut errors,
+    );
+    check_allowed(
+        "reasoningSummary",
+        variant.reasoning_summary.as_deref(),
+        REASONING_SUMMARY_VALUES,
+        &mut errors,
+    );
+    check_allowed(
+        "textVerbosity",
+        variant.text_verbosity.as_deref(),
+        TEXT_VERBOSITY_VALUES,
+        &mut errors,
+    );
+    check_allowed(
+        "effort",
+        variant.effort.as_deref(),
+        EFFORT_VALUES,
+        &mut errors,
+    );
+
+    if let Some(ref thinking) = variant.thinking {
+        check_allowed(
+            "thinking.type",
+            thinking.thinking_type.as_deref(),
+            THINKING_TYPE_VALUES,
+            &mut errors,
+        );
+        match (thinking.thinking_type.as_deref(), thinking.budget_tokens) {
+            (Some("enabled"), None) => {
+                errors.push("thinking.budgetTokens is required when thinking is enabled".into())
+            }
+            (_, Some(budget)) if budget <= 0 => {
+                errors.push("thinking.budgetTokens must be greater than 0".into())
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(ref thinking_config) = variant.thinking_config {
+        check_allowed(
+            "thinkingConfig.thinkingLevel",
+            thinking_config.thinking_level.as_deref(),
+            THINKING_LEVEL_VALUES,
+            &mut errors,
+        );
+        if thinking_config
+            .thinking_budget
+            .is_some_and(|budget| budget < -1)
+        {
+            errors.push("thinkingConfig.thinkingBudget must be -1 (dynamic) or greater".into());
+        }
+    }
+
+    errors
+}
+
+/// Parse the raw variants object of a model into typed variants
+pub fn parse_model_variants(
+    variants: Option<&Value>,
+) -> Result<IndexMap<String, OpenCodeModelVariant>, String> {
+    let Some(Value::Object(map)) = variants else {
+        return Ok(IndexMap::new());
+    };
+
+    map.iter()
+        .map(|(name, value)| {
+            serde_json::from_value::<OpenCodeModelVariant>(value.clone())
+                .map(|variant| (name.clone(), variant))
+                .map_err(|e| format!("Failed to parse variant '{}': {}", name, e))
+        })
+        .collect()
+}
+
+async fn load_config(state: tauri::State<'_, DbState>) -> Result<OpenCodeConfig, String> {
+    config_from_read_result(read_opencode_config(state).await?)
+}
+
+fn config_from_read_result(result: ReadConfigResult) -> Result<OpenCodeConfig, String> {
+    match result {
+        ReadConfigResult::Success { config } => Ok(config),
+        ReadConfigResult::NotFound { path } => {
+            Err(format!("OpenCode config file not found: {}", path))
+        }
+        ReadConfigResult::ParseError { error, .. } => {
+            Err(format!("Failed to parse OpenCode config: {}", error))
+        }
+        ReadConfigResult::Error { error } => Err(error),
+    }
+}
+
+fn find_model_mut<'a>(
+    config: &'a mut OpenCodeConfig,
+    provider_id: &str,
+    model_id: &str,
+) -> Result<&'a mut OpenCodeModel, String> {
+    config
+        .provider
+        .as_mut()
+        .and_then(|providers| providers.get_mut(provider_id))
+        .ok_or_else(|| format!("Provider '{}' not found", provider_id))?
+        .models
+        .get_mut(model_id)
+        .ok_or_else(|| {
+            format!(
+                "Model '{}' not found in provider '{}'",
+                model_id, provider_id
+            )
+        })
+}
+
+/// Get typed variants of a model
+#[tauri::command]
+pub async fn get_opencode_model_variants(
+    state: tauri::State<'_, DbState>,
+    provider_id: String,
+    model_id: String,
+) -> Result<IndexMap<String, OpenCodeModelVariant>, String> {
+    let mut config = load_config(state).await?;
+    let model = find_model_mut(&mut config, &provider_id, &model_id)?;
+    parse_model_variants(model.variants.as_ref())
+}
+
+/// Validate a variant without saving it
+#[tauri::command]
+pub fn validate_opencode_model_variant(
+    variant_name: String,
+    variant: OpenCodeModelVariant,
+) -> Vec<String> {
+    validate_variant(&variant_name, &variant)
+}
+
+/// Create or replace a variant of a model
+///
+/// When `previous_name` is given and differs from `variant_name`, the variant is renamed
+/// in place so its position in the config is kept.
+#[tauri::command]
+pub async fn upsert_opencode_model_variant(
+    state: tauri::State<'_, DbState>,
+    app: tauri::AppHandle,
+    provider_id: String,
+    model_id: String,
+    variant_name: String,
+    variant: OpenCodeModelVariant,
+    previous_name: Option<String>,
+) -> Result<(), String> {
+    let variant_name = variant_name.trim().to_string();
+    let errors = validate_variant(&variant_name, &variant);
+    if !errors.is_empty() {
+        return Err(errors.join("; "));
+    }
+
+    let variant_value = serde_json::to_value(&variant)
+        .map_err(|e| format!("Failed to serialize variant: {}", e))?;
+
+    update_config_internal(state, &app, false, |result| {
+        let mut config = config_from_read_result(result)?;
+        let model = find_model_mut(&mut config, &provider_id, &model_id)?;
+
+        let mut variants = match model.variants.take() {
+            Some(Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        };
+
+        let previous_name = previous_name
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty() && *name != variant_name);
+
+        if let Some(previous_name) = previous_name {
+            if variants.contains_key(&variant_name) {
+                return Err(format!("Variant '{}' already exists", variant_name));
+            }
+            // Rename in place so the variant keeps its position
+            variants = variants
+                .into_iter()
+                .map(|(name, value)| {
+                    if name == previous_name {
+                        (variant_name.clone(), value)
+                    } else {
+                        (name, value)
+                    }
+                })
+                .collect();
+        }
+        variants.insert(variant_name, variant_value);
+
+        model.variants = Some(Value::Object(variants));
+
+        Ok(config)
+    })
+    .await
+}
+
+/// Delete a variant of a model
+#[tauri::command]
+pub async fn delete_opencode_model_variant(
+    state: tauri::State<'_, DbState>,
+    app: tauri::AppHandle,
+    provider_id: String,
+    model_id: String,
+    variant_name: String,
+) -> Result<(), String> {
+    update_config_internal(state, &app, false, |result| {
+        let mut config = config_from_read_result(result)?;
+        let model = find_model_mut(&mut config, &provider_id, &model_id)?;
+
+        let removed = match model.variants {
+            Some(Value::Object(ref mut map)) => {
+                let before = map.len();
+                map.retain(|name, _| *name != variant_name);
+                map.len() != before
+            }
+            _ => false,
+        };
+        if !removed {
+            return Err(format!("Variant '{}' not found", variant_name));
+        }
+
+        if matches!(model.variants, Some(Value::Object(ref map)) if map.is_empty()) {
+            model.variants = None;
+        }
+
+        Ok(config)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parse_model_variants_reads_known_shapes_and_keeps_extra_keys() {
+        let raw = json!({
+            "high": { "reasoningEffort": "high", "reasoningSummary": "auto", "store": false },
+            "thinking": { "thinking": { "type": "enabled", "budgetTokens": 16000 } },
+            "google": { "thinkingConfig": { "includeThoughts": true, "thinkingLevel": "high" } }
+        });
+
+        let variants = parse_model_variants(Some(&raw)).unwrap();
+        assert_eq!(
+            variants.keys().collect::<Vec<_>>(),
+            ["high", "thinking", "google"]
+        );
+        assert_eq!(variants["high"].reasoning_effort.as_deref(), Some("high"));
+        assert_eq!(variants["high"].extra.get("store"), Some(&json!(false)));
+        assert_eq!(
+            variants["thinking"]
+                .thinking
+                .as_ref()
+                .unwrap()
+                .budget_tokens,
+            Some(16000)
+        );
+
+        let round_trip = serde_json::to_value(&variants["high"]).unwrap();
+        assert_eq!(round_trip, raw["high"]);
+    }
+
+    #[test]
+    fn validate_variant_rejects_bad_enum_values_and_budgets() {
+        let variant = OpenCodeModelVariant {
+            reasoning_effort: Some("extreme".to_string()),
+            thinking: Some(OpenCodeThinkingVariant {
+                thinking_type: Some("enabled".to_string()),
+                budget_tokens: None,
+            }),
+            ..Default::default()
+        };
+
+        let errors = validate_variant("high", &variant);
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].contains("reasoningEffort"));
+        assert!(errors[1].contains("budgetTokens"));
+
+        assert!(validate_variant("", &OpenCodeModelVariant::default()).len() == 1);
+        assert!(validate_variant("low", &OpenCodeModelVariant::default()).is_empty());
+    }
+}
@@ -0,0 +1,71 @@
+use serde_json::{json, Value};
+
+use super::types::{PromptLibraryItem, PromptLibraryItemContent, PromptVariable};
+use crate::coding::db_extract_id;
+
+fn get_string_list(value: &Value, key: &str) -> Vec<String> {
+    value
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Convert a database record to PromptLibraryItem, skipping malformed variables
+pub fn from_db_value(value: Value) -> PromptLibraryItem {
+    let variables = value
+        .get("variables")
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| serde_json::from_value::<PromptVariable>(item.clone()).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    PromptLibraryItem {
+        id: db_extract_id(&value),
+        name: value
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unnamed Prompt")
+            .to_string(),
+        description: value
+            .get("description")
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+            .map(String::from),
+        content: value
+            .get("content")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        tags: get_string_list(&value, "tags"),
+        variables,
+        sort_index: value
+            .get("sort_index")
+            .and_then(|v| v.as_i64())
+            .map(|v| v as i32),
+        created_at: value
+            .get("created_at")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(0),
+        updated_at: value
+            .get("updated_at")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(0),
+    }
+}
+
+pub fn to_db_value(content: &PromptLibraryItemContent) -> Value {
+    serde_json::to_value(content).unwrap_or_else(|e| {
+        log::error!("Failed to serialize prompt library item: {}", e);
+        json!({})
+    })
+}
@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use super::adapter;
+use super::export::{file_stem, merge_variables, render, render_export_file};
+use super::types::{
+    PromptExportResult, PromptExportTarget, PromptLibraryItem, PromptLibraryItemContent,
+    PromptLibraryItemInput,
+};
+use crate::coding::runtime_location::{
+    get_claude_runtime_location_async, get_opencode_config_dir_async,
+};
+use crate::coding::{db_new_id, db_record_id, db_reorder_query, expand_local_path};
+use crate::DbState;
+
+const TABLE: &str = "prompt_library";
+
+/// Trimmed, non-empty tags without duplicates
+fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.trim().to_string();
+        if !tag.is_empty() && !result.contains(&tag) {
+            result.push(tag);
+        }
+    }
+    result
+}
+
+async fn get_item(state: &DbState, id: &str) -> Result<PromptLibraryItem, String> {
+    let records: Vec<Value> = state
+        .db()
+        .query(format!(
+            "SELECT *, type::string(id) as id FROM {} LIMIT 1",
+            db_record_id(TABLE, id)
+        ))
+        .await
+        .map_err(|e| format!("Failed to query prompt: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to query prompt: {}", e))?;
+
+    records
+        .into_iter()
+        .next()
+        .map(adapter::from_db_value)
+        .ok_or_else(|| format!("Prompt '{}' not found", id))
+}
+
+/// List library prompts in their manual order, optionally only those with `tag`
+#[tauri::command]
+pub async fn list_prompt_library_items(
+    state: tauri::State<'_, DbState>,
+    tag: Option<String>,
+) -> Result<Vec<PromptLibraryItem>, String> {
+    let records: Vec<Value> = state
+        .db()
+        .query("SELECT *, type::string(id) as id FROM prompt_library ORDER BY sort_index ASC, name ASC")
+        .await
+        .map_err(|e| format!("Failed to query prompt library: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to query prompt library: {}", e))?;
+
+    Ok(records
+        .into_iter()
+        .map(adapter::from_db_value)
+        .filter(|item| !item.id.is_empty())
+        .filter(|item| tag.as_ref().is_none_or(|tag| item.tags.contains(tag)))
+        .collect())
+}
+
+#[tauri::command]
+pub async fn create_prompt_library_item(
+    state: tauri::State<'_, DbState>,
+    input: PromptLibraryItemInput,
+) -> Result<PromptLibraryItem, String> {
+    let db = state.db();
+    let now = chrono::Utc::now().timestamp_millis();
+
+    let sort_records: Vec<Value> = db
+        .query("SELECT sort_index FROM prompt_library ORDER BY sort_index DESC LIMIT 1")
+        .await
+        .map_err(|e| format!("Failed to query prompt sort index: {}", e))?
+        .take(0)
+        .unwrap_or_default();
+    let next_sort_index = sort_records
+        .first()
+        .and_then(|record| record.get("sort_index").and_then(|v| v.as_i64()))
+        .map(|v| v as i32 + 1)
+        .unwrap_or(0);
+
+    let content = PromptLibraryItemContent {
+        name: input.name.trim().to_string(),
+        description: input.description.filter(|d| !d.trim().is_empty()),
+        variables: merge_variables(input.variables, &input.content),
+        content: input.content,
+        tags: normalize_tags(input.tags),
+        sort_index: Some(next_sort_index),
+        created_at: now,
+        updated_at: now,
+    };
+    let id = db_new_id();
+
+    db.query(format!("CREATE {} CONTENT $data", db_record_id(TABLE, &id)))
+        .bind(("data", adapter::to_db_value(&content)))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to create prompt: {}", e))?;
+
+    get_item(&state, &id).await
+}
+
+#[tauri::command]
+pub async fn update_prompt_library_item(
+    state: tauri::State<'_, DbState>,
+    input: PromptLibraryItemInput,
+) -> Result<PromptLibraryItem, String> {
+    let id = input
+        .id
+        .clone()
+        .ok_or_else(|| "ID is required for update".to_string())?;
+    let existing = get_item(&state, &id).await?;
+
+    let content = PromptLibraryItemContent {
+        name: input.name.trim().to_string(),
+        description: input.description.filter(|d| !d.trim().is_empty()),
+        variables: merge_variables(input.variables, &input.content),
+        content: input.content,
+        tags: normalize_tags(input.tags),
+        sort_index: existing.sort_index,
+        created_at: existing.created_at,
+        updated_at: chrono::Utc::now().timestamp_millis(),
+    };
+
+    state
+        .db()
+        .query(format!("UPDATE {} CONTENT $data", db_record_id(TABLE, &id)))
+        .bind(("data", adapter::to_db_value(&content)))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to update prompt: {}", e))?;
+
+    get_item(&state, &id).await
+}
+
+#[tauri::command]
+pub async fn delete_prompt_library_item(
+    state: tauri::State<'_, DbState>,
+    id: String,
+) -> Result<(), String> {
+    state
+        .db()
+        .query(format!("DELETE {}", db_record_id(TABLE, &id)))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to delete prompt: {}", e))?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn reorder_prompt_library_items(
+    state: tauri::State<'_, DbState>,
+    ids: Vec<String>,
+) -> Result<(), String> {
+    state
+        .db()
+        .query(db_reorder_query(TABLE, &ids, false))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to update prompt sort index: {}", e))?;
+    Ok(())
+}
+
+/// Fill a prompt's placeholders; unset variables fall back to their defaults
+#[tauri::command]
+pub async fn render_prompt_library_item(
+    state: tauri::State<'_, DbState>,
+    id: String,
+    values: HashMap<String, String>,
+) -> Result<String, String> {
+    let item = get_item(&state, &id).await?;
+    Ok(render(&item.content, &item.variables, &values))
+}
+
+/// Write the selected prompts as files of `target` into `directory`.
+///
+/// Without a directory, commands go to the Claude Code or OpenCode commands folder.
+/// Existing files with the same name are overwritten.
+#[tauri::command]
+pub async fn export_prompt_library_items(
+    state: tauri::State<'_, DbState>,
+    ids: Vec<String>,
+    target: PromptExportTarget,
+    directory: Option<String>,
+) -> Result<PromptExportResult, String> {
+    let directory = match directory.filter(|dir| !dir.trim().is_empty()) {
+        Some(dir) => PathBuf::from(expand_local_path(dir.trim())?),
+        None => default_export_dir(&state, target).await?,
+    };
+    std::fs::create_dir_all(&directory)
+        .map_err(|e| format!("Failed to create directory {}: {}", directory.display(), e))?;
+
+    let mut written = Vec::new();
+    let mut used_stems: Vec<String> = Vec::new();
+    for id in &ids {
+        let item = get_item(&state, id).await?;
+        let base_stem = file_stem(&item.name, &item.id);
+        let mut stem = base_stem.clone();
+        let mut suffix = 2;
+        while used_stems.contains(&stem) {
+            stem = format!("{}-{}", base_stem, suffix);
+            suffix += 1;
+        }
+        used_stems.push(stem.clone());
+
+        let path = directory.join(format!("{}.md", stem));
+        std::fs::write(&path, render_export_file(&item, target))
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        written.push(path.to_string_lossy().to_string());
+    }
+
+    log::info!(
+        "Exported {} prompts ({:?}) to {}",
+        written.len(),
+        target,
+        directory.display()
+    );
+    Ok(PromptExportResult {
+        directory: directory.to_string_lossy().to_string(),
+        written,
+    })
+}
+
+async fn default_export_dir(
+    state: &DbState,
+    target: PromptExportTarget,
+) -> Result<PathBuf, String> {
+    let db = state.db();
+    match target {
+        PromptExportTarget::ClaudeCommands => Ok(get_claude_runtime_location_async(&db)
+            .await?
+            .host_path
+            .join("commands")),
+        PromptExportTarget::OpencodeCommands => {
+            Ok(get_opencode_config_dir_async(&db).await?.join("command"))
+        }
+        PromptExportTarget::Markdown => {
+            Err("A directory is required for markdown export".to_string())
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Placeholder handling and export file rendering for library prompts
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use super::types::{PromptExportTarget, PromptLibraryItem, PromptVariable};
+
+fn placeholder_regex() -> &'static Regex {
+    static PLACEHOLDER_RE: OnceLock<Regex> = OnceLock::new();
+    PLACEHOLDER_RE.get_or_init(|| Regex::new(r"\{\{\s*([A-Za-z_][A-Za-z0-9_-]*)\s*\}\}").unwrap())
+}
+
+/// Placeholder names in `content`, in order of first use
+pub fn detect_variables(content: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for caps in placeholder_regex().captures_iter(content) {
+        let name = caps[1].to_string();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// Declared variables (without blanks or duplicates) followed by undeclared placeholders
+pub fn merge_variables(declared: Vec<PromptVariable>, content: &str) -> Vec<PromptVariable> {
+    let mut variables: Vec<PromptVariable> = Vec::new();
+    for mut variable in declared {
+        variable.name = variable.name.trim().to_string();
+        if !variable.name.is_empty() && !variables.iter().any(|v| v.name == variable.name) {
+            variables.push(variable);
+        }
+    }
+    for name in detect_variables(content) {
+        if !variables.iter().any(|v| v.name == name) {
+            variables.push(PromptVariable {
+                name,
+                description: None,
+                default: None,
+            });
+        }
+    }
+    variables
+}
+
+/// Fill placeholders from `values`, then from variable defaults; others are kept as written
+pub fn render(
+    content: &str,
+    variables: &[PromptVariable],
+    values: &HashMap<String, String>,
+) -> String {
+    placeholder_regex()
+        .replace_all(content, |caps: &regex::Captures| {
+            let name = &caps[1];
+            values
+                .get(name)
+                .cloned()
+                .or_else(|| {
+                    variables
+                        .iter()
+                        .find(|v| v.name == name)
+                        .and_then(|v| v.default.clone())
+                })
+                .unwrap_or_else(|| caps[0].to_string())
+        })
+        .into_owned()
+}
+
+/// File name (without extension) for a prompt: lowercase words joined by '-'
+pub fn file_stem(name: &str, fallback: &str) -> String {
+    let mut stem = String::new();
+    for c in name.trim().chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() || c == '_' {
+            stem.push(c);
+        } else if !stem.is_empty() && !stem.ends_with('-') {
+            stem.push('-');
+        }
+    }
+    let stem = stem.trim_end_matches('-');
+    if stem.is_empty() {
+        fallback.to_string()
+    } else {
+        stem.to_string()
+    }
+}
+
+/// File content for a prompt in the target's format.
+///
+/// Commands take arguments instead of named variables: a single variable becomes
+/// `$ARGUMENTS`, several become `$1`, `$2`, ... in declaration order.
+pub fn render_export_file(item: &PromptLibraryItem, target: PromptExportTarget) -> String {
+    let description = item
+        .description
+        .as_deref()
+        .filter(|d| !d.trim().is_empty())
+        .unwrap_or(&item.name);
+
+    match target {
+        PromptExportTarget::ClaudeCommands | PromptExportTarget::OpencodeCommands => {
+            let mut frontmatter = format!("description: {}\n", yaml_string(description));
+            if target == PromptExportTarget::ClaudeCommands && !item.variables.is_empty() {
+                let hint: Vec<String> = item
+                    .variables
+                    .iter()
+                    .map(|v| format!("[{}]", v.name))
+                    .collect();
+                frontmatter.push_str(&format!(
+                    "argument-hint: {}\n",
+                    yaml_string(&hint.join(" "))
+                ));
+            }
+            let values: HashMap<String, String> = match item.variables.as_slice() {
+                [variable] => HashMap::from([(variable.name.clone(), "$ARGUMENTS".to_string())]),
+                variables => variables
+                    .iter()
+                    .enumerate()
+                    .map(|(index, v)| (v.name.clone(), format!("${}", index + 1)))
+                    .collect(),
+            };
+            let body = render(&item.content, &[], &values);
+            format!("---\n{}---\n\n{}\n", frontmatter, body.trim_end())
+        }
+        PromptExportTarget::Markdown => {
+            let mut output = format!("# {}\n\n", item.name);
+            if let Some(description) = item.description.as_deref().filter(|d| !d.is_empty()) {
+                output.push_str(&format!("{}\n\n", description));
+            }
+            if !item.tags.is_empty() {
+                output.push_str(&format!("Tags: {}\n\n", item.tags.join(", ")));
+            }
+            output.push_str(item.content.trim_end());
+            output.push('\n');
+            output
+        }
+    }
+}
+
+/// A double-quoted YAML scalar; JSON string escaping is valid YAML
+fn yaml_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("\"{}\"", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variable(name: &str, default: Option<&str>) -> PromptVariable {
+        PromptVariable {
+            name: name.to_string(),
+            description: None,
+            default: default.map(String::from),
+        }
+    }
+
+    fn item(content: &str, variables: Vec<PromptVariable>) -> PromptLibraryItem {
+        PromptLibraryItem {
+            id: "abc".to_string(),
+            name: "Review PR".to_string(),
+            description: Some("Review: a pull request".to_string()),
+            content: content.to_string(),
+            tags: vec!["git".to_string()],
+            variables,
+            sort_index: None,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn merges_declared_and_detected_variables() {
+        let merged = merge_variables(
+            vec![variable("lang", Some("rust")), variable(" ", None)],
+            "Fix {{ file }} in {{lang}}, then {{file}} again. Keep {{secret:TOKEN}}.",
+        );
+        let names: Vec<&str> = merged.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, vec!["lang", "file"]);
+    }
+
+    #[test]
+    fn renders_values_then_defaults() {
+        let variables = vec![variable("lang", Some("rust")), variable("file", None)];
+        let values = HashMap::from([("file".to_string(), "main.rs".to_string())]);
+        assert_eq!(
+            render("{{file}} in {{lang}} by {{who}}", &variables, &values),
+            "main.rs in rust by {{who}}"
+        );
+    }
+
+    #[test]
+    fn exports_commands_with_arguments() {
+        let single = item("Review PR {{number}}", vec![variable("number", None)]);
+        assert_eq!(
+            render_export_file(&single, PromptExportTarget::ClaudeCommands),
+            "---\ndescription: \"Review: a pull request\"\nargument-hint: \"[number]\"\n---\n\nReview PR $ARGUMENTS\n"
+        );
+
+        let several = item(
+            "Compare {{a}} with {{b}}",
+            vec![variable("a", None), variable("b", None)],
+        );
+        assert_eq!(
+            render_export_file(&several, PromptExportTarget::OpencodeCommands),
+            "---\ndescription: \"Review: a pull request\"\n---\n\nCompare $1 with $2\n"
+        );
+    }
+
+    #[test]
+    fn builds_file_stems() {
+        assert_eq!(file_stem("Review PR (fast)", "x"), "review-pr-fast");
+        assert_eq!(file_stem("  ", "fallback"), "fallback");
+    }
+}
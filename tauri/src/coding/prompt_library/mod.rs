@@ -0,0 +1,13 @@
+//! Prompt Library Module
+//!
+//! Reusable prompts and snippets with tags and `{{variable}}` placeholders, stored in
+//! the `prompt_library` table. Selected prompts can be exported as Claude Code slash
+//! commands, OpenCode commands or plain markdown files.
+
+pub mod adapter;
+pub mod commands;
+pub mod export;
+pub mod types;
+
+pub use commands::*;
+pub use types::*;
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+/// A `{{name}}` placeholder of a prompt
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromptVariable {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Used when rendering without a value for this variable
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptLibraryItemInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub content: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Declared variables; placeholders found in the content are added on save
+    #[serde(default)]
+    pub variables: Vec<PromptVariable>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptLibraryItem {
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub content: String,
+    pub tags: Vec<String>,
+    pub variables: Vec<PromptVariable>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_index: Option<i32>,
+    /// Epoch milliseconds
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptLibraryItemContent {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub content: String,
+    pub tags: Vec<String>,
+    pub variables: Vec<PromptVariable>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_index: Option<i32>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// File layout prompts are exported as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PromptExportTarget {
+    /// `<claude root>/commands/<name>.md`, run as `/<name>`
+    ClaudeCommands,
+    /// `<opencode config dir>/command/<name>.md`, run as `/<name>`
+    OpencodeCommands,
+    /// `<name>.md` with the prompt as written
+    Markdown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptExportResult {
+    pub directory: String,
+    /// Paths of the written files
+    pub written: Vec<String>,
+}
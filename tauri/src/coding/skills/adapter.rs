@@ -1,7 +1,7 @@
 use serde_json::Value;
 
 use super::tool_adapters::CustomTool;
-use super::types::{Skill, SkillPreferences, SkillRepo, SkillTarget};
+use super::types::{Skill, SkillPreferences, SkillRepo, SkillRepoCredential, SkillTarget};
 use crate::coding::db_extract_id;
 
 // ==================== Skill ====================
@@ -19,6 +19,17 @@ pub fn from_db_skill(value: Value) -> Skill {
         })
         .unwrap_or_default();
 
+    // Parse tags: JSON array -> Vec<String>
+    let tags: Vec<String> = value
+        .get("tags")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|item| item.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
     // Parse sync_details: JSON object -> Option<Value>
     let sync_details = value.get("sync_details").cloned().filter(|v| !v.is_null());
 
@@ -42,6 +53,10 @@ pub fn from_db_skill(value: Value) -> Skill {
             .get("source_revision")
             .and_then(|v| v.as_str())
             .map(|s| s.to_string()),
+        pinned_ref: value
+            .get("pinned_ref")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
         central_path: value
             .get("central_path")
             .and_then(|v| v.as_str())
@@ -65,6 +80,15 @@ pub fn from_db_skill(value: Value) -> Skill {
             .and_then(|v| v.as_str())
             .unwrap_or("active")
             .to_string(),
+        description: value
+            .get("description")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        tags,
+        notes: value
+            .get("notes")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
         sort_index: value
             .get("sort_index")
             .and_then(|v| v.as_i64())
@@ -81,12 +105,16 @@ pub fn to_clean_skill_payload(skill: &Skill) -> Value {
         "source_type": skill.source_type,
         "source_ref": skill.source_ref,
         "source_revision": skill.source_revision,
+        "pinned_ref": skill.pinned_ref,
         "central_path": skill.central_path,
         "content_hash": skill.content_hash,
         "created_at": skill.created_at,
         "updated_at": skill.updated_at,
         "last_sync_at": skill.last_sync_at,
         "status": skill.status,
+        "description": skill.description,
+        "tags": skill.tags,
+        "notes": skill.notes,
         "sort_index": skill.sort_index,
         "enabled_tools": skill.enabled_tools,
         "sync_details": skill.sync_details,
@@ -127,17 +155,44 @@ pub fn parse_sync_details(skill: &Skill) -> Vec<SkillTarget> {
                 .get("error_message")
                 .and_then(|v| v.as_str())
                 .map(|s| s.to_string()),
+            target_name: entry
+                .get("target_name")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
         })
         .collect()
 }
 
+/// Number of successful (re)syncs recorded for a tool's target
+pub fn get_sync_count(existing: &Option<Value>, tool: &str) -> u32 {
+    existing
+        .as_ref()
+        .and_then(|v| v.get(tool))
+        .and_then(|entry| entry.get("sync_count"))
+        .and_then(|v| v.as_u64())
+        .map(|n| n as u32)
+        .unwrap_or(0)
+}
+
 /// Set a SkillTarget in sync_details JSON (upsert single tool)
+///
+/// `sync_count` is carried over and bumped whenever an ok target gets a new `synced_at`.
 pub fn set_sync_detail(existing: &Option<Value>, tool: &str, target: &SkillTarget) -> Value {
     let mut obj = existing
         .as_ref()
         .and_then(|v| v.as_object().cloned())
         .unwrap_or_default();
 
+    let previous_synced_at = obj
+        .get(tool)
+        .and_then(|entry| entry.get("synced_at"))
+        .and_then(|v| v.as_i64());
+    let mut sync_count = get_sync_count(existing, tool);
+    if target.status == "ok" && target.synced_at.is_some() && target.synced_at != previous_synced_at
+    {
+        sync_count += 1;
+    }
+
     obj.insert(
         tool.to_string(),
         serde_json::json!({
@@ -146,12 +201,21 @@ pub fn set_sync_detail(existing: &Option<Value>, tool: &str, target: &SkillTarge
             "status": target.status,
             "synced_at": target.synced_at,
             "error_message": target.error_message,
+            "target_name": target.target_name,
+            "sync_count": sync_count,
         }),
     );
 
     Value::Object(obj)
 }
 
+/// Directory name a skill uses (or would use) in a tool's skills dir
+pub fn target_dir_name(skill: &Skill, tool: &str) -> String {
+    get_sync_detail(&skill.sync_details, tool)
+        .and_then(|t| t.target_name)
+        .unwrap_or_else(|| skill.name.clone())
+}
+
 /// Remove a tool from sync_details JSON
 pub fn remove_sync_detail(existing: &Option<Value>, tool: &str) -> Value {
     let mut obj = existing
@@ -190,6 +254,10 @@ pub fn get_sync_detail(existing: &Option<Value>, tool: &str) -> Option<SkillTarg
             .get("error_message")
             .and_then(|v| v.as_str())
             .map(|s| s.to_string()),
+        target_name: entry
+            .get("target_name")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
     })
 }
 
@@ -236,6 +304,42 @@ pub fn to_skill_repo_payload(repo: &SkillRepo) -> Value {
     })
 }
 
+// ==================== SkillRepoCredential ====================
+
+/// Convert database record to SkillRepoCredential struct
+pub fn from_db_repo_credential(value: Value) -> SkillRepoCredential {
+    SkillRepoCredential {
+        id: db_extract_id(&value),
+        auth_type: value
+            .get("auth_type")
+            .and_then(|v| v.as_str())
+            .unwrap_or("token")
+            .to_string(),
+        username: value
+            .get("username")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        created_at: value
+            .get("created_at")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(0),
+        updated_at: value
+            .get("updated_at")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(0),
+    }
+}
+
+/// Convert SkillRepoCredential to clean database payload (without id)
+pub fn to_repo_credential_payload(credential: &SkillRepoCredential) -> Value {
+    serde_json::json!({
+        "auth_type": credential.auth_type,
+        "username": credential.username,
+        "created_at": credential.created_at,
+        "updated_at": credential.updated_at,
+    })
+}
+
 // ==================== SkillPreferences ====================
 
 /// Convert database record to SkillPreferences struct
@@ -284,6 +388,10 @@ pub fn from_db_skill_preferences(value: Value) -> SkillPreferences {
             .get("show_skills_in_tray")
             .and_then(|v| v.as_bool())
             .unwrap_or(false),
+        auto_resync_copy_targets: value
+            .get("auto_resync_copy_targets")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
         updated_at: value
             .get("updated_at")
             .and_then(|v| v.as_i64())
@@ -301,6 +409,7 @@ pub fn to_skill_preferences_payload(prefs: &SkillPreferences) -> Value {
         "known_tool_versions": prefs.known_tool_versions,
         "installed_tools": prefs.installed_tools,
         "show_skills_in_tray": prefs.show_skills_in_tray,
+        "auto_resync_copy_targets": prefs.auto_resync_copy_targets,
         "updated_at": prefs.updated_at,
     })
 }
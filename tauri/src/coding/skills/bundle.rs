@@ -0,0 +1,396 @@
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use super::central_repo::{
+    ensure_central_repo, resolve_central_repo_path, resolve_skill_central_path,
+    to_relative_central_path,
+};
+use super::content_hash::hash_dir;
+use super::git_fetcher::{normalize_pinned_ref, validate_repo_url};
+use super::skill_store;
+use super::sync_engine::copy_skill_dir;
+use super::types::{now_ms, InstallResult, Skill};
+use crate::DbState;
+
+const MANIFEST_NAME: &str = "manifest.json";
+const SKILLS_PREFIX: &str = "skills/";
+const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// Metadata of one skill inside a bundle
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BundleSkillEntry {
+    pub name: String,
+    pub source_type: String,
+    pub source_ref: Option<String>,
+    pub source_revision: Option<String>,
+    #[serde(default)]
+    pub pinned_ref: Option<String>,
+    pub content_hash: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+/// `manifest.json` at the root of a skill bundle zip
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BundleManifest {
+    pub format_version: u32,
+    pub exported_at: i64,
+    pub skills: Vec<BundleSkillEntry>,
+}
+
+/// Result of importing a bundle
+pub struct BundleImportResult {
+    pub imported: Vec<InstallResult>,
+    pub skipped: Vec<String>,
+}
+
+/// A skill name must be a single, plain directory name
+fn is_safe_skill_name(name: &str) -> bool {
+    !name.is_empty()
+        && name != "."
+        && name != ".."
+        && !name.contains(['/', '\\', ':'])
+        && !name.chars().any(|c| c.is_control())
+}
+
+/// Write skill dirs and their manifest into a zip file
+pub fn write_bundle(
+    output_path: &Path,
+    manifest: &BundleManifest,
+    skill_dirs: &[PathBuf],
+) -> Result<()> {
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create dir {:?}", parent))?;
+    }
+    let file = File::create(output_path)
+        .with_context(|| format!("failed to create bundle {:?}", output_path))?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    zip.start_file(MANIFEST_NAME, options)?;
+    zip.write_all(serde_json::to_string_pretty(manifest)?.as_bytes())?;
+
+    for (entry, dir) in manifest.skills.iter().zip(skill_dirs) {
+        for item in WalkDir::new(dir)
+            .follow_links(true)
+            .into_iter()
+            .filter_entry(|e| e.file_name() != ".git")
+        {
+            let item = item?;
+            let relative = item
+                .path()
+                .strip_prefix(dir)
+                .with_context(|| format!("strip prefix {:?}", item.path()))?;
+            if relative.as_os_str().is_empty() || !item.file_type().is_file() {
+                continue;
+            }
+            // Use forward slashes for cross-platform compatibility in zip files
+            let name = format!(
+                "{}{}/{}",
+                SKILLS_PREFIX,
+                entry.name,
+                relative.to_string_lossy().replace('\\', "/")
+            );
+            zip.start_file(name, options)?;
+            let mut buffer = Vec::new();
+            File::open(item.path())
+                .and_then(|mut f| f.read_to_end(&mut buffer))
+                .with_context(|| format!("read file {:?}", item.path()))?;
+            zip.write_all(&buffer)?;
+        }
+    }
+
+    zip.finish()?;
+    Ok(())
+}
+
+/// Extract a bundle into `dest` and return its manifest; skill `name` ends up in `dest/name`
+pub fn extract_bundle(bundle_path: &Path, dest: &Path) -> Result<BundleManifest> {
+    let file =
+        File::open(bundle_path).with_context(|| format!("failed to open {:?}", bundle_path))?;
+    let mut archive = ZipArchive::new(file).context("invalid skill bundle")?;
+
+    let manifest: BundleManifest = {
+        let mut manifest_file = archive
+            .by_name(MANIFEST_NAME)
+            .context("INVALID_BUNDLE|manifest.json not found")?;
+        let mut content = String::new();
+        manifest_file.read_to_string(&mut content)?;
+        serde_json::from_str(&content).context("INVALID_BUNDLE|invalid manifest.json")?
+    };
+    if manifest.format_version > BUNDLE_FORMAT_VERSION {
+        anyhow::bail!(
+            "INVALID_BUNDLE|unsupported bundle version {}",
+            manifest.format_version
+        );
+    }
+    if let Some(bad) = manifest
+        .skills
+        .iter()
+        .find(|s| !is_safe_skill_name(&s.name))
+    {
+        anyhow::bail!("INVALID_BUNDLE|invalid skill name {:?}", bad.name);
+    }
+
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        if entry.is_dir() {
+            continue;
+        }
+        // enclosed_name rejects absolute paths and `..` components
+        let Some(relative) = entry.enclosed_name() else {
+            continue;
+        };
+        let Ok(relative) = relative.strip_prefix(SKILLS_PREFIX.trim_end_matches('/')) else {
+            continue;
+        };
+        let out_path = dest.join(relative);
+        if let Some(parent) = out_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create dir {:?}", parent))?;
+        }
+        let mut out = File::create(&out_path)
+            .with_context(|| format!("failed to create file {:?}", out_path))?;
+        std::io::copy(&mut entry, &mut out)?;
+    }
+
+    Ok(manifest)
+}
+
+/// Export selected skills into a zip bundle with a metadata manifest
+pub async fn export_skill_bundle(
+    app: &tauri::AppHandle,
+    state: &DbState,
+    skill_ids: &[String],
+    output_path: &Path,
+) -> Result<usize> {
+    let central_dir = resolve_central_repo_path(app, state).await?;
+
+    let mut entries = Vec::new();
+    let mut dirs = Vec::new();
+    for skill_id in skill_ids {
+        let skill = skill_store::get_skill_by_id(state, skill_id)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?
+            .ok_or_else(|| anyhow::anyhow!("skill not found: {}", skill_id))?;
+        let central_path = resolve_skill_central_path(&skill.central_path, &central_dir);
+        if !central_path.exists() {
+            anyhow::bail!("central path not found: {:?}", central_path);
+        }
+
+        entries.push(BundleSkillEntry {
+            name: skill.name,
+            source_type: skill.source_type,
+            source_ref: skill.source_ref,
+            source_revision: skill.source_revision,
+            pinned_ref: skill.pinned_ref,
+            content_hash: skill.content_hash,
+            description: skill.description,
+            tags: skill.tags,
+            notes: skill.notes,
+        });
+        dirs.push(central_path);
+    }
+
+    let manifest = BundleManifest {
+        format_version: BUNDLE_FORMAT_VERSION,
+        exported_at: now_ms(),
+        skills: entries,
+    };
+    let output_path = output_path.to_path_buf();
+    let count = manifest.skills.len();
+    tokio::task::spawn_blocking(move || write_bundle(&output_path, &manifest, &dirs))
+        .await
+        .context("bundle export task failed")??;
+
+    Ok(count)
+}
+
+/// Install all skills from a bundle into the central repo
+///
+/// Git skills keep their source so they can still be updated where git is available;
+/// everything else is recorded as `import`. Existing skills are skipped unless `overwrite`.
+/// Git entries whose source URL or pinned ref fails validation are skipped.
+pub async fn import_skill_bundle(
+    app: &tauri::AppHandle,
+    state: &DbState,
+    bundle_path: &Path,
+    overwrite: bool,
+) -> Result<BundleImportResult> {
+    super::tool_adapters::set_runtime_db(state.db());
+
+    let staging = tempfile::tempdir().context("failed to create staging dir")?;
+    let staging_path = staging.path().to_path_buf();
+    let bundle = bundle_path.to_path_buf();
+    let manifest = tokio::task::spawn_blocking(move || extract_bundle(&bundle, &staging_path))
+        .await
+        .context("bundle import task failed")??;
+
+    let central_dir = resolve_central_repo_path(app, state).await?;
+    ensure_central_repo(&central_dir)?;
+
+    let mut result = BundleImportResult {
+        imported: Vec::new(),
+        skipped: Vec::new(),
+    };
+    for entry in manifest.skills {
+        let is_git = entry.source_type == "git" && entry.source_ref.is_some();
+        let pinned_ref = if is_git {
+            let validated = entry
+                .source_ref
+                .as_deref()
+                .map_or(Ok(()), validate_repo_url)
+                .and_then(|_| normalize_pinned_ref(entry.pinned_ref.as_deref()));
+            match validated {
+                Ok(pinned_ref) => pinned_ref,
+                Err(err) => {
+                    log::warn!("[bundle] rejected git skill '{}': {:#}", entry.name, err);
+                    result.skipped.push(entry.name);
+                    continue;
+                }
+            }
+        } else {
+            None
+        };
+
+        let source_dir = staging.path().join(&entry.name);
+        if !source_dir.is_dir() {
+            log::warn!("[bundle] skill '{}' has no files in bundle", entry.name);
+            result.skipped.push(entry.name);
+            continue;
+        }
+
+        let central_path = central_dir.join(&entry.name);
+        let existing = skill_store::get_skill_by_name(state, &entry.name)
+            .await
+            .ok()
+            .flatten();
+        if central_path.exists() || existing.is_some() {
+            if !overwrite {
+                result.skipped.push(entry.name);
+                continue;
+            }
+            if central_path.exists() {
+                std::fs::remove_dir_all(&central_path).with_context(|| {
+                    format!("failed to remove existing skill: {:?}", central_path)
+                })?;
+            }
+        }
+
+        copy_skill_dir(&source_dir, &central_path)
+            .with_context(|| format!("copy {:?} -> {:?}", source_dir, central_path))?;
+
+        let now = now_ms();
+        let content_hash = hash_dir(&central_path).ok();
+        if entry.content_hash.is_some() && entry.content_hash != content_hash {
+            log::warn!(
+                "[bundle] content hash of '{}' differs from manifest",
+                entry.name
+            );
+        }
+
+        let record = Skill {
+            id: existing.as_ref().map(|s| s.id.clone()).unwrap_or_default(),
+            name: entry.name.clone(),
+            source_type: if is_git { "git" } else { "import" }.to_string(),
+            source_ref: if is_git { entry.source_ref } else { None },
+            source_revision: if is_git { entry.source_revision } else { None },
+            pinned_ref,
+            central_path: to_relative_central_path(&central_path, &central_dir),
+            content_hash: content_hash.clone(),
+            created_at: existing.as_ref().map(|s| s.created_at).unwrap_or(now),
+            updated_at: now,
+            last_sync_at: existing.as_ref().and_then(|s| s.last_sync_at),
+            status: "ok".to_string(),
+            description: entry.description,
+            tags: entry.tags,
+            notes: entry.notes,
+            sort_index: existing.as_ref().map(|s| s.sort_index).unwrap_or(0),
+            enabled_tools: existing
+                .as_ref()
+                .map(|s| s.enabled_tools.clone())
+                .unwrap_or_default(),
+            sync_details: existing.as_ref().and_then(|s| s.sync_details.clone()),
+        };
+        let skill_id = skill_store::upsert_skill(state, &record)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        result.imported.push(InstallResult {
+            skill_id,
+            name: entry.name,
+            central_path,
+            content_hash,
+        });
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundle_round_trip_keeps_files_and_manifest() {
+        let source = tempfile::tempdir().unwrap();
+        let skill_dir = source.path().join("demo");
+        std::fs::create_dir_all(skill_dir.join("scripts")).unwrap();
+        std::fs::write(skill_dir.join("SKILL.md"), "---\nname: demo\n---\n").unwrap();
+        std::fs::write(skill_dir.join("scripts/run.sh"), "echo hi").unwrap();
+
+        let manifest = BundleManifest {
+            format_version: BUNDLE_FORMAT_VERSION,
+            exported_at: 1,
+            skills: vec![BundleSkillEntry {
+                name: "demo".to_string(),
+                source_type: "git".to_string(),
+                source_ref: Some("https://github.com/o/r".to_string()),
+                source_revision: Some("abc".to_string()),
+                pinned_ref: Some("v1".to_string()),
+                content_hash: hash_dir(&skill_dir).ok(),
+                description: Some("Demo skill".to_string()),
+                tags: vec!["docs".to_string()],
+                notes: None,
+            }],
+        };
+
+        let out = tempfile::tempdir().unwrap();
+        let bundle_path = out.path().join("skills.zip");
+        write_bundle(&bundle_path, &manifest, &[skill_dir.clone()]).unwrap();
+
+        let dest = tempfile::tempdir().unwrap();
+        let read = extract_bundle(&bundle_path, dest.path()).unwrap();
+        assert_eq!(read.skills.len(), 1);
+        assert_eq!(read.skills[0].pinned_ref.as_deref(), Some("v1"));
+        assert_eq!(read.skills[0].tags, vec!["docs".to_string()]);
+        assert_eq!(
+            std::fs::read_to_string(dest.path().join("demo/scripts/run.sh")).unwrap(),
+            "echo hi"
+        );
+        assert_eq!(
+            hash_dir(&dest.path().join("demo")).ok(),
+            manifest.skills[0].content_hash
+        );
+    }
+
+    #[test]
+    fn skill_names_with_paths_are_rejected() {
+        assert!(is_safe_skill_name("my-skill"));
+        assert!(!is_safe_skill_name("../evil"));
+        assert!(!is_safe_skill_name("a/b"));
+        assert!(!is_safe_skill_name(".."));
+    }
+}
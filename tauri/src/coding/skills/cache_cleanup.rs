@@ -5,15 +5,35 @@ use anyhow::{Context, Result};
 use serde::Deserialize;
 use tauri::Manager;
 
+use super::git_pool::repo_lock;
+use super::types::{GitCacheEntryDto, GitCacheStatsDto};
+
 const CACHE_DIR_NAME: &str = "skills-git-cache";
 const CACHE_META_FILE: &str = ".skills-cache.json";
 pub const DEFAULT_GIT_CACHE_CLEANUP_DAYS: i64 = 30;
 const MAX_GIT_CACHE_CLEANUP_DAYS: i64 = 3650;
 pub const DEFAULT_GIT_CACHE_TTL_SECS: i64 = 60;
+pub const DEFAULT_GIT_CACHE_MAX_SIZE_MB: i64 = 2048;
+const MAX_GIT_CACHE_MAX_SIZE_MB: i64 = 1024 * 1024;
 
 #[derive(Debug, Deserialize)]
 struct RepoCacheMeta {
     last_fetched_ms: i64,
+    #[serde(default)]
+    last_used_ms: Option<i64>,
+    #[serde(default)]
+    repo_url: Option<String>,
+    #[serde(default)]
+    ref_name: Option<String>,
+}
+
+/// One repo dir in the git cache, as seen by stats and LRU eviction
+struct CacheEntry {
+    key: String,
+    path: PathBuf,
+    size_bytes: u64,
+    last_used_ms: i64,
+    meta: Option<RepoCacheMeta>,
 }
 
 /// Get git cache cleanup days from settings
@@ -88,15 +108,59 @@ pub async fn get_git_cache_ttl_secs(state: &crate::DbState) -> i64 {
     result.unwrap_or(DEFAULT_GIT_CACHE_TTL_SECS)
 }
 
+/// Get git cache size cap (MB) from settings; 0 means unlimited
+pub async fn get_git_cache_max_size_mb(state: &crate::DbState) -> i64 {
+    let result: std::result::Result<i64, String> = async {
+        let db = state.db();
+        let mut result = db
+            .query("SELECT * FROM skill_settings:`skills` LIMIT 1")
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let records: Vec<serde_json::Value> = result.take(0).map_err(|e| e.to_string())?;
+
+        if let Some(record) = records.first() {
+            if let Some(mb) = record.get("git_cache_max_size_mb").and_then(|v| v.as_i64()) {
+                return Ok(mb);
+            }
+        }
+        Ok(DEFAULT_GIT_CACHE_MAX_SIZE_MB)
+    }
+    .await;
+
+    result.unwrap_or(DEFAULT_GIT_CACHE_MAX_SIZE_MB)
+}
+
+/// Set git cache size cap (MB) in settings
+pub async fn set_git_cache_max_size_mb(state: &crate::DbState, mb: i64) -> Result<i64> {
+    if !(0..=MAX_GIT_CACHE_MAX_SIZE_MB).contains(&mb) {
+        anyhow::bail!(
+            "cache size must be between 0 and {} MB",
+            MAX_GIT_CACHE_MAX_SIZE_MB
+        );
+    }
+
+    let db = state.db();
+    let now = super::types::now_ms();
+
+    db.query(
+        "UPSERT skill_settings:`skills` MERGE { git_cache_max_size_mb: $mb, updated_at: $now }",
+    )
+    .bind(("mb", mb))
+    .bind(("now", now))
+    .await
+    .map_err(|e| anyhow::anyhow!("failed to save setting: {}", e))?;
+
+    Ok(mb)
+}
+
 /// Cleanup old git cache directories
 pub fn cleanup_git_cache_dirs<R: tauri::Runtime>(
     app: &tauri::AppHandle<R>,
     max_age: Duration,
 ) -> Result<usize> {
-    let cache_dir = app
-        .path()
-        .app_cache_dir()
-        .context("failed to resolve app cache dir")?;
+    let cache_dir =
+        crate::portable::app_cache_dir(app).context("failed to resolve app cache dir")?;
     cleanup_git_cache_dirs_in(&cache_dir, max_age)
 }
 
@@ -163,9 +227,195 @@ fn cleanup_git_cache_dirs_in(cache_dir: &Path, max_age: Duration) -> Result<usiz
     Ok(removed)
 }
 
+fn dir_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.metadata().ok())
+        .filter(|meta| meta.is_file())
+        .map(|meta| meta.len())
+        .sum()
+}
+
+fn scan_cache_entries(cache_root: &Path) -> Vec<CacheEntry> {
+    let Ok(rd) = std::fs::read_dir(cache_root) else {
+        return Vec::new();
+    };
+    rd.flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir() && path.join(".git").exists())
+        .map(|path| {
+            let meta = std::fs::read_to_string(path.join(CACHE_META_FILE))
+                .ok()
+                .and_then(|raw| serde_json::from_str::<RepoCacheMeta>(&raw).ok());
+            let modified_ms = std::fs::metadata(&path)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+                .map(|d| d.as_millis() as i64)
+                .unwrap_or(0);
+            let last_used_ms = meta
+                .as_ref()
+                .map(|m| m.last_used_ms.unwrap_or(m.last_fetched_ms))
+                .unwrap_or(modified_ms);
+            CacheEntry {
+                key: path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                size_bytes: dir_size(&path),
+                last_used_ms,
+                meta,
+                path,
+            }
+        })
+        .collect()
+}
+
+fn git_cache_root<R: tauri::Runtime>(app: &tauri::AppHandle<R>) -> Result<PathBuf> {
+    let cache_dir =
+        crate::portable::app_cache_dir(app).context("failed to resolve app cache dir")?;
+    Ok(cache_dir.join(CACHE_DIR_NAME))
+}
+
+/// Evict least recently used cache dirs until the cache fits in `max_bytes`
+///
+/// Dirs that are being fetched right now (repo lock held) and `keep` are never evicted.
+/// Returns how many dirs were removed. Blocking.
+pub fn enforce_git_cache_size_limit<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    max_bytes: u64,
+    keep: Option<&Path>,
+) -> Result<usize> {
+    let cache_root = git_cache_root(app)?;
+    evict_lru_in(&cache_root, max_bytes, keep)
+}
+
+fn evict_lru_in(cache_root: &Path, max_bytes: u64, keep: Option<&Path>) -> Result<usize> {
+    let mut entries = scan_cache_entries(cache_root);
+    let mut total: u64 = entries.iter().map(|e| e.size_bytes).sum();
+    if total <= max_bytes {
+        return Ok(0);
+    }
+    entries.sort_by_key(|e| e.last_used_ms);
+
+    let mut removed = 0usize;
+    for entry in entries {
+        if total <= max_bytes {
+            break;
+        }
+        if keep == Some(entry.path.as_path()) {
+            continue;
+        }
+        let lock = repo_lock(&entry.key);
+        let Ok(_guard) = lock.try_lock() else {
+            continue;
+        };
+        if std::fs::remove_dir_all(&entry.path).is_ok() {
+            total = total.saturating_sub(entry.size_bytes);
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+/// Apply the configured size cap; failures are only logged
+pub async fn enforce_git_cache_limit_from_settings(app: &tauri::AppHandle, keep: Option<PathBuf>) {
+    let state = app.state::<crate::DbState>();
+    let max_mb = get_git_cache_max_size_mb(&state).await;
+    if max_mb <= 0 {
+        return;
+    }
+    let app_handle = app.clone();
+    let max_bytes = (max_mb as u64) * 1024 * 1024;
+    let result = tokio::task::spawn_blocking(move || {
+        enforce_git_cache_size_limit(&app_handle, max_bytes, keep.as_deref())
+    })
+    .await;
+    match result {
+        Ok(Ok(count)) if count > 0 => {
+            log::info!(
+                "[skills] git cache over {} MB: evicted {} repo(s)",
+                max_mb,
+                count
+            )
+        }
+        Ok(Err(err)) => log::warn!("[skills] git cache eviction failed: {:#}", err),
+        Err(err) => log::warn!("[skills] git cache eviction task failed: {}", err),
+        _ => {}
+    }
+}
+
+/// Per-repo disk usage of the git cache, largest first. Blocking.
+pub fn git_cache_stats<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    max_size_mb: i64,
+) -> Result<GitCacheStatsDto> {
+    let cache_root = git_cache_root(app)?;
+    let mut entries = scan_cache_entries(&cache_root);
+    entries.sort_by(|a, b| b.size_bytes.cmp(&a.size_bytes));
+
+    Ok(GitCacheStatsDto {
+        path: cache_root.to_string_lossy().to_string(),
+        total_bytes: entries.iter().map(|e| e.size_bytes).sum(),
+        max_size_mb,
+        entries: entries
+            .into_iter()
+            .map(|entry| GitCacheEntryDto {
+                repo_url: entry.meta.as_ref().and_then(|m| m.repo_url.clone()),
+                ref_name: entry.meta.as_ref().and_then(|m| m.ref_name.clone()),
+                last_fetched_at: entry.meta.as_ref().map(|m| m.last_fetched_ms),
+                last_used_at: entry.last_used_ms,
+                size_bytes: entry.size_bytes,
+                key: entry.key,
+            })
+            .collect(),
+    })
+}
+
 fn now_ms() -> i64 {
     let now = SystemTime::now()
         .duration_since(SystemTime::UNIX_EPOCH)
         .unwrap_or_default();
     now.as_millis() as i64
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_cache_dir(root: &Path, key: &str, bytes: usize, last_used_ms: i64) {
+        let dir = root.join(key);
+        std::fs::create_dir_all(dir.join(".git")).unwrap();
+        std::fs::write(dir.join("data"), vec![0u8; bytes]).unwrap();
+        std::fs::write(
+            dir.join(CACHE_META_FILE),
+            format!(
+                r#"{{"last_fetched_ms":1,"head":"abc","last_used_ms":{}}}"#,
+                last_used_ms
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn evict_lru_removes_least_recently_used_first() {
+        let root = tempfile::tempdir().unwrap();
+        write_cache_dir(root.path(), "lru-old", 4000, 100);
+        write_cache_dir(root.path(), "lru-mid", 4000, 200);
+        write_cache_dir(root.path(), "lru-new", 4000, 300);
+
+        let removed = evict_lru_in(root.path(), 9000, None).unwrap();
+        assert_eq!(removed, 1);
+        assert!(!root.path().join("lru-old").exists());
+        assert!(root.path().join("lru-mid").exists());
+
+        // The kept dir survives even when it is the oldest
+        let keep = root.path().join("lru-mid");
+        let removed = evict_lru_in(root.path(), 5000, Some(&keep)).unwrap();
+        assert_eq!(removed, 1);
+        assert!(keep.exists());
+        assert!(!root.path().join("lru-new").exists());
+    }
+}
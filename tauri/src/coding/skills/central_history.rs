@@ -0,0 +1,270 @@
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use tauri::Emitter;
+
+use super::adapter::parse_sync_details;
+use super::central_repo::{resolve_central_repo_path, resolve_skill_central_path};
+use super::content_hash::hash_dir;
+use super::git_fetcher::run_local_git;
+use super::path_executor::sync_copy_target_path;
+use super::skill_store;
+use super::types::{now_ms, SkillHistoryEntryDto, SkillTarget};
+use crate::DbState;
+
+/// Written once when the history repo is created; staging dirs never get committed
+const HISTORY_GITIGNORE: &str = ".skills-update-*/\n.DS_Store\nThumbs.db\n";
+
+/// Commits are made by the app, independent of the user's git identity and signing setup
+const COMMIT_CONFIG: [&str; 6] = [
+    "-c",
+    "user.name=AI Toolbox",
+    "-c",
+    "user.email=ai-toolbox@localhost",
+    "-c",
+    "commit.gpgsign=false",
+];
+
+/// Serializes index access between concurrent installs / updates
+static HISTORY_LOCK: Mutex<()> = Mutex::new(());
+
+fn ensure_history_repo(central_dir: &Path) -> Result<()> {
+    if central_dir.join(".git").exists() {
+        return Ok(());
+    }
+    std::fs::create_dir_all(central_dir)
+        .with_context(|| format!("failed to create dir {:?}", central_dir))?;
+    run_local_git(central_dir, &["init", "--quiet"])?;
+    let gitignore = central_dir.join(".gitignore");
+    if !gitignore.exists() {
+        std::fs::write(&gitignore, HISTORY_GITIGNORE)
+            .with_context(|| format!("failed to write {:?}", gitignore))?;
+    }
+    log::info!("[skills] initialized history repo in {:?}", central_dir);
+    Ok(())
+}
+
+fn has_commits(central_dir: &Path) -> bool {
+    central_dir.join(".git").exists()
+        && run_local_git(central_dir, &["rev-parse", "--verify", "--quiet", "HEAD"]).is_ok()
+}
+
+/// Stage everything and commit; caller must hold `HISTORY_LOCK`
+fn commit_all_locked(central_dir: &Path, message: &str) -> Result<bool> {
+    ensure_history_repo(central_dir)?;
+    run_local_git(central_dir, &["add", "-A"])?;
+    let status = run_local_git(central_dir, &["status", "--porcelain"])?;
+    if status.trim().is_empty() {
+        return Ok(false);
+    }
+    let mut args = COMMIT_CONFIG.to_vec();
+    args.extend(["commit", "--quiet", "--no-verify", "-m", message]);
+    run_local_git(central_dir, &args)?;
+    Ok(true)
+}
+
+/// Commit every change in the central repo, initializing it on first use
+///
+/// Returns false when there was nothing to commit. Blocking.
+pub fn commit_central_changes(central_dir: &Path, message: &str) -> Result<bool> {
+    let _guard = HISTORY_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    commit_all_locked(central_dir, message)
+}
+
+/// Best-effort history commit after an install / update / delete
+///
+/// Failures (e.g. git missing) are logged and never fail the operation itself.
+pub async fn record_central_change(app: &tauri::AppHandle, state: &DbState, message: String) {
+    let central_dir = match resolve_central_repo_path(app, state).await {
+        Ok(dir) => dir,
+        Err(err) => {
+            log::warn!(
+                "[skills] history: failed to resolve central repo: {:#}",
+                err
+            );
+            return;
+        }
+    };
+    let result =
+        tokio::task::spawn_blocking(move || commit_central_changes(&central_dir, &message)).await;
+    match result {
+        Ok(Ok(_)) => {}
+        Ok(Err(err)) => log::warn!("[skills] history: commit failed: {:#}", err),
+        Err(err) => log::warn!("[skills] history: commit task failed: {}", err),
+    }
+}
+
+/// Skill dir relative to the central repo, as a git pathspec
+fn skill_pathspec(central_dir: &Path, central_path: &Path) -> Result<String> {
+    let relative = central_path
+        .strip_prefix(central_dir)
+        .with_context(|| format!("{:?} is not inside the central repo", central_path))?;
+    let spec = relative.to_string_lossy().replace('\\', "/");
+    if spec.is_empty() {
+        anyhow::bail!("invalid skill path {:?}", central_path);
+    }
+    Ok(spec)
+}
+
+fn validate_commit(commit: &str) -> Result<String> {
+    let commit = commit.trim();
+    if commit.len() < 4 || commit.len() > 40 || !commit.chars().all(|c| c.is_ascii_hexdigit()) {
+        anyhow::bail!("invalid commit id: {:?}", commit);
+    }
+    Ok(commit.to_lowercase())
+}
+
+/// Resolve the central repo and a skill's dir inside it
+async fn resolve_skill_paths(
+    app: &tauri::AppHandle,
+    state: &DbState,
+    skill_id: &str,
+) -> Result<(String, PathBuf, PathBuf)> {
+    let skill = skill_store::get_skill_by_id(state, skill_id)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?
+        .ok_or_else(|| anyhow::anyhow!("skill not found: {}", skill_id))?;
+    let central_dir = resolve_central_repo_path(app, state).await?;
+    let central_path = resolve_skill_central_path(&skill.central_path, &central_dir);
+    Ok((skill.name, central_dir, central_path))
+}
+
+/// Commits touching a skill's dir, newest first
+pub async fn get_skill_history(
+    app: &tauri::AppHandle,
+    state: &DbState,
+    skill_id: &str,
+) -> Result<Vec<SkillHistoryEntryDto>> {
+    let (_, central_dir, central_path) = resolve_skill_paths(app, state, skill_id).await?;
+    let spec = skill_pathspec(&central_dir, &central_path)?;
+
+    tokio::task::spawn_blocking(move || {
+        if !has_commits(&central_dir) {
+            return Ok(Vec::new());
+        }
+        let out = run_local_git(
+            &central_dir,
+            &[
+                "--literal-pathspecs",
+                "log",
+                "--format=%H%x1f%ct%x1f%s",
+                "--",
+                &spec,
+            ],
+        )?;
+        Ok(out
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.splitn(3, '\u{1f}');
+                let commit = parts.next()?.to_string();
+                let committed_at = parts.next()?.parse::<i64>().ok()? * 1000;
+                let message = parts.next().unwrap_or("").to_string();
+                Some(SkillHistoryEntryDto {
+                    short_commit: commit.chars().take(7).collect(),
+                    commit,
+                    message,
+                    committed_at,
+                })
+            })
+            .collect())
+    })
+    .await
+    .map_err(|e| anyhow::anyhow!("history task failed: {}", e))?
+}
+
+/// Restore a skill's central dir to its content at `commit`
+///
+/// Uncommitted edits are committed first so they stay recoverable. Copy-mode targets are
+/// re-copied afterwards; symlink/junction targets pick the change up directly.
+/// Returns the tools whose targets were refreshed.
+pub async fn restore_skill_version(
+    app: &tauri::AppHandle,
+    state: &DbState,
+    skill_id: &str,
+    commit: &str,
+) -> Result<Vec<String>> {
+    let commit = validate_commit(commit)?;
+    let (name, central_dir, central_path) = resolve_skill_paths(app, state, skill_id).await?;
+    let spec = skill_pathspec(&central_dir, &central_path)?;
+
+    let restore_dir = central_path.clone();
+    let restore_name = name.clone();
+    tokio::task::spawn_blocking(move || -> Result<()> {
+        let _guard = HISTORY_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        if !has_commits(&central_dir) {
+            anyhow::bail!("no history recorded for skill '{}'", restore_name);
+        }
+        commit_all_locked(
+            &central_dir,
+            &format!("Snapshot skill '{}' before restore", restore_name),
+        )?;
+
+        let object = format!("{}:{}", commit, spec);
+        run_local_git(&central_dir, &["cat-file", "-e", &object]).map_err(|_| {
+            anyhow::anyhow!(
+                "skill '{}' does not exist in commit {}",
+                restore_name,
+                commit
+            )
+        })?;
+
+        if restore_dir.exists() {
+            std::fs::remove_dir_all(&restore_dir)
+                .with_context(|| format!("failed to remove {:?}", restore_dir))?;
+        }
+        run_local_git(
+            &central_dir,
+            &["--literal-pathspecs", "checkout", &commit, "--", &spec],
+        )?;
+        let short: String = commit.chars().take(7).collect();
+        commit_all_locked(
+            &central_dir,
+            &format!("Restore skill '{}' to {}", restore_name, short),
+        )?;
+        Ok(())
+    })
+    .await
+    .map_err(|e| anyhow::anyhow!("restore task failed: {}", e))??;
+
+    let content_hash = hash_dir(&central_path).ok();
+    skill_store::update_skill_content_hash(state, skill_id, content_hash)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    let skill = skill_store::get_skill_by_id(state, skill_id)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?
+        .ok_or_else(|| anyhow::anyhow!("skill not found: {}", skill_id))?;
+    let mut refreshed = Vec::new();
+    for target in parse_sync_details(&skill)
+        .into_iter()
+        .filter(|t| t.mode == "copy" && !t.is_disabled())
+    {
+        let record = match sync_copy_target_path(&central_path, &target.target_path) {
+            Ok(outcome) => {
+                refreshed.push(target.tool.clone());
+                SkillTarget {
+                    target_path: outcome.target_path.to_string_lossy().to_string(),
+                    status: "ok".to_string(),
+                    synced_at: Some(now_ms()),
+                    error_message: None,
+                    ..target
+                }
+            }
+            Err(err) => SkillTarget {
+                status: "error".to_string(),
+                error_message: Some(format!("{:#}", err)),
+                ..target
+            },
+        };
+        skill_store::upsert_skill_target(state, skill_id, &record)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))?;
+    }
+
+    log::info!("[skills] restored '{}' to {}", name, commit);
+    // Emit skills-changed for WSL sync
+    let _ = app.emit("skills-changed", "window");
+    Ok(refreshed)
+}
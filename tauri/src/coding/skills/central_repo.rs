@@ -1,7 +1,6 @@
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
-use tauri::Manager;
 
 const CENTRAL_DIR_NAME: &str = "skills";
 
@@ -36,10 +35,8 @@ pub async fn resolve_central_repo_path<R: tauri::Runtime>(
     }
 
     // Default to app data directory / skills
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .context("failed to resolve app data directory")?;
+    let app_data_dir =
+        crate::portable::app_data_dir(app).context("failed to resolve app data directory")?;
     Ok(app_data_dir.join(CENTRAL_DIR_NAME))
 }
 
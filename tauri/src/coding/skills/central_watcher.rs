@@ -0,0 +1,206 @@
+use std::collections::HashSet;
+use std::path::{Component, Path, PathBuf};
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use tauri::{Emitter, Manager};
+
+use super::adapter::parse_sync_details;
+use super::central_repo::{resolve_central_repo_path, resolve_skill_central_path};
+use super::content_hash::hash_dir;
+use super::skill_store;
+use super::sync_engine::sync_dir_for_tool_with_overwrite;
+use super::types::{now_ms, SkillTarget};
+use crate::DbState;
+
+/// Wait for edits to settle before hashing (editors write several events per save)
+const DEBOUNCE: Duration = Duration::from_millis(1500);
+
+struct CentralWatcher {
+    path: PathBuf,
+    _watcher: RecommendedWatcher,
+}
+
+static CENTRAL_WATCHER: Mutex<Option<CentralWatcher>> = Mutex::new(None);
+
+/// Payload of the `skills-auto-synced` event
+#[derive(Clone, Debug, Serialize)]
+pub struct SkillAutoSyncedPayload {
+    pub skill_id: String,
+    pub name: String,
+    pub tools: Vec<String>,
+}
+
+/// Top-level skill dir an event path belongs to; staging dirs (`.skills-update-*`) are ignored
+fn skill_name_for_path(central_dir: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(central_dir).ok()?;
+    let Some(Component::Normal(first)) = relative.components().next() else {
+        return None;
+    };
+    let name = first.to_string_lossy().to_string();
+    (!name.starts_with('.')).then_some(name)
+}
+
+fn stop_watcher() {
+    if let Ok(mut guard) = CENTRAL_WATCHER.lock() {
+        if guard.take().is_some() {
+            log::info!("[skills] central repo watcher stopped");
+        }
+    }
+}
+
+/// Start, re-arm or stop the central repo watcher according to the
+/// `auto_resync_copy_targets` setting and the current central repo path
+pub async fn refresh_central_watcher(app: &tauri::AppHandle) -> Result<(), String> {
+    let state = app.state::<DbState>();
+    let enabled = skill_store::get_setting(&state, "auto_resync_copy_targets")
+        .await?
+        .is_some_and(|v| v == "true");
+    if !enabled {
+        stop_watcher();
+        return Ok(());
+    }
+
+    let central_dir = resolve_central_repo_path(app, &state)
+        .await
+        .map_err(|e| format!("{:#}", e))?;
+    if !central_dir.is_dir() {
+        stop_watcher();
+        return Ok(());
+    }
+
+    let mut guard = CENTRAL_WATCHER
+        .lock()
+        .map_err(|_| "central watcher lock poisoned".to_string())?;
+    if guard.as_ref().map(|w| w.path.as_path()) == Some(central_dir.as_path()) {
+        return Ok(());
+    }
+
+    let (tx, rx) = mpsc::channel::<String>();
+    let watch_root = central_dir.clone();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        let Ok(event) = res else {
+            return;
+        };
+        if event.kind.is_access() {
+            return;
+        }
+        for path in &event.paths {
+            if let Some(name) = skill_name_for_path(&watch_root, path) {
+                let _ = tx.send(name);
+            }
+        }
+    })
+    .map_err(|e| format!("Failed to create central repo watcher: {}", e))?;
+    watcher
+        .watch(&central_dir, RecursiveMode::Recursive)
+        .map_err(|e| format!("Failed to watch {:?}: {}", central_dir, e))?;
+
+    // The sender lives in the watcher callback, so this thread ends once the watcher is dropped
+    let app_handle = app.clone();
+    std::thread::spawn(move || {
+        while let Ok(first) = rx.recv() {
+            let mut names = HashSet::from([first]);
+            while let Ok(name) = rx.recv_timeout(DEBOUNCE) {
+                names.insert(name);
+            }
+            let app = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                resync_changed_skills(&app, names).await;
+            });
+        }
+    });
+
+    log::info!("[skills] watching central repo {:?}", central_dir);
+    *guard = Some(CentralWatcher {
+        path: central_dir,
+        _watcher: watcher,
+    });
+    Ok(())
+}
+
+/// Re-copy copy-mode targets of skills whose content hash changed
+async fn resync_changed_skills(app: &tauri::AppHandle, names: HashSet<String>) {
+    let state = app.state::<DbState>();
+    let Ok(central_dir) = resolve_central_repo_path(app, &state).await else {
+        return;
+    };
+
+    let mut synced = Vec::new();
+    for name in names {
+        let Ok(Some(skill)) = skill_store::get_skill_by_name(&state, &name).await else {
+            continue;
+        };
+        let central_path = resolve_skill_central_path(&skill.central_path, &central_dir);
+        if !central_path.is_dir() {
+            continue;
+        }
+        let content_hash = hash_dir(&central_path).ok();
+        if content_hash.is_none() || content_hash == skill.content_hash {
+            continue;
+        }
+
+        let mut tools = Vec::new();
+        for target in parse_sync_details(&skill)
+            .into_iter()
+            .filter(|t| t.mode == "copy" && !t.is_disabled())
+        {
+            let result = sync_dir_for_tool_with_overwrite(
+                &target.tool,
+                &central_path,
+                Path::new(&target.target_path),
+                true,
+                true,
+            );
+            let record = match result {
+                Ok(outcome) => {
+                    tools.push(target.tool.clone());
+                    SkillTarget {
+                        target_path: outcome.target_path.to_string_lossy().to_string(),
+                        status: "ok".to_string(),
+                        synced_at: Some(now_ms()),
+                        error_message: None,
+                        ..target
+                    }
+                }
+                Err(err) => {
+                    log::warn!(
+                        "[skills] auto-resync of '{}' to {} failed: {:#}",
+                        skill.name,
+                        target.tool,
+                        err
+                    );
+                    SkillTarget {
+                        status: "error".to_string(),
+                        error_message: Some(format!("{:#}", err)),
+                        ..target
+                    }
+                }
+            };
+            let _ = skill_store::upsert_skill_target(&state, &skill.id, &record).await;
+        }
+
+        let _ = skill_store::update_skill_content_hash(&state, &skill.id, content_hash).await;
+        if !tools.is_empty() {
+            log::info!(
+                "[skills] auto-resynced '{}' to {}",
+                skill.name,
+                tools.join(", ")
+            );
+            synced.push(SkillAutoSyncedPayload {
+                skill_id: skill.id,
+                name: skill.name,
+                tools,
+            });
+        }
+    }
+
+    if !synced.is_empty() {
+        let _ = app.emit("skills-auto-synced", &synced);
+        // Emit skills-changed for WSL sync
+        let _ = app.emit("skills-changed", "window");
+    }
+}
@@ -4,33 +4,48 @@ use std::time::Duration;
 use tauri::{AppHandle, Emitter, Runtime, State};
 
 use super::adapter::parse_sync_details;
+use super::bundle::{export_skill_bundle, import_skill_bundle};
 use super::cache_cleanup::{
-    cleanup_git_cache_dirs, get_git_cache_cleanup_days, get_git_cache_ttl_secs,
-    set_git_cache_cleanup_days as set_cleanup_days,
+    cleanup_git_cache_dirs, enforce_git_cache_limit_from_settings, get_git_cache_cleanup_days,
+    get_git_cache_max_size_mb, get_git_cache_ttl_secs, git_cache_stats,
+    set_git_cache_cleanup_days as set_cleanup_days, set_git_cache_max_size_mb as set_max_size_mb,
 };
+use super::central_history::{get_skill_history, record_central_change, restore_skill_version};
 use super::central_repo::{
     ensure_central_repo, expand_home_path, resolve_central_repo_path, resolve_skill_central_path,
 };
-use super::git_fetcher::{set_proxy, GitProxyMode};
+use super::central_watcher::refresh_central_watcher;
+use super::content_hash::diff_dirs;
+use super::credentials::{delete_repo_credential, list_repo_credentials, save_repo_credential};
+use super::git_pool::run_git_task;
 use super::installer::{
-    install_git_skill, install_git_skill_from_selection, install_local_skill,
-    install_local_skill_from_selection, list_git_skills, list_local_skills,
-    update_managed_skill_from_source,
+    init_git_env_from_settings, install_git_skill, install_git_skill_from_selection,
+    install_git_skill_selections, install_local_skill, install_local_skill_from_selection,
+    list_git_skills, list_local_skills, update_managed_skill_from_source,
 };
+use super::lint::lint_skill_dir;
+use super::name_conflict::{check_skill_name_conflict, find_target_owner};
 use super::onboarding::build_onboarding_plan;
-use super::path_executor::{remove_skill_target, sync_skill_to_target, target_path_changed};
+use super::path_executor::{
+    remove_skill_target, sync_copy_target_path, sync_skill_to_target, target_path_changed,
+};
 use super::skill_store;
 use super::tool_adapters::{
     adapter_by_key, get_all_tool_adapters, is_tool_installed_async,
     resolve_runtime_skills_path_async, runtime_adapter_by_key,
 };
 use super::types::{
-    now_ms, CustomTool, CustomToolDto, GitSkillCandidate, InstallResultDto, ManagedSkillDto,
-    OnboardingPlan, SkillRepo, SkillRepoDto, SkillTarget, SkillTargetDto, SyncResultDto,
-    ToolInfoDto, ToolStatusDto, UpdateResultDto,
+    now_ms, BatchUpdateResultDto, BundleImportResultDto, CustomTool, CustomToolDto,
+    GitCacheStatsDto, GitRepoListRequest, GitRepoListResultDto, GitSkillCandidate,
+    InstallResultDto, ManagedSkillDto, OnboardingPlan, SelectionInstallResultDto, Skill,
+    SkillDriftDto, SkillHistoryEntryDto, SkillLintReportDto, SkillNameConflictDto, SkillRepo,
+    SkillRepoCredentialDto, SkillRepoDto, SkillTarget, SkillTargetDto, SkillUpdateCheckDto,
+    SkillUpdateErrorDto, SkillUsageDto, SyncResultDto, TargetDriftDto, ToolInfoDto, ToolStatusDto,
+    UpdateResult, UpdateResultDto,
 };
+use super::update_check::check_git_skill_updates;
+use super::usage::build_skill_usage;
 use crate::coding::runtime_location;
-use crate::http_client;
 use crate::DbState;
 
 fn format_error(err: anyhow::Error) -> String {
@@ -39,6 +54,7 @@ fn format_error(err: anyhow::Error) -> String {
     if first.starts_with("MULTI_SKILLS|")
         || first.starts_with("TARGET_EXISTS|")
         || first.starts_with("TOOL_NOT_INSTALLED|")
+        || first.starts_with("NAME_CONFLICT|")
     {
         return first;
     }
@@ -139,6 +155,7 @@ pub async fn skills_get_central_repo_path(
 
 #[tauri::command]
 pub async fn skills_set_central_repo_path(
+    app: tauri::AppHandle,
     state: State<'_, DbState>,
     path: String,
 ) -> Result<String, String> {
@@ -157,6 +174,10 @@ pub async fn skills_set_central_repo_path(
     .await
     .map_err(|e| e)?;
 
+    if let Err(err) = refresh_central_watcher(&app).await {
+        log::warn!("Failed to re-arm central repo watcher: {}", err);
+    }
+
     Ok(new_base.to_string_lossy().to_string())
 }
 
@@ -166,8 +187,18 @@ pub async fn skills_set_central_repo_path(
 pub async fn skills_get_managed_skills(
     app: tauri::AppHandle,
     state: State<'_, DbState>,
+    tags: Option<Vec<String>>,
 ) -> Result<Vec<ManagedSkillDto>, String> {
-    let skills = skill_store::get_managed_skills(&state).await?;
+    let mut skills = skill_store::get_managed_skills(&state).await?;
+    // Keep only skills carrying every requested tag (case-insensitive)
+    let filter_tags = skill_store::normalize_tags(tags.unwrap_or_default());
+    if !filter_tags.is_empty() {
+        skills.retain(|skill| {
+            filter_tags
+                .iter()
+                .all(|tag| skill.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+        });
+    }
     let central_dir = resolve_central_repo_path(&app, &state)
         .await
         .map_err(|e| format_error(e))?;
@@ -182,6 +213,7 @@ pub async fn skills_get_managed_skills(
                 status: t.status,
                 target_path: t.target_path,
                 synced_at: t.synced_at,
+                target_name: t.target_name,
             })
             .collect();
 
@@ -193,11 +225,16 @@ pub async fn skills_get_managed_skills(
             name: skill.name,
             source_type: skill.source_type,
             source_ref: skill.source_ref,
+            source_revision: skill.source_revision,
+            pinned_ref: skill.pinned_ref,
             central_path: resolved_path.to_string_lossy().to_string(),
             created_at: skill.created_at,
             updated_at: skill.updated_at,
             last_sync_at: skill.last_sync_at,
             status: skill.status,
+            description: skill.description,
+            tags: skill.tags,
+            notes: skill.notes,
             sort_index: skill.sort_index,
             enabled_tools: skill.enabled_tools,
             targets,
@@ -207,6 +244,29 @@ pub async fn skills_get_managed_skills(
     Ok(result)
 }
 
+/// Edit a skill's description, tags and notes
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn skills_update_metadata(
+    state: State<'_, DbState>,
+    skillId: String,
+    description: Option<String>,
+    tags: Vec<String>,
+    notes: Option<String>,
+) -> Result<(), String> {
+    get_skill_or_err(&state, &skillId).await?;
+    skill_store::update_skill_metadata(&state, &skillId, description, tags, notes).await
+}
+
+/// All tags used by managed skills, sorted case-insensitively
+#[tauri::command]
+pub async fn skills_get_all_tags(state: State<'_, DbState>) -> Result<Vec<String>, String> {
+    let skills = skill_store::get_managed_skills(&state).await?;
+    let mut tags = skill_store::normalize_tags(skills.into_iter().flat_map(|s| s.tags).collect());
+    tags.sort_by_key(|tag| tag.to_lowercase());
+    Ok(tags)
+}
+
 // --- Install Skills ---
 
 #[tauri::command]
@@ -216,16 +276,25 @@ pub async fn skills_install_local(
     state: State<'_, DbState>,
     sourcePath: String,
     overwrite: Option<bool>,
+    installName: Option<String>,
 ) -> Result<InstallResultDto, String> {
     let result = install_local_skill(
         &app,
         &state,
         std::path::Path::new(&sourcePath),
         overwrite.unwrap_or(false),
+        installName.as_deref(),
     )
     .await
     .map_err(|e| format_error(e))?;
 
+    record_central_change(
+        &app,
+        &state,
+        format!("Install skill '{}' from {}", result.name, sourcePath),
+    )
+    .await;
+
     Ok(InstallResultDto {
         skill_id: result.skill_id,
         name: result.name,
@@ -251,6 +320,7 @@ pub async fn skills_install_local_selection(
     sourcePath: String,
     subpath: String,
     overwrite: Option<bool>,
+    installName: Option<String>,
 ) -> Result<InstallResultDto, String> {
     let result = install_local_skill_from_selection(
         &app,
@@ -258,10 +328,18 @@ pub async fn skills_install_local_selection(
         std::path::Path::new(&sourcePath),
         &subpath,
         overwrite.unwrap_or(false),
+        installName.as_deref(),
     )
     .await
     .map_err(|e| format_error(e))?;
 
+    record_central_change(
+        &app,
+        &state,
+        format!("Install skill '{}' from {}", result.name, sourcePath),
+    )
+    .await;
+
     Ok(InstallResultDto {
         skill_id: result.skill_id,
         name: result.name,
@@ -277,18 +355,29 @@ pub async fn skills_install_git(
     state: State<'_, DbState>,
     repoUrl: String,
     branch: Option<String>,
+    pinnedRef: Option<String>,
     overwrite: Option<bool>,
+    installName: Option<String>,
 ) -> Result<InstallResultDto, String> {
     let result = install_git_skill(
         &app,
         &state,
         &repoUrl,
         branch.as_deref(),
+        pinnedRef.as_deref(),
         overwrite.unwrap_or(false),
+        installName.as_deref(),
     )
     .await
     .map_err(|e| format_error(e))?;
 
+    record_central_change(
+        &app,
+        &state,
+        format!("Install skill '{}' from {}", result.name, repoUrl),
+    )
+    .await;
+
     Ok(InstallResultDto {
         skill_id: result.skill_id,
         name: result.name,
@@ -305,24 +394,59 @@ pub async fn skills_list_git_skills(
     repoUrl: String,
     branch: Option<String>,
 ) -> Result<Vec<GitSkillCandidate>, String> {
-    // Initialize proxy from app settings
-    let proxy_result = http_client::get_proxy_from_settings(&state).await.ok();
-    let proxy_mode = match proxy_result {
-        Some((http_client::ProxyMode::Direct, _)) => GitProxyMode::Direct,
-        Some((http_client::ProxyMode::Custom, url)) if !url.is_empty() => GitProxyMode::Custom(url),
-        _ => GitProxyMode::System,
-    };
-    set_proxy(proxy_mode);
-
+    init_git_env_from_settings(&state).await;
     let ttl = get_git_cache_ttl_secs(&state).await;
-    let branch_clone = branch.clone();
+    list_git_skills_pooled(&app, ttl, &repoUrl, branch)
+        .await
+        .map_err(|e| format_error(e))
+}
 
-    tokio::task::spawn_blocking(move || {
-        list_git_skills(&app, ttl, &repoUrl, branch_clone.as_deref())
+async fn list_git_skills_pooled(
+    app: &tauri::AppHandle,
+    ttl: i64,
+    repo_url: &str,
+    branch: Option<String>,
+) -> anyhow::Result<Vec<GitSkillCandidate>> {
+    let app_handle = app.clone();
+    let url = repo_url.to_string();
+    let skills = run_git_task(app, repo_url, "list", move || {
+        list_git_skills(&app_handle, ttl, &url, branch.as_deref())
     })
-    .await
-    .map_err(|e| e.to_string())?
-    .map_err(|e| format_error(e))
+    .await?;
+    enforce_git_cache_limit_from_settings(app, None).await;
+    Ok(skills)
+}
+
+/// List several Git repos concurrently (bounded by the git pool)
+///
+/// Progress is reported per repo through `skills-git-progress`.
+#[tauri::command]
+pub async fn skills_list_git_skills_batch(
+    app: tauri::AppHandle,
+    state: State<'_, DbState>,
+    repos: Vec<GitRepoListRequest>,
+) -> Result<Vec<GitRepoListResultDto>, String> {
+    init_git_env_from_settings(&state).await;
+    let ttl = get_git_cache_ttl_secs(&state).await;
+
+    let tasks = repos.into_iter().map(|repo| {
+        let app = app.clone();
+        async move {
+            let result =
+                list_git_skills_pooled(&app, ttl, &repo.repo_url, repo.branch.clone()).await;
+            let (skills, error) = match result {
+                Ok(skills) => (skills, None),
+                Err(err) => (Vec::new(), Some(format_error(err))),
+            };
+            GitRepoListResultDto {
+                repo_url: repo.repo_url,
+                branch: repo.branch,
+                skills,
+                error,
+            }
+        }
+    });
+    Ok(futures_util::future::join_all(tasks).await)
 }
 
 #[tauri::command]
@@ -333,7 +457,9 @@ pub async fn skills_install_git_selection(
     repoUrl: String,
     subpath: String,
     branch: Option<String>,
+    pinnedRef: Option<String>,
     overwrite: Option<bool>,
+    installName: Option<String>,
 ) -> Result<InstallResultDto, String> {
     let result = install_git_skill_from_selection(
         &app,
@@ -341,11 +467,20 @@ pub async fn skills_install_git_selection(
         &repoUrl,
         &subpath,
         branch.as_deref(),
+        pinnedRef.as_deref(),
         overwrite.unwrap_or(false),
+        installName.as_deref(),
     )
     .await
     .map_err(|e| format_error(e))?;
 
+    record_central_change(
+        &app,
+        &state,
+        format!("Install skill '{}' from {}", result.name, repoUrl),
+    )
+    .await;
+
     Ok(InstallResultDto {
         skill_id: result.skill_id,
         name: result.name,
@@ -354,6 +489,127 @@ pub async fn skills_install_git_selection(
     })
 }
 
+/// Install several candidates from one Git repo listing with a single fetch
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn skills_install_git_selections(
+    app: tauri::AppHandle,
+    state: State<'_, DbState>,
+    repoUrl: String,
+    subpaths: Vec<String>,
+    branch: Option<String>,
+    pinnedRef: Option<String>,
+    overwrite: Option<bool>,
+) -> Result<Vec<SelectionInstallResultDto>, String> {
+    let results = install_git_skill_selections(
+        &app,
+        &state,
+        &repoUrl,
+        &subpaths,
+        branch.as_deref(),
+        pinnedRef.as_deref(),
+        overwrite.unwrap_or(false),
+    )
+    .await
+    .map_err(|e| format_error(e))?;
+
+    let installed: Vec<&str> = results
+        .iter()
+        .filter_map(|(_, result)| result.as_ref().ok().map(|r| r.name.as_str()))
+        .collect();
+    if !installed.is_empty() {
+        let message = format!("Install {} from {}", installed.join(", "), repoUrl);
+        record_central_change(&app, &state, message).await;
+    }
+
+    Ok(results
+        .into_iter()
+        .map(|(subpath, result)| match result {
+            Ok(result) => SelectionInstallResultDto {
+                subpath,
+                result: Some(InstallResultDto {
+                    skill_id: result.skill_id,
+                    name: result.name,
+                    central_path: result.central_path.to_string_lossy().to_string(),
+                    content_hash: result.content_hash,
+                }),
+                error: None,
+            },
+            Err(err) => SelectionInstallResultDto {
+                subpath,
+                result: None,
+                error: Some(format_error(err)),
+            },
+        })
+        .collect())
+}
+
+// --- Bundles ---
+
+/// Export selected skills into a zip bundle, returning how many were written
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn skills_export_bundle(
+    app: tauri::AppHandle,
+    state: State<'_, DbState>,
+    ids: Vec<String>,
+    outputPath: String,
+) -> Result<usize, String> {
+    export_skill_bundle(&app, &state, &ids, std::path::Path::new(&outputPath))
+        .await
+        .map_err(format_error)
+}
+
+/// Install the skills contained in a bundle created by `skills_export_bundle`
+#[tauri::command]
+pub async fn skills_import_bundle(
+    app: tauri::AppHandle,
+    state: State<'_, DbState>,
+    path: String,
+    overwrite: Option<bool>,
+) -> Result<BundleImportResultDto, String> {
+    let result = import_skill_bundle(
+        &app,
+        &state,
+        std::path::Path::new(&path),
+        overwrite.unwrap_or(false),
+    )
+    .await
+    .map_err(format_error)?;
+
+    if !result.imported.is_empty() {
+        let names: Vec<&str> = result.imported.iter().map(|r| r.name.as_str()).collect();
+        let message = format!("Import {} from bundle", names.join(", "));
+        record_central_change(&app, &state, message).await;
+    }
+
+    Ok(BundleImportResultDto {
+        imported: result
+            .imported
+            .into_iter()
+            .map(|r| InstallResultDto {
+                skill_id: r.skill_id,
+                name: r.name,
+                central_path: r.central_path.to_string_lossy().to_string(),
+                content_hash: r.content_hash,
+            })
+            .collect(),
+        skipped: result.skipped,
+    })
+}
+
+/// Check whether a skill name collides with managed skills or tool directories
+#[tauri::command]
+pub async fn skills_check_name_conflict(
+    app: tauri::AppHandle,
+    state: State<'_, DbState>,
+    name: String,
+) -> Result<SkillNameConflictDto, String> {
+    check_skill_name_conflict(&app, &state, &name)
+        .await
+        .map_err(format_error)
+}
+
 // --- Sync Skills ---
 
 #[tauri::command]
@@ -398,6 +654,20 @@ pub async fn skills_sync_to_tool<R: Runtime>(
     let overwrite = overwrite.unwrap_or(false);
     let previous_target = skill_store::get_skill_target(&state, &skillId, &tool).await?;
 
+    // Never overwrite a folder another managed skill is synced into, even with overwrite
+    let skills = skill_store::get_managed_skills(&state).await?;
+    if let Some(owner) = find_target_owner(&skills, &target).filter(|owner| owner.id != skillId) {
+        return Err(format!(
+            "NAME_CONFLICT|{}|{}",
+            owner.name,
+            target.to_string_lossy()
+        ));
+    }
+    let skill_name = skills
+        .iter()
+        .find(|skill| skill.id == skillId)
+        .map(|skill| skill.name.as_str());
+
     let result = sync_skill_to_target(
         &tool,
         std::path::Path::new(&sourcePath),
@@ -427,6 +697,7 @@ pub async fn skills_sync_to_tool<R: Runtime>(
         status: "ok".to_string(),
         error_message: None,
         synced_at: Some(now_ms()),
+        target_name: (skill_name != Some(name.as_str())).then(|| name.clone()),
     };
     skill_store::upsert_skill_target(&state, &skillId, &record).await?;
 
@@ -439,6 +710,182 @@ pub async fn skills_sync_to_tool<R: Runtime>(
     })
 }
 
+// --- Drift Detection ---
+
+fn check_target_drift(central_path: &std::path::Path, target: &SkillTarget) -> TargetDriftDto {
+    let mut dto = TargetDriftDto {
+        tool: target.tool.clone(),
+        target_path: target.target_path.clone(),
+        status: "in_sync".to_string(),
+        modified_files: Vec::new(),
+        missing_files: Vec::new(),
+        extra_files: Vec::new(),
+        error: None,
+    };
+
+    let target_path = std::path::Path::new(&target.target_path);
+    if !target_path.is_dir() {
+        dto.status = "missing".to_string();
+        return dto;
+    }
+
+    match diff_dirs(central_path, target_path) {
+        Ok(diff) => {
+            if !diff.is_empty() {
+                dto.status = "drifted".to_string();
+            }
+            dto.modified_files = diff.modified;
+            dto.missing_files = diff.missing;
+            dto.extra_files = diff.extra;
+        }
+        Err(err) => {
+            dto.status = "error".to_string();
+            dto.error = Some(format!("{:#}", err));
+        }
+    }
+    dto
+}
+
+async fn build_drift_report(
+    app: &tauri::AppHandle,
+    state: &DbState,
+    skill: Skill,
+) -> Result<(std::path::PathBuf, SkillDriftDto), String> {
+    let central_dir = resolve_central_repo_path(app, state)
+        .await
+        .map_err(format_error)?;
+    let central_path = resolve_skill_central_path(&skill.central_path, &central_dir);
+    if !central_path.exists() {
+        return Err(format!(
+            "central path not found: {}",
+            central_path.to_string_lossy()
+        ));
+    }
+
+    // Symlink / junction targets always reflect the central dir, only copies can drift
+    let targets = parse_sync_details(&skill)
+        .iter()
+        .filter(|t| t.mode == "copy" && !t.is_disabled())
+        .map(|t| check_target_drift(&central_path, t))
+        .collect();
+
+    Ok((
+        central_path,
+        SkillDriftDto {
+            skill_id: skill.id,
+            name: skill.name,
+            targets,
+        },
+    ))
+}
+
+async fn get_skill_or_err(state: &DbState, skill_id: &str) -> Result<Skill, String> {
+    skill_store::get_skill_by_id(state, skill_id)
+        .await?
+        .ok_or_else(|| format!("skill not found: {}", skill_id))
+}
+
+/// Compare the central skill dir against each copy-mode target, file by file
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn skills_check_drift(
+    app: tauri::AppHandle,
+    state: State<'_, DbState>,
+    skillId: String,
+) -> Result<SkillDriftDto, String> {
+    let skill = get_skill_or_err(&state, &skillId).await?;
+    let (_, report) = build_drift_report(&app, &state, skill).await?;
+    Ok(report)
+}
+
+/// Sync history and best-effort last read time (atime) of each target
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn skills_get_usage(
+    state: State<'_, DbState>,
+    skillId: String,
+) -> Result<SkillUsageDto, String> {
+    let skill = get_skill_or_err(&state, &skillId).await?;
+    Ok(build_skill_usage(&skill))
+}
+
+/// Validate SKILL.md frontmatter, bundled file sizes and Windows path names of a skill
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn skills_lint(
+    app: tauri::AppHandle,
+    state: State<'_, DbState>,
+    skillId: String,
+) -> Result<SkillLintReportDto, String> {
+    let skill = get_skill_or_err(&state, &skillId).await?;
+    let central_dir = resolve_central_repo_path(&app, &state)
+        .await
+        .map_err(format_error)?;
+    let central_path = resolve_skill_central_path(&skill.central_path, &central_dir);
+    let issues = tokio::task::spawn_blocking(move || lint_skill_dir(&central_path))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(SkillLintReportDto {
+        skill_id: skill.id,
+        name: skill.name,
+        error_count: issues.iter().filter(|i| i.severity == "error").count(),
+        warning_count: issues.iter().filter(|i| i.severity == "warning").count(),
+        issues,
+    })
+}
+
+/// Re-copy drifted or missing copy-mode targets (all of them, or only `tools`)
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn skills_fix_drift(
+    app: tauri::AppHandle,
+    state: State<'_, DbState>,
+    skillId: String,
+    tools: Option<Vec<String>>,
+) -> Result<SkillDriftDto, String> {
+    let skill = get_skill_or_err(&state, &skillId).await?;
+    let (central_path, report) = build_drift_report(&app, &state, skill).await?;
+
+    let mut fixed = false;
+    for target in &report.targets {
+        if target.status == "in_sync" {
+            continue;
+        }
+        if let Some(tools) = &tools {
+            if !tools.iter().any(|tool| tool == &target.tool) {
+                continue;
+            }
+        }
+
+        let result =
+            sync_copy_target_path(&central_path, &target.target_path).map_err(format_error)?;
+        let target_name = skill_store::get_skill_target(&state, &skillId, &target.tool)
+            .await?
+            .and_then(|t| t.target_name);
+        let record = SkillTarget {
+            tool: target.tool.clone(),
+            target_path: result.target_path.to_string_lossy().to_string(),
+            mode: result.mode_used.as_str().to_string(),
+            status: "ok".to_string(),
+            error_message: None,
+            synced_at: Some(now_ms()),
+            target_name,
+        };
+        skill_store::upsert_skill_target(&state, &skillId, &record).await?;
+        fixed = true;
+    }
+
+    if fixed {
+        // Emit skills-changed for WSL sync
+        let _ = app.emit("skills-changed", "window");
+    }
+
+    let skill = get_skill_or_err(&state, &skillId).await?;
+    let (_, report) = build_drift_report(&app, &state, skill).await?;
+    Ok(report)
+}
+
 #[tauri::command]
 #[allow(non_snake_case)]
 pub async fn skills_unsync_from_tool<R: Runtime>(
@@ -471,6 +918,77 @@ pub async fn skills_unsync_from_tool<R: Runtime>(
     Ok(())
 }
 
+/// Temporarily turn a synced target off (remove it from disk, keep its record) or back on
+///
+/// Re-enabling re-syncs from the central repo into the recorded path using the recorded mode.
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn skills_set_target_enabled<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, DbState>,
+    skillId: String,
+    tool: String,
+    enabled: bool,
+) -> Result<(), String> {
+    let target = skill_store::get_skill_target(&state, &skillId, &tool)
+        .await?
+        .ok_or_else(|| format!("skill '{}' is not synced to {}", skillId, tool))?;
+    // Already in the requested state
+    if enabled != target.is_disabled() {
+        return Ok(());
+    }
+
+    let record = if enabled {
+        let skill = get_skill_or_err(&state, &skillId).await?;
+        let central_dir = resolve_central_repo_path(&app, &state)
+            .await
+            .map_err(format_error)?;
+        let central_path = resolve_skill_central_path(&skill.central_path, &central_dir);
+        if !central_path.exists() {
+            return Err(format!(
+                "central path not found: {}",
+                central_path.to_string_lossy()
+            ));
+        }
+        let custom_tools = skill_store::get_custom_tools(&state)
+            .await
+            .unwrap_or_default();
+        let force_copy = target.mode == "copy"
+            || runtime_adapter_by_key(&tool, &custom_tools).is_some_and(|a| a.force_copy);
+
+        let result = sync_skill_to_target(
+            &tool,
+            &central_path,
+            std::path::Path::new(&target.target_path),
+            true,
+            force_copy,
+        )
+        .map_err(format_error)?;
+        SkillTarget {
+            tool: tool.clone(),
+            target_path: result.target_path.to_string_lossy().to_string(),
+            mode: result.mode_used.as_str().to_string(),
+            status: "ok".to_string(),
+            error_message: None,
+            synced_at: Some(now_ms()),
+            target_name: target.target_name.clone(),
+        }
+    } else {
+        remove_skill_target(&target.target_path).map_err(format_error)?;
+        SkillTarget {
+            status: "disabled".to_string(),
+            error_message: None,
+            ..target
+        }
+    };
+    skill_store::upsert_skill_target(&state, &skillId, &record).await?;
+
+    // Emit skills-changed for WSL sync
+    let _ = app.emit("skills-changed", "window");
+
+    Ok(())
+}
+
 // --- Update/Delete Skills ---
 
 #[tauri::command]
@@ -479,10 +997,20 @@ pub async fn skills_update_managed(
     app: tauri::AppHandle,
     state: State<'_, DbState>,
     skillId: String,
+    movePin: Option<bool>,
+    pinnedRef: Option<String>,
 ) -> Result<UpdateResultDto, String> {
-    let res = update_managed_skill_from_source(&app, &state, &skillId)
-        .await
-        .map_err(|e| format_error(e))?;
+    let res = update_managed_skill_from_source(
+        &app,
+        &state,
+        &skillId,
+        movePin.unwrap_or(false),
+        pinnedRef.as_deref(),
+    )
+    .await
+    .map_err(|e| format_error(e))?;
+
+    record_central_change(&app, &state, update_commit_message(&res)).await;
 
     // Emit skills-changed for WSL sync
     let _ = app.emit("skills-changed", "window");
@@ -492,10 +1020,81 @@ pub async fn skills_update_managed(
         name: res.name,
         content_hash: res.content_hash,
         source_revision: res.source_revision,
+        pinned_ref: res.pinned_ref,
         updated_targets: res.updated_targets,
     })
 }
 
+fn update_commit_message(res: &UpdateResult) -> String {
+    match res.source_revision.as_deref() {
+        Some(rev) => format!(
+            "Update skill '{}' to {}",
+            res.name,
+            rev.chars().take(7).collect::<String>()
+        ),
+        None => format!("Update skill '{}'", res.name),
+    }
+}
+
+/// Check git skills for newer commits on their remote branch
+#[tauri::command]
+pub async fn skills_check_updates(
+    state: State<'_, DbState>,
+    force: Option<bool>,
+) -> Result<Vec<SkillUpdateCheckDto>, String> {
+    let skills = skill_store::get_managed_skills(&state).await?;
+    init_git_env_from_settings(&state).await;
+    let ttl = get_git_cache_ttl_secs(&state).await;
+    let force = force.unwrap_or(false);
+
+    tokio::task::spawn_blocking(move || check_git_skill_updates(&skills, ttl, force))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Update the selected skills from their sources; failures don't stop the rest
+#[tauri::command]
+pub async fn skills_update_all(
+    app: tauri::AppHandle,
+    state: State<'_, DbState>,
+    ids: Vec<String>,
+) -> Result<BatchUpdateResultDto, String> {
+    let mut result = BatchUpdateResultDto {
+        updated: Vec::new(),
+        failed: Vec::new(),
+    };
+
+    for skill_id in ids {
+        match update_managed_skill_from_source(&app, &state, &skill_id, false, None).await {
+            Ok(res) => result.updated.push(UpdateResultDto {
+                skill_id: res.skill_id,
+                name: res.name,
+                content_hash: res.content_hash,
+                source_revision: res.source_revision,
+                pinned_ref: res.pinned_ref,
+                updated_targets: res.updated_targets,
+            }),
+            Err(err) => {
+                log::warn!("Failed to update skill {}: {:#}", skill_id, err);
+                result.failed.push(SkillUpdateErrorDto {
+                    skill_id,
+                    error: format_error(err),
+                });
+            }
+        }
+    }
+
+    if !result.updated.is_empty() {
+        let names: Vec<&str> = result.updated.iter().map(|r| r.name.as_str()).collect();
+        let message = format!("Update {}", names.join(", "));
+        record_central_change(&app, &state, message).await;
+        // Emit skills-changed for WSL sync
+        let _ = app.emit("skills-changed", "window");
+    }
+
+    Ok(result)
+}
+
 #[tauri::command]
 #[allow(non_snake_case)]
 pub async fn skills_delete_managed(
@@ -503,42 +1102,139 @@ pub async fn skills_delete_managed(
     state: State<'_, DbState>,
     skillId: String,
 ) -> Result<(), String> {
-    // Delete synced targets first
-    let targets = skill_store::get_skill_targets(&state, &skillId).await?;
+    let record = skill_store::get_skill_by_id(&state, &skillId).await?;
+    let central_dir = resolve_central_repo_path(&app, &state)
+        .await
+        .map_err(|e| format_error(e))?;
 
-    let mut remove_failures: Vec<String> = Vec::new();
-    for target in targets {
-        if let Err(err) = remove_skill_target(&target.target_path) {
-            remove_failures.push(format!("{}: {}", target.target_path, err));
+    // Remove the files before the record: while the record exists the skill stays listed and
+    // a failed cleanup can be retried, whereas files left behind by a deleted record are orphaned
+    if let Some(skill) = &record {
+        let remove_failures =
+            remove_managed_skill_files(skill, &central_dir, &std::collections::HashSet::new());
+        if !remove_failures.is_empty() {
+            return Err(format!(
+                "Skill was not deleted, some directories could not be removed:\n- {}",
+                remove_failures.join("\n- ")
+            ));
         }
     }
 
-    let record = skill_store::get_skill_by_id(&state, &skillId).await?;
+    skill_store::delete_skill(&state, &skillId).await?;
+
     if let Some(skill) = record {
-        // Resolve central_path (handles cross-platform legacy paths)
-        let central_dir = resolve_central_repo_path(&app, &state)
-            .await
-            .map_err(|e| format_error(e))?;
-        let path = resolve_skill_central_path(&skill.central_path, &central_dir);
-        if path.exists() {
-            std::fs::remove_dir_all(&path).map_err(|e| e.to_string())?;
-        }
-        skill_store::delete_skill(&state, &skillId).await?;
+        record_central_change(&app, &state, format!("Delete skill '{}'", skill.name)).await;
     }
 
     // Emit skills-changed for WSL sync
     let _ = app.emit("skills-changed", "window");
 
+    Ok(())
+}
+
+/// Delete a duplicate skill record (`raw_id` is its full record id) together with the tool
+/// directories and central directory that no remaining skill uses.
+/// The record is kept when a file cannot be removed, so the delete can be retried.
+pub async fn delete_duplicate_skill(
+    app: &AppHandle,
+    state: &DbState,
+    raw_id: &str,
+    duplicate: &Skill,
+    remaining: &[Skill],
+) -> Result<(), String> {
+    let central_dir = resolve_central_repo_path(app, state)
+        .await
+        .map_err(format_error)?;
+    // Duplicates usually share their directories with the record that is kept
+    let in_use: std::collections::HashSet<PathBuf> = remaining
+        .iter()
+        .flat_map(|skill| {
+            parse_sync_details(skill)
+                .into_iter()
+                .map(|target| PathBuf::from(target.target_path))
+                .chain([resolve_skill_central_path(
+                    &skill.central_path,
+                    &central_dir,
+                )])
+        })
+        .collect();
+
+    let remove_failures = remove_managed_skill_files(duplicate, &central_dir, &in_use);
     if !remove_failures.is_empty() {
         return Err(format!(
-            "Deleted managed record, but some tool directories could not be cleaned:\n- {}",
+            "Failed to remove the files of duplicate skill '{}':\n- {}",
+            duplicate.name,
             remove_failures.join("\n- ")
         ));
     }
 
+    skill_store::delete_skill_record(state, raw_id).await?;
+    record_central_change(
+        app,
+        state,
+        format!("Delete duplicate skill '{}'", duplicate.name),
+    )
+    .await;
+    let _ = app.emit("skills-changed", "window");
     Ok(())
 }
 
+/// Remove a skill's tool directories and central directory, skipping the paths in `keep`.
+/// Returns the paths that could not be removed.
+fn remove_managed_skill_files(
+    skill: &Skill,
+    central_dir: &std::path::Path,
+    keep: &std::collections::HashSet<PathBuf>,
+) -> Vec<String> {
+    let mut remove_failures: Vec<String> = Vec::new();
+    for target in parse_sync_details(skill) {
+        if keep.contains(std::path::Path::new(&target.target_path)) {
+            continue;
+        }
+        if let Err(err) = remove_skill_target(&target.target_path) {
+            remove_failures.push(format!("{}: {}", target.target_path, err));
+        }
+    }
+
+    // Resolve central_path (handles cross-platform legacy paths)
+    let path = resolve_skill_central_path(&skill.central_path, central_dir);
+    if path.exists() && !keep.contains(&path) {
+        if let Err(err) = std::fs::remove_dir_all(&path) {
+            remove_failures.push(format!("{}: {}", path.display(), err));
+        }
+    }
+    remove_failures
+}
+
+// --- History ---
+
+/// Central repo commits that touched a skill, newest first
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn skills_get_history(
+    app: tauri::AppHandle,
+    state: State<'_, DbState>,
+    skillId: String,
+) -> Result<Vec<SkillHistoryEntryDto>, String> {
+    get_skill_history(&app, &state, &skillId)
+        .await
+        .map_err(format_error)
+}
+
+/// Restore a skill to a previous commit, returning the copy targets that were refreshed
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn skills_restore_version(
+    app: tauri::AppHandle,
+    state: State<'_, DbState>,
+    skillId: String,
+    commit: String,
+) -> Result<Vec<String>, String> {
+    restore_skill_version(&app, &state, &skillId, &commit)
+        .await
+        .map_err(format_error)
+}
+
 // --- Onboarding ---
 
 #[tauri::command]
@@ -568,10 +1264,18 @@ pub async fn skills_import_existing(
         &state,
         std::path::Path::new(&sourcePath),
         overwrite.unwrap_or(false),
+        None,
     )
     .await
     .map_err(|e| format_error(e))?;
 
+    record_central_change(
+        &app,
+        &state,
+        format!("Import existing skill '{}'", result.name),
+    )
+    .await;
+
     Ok(InstallResultDto {
         skill_id: result.skill_id,
         name: result.name,
@@ -602,6 +1306,37 @@ pub async fn skills_get_git_cache_ttl_secs(state: State<'_, DbState>) -> Result<
     Ok(get_git_cache_ttl_secs(&state).await)
 }
 
+/// Size cap of the git cache in MB (0 = unlimited)
+#[tauri::command]
+pub async fn skills_get_git_cache_max_size_mb(state: State<'_, DbState>) -> Result<i64, String> {
+    Ok(get_git_cache_max_size_mb(&state).await)
+}
+
+/// Save the git cache size cap and evict least recently used repos right away
+#[tauri::command]
+pub async fn skills_set_git_cache_max_size_mb(
+    app: tauri::AppHandle,
+    state: State<'_, DbState>,
+    mb: i64,
+) -> Result<i64, String> {
+    let mb = set_max_size_mb(&state, mb).await.map_err(format_error)?;
+    enforce_git_cache_limit_from_settings(&app, None).await;
+    Ok(mb)
+}
+
+/// Per-repo disk usage of the git cache
+#[tauri::command]
+pub async fn skills_get_git_cache_stats(
+    app: tauri::AppHandle,
+    state: State<'_, DbState>,
+) -> Result<GitCacheStatsDto, String> {
+    let max_size_mb = get_git_cache_max_size_mb(&state).await;
+    tokio::task::spawn_blocking(move || git_cache_stats(&app, max_size_mb))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(format_error)
+}
+
 #[tauri::command]
 pub async fn skills_clear_git_cache(app: tauri::AppHandle) -> Result<usize, String> {
     cleanup_git_cache_dirs(&app, Duration::from_secs(0)).map_err(|e| format_error(e))
@@ -609,8 +1344,7 @@ pub async fn skills_clear_git_cache(app: tauri::AppHandle) -> Result<usize, Stri
 
 #[tauri::command]
 pub async fn skills_get_git_cache_path(app: tauri::AppHandle) -> Result<String, String> {
-    use tauri::Manager;
-    let cache_dir = app.path().app_cache_dir().map_err(|e| e.to_string())?;
+    let cache_dir = crate::portable::app_cache_dir(&app).map_err(|e| e.to_string())?;
     let cache_path = cache_dir.join("skills-git-cache");
     if !cache_path.exists() {
         std::fs::create_dir_all(&cache_path).map_err(|e| e.to_string())?;
@@ -674,6 +1408,33 @@ pub async fn skills_set_show_in_tray(
     .await
 }
 
+// --- Auto Resync Copy Targets ---
+
+#[tauri::command]
+pub async fn skills_get_auto_resync(state: State<'_, DbState>) -> Result<bool, String> {
+    let raw = skill_store::get_setting(&state, "auto_resync_copy_targets")
+        .await
+        .ok()
+        .flatten();
+    Ok(raw.is_some_and(|s| s == "true"))
+}
+
+/// Toggle watching the central repo and re-copying copy-mode targets on change
+#[tauri::command]
+pub async fn skills_set_auto_resync(
+    app: tauri::AppHandle,
+    state: State<'_, DbState>,
+    enabled: bool,
+) -> Result<(), String> {
+    skill_store::set_setting(
+        &state,
+        "auto_resync_copy_targets",
+        if enabled { "true" } else { "false" },
+    )
+    .await?;
+    refresh_central_watcher(&app).await
+}
+
 // --- Custom Tools ---
 
 #[tauri::command]
@@ -829,6 +1590,42 @@ pub async fn skills_remove_repo(
     skill_store::delete_skill_repo(&state, &owner, &name).await
 }
 
+// --- Private Repo Credentials ---
+
+#[tauri::command]
+pub async fn skills_get_repo_credentials(
+    state: State<'_, DbState>,
+) -> Result<Vec<SkillRepoCredentialDto>, String> {
+    list_repo_credentials(&state).await.map_err(format_error)
+}
+
+/// Store a token (`authType = "token"`) or SSH private key (`"ssh_key"`) for a repo;
+/// the secret goes to the OS keychain, only the config is kept in the database
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn skills_set_repo_credential(
+    state: State<'_, DbState>,
+    repoUrl: String,
+    authType: String,
+    username: Option<String>,
+    secret: String,
+) -> Result<SkillRepoCredentialDto, String> {
+    save_repo_credential(&state, &repoUrl, &authType, username, &secret)
+        .await
+        .map_err(format_error)
+}
+
+#[tauri::command]
+#[allow(non_snake_case)]
+pub async fn skills_delete_repo_credential(
+    state: State<'_, DbState>,
+    repoUrl: String,
+) -> Result<(), String> {
+    delete_repo_credential(&state, &repoUrl)
+        .await
+        .map_err(format_error)
+}
+
 #[tauri::command]
 pub async fn skills_init_default_repos(state: State<'_, DbState>) -> Result<usize, String> {
     let existing = skill_store::get_skill_repos(&state).await?;
@@ -903,11 +1700,12 @@ pub async fn resync_all_skills_internal(
                 Err(_) => continue,
             };
 
-            let target = tool_root.join(&skill.name);
             let previous_target = skill_store::get_skill_target(&state, &skill.id, tool_key)
                 .await
                 .ok()
                 .flatten();
+            let target_name = previous_target.as_ref().and_then(|t| t.target_name.clone());
+            let target = tool_root.join(target_name.as_deref().unwrap_or(&skill.name));
 
             // Sync with overwrite
             if let Ok(result) = sync_skill_to_target(
@@ -929,6 +1727,7 @@ pub async fn resync_all_skills_internal(
                     status: "ok".to_string(),
                     error_message: None,
                     synced_at: Some(now_ms()),
+                    target_name,
                 };
                 let _ = skill_store::upsert_skill_target(&state, &skill.id, &record).await;
                 synced.push(format!("{}:{}", skill.name, tool_key));
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::path::Path;
 
 use anyhow::{Context, Result};
@@ -41,3 +42,100 @@ pub fn hash_dir(path: &Path) -> Result<String> {
     let digest = hasher.finalize();
     Ok(hex::encode(digest))
 }
+
+/// Hash every file under a directory, keyed by `/`-separated relative path
+pub fn hash_files(path: &Path) -> Result<BTreeMap<String, String>> {
+    let mut hashes = BTreeMap::new();
+
+    for entry in WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|entry| !is_ignored(entry))
+    {
+        let entry = entry?;
+        if is_ignored(&entry) || !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative = entry
+            .path()
+            .strip_prefix(path)
+            .with_context(|| format!("strip prefix {:?}", entry.path()))?
+            .to_string_lossy()
+            .replace('\\', "/");
+        let bytes =
+            std::fs::read(entry.path()).with_context(|| format!("read file {:?}", entry.path()))?;
+        hashes.insert(relative, hex::encode(Sha256::digest(&bytes)));
+    }
+
+    Ok(hashes)
+}
+
+/// File-level differences of a copy relative to its source directory
+#[derive(Debug, Default, PartialEq)]
+pub struct DirDiff {
+    /// Present in both, content differs
+    pub modified: Vec<String>,
+    /// Present in source, absent in copy
+    pub missing: Vec<String>,
+    /// Present only in copy
+    pub extra: Vec<String>,
+}
+
+impl DirDiff {
+    pub fn is_empty(&self) -> bool {
+        self.modified.is_empty() && self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+/// Compare a copied directory against its source file by file
+pub fn diff_dirs(source: &Path, copy: &Path) -> Result<DirDiff> {
+    let source_hashes = hash_files(source)?;
+    let copy_hashes = hash_files(copy)?;
+
+    let mut diff = DirDiff::default();
+    for (file, hash) in &source_hashes {
+        match copy_hashes.get(file) {
+            Some(copy_hash) if copy_hash != hash => diff.modified.push(file.clone()),
+            Some(_) => {}
+            None => diff.missing.push(file.clone()),
+        }
+    }
+    diff.extra = copy_hashes
+        .keys()
+        .filter(|file| !source_hashes.contains_key(*file))
+        .cloned()
+        .collect();
+
+    Ok(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_dirs_reports_modified_missing_and_extra_files() {
+        let source = tempfile::tempdir().unwrap();
+        let copy = tempfile::tempdir().unwrap();
+
+        std::fs::write(source.path().join("SKILL.md"), "v2").unwrap();
+        std::fs::create_dir(source.path().join("scripts")).unwrap();
+        std::fs::write(source.path().join("scripts/run.sh"), "echo").unwrap();
+        std::fs::write(source.path().join(".DS_Store"), "x").unwrap();
+
+        std::fs::write(copy.path().join("SKILL.md"), "v1").unwrap();
+        std::fs::write(copy.path().join("notes.txt"), "local").unwrap();
+
+        let diff = diff_dirs(source.path(), copy.path()).unwrap();
+        assert_eq!(diff.modified, vec!["SKILL.md"]);
+        assert_eq!(diff.missing, vec!["scripts/run.sh"]);
+        assert_eq!(diff.extra, vec!["notes.txt"]);
+
+        std::fs::write(copy.path().join("SKILL.md"), "v2").unwrap();
+        std::fs::create_dir(copy.path().join("scripts")).unwrap();
+        std::fs::write(copy.path().join("scripts/run.sh"), "echo").unwrap();
+        std::fs::remove_file(copy.path().join("notes.txt")).unwrap();
+        assert!(diff_dirs(source.path(), copy.path()).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use anyhow::Result;
+
+use super::git_fetcher::{repo_key, set_repo_auth, GitAuth};
+use super::skill_store;
+use super::types::{now_ms, SkillRepoCredential, SkillRepoCredentialDto};
+use crate::secrets;
+use crate::DbState;
+
+/// Keychain service name; the account is the repo key
+const KEYCHAIN_SERVICE: &str = "ai-toolbox.skills.git";
+
+/// Username sent with a token when none is configured (accepted by GitHub and GitLab)
+const DEFAULT_TOKEN_USERNAME: &str = "x-access-token";
+
+static AUTH_LOADED: AtomicBool = AtomicBool::new(false);
+
+fn to_git_auth(credential: &SkillRepoCredential, secret: String) -> GitAuth {
+    match credential.auth_type.as_str() {
+        "ssh_key" => GitAuth::SshKey {
+            private_key: secret,
+        },
+        _ => GitAuth::Token {
+            username: credential
+                .username
+                .clone()
+                .unwrap_or_else(|| DEFAULT_TOKEN_USERNAME.to_string()),
+            token: secret,
+        },
+    }
+}
+
+/// Re-read all repo credentials (configs from the DB, secrets from the keychain)
+pub async fn reload_git_auth(state: &DbState) -> Result<(), String> {
+    let credentials = skill_store::get_repo_credentials(state).await?;
+    let mut auth = HashMap::new();
+    for credential in &credentials {
+        match secrets::read_entry(KEYCHAIN_SERVICE, &credential.id) {
+            Ok(Some(secret)) => {
+                auth.insert(credential.id.clone(), to_git_auth(credential, secret));
+            }
+            Ok(None) => log::warn!(
+                "[skills] no keychain secret for repo credential {}",
+                credential.id
+            ),
+            Err(err) => log::warn!(
+                "[skills] failed to load repo credential {}: {}",
+                credential.id,
+                err
+            ),
+        }
+    }
+    set_repo_auth(auth);
+    AUTH_LOADED.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Load repo credentials on first use so the keychain is not touched at startup
+pub async fn ensure_git_auth_loaded(state: &DbState) {
+    if AUTH_LOADED.load(Ordering::SeqCst) {
+        return;
+    }
+    if let Err(err) = reload_git_auth(state).await {
+        log::warn!("[skills] failed to load repo credentials: {}", err);
+    }
+}
+
+/// List configured repo credentials and whether their secret is present
+pub async fn list_repo_credentials(state: &DbState) -> Result<Vec<SkillRepoCredentialDto>> {
+    let credentials = skill_store::get_repo_credentials(state)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+    let mut out: Vec<SkillRepoCredentialDto> = credentials
+        .into_iter()
+        .map(|credential| SkillRepoCredentialDto {
+            has_secret: matches!(
+                secrets::read_entry(KEYCHAIN_SERVICE, &credential.id),
+                Ok(Some(_))
+            ),
+            repo_key: credential.id,
+            auth_type: credential.auth_type,
+            username: credential.username,
+            updated_at: credential.updated_at,
+        })
+        .collect();
+    out.sort_by(|a, b| a.repo_key.cmp(&b.repo_key));
+    Ok(out)
+}
+
+/// Store a token or SSH private key for a repo (any URL form of the repo matches)
+pub async fn save_repo_credential(
+    state: &DbState,
+    repo_url: &str,
+    auth_type: &str,
+    username: Option<String>,
+    secret: &str,
+) -> Result<SkillRepoCredentialDto> {
+    if !matches!(auth_type, "token" | "ssh_key") {
+        anyhow::bail!("unsupported auth type: {}", auth_type);
+    }
+    let secret = secret.trim();
+    if secret.is_empty() {
+        anyhow::bail!("secret must not be empty");
+    }
+    if auth_type == "ssh_key" && !secret.contains("PRIVATE KEY") {
+        anyhow::bail!("SSH key must be a private key in PEM / OpenSSH format");
+    }
+
+    let key = repo_key(repo_url);
+    secrets::write_entry(KEYCHAIN_SERVICE, &key, secret).map_err(|e| anyhow::anyhow!(e))?;
+
+    let existing = skill_store::get_repo_credentials(state)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?
+        .into_iter()
+        .find(|c| c.id == key);
+    let now = now_ms();
+    let credential = SkillRepoCredential {
+        id: key.clone(),
+        auth_type: auth_type.to_string(),
+        username: if auth_type == "token" {
+            username
+                .map(|u| u.trim().to_string())
+                .filter(|u| !u.is_empty())
+        } else {
+            None
+        },
+        created_at: existing.map(|c| c.created_at).unwrap_or(now),
+        updated_at: now,
+    };
+    skill_store::save_repo_credential(state, &credential)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+    reload_git_auth(state)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    Ok(SkillRepoCredentialDto {
+        repo_key: key,
+        auth_type: credential.auth_type,
+        username: credential.username,
+        has_secret: true,
+        updated_at: now,
+    })
+}
+
+/// Remove a repo's credential config and its keychain secret
+pub async fn delete_repo_credential(state: &DbState, repo_url: &str) -> Result<()> {
+    let key = repo_key(repo_url);
+    secrets::delete_entry(KEYCHAIN_SERVICE, &key).map_err(|e| anyhow::anyhow!(e))?;
+    skill_store::delete_repo_credential(state, &key)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+    reload_git_auth(state).await.map_err(|e| anyhow::anyhow!(e))
+}
@@ -1,3 +1,6 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::Write;
 use std::path::Path;
 use std::process::Command;
 use std::process::Stdio;
@@ -6,36 +9,169 @@ use std::sync::RwLock;
 use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
+use base64::Engine;
 
-#[derive(Debug, Clone)]
-pub enum GitProxyMode {
-    Direct,
-    Custom(String),
-    System,
-}
+use crate::http_client::{ProxyRoute, ProxySettings};
 
 /// Thread-safe storage for proxy configuration
-static PROXY_MODE: OnceLock<RwLock<GitProxyMode>> = OnceLock::new();
+static PROXY_SETTINGS: OnceLock<RwLock<ProxySettings>> = OnceLock::new();
 
-/// Set the proxy configuration to be used for git operations
-pub fn set_proxy(proxy_mode: GitProxyMode) {
-    let storage = PROXY_MODE.get_or_init(|| RwLock::new(GitProxyMode::System));
+/// Set the proxy configuration (mode, URL and per-destination rules) for git operations
+pub fn set_proxy(settings: ProxySettings) {
+    let storage = PROXY_SETTINGS.get_or_init(|| RwLock::new(ProxySettings::default()));
     if let Ok(mut guard) = storage.write() {
-        *guard = proxy_mode;
+        *guard = settings;
     }
 }
 
 /// Get the current proxy configuration
-fn get_proxy_mode() -> GitProxyMode {
-    PROXY_MODE
+fn get_proxy_settings() -> ProxySettings {
+    PROXY_SETTINGS
         .get()
         .and_then(|storage| storage.read().ok())
         .map(|guard| guard.clone())
-        .unwrap_or(GitProxyMode::System)
+        .unwrap_or_default()
+}
+
+/// Proxy route for a repo, following the per-destination rules for its host
+fn proxy_route(repo_url: &str) -> ProxyRoute {
+    let key = repo_key(repo_url);
+    get_proxy_settings().route_for_host(key.split('/').next().unwrap_or_default())
+}
+
+/// Credentials for a private repo, resolved from the keychain
+#[derive(Clone)]
+pub enum GitAuth {
+    /// Personal access token sent as an HTTP Basic auth header
+    Token { username: String, token: String },
+    /// Private key (OpenSSH format) used for SSH remotes
+    SshKey { private_key: String },
+}
+
+/// Credentials keyed by `repo_key`
+static REPO_AUTH: OnceLock<RwLock<HashMap<String, GitAuth>>> = OnceLock::new();
+
+/// Replace all per-repo credentials used by git operations
+pub fn set_repo_auth(auth: HashMap<String, GitAuth>) {
+    let storage = REPO_AUTH.get_or_init(|| RwLock::new(HashMap::new()));
+    if let Ok(mut guard) = storage.write() {
+        *guard = auth;
+    }
+}
+
+fn get_repo_auth(repo_url: &str) -> Option<GitAuth> {
+    REPO_AUTH
+        .get()
+        .and_then(|storage| storage.read().ok())
+        .and_then(|guard| guard.get(&repo_key(repo_url)).cloned())
+}
+
+/// Normalize a clone URL to `host/owner/repo` so HTTPS and SSH forms of a repo match
+///
+/// `https://user@GitHub.com/o/r.git`, `git@github.com:o/r` and `ssh://git@github.com/o/r/`
+/// all become `github.com/o/r`.
+pub fn repo_key(repo_url: &str) -> String {
+    let url = repo_url.trim();
+    let rest = match url.split_once("://") {
+        Some((_, rest)) => rest.to_string(),
+        // scp-like syntax: user@host:path
+        None => url.replacen(':', "/", 1),
+    };
+    let rest = match rest.split_once('/') {
+        Some((authority, path)) => {
+            let host = authority.rsplit('@').next().unwrap_or(authority);
+            let host = host.split(':').next().unwrap_or(host);
+            format!("{}/{}", host.to_ascii_lowercase(), path)
+        }
+        None => rest.to_ascii_lowercase(),
+    };
+    let rest = rest.trim_end_matches('/');
+    rest.strip_suffix(".git").unwrap_or(rest).to_string()
+}
+
+/// Environment applied to every git command of the current operation
+struct AuthEnv {
+    envs: Vec<(String, String)>,
+    // Keeps the private key file alive until the operation ends
+    _key_file: Option<tempfile::NamedTempFile>,
+}
+
+thread_local! {
+    static CURRENT_AUTH: RefCell<Option<AuthEnv>> = const { RefCell::new(None) };
+    static CURRENT_PROXY: RefCell<Option<ProxyRoute>> = const { RefCell::new(None) };
+}
+
+/// Applies the repo's credentials and proxy route to git commands spawned on this thread
+/// until dropped
+struct AuthScope;
+
+impl AuthScope {
+    fn enter(repo_url: &str) -> Result<Self> {
+        let env = match get_repo_auth(repo_url) {
+            Some(auth) => Some(build_auth_env(&auth)?),
+            None => None,
+        };
+        CURRENT_AUTH.with(|current| *current.borrow_mut() = env);
+        CURRENT_PROXY.with(|current| *current.borrow_mut() = Some(proxy_route(repo_url)));
+        Ok(AuthScope)
+    }
+}
+
+impl Drop for AuthScope {
+    fn drop(&mut self) {
+        CURRENT_AUTH.with(|current| current.borrow_mut().take());
+        CURRENT_PROXY.with(|current| current.borrow_mut().take());
+    }
+}
+
+fn build_auth_env(auth: &GitAuth) -> Result<AuthEnv> {
+    match auth {
+        GitAuth::Token { username, token } => {
+            // Passed through GIT_CONFIG_* (git >= 2.31) so the token never lands in
+            // .git/config or the process arguments
+            let basic =
+                base64::engine::general_purpose::STANDARD.encode(format!("{}:{}", username, token));
+            Ok(AuthEnv {
+                envs: vec![
+                    ("GIT_CONFIG_COUNT".to_string(), "1".to_string()),
+                    (
+                        "GIT_CONFIG_KEY_0".to_string(),
+                        "http.extraHeader".to_string(),
+                    ),
+                    (
+                        "GIT_CONFIG_VALUE_0".to_string(),
+                        format!("Authorization: Basic {}", basic),
+                    ),
+                ],
+                _key_file: None,
+            })
+        }
+        GitAuth::SshKey { private_key } => {
+            // NamedTempFile is created with owner-only permissions, which ssh requires
+            let mut key_file =
+                tempfile::NamedTempFile::new().context("failed to create ssh key file")?;
+            key_file
+                .write_all(private_key.trim_end().as_bytes())
+                .and_then(|_| key_file.write_all(b"\n"))
+                .context("failed to write ssh key file")?;
+            let key_path = key_file.path().to_string_lossy().replace('\\', "/");
+            Ok(AuthEnv {
+                envs: vec![(
+                    "GIT_SSH_COMMAND".to_string(),
+                    format!(
+                        "ssh -i \"{}\" -o IdentitiesOnly=yes -o BatchMode=yes -o StrictHostKeyChecking=accept-new",
+                        key_path
+                    ),
+                )],
+                _key_file: Some(key_file),
+            })
+        }
+    }
 }
 
 /// Clone or pull a git repository
 pub fn clone_or_pull(repo_url: &str, dest: &Path, branch: Option<&str>) -> Result<String> {
+    let _auth = AuthScope::enter(repo_url)?;
     // Prefer the system `git` binary if available
     if let Some(git_bin) = resolve_git_bin() {
         let started = Instant::now();
@@ -65,6 +201,103 @@ pub fn clone_or_pull(repo_url: &str, dest: &Path, branch: Option<&str>) -> Resul
     }
 }
 
+/// Validate a user supplied tag / commit SHA to pin a git skill to
+///
+/// Returns `None` for empty input. Refs that git would parse as options or that
+/// contain whitespace are rejected.
+pub fn normalize_pinned_ref(pinned_ref: Option<&str>) -> Result<Option<String>> {
+    let Some(pinned_ref) = pinned_ref.map(str::trim).filter(|r| !r.is_empty()) else {
+        return Ok(None);
+    };
+    if pinned_ref.starts_with('-')
+        || pinned_ref.contains("..")
+        || pinned_ref
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control())
+    {
+        anyhow::bail!("INVALID_PINNED_REF|{}", pinned_ref);
+    }
+    Ok(Some(pinned_ref.to_string()))
+}
+
+/// Validate a git remote URL before it is passed to git
+///
+/// URLs that git would parse as options, that contain whitespace, or that use the
+/// command-running `ext::` / `fd::` transports are rejected.
+pub fn validate_repo_url(repo_url: &str) -> Result<()> {
+    let trimmed = repo_url.trim();
+    let lower = trimmed.to_ascii_lowercase();
+    if trimmed.is_empty()
+        || trimmed.starts_with('-')
+        || lower.starts_with("ext::")
+        || lower.starts_with("fd::")
+        || trimmed.chars().any(|c| c.is_whitespace() || c.is_control())
+    {
+        anyhow::bail!("INVALID_REPO_URL|{}", repo_url);
+    }
+    Ok(())
+}
+
+/// Fetch a single tag or full commit SHA and check it out (detached)
+pub fn fetch_pinned_ref(repo_url: &str, dest: &Path, pinned_ref: &str) -> Result<String> {
+    if resolve_git_bin().is_none() {
+        anyhow::bail!("GIT_NOT_FOUND");
+    }
+    let _auth = AuthScope::enter(repo_url)?;
+
+    let started = Instant::now();
+    match fetch_pinned_ref_via_git_cli(repo_url, dest, pinned_ref) {
+        Ok(head) => {
+            log::info!(
+                "[git_fetcher] pinned fetch ok {}s url={} ref={}",
+                started.elapsed().as_secs_f32(),
+                repo_url,
+                pinned_ref
+            );
+            Ok(head)
+        }
+        Err(err) => {
+            log::warn!(
+                "[git_fetcher] pinned fetch failed {}s url={} ref={} err={:#}",
+                started.elapsed().as_secs_f32(),
+                repo_url,
+                pinned_ref,
+                err
+            );
+            anyhow::bail!("GIT_COMMAND_FAILED|{:#}", err);
+        }
+    }
+}
+
+/// Resolve the commit a remote branch (or the default HEAD) points at, without cloning
+pub fn remote_head(repo_url: &str, branch: Option<&str>) -> Result<String> {
+    if resolve_git_bin().is_none() {
+        anyhow::bail!("GIT_NOT_FOUND");
+    }
+    let _auth = AuthScope::enter(repo_url)?;
+
+    let pattern = match branch {
+        Some(branch) => format!("refs/heads/{}", branch),
+        None => "HEAD".to_string(),
+    };
+    let mut cmd = git_cmd();
+    cmd.args(["ls-remote", repo_url, &pattern]);
+    let out = run_cmd_with_timeout(
+        cmd,
+        git_fetch_timeout(),
+        format!("git ls-remote {} {}", repo_url, pattern),
+    )?;
+    if !out.status.success() {
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        anyhow::bail!("GIT_LS_REMOTE_FAILED|{}|{}", repo_url, stderr);
+    }
+
+    String::from_utf8_lossy(&out.stdout)
+        .lines()
+        .find_map(|line| line.split_whitespace().next().map(|s| s.to_string()))
+        .ok_or_else(|| anyhow::anyhow!("GIT_REF_NOT_FOUND|{}|{}", repo_url, pattern))
+}
+
 fn git_timeout() -> Duration {
     let secs = std::env::var("SKILLS_GIT_TIMEOUT_SECS")
         .ok()
@@ -83,7 +316,7 @@ fn git_fetch_timeout() -> Duration {
 
 static GIT_BIN: OnceLock<Option<String>> = OnceLock::new();
 
-fn resolve_git_bin() -> Option<String> {
+pub(crate) fn resolve_git_bin() -> Option<String> {
     GIT_BIN
         .get_or_init(|| {
             // Allow overriding from environment
@@ -142,23 +375,32 @@ fn git_cmd() -> Command {
     cmd.env("GIT_HTTP_LOW_SPEED_LIMIT", "1024")
         .env("GIT_HTTP_LOW_SPEED_TIME", "120");
 
-    match get_proxy_mode() {
-        GitProxyMode::Direct => {
+    let route = CURRENT_PROXY
+        .with(|current| current.borrow().clone())
+        .unwrap_or_else(|| get_proxy_settings().default_route());
+    match route {
+        ProxyRoute::Direct => {
             cmd.env_remove("HTTP_PROXY")
                 .env_remove("HTTPS_PROXY")
                 .env_remove("http_proxy")
                 .env_remove("https_proxy");
         }
-        GitProxyMode::Custom(proxy_url) => {
+        ProxyRoute::Proxy(proxy_url) if !proxy_url.is_empty() => {
             log::info!("[git_fetcher] using proxy: {}", proxy_url);
             cmd.env("HTTP_PROXY", &proxy_url)
                 .env("HTTPS_PROXY", &proxy_url)
                 .env("http_proxy", &proxy_url)
                 .env("https_proxy", &proxy_url);
         }
-        GitProxyMode::System => {}
+        ProxyRoute::Proxy(_) | ProxyRoute::System => {}
     }
 
+    CURRENT_AUTH.with(|current| {
+        if let Some(auth) = current.borrow().as_ref() {
+            cmd.envs(auth.envs.iter().map(|(k, v)| (k, v)));
+        }
+    });
+
     cmd
 }
 
@@ -259,7 +501,7 @@ fn clone_or_pull_via_git_cli(repo_url: &str, dest: &Path, branch: Option<&str>)
         if let Some(branch) = branch {
             cmd.arg("--branch").arg(branch).arg("--single-branch");
         }
-        cmd.arg(repo_url).arg(dest);
+        cmd.arg("--").arg(repo_url).arg(dest);
         let out = run_cmd_with_timeout(
             cmd,
             git_timeout(),
@@ -306,3 +548,119 @@ fn clone_or_pull_via_git_cli(repo_url: &str, dest: &Path, branch: Option<&str>)
     }
     Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
 }
+
+fn run_git_in(dest: &Path, args: &[&str], timeout: Duration) -> Result<std::process::Output> {
+    let mut cmd = git_cmd();
+    cmd.arg("-C").arg(dest).args(args);
+    run_cmd_with_timeout(
+        cmd,
+        timeout,
+        format!("git {} in {:?}", args.join(" "), dest),
+    )
+}
+
+/// Run a local (non-network) git command in `dir` and return its stdout
+pub fn run_local_git(dir: &Path, args: &[&str]) -> Result<String> {
+    let out = run_git_in(dir, args, git_timeout())?;
+    if !out.status.success() {
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        anyhow::bail!("git {} failed: {}", args.join(" "), stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).to_string())
+}
+
+fn fetch_pinned_ref_via_git_cli(repo_url: &str, dest: &Path, pinned_ref: &str) -> Result<String> {
+    if !dest.join(".git").exists() {
+        std::fs::create_dir_all(dest)
+            .with_context(|| format!("failed to create dir {:?}", dest))?;
+        let out = run_git_in(dest, &["init", "--quiet"], git_fetch_timeout())?;
+        if !out.status.success() {
+            let stderr = String::from_utf8_lossy(&out.stderr);
+            anyhow::bail!("GIT_INIT_FAILED|{}", stderr);
+        }
+        let out = run_git_in(
+            dest,
+            &["remote", "add", "origin", repo_url],
+            git_fetch_timeout(),
+        )?;
+        if !out.status.success() {
+            let stderr = String::from_utf8_lossy(&out.stderr);
+            anyhow::bail!("GIT_REMOTE_FAILED|{}", stderr);
+        }
+    }
+
+    // Tags and full SHAs can both be fetched by name; abbreviated SHAs cannot
+    let out = run_git_in(
+        dest,
+        &[
+            "fetch",
+            "--depth",
+            "1",
+            "--filter=blob:none",
+            "--no-tags",
+            "origin",
+            "--",
+            pinned_ref,
+        ],
+        git_timeout(),
+    )?;
+    if !out.status.success() {
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        anyhow::bail!("GIT_FETCH_FAILED|{}|{}", pinned_ref, stderr);
+    }
+
+    let out = run_git_in(
+        dest,
+        &["checkout", "--force", "--detach", "FETCH_HEAD"],
+        git_fetch_timeout(),
+    )?;
+    if !out.status.success() {
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        anyhow::bail!("GIT_CHECKOUT_FAILED|{}|{}", pinned_ref, stderr);
+    }
+
+    let out = run_git_in(dest, &["rev-parse", "HEAD"], git_fetch_timeout())?;
+    if !out.status.success() {
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        anyhow::bail!("GIT_REVPARSE_FAILED|{}", stderr);
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_pinned_ref_rejects_option_like_refs() {
+        assert_eq!(normalize_pinned_ref(None).unwrap(), None);
+        assert_eq!(normalize_pinned_ref(Some("  ")).unwrap(), None);
+        assert_eq!(
+            normalize_pinned_ref(Some(" v1.2.0 ")).unwrap().as_deref(),
+            Some("v1.2.0")
+        );
+        assert!(normalize_pinned_ref(Some("--upload-pack=evil")).is_err());
+        assert!(normalize_pinned_ref(Some("main..dev")).is_err());
+        assert!(normalize_pinned_ref(Some("v1 v2")).is_err());
+    }
+
+    #[test]
+    fn validate_repo_url_rejects_options_and_command_transports() {
+        assert!(validate_repo_url("https://github.com/owner/repo").is_ok());
+        assert!(validate_repo_url("git@github.com:owner/repo.git").is_ok());
+        assert!(validate_repo_url("owner/repo").is_ok());
+        assert!(validate_repo_url("").is_err());
+        assert!(validate_repo_url("--upload-pack=evil").is_err());
+        assert!(validate_repo_url("ext::sh -c evil").is_err());
+        assert!(validate_repo_url("https://github.com/o/r --x").is_err());
+    }
+
+    #[test]
+    fn repo_key_matches_https_and_ssh_forms() {
+        let expected = "github.com/owner/repo";
+        assert_eq!(repo_key("https://github.com/owner/repo.git"), expected);
+        assert_eq!(repo_key("https://user@GitHub.com/owner/repo/"), expected);
+        assert_eq!(repo_key("git@github.com:owner/repo.git"), expected);
+        assert_eq!(repo_key("ssh://git@github.com:22/owner/repo"), expected);
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+
+use anyhow::Result;
+use serde::Serialize;
+use tauri::Emitter;
+
+/// Max number of git fetch / clone operations running at the same time
+const MAX_CONCURRENT_GIT_OPS: usize = 4;
+
+struct GitSlots {
+    active: Mutex<usize>,
+    freed: Condvar,
+}
+
+static GIT_SLOTS: GitSlots = GitSlots {
+    active: Mutex::new(0),
+    freed: Condvar::new(),
+};
+
+/// Per cache-dir locks so different repos fetch in parallel while the same repo never does
+static REPO_LOCKS: OnceLock<Mutex<HashMap<String, Arc<Mutex<()>>>>> = OnceLock::new();
+
+struct GitSlotGuard;
+
+impl Drop for GitSlotGuard {
+    fn drop(&mut self) {
+        let mut active = GIT_SLOTS
+            .active
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        *active = active.saturating_sub(1);
+        GIT_SLOTS.freed.notify_one();
+    }
+}
+
+/// Block until one of the `MAX_CONCURRENT_GIT_OPS` slots is free
+fn acquire_git_slot() -> GitSlotGuard {
+    let mut active = GIT_SLOTS
+        .active
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    while *active >= MAX_CONCURRENT_GIT_OPS {
+        active = GIT_SLOTS
+            .freed
+            .wait(active)
+            .unwrap_or_else(|err| err.into_inner());
+    }
+    *active += 1;
+    GitSlotGuard
+}
+
+/// Lock guarding a single git cache dir
+pub fn repo_lock(cache_key: &str) -> Arc<Mutex<()>> {
+    let locks = REPO_LOCKS.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut locks = locks.lock().unwrap_or_else(|err| err.into_inner());
+    locks
+        .entry(cache_key.to_string())
+        .or_insert_with(|| Arc::new(Mutex::new(())))
+        .clone()
+}
+
+/// Payload of the `skills-git-progress` event
+#[derive(Clone, Debug, Serialize)]
+pub struct GitProgressPayload {
+    pub repo_url: String,
+    pub operation: String, // "list" | "install" | "update"
+    pub stage: String,     // "queued" | "running" | "done" | "error"
+    pub error: Option<String>,
+}
+
+fn emit_progress(
+    app: &tauri::AppHandle,
+    repo_url: &str,
+    operation: &str,
+    stage: &str,
+    error: Option<String>,
+) {
+    let _ = app.emit(
+        "skills-git-progress",
+        GitProgressPayload {
+            repo_url: repo_url.to_string(),
+            operation: operation.to_string(),
+            stage: stage.to_string(),
+            error,
+        },
+    );
+}
+
+/// Run a blocking git task on the bounded pool, emitting `skills-git-progress` per repo
+pub async fn run_git_task<T, F>(
+    app: &tauri::AppHandle,
+    repo_url: &str,
+    operation: &str,
+    task: F,
+) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    emit_progress(app, repo_url, operation, "queued", None);
+
+    let app_handle = app.clone();
+    let url = repo_url.to_string();
+    let op = operation.to_string();
+    let result = tokio::task::spawn_blocking(move || {
+        let _slot = acquire_git_slot();
+        emit_progress(&app_handle, &url, &op, "running", None);
+        task()
+    })
+    .await
+    .map_err(|e| anyhow::anyhow!("git task failed: {}", e))
+    .and_then(|result| result);
+
+    match &result {
+        Ok(_) => emit_progress(app, repo_url, operation, "done", None),
+        Err(err) => emit_progress(
+            app,
+            repo_url,
+            operation,
+            "error",
+            Some(format!("{:#}", err)),
+        ),
+    }
+    result
+}
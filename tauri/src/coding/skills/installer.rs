@@ -1,17 +1,21 @@
 use std::path::{Path, PathBuf};
-use std::sync::{Mutex, OnceLock};
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::cache_cleanup::get_git_cache_ttl_secs;
+use super::cache_cleanup::{enforce_git_cache_limit_from_settings, get_git_cache_ttl_secs};
 use super::central_repo::{
     ensure_central_repo, resolve_central_repo_path, resolve_skill_central_path,
     to_relative_central_path,
 };
 use super::content_hash::hash_dir;
-use super::git_fetcher::{clone_or_pull, set_proxy, GitProxyMode};
+use super::credentials::ensure_git_auth_loaded;
+use super::git_fetcher::{
+    clone_or_pull, fetch_pinned_ref, normalize_pinned_ref, set_proxy, validate_repo_url,
+};
+use super::git_pool::{repo_lock, run_git_task};
+use super::name_conflict::validate_skill_dir_name;
 use super::path_executor::{
     remove_skill_target, sync_copy_target_path, sync_skill_to_target, target_path_changed,
 };
@@ -31,6 +35,7 @@ pub async fn install_local_skill(
     state: &DbState,
     source_path: &Path,
     overwrite: bool,
+    install_name: Option<&str>,
 ) -> Result<InstallResult> {
     super::tool_adapters::set_runtime_db(state.db());
 
@@ -42,6 +47,7 @@ pub async fn install_local_skill(
         .file_name()
         .map(|v| v.to_string_lossy().to_string())
         .unwrap_or_else(|| "unnamed-skill".to_string());
+    let name = with_install_name(name, install_name)?;
 
     let central_dir = resolve_central_repo_path(app, state).await?;
     ensure_central_repo(&central_dir)?;
@@ -77,12 +83,16 @@ pub async fn install_local_skill(
         source_type: "local".to_string(),
         source_ref: Some(source_path.to_string_lossy().to_string()),
         source_revision: None,
+        pinned_ref: None,
         central_path: to_relative_central_path(&central_path, &central_dir),
         content_hash: content_hash.clone(),
         created_at: now,
         updated_at: now,
         last_sync_at: None,
         status: "ok".to_string(),
+        description: None,
+        tags: Vec::new(),
+        notes: None,
         sort_index: 0,
         enabled_tools: Vec::new(),
         sync_details: None,
@@ -138,6 +148,7 @@ pub async fn install_local_skill_from_selection(
     source_path: &Path,
     subpath: &str,
     overwrite: bool,
+    install_name: Option<&str>,
 ) -> Result<InstallResult> {
     super::tool_adapters::set_runtime_db(state.db());
 
@@ -157,6 +168,7 @@ pub async fn install_local_skill_from_selection(
             .map(|v| v.to_string_lossy().to_string())
             .unwrap_or_else(|| "unnamed-skill".to_string())
     });
+    let name = with_install_name(name, install_name)?;
 
     let central_dir = resolve_central_repo_path(app, state).await?;
     ensure_central_repo(&central_dir)?;
@@ -198,12 +210,16 @@ pub async fn install_local_skill_from_selection(
         source_type: "local".to_string(),
         source_ref: Some(full_source_ref),
         source_revision: None,
+        pinned_ref: None,
         central_path: to_relative_central_path(&central_path, &central_dir),
         content_hash: content_hash.clone(),
         created_at: now,
         updated_at: now,
         last_sync_at: None,
         status: "ok".to_string(),
+        description: None,
+        tags: Vec::new(),
+        notes: None,
         sort_index: 0,
         enabled_tools: Vec::new(),
         sync_details: None,
@@ -227,12 +243,16 @@ pub async fn install_git_skill(
     state: &DbState,
     repo_url: &str,
     branch: Option<&str>,
+    pinned_ref: Option<&str>,
     overwrite: bool,
+    install_name: Option<&str>,
 ) -> Result<InstallResult> {
     super::tool_adapters::set_runtime_db(state.db());
+    validate_repo_url(repo_url)?;
+    let pinned_ref = normalize_pinned_ref(pinned_ref)?;
 
     // Initialize proxy from app settings
-    init_proxy_from_settings(state).await;
+    init_git_env_from_settings(state).await;
 
     let parsed = parse_github_url(repo_url);
     // Use provided branch, or fall back to parsed branch from URL, or default to "main"
@@ -240,7 +260,16 @@ pub async fn install_git_skill(
 
     // Clone first, then read skill name from SKILL.md
     let ttl = get_git_cache_ttl_secs(state).await;
-    let (repo_dir, rev) = clone_to_cache(app, ttl, &parsed.clone_url, effective_branch)?;
+    let (repo_dir, rev) = fetch_to_cache(
+        app,
+        ttl,
+        "install",
+        repo_url,
+        &parsed.clone_url,
+        effective_branch,
+        pinned_ref.as_deref(),
+    )
+    .await?;
 
     let copy_src = if let Some(subpath) = &parsed.subpath {
         let sub_src = repo_dir.join(subpath);
@@ -281,6 +310,7 @@ pub async fn install_git_skill(
     // Try to read name from SKILL.md, fallback to URL-derived name
     let name = read_skill_name_from_dir(&copy_src)
         .unwrap_or_else(|| derive_name_from_repo_url(&parsed.clone_url));
+    let name = with_install_name(name, install_name)?;
 
     let central_dir = resolve_central_repo_path(app, state).await?;
     ensure_central_repo(&central_dir)?;
@@ -335,12 +365,16 @@ pub async fn install_git_skill(
         source_type: "git".to_string(),
         source_ref: Some(full_source_ref),
         source_revision: Some(rev),
+        pinned_ref,
         central_path: to_relative_central_path(&central_path, &central_dir),
         content_hash: content_hash.clone(),
         created_at: now,
         updated_at: now,
         last_sync_at: None,
         status: "ok".to_string(),
+        description: None,
+        tags: Vec::new(),
+        notes: None,
         sort_index: 0,
         enabled_tools: Vec::new(),
         sync_details: None,
@@ -365,11 +399,17 @@ pub fn list_git_skills(
     repo_url: &str,
     branch: Option<&str>,
 ) -> Result<Vec<GitSkillCandidate>> {
+    validate_repo_url(repo_url)?;
     let parsed = parse_github_url(repo_url);
     // Use provided branch, or fall back to parsed branch from URL
     let effective_branch = branch.or(parsed.branch.as_deref());
-    let (repo_dir, _rev) =
-        clone_to_cache(app, cache_ttl_secs, &parsed.clone_url, effective_branch)?;
+    let (repo_dir, _rev) = clone_to_cache(
+        app,
+        cache_ttl_secs,
+        &parsed.clone_url,
+        effective_branch,
+        None,
+    )?;
 
     let mut out: Vec<GitSkillCandidate> = Vec::new();
 
@@ -420,10 +460,15 @@ pub async fn install_git_skill_from_selection(
     repo_url: &str,
     subpath: &str,
     branch: Option<&str>,
+    pinned_ref: Option<&str>,
     overwrite: bool,
+    install_name: Option<&str>,
 ) -> Result<InstallResult> {
+    validate_repo_url(repo_url)?;
+    let pinned_ref = normalize_pinned_ref(pinned_ref)?;
+
     // Initialize proxy from app settings
-    init_proxy_from_settings(state).await;
+    init_git_env_from_settings(state).await;
 
     let parsed = parse_github_url(repo_url);
     // Use provided branch, or fall back to parsed branch from URL
@@ -431,10 +476,105 @@ pub async fn install_git_skill_from_selection(
 
     // Clone first, then read skill name from SKILL.md
     let ttl = get_git_cache_ttl_secs(state).await;
-    let (repo_dir, revision) = clone_to_cache(app, ttl, &parsed.clone_url, effective_branch)?;
+    let (repo_dir, revision) = fetch_to_cache(
+        app,
+        ttl,
+        "install",
+        repo_url,
+        &parsed.clone_url,
+        effective_branch,
+        pinned_ref.as_deref(),
+    )
+    .await?;
+
+    let fetched = FetchedGitRepo {
+        repo_url,
+        clone_url: &parsed.clone_url,
+        repo_dir: &repo_dir,
+        revision: &revision,
+        branch: effective_branch,
+        pinned_ref: pinned_ref.as_deref(),
+    };
+    install_selection_from_fetched_repo(app, state, &fetched, subpath, overwrite, install_name)
+        .await
+}
+
+/// Install several skills from one Git repo, fetching it only once
+///
+/// Each subpath gets its own result, so one failure does not abort the rest.
+pub async fn install_git_skill_selections(
+    app: &tauri::AppHandle,
+    state: &DbState,
+    repo_url: &str,
+    subpaths: &[String],
+    branch: Option<&str>,
+    pinned_ref: Option<&str>,
+    overwrite: bool,
+) -> Result<Vec<(String, Result<InstallResult>)>> {
+    validate_repo_url(repo_url)?;
+    let pinned_ref = normalize_pinned_ref(pinned_ref)?;
+
+    // Initialize proxy from app settings
+    init_git_env_from_settings(state).await;
+
+    let parsed = parse_github_url(repo_url);
+    // Use provided branch, or fall back to parsed branch from URL
+    let effective_branch = branch.or(parsed.branch.as_deref());
+
+    let ttl = get_git_cache_ttl_secs(state).await;
+    let (repo_dir, revision) = fetch_to_cache(
+        app,
+        ttl,
+        "install",
+        repo_url,
+        &parsed.clone_url,
+        effective_branch,
+        pinned_ref.as_deref(),
+    )
+    .await?;
+
+    let fetched = FetchedGitRepo {
+        repo_url,
+        clone_url: &parsed.clone_url,
+        repo_dir: &repo_dir,
+        revision: &revision,
+        branch: effective_branch,
+        pinned_ref: pinned_ref.as_deref(),
+    };
+    let mut results = Vec::with_capacity(subpaths.len());
+    for subpath in subpaths {
+        let result =
+            install_selection_from_fetched_repo(app, state, &fetched, subpath, overwrite, None)
+                .await;
+        results.push((subpath.clone(), result));
+    }
+    Ok(results)
+}
+
+/// A Git repo already checked out in the cache
+struct FetchedGitRepo<'a> {
+    repo_url: &'a str,
+    clone_url: &'a str,
+    repo_dir: &'a Path,
+    revision: &'a str,
+    branch: Option<&'a str>,
+    pinned_ref: Option<&'a str>,
+}
+
+async fn install_selection_from_fetched_repo(
+    app: &tauri::AppHandle,
+    state: &DbState,
+    fetched: &FetchedGitRepo<'_>,
+    subpath: &str,
+    overwrite: bool,
+    install_name: Option<&str>,
+) -> Result<InstallResult> {
+    let repo_url = fetched.repo_url;
+    let repo_dir = fetched.repo_dir;
+    let effective_branch = fetched.branch;
 
     let copy_src = if subpath == "." {
-        repo_dir.clone()
+        repo_dir.to_path_buf()
     } else {
         repo_dir.join(subpath)
     };
@@ -447,8 +587,9 @@ pub async fn install_git_skill_from_selection(
         Path::new(subpath)
             .file_name()
             .map(|s| s.to_string_lossy().to_string())
-            .unwrap_or_else(|| derive_name_from_repo_url(&parsed.clone_url))
+            .unwrap_or_else(|| derive_name_from_repo_url(fetched.clone_url))
     });
+    let display_name = with_install_name(display_name, install_name)?;
 
     let central_dir = resolve_central_repo_path(app, state).await?;
     ensure_central_repo(&central_dir)?;
@@ -483,7 +624,7 @@ pub async fn install_git_skill_from_selection(
         // Build GitHub tree URL: https://github.com/owner/repo/tree/branch/subpath
         format!(
             "{}/tree/{}/{}",
-            parsed.clone_url.trim_end_matches(".git"),
+            fetched.clone_url.trim_end_matches(".git"),
             branch_for_url,
             subpath
         )
@@ -496,13 +637,17 @@ pub async fn install_git_skill_from_selection(
         name: display_name.clone(),
         source_type: "git".to_string(),
         source_ref: Some(full_source_ref),
-        source_revision: Some(revision),
+        source_revision: Some(fetched.revision.to_string()),
+        pinned_ref: fetched.pinned_ref.map(str::to_string),
         central_path: to_relative_central_path(&central_path, &central_dir),
         content_hash: content_hash.clone(),
         created_at: now,
         updated_at: now,
         last_sync_at: None,
         status: "ok".to_string(),
+        description: None,
+        tags: Vec::new(),
+        notes: None,
         sort_index: 0,
         enabled_tools: Vec::new(),
         sync_details: None,
@@ -520,20 +665,31 @@ pub async fn install_git_skill_from_selection(
 }
 
 /// Update a managed skill from its source
+///
+/// Git skills pinned to a tag / commit stay on that ref. Pass `move_pin = true` to
+/// re-pin to `new_pinned_ref`, or to unpin and follow the branch again when it is `None`.
 pub async fn update_managed_skill_from_source(
     app: &tauri::AppHandle,
     state: &DbState,
     skill_id: &str,
+    move_pin: bool,
+    new_pinned_ref: Option<&str>,
 ) -> Result<UpdateResult> {
     super::tool_adapters::set_runtime_db(state.db());
+    let new_pinned_ref = normalize_pinned_ref(new_pinned_ref)?;
 
     // Initialize proxy from app settings (for git source types)
-    init_proxy_from_settings(state).await;
+    init_git_env_from_settings(state).await;
 
     let record = skill_store::get_skill_by_id(state, skill_id)
         .await
         .map_err(|e| anyhow::anyhow!(e))?
         .ok_or_else(|| anyhow::anyhow!("skill not found"))?;
+    let pinned_ref = if move_pin {
+        new_pinned_ref
+    } else {
+        normalize_pinned_ref(record.pinned_ref.as_deref())?
+    };
 
     // Resolve central_path: supports both relative (new) and legacy absolute paths
     let central_dir = resolve_central_repo_path(app, state).await?;
@@ -561,11 +717,20 @@ pub async fn update_managed_skill_from_source(
             .source_ref
             .as_deref()
             .ok_or_else(|| anyhow::anyhow!("missing source_ref for git skill"))?;
+        validate_repo_url(repo_url)?;
         let parsed = parse_github_url(repo_url);
 
         let ttl = get_git_cache_ttl_secs(state).await;
-        let (repo_dir, rev) =
-            clone_to_cache(app, ttl, &parsed.clone_url, parsed.branch.as_deref())?;
+        let (repo_dir, rev) = fetch_to_cache(
+            app,
+            ttl,
+            "update",
+            repo_url,
+            &parsed.clone_url,
+            parsed.branch.as_deref(),
+            pinned_ref.as_deref(),
+        )
+        .await?;
         new_revision = Some(rev);
 
         let copy_src = if let Some(subpath) = &parsed.subpath {
@@ -614,12 +779,20 @@ pub async fn update_managed_skill_from_source(
         source_type: record.source_type.clone(),
         source_ref: record.source_ref.clone(),
         source_revision: new_revision.clone().or(record.source_revision.clone()),
+        pinned_ref: if record.source_type == "git" {
+            pinned_ref.clone()
+        } else {
+            None
+        },
         central_path: relative_central_path,
         content_hash: content_hash.clone(),
         created_at: record.created_at,
         updated_at: now,
         last_sync_at: record.last_sync_at,
         status: "ok".to_string(),
+        description: record.description.clone(),
+        tags: record.tags.clone(),
+        notes: record.notes.clone(),
         sort_index: record.sort_index,
         enabled_tools: record.enabled_tools.clone(),
         sync_details: record.sync_details.clone(),
@@ -636,13 +809,7 @@ pub async fn update_managed_skill_from_source(
         .await
         .unwrap_or_default();
     let mut updated_targets: Vec<String> = Vec::new();
-    for t in targets {
-        // Check if custom tool has force_copy enabled
-        let custom_tool_force_copy = custom_tools
-            .iter()
-            .find(|ct| ct.key == t.tool)
-            .map(|ct| ct.force_copy)
-            .unwrap_or(false);
+    for t in targets.into_iter().filter(|t| !t.is_disabled()) {
         let runtime_adapter = if let Some(adapter) = runtime_adapter_by_key(&t.tool, &custom_tools)
         {
             adapter
@@ -664,9 +831,10 @@ pub async fn update_managed_skill_from_source(
             Ok(path) => path,
             Err(_) => continue,
         };
-        let current_target = tool_root.join(&record.name);
+        let current_target = tool_root.join(t.target_name.as_deref().unwrap_or(&record.name));
         let target_path_moved = target_path_changed(&t.target_path, &current_target);
-        let force_copy = t.mode == "copy" || t.tool == "cursor" || custom_tool_force_copy;
+        // Custom tools can force copy; built-in tools like Cursor prefer it
+        let force_copy = t.mode == "copy" || runtime_adapter.force_copy;
 
         let sync_result = if target_path_moved {
             let sync_result = sync_skill_to_target(
@@ -698,6 +866,7 @@ pub async fn update_managed_skill_from_source(
             status: "ok".to_string(),
             synced_at: Some(now),
             error_message: None,
+            target_name: t.target_name.clone(),
         };
         let _ = skill_store::upsert_skill_target(state, skill_id, &target_record).await;
 
@@ -712,6 +881,7 @@ pub async fn update_managed_skill_from_source(
         central_path,
         content_hash,
         source_revision: new_revision,
+        pinned_ref: updated.pinned_ref,
         updated_targets,
     })
 }
@@ -719,13 +889,13 @@ pub async fn update_managed_skill_from_source(
 // --- Git URL parsing ---
 
 #[derive(Clone, Debug)]
-struct ParsedGitSource {
-    clone_url: String,
-    branch: Option<String>,
-    subpath: Option<String>,
+pub(super) struct ParsedGitSource {
+    pub(super) clone_url: String,
+    pub(super) branch: Option<String>,
+    pub(super) subpath: Option<String>,
 }
 
-fn parse_github_url(input: &str) -> ParsedGitSource {
+pub(super) fn parse_github_url(input: &str) -> ParsedGitSource {
     let trimmed = input.trim().trim_end_matches('/');
 
     // Convenience: allow GitHub shorthand inputs
@@ -848,6 +1018,14 @@ fn derive_name_from_repo_url(repo_url: &str) -> String {
     }
 }
 
+/// Install under a caller-chosen name (to resolve a name conflict) instead of the detected one
+fn with_install_name(detected: String, install_name: Option<&str>) -> Result<String> {
+    match install_name {
+        Some(name) => validate_skill_dir_name(name),
+        None => Ok(detected),
+    }
+}
+
 fn compute_content_hash(path: &Path) -> Option<String> {
     hash_dir(path).ok()
 }
@@ -964,39 +1142,92 @@ fn scan_skills_recursive_paths(current_dir: &Path, base_dir: &Path, out: &mut Ve
 struct RepoCacheMeta {
     last_fetched_ms: i64,
     head: Option<String>,
+    // Last fetch or cache hit, used for LRU eviction
+    #[serde(default)]
+    last_used_ms: Option<i64>,
+    #[serde(default)]
+    repo_url: Option<String>,
+    #[serde(default)]
+    ref_name: Option<String>,
 }
 
-static GIT_CACHE_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+/// Run `clone_to_cache` on the bounded git pool, reporting progress for `repo_url`
+async fn fetch_to_cache(
+    app: &tauri::AppHandle,
+    cache_ttl_secs: i64,
+    operation: &str,
+    repo_url: &str,
+    clone_url: &str,
+    branch: Option<&str>,
+    pinned_ref: Option<&str>,
+) -> Result<(PathBuf, String)> {
+    let app_handle = app.clone();
+    let clone_url = clone_url.to_string();
+    let branch = branch.map(str::to_string);
+    let pinned_ref = pinned_ref.map(str::to_string);
+    let fetched = run_git_task(app, repo_url, operation, move || {
+        clone_to_cache(
+            &app_handle,
+            cache_ttl_secs,
+            &clone_url,
+            branch.as_deref(),
+            pinned_ref.as_deref(),
+        )
+    })
+    .await?;
+
+    enforce_git_cache_limit_from_settings(app, Some(fetched.0.clone())).await;
+    Ok(fetched)
+}
 
+/// Fetch a repo into the git cache; a pinned tag / SHA gets its own cache entry
+/// and takes precedence over `branch`
 fn clone_to_cache(
     app: &tauri::AppHandle,
     cache_ttl_secs: i64,
     clone_url: &str,
     branch: Option<&str>,
+    pinned_ref: Option<&str>,
 ) -> Result<(PathBuf, String)> {
-    use tauri::Manager;
-
-    let cache_dir = app
-        .path()
-        .app_cache_dir()
-        .context("failed to resolve app cache dir")?;
+    let cache_dir =
+        crate::portable::app_cache_dir(app).context("failed to resolve app cache dir")?;
     let cache_root = cache_dir.join("skills-git-cache");
     std::fs::create_dir_all(&cache_root)
         .with_context(|| format!("failed to create cache dir {:?}", cache_root))?;
 
-    let repo_dir = cache_root.join(repo_cache_key(clone_url, branch));
+    let ref_name = match pinned_ref {
+        Some(pinned_ref) => Some(format!("pin:{}", pinned_ref)),
+        None => branch.map(str::to_string),
+    };
+    let cache_key = repo_cache_key(clone_url, ref_name.as_deref());
+    let repo_dir = cache_root.join(&cache_key);
+    let fetch = |repo_dir: &Path| match pinned_ref {
+        Some(pinned_ref) => fetch_pinned_ref(clone_url, repo_dir, pinned_ref),
+        None => clone_or_pull(clone_url, repo_dir, branch),
+    };
     let meta_path = repo_dir.join(".skills-cache.json");
 
-    let lock = GIT_CACHE_LOCK.get_or_init(|| Mutex::new(()));
+    let lock = repo_lock(&cache_key);
     let _guard = lock.lock().unwrap_or_else(|err| err.into_inner());
 
+    let write_meta = |meta: &RepoCacheMeta| {
+        let _ = std::fs::write(
+            &meta_path,
+            serde_json::to_string(meta).unwrap_or_else(|_| "{}".to_string()),
+        );
+    };
+
     // Check cache freshness
     if repo_dir.join(".git").exists() {
         if let Ok(meta) = std::fs::read_to_string(&meta_path) {
             if let Ok(meta) = serde_json::from_str::<RepoCacheMeta>(&meta) {
-                if let Some(head) = meta.head {
+                if let Some(head) = meta.head.clone() {
                     let ttl_ms = cache_ttl_secs.saturating_mul(1000);
                     if ttl_ms > 0 && now_ms().saturating_sub(meta.last_fetched_ms) < ttl_ms {
+                        write_meta(&RepoCacheMeta {
+                            last_used_ms: Some(now_ms()),
+                            ..meta
+                        });
                         return Ok((repo_dir, head));
                     }
                 }
@@ -1004,25 +1235,24 @@ fn clone_to_cache(
         }
     }
 
-    let rev = match clone_or_pull(clone_url, &repo_dir, branch) {
+    let rev = match fetch(&repo_dir) {
         Ok(rev) => rev,
         Err(err) => {
             // If cache got corrupted, retry once from a clean state
             if repo_dir.exists() {
                 let _ = std::fs::remove_dir_all(&repo_dir);
             }
-            clone_or_pull(clone_url, &repo_dir, branch).with_context(|| format!("{:#}", err))?
+            fetch(&repo_dir).with_context(|| format!("{:#}", err))?
         }
     };
 
-    let _ = std::fs::write(
-        &meta_path,
-        serde_json::to_string(&RepoCacheMeta {
-            last_fetched_ms: now_ms(),
-            head: Some(rev.clone()),
-        })
-        .unwrap_or_else(|_| "{}".to_string()),
-    );
+    write_meta(&RepoCacheMeta {
+        last_fetched_ms: now_ms(),
+        head: Some(rev.clone()),
+        last_used_ms: Some(now_ms()),
+        repo_url: Some(clone_url.to_string()),
+        ref_name,
+    });
 
     Ok((repo_dir, rev))
 }
@@ -1038,13 +1268,12 @@ fn repo_cache_key(clone_url: &str, branch: Option<&str>) -> String {
     hex::encode(hasher.finalize())
 }
 
-/// Initialize proxy settings from app settings database
-async fn init_proxy_from_settings(state: &DbState) {
-    let proxy_result = http_client::get_proxy_from_settings(state).await.ok();
-    let proxy_mode = match proxy_result {
-        Some((http_client::ProxyMode::Direct, _)) => GitProxyMode::Direct,
-        Some((http_client::ProxyMode::Custom, url)) if !url.is_empty() => GitProxyMode::Custom(url),
-        _ => GitProxyMode::System,
-    };
-    set_proxy(proxy_mode);
+/// Initialize git proxy settings and private repo credentials before git operations
+pub(super) async fn init_git_env_from_settings(state: &DbState) {
+    ensure_git_auth_loaded(state).await;
+
+    let proxy_settings = http_client::get_proxy_settings(state)
+        .await
+        .unwrap_or_default();
+    set_proxy(proxy_settings);
 }
@@ -0,0 +1,453 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::types::SkillLintIssueDto;
+
+const MAX_NAME_LEN: usize = 64;
+const MAX_DESCRIPTION_LEN: usize = 1024;
+/// Bundled files above this are loaded into context slowly or not at all
+const MAX_FILE_BYTES: u64 = 1024 * 1024;
+const MAX_SKILL_BYTES: u64 = 10 * 1024 * 1024;
+/// Leaves room for the tool skills dir prefix under the 260 char Windows MAX_PATH
+const MAX_RELATIVE_PATH_LEN: usize = 160;
+
+const WINDOWS_RESERVED_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+#[derive(Debug, PartialEq)]
+enum FrontmatterValue {
+    Scalar(String),
+    List(Vec<String>),
+}
+
+fn issue(severity: &str, code: &str, message: String, path: Option<&str>) -> SkillLintIssueDto {
+    SkillLintIssueDto {
+        severity: severity.to_string(),
+        code: code.to_string(),
+        message,
+        path: path.map(str::to_string),
+    }
+}
+
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    let quoted = value.len() >= 2
+        && ((value.starts_with('"') && value.ends_with('"'))
+            || (value.starts_with('\'') && value.ends_with('\'')));
+    if quoted {
+        value[1..value.len() - 1].to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Parse the top-level keys of a SKILL.md frontmatter block
+///
+/// Handles the subset skills use in practice: `key: value`, quoted values, `[a, b]` and
+/// `- item` lists, and `|` / `>` block scalars. Returns None when there is no frontmatter,
+/// Err when the block is never closed.
+fn parse_frontmatter(text: &str) -> Option<Result<HashMap<String, FrontmatterValue>, ()>> {
+    let mut lines = text.lines();
+    if lines.next()?.trim() != "---" {
+        return None;
+    }
+
+    let mut entries: Vec<(String, String, Vec<String>)> = Vec::new();
+    let mut closed = false;
+    for line in lines {
+        if line.trim() == "---" {
+            closed = true;
+            break;
+        }
+        let indented = line.starts_with(' ') || line.starts_with('\t');
+        match line.split_once(':') {
+            Some((key, value)) if !indented && !key.trim().is_empty() && !key.contains(' ') => {
+                entries.push((key.trim().to_string(), value.trim().to_string(), Vec::new()));
+            }
+            _ => {
+                if let Some((_, _, continuation)) = entries.last_mut() {
+                    if !line.trim().is_empty() {
+                        continuation.push(line.trim().to_string());
+                    }
+                }
+            }
+        }
+    }
+    if !closed {
+        return Some(Err(()));
+    }
+
+    let map = entries
+        .into_iter()
+        .map(|(key, inline, continuation)| {
+            let value = if let Some(inner) = inline
+                .strip_prefix('[')
+                .and_then(|rest| rest.strip_suffix(']'))
+            {
+                FrontmatterValue::List(
+                    inner
+                        .split(',')
+                        .map(unquote)
+                        .filter(|item| !item.is_empty())
+                        .collect(),
+                )
+            } else if inline.is_empty()
+                && !continuation.is_empty()
+                && continuation.iter().all(|l| l.starts_with('-'))
+            {
+                FrontmatterValue::List(
+                    continuation
+                        .iter()
+                        .map(|l| unquote(l.trim_start_matches('-')))
+                        .collect(),
+                )
+            } else if inline.is_empty() || inline.starts_with('|') || inline.starts_with('>') {
+                FrontmatterValue::Scalar(continuation.join(" "))
+            } else {
+                let mut parts = vec![unquote(&inline)];
+                parts.extend(continuation);
+                FrontmatterValue::Scalar(parts.join(" "))
+            };
+            (key, value)
+        })
+        .collect();
+    Some(Ok(map))
+}
+
+/// Split `Read, Bash(git add:*), Grep` on top-level commas
+fn split_tool_list(value: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut depth = 0i32;
+    let mut current = String::new();
+    for c in value.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                items.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    items.push(current.trim().to_string());
+    items
+}
+
+/// `Tool` or `Tool(specifier)`; tool names may contain `_`, `-` and `:` (e.g. MCP tools)
+fn is_valid_tool_entry(entry: &str) -> bool {
+    let (name, specifier) = match entry.split_once('(') {
+        Some((name, rest)) => match rest.strip_suffix(')') {
+            Some(inner) if !inner.trim().is_empty() && !inner.contains('(') => (name, Some(inner)),
+            _ => return false,
+        },
+        None => (entry, None),
+    };
+    let name_ok = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | ':' | '*'));
+    name_ok && specifier.is_none_or(|s| !s.contains(')'))
+}
+
+fn lint_frontmatter(dir: &Path, text: &str, issues: &mut Vec<SkillLintIssueDto>) {
+    let path = Some("SKILL.md");
+    let frontmatter = match parse_frontmatter(text) {
+        None => {
+            issues.push(issue(
+                "error",
+                "missing_frontmatter",
+                "SKILL.md must start with a `---` frontmatter block".to_string(),
+                path,
+            ));
+            return;
+        }
+        Some(Err(())) => {
+            issues.push(issue(
+                "error",
+                "unterminated_frontmatter",
+                "SKILL.md frontmatter is missing its closing `---`".to_string(),
+                path,
+            ));
+            return;
+        }
+        Some(Ok(frontmatter)) => frontmatter,
+    };
+
+    match frontmatter.get("name") {
+        Some(FrontmatterValue::Scalar(name)) if !name.trim().is_empty() => {
+            let name = name.trim();
+            if name.chars().count() > MAX_NAME_LEN {
+                issues.push(issue(
+                    "error",
+                    "name_too_long",
+                    format!("name is longer than {} characters", MAX_NAME_LEN),
+                    path,
+                ));
+            }
+            if !name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            {
+                issues.push(issue(
+                    "error",
+                    "invalid_name",
+                    format!(
+                        "name {:?} may only contain lowercase letters, digits and hyphens",
+                        name
+                    ),
+                    path,
+                ));
+            }
+            let dir_name = dir
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if !dir_name.is_empty() && dir_name != name {
+                issues.push(issue(
+                    "warning",
+                    "name_mismatch",
+                    format!(
+                        "name {:?} differs from the directory name {:?}",
+                        name, dir_name
+                    ),
+                    path,
+                ));
+            }
+        }
+        _ => issues.push(issue(
+            "error",
+            "missing_name",
+            "frontmatter has no `name`".to_string(),
+            path,
+        )),
+    }
+
+    match frontmatter.get("description") {
+        Some(FrontmatterValue::Scalar(desc)) if !desc.trim().is_empty() => {
+            if desc.trim().chars().count() > MAX_DESCRIPTION_LEN {
+                issues.push(issue(
+                    "error",
+                    "description_too_long",
+                    format!(
+                        "description is longer than {} characters",
+                        MAX_DESCRIPTION_LEN
+                    ),
+                    path,
+                ));
+            }
+        }
+        _ => issues.push(issue(
+            "error",
+            "missing_description",
+            "frontmatter has no `description`; tools use it to decide when to load the skill"
+                .to_string(),
+            path,
+        )),
+    }
+
+    if let Some(value) = frontmatter.get("allowed-tools") {
+        let entries = match value {
+            FrontmatterValue::Scalar(value) => split_tool_list(value),
+            FrontmatterValue::List(items) => items.clone(),
+        };
+        for entry in entries {
+            if !is_valid_tool_entry(&entry) {
+                issues.push(issue(
+                    "error",
+                    "invalid_allowed_tools",
+                    format!(
+                        "allowed-tools entry {:?} is not `Tool` or `Tool(pattern)`",
+                        entry
+                    ),
+                    path,
+                ));
+            }
+        }
+    }
+}
+
+/// Problems a file or dir name would cause on Windows
+fn windows_name_issue(name: &str) -> Option<(&'static str, String)> {
+    if let Some(c) = name
+        .chars()
+        .find(|c| matches!(c, '<' | '>' | ':' | '"' | '|' | '?' | '*' | '\\') || c.is_control())
+    {
+        return Some((
+            "windows_invalid_char",
+            format!("{:?} contains {:?}, which Windows does not allow", name, c),
+        ));
+    }
+    if name.ends_with('.') || name.ends_with(' ') {
+        return Some((
+            "windows_trailing_char",
+            format!("{:?} ends with a dot or space, which Windows strips", name),
+        ));
+    }
+    let stem = name.split('.').next().unwrap_or(name).trim_end();
+    if WINDOWS_RESERVED_NAMES
+        .iter()
+        .any(|reserved| stem.eq_ignore_ascii_case(reserved))
+    {
+        return Some((
+            "windows_reserved_name",
+            format!("{:?} is a reserved device name on Windows", name),
+        ));
+    }
+    None
+}
+
+fn lint_files(dir: &Path, issues: &mut Vec<SkillLintIssueDto>) {
+    let mut total: u64 = 0;
+    let mut seen_lowercase: HashMap<String, String> = HashMap::new();
+
+    let walker = walkdir::WalkDir::new(dir)
+        .min_depth(1)
+        .follow_links(false)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| e.file_name() != ".git");
+    for entry in walker.flatten() {
+        let Ok(relative) = entry.path().strip_prefix(dir) else {
+            continue;
+        };
+        let rel = relative.to_string_lossy().replace('\\', "/");
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        if let Some((code, message)) = windows_name_issue(&name) {
+            issues.push(issue("warning", code, message, Some(&rel)));
+        }
+        if rel.chars().count() > MAX_RELATIVE_PATH_LEN {
+            issues.push(issue(
+                "warning",
+                "path_too_long",
+                format!(
+                    "path is longer than {} characters and may exceed MAX_PATH on Windows",
+                    MAX_RELATIVE_PATH_LEN
+                ),
+                Some(&rel),
+            ));
+        }
+        if let Some(other) = seen_lowercase.insert(rel.to_lowercase(), rel.clone()) {
+            issues.push(issue(
+                "warning",
+                "case_conflict",
+                format!(
+                    "{:?} and {:?} differ only in case and collide on Windows/macOS",
+                    other, rel
+                ),
+                Some(&rel),
+            ));
+        }
+
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        if meta.is_file() {
+            total += meta.len();
+            if meta.len() > MAX_FILE_BYTES {
+                issues.push(issue(
+                    "warning",
+                    "large_file",
+                    format!(
+                        "file is {} KB (limit {} KB)",
+                        meta.len() / 1024,
+                        MAX_FILE_BYTES / 1024
+                    ),
+                    Some(&rel),
+                ));
+            }
+        }
+    }
+
+    if total > MAX_SKILL_BYTES {
+        issues.push(issue(
+            "warning",
+            "large_skill",
+            format!(
+                "skill bundles {} MB of files (limit {} MB)",
+                total / 1024 / 1024,
+                MAX_SKILL_BYTES / 1024 / 1024
+            ),
+            None,
+        ));
+    }
+}
+
+/// Validate a skill dir: SKILL.md frontmatter, bundled file sizes and Windows path names
+pub fn lint_skill_dir(dir: &Path) -> Vec<SkillLintIssueDto> {
+    let mut issues = Vec::new();
+    match std::fs::read_to_string(dir.join("SKILL.md")) {
+        Ok(text) => lint_frontmatter(dir, &text, &mut issues),
+        Err(_) => issues.push(issue(
+            "error",
+            "missing_skill_md",
+            "skill has no readable SKILL.md".to_string(),
+            None,
+        )),
+    }
+    lint_files(dir, &mut issues);
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_scalars_lists_and_block_values() {
+        let text = "---\nname: \"my-skill\"\ndescription: >\n  Does things\n  well\nallowed-tools:\n  - Read\n  - Bash(git:*)\ntags: [a, 'b']\n---\nbody";
+        let fm = parse_frontmatter(text).unwrap().unwrap();
+        assert_eq!(
+            fm.get("name"),
+            Some(&FrontmatterValue::Scalar("my-skill".to_string()))
+        );
+        assert_eq!(
+            fm.get("description"),
+            Some(&FrontmatterValue::Scalar("Does things well".to_string()))
+        );
+        assert_eq!(
+            fm.get("allowed-tools"),
+            Some(&FrontmatterValue::List(vec![
+                "Read".to_string(),
+                "Bash(git:*)".to_string()
+            ]))
+        );
+        assert_eq!(
+            fm.get("tags"),
+            Some(&FrontmatterValue::List(vec![
+                "a".to_string(),
+                "b".to_string()
+            ]))
+        );
+        assert!(parse_frontmatter("---\nname: x\n").unwrap().is_err());
+        assert!(parse_frontmatter("# no frontmatter").is_none());
+    }
+
+    #[test]
+    fn validates_allowed_tools_and_windows_names() {
+        assert_eq!(
+            split_tool_list("Read, Bash(git add:*), mcp__github__search"),
+            vec!["Read", "Bash(git add:*)", "mcp__github__search"]
+        );
+        assert!(is_valid_tool_entry("Bash(npm run test:*)"));
+        assert!(!is_valid_tool_entry("Bash(git"));
+        assert!(!is_valid_tool_entry("Bash()"));
+        assert!(!is_valid_tool_entry("1Read"));
+
+        assert!(windows_name_issue("notes.md").is_none());
+        assert_eq!(
+            windows_name_issue("aux.txt").unwrap().0,
+            "windows_reserved_name"
+        );
+        assert_eq!(windows_name_issue("a:b").unwrap().0, "windows_invalid_char");
+        assert_eq!(
+            windows_name_issue("draft.").unwrap().0,
+            "windows_trailing_char"
+        );
+    }
+}
@@ -2,12 +2,19 @@
 // Unified management for AI coding tool skills
 
 pub mod adapter;
+pub mod bundle;
 pub mod cache_cleanup;
+pub mod central_history;
 pub mod central_repo;
+pub mod central_watcher;
 pub mod commands;
 pub mod content_hash;
+pub mod credentials;
 pub mod git_fetcher;
+pub mod git_pool;
 pub mod installer;
+pub mod lint;
+pub mod name_conflict;
 pub mod onboarding;
 pub mod path_executor;
 pub mod skill_store;
@@ -15,6 +22,8 @@ pub mod sync_engine;
 pub mod tool_adapters;
 pub mod tray_support;
 pub mod types;
+pub mod update_check;
+pub mod usage;
 
 pub use commands::*;
 pub use types::*;
@@ -0,0 +1,120 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use super::adapter::parse_sync_details;
+use super::central_repo::resolve_central_repo_path;
+use super::path_executor::target_path_changed;
+use super::skill_store;
+use super::tool_adapters::{
+    get_all_tool_adapters, is_tool_installed_async, resolve_runtime_skills_path_async,
+};
+use super::types::{Skill, SkillNameConflictDto, ToolNameConflictDto};
+use crate::DbState;
+
+/// Upper bound for `name-2`, `name-3`, ... suggestions
+const MAX_SUGGESTION_SUFFIX: usize = 50;
+
+/// Managed skill whose recorded target (including disabled ones) is `path`
+pub fn find_target_owner<'a>(skills: &'a [Skill], path: &Path) -> Option<&'a Skill> {
+    skills.iter().find(|skill| {
+        parse_sync_details(skill)
+            .iter()
+            .any(|target| !target_path_changed(&target.target_path, path))
+    })
+}
+
+/// Validate a user supplied skill name used as a directory name
+pub fn validate_skill_dir_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty()
+        || name.starts_with('.')
+        || name
+            .chars()
+            .any(|c| matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|'))
+    {
+        anyhow::bail!("invalid skill name: {:?}", name);
+    }
+    Ok(name.to_string())
+}
+
+/// Check a skill name against managed skills, the central repo and every tool's skills dir
+///
+/// Tool dirs are checked for installed tools (and all custom tools) only. When anything
+/// conflicts, the first free `name-N` is suggested as an alternate install / target name.
+pub async fn check_skill_name_conflict(
+    app: &tauri::AppHandle,
+    state: &DbState,
+    name: &str,
+) -> Result<SkillNameConflictDto> {
+    super::tool_adapters::set_runtime_db(state.db());
+    let name = validate_skill_dir_name(name)?;
+
+    let skills = skill_store::get_managed_skills(state)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+    let central_dir = resolve_central_repo_path(app, state).await?;
+    let custom_tools = skill_store::get_custom_tools(state)
+        .await
+        .unwrap_or_default();
+
+    let mut tool_roots = Vec::new();
+    for adapter in get_all_tool_adapters(&custom_tools) {
+        if !adapter.is_custom && !is_tool_installed_async(&adapter).await.unwrap_or(false) {
+            continue;
+        }
+        if let Ok(root) = resolve_runtime_skills_path_async(&adapter).await {
+            tool_roots.push((adapter.key.clone(), root));
+        }
+    }
+
+    let tool_conflicts = |candidate: &str| -> Vec<ToolNameConflictDto> {
+        tool_roots
+            .iter()
+            .filter_map(|(tool, root)| {
+                let path = root.join(candidate);
+                let owner = find_target_owner(&skills, &path);
+                if owner.is_none() && std::fs::symlink_metadata(&path).is_err() {
+                    return None;
+                }
+                Some(ToolNameConflictDto {
+                    tool: tool.clone(),
+                    target_path: path.to_string_lossy().to_string(),
+                    owner_skill_id: owner.map(|s| s.id.clone()),
+                    owner_skill_name: owner.map(|s| s.name.clone()),
+                })
+            })
+            .collect()
+    };
+    let name_taken = |candidate: &str| {
+        skills
+            .iter()
+            .any(|s| s.name.eq_ignore_ascii_case(candidate))
+            || central_dir.join(candidate).exists()
+    };
+
+    let existing_skill_id = skills
+        .iter()
+        .find(|s| s.name.eq_ignore_ascii_case(&name))
+        .map(|s| s.id.clone());
+    let central_path_exists = central_dir.join(&name).exists();
+    let tools = tool_conflicts(&name);
+    let has_conflict = existing_skill_id.is_some() || central_path_exists || !tools.is_empty();
+
+    let suggested_name = if has_conflict {
+        (2..=MAX_SUGGESTION_SUFFIX)
+            .map(|n| format!("{}-{}", name, n))
+            .find(|candidate| !name_taken(candidate) && tool_conflicts(candidate).is_empty())
+    } else {
+        None
+    };
+
+    Ok(SkillNameConflictDto {
+        name,
+        has_conflict,
+        existing_skill_id,
+        central_path_exists,
+        tools,
+        suggested_name,
+    })
+}
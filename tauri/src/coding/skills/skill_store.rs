@@ -1,15 +1,16 @@
 use serde_json::Value;
 
-use crate::coding::db_id::{db_new_id, db_record_id};
+use crate::coding::db_id::{db_new_id, db_record_id, db_reorder_query};
 use crate::DbState;
 
 use super::adapter::{
-    from_db_skill, from_db_skill_preferences, from_db_skill_repo, get_sync_detail,
-    parse_sync_details, remove_sync_detail, set_sync_detail, to_clean_skill_payload,
-    to_skill_preferences_payload, to_skill_repo_payload,
+    from_db_repo_credential, from_db_skill, from_db_skill_preferences, from_db_skill_repo,
+    get_sync_detail, parse_sync_details, remove_sync_detail, set_sync_detail,
+    to_clean_skill_payload, to_repo_credential_payload, to_skill_preferences_payload,
+    to_skill_repo_payload,
 };
 use super::tool_adapters::CustomTool;
-use super::types::{now_ms, Skill, SkillPreferences, SkillRepo, SkillTarget};
+use super::types::{now_ms, Skill, SkillPreferences, SkillRepo, SkillRepoCredential, SkillTarget};
 
 // ==================== Skill CRUD ====================
 
@@ -99,6 +100,64 @@ pub async fn get_skill_by_name(state: &DbState, name: &str) -> Result<Option<Ski
     Ok(records.first().map(|r| from_db_skill(r.clone())))
 }
 
+/// Update only a skill's content hash (keeps updated_at so sort order is untouched)
+pub async fn update_skill_content_hash(
+    state: &DbState,
+    skill_id: &str,
+    content_hash: Option<String>,
+) -> Result<(), String> {
+    let db = state.db();
+    let record_id = db_record_id("skill", skill_id);
+
+    db.query(&format!(
+        "UPDATE {} SET content_hash = $content_hash",
+        record_id
+    ))
+    .bind(("content_hash", content_hash))
+    .await
+    .map_err(|e| format!("Failed to update skill content hash: {}", e))?;
+
+    Ok(())
+}
+
+/// Trim tags, drop empty ones and case-insensitive duplicates (first spelling wins)
+pub fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.trim();
+        if !tag.is_empty() && !out.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            out.push(tag.to_string());
+        }
+    }
+    out
+}
+
+/// Update a skill's user-editable metadata (keeps updated_at so sort order is untouched)
+pub async fn update_skill_metadata(
+    state: &DbState,
+    skill_id: &str,
+    description: Option<String>,
+    tags: Vec<String>,
+    notes: Option<String>,
+) -> Result<(), String> {
+    let db = state.db();
+    let record_id = db_record_id("skill", skill_id);
+    let blank_to_none =
+        |v: Option<String>| v.map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+
+    db.query(&format!(
+        "UPDATE {} SET description = $description, tags = $tags, notes = $notes",
+        record_id
+    ))
+    .bind(("description", blank_to_none(description)))
+    .bind(("tags", normalize_tags(tags)))
+    .bind(("notes", blank_to_none(notes)))
+    .await
+    .map_err(|e| format!("Failed to update skill metadata: {}", e))?;
+
+    Ok(())
+}
+
 /// Delete a skill
 pub async fn delete_skill(state: &DbState, skill_id: &str) -> Result<(), String> {
     let db = state.db();
@@ -111,6 +170,20 @@ pub async fn delete_skill(state: &DbState, skill_id: &str) -> Result<(), String>
     Ok(())
 }
 
+/// Delete a skill by its full record id (`skill:⟨id⟩`), for records whose clean id is
+/// shared with another record
+pub async fn delete_skill_record(state: &DbState, raw_id: &str) -> Result<(), String> {
+    let db = state.db();
+
+    db.query("DELETE skill WHERE type::string(id) = $raw_id")
+        .bind(("raw_id", raw_id.to_string()))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to delete skill: {}", e))?;
+
+    Ok(())
+}
+
 // ==================== Skill sync_details operations ====================
 
 /// Get all targets for a specific skill (parsed from sync_details)
@@ -159,19 +232,32 @@ pub async fn upsert_skill_target(
     // Update sync_details
     let new_sync_details = set_sync_detail(&skill.sync_details, &target.tool, target);
 
-    // Update enabled_tools
+    // Update enabled_tools (disabled targets stay recorded but are not enabled)
     let mut enabled_tools = skill.enabled_tools.clone();
-    if !enabled_tools.contains(&target.tool) {
+    if target.is_disabled() {
+        enabled_tools.retain(|t| t != &target.tool);
+    } else if !enabled_tools.contains(&target.tool) {
         enabled_tools.push(target.tool.clone());
     }
 
+    // Remember the latest successful (re)sync of any target
+    let last_sync_at = match target.synced_at {
+        Some(synced_at) if target.status == "ok" => Some(
+            skill
+                .last_sync_at
+                .map_or(synced_at, |prev| prev.max(synced_at)),
+        ),
+        _ => skill.last_sync_at,
+    };
+
     // Save updates (don't update updated_at to preserve sort order)
     db.query(&format!(
-        "UPDATE {} SET sync_details = $sync_details, enabled_tools = $enabled_tools",
+        "UPDATE {} SET sync_details = $sync_details, enabled_tools = $enabled_tools, last_sync_at = $last_sync_at",
         record_id
     ))
     .bind(("sync_details", new_sync_details))
     .bind(("enabled_tools", enabled_tools))
+    .bind(("last_sync_at", last_sync_at))
     .await
     .map_err(|e| format!("Failed to update skill target: {}", e))?;
 
@@ -270,6 +356,50 @@ pub async fn delete_skill_repo(state: &DbState, owner: &str, name: &str) -> Resu
     Ok(())
 }
 
+// ==================== SkillRepoCredential CRUD ====================
+
+/// Get all private repo credential configs
+pub async fn get_repo_credentials(state: &DbState) -> Result<Vec<SkillRepoCredential>, String> {
+    let db = state.db();
+
+    let mut result = db
+        .query("SELECT *, type::string(id) as id FROM skill_repo_credential")
+        .await
+        .map_err(|e| format!("Failed to query repo credentials: {}", e))?;
+
+    let records: Vec<Value> = result.take(0).map_err(|e| e.to_string())?;
+    Ok(records.into_iter().map(from_db_repo_credential).collect())
+}
+
+/// Save a private repo credential config (keyed by repo key)
+pub async fn save_repo_credential(
+    state: &DbState,
+    credential: &SkillRepoCredential,
+) -> Result<(), String> {
+    let db = state.db();
+    let payload = to_repo_credential_payload(credential);
+    let record_id = db_record_id("skill_repo_credential", &credential.id);
+
+    db.query(&format!("UPSERT {} CONTENT $data", record_id))
+        .bind(("data", payload))
+        .await
+        .map_err(|e| format!("Failed to save repo credential: {}", e))?;
+
+    Ok(())
+}
+
+/// Delete a private repo credential config
+pub async fn delete_repo_credential(state: &DbState, repo_key: &str) -> Result<(), String> {
+    let db = state.db();
+    let record_id = db_record_id("skill_repo_credential", repo_key);
+
+    db.query(&format!("DELETE {}", record_id))
+        .await
+        .map_err(|e| format!("Failed to delete repo credential: {}", e))?;
+
+    Ok(())
+}
+
 // ==================== SkillPreferences CRUD ====================
 
 /// Get skill preferences (singleton record)
@@ -323,6 +453,7 @@ pub async fn get_setting(state: &DbState, key: &str) -> Result<Option<String>, S
         "git_cache_cleanup_days" => Some(prefs.git_cache_cleanup_days.to_string()),
         "git_cache_ttl_secs" => Some(prefs.git_cache_ttl_secs.to_string()),
         "show_skills_in_tray" => Some(prefs.show_skills_in_tray.to_string()),
+        "auto_resync_copy_targets" => Some(prefs.auto_resync_copy_targets.to_string()),
         _ => None,
     };
 
@@ -351,6 +482,9 @@ pub async fn set_setting(state: &DbState, key: &str, value: &str) -> Result<(),
         "show_skills_in_tray" => {
             prefs.show_skills_in_tray = value == "true";
         }
+        "auto_resync_copy_targets" => {
+            prefs.auto_resync_copy_targets = value == "true";
+        }
         _ => return Err(format!("Unknown setting key: {}", key)),
     };
 
@@ -377,15 +511,12 @@ pub async fn list_all_skill_target_paths(state: &DbState) -> Result<Vec<(String,
 
 /// Reorder skills by updating sort_index for each skill
 pub async fn reorder_skills(state: &DbState, ids: &[String]) -> Result<(), String> {
-    let db = state.db();
-
-    for (index, id) in ids.iter().enumerate() {
-        let record_id = db_record_id("skill", id);
-        db.query(&format!("UPDATE {} SET sort_index = $index", record_id))
-            .bind(("index", index as i32))
-            .await
-            .map_err(|e| format!("Failed to reorder skills: {}", e))?;
-    }
+    state
+        .db()
+        .query(db_reorder_query("skill", ids, false))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to reorder skills: {}", e))?;
 
     Ok(())
 }
@@ -8,8 +8,36 @@ use std::sync::OnceLock;
 
 use anyhow::{Context, Result};
 
+use super::types::SyncMode;
 use crate::coding::tools::{self, BUILTIN_TOOLS};
 
+/// Built-in tools that don't pick up symlinked skill directories, so skills are copied
+const COPY_MODE_TOOLS: &[&str] = &["cursor", "amp"];
+
+/// Well-known install locations of CLI agents that may be installed before they
+/// create their config directory; `PATH` is searched as well
+const TOOL_BINARIES: &[(&str, &str, &[&str])] = &[
+    (
+        "amp",
+        "amp",
+        &[
+            "~/.amp/bin/amp",
+            "~/.local/bin/amp",
+            "/opt/homebrew/bin/amp",
+            "/usr/local/bin/amp",
+        ],
+    ),
+    (
+        "goose",
+        "goose",
+        &[
+            "~/.local/bin/goose",
+            "/opt/homebrew/bin/goose",
+            "/usr/local/bin/goose",
+        ],
+    ),
+];
+
 /// Legacy CustomTool type for backward compatibility with Skills
 /// This type has required fields while the new tools::CustomTool has optional fields
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -62,6 +90,39 @@ pub struct ToolAdapter {
     pub display_name: &'static str,
     pub relative_skills_dir: &'static str,
     pub relative_detect_dir: &'static str,
+    pub preferred_sync_mode: SyncMode,
+}
+
+/// How skills are synced to a built-in tool unless a target asks for copy mode
+pub fn preferred_sync_mode(key: &str) -> SyncMode {
+    if COPY_MODE_TOOLS.contains(&key) {
+        SyncMode::Copy
+    } else {
+        SyncMode::Auto
+    }
+}
+
+/// Find the executable of a CLI agent in its install locations or on `PATH`
+pub fn find_tool_binary(key: &str) -> Option<PathBuf> {
+    let (_, binary, locations) = TOOL_BINARIES.iter().find(|(k, _, _)| *k == key)?;
+
+    let installed = locations
+        .iter()
+        .filter_map(|location| tools::path_utils::resolve_storage_path(location))
+        .find(|path| path.is_file());
+    if installed.is_some() {
+        return installed;
+    }
+
+    let names: Vec<String> = if cfg!(target_os = "windows") {
+        vec![format!("{}.exe", binary), format!("{}.cmd", binary)]
+    } else {
+        vec![binary.to_string()]
+    };
+    let path_var = std::env::var_os("PATH")?;
+    std::env::split_paths(&path_var)
+        .flat_map(|dir| names.iter().map(move |name| dir.join(name)))
+        .find(|path| path.is_file())
 }
 
 /// Get all default tool adapters (built-in tools that support Skills)
@@ -75,6 +136,7 @@ pub fn default_tool_adapters() -> Vec<ToolAdapter> {
                 display_name: tool.display_name,
                 relative_skills_dir: tool.relative_skills_dir?,
                 relative_detect_dir: tool.relative_detect_dir?,
+                preferred_sync_mode: preferred_sync_mode(tool.key),
             })
         })
         .collect()
@@ -121,7 +183,7 @@ impl From<&ToolAdapter> for RuntimeToolAdapter {
             relative_skills_dir: adapter.relative_skills_dir.to_string(),
             relative_detect_dir: adapter.relative_detect_dir.to_string(),
             is_custom: false,
-            force_copy: false, // Built-in tools use default (cursor handled specially in sync logic)
+            force_copy: adapter.preferred_sync_mode == SyncMode::Copy,
         }
     }
 }
@@ -189,10 +251,11 @@ pub fn is_tool_installed(adapter: &RuntimeToolAdapter) -> Result<bool> {
     // Use shared detection logic for built-in tools
     if let Some(builtin) = tools::builtin_tool_by_key(&adapter.key) {
         let runtime_tool = tools::RuntimeTool::from(builtin);
-        if let Some(db) = runtime_db() {
-            return Ok(tools::is_tool_installed_with_db(db, &runtime_tool));
-        }
-        return Ok(tools::is_tool_installed(&runtime_tool));
+        let installed = match runtime_db() {
+            Some(db) => tools::is_tool_installed_with_db(db, &runtime_tool),
+            None => tools::is_tool_installed(&runtime_tool),
+        };
+        return Ok(installed || find_tool_binary(&adapter.key).is_some());
     }
     // Fallback
     Ok(false)
@@ -205,10 +268,11 @@ pub async fn is_tool_installed_async(adapter: &RuntimeToolAdapter) -> Result<boo
 
     if let Some(builtin) = tools::builtin_tool_by_key(&adapter.key) {
         let runtime_tool = tools::RuntimeTool::from(builtin);
-        if let Some(db) = runtime_db() {
-            return Ok(tools::is_tool_installed_with_db_async(db, &runtime_tool).await);
-        }
-        return Ok(tools::is_tool_installed(&runtime_tool));
+        let installed = match runtime_db() {
+            Some(db) => tools::is_tool_installed_with_db_async(db, &runtime_tool).await,
+            None => tools::is_tool_installed(&runtime_tool),
+        };
+        return Ok(installed || find_tool_binary(&adapter.key).is_some());
     }
 
     Ok(false)
@@ -321,7 +385,10 @@ fn detect_link(path: &Path) -> (bool, Option<PathBuf>) {
 mod tests {
     use std::collections::HashSet;
 
-    use super::{adapter_by_key, default_tool_adapters, runtime_adapter_by_key, CustomTool};
+    use super::{
+        adapter_by_key, default_tool_adapters, runtime_adapter_by_key, CustomTool,
+        RuntimeToolAdapter, SyncMode,
+    };
     use crate::coding::tools::BUILTIN_TOOLS;
 
     #[test]
@@ -351,6 +418,28 @@ mod tests {
         assert_eq!(qoder_work.relative_skills_dir, "~/.qoderwork/skills");
     }
 
+    #[test]
+    fn adapter_by_key_returns_github_copilot_cli() {
+        let cli = adapter_by_key("github_copilot_cli")
+            .expect("github_copilot_cli should be available in skills adapters");
+        assert_eq!(cli.display_name, "GitHub Copilot CLI");
+        assert_eq!(cli.relative_skills_dir, "~/.copilot/skills");
+        assert_eq!(cli.relative_detect_dir, "~/.copilot");
+    }
+
+    #[test]
+    fn amp_and_goose_adapters_use_their_sync_modes() {
+        let amp = adapter_by_key("amp").expect("amp should be available in skills adapters");
+        assert_eq!(amp.relative_skills_dir, "~/.config/agents/skills");
+        assert_eq!(amp.relative_detect_dir, "~/.config/amp");
+        assert!(RuntimeToolAdapter::from(&amp).force_copy);
+
+        let goose = adapter_by_key("goose").expect("goose should be available in skills adapters");
+        assert_eq!(goose.relative_skills_dir, "~/.config/goose/skills");
+        assert_eq!(goose.preferred_sync_mode, SyncMode::Auto);
+        assert!(!RuntimeToolAdapter::from(&goose).force_copy);
+    }
+
     #[test]
     fn runtime_adapter_by_key_prefers_builtin_tool_without_custom_entry() {
         let custom_tools: Vec<CustomTool> = Vec::new();
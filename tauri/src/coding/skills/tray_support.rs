@@ -110,8 +110,11 @@ pub async fn get_skills_tray_data<R: Runtime>(app: &AppHandle<R>) -> Result<Tray
 
     for skill in skills {
         let targets = parse_sync_details(&skill);
-        let synced_tools: std::collections::HashSet<String> =
-            targets.iter().map(|target| target.tool.clone()).collect();
+        let synced_tools: std::collections::HashSet<String> = targets
+            .iter()
+            .filter(|target| !target.is_disabled())
+            .map(|target| target.tool.clone())
+            .collect();
 
         let mut tool_items: Vec<TraySkillToolItem> = Vec::new();
         for tool_key in &tools_to_show {
@@ -201,6 +204,7 @@ pub async fn apply_skills_tool_toggle<R: Runtime>(
             status: "ok".to_string(),
             error_message: None,
             synced_at: Some(now_ms()),
+            target_name: None,
         };
         skill_store::upsert_skill_target(&state, skill_id, &record).await?;
     }
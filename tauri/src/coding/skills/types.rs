@@ -12,6 +12,8 @@ pub struct Skill {
     pub source_type: String, // "local" | "git" | "import"
     pub source_ref: Option<String>,
     pub source_revision: Option<String>,
+    // Tag or commit SHA a git skill is pinned to; None follows the branch
+    pub pinned_ref: Option<String>,
     pub central_path: String,
     pub content_hash: Option<String>,
     pub created_at: i64,
@@ -19,6 +21,11 @@ pub struct Skill {
     pub last_sync_at: Option<i64>,
     pub status: String,
 
+    // User-editable metadata
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub notes: Option<String>,
+
     // Sort order for drag-and-drop reordering
     pub sort_index: i32,
 
@@ -35,10 +42,19 @@ pub struct Skill {
 pub struct SkillTarget {
     pub tool: String,
     pub target_path: String,
-    pub mode: String, // "symlink" | "copy" | "junction"
-    pub status: String,
+    pub mode: String,   // "symlink" | "copy" | "junction"
+    pub status: String, // "ok" | "error" | "pending" | "disabled"
     pub synced_at: Option<i64>,
     pub error_message: Option<String>,
+    // Directory name in the tool dir when synced under a name other than the skill's
+    pub target_name: Option<String>,
+}
+
+impl SkillTarget {
+    /// Disabled targets keep their recorded path/mode but have nothing on disk
+    pub fn is_disabled(&self) -> bool {
+        self.status == "disabled"
+    }
 }
 
 /// Skill repository source - user configured skill source repos
@@ -52,6 +68,16 @@ pub struct SkillRepo {
     pub created_at: i64,
 }
 
+/// Credential config for a private skill repo; the secret itself lives in the OS keychain
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SkillRepoCredential {
+    pub id: String,               // repo key, e.g. "github.com/owner/repo"
+    pub auth_type: String,        // "token" | "ssh_key"
+    pub username: Option<String>, // token auth only; defaults to "x-access-token"
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
 /// Skill preferences - user preference settings (structured wide table)
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SkillPreferences {
@@ -63,6 +89,7 @@ pub struct SkillPreferences {
     pub known_tool_versions: Option<Value>,
     pub installed_tools: Option<Vec<String>>, // Detected installed tools
     pub show_skills_in_tray: bool,            // Show skills in system tray quick menu
+    pub auto_resync_copy_targets: bool,       // Watch central repo and re-copy copy-mode targets
     pub updated_at: i64,
 }
 
@@ -79,6 +106,7 @@ impl Default for SkillPreferences {
             known_tool_versions: None,
             installed_tools: None,
             show_skills_in_tray: false,
+            auto_resync_copy_targets: false,
             updated_at: 0,
         }
     }
@@ -117,11 +145,16 @@ pub struct ManagedSkillDto {
     pub name: String,
     pub source_type: String,
     pub source_ref: Option<String>,
+    pub source_revision: Option<String>,
+    pub pinned_ref: Option<String>,
     pub central_path: String,
     pub created_at: i64,
     pub updated_at: i64,
     pub last_sync_at: Option<i64>,
     pub status: String,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub notes: Option<String>,
     pub sort_index: i32,
     pub enabled_tools: Vec<String>,
     pub targets: Vec<SkillTargetDto>, // Derived from sync_details
@@ -134,6 +167,7 @@ pub struct SkillTargetDto {
     pub status: String,
     pub target_path: String,
     pub synced_at: Option<i64>,
+    pub target_name: Option<String>,
 }
 
 /// DTO for install result
@@ -145,6 +179,21 @@ pub struct InstallResultDto {
     pub content_hash: Option<String>,
 }
 
+/// DTO for one item of a batch Git selection install
+#[derive(Debug, Serialize)]
+pub struct SelectionInstallResultDto {
+    pub subpath: String,
+    pub result: Option<InstallResultDto>,
+    pub error: Option<String>,
+}
+
+/// DTO for bundle import result
+#[derive(Debug, Serialize)]
+pub struct BundleImportResultDto {
+    pub imported: Vec<InstallResultDto>,
+    pub skipped: Vec<String>,
+}
+
 /// DTO for sync result
 #[derive(Debug, Serialize)]
 pub struct SyncResultDto {
@@ -159,9 +208,151 @@ pub struct UpdateResultDto {
     pub name: String,
     pub content_hash: Option<String>,
     pub source_revision: Option<String>,
+    pub pinned_ref: Option<String>,
     pub updated_targets: Vec<String>,
 }
 
+/// DTO for a git skill's remote update check
+#[derive(Debug, Serialize)]
+pub struct SkillUpdateCheckDto {
+    pub skill_id: String,
+    pub name: String,
+    pub current_revision: Option<String>,
+    pub remote_revision: Option<String>,
+    pub pinned_ref: Option<String>,
+    pub has_update: bool,
+    pub error: Option<String>,
+}
+
+/// DTO for a skill that failed in a batch update
+#[derive(Debug, Serialize)]
+pub struct SkillUpdateErrorDto {
+    pub skill_id: String,
+    pub error: String,
+}
+
+/// DTO for batch update result
+#[derive(Debug, Serialize)]
+pub struct BatchUpdateResultDto {
+    pub updated: Vec<UpdateResultDto>,
+    pub failed: Vec<SkillUpdateErrorDto>,
+}
+
+/// DTO for drift state of one copy-mode target
+#[derive(Debug, Serialize)]
+pub struct TargetDriftDto {
+    pub tool: String,
+    pub target_path: String,
+    pub status: String, // "in_sync" | "drifted" | "missing" | "error"
+    pub modified_files: Vec<String>,
+    pub missing_files: Vec<String>,
+    pub extra_files: Vec<String>,
+    pub error: Option<String>,
+}
+
+/// DTO for drift report of a skill's copy-mode targets
+#[derive(Debug, Serialize)]
+pub struct SkillDriftDto {
+    pub skill_id: String,
+    pub name: String,
+    pub targets: Vec<TargetDriftDto>,
+}
+
+/// DTO for one problem found by `skills_lint`
+#[derive(Debug, Serialize)]
+pub struct SkillLintIssueDto {
+    pub severity: String, // "error" | "warning"
+    pub code: String,
+    pub message: String,
+    pub path: Option<String>, // Relative to the skill dir; None for skill-wide issues
+}
+
+/// DTO for lint report of a skill
+#[derive(Debug, Serialize)]
+pub struct SkillLintReportDto {
+    pub skill_id: String,
+    pub name: String,
+    pub error_count: usize,
+    pub warning_count: usize,
+    pub issues: Vec<SkillLintIssueDto>,
+}
+
+/// DTO for one repo dir in the skills git cache
+#[derive(Debug, Serialize)]
+pub struct GitCacheEntryDto {
+    pub key: String,
+    pub repo_url: Option<String>, // None for caches written before this was recorded
+    pub ref_name: Option<String>, // branch, or "pin:<ref>" for pinned caches
+    pub size_bytes: u64,
+    pub last_fetched_at: Option<i64>,
+    pub last_used_at: i64,
+}
+
+/// DTO for skills git cache disk usage
+#[derive(Debug, Serialize)]
+pub struct GitCacheStatsDto {
+    pub path: String,
+    pub total_bytes: u64,
+    pub max_size_mb: i64, // 0 = unlimited
+    pub entries: Vec<GitCacheEntryDto>,
+}
+
+/// DTO for one central repo commit touching a skill
+#[derive(Debug, Serialize)]
+pub struct SkillHistoryEntryDto {
+    pub commit: String,
+    pub short_commit: String,
+    pub message: String,
+    pub committed_at: i64,
+}
+
+/// DTO for usage of one skill target
+#[derive(Debug, Serialize)]
+pub struct TargetUsageDto {
+    pub tool: String,
+    pub target_path: String,
+    pub mode: String,
+    pub status: String,
+    pub synced_at: Option<i64>,
+    pub sync_count: u32,
+    // Last access time of the target's SKILL.md / dir, None when atime is unavailable
+    pub last_read_at: Option<i64>,
+    pub read_since_sync: bool,
+    // Symlink/junction targets share the central files, so the read time is not tool-specific
+    pub shared_with_central: bool,
+}
+
+/// DTO for usage insight of a skill
+#[derive(Debug, Serialize)]
+pub struct SkillUsageDto {
+    pub skill_id: String,
+    pub name: String,
+    pub last_sync_at: Option<i64>,
+    pub last_read_at: Option<i64>,
+    pub targets: Vec<TargetUsageDto>,
+}
+
+/// A tool directory entry that already uses a skill name
+#[derive(Debug, Serialize)]
+pub struct ToolNameConflictDto {
+    pub tool: String,
+    pub target_path: String,
+    // Managed skill whose target occupies the path; None for unmanaged folders
+    pub owner_skill_id: Option<String>,
+    pub owner_skill_name: Option<String>,
+}
+
+/// DTO for name conflict check before install / sync
+#[derive(Debug, Serialize)]
+pub struct SkillNameConflictDto {
+    pub name: String,
+    pub has_conflict: bool,
+    pub existing_skill_id: Option<String>,
+    pub central_path_exists: bool,
+    pub tools: Vec<ToolNameConflictDto>,
+    pub suggested_name: Option<String>,
+}
+
 /// Git skill candidate for multi-skill repos
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GitSkillCandidate {
@@ -170,6 +361,22 @@ pub struct GitSkillCandidate {
     pub subpath: String,
 }
 
+/// One repo to list in `skills_list_git_skills_batch`
+#[derive(Clone, Debug, Deserialize)]
+pub struct GitRepoListRequest {
+    pub repo_url: String,
+    pub branch: Option<String>,
+}
+
+/// DTO for one repo's listing in a batch; failures are per repo
+#[derive(Debug, Serialize)]
+pub struct GitRepoListResultDto {
+    pub repo_url: String,
+    pub branch: Option<String>,
+    pub skills: Vec<GitSkillCandidate>,
+    pub error: Option<String>,
+}
+
 /// Onboarding plan for discovered skills
 #[derive(Clone, Debug, Serialize)]
 pub struct OnboardingPlan {
@@ -214,11 +421,12 @@ pub struct UpdateResult {
     pub central_path: std::path::PathBuf,
     pub content_hash: Option<String>,
     pub source_revision: Option<String>,
+    pub pinned_ref: Option<String>,
     pub updated_targets: Vec<String>,
 }
 
 /// Sync mode used for skill syncing
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SyncMode {
     Auto,
     Symlink,
@@ -279,6 +487,16 @@ pub struct SkillRepoDto {
     pub created_at: i64,
 }
 
+/// DTO for a private repo credential (never includes the secret)
+#[derive(Debug, Serialize)]
+pub struct SkillRepoCredentialDto {
+    pub repo_key: String,
+    pub auth_type: String,
+    pub username: Option<String>,
+    pub has_secret: bool,
+    pub updated_at: i64,
+}
+
 /// Helper function to get current timestamp in milliseconds
 pub fn now_ms() -> i64 {
     let now = std::time::SystemTime::now()
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use super::git_fetcher::remote_head;
+use super::installer::parse_github_url;
+use super::types::{now_ms, Skill, SkillUpdateCheckDto};
+
+/// Max number of `git ls-remote` calls running at the same time
+const REMOTE_CHECK_CONCURRENCY: usize = 4;
+
+/// Remote HEAD lookups keyed by `clone_url\nbranch`: (fetched_at_ms, revision)
+static REMOTE_HEAD_CACHE: OnceLock<Mutex<HashMap<String, (i64, String)>>> = OnceLock::new();
+
+fn remote_key(clone_url: &str, branch: Option<&str>) -> String {
+    format!("{}\n{}", clone_url, branch.unwrap_or(""))
+}
+
+fn cached_remote_head(key: &str, ttl_secs: i64) -> Option<String> {
+    let ttl_ms = ttl_secs.saturating_mul(1000);
+    if ttl_ms <= 0 {
+        return None;
+    }
+    let cache = REMOTE_HEAD_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    let cache = cache.lock().unwrap_or_else(|err| err.into_inner());
+    cache
+        .get(key)
+        .filter(|(fetched_at, _)| now_ms().saturating_sub(*fetched_at) < ttl_ms)
+        .map(|(_, rev)| rev.clone())
+}
+
+fn store_remote_head(key: &str, rev: &str) {
+    let cache = REMOTE_HEAD_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut cache = cache.lock().unwrap_or_else(|err| err.into_inner());
+    cache.insert(key.to_string(), (now_ms(), rev.to_string()));
+}
+
+/// Compare git skills' stored revisions against their remote branch HEAD
+///
+/// Each (repo, branch) pair is looked up once no matter how many skills come from it,
+/// and results are cached for `ttl_secs` unless `force` is set. Pinned skills report the
+/// remote HEAD but never `has_update`, since updating them keeps the pin.
+/// Blocking: run inside `spawn_blocking`.
+pub fn check_git_skill_updates(
+    skills: &[Skill],
+    ttl_secs: i64,
+    force: bool,
+) -> Vec<SkillUpdateCheckDto> {
+    let git_skills: Vec<(&Skill, String, Option<String>)> = skills
+        .iter()
+        .filter(|skill| skill.source_type == "git")
+        .filter_map(|skill| {
+            let parsed = parse_github_url(skill.source_ref.as_deref()?);
+            Some((skill, parsed.clone_url, parsed.branch))
+        })
+        .collect();
+
+    let mut remotes: HashMap<String, (String, Option<String>)> = HashMap::new();
+    for (_, clone_url, branch) in &git_skills {
+        remotes
+            .entry(remote_key(clone_url, branch.as_deref()))
+            .or_insert_with(|| (clone_url.clone(), branch.clone()));
+    }
+
+    let mut resolved: HashMap<String, Result<String, String>> = HashMap::new();
+    let mut pending: Vec<(String, String, Option<String>)> = Vec::new();
+    for (key, (clone_url, branch)) in remotes {
+        let cached = if force {
+            None
+        } else {
+            cached_remote_head(&key, ttl_secs)
+        };
+        match cached {
+            Some(rev) => {
+                resolved.insert(key, Ok(rev));
+            }
+            None => pending.push((key, clone_url, branch)),
+        }
+    }
+
+    for chunk in pending.chunks(REMOTE_CHECK_CONCURRENCY) {
+        let results: Vec<(String, Result<String, String>)> = std::thread::scope(|scope| {
+            let handles: Vec<_> = chunk
+                .iter()
+                .map(|(key, clone_url, branch)| {
+                    scope.spawn(move || {
+                        let result = remote_head(clone_url, branch.as_deref())
+                            .map_err(|e| format!("{:#}", e));
+                        (key.clone(), result)
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .filter_map(|handle| handle.join().ok())
+                .collect()
+        });
+        for (key, result) in results {
+            if let Ok(rev) = &result {
+                store_remote_head(&key, rev);
+            }
+            resolved.insert(key, result);
+        }
+    }
+
+    git_skills
+        .into_iter()
+        .map(|(skill, clone_url, branch)| {
+            let remote = resolved
+                .get(&remote_key(&clone_url, branch.as_deref()))
+                .cloned()
+                .unwrap_or_else(|| Err("remote check did not run".to_string()));
+            let (remote_revision, error) = match remote {
+                Ok(rev) => (Some(rev), None),
+                Err(err) => (None, Some(err)),
+            };
+            let has_update = skill.pinned_ref.is_none()
+                && remote_revision.is_some()
+                && remote_revision != skill.source_revision;
+
+            SkillUpdateCheckDto {
+                skill_id: skill.id.clone(),
+                name: skill.name.clone(),
+                current_revision: skill.source_revision.clone(),
+                remote_revision,
+                pinned_ref: skill.pinned_ref.clone(),
+                has_update,
+                error,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,66 @@
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use super::adapter::{get_sync_count, parse_sync_details};
+use super::types::{Skill, SkillUsageDto, TargetUsageDto};
+
+/// Reads this close to a sync are the sync itself (copying writes and hashes the files)
+const SYNC_READ_SLACK_MS: i64 = 5_000;
+
+fn accessed_ms(path: &Path) -> Option<i64> {
+    let accessed = std::fs::metadata(path).ok()?.accessed().ok()?;
+    accessed
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_millis() as i64)
+}
+
+/// Latest access time of a skill dir or its SKILL.md (follows symlinks)
+fn probe_last_read(dir: &Path) -> Option<i64> {
+    [dir.join("SKILL.md"), dir.to_path_buf()]
+        .iter()
+        .filter_map(|path| accessed_ms(path))
+        .max()
+}
+
+/// Collect sync history and a best-effort "last read" probe for each target
+///
+/// Read times come from file atime, which many systems only update lazily (relatime)
+/// or not at all (noatime), so a missing or stale value does not prove the skill is unused.
+pub fn build_skill_usage(skill: &Skill) -> SkillUsageDto {
+    let targets: Vec<TargetUsageDto> = parse_sync_details(skill)
+        .into_iter()
+        .map(|target| {
+            let last_read_at = if target.is_disabled() {
+                None
+            } else {
+                probe_last_read(Path::new(&target.target_path))
+            };
+            let read_since_sync = match (last_read_at, target.synced_at) {
+                (Some(read_at), Some(synced_at)) => read_at > synced_at + SYNC_READ_SLACK_MS,
+                _ => false,
+            };
+            TargetUsageDto {
+                sync_count: get_sync_count(&skill.sync_details, &target.tool),
+                shared_with_central: target.mode != "copy",
+                tool: target.tool,
+                target_path: target.target_path,
+                mode: target.mode,
+                status: target.status,
+                synced_at: target.synced_at,
+                last_read_at,
+                read_since_sync,
+            }
+        })
+        .collect();
+
+    SkillUsageDto {
+        skill_id: skill.id.clone(),
+        name: skill.name.clone(),
+        last_sync_at: skill
+            .last_sync_at
+            .or_else(|| targets.iter().filter_map(|t| t.synced_at).max()),
+        last_read_at: targets.iter().filter_map(|t| t.last_read_at).max(),
+        targets,
+    }
+}
@@ -1,5 +1,6 @@
 use super::super::db_id;
-use super::types::{SSHConnection, SSHFileMapping, SSHSyncConfig};
+use super::types::{SSHConnection, SSHFileMapping, SSHPathRewrite, SSHSyncConfig};
+use crate::db_timestamp::Timestamp;
 use chrono::Local;
 use serde_json::{json, Value};
 
@@ -29,11 +30,8 @@ pub fn config_from_db_value(
         sync_skills: true,
         file_mappings,
         connections,
-        last_sync_time: value
-            .get("last_sync_time")
-            .or_else(|| value.get("lastSyncTime"))
-            .and_then(|v| v.as_str())
-            .map(String::from),
+        last_sync_time: Timestamp::from_db(&value, "last_sync_time")
+            .or_else(|| Timestamp::from_db(&value, "lastSyncTime")),
         last_sync_status: value
             .get("last_sync_status")
             .or_else(|| value.get("lastSyncStatus"))
@@ -45,6 +43,26 @@ pub fn config_from_db_value(
             .or_else(|| value.get("lastSyncError"))
             .and_then(|v| v.as_str())
             .map(String::from),
+        incremental_dir_sync: value
+            .get("incremental_dir_sync")
+            .or_else(|| value.get("incrementalDirSync"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        skills_remote_bootstrap: value
+            .get("skills_remote_bootstrap")
+            .or_else(|| value.get("skillsRemoteBootstrap"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        last_failed_mapping_ids: value
+            .get("last_failed_mapping_ids")
+            .or_else(|| value.get("lastFailedMappingIds"))
+            .and_then(|v| v.as_array())
+            .map(|ids| {
+                ids.iter()
+                    .filter_map(|id| id.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default(),
         module_statuses: vec![],
     }
 }
@@ -57,6 +75,9 @@ pub fn config_to_db_value(config: &SSHSyncConfig) -> Value {
         "last_sync_time": config.last_sync_time,
         "last_sync_status": config.last_sync_status,
         "last_sync_error": config.last_sync_error,
+        "incremental_dir_sync": config.incremental_dir_sync,
+        "skills_remote_bootstrap": config.skills_remote_bootstrap,
+        "last_failed_mapping_ids": config.last_failed_mapping_ids,
     })
 }
 
@@ -119,24 +140,72 @@ pub fn connection_from_db_value(value: Value) -> SSHConnection {
             .or_else(|| value.get("sortOrder"))
             .and_then(|v| v.as_u64())
             .unwrap_or(0) as u32,
+        redact_secrets: value
+            .get("redact_secrets")
+            .or_else(|| value.get("redactSecrets"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        path_rewrites: value
+            .get("path_rewrites")
+            .or_else(|| value.get("pathRewrites"))
+            .and_then(|v| v.as_array())
+            .map(|rules| {
+                rules
+                    .iter()
+                    .filter_map(path_rewrite_from_db_value)
+                    .collect()
+            })
+            .unwrap_or_default(),
     }
 }
 
+fn path_rewrite_from_db_value(value: &Value) -> Option<SSHPathRewrite> {
+    Some(SSHPathRewrite {
+        pattern: value.get("pattern")?.as_str()?.to_string(),
+        replacement: value
+            .get("replacement")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        is_regex: value
+            .get("is_regex")
+            .or_else(|| value.get("isRegex"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+    })
+}
+
 /// Convert SSHConnection to database Value
-pub fn connection_to_db_value(conn: &SSHConnection) -> Value {
-    json!({
+///
+/// Password and passphrase live in the OS keychain; `include_secrets` is only set
+/// when the keychain is unavailable.
+pub fn connection_to_db_value(conn: &SSHConnection, include_secrets: bool) -> Value {
+    let mut value = json!({
         "name": conn.name,
         "host": conn.host,
         "port": conn.port,
         "username": conn.username,
         "auth_method": conn.auth_method,
-        "password": conn.password,
         "private_key_path": conn.private_key_path,
         "private_key_content": conn.private_key_content,
-        "passphrase": conn.passphrase,
         "sort_order": conn.sort_order,
+        "redact_secrets": conn.redact_secrets,
+        "path_rewrites": conn
+            .path_rewrites
+            .iter()
+            .map(|rule| json!({
+                "pattern": rule.pattern,
+                "replacement": rule.replacement,
+                "is_regex": rule.is_regex,
+            }))
+            .collect::<Vec<_>>(),
         "updated_at": Local::now().to_rfc3339(),
-    })
+    });
+    if include_secrets {
+        value["password"] = json!(conn.password);
+        value["passphrase"] = json!(conn.passphrase);
+    }
+    value
 }
 
 // ============================================================================
@@ -185,6 +254,17 @@ pub fn mapping_from_db_value(value: Value) -> SSHFileMapping {
             .or_else(|| value.get("isDirectory"))
             .and_then(|v| v.as_bool())
             .unwrap_or(false),
+        exclude_patterns: value
+            .get("exclude_patterns")
+            .or_else(|| value.get("excludePatterns"))
+            .and_then(|v| v.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| item.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default(),
     }
 }
 
@@ -198,6 +278,7 @@ pub fn mapping_to_db_value(mapping: &SSHFileMapping) -> Value {
         "enabled": mapping.enabled,
         "is_pattern": mapping.is_pattern,
         "is_directory": mapping.is_directory,
+        "exclude_patterns": mapping.exclude_patterns,
         "updated_at": Local::now().to_rfc3339(),
     })
 }
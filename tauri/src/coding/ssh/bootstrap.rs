@@ -0,0 +1,163 @@
+//! Remote CLI bootstrap
+//!
+//! Checks whether the coding CLIs exist on the remote host and optionally installs them
+//! through the package manager that is available there (npm, then brew; apt only to get npm).
+
+use super::session::SshSession;
+use super::types::SSHBootstrapToolResult;
+
+struct RemoteCli {
+    tool: &'static str,
+    binary: &'static str,
+    npm_package: &'static str,
+    brew_package: &'static str,
+}
+
+const REMOTE_CLIS: [RemoteCli; 3] = [
+    RemoteCli {
+        tool: "opencode",
+        binary: "opencode",
+        npm_package: "opencode-ai",
+        brew_package: "opencode",
+    },
+    RemoteCli {
+        tool: "claude",
+        binary: "claude",
+        npm_package: "@anthropic-ai/claude-code",
+        brew_package: "--cask claude-code",
+    },
+    RemoteCli {
+        tool: "codex",
+        binary: "codex",
+        npm_package: "@openai/codex",
+        brew_package: "codex",
+    },
+];
+
+/// 包装为登录 shell 命令，加载用户 PATH（npm 全局目录、brew 等）
+pub(super) fn login_shell_command(cmd: &str) -> String {
+    format!(
+        "\"${{SHELL:-/bin/sh}}\" -lc '{}'",
+        cmd.replace('\'', "'\\''")
+    )
+}
+
+async fn exec_login(session: &SshSession, cmd: &str) -> Result<String, String> {
+    session.exec_command(&login_shell_command(cmd)).await
+}
+
+async fn has_command(session: &SshSession, binary: &str) -> bool {
+    exec_login(
+        session,
+        &format!(
+            "command -v {} >/dev/null 2>&1 && echo yes || echo no",
+            binary
+        ),
+    )
+    .await
+    .map(|out| out.trim().ends_with("yes"))
+    .unwrap_or(false)
+}
+
+async fn cli_version(session: &SshSession, binary: &str) -> Option<String> {
+    if !has_command(session, binary).await {
+        return None;
+    }
+    let output = exec_login(
+        session,
+        &format!("{} --version 2>/dev/null | head -n 1", binary),
+    )
+    .await
+    .unwrap_or_default();
+    Some(output.trim().to_string())
+}
+
+/// 确保 npm 可用；仅在无需密码的 sudo 下通过 apt 安装 nodejs/npm
+async fn ensure_npm(session: &SshSession) -> Result<(), String> {
+    if has_command(session, "npm").await {
+        return Ok(());
+    }
+    if !has_command(session, "apt-get").await {
+        return Err("远程未找到 npm 或 brew，请先安装 Node.js".to_string());
+    }
+    exec_login(
+        session,
+        "sudo -n apt-get update -qq && sudo -n DEBIAN_FRONTEND=noninteractive apt-get install -y -qq nodejs npm",
+    )
+    .await
+    .map_err(|e| format!("通过 apt 安装 npm 失败（需要免密 sudo）: {}", e))?;
+    Ok(())
+}
+
+async fn install_cli(session: &SshSession, cli: &RemoteCli) -> Result<String, String> {
+    if !has_command(session, "npm").await && has_command(session, "brew").await {
+        exec_login(session, &format!("brew install {}", cli.brew_package)).await?;
+        return Ok("brew".to_string());
+    }
+    ensure_npm(session).await?;
+    exec_login(session, &format!("npm install -g {}", cli.npm_package)).await?;
+    Ok("npm".to_string())
+}
+
+/// 检查（并可选安装）远程 CLI，逐个工具返回结果
+pub async fn bootstrap_remote(
+    session: &SshSession,
+    tools: &[String],
+    install: bool,
+) -> Vec<SSHBootstrapToolResult> {
+    let mut results = Vec::with_capacity(tools.len());
+    for tool in tools {
+        let Some(cli) = REMOTE_CLIS.iter().find(|cli| cli.tool == tool) else {
+            results.push(SSHBootstrapToolResult {
+                tool: tool.clone(),
+                action: "failed".to_string(),
+                version: None,
+                method: None,
+                error: Some(format!("不支持的工具: {}", tool)),
+            });
+            continue;
+        };
+
+        let mut result = SSHBootstrapToolResult {
+            tool: tool.clone(),
+            action: "present".to_string(),
+            version: cli_version(session, cli.binary).await,
+            method: None,
+            error: None,
+        };
+        if result.version.is_some() {
+            results.push(result);
+            continue;
+        }
+        if !install {
+            result.action = "missing".to_string();
+            results.push(result);
+            continue;
+        }
+
+        log::info!("SSH bootstrap installing remote CLI: tool={}", cli.tool);
+        match install_cli(session, cli).await {
+            Ok(method) => {
+                result.method = Some(method);
+                result.version = cli_version(session, cli.binary).await;
+                if result.version.is_some() {
+                    result.action = "installed".to_string();
+                } else {
+                    result.action = "failed".to_string();
+                    result.error = Some(format!("安装完成但 PATH 中找不到 {}", cli.binary));
+                }
+            }
+            Err(e) => {
+                log::warn!(
+                    "SSH bootstrap failed to install remote CLI: tool={}, error={}",
+                    cli.tool,
+                    e
+                );
+                result.action = "failed".to_string();
+                result.error = Some(e);
+            }
+        }
+        results.push(result);
+    }
+    results
+}
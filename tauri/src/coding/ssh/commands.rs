@@ -1,13 +1,21 @@
+use super::credentials;
 use super::key_file;
+use super::known_hosts;
 use super::types::{
-    SSHConnection, SSHConnectionResult, SSHFileMapping, SSHStatusResult, SSHSyncConfig,
-    SyncProgress, SyncResult,
+    SSHBootstrapToolResult, SSHConnection, SSHConnectionResult, SSHFileMapping, SSHPathRewrite,
+    SSHPortForward, SSHPullItem, SSHRemoteEntry, SSHRemoteFile, SSHStatusResult, SSHSyncConfig,
+    SSHSyncLogEntry, SyncProgress, SyncResult,
+};
+use super::{
+    adapter, bootstrap, exec, exec::ExecState, forward, forward::PortForwardState, history,
+    path_rewrite, plan, pull, session::SshSession, session::SshSessionState, sync,
 };
-use super::{adapter, session::SshSession, session::SshSessionState, sync};
 use crate::coding::db_id::db_record_id;
 use crate::coding::runtime_location;
 use crate::db::DbState;
-use chrono::Local;
+use crate::db_timestamp::{with_datetimes, Timestamp};
+use crate::locale;
+use futures_util::StreamExt;
 use tauri::Emitter;
 
 // ============================================================================
@@ -44,11 +52,9 @@ pub async fn get_ssh_config_internal(
         .map_err(|e| format!("Failed to query SSH connections: {}", e))?
         .take(0);
 
-    let connections = connections_result
-        .unwrap_or_default()
-        .into_iter()
-        .map(adapter::connection_from_db_value)
-        .collect();
+    let connections =
+        credentials::load_connections(db, connections_result.unwrap_or_default()).await;
+    known_hosts::ensure_loaded(db, &connections).await;
 
     let file_mappings = if include_mappings {
         let result: Result<Vec<serde_json::Value>, _> = db
@@ -126,7 +132,7 @@ pub async fn ssh_save_config(
         // Save config
         let config_data = adapter::config_to_db_value(&config);
         db.query("UPSERT ssh_sync_config:`config` CONTENT $data")
-            .bind(("data", config_data))
+            .bind(("data", with_datetimes(config_data)))
             .await
             .map_err(|e| format!("Failed to save SSH config: {}", e))?;
 
@@ -205,10 +211,7 @@ pub async fn ssh_list_connections(
         .take(0);
 
     match result {
-        Ok(records) => Ok(records
-            .into_iter()
-            .map(adapter::connection_from_db_value)
-            .collect()),
+        Ok(records) => Ok(credentials::load_connections(&db, records).await),
         Err(_) => Ok(vec![]),
     }
 }
@@ -221,10 +224,11 @@ pub async fn ssh_create_connection(
     mut connection: SSHConnection,
 ) -> Result<(), String> {
     normalise_key_fields(&mut connection);
+    path_rewrite::validate_rules(&connection.path_rewrites)?;
 
     let db = state.db();
 
-    let conn_data = adapter::connection_to_db_value(&connection);
+    let conn_data = credentials::connection_to_db_value_secure(&connection);
     let record_id = db_record_id("ssh_connection", &connection.id);
     db.query(&format!("UPSERT {} CONTENT $data", record_id))
         .bind(("data", conn_data))
@@ -243,10 +247,11 @@ pub async fn ssh_update_connection(
     mut connection: SSHConnection,
 ) -> Result<(), String> {
     normalise_key_fields(&mut connection);
+    path_rewrite::validate_rules(&connection.path_rewrites)?;
 
     let db = state.db();
 
-    let conn_data = adapter::connection_to_db_value(&connection);
+    let conn_data = credentials::connection_to_db_value_secure(&connection);
     let record_id = db_record_id("ssh_connection", &connection.id);
     db.query(&format!("UPSERT {} CONTENT $data", record_id))
         .bind(("data", conn_data))
@@ -261,15 +266,18 @@ pub async fn ssh_update_connection(
 #[tauri::command]
 pub async fn ssh_delete_connection(
     state: tauri::State<'_, DbState>,
+    forward_state: tauri::State<'_, PortForwardState>,
     app: tauri::AppHandle,
     id: String,
 ) -> Result<(), String> {
     let db = state.db();
+    forward::stop_connection_forwards(&forward_state, &id).await;
 
     let record_id = db_record_id("ssh_connection", &id);
     db.query(&format!("DELETE {}", record_id))
         .await
         .map_err(|e| format!("Failed to delete SSH connection: {}", e))?;
+    credentials::delete_connection_secrets(&id);
 
     // 如果删除的是当前活跃连接，清除 active_connection_id
     db.query("UPDATE ssh_sync_config SET active_connection_id = '' WHERE id = ssh_sync_config:`config` AND active_connection_id = $id")
@@ -316,11 +324,143 @@ pub async fn ssh_set_active_connection(
 }
 
 /// Test an SSH connection (async, non-blocking)
+///
+/// Unknown hosts fail with a `HOST_KEY_UNKNOWN` error and return the host key, so the UI
+/// can show the fingerprint and call `ssh_trust_host_key`.
 #[tauri::command]
-pub async fn ssh_test_connection(mut connection: SSHConnection) -> SSHConnectionResult {
+pub async fn ssh_test_connection(
+    state: tauri::State<'_, DbState>,
+    mut connection: SSHConnection,
+) -> Result<SSHConnectionResult, String> {
     normalise_key_fields(&mut connection);
 
-    sync::test_connection(&connection).await
+    // Loads the trusted host keys
+    let db = state.db();
+    get_ssh_config_internal(&db, false).await?;
+
+    Ok(sync::test_connection(&connection).await)
+}
+
+/// Trust a server host key (after the user compared the fingerprint)
+#[tauri::command]
+pub async fn ssh_trust_host_key(
+    state: tauri::State<'_, DbState>,
+    host: String,
+    port: u16,
+    key_type: String,
+    fingerprint: String,
+) -> Result<(), String> {
+    let db = state.db();
+    known_hosts::trust_host_key(&db, &host, port, &key_type, &fingerprint).await?;
+    log::info!(
+        "SSH host key trusted: {}:{} {} {}",
+        host,
+        port,
+        key_type,
+        fingerprint
+    );
+    Ok(())
+}
+
+/// Forget a trusted host key; the next connection to the host asks again
+#[tauri::command]
+pub async fn ssh_forget_host_key(
+    state: tauri::State<'_, DbState>,
+    host: String,
+    port: u16,
+) -> Result<(), String> {
+    let db = state.db();
+    known_hosts::forget_host_key(&db, &host, port).await
+}
+
+// ============================================================================
+// Remote Browse Commands
+// ============================================================================
+
+async fn find_connection(state: &DbState, connection_id: &str) -> Result<SSHConnection, String> {
+    let db = state.db();
+    get_ssh_config_internal(&db, false)
+        .await?
+        .connections
+        .into_iter()
+        .find(|c| c.id == connection_id)
+        .ok_or_else(|| format!("SSH 连接不存在: {}", connection_id))
+}
+
+/// List a remote directory for the remote path picker
+///
+/// Reuses the main session when it is connected to `connection_id`, otherwise opens a
+/// short-lived one.
+#[tauri::command]
+pub async fn ssh_list_dir(
+    state: tauri::State<'_, DbState>,
+    session_state: tauri::State<'_, SshSessionState>,
+    connection_id: String,
+    path: String,
+) -> Result<Vec<SSHRemoteEntry>, String> {
+    {
+        let session = session_state.0.lock().await;
+        if session.is_alive() && session.conn().is_some_and(|c| c.id == connection_id) {
+            return sync::list_remote_entries(&session, &path).await;
+        }
+    }
+
+    let conn = find_connection(state.inner(), &connection_id).await?;
+    let mut session = SshSession::new();
+    session.connect(&conn).await?;
+    let result = sync::list_remote_entries(&session, &path).await;
+    session.disconnect().await;
+    result
+}
+
+/// Read a remote file (up to 1 MB) with its metadata
+#[tauri::command]
+pub async fn ssh_read_file(
+    state: tauri::State<'_, DbState>,
+    session_state: tauri::State<'_, SshSessionState>,
+    connection_id: String,
+    path: String,
+) -> Result<SSHRemoteFile, String> {
+    {
+        let session = session_state.0.lock().await;
+        if session.is_alive() && session.conn().is_some_and(|c| c.id == connection_id) {
+            return sync::read_remote_file_with_metadata(&session, &path).await;
+        }
+    }
+
+    let conn = find_connection(state.inner(), &connection_id).await?;
+    let mut session = SshSession::new();
+    session.connect(&conn).await?;
+    let result = sync::read_remote_file_with_metadata(&session, &path).await;
+    session.disconnect().await;
+    result
+}
+
+/// Check for (and with `install` set, install) the coding CLIs on the remote host
+///
+/// `tools` takes "opencode" | "claude" | "codex".
+#[tauri::command]
+pub async fn ssh_bootstrap_remote(
+    state: tauri::State<'_, DbState>,
+    session_state: tauri::State<'_, SshSessionState>,
+    connection_id: String,
+    tools: Vec<String>,
+    install: Option<bool>,
+) -> Result<Vec<SSHBootstrapToolResult>, String> {
+    let install = install.unwrap_or(false);
+    {
+        let session = session_state.0.lock().await;
+        if session.is_alive() && session.conn().is_some_and(|c| c.id == connection_id) {
+            return Ok(bootstrap::bootstrap_remote(&session, &tools, install).await);
+        }
+    }
+
+    let conn = find_connection(state.inner(), &connection_id).await?;
+    let mut session = SshSession::new();
+    session.connect(&conn).await?;
+    let results = bootstrap::bootstrap_remote(&session, &tools, install).await;
+    session.disconnect().await;
+    Ok(results)
 }
 
 // ============================================================================
@@ -414,6 +554,7 @@ pub async fn do_full_sync(
     module: Option<&str>,
     skip_modules: Option<&[String]>,
 ) -> SyncResult {
+    let started_at = std::time::Instant::now();
     let total_mapping_count = config.file_mappings.len();
     let enabled_mapping_count = config.file_mappings.iter().filter(|m| m.enabled).count();
     let disabled_mapping_count = total_mapping_count.saturating_sub(enabled_mapping_count);
@@ -435,24 +576,34 @@ pub async fn do_full_sync(
         "ssh-sync-progress",
         SyncProgress {
             phase: "files".to_string(),
-            current_item: "准备中...".to_string(),
+            current_item: locale::text("sync.preparing").to_string(),
             current: 0,
             total: total_files,
-            message: format!("文件同步: 0/{}", total_files),
+            message: locale::format(
+                "sync.files_progress",
+                &[("current", &0), ("total", &total_files)],
+            ),
         },
     );
 
     // Resolve dynamic config paths
     let db = state.db();
-    let file_mappings = resolve_dynamic_paths_with_db(&db, config.file_mappings.clone()).await;
+    let file_mappings =
+        resolve_dynamic_paths_with_db(&db, config.file_mappings.clone(), config.path_rewrites())
+            .await;
     log::info!(
         "SSH full sync resolved dynamic mappings: resolved_count={}",
         file_mappings.len()
     );
 
     // Sync file mappings with progress
-    let mut result =
-        sync_mappings_with_progress(&file_mappings, session, module, skip_modules, app).await;
+    let outcome =
+        sync_mappings_with_progress(&file_mappings, session, module, skip_modules, config, app)
+            .await;
+    if let Err(e) = update_failed_mapping_ids(state, config, &outcome).await {
+        log::warn!("{}", e);
+    }
+    let mut result = outcome.result;
     log::info!(
         "SSH full sync file stage completed: synced_files={}, skipped_files={}, errors={}",
         result.synced_files.len(),
@@ -514,20 +665,48 @@ pub async fn do_full_sync(
         result.skipped_files.len(),
         result.errors.len()
     );
+
+    let connection_name = config.active_connection().map(|c| c.name.as_str());
+    if let Err(e) = history::record_sync_run(
+        &db,
+        &config.active_connection_id,
+        connection_name,
+        module,
+        &result,
+        started_at.elapsed().as_millis() as u64,
+    )
+    .await
+    {
+        log::warn!("{}", e);
+    }
     result
 }
 
+/// File stage result plus which mappings ran and which of them failed
+struct MappingSyncOutcome {
+    result: SyncResult,
+    attempted_mapping_ids: Vec<String>,
+    failed_mapping_ids: Vec<String>,
+}
+
+/// Upper bound on mappings transferred at the same time over the shared connection
+const MAX_CONCURRENT_MAPPING_SYNCS: usize = 4;
+
 /// Sync file mappings with progress events
+///
+/// Mappings are transferred concurrently (bounded), progress is reported in mapping order.
 async fn sync_mappings_with_progress(
     mappings: &[SSHFileMapping],
     session: &SshSession,
     module_filter: Option<&str>,
     skip_modules: Option<&[String]>,
+    config: &SSHSyncConfig,
     app: &tauri::AppHandle,
-) -> SyncResult {
+) -> MappingSyncOutcome {
     let mut synced_files = vec![];
     let mut skipped_files = vec![];
     let mut errors = vec![];
+    let mut failed_mapping_ids = vec![];
     let mut filtered_mappings = Vec::new();
     let mut disabled_mapping_count = 0usize;
     let mut filtered_by_module_count = 0usize;
@@ -587,9 +766,24 @@ async fn sync_mappings_with_progress(
         skip_modules
     );
 
-    for (idx, mapping) in filtered_mappings.iter().enumerate() {
-        let current = (idx + 1) as u32;
+    // 所有映射复用同一条 SSH 连接（每个传输一个 channel），限制并发数避免超出服务端 MaxSessions
+    // buffered 按输入顺序产出结果，进度事件因此保持有序
+    let mut results =
+        futures_util::stream::iter(filtered_mappings.iter().map(|mapping| async move {
+            let result = sync::sync_file_mapping(
+                mapping,
+                session,
+                config.incremental_dir_sync,
+                config.redact_secrets(),
+            )
+            .await;
+            (*mapping, result)
+        }))
+        .buffered(MAX_CONCURRENT_MAPPING_SYNCS);
 
+    let mut current = 0u32;
+    while let Some((mapping, result)) = results.next().await {
+        current += 1;
         let _ = app.emit(
             "ssh-sync-progress",
             SyncProgress {
@@ -597,11 +791,18 @@ async fn sync_mappings_with_progress(
                 current_item: mapping.name.clone(),
                 current,
                 total,
-                message: format!("文件同步: {}/{} - {}", current, total, mapping.name),
+                message: locale::format(
+                    "sync.files_progress_item",
+                    &[
+                        ("current", &current),
+                        ("total", &total),
+                        ("name", &mapping.name),
+                    ],
+                ),
             },
         );
 
-        match sync::sync_file_mapping(mapping, session).await {
+        match result {
             Ok(files) if files.is_empty() => {
                 log::warn!(
                     "SSH sync mapping produced no uploaded files: id={}, name={}, module={}, local_path={}, remote_path={}",
@@ -635,19 +836,158 @@ async fn sync_mappings_with_progress(
                     e
                 );
                 errors.push(format!("{}: {}", mapping.name, e));
+                failed_mapping_ids.push(mapping.id.clone());
             }
         }
     }
 
-    SyncResult {
-        success: errors.is_empty(),
-        synced_files,
-        skipped_files,
-        errors,
+    MappingSyncOutcome {
+        attempted_mapping_ids: filtered_mappings.iter().map(|m| m.id.clone()).collect(),
+        failed_mapping_ids,
+        result: SyncResult {
+            success: errors.is_empty(),
+            synced_files,
+            skipped_files,
+            errors,
+        },
+    }
+}
+
+/// Persist the failed mapping IDs of a file stage
+///
+/// Mappings that were not part of this run (module filter, skip list) keep their previous
+/// failed state, so a partial sync does not clear failures it never retried.
+async fn update_failed_mapping_ids(
+    state: &DbState,
+    config: &SSHSyncConfig,
+    outcome: &MappingSyncOutcome,
+) -> Result<(), String> {
+    let mut failed: Vec<String> = config
+        .last_failed_mapping_ids
+        .iter()
+        .filter(|id| !outcome.attempted_mapping_ids.contains(id))
+        .cloned()
+        .collect();
+    failed.extend(outcome.failed_mapping_ids.iter().cloned());
+
+    let db = state.db();
+    db.query("UPDATE ssh_sync_config SET last_failed_mapping_ids = $ids WHERE id = ssh_sync_config:`config`")
+        .bind(("ids", failed))
+        .await
+        .map_err(|e| format!("Failed to save SSH failed mappings: {}", e))?;
+    Ok(())
+}
+
+/// Re-sync only the file mappings that failed in the last sync
+///
+/// Emits the same `ssh-sync-progress` / `ssh-sync-completed` events as `ssh_sync`.
+#[tauri::command]
+pub async fn ssh_retry_failed(
+    state: tauri::State<'_, DbState>,
+    session_state: tauri::State<'_, SshSessionState>,
+    app: tauri::AppHandle,
+) -> Result<SyncResult, String> {
+    let config = ssh_get_config(state.clone()).await?;
+    if !config.enabled || config.active_connection_id.is_empty() {
+        return Ok(SyncResult {
+            success: false,
+            synced_files: vec![],
+            skipped_files: vec![],
+            errors: vec![locale::text("sync.ssh_not_enabled").to_string()],
+        });
+    }
+
+    let retry_mappings: Vec<SSHFileMapping> = config
+        .file_mappings
+        .iter()
+        .filter(|m| config.last_failed_mapping_ids.contains(&m.id))
+        .cloned()
+        .collect();
+    log::info!(
+        "SSH retry failed mappings requested: failed_ids={:?}, retry_mappings={}",
+        config.last_failed_mapping_ids,
+        retry_mappings.len()
+    );
+
+    let mut session = session_state.0.lock().await;
+    if !session.try_acquire_sync_lock() {
+        return Ok(SyncResult {
+            success: false,
+            synced_files: vec![],
+            skipped_files: vec![],
+            errors: vec![locale::text("sync.already_running").to_string()],
+        });
+    }
+    if let Err(e) = session.ensure_connected().await {
+        session.release_sync_lock();
+        return Ok(SyncResult {
+            success: false,
+            synced_files: vec![],
+            skipped_files: vec![],
+            errors: vec![locale::format("sync.ssh_connect_failed", &[("error", &e)])],
+        });
+    }
+
+    let started_at = std::time::Instant::now();
+    let _ = app.emit(
+        "ssh-sync-progress",
+        SyncProgress {
+            phase: "files".to_string(),
+            current_item: locale::text("sync.preparing").to_string(),
+            current: 0,
+            total: retry_mappings.len() as u32,
+            message: locale::format(
+                "sync.files_progress",
+                &[("current", &0), ("total", &retry_mappings.len())],
+            ),
+        },
+    );
+    let db = state.db();
+    let retry_mappings =
+        resolve_dynamic_paths_with_db(&db, retry_mappings, config.path_rewrites()).await;
+    let outcome =
+        sync_mappings_with_progress(&retry_mappings, &session, None, None, &config, &app).await;
+    super::mcp_sync::post_process_synced_mcp_files(
+        &session,
+        &retry_mappings,
+        &outcome.result.synced_files,
+        config.redact_secrets(),
+    )
+    .await;
+    session.release_sync_lock();
+
+    if let Err(e) = update_failed_mapping_ids(state.inner(), &config, &outcome).await {
+        log::warn!("{}", e);
+    }
+    let result = outcome.result;
+    if let Err(e) = history::record_sync_run(
+        &db,
+        &config.active_connection_id,
+        config.active_connection().map(|c| c.name.as_str()),
+        None,
+        &result,
+        started_at.elapsed().as_millis() as u64,
+    )
+    .await
+    {
+        log::warn!("{}", e);
     }
+    update_sync_status(state.inner(), &result).await?;
+    let _ = app.emit("ssh-sync-completed", result.clone());
+    log::info!(
+        "SSH retry failed mappings finished: success={}, synced_files={}, errors={}",
+        result.success,
+        result.synced_files.len(),
+        result.errors.len()
+    );
+    Ok(result)
 }
 
 /// Execute SSH sync
+///
+/// With `dry_run` the enabled file mappings are only evaluated (existence, size and a
+/// checksum comparison with the remote) and the planned actions are returned;
+/// nothing is uploaded and the sync status is left untouched.
 #[tauri::command]
 pub async fn ssh_sync(
     state: tauri::State<'_, DbState>,
@@ -655,6 +995,7 @@ pub async fn ssh_sync(
     app: tauri::AppHandle,
     module: Option<String>,
     skip_modules: Option<Vec<String>>,
+    dry_run: Option<bool>,
 ) -> Result<SyncResult, String> {
     let config = ssh_get_config(state.clone()).await?;
     let active_connection = config
@@ -687,7 +1028,7 @@ pub async fn ssh_sync(
             success: false,
             synced_files: vec![],
             skipped_files: vec![],
-            errors: vec!["SSH 同步未启用".to_string()],
+            errors: vec![locale::text("sync.ssh_not_enabled").to_string()],
         });
     }
 
@@ -704,7 +1045,7 @@ pub async fn ssh_sync(
             success: false,
             synced_files: vec![],
             skipped_files: vec![],
-            errors: vec!["另一个同步操作正在进行中".to_string()],
+            errors: vec![locale::text("sync.already_running").to_string()],
         });
     }
 
@@ -720,10 +1061,40 @@ pub async fn ssh_sync(
             success: false,
             synced_files: vec![],
             skipped_files: vec![],
-            errors: vec![format!("SSH 连接失败: {}", e)],
+            errors: vec![locale::format("sync.ssh_connect_failed", &[("error", &e)])],
         });
     }
 
+    if dry_run.unwrap_or(false) {
+        let db = state.db();
+        let file_mappings = resolve_dynamic_paths_with_db(
+            &db,
+            config.file_mappings.clone(),
+            config.path_rewrites(),
+        )
+        .await;
+        let selected: Vec<&SSHFileMapping> = file_mappings
+            .iter()
+            .filter(|m| m.enabled)
+            .filter(|m| module.as_deref().is_none_or(|module| m.module == module))
+            .filter(|m| {
+                skip_modules
+                    .as_ref()
+                    .is_none_or(|skip| !skip.iter().any(|s| s == &m.module))
+            })
+            .collect();
+        let result = plan::plan_mappings(&selected, &session).await;
+        session.release_sync_lock();
+        log::info!(
+            "SSH sync dry run finished: mappings={}, planned_uploads={}, skipped={}, errors={}",
+            selected.len(),
+            result.synced_files.len(),
+            result.skipped_files.len(),
+            result.errors.len()
+        );
+        return Ok(result);
+    }
+
     let result = do_full_sync(
         &state,
         &app,
@@ -759,6 +1130,98 @@ pub async fn ssh_sync(
     Ok(result)
 }
 
+/// Preview pulling mapped files from the remote host
+///
+/// Downloads the remote content of each enabled single-file mapping and compares it
+/// with the local file. Nothing is written locally.
+#[tauri::command]
+pub async fn ssh_pull(
+    state: tauri::State<'_, DbState>,
+    session_state: tauri::State<'_, SshSessionState>,
+    module: Option<String>,
+) -> Result<Vec<SSHPullItem>, String> {
+    let config = ssh_get_config(state.clone()).await?;
+    if !config.enabled || config.active_connection_id.is_empty() {
+        return Err(locale::text("sync.ssh_not_enabled").to_string());
+    }
+
+    let db = state.db();
+    let mappings: Vec<SSHFileMapping> =
+        resolve_dynamic_paths_with_db(&db, config.file_mappings.clone(), config.path_rewrites())
+            .await
+            .into_iter()
+            .filter(|m| m.enabled)
+            .filter(|m| module.as_deref().is_none_or(|module| m.module == module))
+            .collect();
+
+    let mut session = session_state.0.lock().await;
+    if !session.try_acquire_sync_lock() {
+        return Err(locale::text("sync.already_running").to_string());
+    }
+    if let Err(e) = session.ensure_connected().await {
+        session.release_sync_lock();
+        return Err(locale::format("sync.ssh_connect_failed", &[("error", &e)]));
+    }
+
+    let items = pull::preview_pull(&mappings, &session).await;
+    session.release_sync_lock();
+
+    log::info!(
+        "SSH pull preview finished: module={:?}, mappings={}, modified={}",
+        module,
+        items.len(),
+        items
+            .iter()
+            .filter(|item| item.status == "modified" || item.status == "remote_only")
+            .count()
+    );
+    Ok(items)
+}
+
+/// Apply a pull: overwrite local files with the remote content of the selected mappings
+#[tauri::command]
+pub async fn ssh_pull_apply(
+    state: tauri::State<'_, DbState>,
+    session_state: tauri::State<'_, SshSessionState>,
+    app: tauri::AppHandle,
+    mapping_ids: Vec<String>,
+) -> Result<SyncResult, String> {
+    let config = ssh_get_config(state.clone()).await?;
+    if !config.enabled || config.active_connection_id.is_empty() {
+        return Err(locale::text("sync.ssh_not_enabled").to_string());
+    }
+
+    let db = state.db();
+    let mappings: Vec<SSHFileMapping> =
+        resolve_dynamic_paths_with_db(&db, config.file_mappings.clone(), config.path_rewrites())
+            .await
+            .into_iter()
+            .filter(|m| mapping_ids.contains(&m.id))
+            .collect();
+
+    let mut session = session_state.0.lock().await;
+    if !session.try_acquire_sync_lock() {
+        return Err(locale::text("sync.already_running").to_string());
+    }
+    if let Err(e) = session.ensure_connected().await {
+        session.release_sync_lock();
+        return Err(locale::format("sync.ssh_connect_failed", &[("error", &e)]));
+    }
+
+    let result = pull::apply_pull(&mappings, &session).await;
+    session.release_sync_lock();
+
+    log::info!(
+        "SSH pull finished: success={}, pulled_files={}, skipped_files={}, errors={}",
+        result.success,
+        result.synced_files.len(),
+        result.skipped_files.len(),
+        result.errors.len()
+    );
+    let _ = app.emit("ssh-pull-completed", result.clone());
+    Ok(result)
+}
+
 /// Get SSH sync status
 #[tauri::command]
 pub async fn ssh_get_status(state: tauri::State<'_, DbState>) -> Result<SSHStatusResult, String> {
@@ -783,6 +1246,100 @@ pub async fn ssh_get_status(state: tauri::State<'_, DbState>) -> Result<SSHStatu
     })
 }
 
+/// Recent sync runs, newest first (default 50)
+#[tauri::command]
+pub async fn ssh_get_sync_history(
+    state: tauri::State<'_, DbState>,
+    limit: Option<u32>,
+) -> Result<Vec<SSHSyncLogEntry>, String> {
+    let db = state.db();
+    history::get_sync_history(&db, limit.unwrap_or(50) as usize).await
+}
+
+/// Run a command on the remote and stream its output
+///
+/// Returns the exec id immediately; output arrives as `ssh-exec-output` events and the end
+/// as one `ssh-exec-finished` event.
+#[tauri::command]
+pub async fn ssh_exec(
+    state: tauri::State<'_, DbState>,
+    exec_state: tauri::State<'_, ExecState>,
+    app: tauri::AppHandle,
+    connection_id: String,
+    command: String,
+) -> Result<String, String> {
+    if command.trim().is_empty() {
+        return Err("命令不能为空".to_string());
+    }
+    let conn = find_connection(&state, &connection_id).await?;
+    exec::start_exec(&app, &exec_state, &conn, &command).await
+}
+
+/// Cancel a running remote command
+#[tauri::command]
+pub async fn ssh_exec_cancel(
+    exec_state: tauri::State<'_, ExecState>,
+    exec_id: String,
+) -> Result<(), String> {
+    if exec::cancel_exec(&exec_state, &exec_id).await {
+        Ok(())
+    } else {
+        Err(format!("远程命令不存在或已结束: {}", exec_id))
+    }
+}
+
+/// Start forwarding 127.0.0.1:`local_port` to `remote_host:remote_port` on the remote
+///
+/// `local_port` defaults to a free port, `remote_host` to `127.0.0.1` (a server listening
+/// on the remote loopback, e.g. an HTTP MCP server).
+#[tauri::command]
+pub async fn ssh_port_forward_start(
+    state: tauri::State<'_, DbState>,
+    forward_state: tauri::State<'_, PortForwardState>,
+    connection_id: String,
+    local_port: Option<u16>,
+    remote_host: Option<String>,
+    remote_port: u16,
+) -> Result<SSHPortForward, String> {
+    if remote_port == 0 {
+        return Err("远程端口无效".to_string());
+    }
+    let remote_host = remote_host
+        .map(|h| h.trim().to_string())
+        .filter(|h| !h.is_empty())
+        .unwrap_or_else(|| "127.0.0.1".to_string());
+    let conn = find_connection(&state, &connection_id).await?;
+    forward::start_forward(
+        &forward_state,
+        &conn,
+        local_port.unwrap_or(0),
+        &remote_host,
+        remote_port,
+    )
+    .await
+}
+
+/// Stop a port forward and close its SSH connection
+#[tauri::command]
+pub async fn ssh_port_forward_stop(
+    forward_state: tauri::State<'_, PortForwardState>,
+    forward_id: String,
+) -> Result<(), String> {
+    if forward::stop_forward(&forward_state, &forward_id).await {
+        Ok(())
+    } else {
+        Err(format!("端口转发不存在: {}", forward_id))
+    }
+}
+
+/// Status of all port forwards
+#[tauri::command]
+pub async fn ssh_port_forward_status(
+    forward_state: tauri::State<'_, PortForwardState>,
+) -> Result<Vec<SSHPortForward>, String> {
+    Ok(forward::list_forwards(&forward_state).await)
+}
+
 /// Test if a local path exists
 #[tauri::command]
 pub fn ssh_test_local_path(local_path: String) -> Result<bool, String> {
@@ -811,7 +1368,7 @@ async fn backfill_default_mappings(
     mut file_mappings: Vec<SSHFileMapping>,
 ) -> Vec<SSHFileMapping> {
     // Bump this number whenever new default mappings are added.
-    const CURRENT_DEFAULTS_VERSION: u64 = 4;
+    const CURRENT_DEFAULTS_VERSION: u64 = 5;
 
     // Read stored version
     let stored_version: u64 = db
@@ -866,24 +1423,22 @@ async fn backfill_default_mappings(
 }
 
 /// Dynamically resolve config file paths for OpenCode and Oh My OpenAgent.
-pub fn resolve_dynamic_paths(mappings: Vec<SSHFileMapping>) -> Vec<SSHFileMapping> {
-    mappings
-        .into_iter()
-        .map(|mapping| {
-            match mapping.id.as_str() {
-                _ => {}
-            }
-            mapping
-        })
-        .collect()
+/// Apply the connection's remote path rewrite rules (see `path_rewrite`)
+pub fn resolve_dynamic_paths(
+    mappings: Vec<SSHFileMapping>,
+    rewrites: &[SSHPathRewrite],
+) -> Vec<SSHFileMapping> {
+    path_rewrite::apply_rules(mappings, rewrites)
 }
 
+/// Resolve runtime config locations, then apply the path rewrite rules
 pub async fn resolve_dynamic_paths_with_db(
     db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
     mappings: Vec<SSHFileMapping>,
+    rewrites: &[SSHPathRewrite],
 ) -> Vec<SSHFileMapping> {
     let mut resolved = Vec::with_capacity(mappings.len());
-    for mut mapping in resolve_dynamic_paths(mappings) {
+    for mut mapping in mappings {
         match mapping.id.as_str() {
             "opencode-main" => {
                 if let Ok(location) =
@@ -1021,7 +1576,7 @@ pub async fn resolve_dynamic_paths_with_db(
         }
         resolved.push(mapping);
     }
-    resolved
+    resolve_dynamic_paths(resolved, rewrites)
 }
 
 /// Update sync status in database
@@ -1035,10 +1590,10 @@ pub async fn update_sync_status(state: &DbState, result: &SyncResult) -> Result<
         ("error".to_string(), Some(error_msg))
     };
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
     db.query("UPDATE ssh_sync_config SET last_sync_time = $time, last_sync_status = $status, last_sync_error = $error WHERE id = ssh_sync_config:`config`")
-        .bind(("time", now))
+        .bind(("time", now.to_db()))
         .bind(("status", status))
         .bind(("error", error))
         .await
@@ -1060,6 +1615,7 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            exclude_patterns: vec![],
         },
         SSHFileMapping {
             id: "opencode-oh-my".to_string(),
@@ -1070,6 +1626,7 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            exclude_patterns: vec![],
         },
         SSHFileMapping {
             id: "opencode-oh-my-slim".to_string(),
@@ -1080,6 +1637,7 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: false,
             is_pattern: false,
             is_directory: false,
+            exclude_patterns: vec![],
         },
         SSHFileMapping {
             id: "opencode-auth".to_string(),
@@ -1090,6 +1648,7 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            exclude_patterns: vec![],
         },
         SSHFileMapping {
             id: "opencode-plugins".to_string(),
@@ -1100,6 +1659,7 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: true,
             is_pattern: true,
             is_directory: false,
+            exclude_patterns: vec![],
         },
         SSHFileMapping {
             id: "opencode-prompt".to_string(),
@@ -1110,6 +1670,7 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            exclude_patterns: vec![],
         },
         // Claude Code
         SSHFileMapping {
@@ -1121,6 +1682,7 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            exclude_patterns: vec![],
         },
         SSHFileMapping {
             id: "claude-config".to_string(),
@@ -1131,6 +1693,7 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            exclude_patterns: vec![],
         },
         SSHFileMapping {
             id: "claude-prompt".to_string(),
@@ -1141,6 +1704,7 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            exclude_patterns: vec![],
         },
         SSHFileMapping {
             id: "claude-plugins".to_string(),
@@ -1151,6 +1715,7 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: true,
+            exclude_patterns: vec![],
         },
         // Codex
         SSHFileMapping {
@@ -1162,6 +1727,7 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            exclude_patterns: vec![],
         },
         SSHFileMapping {
             id: "codex-config".to_string(),
@@ -1172,6 +1738,7 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            exclude_patterns: vec![],
         },
         SSHFileMapping {
             id: "codex-prompt".to_string(),
@@ -1182,6 +1749,7 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            exclude_patterns: vec![],
         },
         SSHFileMapping {
             id: "codex-plugins".to_string(),
@@ -1192,6 +1760,7 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: true,
+            exclude_patterns: vec![],
         },
         // OpenClaw
         SSHFileMapping {
@@ -1203,6 +1772,30 @@ pub fn default_file_mappings() -> Vec<SSHFileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            exclude_patterns: vec![],
+        },
+        // Cursor
+        SSHFileMapping {
+            id: "cursor-mcp".to_string(),
+            name: "Cursor MCP 配置".to_string(),
+            module: "cursor".to_string(),
+            local_path: "~/.cursor/mcp.json".to_string(),
+            remote_path: "~/.cursor/mcp.json".to_string(),
+            enabled: true,
+            is_pattern: false,
+            is_directory: false,
+            exclude_patterns: vec![],
+        },
+        SSHFileMapping {
+            id: "cursor-rules".to_string(),
+            name: "Cursor 规则目录".to_string(),
+            module: "cursor".to_string(),
+            local_path: "~/.cursor/rules".to_string(),
+            remote_path: "~/.cursor/rules".to_string(),
+            enabled: true,
+            is_pattern: false,
+            is_directory: true,
+            exclude_patterns: vec![],
         },
     ]
 }
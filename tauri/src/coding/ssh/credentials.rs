@@ -0,0 +1,138 @@
+use super::adapter;
+use super::types::SSHConnection;
+use crate::coding::db_id::db_record_id;
+use crate::secrets;
+
+/// Keychain service name; the account is `<connection_id>:<field>`
+const KEYCHAIN_SERVICE: &str = "ai-toolbox.ssh";
+
+const SECRET_FIELDS: [&str; 2] = ["password", "passphrase"];
+
+fn keychain_account(connection_id: &str, field: &str) -> String {
+    format!("{}:{}", connection_id, field)
+}
+
+fn secret_value<'a>(conn: &'a SSHConnection, field: &str) -> &'a str {
+    match field {
+        "password" => &conn.password,
+        _ => &conn.passphrase,
+    }
+}
+
+/// Save a connection's password / passphrase to the OS keychain
+///
+/// Empty values remove the stored entry.
+pub fn store_connection_secrets(conn: &SSHConnection) -> Result<(), String> {
+    for field in SECRET_FIELDS {
+        secrets::write_entry(
+            KEYCHAIN_SERVICE,
+            &keychain_account(&conn.id, field),
+            secret_value(conn, field),
+        )?;
+    }
+    Ok(())
+}
+
+/// Remove a connection's secrets from the OS keychain
+pub fn delete_connection_secrets(connection_id: &str) {
+    for field in SECRET_FIELDS {
+        if let Err(e) =
+            secrets::delete_entry(KEYCHAIN_SERVICE, &keychain_account(connection_id, field))
+        {
+            log::warn!(
+                "Failed to delete SSH {} for connection {}: {}",
+                field,
+                connection_id,
+                e
+            );
+        }
+    }
+}
+
+/// Fill in secrets from the keychain for fields the database no longer holds
+fn fill_connection_secrets(conn: &mut SSHConnection) {
+    for field in SECRET_FIELDS {
+        if !secret_value(conn, field).is_empty() {
+            continue;
+        }
+        match secrets::read_entry(KEYCHAIN_SERVICE, &keychain_account(&conn.id, field)) {
+            Ok(Some(secret)) => match field {
+                "password" => conn.password = secret,
+                _ => conn.passphrase = secret,
+            },
+            Ok(None) => {}
+            Err(e) => log::warn!(
+                "Failed to load SSH {} for connection {}: {}",
+                field,
+                conn.id,
+                e
+            ),
+        }
+    }
+}
+
+/// Build a connection record for the database, moving its secrets to the keychain
+///
+/// When the keychain is unavailable (e.g. no secret service on a headless Linux box)
+/// the secrets are kept in the record so the connection keeps working.
+pub fn connection_to_db_value_secure(conn: &SSHConnection) -> serde_json::Value {
+    match store_connection_secrets(conn) {
+        Ok(()) => adapter::connection_to_db_value(conn, false),
+        Err(e) => {
+            log::warn!(
+                "SSH keychain unavailable, keeping secrets of connection {} in the database: {}",
+                conn.id,
+                e
+            );
+            adapter::connection_to_db_value(conn, true)
+        }
+    }
+}
+
+/// Load connections from database records, resolving secrets from the keychain
+///
+/// Records still holding plaintext secrets (written by older versions) are migrated:
+/// the secrets move to the keychain and are cleared from the database.
+pub async fn load_connections(
+    db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
+    records: Vec<serde_json::Value>,
+) -> Vec<SSHConnection> {
+    let mut connections = Vec::with_capacity(records.len());
+    for record in records {
+        let mut conn = adapter::connection_from_db_value(record);
+        let has_plaintext = !conn.password.is_empty() || !conn.passphrase.is_empty();
+        if has_plaintext {
+            match store_connection_secrets(&conn) {
+                Ok(()) => {
+                    let record_id = db_record_id("ssh_connection", &conn.id);
+                    match db
+                        .query(&format!(
+                            "UPDATE {} SET password = NONE, passphrase = NONE",
+                            record_id
+                        ))
+                        .await
+                    {
+                        Ok(_) => log::info!(
+                            "Migrated SSH secrets of connection {} to the keychain",
+                            conn.id
+                        ),
+                        Err(e) => log::warn!(
+                            "Failed to clear plaintext SSH secrets of connection {}: {}",
+                            conn.id,
+                            e
+                        ),
+                    }
+                }
+                Err(e) => log::warn!(
+                    "Failed to migrate SSH secrets of connection {} to the keychain: {}",
+                    conn.id,
+                    e
+                ),
+            }
+        }
+        // A connection may hold only one of the two secrets in plaintext
+        fill_connection_secrets(&mut conn);
+        connections.push(conn);
+    }
+    connections
+}
@@ -0,0 +1,172 @@
+//! Streaming remote command console
+//!
+//! `ssh_exec` runs a command through the remote login shell on its own SSH connection and
+//! streams output line by line as `ssh-exec-output` events, then emits one `ssh-exec-finished`
+//! event. Every event carries the exec id returned by `ssh_exec`, so listeners should subscribe
+//! before invoking it. A running command is cancelled by closing its channel.
+
+use std::collections::HashMap;
+
+use russh::ChannelMsg;
+use tauri::{Emitter, Manager};
+use tokio::sync::{oneshot, Mutex};
+
+use super::bootstrap::login_shell_command;
+use super::session::SshSession;
+use super::types::{SSHConnection, SSHExecFinished, SSHExecOutput};
+use crate::coding::db_id::db_new_id;
+
+/// 运行中的远程命令（exec id -> 取消信号），注册到 Tauri State
+#[derive(Default)]
+pub struct ExecState(Mutex<HashMap<String, oneshot::Sender<()>>>);
+
+/// Splits streamed bytes into lines; a trailing partial line is kept until more data arrives
+#[derive(Default)]
+struct LineBuffer(Vec<u8>);
+
+impl LineBuffer {
+    fn push(&mut self, data: &[u8]) -> Vec<String> {
+        self.0.extend_from_slice(data);
+        let mut lines = Vec::new();
+        while let Some(pos) = self.0.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.0.drain(..=pos).collect();
+            lines.push(decode_line(&line));
+        }
+        lines
+    }
+
+    fn finish(self) -> Option<String> {
+        (!self.0.is_empty()).then(|| decode_line(&self.0))
+    }
+}
+
+fn decode_line(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .trim_end_matches(['\n', '\r'])
+        .to_string()
+}
+
+fn emit_lines(app: &tauri::AppHandle, exec_id: &str, stream: &str, lines: Vec<String>) {
+    for line in lines {
+        let _ = app.emit(
+            "ssh-exec-output",
+            SSHExecOutput {
+                exec_id: exec_id.to_string(),
+                stream: stream.to_string(),
+                line,
+            },
+        );
+    }
+}
+
+/// 建立专用连接并启动命令，返回 exec id；输出通过事件推送
+pub async fn start_exec(
+    app: &tauri::AppHandle,
+    state: &ExecState,
+    conn: &SSHConnection,
+    command: &str,
+) -> Result<String, String> {
+    let mut session = SshSession::new();
+    session.connect(conn).await?;
+    let mut channel = match session
+        .open_exec_channel(&login_shell_command(command))
+        .await
+    {
+        Ok(channel) => channel,
+        Err(e) => {
+            session.disconnect().await;
+            return Err(e);
+        }
+    };
+
+    let exec_id = db_new_id();
+    let (cancel_tx, mut cancel_rx) = oneshot::channel();
+    state.0.lock().await.insert(exec_id.clone(), cancel_tx);
+    log::info!(
+        "SSH exec started: id={}, connection={}, command={}",
+        exec_id,
+        conn.name,
+        command
+    );
+
+    let app = app.clone();
+    let id = exec_id.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut stdout = LineBuffer::default();
+        let mut stderr = LineBuffer::default();
+        let mut exit_code: Option<u32> = None;
+        let mut cancelled = false;
+
+        loop {
+            tokio::select! {
+                _ = &mut cancel_rx => {
+                    cancelled = true;
+                    let _ = channel.close().await;
+                    break;
+                }
+                msg = channel.wait() => match msg {
+                    Some(ChannelMsg::Data { ref data }) => {
+                        emit_lines(&app, &id, "stdout", stdout.push(data));
+                    }
+                    // SSH_EXTENDED_DATA_STDERR
+                    Some(ChannelMsg::ExtendedData { ref data, ext: 1 }) => {
+                        emit_lines(&app, &id, "stderr", stderr.push(data));
+                    }
+                    Some(ChannelMsg::ExitStatus { exit_status }) => exit_code = Some(exit_status),
+                    Some(_) => {}
+                    None => break,
+                },
+            }
+        }
+
+        emit_lines(&app, &id, "stdout", stdout.finish().into_iter().collect());
+        emit_lines(&app, &id, "stderr", stderr.finish().into_iter().collect());
+        app.state::<ExecState>().0.lock().await.remove(&id);
+        session.disconnect().await;
+
+        log::info!(
+            "SSH exec finished: id={}, exit_code={:?}, cancelled={}",
+            id,
+            exit_code,
+            cancelled
+        );
+        let _ = app.emit(
+            "ssh-exec-finished",
+            SSHExecFinished {
+                exec_id: id,
+                exit_code,
+                cancelled,
+            },
+        );
+    });
+
+    Ok(exec_id)
+}
+
+/// 取消运行中的命令，返回是否存在该命令
+pub async fn cancel_exec(state: &ExecState, exec_id: &str) -> bool {
+    match state.0.lock().await.remove(exec_id) {
+        Some(cancel_tx) => {
+            let _ = cancel_tx.send(());
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_buffer_splits_across_chunks() {
+        let mut buffer = LineBuffer::default();
+        assert!(buffer.push(b"open").is_empty());
+        assert_eq!(
+            buffer.push(b"code 1.2.3\r\nsecond\nthi"),
+            vec!["opencode 1.2.3".to_string(), "second".to_string()]
+        );
+        assert_eq!(buffer.finish(), Some("thi".to_string()));
+        assert_eq!(LineBuffer::default().finish(), None);
+    }
+}
@@ -0,0 +1,206 @@
+//! Managed SSH port forwarding (`ssh -L`)
+//!
+//! Each forward owns a dedicated SSH connection, so long-lived tunnels are independent of
+//! the sync session (which is locked during syncs and reconnected on health checks).
+//! The local side only listens on 127.0.0.1.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use chrono::Local;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Mutex;
+
+use super::session::SshSession;
+use super::types::{SSHConnection, SSHPortForward};
+use crate::coding::db_id::db_new_id;
+
+struct ActiveForward {
+    info: SSHPortForward,
+    session: Arc<SshSession>,
+    active_connections: Arc<AtomicU32>,
+    accept_task: tauri::async_runtime::JoinHandle<()>,
+}
+
+impl ActiveForward {
+    fn snapshot(&self) -> SSHPortForward {
+        let mut info = self.info.clone();
+        info.active_connections = self.active_connections.load(Ordering::Relaxed);
+        if !self.session.is_alive() {
+            info.status = "disconnected".to_string();
+        }
+        info
+    }
+}
+
+/// 全局端口转发状态，注册到 Tauri State
+#[derive(Default)]
+pub struct PortForwardState(Mutex<HashMap<String, ActiveForward>>);
+
+async fn bridge(
+    mut local: TcpStream,
+    session: Arc<SshSession>,
+    remote_host: String,
+    remote_port: u16,
+    active_connections: Arc<AtomicU32>,
+) {
+    let originator_port = local.peer_addr().map(|addr| addr.port()).unwrap_or(0);
+    let channel = match session
+        .open_direct_tcpip(&remote_host, remote_port, originator_port)
+        .await
+    {
+        Ok(channel) => channel,
+        Err(e) => {
+            log::warn!("{}", e);
+            return;
+        }
+    };
+
+    active_connections.fetch_add(1, Ordering::Relaxed);
+    let mut remote = channel.into_stream();
+    if let Err(e) = tokio::io::copy_bidirectional(&mut local, &mut remote).await {
+        log::debug!(
+            "SSH port forward stream closed with error: {}:{}: {}",
+            remote_host,
+            remote_port,
+            e
+        );
+    }
+    active_connections.fetch_sub(1, Ordering::Relaxed);
+}
+
+/// 建立专用连接并开始监听本地端口；`local_port` 为 0 时由系统分配
+pub async fn start_forward(
+    state: &PortForwardState,
+    conn: &SSHConnection,
+    local_port: u16,
+    remote_host: &str,
+    remote_port: u16,
+) -> Result<SSHPortForward, String> {
+    let listener = TcpListener::bind(("127.0.0.1", local_port))
+        .await
+        .map_err(|e| format!("监听本地端口 {} 失败: {}", local_port, e))?;
+    let local_port = listener
+        .local_addr()
+        .map(|addr| addr.port())
+        .map_err(|e| format!("读取本地端口失败: {}", e))?;
+
+    let mut session = SshSession::new();
+    session.connect(conn).await?;
+    let session = Arc::new(session);
+
+    let info = SSHPortForward {
+        id: db_new_id(),
+        connection_id: conn.id.clone(),
+        connection_name: conn.name.clone(),
+        local_port,
+        remote_host: remote_host.to_string(),
+        remote_port,
+        status: "running".to_string(),
+        active_connections: 0,
+        started_at: Local::now().to_rfc3339(),
+    };
+
+    let active_connections = Arc::new(AtomicU32::new(0));
+    let accept_task = {
+        let session = session.clone();
+        let active_connections = active_connections.clone();
+        let remote_host = remote_host.to_string();
+        tauri::async_runtime::spawn(async move {
+            // 桥接任务归属 JoinSet，停止转发时随监听任务一起取消
+            let mut bridges = tokio::task::JoinSet::new();
+            loop {
+                tokio::select! {
+                    accepted = listener.accept() => match accepted {
+                        Ok((local, _)) => {
+                            bridges.spawn(bridge(
+                                local,
+                                session.clone(),
+                                remote_host.clone(),
+                                remote_port,
+                                active_connections.clone(),
+                            ));
+                        }
+                        Err(e) => log::warn!("SSH port forward accept failed: {}", e),
+                    },
+                    Some(_) = bridges.join_next(), if !bridges.is_empty() => {}
+                }
+            }
+        })
+    };
+
+    log::info!(
+        "SSH port forward started: id={}, connection={}, 127.0.0.1:{} -> {}:{}",
+        info.id,
+        conn.name,
+        local_port,
+        remote_host,
+        remote_port
+    );
+    state.0.lock().await.insert(
+        info.id.clone(),
+        ActiveForward {
+            info: info.clone(),
+            session,
+            active_connections,
+            accept_task,
+        },
+    );
+    Ok(info)
+}
+
+async fn shutdown(forward: ActiveForward) {
+    forward.accept_task.abort();
+    let _ = forward.accept_task.await;
+    log::info!(
+        "SSH port forward stopped: id={}, local_port={}",
+        forward.info.id,
+        forward.info.local_port
+    );
+    // 桥接任务可能尚未释放引用；那种情况下最后一个引用释放时 SshSession 会丢弃连接
+    if let Ok(mut session) = Arc::try_unwrap(forward.session) {
+        session.disconnect().await;
+    }
+}
+
+/// 停止端口转发，返回是否存在该转发
+pub async fn stop_forward(state: &PortForwardState, forward_id: &str) -> bool {
+    let forward = state.0.lock().await.remove(forward_id);
+    match forward {
+        Some(forward) => {
+            shutdown(forward).await;
+            true
+        }
+        None => false,
+    }
+}
+
+/// 停止某个连接的全部端口转发（删除连接时调用）
+pub async fn stop_connection_forwards(state: &PortForwardState, connection_id: &str) {
+    let forwards: Vec<ActiveForward> = {
+        let mut forwards = state.0.lock().await;
+        let ids: Vec<String> = forwards
+            .values()
+            .filter(|f| f.info.connection_id == connection_id)
+            .map(|f| f.info.id.clone())
+            .collect();
+        ids.iter().filter_map(|id| forwards.remove(id)).collect()
+    };
+    for forward in forwards {
+        shutdown(forward).await;
+    }
+}
+
+/// 当前所有端口转发的状态
+pub async fn list_forwards(state: &PortForwardState) -> Vec<SSHPortForward> {
+    let mut forwards: Vec<SSHPortForward> = state
+        .0
+        .lock()
+        .await
+        .values()
+        .map(ActiveForward::snapshot)
+        .collect();
+    forwards.sort_by(|a, b| a.started_at.cmp(&b.started_at));
+    forwards
+}
@@ -0,0 +1,118 @@
+//! SSH 同步历史
+//!
+//! 每次完整同步写入一条 `ssh_sync_log` 记录，只保留最近 `MAX_SYNC_LOG_ENTRIES` 条。
+
+use serde_json::{json, Value};
+
+use super::types::{SSHSyncLogEntry, SyncResult};
+use crate::coding::db_id::{db_extract_id, db_new_id, db_record_id};
+use crate::db_timestamp::{with_datetimes, Timestamp};
+
+type Db = surrealdb::Surreal<surrealdb::engine::local::Db>;
+
+/// Number of sync runs kept in the log
+const MAX_SYNC_LOG_ENTRIES: usize = 200;
+
+fn string_list(record: &Value, key: &str) -> Vec<String> {
+    record
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn entry_from_db_value(record: &Value) -> SSHSyncLogEntry {
+    let str_field = |key: &str| {
+        record
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    };
+    SSHSyncLogEntry {
+        id: db_extract_id(record),
+        timestamp: Timestamp::from_db(record, "timestamp"),
+        connection_id: str_field("connection_id").unwrap_or_default(),
+        connection_name: str_field("connection_name"),
+        module: str_field("module"),
+        success: record
+            .get("success")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        synced_files: string_list(record, "synced_files"),
+        skipped_files: string_list(record, "skipped_files"),
+        errors: string_list(record, "errors"),
+        duration_ms: record
+            .get("duration_ms")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0),
+    }
+}
+
+/// 记录一次同步，并删除超出保留数量的旧记录
+pub async fn record_sync_run(
+    db: &Db,
+    connection_id: &str,
+    connection_name: Option<&str>,
+    module: Option<&str>,
+    result: &SyncResult,
+    duration_ms: u64,
+) -> Result<(), String> {
+    let record_id = db_record_id("ssh_sync_log", &db_new_id());
+    db.query(&format!("CREATE {} CONTENT $data", record_id))
+        .bind((
+            "data",
+            with_datetimes(json!({
+                "timestamp": Timestamp::now().to_string(),
+                "connection_id": connection_id,
+                "connection_name": connection_name,
+                "module": module,
+                "success": result.success,
+                "synced_files": result.synced_files,
+                "skipped_files": result.skipped_files,
+                "errors": result.errors,
+                "duration_ms": duration_ms,
+            })),
+        ))
+        .await
+        .map_err(|e| format!("Failed to record SSH sync history: {}", e))?;
+
+    prune_sync_log(db).await
+}
+
+async fn prune_sync_log(db: &Db) -> Result<(), String> {
+    let stale: Vec<Value> = db
+        .query("SELECT type::string(id) as id, timestamp FROM ssh_sync_log ORDER BY timestamp DESC START $keep")
+        .bind(("keep", MAX_SYNC_LOG_ENTRIES))
+        .await
+        .map_err(|e| format!("Failed to query SSH sync history: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to parse SSH sync history: {}", e))?;
+
+    for record in &stale {
+        let id = db_extract_id(record);
+        if id.is_empty() {
+            continue;
+        }
+        db.query(&format!("DELETE {}", db_record_id("ssh_sync_log", &id)))
+            .await
+            .map_err(|e| format!("Failed to prune SSH sync history: {}", e))?;
+    }
+    Ok(())
+}
+
+/// 最近的同步记录，按时间倒序
+pub async fn get_sync_history(db: &Db, limit: usize) -> Result<Vec<SSHSyncLogEntry>, String> {
+    let records: Vec<Value> = db
+        .query("SELECT *, type::string(id) as id FROM ssh_sync_log ORDER BY timestamp DESC LIMIT $limit")
+        .bind(("limit", limit))
+        .await
+        .map_err(|e| format!("Failed to query SSH sync history: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to parse SSH sync history: {}", e))?;
+    Ok(records.iter().map(entry_from_db_value).collect())
+}
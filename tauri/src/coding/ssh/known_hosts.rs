@@ -0,0 +1,260 @@
+//! SSH 主机密钥校验（known hosts）
+//!
+//! 已信任的主机密钥指纹保存在 `ssh_known_host` 表中，并缓存在内存里供 russh handler 同步读取。
+//! 未知主机默认拒绝连接，由 UI 展示指纹并让用户确认信任；密钥变化时返回明确的错误。
+//! 升级前已存在的连接在首次连接时自动信任一次（等同 accept-new），避免升级后同步中断。
+//! 这些主机与迁移版本一起保存在 `ssh_sync_config:known_hosts_version` 中，重启后仍然有效，直到被信任。
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{OnceLock, RwLock};
+
+use russh::keys::ssh_key;
+use serde_json::json;
+
+use super::types::{SSHConnection, SSHHostKeyInfo};
+use crate::coding::db_id::db_record_id;
+use crate::db_timestamp::{with_datetimes, Timestamp};
+
+type Db = surrealdb::Surreal<surrealdb::engine::local::Db>;
+
+/// Error prefixes the UI matches on to offer "trust" / "re-trust" actions
+pub const HOST_KEY_UNKNOWN_PREFIX: &str = "HOST_KEY_UNKNOWN";
+pub const HOST_KEY_CHANGED_PREFIX: &str = "HOST_KEY_CHANGED";
+
+/// Bump when the migration below changes
+const KNOWN_HOSTS_VERSION: u64 = 1;
+const KNOWN_HOSTS_VERSION_RECORD: &str = "ssh_sync_config:`known_hosts_version`";
+
+#[derive(Default)]
+struct KnownHosts {
+    loaded: bool,
+    /// "host:port" -> fingerprint
+    fingerprints: HashMap<String, String>,
+    /// Hosts of connections created before host key checking existed
+    legacy_hosts: HashSet<String>,
+}
+
+static KNOWN_HOSTS: OnceLock<RwLock<KnownHosts>> = OnceLock::new();
+static DB: OnceLock<Db> = OnceLock::new();
+
+fn storage() -> &'static RwLock<KnownHosts> {
+    KNOWN_HOSTS.get_or_init(|| RwLock::new(KnownHosts::default()))
+}
+
+fn host_key(host: &str, port: u16) -> String {
+    format!("{}:{}", host.trim().to_lowercase(), port)
+}
+
+fn record_id(host: &str, port: u16) -> String {
+    db_record_id("ssh_known_host", &hex::encode(host_key(host, port)))
+}
+
+/// SHA256 fingerprint in OpenSSH format (`SHA256:...`)
+pub fn fingerprint(key: &ssh_key::PublicKey) -> String {
+    key.fingerprint(ssh_key::HashAlg::Sha256).to_string()
+}
+
+/// Load trusted host keys once; on the first run also mark existing connections as legacy
+pub async fn ensure_loaded(db: &Db, connections: &[SSHConnection]) {
+    let _ = DB.set(db.clone());
+    if storage().read().map(|s| s.loaded).unwrap_or(false) {
+        return;
+    }
+
+    let records: Vec<serde_json::Value> = match db.query("SELECT * FROM ssh_known_host").await {
+        Ok(mut response) => response.take(0).unwrap_or_default(),
+        Err(e) => {
+            log::warn!("Failed to load SSH known hosts: {}", e);
+            return;
+        }
+    };
+    let fingerprints: HashMap<String, String> = records
+        .iter()
+        .filter_map(|record| {
+            let host = record.get("host")?.as_str()?;
+            let port = record.get("port")?.as_u64()? as u16;
+            let fingerprint = record.get("fingerprint")?.as_str()?;
+            Some((host_key(host, port), fingerprint.to_string()))
+        })
+        .collect();
+
+    let version_record = db
+        .query(format!(
+            "SELECT version, legacy_hosts FROM {} LIMIT 1",
+            KNOWN_HOSTS_VERSION_RECORD
+        ))
+        .await
+        .ok()
+        .and_then(|mut r| r.take::<Vec<serde_json::Value>>(0).ok())
+        .and_then(|records| records.first().cloned())
+        .unwrap_or_default();
+    let stored_version = version_record
+        .get("version")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0);
+    let legacy_hosts: HashSet<String> = if stored_version < KNOWN_HOSTS_VERSION {
+        let legacy_hosts: HashSet<String> = connections
+            .iter()
+            .map(|c| host_key(&c.host, c.port))
+            .filter(|key| !fingerprints.contains_key(key))
+            .collect();
+        // Saved with the version, so hosts not connected to before a restart stay legacy
+        if let Err(e) = db
+            .query(format!(
+                "UPSERT {} CONTENT {{ version: $v, legacy_hosts: $hosts }}",
+                KNOWN_HOSTS_VERSION_RECORD
+            ))
+            .bind(("v", KNOWN_HOSTS_VERSION))
+            .bind(("hosts", legacy_hosts.iter().cloned().collect::<Vec<_>>()))
+            .await
+            .and_then(|response| response.check())
+        {
+            log::warn!("Failed to save SSH known hosts version: {}", e);
+        }
+        legacy_hosts
+    } else {
+        version_record
+            .get("legacy_hosts")
+            .and_then(|v| v.as_array())
+            .map(|hosts| {
+                hosts
+                    .iter()
+                    .filter_map(|host| host.as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    if let Ok(mut known) = storage().write() {
+        known.fingerprints = fingerprints;
+        known.legacy_hosts = legacy_hosts;
+        known.loaded = true;
+    }
+}
+
+/// Persist a trusted host key and update the in-memory cache
+pub async fn trust_host_key(
+    db: &Db,
+    host: &str,
+    port: u16,
+    key_type: &str,
+    fingerprint: &str,
+) -> Result<(), String> {
+    db.query(&format!("UPSERT {} CONTENT $data", record_id(host, port)))
+        .bind((
+            "data",
+            with_datetimes(json!({
+                "host": host.trim().to_lowercase(),
+                "port": port,
+                "key_type": key_type,
+                "fingerprint": fingerprint,
+                "accepted_at": Timestamp::now().to_string(),
+            })),
+        ))
+        .await
+        .map_err(|e| format!("Failed to save SSH host key: {}", e))?;
+
+    let key = host_key(host, port);
+    let was_legacy = match storage().write() {
+        Ok(mut known) => {
+            known
+                .fingerprints
+                .insert(key.clone(), fingerprint.to_string());
+            known.legacy_hosts.remove(&key)
+        }
+        Err(_) => false,
+    };
+    if was_legacy {
+        db.query(format!(
+            "UPDATE {} SET legacy_hosts -= $host",
+            KNOWN_HOSTS_VERSION_RECORD
+        ))
+        .bind(("host", key))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to save SSH known hosts: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Forget a trusted host key (the next connection asks again)
+pub async fn forget_host_key(db: &Db, host: &str, port: u16) -> Result<(), String> {
+    db.query(&format!("DELETE {}", record_id(host, port)))
+        .await
+        .map_err(|e| format!("Failed to delete SSH host key: {}", e))?;
+    if let Ok(mut known) = storage().write() {
+        known.fingerprints.remove(&host_key(host, port));
+    }
+    Ok(())
+}
+
+/// Check a server key against the trusted fingerprints
+///
+/// Legacy hosts are trusted on first sight and persisted in the background.
+pub fn verify(host: &str, port: u16, key: &ssh_key::PublicKey) -> SSHHostKeyInfo {
+    let key_name = host_key(host, port);
+    let fingerprint = fingerprint(key);
+    let key_type = key.algorithm().as_str().to_string();
+    let mut info = SSHHostKeyInfo {
+        host: host.to_string(),
+        port,
+        key_type: key_type.clone(),
+        fingerprint: fingerprint.clone(),
+        known_fingerprint: None,
+        status: "unknown".to_string(),
+    };
+
+    let (known, legacy) = match storage().read() {
+        Ok(known) => (
+            known.fingerprints.get(&key_name).cloned(),
+            known.legacy_hosts.contains(&key_name),
+        ),
+        Err(_) => (None, false),
+    };
+
+    match known {
+        Some(known) if known == fingerprint => info.status = "trusted".to_string(),
+        Some(known) => {
+            info.status = "changed".to_string();
+            info.known_fingerprint = Some(known);
+        }
+        None if legacy => {
+            info.status = "trusted".to_string();
+            log::info!(
+                "Trusting host key of existing SSH connection on first use: {} {}",
+                key_name,
+                fingerprint
+            );
+            if let Some(db) = DB.get().cloned() {
+                let host = host.to_string();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = trust_host_key(&db, &host, port, &key_type, &fingerprint).await
+                    {
+                        log::warn!("{}", e);
+                    }
+                });
+            }
+        }
+        None => {}
+    }
+    info
+}
+
+/// Human readable connection error for a rejected host key
+pub fn rejection_error(info: &SSHHostKeyInfo) -> String {
+    if info.status == "changed" {
+        format!(
+            "{}: {}:{} 的主机密钥已变更（已信任 {}，当前 {}）。可能存在中间人攻击；如确认服务器已重装，请重新信任该主机密钥",
+            HOST_KEY_CHANGED_PREFIX,
+            info.host,
+            info.port,
+            info.known_fingerprint.as_deref().unwrap_or(""),
+            info.fingerprint
+        )
+    } else {
+        format!(
+            "{}: 首次连接 {}:{}，主机密钥 {} {}。请确认指纹后信任该主机",
+            HOST_KEY_UNKNOWN_PREFIX, info.host, info.port, info.key_type, info.fingerprint
+        )
+    }
+}
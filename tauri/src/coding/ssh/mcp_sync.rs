@@ -3,18 +3,25 @@
 //! Syncs MCP server configurations to remote Linux server for all MCP-enabled tools:
 //! - Claude Code: directly edit ~/.claude.json mcpServers field
 //! - OpenCode/Codex: sync config files via file mappings
+//!
+//! When the active connection has `redact_secrets` set, API keys and tokens are replaced
+//! with a placeholder in everything written to the remote (see `redact`). Config files are
+//! redacted locally before they are uploaded.
 
 use log::info;
 use serde_json::Value;
 use tauri::{AppHandle, Emitter};
 
 use super::commands::resolve_dynamic_paths_with_db;
+use super::redact;
 use super::session::SshSession;
 use super::sync::{read_remote_file, sync_mappings, write_remote_file};
 use super::types::{SSHFileMapping, SyncProgress};
 use crate::coding::mcp::command_normalize;
 use crate::coding::mcp::mcp_store;
+use crate::coding::mcp::secrets;
 use crate::coding::runtime_location;
+use crate::locale;
 use crate::DbState;
 
 /// Get file mappings from database
@@ -67,7 +74,7 @@ pub async fn sync_mcp_to_ssh(
             current_item: "Claude Code MCP".to_string(),
             current: 1,
             total: 2,
-            message: "MCP 同步: Claude Code...".to_string(),
+            message: locale::text("sync.mcp_claude").to_string(),
         },
     );
 
@@ -76,6 +83,8 @@ pub async fn sync_mcp_to_ssh(
     let claude_servers: Vec<_> = servers
         .iter()
         .filter(|s| s.enabled_tools.contains(&"claude_code".to_string()))
+        // Project-scoped servers belong to local project paths, not the remote global config
+        .filter(|s| s.project_paths.is_empty())
         .collect();
     info!(
         "MCP SSH sync server summary: total_servers={}, claude_servers={}",
@@ -83,14 +92,15 @@ pub async fn sync_mcp_to_ssh(
         claude_servers.len()
     );
 
-    if let Err(e) = sync_mcp_to_ssh_claude(state, session, &claude_servers).await {
+    let redact_secrets = config.redact_secrets();
+    if let Err(e) = sync_mcp_to_ssh_claude(state, session, &claude_servers, redact_secrets).await {
         log::warn!("Skipped claude.json MCP sync: {}", e);
         all_errors.push(format!("Claude Code: {}", e));
         let _ = app.emit(
             "ssh-sync-warning",
-            format!(
-                "SSH ~/.claude.json 同步已跳过：文件解析失败，请检查该文件格式是否正确。({})",
-                e
+            locale::format(
+                "sync.claude_json_skipped",
+                &[("target", &"SSH"), ("error", &e)],
             ),
         );
     }
@@ -103,7 +113,7 @@ pub async fn sync_mcp_to_ssh(
             current_item: "OpenCode/Codex MCP".to_string(),
             current: 2,
             total: 2,
-            message: "MCP 同步: OpenCode/Codex...".to_string(),
+            message: locale::text("sync.mcp_opencode_codex").to_string(),
         },
     );
 
@@ -121,7 +131,12 @@ pub async fn sync_mcp_to_ssh(
             );
 
             if !mcp_mappings.is_empty() {
-                let resolved = resolve_dynamic_paths_with_db(&state.db(), mcp_mappings).await;
+                let resolved = resolve_dynamic_paths_with_db(
+                    &state.db(),
+                    mcp_mappings,
+                    config.path_rewrites(),
+                )
+                .await;
                 for mapping in &resolved {
                     log::trace!(
                         "MCP SSH sync mapping resolved: id={}, name={}, module={}, local_path={}, remote_path={}",
@@ -132,7 +147,14 @@ pub async fn sync_mcp_to_ssh(
                         mapping.remote_path
                     );
                 }
-                let result = sync_mappings(&resolved, session, None).await;
+                let result = sync_mappings(
+                    &resolved,
+                    session,
+                    None,
+                    config.incremental_dir_sync,
+                    redact_secrets,
+                )
+                .await;
                 info!(
                     "MCP SSH sync file mapping result: synced_files={}, skipped_files={}, errors={}",
                     result.synced_files.len(),
@@ -145,7 +167,7 @@ pub async fn sync_mcp_to_ssh(
                     all_errors.push(format!("OpenCode/Codex: {}", msg));
                     let _ = app.emit(
                         "ssh-sync-warning",
-                        format!("OpenCode/Codex 配置同步部分失败：{}", msg),
+                        locale::format("sync.config_partially_failed", &[("error", &msg)]),
                     );
                 }
 
@@ -184,7 +206,7 @@ pub async fn sync_mcp_to_ssh(
             all_errors.push(format!("OpenCode/Codex: {}", e));
             let _ = app.emit(
                 "ssh-sync-warning",
-                format!("OpenCode/Codex MCP 同步已跳过：{}", e),
+                locale::format("sync.opencode_codex_mcp_skipped", &[("error", &e)]),
             );
         }
     }
@@ -209,6 +231,7 @@ async fn sync_mcp_to_ssh_claude(
     state: &DbState,
     session: &SshSession,
     servers: &[&crate::coding::mcp::types::McpServer],
+    redact_secrets: bool,
 ) -> Result<(), String> {
     let db = state.db();
     let config_path = runtime_location::get_claude_wsl_claude_json_path_async(&db).await;
@@ -237,15 +260,21 @@ async fn sync_mcp_to_ssh_claude(
     // Build mcpServers object
     let mut mcp_servers = serde_json::Map::new();
     for server in servers {
-        let server_config = build_standard_server_config(server);
+        let server = secrets::resolve_server_secrets(server)?;
+        let server_config = build_standard_server_config(&server);
         mcp_servers.insert(server.name.clone(), server_config);
     }
 
+    let mut mcp_servers = Value::Object(mcp_servers);
+    if redact_secrets {
+        redact::redact_json_value(&mut mcp_servers);
+    }
+
     // Update only mcpServers field
     config
         .as_object_mut()
         .ok_or("Remote claude.json is not a JSON object")?
-        .insert("mcpServers".to_string(), Value::Object(mcp_servers));
+        .insert("mcpServers".to_string(), mcp_servers);
 
     // Write back
     let content = serde_json::to_string_pretty(&config)
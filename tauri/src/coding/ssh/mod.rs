@@ -1,13 +1,25 @@
 mod adapter;
+mod bootstrap;
 mod commands;
+mod credentials;
+mod exec;
+mod forward;
+mod history;
 pub mod key_file;
+mod known_hosts;
 mod mcp_sync;
+mod path_rewrite;
+mod plan;
+mod pull;
+mod redact;
 mod session;
 mod skills_sync;
 mod sync;
 mod types;
 
 pub use commands::*;
+pub use exec::ExecState;
+pub use forward::PortForwardState;
 pub use mcp_sync::sync_mcp_to_ssh;
 pub use session::*;
 pub use skills_sync::sync_skills_to_ssh;
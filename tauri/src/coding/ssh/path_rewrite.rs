@@ -0,0 +1,126 @@
+//! Remote path rewrite rules
+//!
+//! A connection can carry rules that rewrite the remote path of every mapping, e.g. to map
+//! macOS locations (`~/Library/Application Support/...`) to Linux XDG ones (`~/.config/...`).
+//! Plain rules replace a path prefix; regex rules use `Regex::replace` with `$1`-style
+//! capture references. Rules run in order, each on the output of the previous one.
+
+use regex::Regex;
+
+use super::types::{SSHFileMapping, SSHPathRewrite};
+
+enum CompiledRule<'a> {
+    Prefix(&'a str, &'a str),
+    Regex(Regex, &'a str),
+}
+
+fn compile(rule: &SSHPathRewrite) -> Result<CompiledRule<'_>, String> {
+    if rule.is_regex {
+        Regex::new(&rule.pattern)
+            .map(|re| CompiledRule::Regex(re, &rule.replacement))
+            .map_err(|e| format!("无效的路径重写正则 '{}': {}", rule.pattern, e))
+    } else {
+        Ok(CompiledRule::Prefix(&rule.pattern, &rule.replacement))
+    }
+}
+
+/// Check that every rule has a pattern and regex rules compile
+pub fn validate_rules(rules: &[SSHPathRewrite]) -> Result<(), String> {
+    for rule in rules {
+        if rule.pattern.trim().is_empty() {
+            return Err("路径重写规则的匹配内容不能为空".to_string());
+        }
+        compile(rule)?;
+    }
+    Ok(())
+}
+
+fn rewrite_path(path: &str, rules: &[CompiledRule]) -> String {
+    let mut path = path.to_string();
+    for rule in rules {
+        match rule {
+            CompiledRule::Prefix(prefix, replacement) => {
+                if let Some(rest) = path.strip_prefix(*prefix) {
+                    path = format!("{}{}", replacement, rest);
+                }
+            }
+            CompiledRule::Regex(re, replacement) => {
+                path = re.replace(&path, *replacement).into_owned();
+            }
+        }
+    }
+    path
+}
+
+/// Apply the rules to the remote path of each mapping; invalid rules are skipped
+pub fn apply_rules(mappings: Vec<SSHFileMapping>, rules: &[SSHPathRewrite]) -> Vec<SSHFileMapping> {
+    if rules.is_empty() {
+        return mappings;
+    }
+    let compiled: Vec<CompiledRule> = rules
+        .iter()
+        .filter_map(|rule| {
+            compile(rule)
+                .map_err(|e| log::warn!("SSH path rewrite rule skipped: {}", e))
+                .ok()
+        })
+        .collect();
+
+    mappings
+        .into_iter()
+        .map(|mut mapping| {
+            let rewritten = rewrite_path(&mapping.remote_path, &compiled);
+            if rewritten != mapping.remote_path {
+                log::trace!(
+                    "SSH remote path rewritten: id={}, from={}, to={}",
+                    mapping.id,
+                    mapping.remote_path,
+                    rewritten
+                );
+                mapping.remote_path = rewritten;
+            }
+            mapping
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str, replacement: &str, is_regex: bool) -> SSHPathRewrite {
+        SSHPathRewrite {
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+            is_regex,
+        }
+    }
+
+    #[test]
+    fn prefix_and_regex_rules_apply_in_order() {
+        let rules = [
+            rule("~/Library/Application Support/", "~/.config/", false),
+            rule(
+                r"^~/\.config/(\w+)/settings\.json$",
+                "~/.config/$1/config.json",
+                true,
+            ),
+        ];
+        let compiled: Vec<CompiledRule> = rules.iter().map(|r| compile(r).unwrap()).collect();
+        assert_eq!(
+            rewrite_path("~/Library/Application Support/foo/settings.json", &compiled),
+            "~/.config/foo/config.json"
+        );
+        assert_eq!(
+            rewrite_path("~/.codex/config.toml", &compiled),
+            "~/.codex/config.toml"
+        );
+    }
+
+    #[test]
+    fn validate_rejects_empty_and_invalid_rules() {
+        assert!(validate_rules(&[rule("", "x", false)]).is_err());
+        assert!(validate_rules(&[rule("(", "x", true)]).is_err());
+        assert!(validate_rules(&[rule("~/Library/", "~/.config/", false)]).is_ok());
+    }
+}
@@ -0,0 +1,173 @@
+use super::session::SshSession;
+use super::sync::{
+    expand_local_path, is_excluded_file, local_dir_sha256, local_sha256, remote_dir_sha256,
+    remote_file_sha256, ExcludeFilter,
+};
+use super::types::{SSHFileMapping, SyncResult};
+use std::path::Path;
+
+// ============================================================================
+// Dry Run (只评估，不上传)
+// ============================================================================
+
+enum PlannedAction {
+    Upload(String),
+    Skip(String),
+}
+
+async fn plan_file(
+    local_file: &Path,
+    remote_path: &str,
+    session: &SshSession,
+) -> Result<PlannedAction, String> {
+    let display = format!("{} -> {}", local_file.display(), remote_path);
+    let size = std::fs::metadata(local_file)
+        .map_err(|e| format!("读取本地文件失败: {}", e))?
+        .len();
+    let local_hash = local_sha256(local_file)?;
+    Ok(match remote_file_sha256(session, remote_path).await? {
+        None => PlannedAction::Upload(format!("[create] {} ({} bytes)", display, size)),
+        Some(remote_hash) if remote_hash == local_hash => {
+            PlannedAction::Skip(format!("[unchanged] {}", display))
+        }
+        Some(_) => PlannedAction::Upload(format!(
+            "[update] {} ({} bytes, remote differs)",
+            display, size
+        )),
+    })
+}
+
+async fn plan_directory(
+    mapping: &SSHFileMapping,
+    expanded: &str,
+    session: &SshSession,
+    exclude: &ExcludeFilter,
+) -> Result<Vec<PlannedAction>, String> {
+    let (local, total_size) = local_dir_sha256(Path::new(expanded), exclude)?;
+
+    let display = format!("{} -> {}", mapping.local_path, mapping.remote_path);
+    let Some(remote) = remote_dir_sha256(session, &mapping.remote_path).await? else {
+        return Ok(vec![PlannedAction::Upload(format!(
+            "[create] {} ({} files, {} bytes)",
+            display,
+            local.len(),
+            total_size
+        ))]);
+    };
+
+    let added = local.keys().filter(|k| !remote.contains_key(*k)).count();
+    let changed = local
+        .iter()
+        .filter(|(k, hash)| remote.get(*k).is_some_and(|r| r != *hash))
+        .count();
+    // 目录同步是整体替换，远程多出的文件会被删除
+    let removed = remote
+        .keys()
+        .filter(|k| !local.contains_key(*k) && !exclude.is_excluded(k))
+        .count();
+
+    if added + changed + removed == 0 {
+        return Ok(vec![PlannedAction::Skip(format!(
+            "[unchanged] {}",
+            display
+        ))]);
+    }
+    Ok(vec![PlannedAction::Upload(format!(
+        "[replace] {} ({} files, {} bytes; {} new, {} changed, {} removed)",
+        display,
+        local.len(),
+        total_size,
+        added,
+        changed,
+        removed
+    ))])
+}
+
+async fn plan_pattern(
+    mapping: &SSHFileMapping,
+    expanded: &str,
+    session: &SshSession,
+    exclude: &ExcludeFilter,
+) -> Result<Vec<PlannedAction>, String> {
+    let matches: Vec<_> = glob::glob(expanded)
+        .map_err(|e| format!("无效的 glob 模式: {}", e))?
+        .filter_map(|entry| entry.ok())
+        .filter(|path| path.is_file() && !is_excluded_file(path, exclude))
+        .collect();
+    if matches.is_empty() {
+        return Ok(vec![PlannedAction::Skip(format!(
+            "[skip] {}: no local files match {}",
+            mapping.name, mapping.local_path
+        ))]);
+    }
+
+    let mut actions = Vec::with_capacity(matches.len());
+    for file_path in &matches {
+        let file_name = file_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let remote_dest = format!(
+            "{}/{}",
+            mapping.remote_path.trim_end_matches('/'),
+            file_name
+        );
+        actions.push(plan_file(file_path, &remote_dest, session).await?);
+    }
+    Ok(actions)
+}
+
+async fn plan_mapping(
+    mapping: &SSHFileMapping,
+    session: &SshSession,
+) -> Result<Vec<PlannedAction>, String> {
+    let expanded = expand_local_path(&mapping.local_path)?;
+    let exclude = ExcludeFilter::new(&mapping.exclude_patterns)?;
+    if mapping.is_pattern {
+        return plan_pattern(mapping, &expanded, session, &exclude).await;
+    }
+    if !Path::new(&expanded).exists() {
+        return Ok(vec![PlannedAction::Skip(format!(
+            "[skip] {}: local path {} does not exist",
+            mapping.name, mapping.local_path
+        ))]);
+    }
+    if mapping.is_directory {
+        plan_directory(mapping, &expanded, session, &exclude).await
+    } else {
+        Ok(vec![
+            plan_file(Path::new(&expanded), &mapping.remote_path, session).await?,
+        ])
+    }
+}
+
+/// 评估映射的同步计划，不复制任何文件
+///
+/// `synced_files` lists the planned uploads, `skipped_files` the mappings that would be
+/// skipped or are already up to date.
+pub async fn plan_mappings(mappings: &[&SSHFileMapping], session: &SshSession) -> SyncResult {
+    let mut synced_files = vec![];
+    let mut skipped_files = vec![];
+    let mut errors = vec![];
+
+    for mapping in mappings {
+        match plan_mapping(mapping, session).await {
+            Ok(actions) => {
+                for action in actions {
+                    match action {
+                        PlannedAction::Upload(line) => synced_files.push(line),
+                        PlannedAction::Skip(line) => skipped_files.push(line),
+                    }
+                }
+            }
+            Err(e) => errors.push(format!("{}: {}", mapping.name, e)),
+        }
+    }
+
+    SyncResult {
+        success: errors.is_empty(),
+        synced_files,
+        skipped_files,
+        errors,
+    }
+}
@@ -0,0 +1,142 @@
+use super::redact::REDACTED_PLACEHOLDER;
+use super::session::SshSession;
+use super::sync::{expand_local_path, read_remote_file};
+use super::types::{SSHFileMapping, SSHPullItem, SyncResult};
+use std::path::Path;
+
+// ============================================================================
+// Pull (远程 -> 本地)
+// ============================================================================
+
+async fn remote_file_exists(session: &SshSession, remote_path: &str) -> Result<bool, String> {
+    let remote_target = remote_path.replace("~", "$HOME");
+    let check_cmd = format!(
+        "if [ -f \"{}\" ]; then echo EXISTS; else echo MISSING; fi",
+        remote_target
+    );
+    Ok(session.exec_command(&check_cmd).await?.trim() == "EXISTS")
+}
+
+fn read_local_file(local_path: &str) -> Result<Option<String>, String> {
+    let expanded = expand_local_path(local_path)?;
+    let path = Path::new(&expanded);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let bytes = std::fs::read(path).map_err(|e| format!("读取本地文件失败: {}", e))?;
+    Ok(Some(String::from_utf8_lossy(&bytes).to_string()))
+}
+
+/// 拉取单个映射的远程内容并与本地对比
+async fn preview_mapping(mapping: &SSHFileMapping, session: &SshSession) -> SSHPullItem {
+    let mut item = SSHPullItem {
+        mapping_id: mapping.id.clone(),
+        name: mapping.name.clone(),
+        module: mapping.module.clone(),
+        local_path: mapping.local_path.clone(),
+        remote_path: mapping.remote_path.clone(),
+        status: "unchanged".to_string(),
+        local_content: None,
+        remote_content: None,
+        error: None,
+    };
+
+    // 目录和通配符映射无法逐文件对比，只支持单文件
+    if mapping.is_directory || mapping.is_pattern {
+        item.status = "unsupported".to_string();
+        return item;
+    }
+
+    let result: Result<(), String> = async {
+        item.local_content = read_local_file(&mapping.local_path)?;
+        if !remote_file_exists(session, &mapping.remote_path).await? {
+            item.status = "remote_missing".to_string();
+            return Ok(());
+        }
+        let remote_content = read_remote_file(session, &mapping.remote_path).await?;
+        item.status = match &item.local_content {
+            None => "remote_only",
+            Some(local) if local == &remote_content => "unchanged",
+            Some(_) => "modified",
+        }
+        .to_string();
+        item.remote_content = Some(remote_content);
+        Ok(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        log::warn!(
+            "SSH pull preview failed: id={}, name={}, remote_path={}, error={}",
+            mapping.id,
+            mapping.name,
+            mapping.remote_path,
+            e
+        );
+        item.status = "error".to_string();
+        item.error = Some(e);
+    }
+    item
+}
+
+/// 预览拉取：下载远程文件内容并与本地对比，不修改任何文件
+pub async fn preview_pull(mappings: &[SSHFileMapping], session: &SshSession) -> Vec<SSHPullItem> {
+    let mut items = Vec::with_capacity(mappings.len());
+    for mapping in mappings {
+        items.push(preview_mapping(mapping, session).await);
+    }
+    items
+}
+
+/// 将远程文件写回本地（覆盖本地内容）
+async fn pull_mapping(mapping: &SSHFileMapping, session: &SshSession) -> Result<bool, String> {
+    if mapping.is_directory || mapping.is_pattern {
+        return Err("目录和通配符映射不支持拉取".to_string());
+    }
+    if !remote_file_exists(session, &mapping.remote_path).await? {
+        return Ok(false);
+    }
+
+    let remote_content = read_remote_file(session, &mapping.remote_path).await?;
+    // 远程配置经过脱敏时，拉回会用占位符覆盖本地密钥
+    if remote_content.contains(REDACTED_PLACEHOLDER) {
+        return Err("远程文件包含已脱敏的密钥，拉取会覆盖本地密钥".to_string());
+    }
+    let expanded = expand_local_path(&mapping.local_path)?;
+    let local_path = Path::new(&expanded);
+    if let Some(parent) = local_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("创建本地目录失败: {}", e))?;
+    }
+    std::fs::write(local_path, remote_content).map_err(|e| format!("写入本地文件失败: {}", e))?;
+    log::info!(
+        "SSH pull applied: id={}, remote_path={}, local_path={}",
+        mapping.id,
+        mapping.remote_path,
+        expanded
+    );
+    Ok(true)
+}
+
+/// 应用拉取：把选中映射的远程内容写到本地
+pub async fn apply_pull(mappings: &[SSHFileMapping], session: &SshSession) -> SyncResult {
+    let mut synced_files = vec![];
+    let mut skipped_files = vec![];
+    let mut errors = vec![];
+
+    for mapping in mappings {
+        match pull_mapping(mapping, session).await {
+            Ok(true) => {
+                synced_files.push(format!("{} -> {}", mapping.remote_path, mapping.local_path))
+            }
+            Ok(false) => skipped_files.push(mapping.name.clone()),
+            Err(e) => errors.push(format!("{}: {}", mapping.name, e)),
+        }
+    }
+
+    SyncResult {
+        success: errors.is_empty(),
+        synced_files,
+        skipped_files,
+        errors,
+    }
+}
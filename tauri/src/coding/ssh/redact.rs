@@ -0,0 +1,330 @@
+//! Secret redaction for configs synced to shared servers
+//!
+//! When a connection has `redact_secrets` enabled, string values stored under secret-like
+//! keys (API keys, tokens, passwords, auth headers) are replaced with a placeholder before
+//! the config lands on the remote. Environment references such as `{env:VAR}` or `${VAR}`
+//! are kept because they do not contain the secret itself.
+//!
+//! Files are redacted locally before they are uploaded, so the secrets never reach the
+//! remote, not even for the moment between an upload and a rewrite.
+
+use std::path::Path;
+
+use serde_json::Value;
+
+/// Placeholder written in place of a redacted secret
+pub const REDACTED_PLACEHOLDER: &str = "<redacted>";
+
+const SECRET_KEY_MARKERS: [&str; 9] = [
+    "apikey",
+    "secret",
+    "password",
+    "passwd",
+    "authorization",
+    "accesskey",
+    "privatekey",
+    "credential",
+    "cookie",
+];
+
+/// Whether a config key names a secret (`apiKey`, `OPENAI_API_KEY`, `X-Auth-Token`, ...)
+fn is_secret_key(key: &str) -> bool {
+    let normalized: String = key
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    // "max_tokens" / "output_tokens" are limits, not credentials
+    (normalized.contains("token") && !normalized.ends_with("tokens"))
+        || SECRET_KEY_MARKERS
+            .iter()
+            .any(|marker| normalized.contains(marker))
+}
+
+fn should_redact(value: &str) -> bool {
+    let trimmed = value.trim();
+    !trimmed.is_empty()
+        && trimmed != REDACTED_PLACEHOLDER
+        && !trimmed.starts_with("{env:")
+        && !trimmed.starts_with("${")
+}
+
+/// Redact secrets in a JSON value in place, returns the number of replaced values
+pub fn redact_json_value(value: &mut Value) -> usize {
+    match value {
+        Value::Object(map) => map
+            .iter_mut()
+            .map(|(key, child)| match child {
+                Value::String(s) if is_secret_key(key) && should_redact(s) => {
+                    *s = REDACTED_PLACEHOLDER.to_string();
+                    1
+                }
+                _ => redact_json_value(child),
+            })
+            .sum(),
+        Value::Array(items) => items.iter_mut().map(redact_json_value).sum(),
+        _ => 0,
+    }
+}
+
+/// Redact secrets in JSON/JSONC content (opencode.jsonc, claude.json)
+pub fn redact_json(content: &str) -> Result<String, String> {
+    if content.trim().is_empty() {
+        return Ok(content.to_string());
+    }
+    let mut root: Value =
+        json5::from_str(content).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    if redact_json_value(&mut root) == 0 {
+        return Ok(content.to_string());
+    }
+    serde_json::to_string_pretty(&root).map_err(|e| format!("Failed to serialize JSON: {}", e))
+}
+
+/// Credential stores (`auth.json` of Codex and OpenCode) hold nothing but secrets under
+/// provider-specific keys (`access`, `refresh`, `key`), so every string is redacted except
+/// the ones describing the kind of credential
+const AUTH_FILE_KEPT_KEYS: [&str; 2] = ["type", "auth_mode"];
+
+fn redact_all_strings(value: &mut Value) -> usize {
+    match value {
+        Value::Object(map) => map
+            .iter_mut()
+            .map(|(key, child)| match child {
+                Value::String(_) if AUTH_FILE_KEPT_KEYS.contains(&key.as_str()) => 0,
+                Value::String(s) if should_redact(s) => {
+                    *s = REDACTED_PLACEHOLDER.to_string();
+                    1
+                }
+                _ => redact_all_strings(child),
+            })
+            .sum(),
+        Value::Array(items) => items.iter_mut().map(redact_all_strings).sum(),
+        _ => 0,
+    }
+}
+
+/// Redact every credential in an `auth.json` credential store
+pub fn redact_auth_json(content: &str) -> Result<String, String> {
+    if content.trim().is_empty() {
+        return Ok(content.to_string());
+    }
+    let mut root: Value =
+        json5::from_str(content).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    if redact_all_strings(&mut root) == 0 {
+        return Ok(content.to_string());
+    }
+    serde_json::to_string_pretty(&root).map_err(|e| format!("Failed to serialize JSON: {}", e))
+}
+
+fn redact_toml_table(table: &mut dyn toml_edit::TableLike) -> usize {
+    let mut count = 0;
+    for (key, item) in table.iter_mut() {
+        let secret = is_secret_key(key.get());
+        if let Some(value) = item.as_value_mut() {
+            match value {
+                toml_edit::Value::String(s) if secret && should_redact(s.value()) => {
+                    let mut redacted = toml_edit::Formatted::new(REDACTED_PLACEHOLDER.to_string());
+                    *redacted.decor_mut() = s.decor().clone();
+                    *s = redacted;
+                    count += 1;
+                }
+                toml_edit::Value::InlineTable(inline) => count += redact_toml_table(inline),
+                _ => {}
+            }
+        } else if let Some(tables) = item.as_array_of_tables_mut() {
+            for child in tables.iter_mut() {
+                count += redact_toml_table(child);
+            }
+        } else if let Some(child) = item.as_table_like_mut() {
+            count += redact_toml_table(child);
+        }
+    }
+    count
+}
+
+/// Redact secrets in TOML content (codex config.toml), keeping formatting and comments
+pub fn redact_toml(content: &str) -> Result<String, String> {
+    if content.trim().is_empty() {
+        return Ok(content.to_string());
+    }
+    let mut doc: toml_edit::DocumentMut = content
+        .parse()
+        .map_err(|e| format!("Failed to parse TOML: {}", e))?;
+    if redact_toml_table(doc.as_table_mut()) == 0 {
+        return Ok(content.to_string());
+    }
+    Ok(doc.to_string())
+}
+
+/// Redact secrets in `.env` content, keeping comments and `export` prefixes
+pub fn redact_env(content: &str) -> String {
+    let mut redacted: Vec<String> = content
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            let body = trimmed.strip_prefix("export ").unwrap_or(trimmed);
+            match body.split_once('=') {
+                Some((key, value))
+                    if !trimmed.starts_with('#')
+                        && is_secret_key(key.trim())
+                        && should_redact(value.trim_matches(|c| c == '"' || c == '\'')) =>
+                {
+                    let prefix = &line[..line.len() - body.len()];
+                    format!("{}{}={}", prefix, key, REDACTED_PLACEHOLDER)
+                }
+                _ => line.to_string(),
+            }
+        })
+        .collect();
+    if content.ends_with('\n') {
+        redacted.push(String::new());
+    }
+    redacted.join("\n")
+}
+
+/// Redact a local file about to be uploaded, based on its name.
+///
+/// JSON/JSONC, TOML and `.env` files are redacted; other files (prompts, plugins) are
+/// returned unchanged. A config file that can't be parsed is an error, so it is never
+/// uploaded with its secrets.
+pub fn redact_file_content(path: &Path, data: Vec<u8>) -> Result<Vec<u8>, String> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+    let is_env = file_name == ".env" || extension == "env";
+    if !is_env && !matches!(extension.as_str(), "json" | "jsonc" | "json5" | "toml") {
+        return Ok(data);
+    }
+
+    let content = String::from_utf8(data)
+        .map_err(|_| format!("{} is not valid UTF-8, refusing to upload", path.display()))?;
+    let redacted = if is_env {
+        redact_env(&content)
+    } else if extension == "toml" {
+        redact_toml(&content)
+    } else if file_name == "auth.json" {
+        redact_auth_json(&content)
+    } else {
+        redact_json(&content)
+    }
+    .map_err(|e| format!("Failed to redact {}: {}", path.display(), e))?;
+    Ok(redacted.into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_json_secrets_and_keeps_env_references() {
+        let content = r#"{
+            // jsonc comment
+            "provider": { "openai": { "options": { "apiKey": "sk-live", "baseURL": "https://api" } } },
+            "mcp": {
+                "github": {
+                    "environment": { "GITHUB_TOKEN": "ghp_x", "OTHER": "{env:OTHER}" },
+                    "headers": { "Authorization": "Bearer abc" }
+                }
+            },
+            "limit": { "max_tokens": "4096" }
+        }"#;
+        let redacted: Value = serde_json::from_str(&redact_json(content).unwrap()).unwrap();
+        assert_eq!(
+            redacted["provider"]["openai"]["options"]["apiKey"],
+            REDACTED_PLACEHOLDER
+        );
+        assert_eq!(
+            redacted["provider"]["openai"]["options"]["baseURL"],
+            "https://api"
+        );
+        assert_eq!(
+            redacted["mcp"]["github"]["environment"]["GITHUB_TOKEN"],
+            REDACTED_PLACEHOLDER
+        );
+        assert_eq!(
+            redacted["mcp"]["github"]["headers"]["Authorization"],
+            REDACTED_PLACEHOLDER
+        );
+        assert_eq!(redacted["limit"]["max_tokens"], "4096");
+        assert_eq!(
+            redacted["mcp"]["github"]["environment"]["OTHER"],
+            "{env:OTHER}"
+        );
+    }
+
+    #[test]
+    fn redacts_toml_secrets_in_tables_and_inline_tables() {
+        let content = r#"# codex config
+model = "gpt-5"
+
+[model_providers.custom]
+experimental_bearer_token = "tok"
+
+[mcp_servers.github]
+command = "npx"
+env = { GITHUB_PERSONAL_ACCESS_TOKEN = "ghp_x", DEBUG = "1" }
+"#;
+        let redacted = redact_toml(content).unwrap();
+        assert!(redacted.starts_with("# codex config"));
+        assert!(!redacted.contains("\"tok\""));
+        assert!(!redacted.contains("ghp_x"));
+        assert!(redacted.contains("DEBUG = \"1\""));
+        assert!(redacted.contains("model = \"gpt-5\""));
+    }
+
+    #[test]
+    fn redacts_credential_stores_and_env_files() {
+        let opencode_auth = r#"{
+            "anthropic": { "type": "oauth", "refresh": "rt", "access": "at", "expires": 1 },
+            "deepseek": { "type": "api", "key": "sk-x" }
+        }"#;
+        let redacted: Value = serde_json::from_slice(
+            &redact_file_content(Path::new("auth.json"), opencode_auth.as_bytes().to_vec())
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(redacted["anthropic"]["type"], "oauth");
+        assert_eq!(redacted["anthropic"]["refresh"], REDACTED_PLACEHOLDER);
+        assert_eq!(redacted["anthropic"]["expires"], 1);
+        assert_eq!(redacted["deepseek"]["key"], REDACTED_PLACEHOLDER);
+
+        let settings =
+            r#"{ "env": { "ANTHROPIC_AUTH_TOKEN": "sk-ant", "ANTHROPIC_BASE_URL": "https://x" } }"#;
+        let redacted: Value = serde_json::from_slice(
+            &redact_file_content(Path::new("settings.json"), settings.as_bytes().to_vec()).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            redacted["env"]["ANTHROPIC_AUTH_TOKEN"],
+            REDACTED_PLACEHOLDER
+        );
+        assert_eq!(redacted["env"]["ANTHROPIC_BASE_URL"], "https://x");
+
+        assert_eq!(
+            redact_env("# keys\nexport OPENAI_API_KEY=sk-1\nOPENAI_API_BASE=https://x\n"),
+            "# keys\nexport OPENAI_API_KEY=<redacted>\nOPENAI_API_BASE=https://x\n"
+        );
+    }
+
+    #[test]
+    fn refuses_to_upload_unparsable_configs() {
+        let broken = b"{ \"apiKey\": \"sk-live\"".to_vec();
+        assert!(redact_file_content(Path::new("opencode.jsonc"), broken).is_err());
+        let prompt = b"apiKey: sk-live".to_vec();
+        assert_eq!(
+            redact_file_content(Path::new("AGENTS.md"), prompt.clone()).unwrap(),
+            prompt
+        );
+    }
+
+    #[test]
+    fn leaves_content_without_secrets_untouched() {
+        let content = "{\n  // keep\n  \"theme\": \"dark\"\n}";
+        assert_eq!(redact_json(content).unwrap(), content);
+    }
+}
@@ -14,14 +14,31 @@ use tokio::io::AsyncWriteExt;
 use tokio::sync::Mutex;
 
 use super::key_file;
-use super::types::SSHConnection;
+use super::known_hosts;
+use super::redact;
+use super::sync::ExcludeFilter;
+use super::types::{SSHConnection, SSHHostKeyInfo};
+
+/// 健康检查最近一次发现连接断开且重连失败（托盘状态显示用）
+static CONNECTION_LOST: AtomicBool = AtomicBool::new(false);
+
+/// 记录健康检查结果
+pub fn set_connection_lost(lost: bool) {
+    CONNECTION_LOST.store(lost, Ordering::SeqCst);
+}
+
+/// 最近一次健康检查是否判定连接断开
+pub fn is_connection_lost() -> bool {
+    CONNECTION_LOST.load(Ordering::SeqCst)
+}
 
 /// 加载私钥：优先从内容直接解析，否则从文件路径加载
 fn load_private_key(conn: &SSHConnection) -> Result<russh::keys::PrivateKey, String> {
-    let passphrase = if conn.passphrase.is_empty() {
+    let passphrase = resolve_secret(&conn.passphrase)?;
+    let passphrase = if passphrase.is_empty() {
         None
     } else {
-        Some(conn.passphrase.as_str())
+        Some(passphrase.as_str())
     };
 
     let content = conn.private_key_content.trim();
@@ -38,6 +55,11 @@ fn load_private_key(conn: &SSHConnection) -> Result<russh::keys::PrivateKey, Str
     }
 }
 
+/// 解析密码 / 口令中的密钥库引用（`{{secret:NAME}}`）
+fn resolve_secret(value: &str) -> Result<String, String> {
+    crate::secrets::resolve_text(value).map_err(|e| format!("解析密钥库引用失败: {}", e))
+}
+
 /// SSH 会话状态
 #[derive(Debug, Clone, PartialEq)]
 pub enum SessionStatus {
@@ -51,30 +73,67 @@ pub enum SessionStatus {
     Failed(String),
 }
 
+/// 握手时看到的主机密钥，连接失败后用于生成明确的错误信息
+type HostKeySlot = Arc<std::sync::Mutex<Option<SSHHostKeyInfo>>>;
+
 /// russh 客户端 Handler 实现
-struct SshHandler;
+struct SshHandler {
+    host: String,
+    port: u16,
+    host_key: HostKeySlot,
+}
+
+impl SshHandler {
+    fn new(conn: &SSHConnection) -> (Self, HostKeySlot) {
+        let slot = HostKeySlot::default();
+        let handler = Self {
+            host: conn.host.clone(),
+            port: conn.port,
+            host_key: slot.clone(),
+        };
+        (handler, slot)
+    }
+}
 
 impl client::Handler for SshHandler {
     type Error = russh::Error;
 
     async fn check_server_key(
         &mut self,
-        _server_public_key: &ssh_key::PublicKey,
+        server_public_key: &ssh_key::PublicKey,
     ) -> Result<bool, Self::Error> {
-        // TODO: 实现 known_hosts 验证以达到真正的 StrictHostKeyChecking=accept-new 行为
-        // 当前行为等同于 StrictHostKeyChecking=no，无条件接受所有服务器密钥
-        Ok(true)
+        // 只接受已信任的主机密钥；未知或已变更的密钥由调用方转换为明确错误
+        let info = known_hosts::verify(&self.host, self.port, server_public_key);
+        let trusted = info.status == "trusted";
+        if !trusted {
+            warn!(
+                "SSH host key rejected: {}:{} status={} fingerprint={}",
+                self.host, self.port, info.status, info.fingerprint
+            );
+        }
+        if let Ok(mut slot) = self.host_key.lock() {
+            *slot = Some(info);
+        }
+        Ok(trusted)
     }
 }
 
+/// 连接失败时，若原因是主机密钥未被信任，返回明确的主机密钥错误
+fn host_key_error(slot: &HostKeySlot) -> Option<String> {
+    let slot = slot.lock().ok()?;
+    let info = slot.as_ref()?;
+    (info.status != "trusted").then(|| known_hosts::rejection_error(info))
+}
+
 /// 对已建立的 SSH 连接进行用户认证（密码或公钥）
 async fn authenticate(
     session: &mut client::Handle<SshHandler>,
     conn: &SSHConnection,
 ) -> Result<(), String> {
     if conn.auth_method == "password" && !conn.password.is_empty() {
+        let password = resolve_secret(&conn.password)?;
         let auth_result = session
-            .authenticate_password(&conn.username, &conn.password)
+            .authenticate_password(&conn.username, &password)
             .await
             .map_err(|e| format!("密码认证失败: {}", e))?;
         if !auth_result.success() {
@@ -183,14 +242,17 @@ impl SshSession {
             ..Default::default()
         };
 
-        let handler = SshHandler;
+        let (handler, host_key) = SshHandler::new(conn);
         let mut session = tokio::time::timeout(
             Duration::from_secs(30),
             client::connect(Arc::new(config), (conn.host.as_str(), conn.port), handler),
         )
         .await
         .map_err(|_| format!("连接超时: {}:{}", conn.host, conn.port))?
-        .map_err(|e| format!("连接到 {}:{} 失败: {}", conn.host, conn.port, e))?;
+        .map_err(|e| {
+            host_key_error(&host_key)
+                .unwrap_or_else(|| format!("连接到 {}:{} 失败: {}", conn.host, conn.port, e))
+        })?;
 
         authenticate(&mut session, conn).await?;
 
@@ -236,17 +298,7 @@ impl SshSession {
 
     /// 在远程执行命令并返回 stdout
     pub async fn exec_command(&self, cmd: &str) -> Result<String, String> {
-        let handle = self.handle.as_ref().ok_or("SSH 会话未建立")?;
-
-        let mut channel = handle
-            .channel_open_session()
-            .await
-            .map_err(|e| format!("打开 SSH channel 失败: {}", e))?;
-
-        channel
-            .exec(true, cmd)
-            .await
-            .map_err(|e| format!("执行远程命令失败: {}", e))?;
+        let mut channel = self.open_exec_channel(cmd).await?;
 
         let mut stdout_buf = Vec::new();
         let mut stderr_buf = Vec::new();
@@ -295,17 +347,7 @@ impl SshSession {
         cmd: &str,
         stdin_data: &[u8],
     ) -> Result<(), String> {
-        let handle = self.handle.as_ref().ok_or("SSH 会话未建立")?;
-
-        let mut channel = handle
-            .channel_open_session()
-            .await
-            .map_err(|e| format!("打开 SSH channel 失败: {}", e))?;
-
-        channel
-            .exec(true, cmd)
-            .await
-            .map_err(|e| format!("执行远程命令失败: {}", e))?;
+        let mut channel = self.open_exec_channel(cmd).await?;
 
         channel
             .data(stdin_data)
@@ -369,21 +411,85 @@ impl SshSession {
             .map_err(|e| format!("初始化 SFTP 会话失败: {}", e))
     }
 
+    /// 打开 session channel 并执行命令，由调用方读取输出
+    pub async fn open_exec_channel(
+        &self,
+        cmd: &str,
+    ) -> Result<russh::Channel<client::Msg>, String> {
+        let handle = self.handle.as_ref().ok_or("SSH 会话未建立")?;
+
+        let channel = handle
+            .channel_open_session()
+            .await
+            .map_err(|e| format!("打开 SSH channel 失败: {}", e))?;
+
+        channel
+            .exec(true, cmd)
+            .await
+            .map_err(|e| format!("执行远程命令失败: {}", e))?;
+        Ok(channel)
+    }
+
+    /// 打开 direct-tcpip channel（本地端口转发，等同 `ssh -L`）
+    pub async fn open_direct_tcpip(
+        &self,
+        remote_host: &str,
+        remote_port: u16,
+        originator_port: u16,
+    ) -> Result<russh::Channel<client::Msg>, String> {
+        let handle = self.handle.as_ref().ok_or("SSH 会话未建立")?;
+        handle
+            .channel_open_direct_tcpip(
+                remote_host,
+                remote_port as u32,
+                "127.0.0.1",
+                originator_port as u32,
+            )
+            .await
+            .map_err(|e| {
+                format!(
+                    "打开端口转发 channel 失败 {}:{}: {}",
+                    remote_host, remote_port, e
+                )
+            })
+    }
+
     /// 通过 SFTP 上传单个文件
     pub async fn upload_file(&self, local_path: &str, remote_path: &str) -> Result<(), String> {
         let sftp = self.create_sftp_session().await?;
         upload_file_via_sftp(&sftp, local_path, remote_path).await
     }
 
-    /// 通过 SFTP 递归上传目录
-    pub async fn upload_dir(&self, local_path: &str, remote_path: &str) -> Result<(), String> {
+    /// 通过 SFTP 上传内存中的内容（已脱敏的配置）
+    pub async fn upload_bytes(&self, data: &[u8], remote_path: &str) -> Result<(), String> {
+        let sftp = self.create_sftp_session().await?;
+        upload_bytes_via_sftp(&sftp, data, remote_path).await
+    }
+
+    /// 通过 SFTP 递归上传目录（跳过被排除的路径）
+    /// `redact_secrets` 为 true 时，配置文件在上传前脱敏
+    pub async fn upload_dir(
+        &self,
+        local_path: &str,
+        remote_path: &str,
+        exclude: &ExcludeFilter,
+        redact_secrets: bool,
+    ) -> Result<(), String> {
         let sftp = self.create_sftp_session().await?;
 
         // 将 ~ 展开为绝对路径
         let abs_remote_path = resolve_remote_path(&sftp, remote_path).await?;
 
         // 递归上传
-        upload_dir_recursive(&sftp, std::path::Path::new(local_path), &abs_remote_path).await
+        upload_dir_recursive(
+            &sftp,
+            std::path::Path::new(local_path),
+            &abs_remote_path,
+            "",
+            exclude,
+            redact_secrets,
+        )
+        .await
     }
 
     /// 获取 user@host 字符串
@@ -406,24 +512,38 @@ impl SshSession {
 }
 
 /// 创建一个独立的临时 SSH 连接并执行命令（用于测试连接）
-/// 返回命令输出结果
+/// 返回命令输出结果，以及握手时看到的主机密钥（供 UI 展示指纹）
 pub async fn test_connection_with_command(
     conn: &SSHConnection,
     cmd: &str,
+) -> (Result<String, String>, Option<SSHHostKeyInfo>) {
+    let (handler, host_key) = SshHandler::new(conn);
+    let result = run_test_command(conn, cmd, handler, &host_key).await;
+    let info = host_key.lock().ok().and_then(|slot| slot.clone());
+    (result, info)
+}
+
+async fn run_test_command(
+    conn: &SSHConnection,
+    cmd: &str,
+    handler: SshHandler,
+    host_key: &HostKeySlot,
 ) -> Result<String, String> {
     let config = client::Config {
         inactivity_timeout: Some(Duration::from_secs(10)),
         ..Default::default()
     };
 
-    let handler = SshHandler;
     let mut session = tokio::time::timeout(
         Duration::from_secs(15),
         client::connect(Arc::new(config), (conn.host.as_str(), conn.port), handler),
     )
     .await
     .map_err(|_| format!("连接超时: {}:{}", conn.host, conn.port))?
-    .map_err(|e| format!("连接到 {}:{} 失败: {}", conn.host, conn.port, e))?;
+    .map_err(|e| {
+        host_key_error(host_key)
+            .unwrap_or_else(|| format!("连接到 {}:{} 失败: {}", conn.host, conn.port, e))
+    })?;
 
     authenticate(&mut session, conn).await?;
 
@@ -473,6 +593,15 @@ pub async fn upload_file_via_sftp(
         .await
         .map_err(|e| format!("读取本地文件失败 {}: {}", local_path, e))?;
 
+    upload_bytes_via_sftp(sftp, &data, remote_path).await
+}
+
+/// 通过已有 SFTP 会话将内容写入远程文件
+pub async fn upload_bytes_via_sftp(
+    sftp: &russh_sftp::client::SftpSession,
+    data: &[u8],
+    remote_path: &str,
+) -> Result<(), String> {
     // 将 ~ 展开为绝对路径（SFTP 不支持 ~ 语法）
     let abs_remote_path = resolve_remote_path(sftp, remote_path).await?;
 
@@ -493,7 +622,7 @@ pub async fn upload_file_via_sftp(
         .map_err(|e| format!("打开远程文件失败 {}: {}", abs_remote_path, e))?;
 
     remote_file
-        .write_all(&data)
+        .write_all(data)
         .await
         .map_err(|e| format!("写入远程文件失败: {}", e))?;
 
@@ -516,6 +645,9 @@ async fn upload_dir_recursive(
     sftp: &russh_sftp::client::SftpSession,
     local_dir: &std::path::Path,
     remote_dir: &str,
+    relative_dir: &str,
+    exclude: &ExcludeFilter,
+    redact_secrets: bool,
 ) -> Result<(), String> {
     // 创建远程目录（忽略已存在的错误）
     let _ = sftp.create_dir(remote_dir).await;
@@ -530,19 +662,40 @@ async fn upload_dir_recursive(
         .map_err(|e| format!("读取目录项失败: {}", e))?
     {
         let path = entry.path();
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let relative_child = if relative_dir.is_empty() {
+            file_name.clone()
+        } else {
+            format!("{}/{}", relative_dir, file_name)
+        };
+        if exclude.is_excluded(&relative_child) {
+            continue;
+        }
         // 使用 metadata（而非 symlink_metadata）跟随符号链接，获取最终目标的类型
         let metadata = tokio::fs::metadata(&path)
             .await
             .map_err(|e| format!("获取文件元数据失败 {}: {}", path.display(), e))?;
-        let file_name = entry.file_name().to_string_lossy().to_string();
         let remote_child = format!("{}/{}", remote_dir, file_name);
 
         if metadata.is_dir() {
-            Box::pin(upload_dir_recursive(sftp, &path, &remote_child)).await?;
+            Box::pin(upload_dir_recursive(
+                sftp,
+                &path,
+                &remote_child,
+                &relative_child,
+                exclude,
+                redact_secrets,
+            ))
+            .await?;
         } else if metadata.is_file() {
             let data = tokio::fs::read(&path)
                 .await
                 .map_err(|e| format!("读取文件失败 {}: {}", path.display(), e))?;
+            let data = if redact_secrets {
+                redact::redact_file_content(&path, data)?
+            } else {
+                data
+            };
 
             let mut remote_file = sftp
                 .open_with_flags(
@@ -576,7 +729,7 @@ async fn upload_dir_recursive(
 
 /// 将远程路径中的 ~ 和 $HOME 展开为绝对路径
 /// SFTP 协议不支持 shell 变量或 ~ 语法，需要用 canonicalize 获取 home 路径
-async fn resolve_remote_path(
+pub(super) async fn resolve_remote_path(
     sftp: &russh_sftp::client::SftpSession,
     path: &str,
 ) -> Result<String, String> {
@@ -2,7 +2,7 @@
 //!
 //! Full sync of managed skills to remote server's central repo with symlinks to tool directories.
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use log::info;
 use tauri::{AppHandle, Emitter};
@@ -11,13 +11,15 @@ use super::commands::get_ssh_config_internal;
 use super::session::SshSession;
 use super::sync::{
     check_remote_symlink_exists, create_remote_symlink, list_remote_dir, read_remote_file_raw,
-    remove_remote_path, sync_directory, write_remote_file,
+    remove_remote_path, sync_directory, write_remote_file, ExcludeFilter,
 };
-use super::types::SyncProgress;
+use super::types::{SSHSkillSyncResult, SyncProgress};
 use crate::coding::runtime_location;
+use crate::coding::skills::adapter::target_dir_name;
 use crate::coding::skills::central_repo::{resolve_central_repo_path, resolve_skill_central_path};
 use crate::coding::skills::skill_store;
 use crate::coding::tools::builtin::BUILTIN_TOOLS;
+use crate::locale;
 use crate::DbState;
 
 const SSH_CENTRAL_DIR: &str = "~/.ai-toolbox/skills";
@@ -63,18 +65,21 @@ fn get_all_skill_tool_keys() -> Vec<&'static str> {
 }
 
 /// Sync all skills to SSH remote (called on skills-changed event)
+///
+/// Returns one result per managed skill; the same list is emitted with
+/// `ssh-skills-sync-completed`.
 pub async fn sync_skills_to_ssh(
     state: &DbState,
     session: &SshSession,
     app: AppHandle,
-) -> Result<(), String> {
+) -> Result<Vec<SSHSkillSyncResult>, String> {
     let db = state.db();
     let config = get_ssh_config_internal(&db, false).await?;
     drop(db);
 
     if !config.enabled {
         info!("Skills SSH sync skipped: enabled={}", config.enabled);
-        return Ok(());
+        return Ok(vec![]);
     }
     info!(
         "Skills SSH sync start: active_connection_id={}, remote_central_dir={}",
@@ -100,10 +105,13 @@ pub async fn sync_skills_to_ssh(
         "ssh-sync-progress",
         SyncProgress {
             phase: "skills".to_string(),
-            current_item: "准备中...".to_string(),
+            current_item: locale::text("sync.preparing").to_string(),
             current: 0,
             total: total_skills,
-            message: format!("Skills 同步: 0/{}", total_skills),
+            message: locale::format(
+                "sync.skills_progress",
+                &[("current", &0), ("total", &total_skills)],
+            ),
         },
     );
 
@@ -154,9 +162,37 @@ pub async fn sync_skills_to_ssh(
         }
     }
 
-    // 4. Sync/update each skill
+    // 4. Ensure the remote central repo and tool skills directories exist
+    let mut tool_dir_errors: HashMap<String, String> = HashMap::new();
+    if let Err(e) =
+        ensure_remote_dir(session, SSH_CENTRAL_DIR, config.skills_remote_bootstrap).await
+    {
+        return Err(format!("创建远程 Skills 目录失败: {}", e));
+    }
+    let used_tools: BTreeSet<&str> = skills
+        .iter()
+        .flat_map(|skill| skill.enabled_tools.iter().map(|t| t.as_str()))
+        .collect();
+    for tool_key in used_tools {
+        if let Some(remote_skills_dir) = get_remote_tool_skills_dir_with_db(&db, tool_key).await {
+            if let Err(e) =
+                ensure_remote_dir(session, &remote_skills_dir, config.skills_remote_bootstrap).await
+            {
+                log::warn!(
+                    "Skills SSH sync failed to prepare tool directory: tool_key={}, dir={}, error={}",
+                    tool_key,
+                    remote_skills_dir,
+                    e
+                );
+                tool_dir_errors.insert(tool_key.to_string(), e);
+            }
+        }
+    }
+
+    // 5. Sync/update each skill
     let mut synced_count = 0;
     let mut all_errors: Vec<String> = vec![];
+    let mut results: Vec<SSHSkillSyncResult> = Vec::with_capacity(skills.len());
     for (idx, skill) in skills.iter().enumerate() {
         let current_idx = (idx + 1) as u32;
 
@@ -167,13 +203,25 @@ pub async fn sync_skills_to_ssh(
                 current_item: skill.name.clone(),
                 current: current_idx,
                 total: total_skills,
-                message: format!(
-                    "Skills 同步: {}/{} - {}",
-                    current_idx, total_skills, skill.name
+                message: locale::format(
+                    "sync.skills_progress_item",
+                    &[
+                        ("current", &current_idx),
+                        ("total", &total_skills),
+                        ("name", &skill.name),
+                    ],
                 ),
             },
         );
 
+        let mut result = SSHSkillSyncResult {
+            skill_id: skill.id.clone(),
+            name: skill.name.clone(),
+            status: "unchanged".to_string(),
+            linked_tools: vec![],
+            errors: vec![],
+        };
+
         let source = resolve_skill_central_path(&skill.central_path, &central_dir);
         if !source.exists() {
             log::warn!(
@@ -181,6 +229,11 @@ pub async fn sync_skills_to_ssh(
                 skill.name,
                 source.display()
             );
+            result.status = "skipped".to_string();
+            result
+                .errors
+                .push(format!("本地源目录不存在: {}", source.display()));
+            results.push(result);
             continue;
         }
 
@@ -215,7 +268,16 @@ pub async fn sync_skills_to_ssh(
                 source_str,
                 remote_target
             );
-            match sync_directory(&source_str, &remote_target, session).await {
+            match sync_directory(
+                &source_str,
+                &remote_target,
+                session,
+                config.incremental_dir_sync,
+                &ExcludeFilter::default(),
+                false,
+            )
+            .await
+            {
                 Ok(_) => {
                     if let Err(e) = write_remote_file(session, &hash_file, local_hash).await {
                         log::warn!(
@@ -225,11 +287,15 @@ pub async fn sync_skills_to_ssh(
                         );
                     }
                     synced_count += 1;
+                    result.status = "updated".to_string();
                 }
                 Err(e) => {
                     let msg = format!("Skill '{}': {}", skill.name, e);
                     log::warn!("Skills SSH sync failed: {}", msg);
                     all_errors.push(msg);
+                    result.status = "error".to_string();
+                    result.errors.push(e);
+                    results.push(result);
                     continue;
                 }
             }
@@ -243,10 +309,20 @@ pub async fn sync_skills_to_ssh(
 
         // Ensure symlinks for each enabled tool
         for tool_key in &skill.enabled_tools {
+            if let Some(error) = tool_dir_errors.get(tool_key) {
+                result
+                    .errors
+                    .push(format!("{}: 工具目录不可用: {}", tool_key, error));
+                continue;
+            }
             if let Some(remote_skills_dir) = get_remote_tool_skills_dir_with_db(&db, tool_key).await
             {
-                let link_path = format!("{}/{}", remote_skills_dir, skill.name);
-                if !check_remote_symlink_exists(session, &link_path, &remote_target).await {
+                let link_path =
+                    format!("{}/{}", remote_skills_dir, target_dir_name(skill, tool_key));
+                // bootstrap 模式下总是重建符号链接，修复被手动改动的布局
+                if config.skills_remote_bootstrap
+                    || !check_remote_symlink_exists(session, &link_path, &remote_target).await
+                {
                     if let Err(error) =
                         create_remote_symlink(session, &remote_target, &link_path).await
                     {
@@ -258,6 +334,8 @@ pub async fn sync_skills_to_ssh(
                             link_path,
                             error
                         );
+                        result.errors.push(format!("{}: {}", tool_key, error));
+                        continue;
                     }
                 } else {
                     log::trace!(
@@ -267,6 +345,7 @@ pub async fn sync_skills_to_ssh(
                         link_path
                     );
                 }
+                result.linked_tools.push(tool_key.clone());
             }
         }
 
@@ -277,7 +356,8 @@ pub async fn sync_skills_to_ssh(
                 if let Some(remote_skills_dir) =
                     get_remote_tool_skills_dir_with_db(&db, tool_key).await
                 {
-                    let link_path = format!("{}/{}", remote_skills_dir, skill.name);
+                    let link_path =
+                        format!("{}/{}", remote_skills_dir, target_dir_name(skill, tool_key));
                     log::trace!(
                         "Skills SSH sync removing disabled-tool symlink: tool_key={}, skill_name={}, link_path={}",
                         tool_key,
@@ -296,6 +376,11 @@ pub async fn sync_skills_to_ssh(
                 }
             }
         }
+
+        if !result.errors.is_empty() && result.status != "error" {
+            result.status = "partial".to_string();
+        }
+        results.push(result);
     }
 
     info!(
@@ -305,11 +390,29 @@ pub async fn sync_skills_to_ssh(
         all_errors.len()
     );
 
+    let _ = app.emit("ssh-skills-sync-completed", results.clone());
+
     if !all_errors.is_empty() {
         return Err(all_errors.join("; "));
     }
 
-    let _ = app.emit("ssh-skills-sync-completed", ());
+    Ok(results)
+}
 
+/// Create a remote directory if missing; with `bootstrap` also make it user-writable
+/// (recursively for the central repo so uploaded skills stay readable)
+async fn ensure_remote_dir(session: &SshSession, dir: &str, bootstrap: bool) -> Result<(), String> {
+    let expanded = dir.replace("~", "$HOME");
+    let mut command = format!(
+        "if [ -e \"{0}\" ] && [ ! -d \"{0}\" ]; then echo NOT_DIR; exit 0; fi; mkdir -p \"{0}\"",
+        expanded
+    );
+    if bootstrap {
+        command.push_str(&format!(" && chmod -R u+rwX \"{}\"", expanded));
+    }
+    let output = session.exec_command(&command).await?;
+    if output.trim() == "NOT_DIR" {
+        return Err(format!("{} 已存在但不是目录", dir));
+    }
     Ok(())
 }
@@ -1,7 +1,56 @@
-use super::session::{self, upload_file_via_sftp, SshSession};
-use super::types::{SSHConnection, SSHConnectionResult, SSHFileMapping, SyncResult};
+use super::redact;
+use super::session::{self, upload_bytes_via_sftp, upload_file_via_sftp, SshSession};
+use super::types::{
+    SSHConnection, SSHConnectionResult, SSHFileMapping, SSHRemoteEntry, SSHRemoteFile, SyncResult,
+};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::path::Path;
 
+/// Remote checksum command; prefers sha256sum (Linux), falls back to shasum (macOS)
+const REMOTE_SHA256: &str = "sha256sum \"$@\" 2>/dev/null || shasum -a 256 \"$@\"";
+
+/// Compiled exclude globs of a mapping
+///
+/// A path is excluded when a glob matches its relative path (`/`-separated, from the
+/// mapping root), any of its parent directories, or any single path component.
+#[derive(Default)]
+pub struct ExcludeFilter(Vec<glob::Pattern>);
+
+impl ExcludeFilter {
+    pub fn new(patterns: &[String]) -> Result<Self, String> {
+        patterns
+            .iter()
+            .map(|p| p.trim())
+            .filter(|p| !p.is_empty())
+            .map(|p| glob::Pattern::new(p).map_err(|e| format!("无效的排除规则 '{}': {}", p, e)))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+
+    pub fn is_excluded(&self, relative: &str) -> bool {
+        if self.0.is_empty() {
+            return false;
+        }
+        let relative = relative.trim_start_matches("./");
+        let mut prefix = String::new();
+        for component in relative.split('/').filter(|c| !c.is_empty()) {
+            if !prefix.is_empty() {
+                prefix.push('/');
+            }
+            prefix.push_str(component);
+            if self
+                .0
+                .iter()
+                .any(|p| p.matches(component) || p.matches(&prefix))
+            {
+                return true;
+            }
+        }
+        false
+    }
+}
+
 fn mapping_kind(mapping: &SSHFileMapping) -> &'static str {
     if mapping.is_directory {
         "directory"
@@ -19,7 +68,8 @@ fn mapping_kind(mapping: &SSHFileMapping) -> &'static str {
 /// 测试 SSH 连接（独立短连接，不复用主连接）
 /// 用于测试未保存的连接配置
 pub async fn test_connection(conn: &SSHConnection) -> SSHConnectionResult {
-    match session::test_connection_with_command(conn, "uname -a").await {
+    let (result, host_key) = session::test_connection_with_command(conn, "uname -a").await;
+    match result {
         Ok(output) => {
             let server_info = output.trim().to_string();
             SSHConnectionResult {
@@ -30,12 +80,14 @@ pub async fn test_connection(conn: &SSHConnection) -> SSHConnectionResult {
                 } else {
                     Some(server_info)
                 },
+                host_key,
             }
         }
         Err(e) => SSHConnectionResult {
             connected: false,
             error: Some(e),
             server_info: None,
+            host_key,
         },
     }
 }
@@ -54,10 +106,12 @@ pub fn expand_local_path(path: &str) -> Result<String, String> {
 // ============================================================================
 
 /// 同步单个文件到远程（通过 SFTP）
+/// `redact_secrets` 为 true 时在本地脱敏后再上传，脱敏失败则不上传
 pub async fn sync_single_file(
     local_path: &str,
     remote_path: &str,
     session: &SshSession,
+    redact_secrets: bool,
 ) -> Result<Vec<String>, String> {
     let expanded = expand_local_path(local_path)?;
     log::trace!(
@@ -84,7 +138,15 @@ pub async fn sync_single_file(
     session.exec_command(&mkdir_cmd).await?;
 
     // SFTP 上传文件
-    session.upload_file(&expanded, remote_path).await?;
+    if redact_secrets {
+        let data = tokio::fs::read(&expanded)
+            .await
+            .map_err(|e| format!("读取本地文件失败 {}: {}", expanded, e))?;
+        let data = redact::redact_file_content(Path::new(&expanded), data)?;
+        session.upload_bytes(&data, remote_path).await?;
+    } else {
+        session.upload_file(&expanded, remote_path).await?;
+    }
     log::trace!(
         "SSH single file sync uploaded successfully: expanded_local_path={}, remote_path={}",
         expanded,
@@ -96,10 +158,14 @@ pub async fn sync_single_file(
 
 /// 同步整个目录到远程（通过 SFTP）
 /// 使用临时目录 + mv 实现原子替换，防止上传中断导致数据丢失
+/// `incremental` 为 true 且远程目录已存在时，改为增量同步（见 `sync_directory_incremental`）
 pub async fn sync_directory(
     local_path: &str,
     remote_path: &str,
     session: &SshSession,
+    incremental: bool,
+    exclude: &ExcludeFilter,
+    redact_secrets: bool,
 ) -> Result<Vec<String>, String> {
     let expanded = expand_local_path(local_path)?;
     log::trace!(
@@ -127,6 +193,31 @@ pub async fn sync_directory(
         return Err(format!("拒绝同步到危险路径: '{}'", remote_path));
     }
 
+    if incremental {
+        match sync_directory_incremental(&expanded, remote_path, session, exclude, redact_secrets)
+            .await
+        {
+            Ok(Some((uploaded, removed))) => {
+                log::trace!(
+                    "SSH directory incremental sync finished: expanded_local_path={}, remote_path={}, uploaded_files={}, removed_files={}",
+                    expanded,
+                    remote_path,
+                    uploaded,
+                    removed
+                );
+                return Ok(vec![format!("{} -> {}", local_path, remote_path)]);
+            }
+            Ok(None) => {}
+            Err(e) => {
+                log::warn!(
+                    "SSH directory incremental sync failed, falling back to full upload: remote_path={}, error={}",
+                    remote_path,
+                    e
+                );
+            }
+        }
+    }
+
     // 使用临时目录上传，完成后原子替换
     let tmp_suffix = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -140,7 +231,9 @@ pub async fn sync_directory(
     session.exec_command(&mkdir_cmd).await?;
 
     // SFTP 递归上传到临时目录（upload_dir 内部会展开 ~ 和 $HOME）
-    session.upload_dir(&expanded, &tmp_remote_path).await?;
+    session
+        .upload_dir(&expanded, &tmp_remote_path, exclude, redact_secrets)
+        .await?;
 
     // 原子替换：rm 旧目录 + mv 临时目录到目标
     let swap_cmd = format!(
@@ -164,11 +257,88 @@ pub async fn sync_directory(
     Ok(vec![format!("{} -> {}", local_path, remote_path)])
 }
 
+/// 增量同步目录：只上传内容有变化的文件，并删除远程多余的文件（类似 rsync --delete）
+/// 远程目录始终保留，不会出现配置短暂缺失的窗口
+/// 被排除的文件既不上传也不删除（同 rsync --exclude）
+/// 远程目录不存在时返回 None，由调用方走整体上传；成功时返回 (上传数, 删除数)
+/// 脱敏后的文件与本地哈希不同，因此每次都会重新上传
+async fn sync_directory_incremental(
+    expanded: &str,
+    remote_path: &str,
+    session: &SshSession,
+    exclude: &ExcludeFilter,
+    redact_secrets: bool,
+) -> Result<Option<(usize, usize)>, String> {
+    let Some(remote) = remote_dir_sha256(session, remote_path).await? else {
+        return Ok(None);
+    };
+    let local_root = Path::new(expanded);
+    let (local, _) = local_dir_sha256(local_root, exclude)?;
+    let remote_root = remote_path.trim_end_matches('/');
+
+    let changed: Vec<&String> = local
+        .iter()
+        .filter(|(relative, hash)| remote.get(*relative) != Some(*hash))
+        .map(|(relative, _)| relative)
+        .collect();
+    if !changed.is_empty() {
+        let sftp = session.create_sftp_session().await?;
+        for relative in &changed {
+            let local_file = local_root.join(relative.as_str());
+            let remote_file = format!("{}/{}", remote_root, relative);
+            if redact_secrets {
+                let data = redact_local_file(&local_file).await?;
+                upload_bytes_via_sftp(&sftp, &data, &remote_file).await?;
+            } else {
+                upload_file_via_sftp(&sftp, &local_file.to_string_lossy(), &remote_file).await?;
+            }
+        }
+    }
+
+    let stale: Vec<&String> = remote
+        .keys()
+        .filter(|relative| !local.contains_key(*relative) && !exclude.is_excluded(relative))
+        .collect();
+    if !stale.is_empty() {
+        let remote_target = remote_root.replace("~", "$HOME");
+        // 通过 stdin 传递 NUL 分隔的路径，避免命令行过长和引号转义问题
+        let mut paths = Vec::new();
+        for relative in &stale {
+            paths.extend_from_slice(relative.as_bytes());
+            paths.push(0);
+        }
+        session
+            .exec_command_with_stdin(
+                &format!("cd \"{}\" && xargs -0 rm -f --", remote_target),
+                &paths,
+            )
+            .await?;
+        session
+            .exec_command(&format!(
+                "find \"{}\" -mindepth 1 -type d -empty -delete",
+                remote_target
+            ))
+            .await?;
+    }
+
+    Ok(Some((changed.len(), stale.len())))
+}
+
+/// 读取本地文件并脱敏
+async fn redact_local_file(path: &Path) -> Result<Vec<u8>, String> {
+    let data = tokio::fs::read(path)
+        .await
+        .map_err(|e| format!("读取本地文件失败 {}: {}", path.display(), e))?;
+    redact::redact_file_content(path, data)
+}
+
 /// 同步符合 glob 模式的文件到远程
 pub async fn sync_pattern_files(
     local_pattern: &str,
     remote_dir: &str,
     session: &SshSession,
+    exclude: &ExcludeFilter,
+    redact_secrets: bool,
 ) -> Result<Vec<String>, String> {
     let expanded = expand_local_path(local_pattern)?;
     log::trace!(
@@ -178,10 +348,11 @@ pub async fn sync_pattern_files(
         remote_dir
     );
 
-    // 使用 glob 查找匹配的文件
+    // 使用 glob 查找匹配的文件（按文件名应用排除规则）
     let matches: Vec<_> = glob::glob(&expanded)
         .map_err(|e| format!("无效的 glob 模式: {}", e))?
         .filter_map(|entry| entry.ok())
+        .filter(|path| !is_excluded_file(path, exclude))
         .collect();
 
     if matches.is_empty() {
@@ -214,7 +385,14 @@ pub async fn sync_pattern_files(
 
         let remote_dest = format!("{}/{}", remote_dir.trim_end_matches('/'), file_name);
 
-        match upload_file_via_sftp(&sftp, &file_str, &remote_dest).await {
+        let upload = if redact_secrets {
+            // 脱敏失败时整个映射失败，不上传原文
+            let data = redact_local_file(file_path).await?;
+            upload_bytes_via_sftp(&sftp, &data, &remote_dest).await
+        } else {
+            upload_file_via_sftp(&sftp, &file_str, &remote_dest).await
+        };
+        match upload {
             Ok(()) => {
                 synced.push(format!(
                     "{} -> {}/{}",
@@ -250,10 +428,19 @@ pub async fn sync_pattern_files(
     Ok(synced)
 }
 
+/// Pattern matches are checked by file name
+pub fn is_excluded_file(path: &Path, exclude: &ExcludeFilter) -> bool {
+    path.file_name()
+        .map(|name| exclude.is_excluded(&name.to_string_lossy()))
+        .unwrap_or(false)
+}
+
 /// 同步单个文件映射
 pub async fn sync_file_mapping(
     mapping: &SSHFileMapping,
     session: &SshSession,
+    incremental_dir_sync: bool,
+    redact_secrets: bool,
 ) -> Result<Vec<String>, String> {
     let kind = mapping_kind(mapping);
     log::trace!(
@@ -266,13 +453,38 @@ pub async fn sync_file_mapping(
         mapping.remote_path
     );
 
-    let result = if mapping.is_directory {
-        sync_directory(&mapping.local_path, &mapping.remote_path, session).await
-    } else if mapping.is_pattern {
-        sync_pattern_files(&mapping.local_path, &mapping.remote_path, session).await
-    } else {
-        sync_single_file(&mapping.local_path, &mapping.remote_path, session).await
-    };
+    let result = async {
+        let exclude = ExcludeFilter::new(&mapping.exclude_patterns)?;
+        if mapping.is_directory {
+            sync_directory(
+                &mapping.local_path,
+                &mapping.remote_path,
+                session,
+                incremental_dir_sync,
+                &exclude,
+                redact_secrets,
+            )
+            .await
+        } else if mapping.is_pattern {
+            sync_pattern_files(
+                &mapping.local_path,
+                &mapping.remote_path,
+                session,
+                &exclude,
+                redact_secrets,
+            )
+            .await
+        } else {
+            sync_single_file(
+                &mapping.local_path,
+                &mapping.remote_path,
+                session,
+                redact_secrets,
+            )
+            .await
+        }
+    }
+    .await;
 
     match &result {
         Ok(files) if files.is_empty() => {
@@ -319,6 +531,8 @@ pub async fn sync_mappings(
     mappings: &[SSHFileMapping],
     session: &SshSession,
     module_filter: Option<&str>,
+    incremental_dir_sync: bool,
+    redact_secrets: bool,
 ) -> SyncResult {
     let mut synced_files = vec![];
     let mut skipped_files = vec![];
@@ -337,7 +551,7 @@ pub async fn sync_mappings(
     );
 
     for mapping in filtered_mappings {
-        match sync_file_mapping(mapping, session).await {
+        match sync_file_mapping(mapping, session, incremental_dir_sync, redact_secrets).await {
             Ok(files) if files.is_empty() => {
                 skipped_files.push(mapping.name.clone());
             }
@@ -454,6 +668,77 @@ pub async fn read_remote_file(session: &SshSession, path: &str) -> Result<String
     }
 }
 
+/// Max size of a remote file returned by `read_remote_file_with_metadata`
+const MAX_BROWSE_FILE_SIZE: u64 = 1024 * 1024;
+
+/// 通过 SFTP 列出远程目录（目录在前，按名称排序），符号链接按目标类型显示
+pub async fn list_remote_entries(
+    session: &SshSession,
+    path: &str,
+) -> Result<Vec<SSHRemoteEntry>, String> {
+    let sftp = session.create_sftp_session().await?;
+    let abs_path = session::resolve_remote_path(&sftp, path).await?;
+    let dir = abs_path.trim_end_matches('/');
+    let read_dir = sftp
+        .read_dir(&abs_path)
+        .await
+        .map_err(|e| format!("读取远程目录失败 {}: {}", abs_path, e))?;
+
+    let mut entries = Vec::new();
+    for entry in read_dir {
+        let name = entry.file_name();
+        if name == "." || name == ".." {
+            continue;
+        }
+        let entry_path = format!("{}/{}", dir, name);
+        let mut metadata = entry.metadata();
+        if entry.file_type().is_symlink() {
+            if let Ok(target) = sftp.metadata(&entry_path).await {
+                metadata = target;
+            }
+        }
+        entries.push(SSHRemoteEntry {
+            name,
+            path: entry_path,
+            is_dir: metadata.is_dir(),
+            size: metadata.size.unwrap_or(0),
+            mtime: metadata.mtime.map(|t| t as i64 * 1000),
+        });
+    }
+    entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)));
+    Ok(entries)
+}
+
+/// 读取远程文件内容及元数据（用于文件选择器预览，限制大小）
+pub async fn read_remote_file_with_metadata(
+    session: &SshSession,
+    path: &str,
+) -> Result<SSHRemoteFile, String> {
+    let sftp = session.create_sftp_session().await?;
+    let abs_path = session::resolve_remote_path(&sftp, path).await?;
+    let metadata = sftp
+        .metadata(&abs_path)
+        .await
+        .map_err(|e| format!("远程文件不存在 {}: {}", abs_path, e))?;
+    if metadata.is_dir() {
+        return Err(format!("{} 是目录", abs_path));
+    }
+    let size = metadata.size.unwrap_or(0);
+    if size > MAX_BROWSE_FILE_SIZE {
+        return Err(format!(
+            "文件过大 ({} bytes)，最多预览 {} bytes",
+            size, MAX_BROWSE_FILE_SIZE
+        ));
+    }
+
+    Ok(SSHRemoteFile {
+        content: read_remote_file(session, &abs_path).await?,
+        path: abs_path,
+        size,
+        mtime: metadata.mtime.map(|t| t as i64 * 1000),
+    })
+}
+
 /// 将内容写入远程文件
 pub async fn write_remote_file(
     session: &SshSession,
@@ -540,3 +825,111 @@ pub async fn check_remote_symlink_exists(
         Err(_) => false,
     }
 }
+
+/// SHA-256 of a local file (hex)
+pub fn local_sha256(path: &Path) -> Result<String, String> {
+    let bytes =
+        std::fs::read(path).map_err(|e| format!("读取本地文件失败 {}: {}", path.display(), e))?;
+    Ok(hex::encode(Sha256::digest(&bytes)))
+}
+
+/// SHA-256 of every file under a local dir, keyed by `/`-separated relative path,
+/// plus the total size. Follows symlinks like the SFTP upload does; excluded paths are skipped.
+pub fn local_dir_sha256(
+    root: &Path,
+    exclude: &ExcludeFilter,
+) -> Result<(HashMap<String, String>, u64), String> {
+    let mut hashes = HashMap::new();
+    let mut total_size = 0u64;
+    for entry in walkdir::WalkDir::new(root)
+        .follow_links(true)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+    {
+        let relative = entry
+            .path()
+            .strip_prefix(root)
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_default();
+        if exclude.is_excluded(&relative) {
+            continue;
+        }
+        total_size += entry.metadata().map(|m| m.len()).unwrap_or(0);
+        hashes.insert(relative, local_sha256(entry.path())?);
+    }
+    Ok((hashes, total_size))
+}
+
+/// Parse `<hash>  <path>` lines into path -> hash
+fn parse_checksum_lines(output: &str) -> HashMap<String, String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (hash, path) = line.trim().split_once(char::is_whitespace)?;
+            let path = path.trim_start().trim_start_matches('*');
+            Some((
+                path.trim_start_matches("./").to_string(),
+                hash.to_lowercase(),
+            ))
+        })
+        .collect()
+}
+
+/// SHA-256 of a single remote file, None when it does not exist
+pub async fn remote_file_sha256(
+    session: &SshSession,
+    remote_path: &str,
+) -> Result<Option<String>, String> {
+    let remote_target = remote_path.replace("~", "$HOME");
+    let cmd = format!(
+        "if [ -f \"{0}\" ]; then sh -c '{1}' _ \"{0}\"; else echo MISSING; fi",
+        remote_target, REMOTE_SHA256
+    );
+    let output = session.exec_command(&cmd).await?;
+    if output.trim() == "MISSING" {
+        return Ok(None);
+    }
+    Ok(output
+        .split_whitespace()
+        .next()
+        .map(|hash| hash.to_lowercase()))
+}
+
+/// SHA-256 of every file under a remote dir (relative paths), None when it does not exist
+pub async fn remote_dir_sha256(
+    session: &SshSession,
+    remote_path: &str,
+) -> Result<Option<HashMap<String, String>>, String> {
+    let remote_target = remote_path.replace("~", "$HOME");
+    let cmd = format!(
+        "if [ -d \"{}\" ]; then cd \"{}\" && find . -type f -exec sh -c '{}' _ {{}} +; else echo MISSING; fi",
+        remote_target, remote_target, REMOTE_SHA256
+    );
+    let output = session.exec_command(&cmd).await?;
+    if output.trim() == "MISSING" {
+        return Ok(None);
+    }
+    Ok(Some(parse_checksum_lines(&output)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExcludeFilter;
+
+    #[test]
+    fn exclude_filter_matches_names_and_parent_dirs() {
+        let filter = ExcludeFilter::new(&["*.bak.*".to_string(), "cache".to_string()]).unwrap();
+        assert!(filter.is_excluded("opencode.jsonc.bak.20240101"));
+        assert!(filter.is_excluded("nested/settings.json.bak.1"));
+        assert!(filter.is_excluded("cache/models.json"));
+        assert!(filter.is_excluded("plugins/cache/index.js"));
+        assert!(!filter.is_excluded("plugins/index.js"));
+        assert!(!ExcludeFilter::default().is_excluded("cache/models.json"));
+    }
+
+    #[test]
+    fn exclude_filter_rejects_invalid_glob() {
+        assert!(ExcludeFilter::new(&["[".to_string()]).is_err());
+    }
+}
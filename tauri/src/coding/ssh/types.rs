@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 // Re-use SyncResult and SyncProgress from wsl module
 pub use super::super::wsl::{SyncProgress, SyncResult};
 use crate::coding::runtime_location::WslDirectModuleStatus;
+use crate::db_timestamp::Timestamp;
 
 // ============================================================================
 // SSH Connection Types
@@ -23,6 +24,22 @@ pub struct SSHConnection {
     pub private_key_content: String,
     pub passphrase: String,
     pub sort_order: u32,
+    /// Replace API keys and tokens in synced config files before upload (shared servers)
+    #[serde(default)]
+    pub redact_secrets: bool,
+    /// Rewrite rules applied to the remote path of every mapping
+    #[serde(default)]
+    pub path_rewrites: Vec<SSHPathRewrite>,
+}
+
+/// Remote path rewrite rule (prefix replacement, or regex with `$1` captures)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SSHPathRewrite {
+    pub pattern: String,
+    pub replacement: String,
+    #[serde(default)]
+    pub is_regex: bool,
 }
 
 // ============================================================================
@@ -41,6 +58,9 @@ pub struct SSHFileMapping {
     pub enabled: bool,
     pub is_pattern: bool,
     pub is_directory: bool,
+    /// Globs skipped by directory and pattern mappings (e.g. `*.bak.*`, `cache`)
+    #[serde(default)]
+    pub exclude_patterns: Vec<String>,
 }
 
 // ============================================================================
@@ -58,9 +78,19 @@ pub struct SSHSyncConfig {
     pub sync_skills: bool,
     pub file_mappings: Vec<SSHFileMapping>,
     pub connections: Vec<SSHConnection>,
-    pub last_sync_time: Option<String>,
+    #[serde(default, deserialize_with = "crate::db_timestamp::lenient")]
+    pub last_sync_time: Option<Timestamp>,
     pub last_sync_status: String, // "success" | "error" | "never"
     pub last_sync_error: Option<String>,
+    /// Directory mappings upload only changed files and delete stale remote ones
+    #[serde(default)]
+    pub incremental_dir_sync: bool,
+    /// Skills sync also fixes permissions and re-creates every tool symlink
+    #[serde(default)]
+    pub skills_remote_bootstrap: bool,
+    /// File mappings that failed in the last sync, re-run by `ssh_retry_failed`
+    #[serde(default)]
+    pub last_failed_mapping_ids: Vec<String>,
     #[serde(default)]
     pub module_statuses: Vec<WslDirectModuleStatus>,
 }
@@ -77,11 +107,34 @@ impl Default for SSHSyncConfig {
             last_sync_time: None,
             last_sync_status: "never".to_string(),
             last_sync_error: None,
+            incremental_dir_sync: false,
+            skills_remote_bootstrap: false,
+            last_failed_mapping_ids: vec![],
             module_statuses: vec![],
         }
     }
 }
 
+impl SSHSyncConfig {
+    pub fn active_connection(&self) -> Option<&SSHConnection> {
+        self.connections
+            .iter()
+            .find(|c| c.id == self.active_connection_id)
+    }
+
+    /// Whether configs synced to the active connection must have secrets redacted
+    pub fn redact_secrets(&self) -> bool {
+        self.active_connection().is_some_and(|c| c.redact_secrets)
+    }
+
+    /// Remote path rewrite rules of the active connection
+    pub fn path_rewrites(&self) -> &[SSHPathRewrite] {
+        self.active_connection()
+            .map(|c| c.path_rewrites.as_slice())
+            .unwrap_or_default()
+    }
+}
+
 // ============================================================================
 // SSH Result Types
 // ============================================================================
@@ -93,6 +146,127 @@ pub struct SSHConnectionResult {
     pub connected: bool,
     pub error: Option<String>,
     pub server_info: Option<String>,
+    /// Host key seen during the handshake (fingerprint to show before trusting)
+    pub host_key: Option<SSHHostKeyInfo>,
+}
+
+/// Server host key and whether it matches the trusted one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SSHHostKeyInfo {
+    pub host: String,
+    pub port: u16,
+    pub key_type: String,
+    pub fingerprint: String, // "SHA256:..."
+    pub known_fingerprint: Option<String>,
+    pub status: String, // "trusted" | "unknown" | "changed"
+}
+
+/// One mapping in a pull preview (remote -> local)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SSHPullItem {
+    pub mapping_id: String,
+    pub name: String,
+    pub module: String,
+    pub local_path: String,
+    pub remote_path: String,
+    pub status: String, // "unchanged" | "modified" | "remote_only" | "remote_missing" | "unsupported" | "error"
+    pub local_content: Option<String>,
+    pub remote_content: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Per-skill outcome of a skills sync to the remote host
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SSHSkillSyncResult {
+    pub skill_id: String,
+    pub name: String,
+    pub status: String, // "updated" | "unchanged" | "partial" | "skipped" | "error"
+    pub linked_tools: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+/// Per-tool outcome of a remote CLI bootstrap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SSHBootstrapToolResult {
+    pub tool: String,   // "opencode" | "claude" | "codex"
+    pub action: String, // "present" | "installed" | "missing" | "failed"
+    pub version: Option<String>,
+    pub method: Option<String>, // "npm" | "brew"
+    pub error: Option<String>,
+}
+
+/// Entry returned when browsing a remote directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SSHRemoteEntry {
+    pub name: String,
+    pub path: String,
+    pub is_dir: bool,
+    pub size: u64,
+    pub mtime: Option<i64>, // unix millis
+}
+
+/// Remote file content with metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SSHRemoteFile {
+    pub path: String,
+    pub size: u64,
+    pub mtime: Option<i64>, // unix millis
+    pub content: String,
+}
+
+/// One recorded sync run (`ssh_sync_log` table)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SSHSyncLogEntry {
+    pub id: String,
+    pub timestamp: Option<Timestamp>,
+    pub connection_id: String,
+    pub connection_name: Option<String>,
+    pub module: Option<String>,
+    pub success: bool,
+    pub synced_files: Vec<String>,
+    pub skipped_files: Vec<String>,
+    pub errors: Vec<String>,
+    pub duration_ms: u64,
+}
+
+/// Managed local port forward (`ssh -L`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SSHPortForward {
+    pub id: String,
+    pub connection_id: String,
+    pub connection_name: String,
+    pub local_port: u16,
+    pub remote_host: String,
+    pub remote_port: u16,
+    pub status: String, // "running" | "disconnected"
+    pub active_connections: u32,
+    pub started_at: String,
+}
+
+/// One output line of a remote command (`ssh-exec-output` event)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SSHExecOutput {
+    pub exec_id: String,
+    pub stream: String, // "stdout" | "stderr"
+    pub line: String,
+}
+
+/// Remote command finished or was cancelled (`ssh-exec-finished` event)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SSHExecFinished {
+    pub exec_id: String,
+    pub exit_code: Option<u32>,
+    pub cancelled: bool,
 }
 
 /// SSH status result
@@ -101,7 +275,7 @@ pub struct SSHConnectionResult {
 pub struct SSHStatusResult {
     pub ssh_available: bool,
     pub active_connection_name: Option<String>,
-    pub last_sync_time: Option<String>,
+    pub last_sync_time: Option<Timestamp>,
     pub last_sync_status: String,
     pub last_sync_error: Option<String>,
 }
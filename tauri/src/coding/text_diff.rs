@@ -0,0 +1,101 @@
+//! Line diff for sync and export previews
+
+/// Skip the line diff when the LCS table would get larger than this
+const MAX_DIFF_CELLS: usize = 4_000_000;
+/// Unchanged lines shown around each change
+const DIFF_CONTEXT: usize = 2;
+
+enum DiffOp<'a> {
+    Equal(&'a str),
+    Delete(&'a str),
+    Insert(&'a str),
+}
+
+/// Line diff of `old` against `new`, `- ` / `+ ` prefixed with some context
+pub fn line_diff(old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().map(|l| l.trim_end_matches('\r')).collect();
+    let new: Vec<&str> = new.lines().map(|l| l.trim_end_matches('\r')).collect();
+    let (n, m) = (old.len(), new.len());
+    if (n + 1).saturating_mul(m + 1) > MAX_DIFF_CELLS {
+        return format!("  (diff skipped: {} -> {} lines)", n, m);
+    }
+
+    // lcs[i][j] = LCS length of old[i..] and new[j..]
+    let mut lcs = vec![0u32; (n + 1) * (m + 1)];
+    let at = |i: usize, j: usize| i * (m + 1) + j;
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[at(i, j)] = if old[i] == new[j] {
+                lcs[at(i + 1, j + 1)] + 1
+            } else {
+                lcs[at(i + 1, j)].max(lcs[at(i, j + 1)])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && old[i] == new[j] {
+            ops.push(DiffOp::Equal(old[i]));
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[at(i + 1, j)] >= lcs[at(i, j + 1)]) {
+            ops.push(DiffOp::Delete(old[i]));
+            i += 1;
+        } else {
+            ops.push(DiffOp::Insert(new[j]));
+            j += 1;
+        }
+    }
+
+    let changed: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, op)| !matches!(op, DiffOp::Equal(_)))
+        .map(|(idx, _)| idx)
+        .collect();
+    let visible = |idx: usize| {
+        changed
+            .iter()
+            .any(|c| idx + DIFF_CONTEXT >= *c && idx <= c + DIFF_CONTEXT)
+    };
+
+    let mut lines = Vec::new();
+    let mut skipped = false;
+    for (idx, op) in ops.iter().enumerate() {
+        if !visible(idx) {
+            skipped = true;
+            continue;
+        }
+        if std::mem::take(&mut skipped) && !lines.is_empty() {
+            lines.push("  ...".to_string());
+        }
+        lines.push(match op {
+            DiffOp::Equal(line) => format!("  {}", line),
+            DiffOp::Delete(line) => format!("- {}", line),
+            DiffOp::Insert(line) => format!("+ {}", line),
+        });
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_diff_shows_changes_with_context() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n";
+        let new = "1\nX\n3\n4\n5\n6\n7\n8\nY\n10\n";
+        assert_eq!(
+            line_diff(old, new),
+            "  1\n- 2\n+ X\n  3\n  4\n  ...\n  7\n  8\n- 9\n+ Y\n  10"
+        );
+    }
+
+    #[test]
+    fn line_diff_ignores_crlf() {
+        assert_eq!(line_diff("a\r\nb\r\n", "a\nc\n"), "  a\n- b\n+ c");
+    }
+}
@@ -85,11 +85,12 @@ pub const BUILTIN_TOOLS: &[BuiltinTool] = &[
     // Amp - supports both Skills and MCP
     // MCP path uses VSCode plugin config path (%APPDATA%/Code/User/mcp.json)
     // Skills use home_dir: ~/.config/agents/skills
+    // Detection uses the Amp CLI config dir; the `amp` binary is also checked for Skills
     BuiltinTool {
         key: "amp",
         display_name: "Amp",
         relative_skills_dir: Some("~/.config/agents/skills"),
-        relative_detect_dir: Some("%APPDATA%/Code"),
+        relative_detect_dir: Some("~/.config/amp"),
         mcp_config_path: Some("%APPDATA%/Code/User/mcp.json"),
         mcp_config_format: Some("json"),
         mcp_field: Some("servers"),
@@ -118,7 +119,20 @@ pub const BUILTIN_TOOLS: &[BuiltinTool] = &[
         mcp_config_format: Some("json"),
         mcp_field: Some("mcpServers"),
     },
+    // Cline - supports both Skills and MCP
+    // MCP path uses VSCode plugin config path
+    // Skills use home_dir: ~/.cline/skills
+    BuiltinTool {
+        key: "cline",
+        display_name: "Cline",
+        relative_skills_dir: Some("~/.cline/skills"),
+        relative_detect_dir: Some("%APPDATA%/Code/User/globalStorage/saoudrizwan.claude-dev"),
+        mcp_config_path: Some("%APPDATA%/Code/User/globalStorage/saoudrizwan.claude-dev/settings/cline_mcp_settings.json"),
+        mcp_config_format: Some("json"),
+        mcp_field: Some("mcpServers"),
+    },
     // Goose - Skills only
+    // The `goose` binary is also checked for Skills (its config dir differs on Windows)
     BuiltinTool {
         key: "goose",
         display_name: "Goose",
@@ -151,6 +165,18 @@ pub const BUILTIN_TOOLS: &[BuiltinTool] = &[
         mcp_config_format: Some("json"),
         mcp_field: Some("servers"),
     },
+    // GitHub Copilot CLI - supports both Skills and MCP
+    // Skills share ~/.copilot/skills with the VSCode extension, which reads it too.
+    // Servers need `tools` to expose anything; config_sync adds `"tools": ["*"]`.
+    BuiltinTool {
+        key: "github_copilot_cli",
+        display_name: "GitHub Copilot CLI",
+        relative_skills_dir: Some("~/.copilot/skills"),
+        relative_detect_dir: Some("~/.copilot"),
+        mcp_config_path: Some("~/.copilot/mcp-config.json"),
+        mcp_config_format: Some("json"),
+        mcp_field: Some("mcpServers"),
+    },
     // OpenClaw - supports both Skills and MCP
     BuiltinTool {
         key: "openclaw",
@@ -197,7 +223,7 @@ pub const BUILTIN_TOOLS: &[BuiltinTool] = &[
         display_name: "Windsurf",
         relative_skills_dir: Some("~/.codeium/windsurf/skills"),
         relative_detect_dir: Some("~/.codeium/windsurf"),
-        mcp_config_path: Some("~/.codeium/mcp_config.json"),
+        mcp_config_path: Some("~/.codeium/windsurf/mcp_config.json"),
         mcp_config_format: Some("json"),
         mcp_field: Some("mcpServers"),
     },
@@ -0,0 +1,60 @@
+//! Claude Code: `<root>/projects/<project>/<session>.jsonl`
+//!
+//! Each assistant line carries `message.usage`. A streamed reply is written as several
+//! lines with the same message id and request id, so those identify one request.
+
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::{parse_rfc3339_ms, UsageEntry, TOOL_CLAUDE_CODE};
+
+pub fn parse_session_file(path: &Path) -> Vec<UsageEntry> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter_map(|value| parse_line(&value))
+        .collect()
+}
+
+fn parse_line(value: &Value) -> Option<UsageEntry> {
+    if value.get("type").and_then(Value::as_str) != Some("assistant") {
+        return None;
+    }
+    let message = value.get("message")?;
+    let usage = message.get("usage")?;
+    let model = message.get("model").and_then(Value::as_str)?;
+    // Locally generated messages (errors, interruptions) are not billed
+    if model.starts_with('<') {
+        return None;
+    }
+    let message_id = message.get("id").and_then(Value::as_str)?;
+    let request_id = value
+        .get("requestId")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let tokens = |key: &str| usage.get(key).and_then(Value::as_i64).unwrap_or(0);
+
+    Some(UsageEntry {
+        tool: TOOL_CLAUDE_CODE,
+        source_id: format!("{}:{}", message_id, request_id),
+        model: model.to_string(),
+        session_id: value
+            .get("sessionId")
+            .and_then(Value::as_str)
+            .map(String::from),
+        timestamp: value
+            .get("timestamp")
+            .and_then(Value::as_str)
+            .and_then(parse_rfc3339_ms)?,
+        input_tokens: tokens("input_tokens"),
+        output_tokens: tokens("output_tokens"),
+        cache_read_tokens: tokens("cache_read_input_tokens"),
+        cache_write_tokens: tokens("cache_creation_input_tokens"),
+        reasoning_tokens: 0,
+        cost_usd: None,
+    })
+}
@@ -0,0 +1,140 @@
+//! Codex: `<root>/sessions/YYYY/MM/DD/rollout-*.jsonl`
+//!
+//! `token_count` events report the last request's usage and a running session total.
+//! The same event can be written more than once, so a request is only counted when
+//! the session total grows. The model comes from the latest `turn_context`.
+
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::{parse_rfc3339_ms, UsageEntry, TOOL_CODEX};
+
+pub fn parse_session_file(path: &Path) -> Vec<UsageEntry> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let fallback_session_id = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    parse_lines(
+        content
+            .lines()
+            .filter_map(|line| serde_json::from_str::<Value>(line).ok()),
+        &fallback_session_id,
+    )
+}
+
+fn parse_lines(lines: impl Iterator<Item = Value>, fallback_session_id: &str) -> Vec<UsageEntry> {
+    let mut entries = Vec::new();
+    let mut session_id = fallback_session_id.to_string();
+    let mut model = String::from("unknown");
+    let mut last_total = 0;
+
+    for value in lines {
+        let Some(payload) = value.get("payload") else {
+            continue;
+        };
+        match value.get("type").and_then(Value::as_str) {
+            Some("session_meta") => {
+                if let Some(id) = payload.get("id").and_then(Value::as_str) {
+                    session_id = id.to_string();
+                }
+            }
+            Some("turn_context") => {
+                if let Some(turn_model) = payload.get("model").and_then(Value::as_str) {
+                    model = turn_model.to_string();
+                }
+            }
+            Some("event_msg")
+                if payload.get("type").and_then(Value::as_str) == Some("token_count") =>
+            {
+                let Some(info) = payload.get("info").filter(|info| !info.is_null()) else {
+                    continue;
+                };
+                let total = info
+                    .pointer("/total_token_usage/total_tokens")
+                    .and_then(Value::as_i64)
+                    .unwrap_or(0);
+                let Some(last) = info.get("last_token_usage") else {
+                    continue;
+                };
+                if total <= last_total {
+                    continue;
+                }
+                last_total = total;
+                let Some(timestamp) = value
+                    .get("timestamp")
+                    .and_then(Value::as_str)
+                    .and_then(parse_rfc3339_ms)
+                else {
+                    continue;
+                };
+                let tokens = |key: &str| last.get(key).and_then(Value::as_i64).unwrap_or(0);
+                let cached = tokens("cached_input_tokens");
+
+                entries.push(UsageEntry {
+                    tool: TOOL_CODEX,
+                    source_id: format!("{}:{}", session_id, total),
+                    model: model.clone(),
+                    session_id: Some(session_id.clone()),
+                    timestamp,
+                    // Codex counts cached tokens as part of the input
+                    input_tokens: (tokens("input_tokens") - cached).max(0),
+                    output_tokens: tokens("output_tokens"),
+                    cache_read_tokens: cached,
+                    cache_write_tokens: 0,
+                    reasoning_tokens: tokens("reasoning_output_tokens"),
+                    cost_usd: None,
+                });
+            }
+            _ => {}
+        }
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn token_count(total: i64) -> Value {
+        json!({
+            "timestamp": "2026-01-02T03:04:05.000Z",
+            "type": "event_msg",
+            "payload": {
+                "type": "token_count",
+                "info": {
+                    "total_token_usage": { "total_tokens": total },
+                    "last_token_usage": {
+                        "input_tokens": 100,
+                        "cached_input_tokens": 60,
+                        "output_tokens": 7,
+                        "reasoning_output_tokens": 3
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn counts_each_growing_token_total_once() {
+        let lines = vec![
+            json!({ "type": "session_meta", "payload": { "id": "s1" } }),
+            json!({ "type": "turn_context", "payload": { "model": "gpt-5-codex" } }),
+            token_count(107),
+            token_count(107),
+            token_count(214),
+        ];
+        let entries = parse_lines(lines.into_iter(), "fallback");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].source_id, "s1:107");
+        assert_eq!(entries[0].model, "gpt-5-codex");
+        assert_eq!(
+            (entries[0].input_tokens, entries[0].cache_read_tokens),
+            (40, 60)
+        );
+    }
+}
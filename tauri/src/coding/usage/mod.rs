@@ -0,0 +1,410 @@
+//! Usage Module
+//!
+//! Collects token usage from the local logs of Claude Code, OpenCode and Codex into the
+//! `usage_record` table, one record per model request, and aggregates it by tool,
+//! model and day:
+//! - Claude Code: session JSONL files under `<root>/projects`
+//! - Codex: rollout JSONL files under `<root>/sessions`
+//! - OpenCode: assistant messages in its SQLite database or JSON storage
+//!
+//! Record ids are derived from the tool's own message ids, so rescanning a log only
+//! updates existing records. A scan only reads files changed since the previous one.
+//! Only OpenCode records a cost; other tools report tokens only.
+
+mod claude_code;
+mod codex;
+mod open_code;
+
+use std::path::{Path, PathBuf};
+
+use chrono::TimeZone;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::coding::db_record_id;
+use crate::coding::runtime_location::{
+    get_claude_runtime_location_async, get_codex_runtime_location_async,
+    get_opencode_runtime_location_async,
+};
+use crate::coding::session_manager::resolve_opencode_data_root;
+use crate::DbState;
+
+pub const TOOL_CLAUDE_CODE: &str = "claudecode";
+pub const TOOL_CODEX: &str = "codex";
+pub const TOOL_OPENCODE: &str = "opencode";
+const TOOLS: &[&str] = &[TOOL_CLAUDE_CODE, TOOL_CODEX, TOOL_OPENCODE];
+
+/// Files changed shortly before the last scan are read again, for writes still in flight
+const RESCAN_MARGIN_MS: i64 = 60 * 60 * 1000;
+const UPSERT_BATCH_SIZE: usize = 200;
+
+/// One model request parsed from a tool's logs
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageEntry {
+    pub tool: &'static str,
+    /// Id of the request in the tool's logs, unique per tool
+    pub source_id: String,
+    pub model: String,
+    pub session_id: Option<String>,
+    /// Epoch milliseconds
+    pub timestamp: i64,
+    /// Input tokens not read from the cache
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cache_read_tokens: i64,
+    pub cache_write_tokens: i64,
+    pub reasoning_tokens: i64,
+    pub cost_usd: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageToolRefresh {
+    pub tool: String,
+    /// Requests found in the files read this time
+    pub records: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageGroupBy {
+    Tool,
+    Model,
+    Day,
+}
+
+impl UsageGroupBy {
+    fn field(self) -> &'static str {
+        match self {
+            Self::Tool => "tool",
+            Self::Model => "model",
+            Self::Day => "day",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageFilter {
+    pub tool: Option<String>,
+    pub model: Option<String>,
+    /// First local day included, `YYYY-MM-DD`
+    pub since: Option<String>,
+    /// Last local day included, `YYYY-MM-DD`
+    pub until: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageAggregate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub day: Option<String>,
+    pub requests: i64,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cache_read_tokens: i64,
+    pub cache_write_tokens: i64,
+    pub reasoning_tokens: i64,
+    /// Sum over the requests with a known cost
+    pub cost_usd: f64,
+    pub priced_requests: i64,
+}
+
+/// Where a tool's logs are read from
+enum UsageSource {
+    JsonlFiles {
+        root: PathBuf,
+        parse: fn(&Path) -> Vec<UsageEntry>,
+    },
+    OpenCode {
+        database: PathBuf,
+        storage: PathBuf,
+    },
+}
+
+/// Read new usage from the tools' logs into `usage_record`.
+///
+/// With `full`, every log is read again instead of only those changed since the last scan.
+#[tauri::command]
+pub async fn refresh_usage_records(
+    state: tauri::State<'_, DbState>,
+    full: Option<bool>,
+) -> Result<Vec<UsageToolRefresh>, String> {
+    let mut results = Vec::new();
+    for tool in TOOLS {
+        let outcome = refresh_tool(&state, tool, full.unwrap_or(false)).await;
+        if let Err(e) = &outcome {
+            log::warn!("读取 {} 用量失败: {}", tool, e);
+        }
+        results.push(UsageToolRefresh {
+            tool: tool.to_string(),
+            records: *outcome.as_ref().unwrap_or(&0),
+            error: outcome.err(),
+        });
+    }
+    Ok(results)
+}
+
+/// Token and cost totals grouped by any of tool, model and day
+#[tauri::command]
+pub async fn get_usage_summary(
+    state: tauri::State<'_, DbState>,
+    filter: Option<UsageFilter>,
+    group_by: Vec<UsageGroupBy>,
+) -> Result<Vec<UsageAggregate>, String> {
+    let filter = filter.unwrap_or_default();
+    let mut conditions = Vec::new();
+    if filter.tool.is_some() {
+        conditions.push("tool = $tool");
+    }
+    if filter.model.is_some() {
+        conditions.push("model = $model");
+    }
+    if filter.since.is_some() {
+        conditions.push("day >= $since");
+    }
+    if filter.until.is_some() {
+        conditions.push("day <= $until");
+    }
+    let where_clause = if conditions.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", conditions.join(" AND "))
+    };
+
+    let mut fields: Vec<&str> = Vec::new();
+    for group in &group_by {
+        if !fields.contains(&group.field()) {
+            fields.push(group.field());
+        }
+    }
+    let group_clause = if fields.is_empty() {
+        "GROUP ALL".to_string()
+    } else {
+        format!("GROUP BY {}", fields.join(", "))
+    };
+    let selected = fields
+        .iter()
+        .map(|field| format!("{}, ", field))
+        .collect::<String>();
+
+    let query = format!(
+        "SELECT {selected}count() AS requests, \
+         math::sum(input_tokens) AS input_tokens, \
+         math::sum(output_tokens) AS output_tokens, \
+         math::sum(cache_read_tokens) AS cache_read_tokens, \
+         math::sum(cache_write_tokens) AS cache_write_tokens, \
+         math::sum(reasoning_tokens) AS reasoning_tokens, \
+         math::sum(cost_usd ?? 0) AS cost_usd, \
+         count(cost_usd != NONE) AS priced_requests \
+         FROM usage_record{where_clause} {group_clause}"
+    );
+    let records: Vec<Value> = state
+        .db()
+        .query(query)
+        .bind(("tool", filter.tool))
+        .bind(("model", filter.model))
+        .bind(("since", filter.since))
+        .bind(("until", filter.until))
+        .await
+        .map_err(|e| format!("Failed to query usage: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to query usage: {}", e))?;
+
+    let mut aggregates: Vec<UsageAggregate> = records
+        .into_iter()
+        .filter_map(|record| serde_json::from_value(record).ok())
+        .filter(|aggregate: &UsageAggregate| aggregate.requests > 0)
+        .collect();
+    aggregates.sort_by(|a, b| {
+        b.day
+            .cmp(&a.day)
+            .then_with(|| a.tool.cmp(&b.tool))
+            .then_with(|| b.input_tokens.cmp(&a.input_tokens))
+    });
+    Ok(aggregates)
+}
+
+async fn refresh_tool(state: &DbState, tool: &str, full: bool) -> Result<usize, String> {
+    let source = resolve_source(state, tool).await?;
+    let scan_state_id = db_record_id("usage_scan_state", tool);
+    let since_ms = if full {
+        0
+    } else {
+        let records: Vec<Value> = state
+            .db()
+            .query(format!("SELECT last_scan_ms FROM {}", scan_state_id))
+            .await
+            .map_err(|e| format!("Failed to read usage scan state: {}", e))?
+            .take(0)
+            .map_err(|e| format!("Failed to read usage scan state: {}", e))?;
+        records
+            .first()
+            .and_then(|record| record.get("last_scan_ms"))
+            .and_then(Value::as_i64)
+            .map_or(0, |last_scan| (last_scan - RESCAN_MARGIN_MS).max(0))
+    };
+    let started_at = chrono::Utc::now().timestamp_millis();
+
+    let entries = tauri::async_runtime::spawn_blocking(move || collect_entries(&source, since_ms))
+        .await
+        .map_err(|e| format!("Failed to read usage logs: {}", e))??;
+    store_entries(state, &entries).await?;
+
+    state
+        .db()
+        .query(format!(
+            "UPSERT {} CONTENT {{ last_scan_ms: $started_at }}",
+            scan_state_id
+        ))
+        .bind(("started_at", started_at))
+        .await
+        .and_then(|response| response.check())
+        .map_err(|e| format!("Failed to save usage scan state: {}", e))?;
+    Ok(entries.len())
+}
+
+async fn resolve_source(state: &DbState, tool: &str) -> Result<UsageSource, String> {
+    let db = state.db();
+    match tool {
+        TOOL_CLAUDE_CODE => Ok(UsageSource::JsonlFiles {
+            root: get_claude_runtime_location_async(&db)
+                .await?
+                .host_path
+                .join("projects"),
+            parse: claude_code::parse_session_file,
+        }),
+        TOOL_CODEX => Ok(UsageSource::JsonlFiles {
+            root: get_codex_runtime_location_async(&db)
+                .await?
+                .host_path
+                .join("sessions"),
+            parse: codex::parse_session_file,
+        }),
+        TOOL_OPENCODE => {
+            let location = get_opencode_runtime_location_async(&db).await?;
+            let data_root = resolve_opencode_data_root(&location)?;
+            Ok(UsageSource::OpenCode {
+                database: data_root.join("opencode.db"),
+                storage: data_root.join("storage").join("message"),
+            })
+        }
+        _ => Err(format!("Unsupported usage tool: {}", tool)),
+    }
+}
+
+fn collect_entries(source: &UsageSource, since_ms: i64) -> Result<Vec<UsageEntry>, String> {
+    match source {
+        UsageSource::JsonlFiles { root, parse } => Ok(changed_files(root, "jsonl", since_ms)
+            .iter()
+            .flat_map(|path| parse(path))
+            .collect()),
+        UsageSource::OpenCode { database, storage } => {
+            let mut entries: Vec<UsageEntry> = changed_files(storage, "json", since_ms)
+                .iter()
+                .filter_map(|path| open_code::parse_message_file(path))
+                .collect();
+            if database.exists() {
+                entries.extend(open_code::parse_database(database, since_ms)?);
+            }
+            Ok(entries)
+        }
+    }
+}
+
+/// Files under `root` with the extension, modified at or after `since_ms`
+fn changed_files(root: &Path, extension: &str, since_ms: i64) -> Vec<PathBuf> {
+    if !root.exists() {
+        return Vec::new();
+    }
+    walkdir::WalkDir::new(root)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter(|entry| entry.path().extension().and_then(|ext| ext.to_str()) == Some(extension))
+        .filter(|entry| {
+            since_ms == 0
+                || entry
+                    .metadata()
+                    .ok()
+                    .and_then(|metadata| metadata.modified().ok())
+                    .and_then(|modified| modified.duration_since(std::time::UNIX_EPOCH).ok())
+                    .is_none_or(|modified| modified.as_millis() as i64 >= since_ms)
+        })
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+async fn store_entries(state: &DbState, entries: &[UsageEntry]) -> Result<(), String> {
+    for batch in entries.chunks(UPSERT_BATCH_SIZE) {
+        let mut query = String::from("BEGIN TRANSACTION;\n");
+        for (index, entry) in batch.iter().enumerate() {
+            query.push_str(&format!(
+                "UPSERT {} CONTENT $r{};\n",
+                db_record_id("usage_record", &record_key(entry)),
+                index
+            ));
+        }
+        query.push_str("COMMIT TRANSACTION;");
+
+        let mut request = state.db().query(query);
+        for (index, entry) in batch.iter().enumerate() {
+            request = request.bind((format!("r{}", index), to_db_value(entry)));
+        }
+        request
+            .await
+            .and_then(|response| response.check())
+            .map_err(|e| format!("Failed to save usage records: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Stable record key for a request, safe for record ids
+fn record_key(entry: &UsageEntry) -> String {
+    let digest = Sha256::digest(format!("{}:{}", entry.tool, entry.source_id).as_bytes());
+    hex::encode(&digest[..16])
+}
+
+fn to_db_value(entry: &UsageEntry) -> Value {
+    let mut value = json!({
+        "tool": entry.tool,
+        "source_id": entry.source_id,
+        "model": entry.model,
+        "timestamp": entry.timestamp,
+        "day": local_day(entry.timestamp),
+        "input_tokens": entry.input_tokens,
+        "output_tokens": entry.output_tokens,
+        "cache_read_tokens": entry.cache_read_tokens,
+        "cache_write_tokens": entry.cache_write_tokens,
+        "reasoning_tokens": entry.reasoning_tokens,
+    });
+    if let Some(session_id) = &entry.session_id {
+        value["session_id"] = json!(session_id);
+    }
+    if let Some(cost_usd) = entry.cost_usd {
+        value["cost_usd"] = json!(cost_usd);
+    }
+    value
+}
+
+/// Local calendar day of an epoch-millisecond timestamp
+fn local_day(timestamp_ms: i64) -> String {
+    chrono::Local
+        .timestamp_millis_opt(timestamp_ms)
+        .single()
+        .map(|time| time.format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
+
+fn parse_rfc3339_ms(value: &str) -> Option<i64> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|time| time.timestamp_millis())
+}
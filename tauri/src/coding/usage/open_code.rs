@@ -0,0 +1,88 @@
+//! OpenCode: assistant messages in `<data>/opencode.db` (`message` table) or, for older
+//! versions, `<data>/storage/message/<session>/<message>.json`.
+//!
+//! Messages carry `tokens` and a `cost` computed by OpenCode from its model prices.
+
+use std::path::Path;
+
+use rusqlite::Connection;
+use serde_json::Value;
+
+use super::{UsageEntry, TOOL_OPENCODE};
+
+pub fn parse_message_file(path: &Path) -> Option<UsageEntry> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let value: Value = serde_json::from_str(&content).ok()?;
+    let message_id = value.get("id").and_then(Value::as_str)?.to_string();
+    let session_id = value
+        .get("sessionID")
+        .and_then(Value::as_str)
+        .map(String::from);
+    parse_message(&value, message_id, session_id, None)
+}
+
+/// Assistant messages created at or after `since_ms`
+pub fn parse_database(database_path: &Path, since_ms: i64) -> Result<Vec<UsageEntry>, String> {
+    let connection = Connection::open_with_flags(
+        database_path,
+        rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY | rusqlite::OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .map_err(|e| format!("Failed to open OpenCode database: {}", e))?;
+    let mut statement = connection
+        .prepare("SELECT id, session_id, time_created, data FROM message WHERE time_created >= ?1")
+        .map_err(|e| format!("Failed to prepare message query: {}", e))?;
+    let rows = statement
+        .query_map([since_ms], |row| {
+            let message_id: String = row.get(0)?;
+            let session_id: String = row.get(1)?;
+            let time_created: i64 = row.get(2)?;
+            let data: String = row.get(3)?;
+            Ok((message_id, session_id, time_created, data))
+        })
+        .map_err(|e| format!("Failed to query messages: {}", e))?;
+
+    Ok(rows
+        .flatten()
+        .filter_map(|(message_id, session_id, time_created, data)| {
+            let value: Value = serde_json::from_str(&data).ok()?;
+            parse_message(&value, message_id, Some(session_id), Some(time_created))
+        })
+        .collect())
+}
+
+fn parse_message(
+    value: &Value,
+    message_id: String,
+    session_id: Option<String>,
+    time_created: Option<i64>,
+) -> Option<UsageEntry> {
+    if value.get("role").and_then(Value::as_str) != Some("assistant") {
+        return None;
+    }
+    let tokens = value.get("tokens")?;
+    let count = |pointer: &str| tokens.pointer(pointer).and_then(Value::as_i64).unwrap_or(0);
+    let model = value.get("modelID").and_then(Value::as_str)?;
+    let model = match value.get("providerID").and_then(Value::as_str) {
+        Some(provider) if !provider.is_empty() => format!("{}/{}", provider, model),
+        _ => model.to_string(),
+    };
+
+    let entry = UsageEntry {
+        tool: TOOL_OPENCODE,
+        source_id: message_id,
+        model,
+        session_id,
+        timestamp: value
+            .pointer("/time/created")
+            .and_then(Value::as_i64)
+            .or(time_created)?,
+        input_tokens: count("/input"),
+        output_tokens: count("/output"),
+        cache_read_tokens: count("/cache/read"),
+        cache_write_tokens: count("/cache/write"),
+        reasoning_tokens: count("/reasoning"),
+        cost_usd: value.get("cost").and_then(Value::as_f64),
+    };
+    // Messages still streaming have no usage yet
+    (entry.input_tokens + entry.output_tokens > 0).then_some(entry)
+}
@@ -1,5 +1,6 @@
 use super::super::db_id;
 use super::types::{FileMapping, WSLSyncConfig};
+use crate::db_timestamp::Timestamp;
 use chrono::Local;
 use serde_json::{json, Value};
 
@@ -22,12 +23,25 @@ pub fn config_from_db_value(value: Value, file_mappings: Vec<FileMapping>) -> WS
         // sync_mcp and sync_skills are always true (no UI to toggle them)
         sync_mcp: true,
         sync_skills: true,
-        file_mappings,
-        last_sync_time: value
-            .get("last_sync_time")
-            .or_else(|| value.get("lastSyncTime"))
+        watch_config_files: value
+            .get("watch_config_files")
+            .or_else(|| value.get("watchConfigFiles"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        auto_start_distro: value
+            .get("auto_start_distro")
+            .or_else(|| value.get("autoStartDistro"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        wsl_user: value
+            .get("wsl_user")
+            .or_else(|| value.get("wslUser"))
             .and_then(|v| v.as_str())
-            .map(String::from),
+            .unwrap_or("")
+            .to_string(),
+        file_mappings,
+        last_sync_time: Timestamp::from_db(&value, "last_sync_time")
+            .or_else(|| Timestamp::from_db(&value, "lastSyncTime")),
         last_sync_status: value
             .get("last_sync_status")
             .or_else(|| value.get("lastSyncStatus"))
@@ -48,6 +62,9 @@ pub fn config_to_db_value(config: &WSLSyncConfig) -> Value {
     json!({
         "enabled": config.enabled,
         "distro": config.distro,
+        "watch_config_files": config.watch_config_files,
+        "auto_start_distro": config.auto_start_distro,
+        "wsl_user": config.wsl_user.trim(),
     })
 }
 
@@ -94,6 +111,11 @@ pub fn mapping_from_db_value(value: Value) -> FileMapping {
             .or_else(|| value.get("isDirectory"))
             .and_then(|v| v.as_bool())
             .unwrap_or(false),
+        strip_cmd_c: value
+            .get("strip_cmd_c")
+            .or_else(|| value.get("stripCmdC"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true),
     }
 }
 
@@ -108,6 +130,7 @@ pub fn mapping_to_db_value(mapping: &FileMapping) -> Value {
         "enabled": mapping.enabled,
         "is_pattern": mapping.is_pattern,
         "is_directory": mapping.is_directory,
+        "strip_cmd_c": mapping.strip_cmd_c,
         "updated_at": Local::now().to_rfc3339(),
     })
 }
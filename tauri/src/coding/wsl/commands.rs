@@ -1,11 +1,12 @@
 use super::types::{
     FileMapping, SyncProgress, SyncResult, WSLDetectResult, WSLErrorResult, WSLStatusResult,
-    WSLSyncConfig,
+    WSLSyncConfig, WSLSyncLogEntry, WSLTransformPreview,
 };
-use super::{adapter, sync};
+use super::{adapter, history, plan, sync};
 use crate::coding::runtime_location;
 use crate::db::DbState;
-use chrono::Local;
+use crate::db_timestamp::{with_datetimes, Timestamp};
+use crate::locale;
 use tauri::Emitter;
 
 // ============================================================================
@@ -88,6 +89,8 @@ pub async fn wsl_get_config(state: tauri::State<'_, DbState>) -> Result<WSLSyncC
         Err(_) => vec![],
     };
 
+    sync::set_wsl_user(&config.wsl_user);
+
     // Auto-insert missing default mappings for upgrading users
     let file_mappings = backfill_default_mappings(&db, file_mappings).await;
     let module_statuses = runtime_location::get_wsl_direct_status_map_async(&db).await?;
@@ -106,6 +109,12 @@ pub async fn wsl_save_config(
     app: tauri::AppHandle,
     config: WSLSyncConfig,
 ) -> Result<(), String> {
+    let wsl_user = config.wsl_user.trim();
+    if !wsl_user.is_empty() && !sync::is_valid_wsl_user(wsl_user) {
+        return Err(format!("Invalid WSL user name: {}", wsl_user));
+    }
+    sync::set_wsl_user(wsl_user);
+
     // Check if WSL sync is being enabled (was disabled, now enabled)
     let was_enabled = {
         let db = state.db();
@@ -166,7 +175,7 @@ pub async fn wsl_save_config(
         }
 
         db.query("UPSERT wsl_sync_config:`config` CONTENT $data")
-            .bind(("data", config_data))
+            .bind(("data", with_datetimes(config_data)))
             .await
             .map_err(|e| format!("Failed to save WSL config: {}", e))?;
 
@@ -184,6 +193,10 @@ pub async fn wsl_save_config(
     // Emit event to refresh UI
     let _ = app.emit("wsl-config-changed", ());
 
+    if let Err(e) = super::watcher::refresh_config_watcher(&app).await {
+        log::warn!("Failed to refresh WSL config watcher: {}", e);
+    }
+
     // If WSL sync was just enabled, trigger a full sync
     if is_being_enabled {
         log::info!("WSL sync enabled, triggering full sync...");
@@ -301,6 +314,7 @@ pub(super) async fn do_full_sync(
     module: Option<&str>,
     skip_modules: Option<&[String]>,
 ) -> SyncResult {
+    let started_at = std::time::Instant::now();
     let direct_modules: std::collections::HashSet<String> = config
         .module_statuses
         .iter()
@@ -323,6 +337,20 @@ pub(super) async fn do_full_sync(
         }
     };
 
+    if config.auto_start_distro {
+        if let Err(e) = super::distro_state::ensure_distro_running(&distro) {
+            log::warn!("WSL full sync skipped: {}", e);
+            let result = SyncResult {
+                success: false,
+                synced_files: vec![],
+                skipped_files: vec![],
+                errors: vec![e],
+            };
+            record_full_sync(state, &distro, module, &result, started_at).await;
+            return result;
+        }
+    }
+
     // Emit initial progress for file mappings
     let enabled_mappings: Vec<_> = config.file_mappings.iter().filter(|m| m.enabled).collect();
     let total_files = enabled_mappings.len() as u32;
@@ -330,10 +358,13 @@ pub(super) async fn do_full_sync(
         "wsl-sync-progress",
         SyncProgress {
             phase: "files".to_string(),
-            current_item: "准备中...".to_string(),
+            current_item: locale::text("sync.preparing").to_string(),
             current: 0,
             total: total_files,
-            message: format!("文件同步: 0/{}", total_files),
+            message: locale::format(
+                "sync.files_progress",
+                &[("current", &0), ("total", &total_files)],
+            ),
         },
     );
 
@@ -341,6 +372,21 @@ pub(super) async fn do_full_sync(
     let db = state.db();
     let file_mappings = resolve_dynamic_paths_with_db(&db, config.file_mappings.clone()).await;
 
+    // Files written by earlier syncs as another user would not be writable by the sync user
+    let wsl_user = config.wsl_user.trim();
+    if !wsl_user.is_empty() {
+        let targets: Vec<String> = file_mappings
+            .iter()
+            .filter(|m| m.enabled)
+            .filter(|m| module.is_none_or(|module| m.module == module))
+            .filter(|m| !merged_skip_modules.iter().any(|s| s == &m.module))
+            .map(|m| m.wsl_path.trim_end_matches('/').to_string())
+            .collect();
+        if let Err(e) = sync::fix_wsl_ownership(&distro, wsl_user, &targets) {
+            log::warn!("{}", e);
+        }
+    }
+
     // Sync file mappings with progress
     let mut result = sync_mappings_with_progress(
         &file_mappings,
@@ -385,9 +431,28 @@ pub(super) async fn do_full_sync(
         }
     }
 
+    record_full_sync(state, &distro, module, &result, started_at).await;
     result
 }
 
+/// Write a full sync run to the history log and the distro's last sync status
+async fn record_full_sync(
+    state: &DbState,
+    distro: &str,
+    module: Option<&str>,
+    result: &SyncResult,
+    started_at: std::time::Instant,
+) {
+    let db = state.db();
+    let duration_ms = started_at.elapsed().as_millis() as u64;
+    if let Err(e) = history::record_sync_run(&db, distro, module, result, duration_ms).await {
+        log::warn!("{}", e);
+    }
+    if let Err(e) = history::record_distro_status(&db, distro, result).await {
+        log::warn!("{}", e);
+    }
+}
+
 /// Sync file mappings with progress events
 fn sync_mappings_with_progress(
     mappings: &[FileMapping],
@@ -420,7 +485,14 @@ fn sync_mappings_with_progress(
                 current_item: mapping.name.clone(),
                 current,
                 total,
-                message: format!("文件同步: {}/{} - {}", current, total, mapping.name),
+                message: locale::format(
+                    "sync.files_progress_item",
+                    &[
+                        ("current", &current),
+                        ("total", &total),
+                        ("name", &mapping.name),
+                    ],
+                ),
             },
         );
 
@@ -446,15 +518,47 @@ fn sync_mappings_with_progress(
 }
 
 /// Sync all files or specific module to WSL
+///
+/// With `dry_run` set, only evaluates the file mappings (see `plan::plan_mappings`) and
+/// writes nothing into the distro.
 #[tauri::command]
 pub async fn wsl_sync(
     state: tauri::State<'_, DbState>,
     app: tauri::AppHandle,
     module: Option<String>,
     skip_modules: Option<Vec<String>>,
+    dry_run: Option<bool>,
 ) -> Result<SyncResult, String> {
     let config = wsl_get_config(state.clone()).await?;
 
+    if dry_run.unwrap_or(false) {
+        let distro = sync::get_effective_distro(&config.distro)?;
+        let direct_modules: std::collections::HashSet<String> = config
+            .module_statuses
+            .iter()
+            .filter(|status| status.is_wsl_direct)
+            .map(|status| status.module.clone())
+            .collect();
+        let skip = merge_skip_modules(skip_modules.as_deref(), &direct_modules);
+        let db = state.db();
+        let file_mappings = resolve_dynamic_paths_with_db(&db, config.file_mappings.clone()).await;
+        let selected: Vec<&FileMapping> = file_mappings
+            .iter()
+            .filter(|m| m.enabled)
+            .filter(|m| module.as_deref().is_none_or(|module| m.module == module))
+            .filter(|m| !skip.iter().any(|s| s == &m.module))
+            .collect();
+        let result = plan::plan_mappings(&selected, &distro);
+        log::info!(
+            "WSL sync dry run finished: mappings={}, planned_copies={}, skipped={}, errors={}",
+            selected.len(),
+            result.synced_files.len(),
+            result.skipped_files.len(),
+            result.errors.len()
+        );
+        return Ok(result);
+    }
+
     let result = do_full_sync(
         &state,
         &app,
@@ -502,7 +606,7 @@ pub async fn is_wsl_auto_sync_enabled(state: &DbState) -> bool {
 /// Get current WSL sync status
 #[tauri::command]
 pub async fn wsl_get_status(state: tauri::State<'_, DbState>) -> Result<WSLStatusResult, String> {
-    let config = wsl_get_config(state).await?;
+    let config = wsl_get_config(state.clone()).await?;
 
     let wsl_available = if config.enabled {
         sync::get_effective_distro(&config.distro).is_ok()
@@ -510,12 +614,73 @@ pub async fn wsl_get_status(state: tauri::State<'_, DbState>) -> Result<WSLStatu
         false
     };
 
+    let distro_statuses = history::get_distro_statuses(&state.db())
+        .await
+        .unwrap_or_else(|e| {
+            log::warn!("{}", e);
+            vec![]
+        });
+
     Ok(WSLStatusResult {
         wsl_available,
         last_sync_time: config.last_sync_time,
         last_sync_status: config.last_sync_status,
         last_sync_error: config.last_sync_error,
         module_statuses: config.module_statuses,
+        distro_statuses,
+    })
+}
+
+/// Recent full sync runs, newest first (default 50)
+#[tauri::command]
+pub async fn wsl_get_sync_history(
+    state: tauri::State<'_, DbState>,
+    limit: Option<u32>,
+) -> Result<Vec<WSLSyncLogEntry>, String> {
+    let db = state.db();
+    history::get_sync_history(&db, limit.unwrap_or(50) as usize).await
+}
+
+/// Preview the cmd /c normalization applied to a mapping's MCP config in WSL
+///
+/// Reads the Windows-side file and returns it next to the content WSL would get, so the
+/// per-mapping `strip_cmd_c` toggle can be judged before syncing.
+#[tauri::command]
+pub async fn wsl_preview_mcp_transform(
+    state: tauri::State<'_, DbState>,
+    mapping_id: String,
+) -> Result<WSLTransformPreview, String> {
+    let config = wsl_get_config(state.clone()).await?;
+    let mapping = config
+        .file_mappings
+        .into_iter()
+        .find(|m| m.id == mapping_id)
+        .ok_or_else(|| format!("File mapping not found: {}", mapping_id))?;
+    if !super::mcp_sync::is_mcp_config_file(&mapping.id) {
+        return Err(format!("{} is not an MCP config file", mapping.name));
+    }
+
+    let db = state.db();
+    let mapping = resolve_dynamic_paths_with_db(&db, vec![mapping])
+        .await
+        .remove(0);
+    let windows_path = sync::expand_env_vars(&mapping.windows_path)?;
+    let original = std::fs::read_to_string(&windows_path)
+        .map_err(|e| format!("Failed to read {}: {}", windows_path, e))?;
+    let transformed = if mapping.strip_cmd_c && !original.trim().is_empty() {
+        super::mcp_sync::strip_cmd_c_from_content(&original, &mapping.wsl_path, &mapping.module)?
+            .unwrap_or_else(|| original.clone())
+    } else {
+        original.clone()
+    };
+
+    Ok(WSLTransformPreview {
+        mapping_id: mapping.id,
+        windows_path,
+        wsl_path: mapping.wsl_path,
+        changed: transformed != original,
+        original,
+        transformed,
     })
 }
 
@@ -610,7 +775,7 @@ async fn backfill_default_mappings(
     mut file_mappings: Vec<FileMapping>,
 ) -> Vec<FileMapping> {
     // Bump this number whenever new default mappings are added.
-    const CURRENT_DEFAULTS_VERSION: u64 = 4;
+    const CURRENT_DEFAULTS_VERSION: u64 = 5;
 
     // Read stored version
     let stored_version: u64 = db
@@ -839,10 +1004,10 @@ pub(super) async fn update_sync_status(state: &DbState, result: &SyncResult) ->
         ("error".to_string(), Some(error_msg))
     };
 
-    let now = Local::now().to_rfc3339();
+    let now = Timestamp::now();
 
     db.query("UPDATE wsl_sync_config SET last_sync_time = $time, last_sync_status = $status, last_sync_error = $error WHERE id = wsl_sync_config:`config`")
-        .bind(("time", now))
+        .bind(("time", now.to_db()))
         .bind(("status", status))
         .bind(("error", error))
         .await
@@ -864,6 +1029,7 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            strip_cmd_c: true,
         },
         FileMapping {
             id: "opencode-oh-my".to_string(),
@@ -874,6 +1040,7 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            strip_cmd_c: true,
         },
         FileMapping {
             id: "opencode-oh-my-slim".to_string(),
@@ -884,6 +1051,7 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: false, // Disabled by default: this file is optional and not present on all systems
             is_pattern: false,
             is_directory: false,
+            strip_cmd_c: true,
         },
         FileMapping {
             id: "opencode-auth".to_string(),
@@ -894,6 +1062,7 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            strip_cmd_c: true,
         },
         FileMapping {
             id: "opencode-plugins".to_string(),
@@ -904,6 +1073,7 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: true,
             is_pattern: true,
             is_directory: false,
+            strip_cmd_c: true,
         },
         FileMapping {
             id: "opencode-prompt".to_string(),
@@ -914,6 +1084,7 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            strip_cmd_c: true,
         },
         // ClaudeCode
         FileMapping {
@@ -925,6 +1096,7 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            strip_cmd_c: true,
         },
         FileMapping {
             id: "claude-config".to_string(),
@@ -935,6 +1107,7 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            strip_cmd_c: true,
         },
         FileMapping {
             id: "claude-prompt".to_string(),
@@ -945,6 +1118,7 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            strip_cmd_c: true,
         },
         FileMapping {
             id: "claude-plugins".to_string(),
@@ -955,6 +1129,7 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: true,
+            strip_cmd_c: true,
         },
         // Codex
         FileMapping {
@@ -966,6 +1141,7 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            strip_cmd_c: true,
         },
         FileMapping {
             id: "codex-config".to_string(),
@@ -976,6 +1152,7 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            strip_cmd_c: true,
         },
         FileMapping {
             id: "codex-prompt".to_string(),
@@ -986,6 +1163,7 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            strip_cmd_c: true,
         },
         FileMapping {
             id: "codex-plugins".to_string(),
@@ -996,6 +1174,7 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: true,
+            strip_cmd_c: true,
         },
         // OpenClaw
         FileMapping {
@@ -1007,6 +1186,30 @@ pub fn default_file_mappings() -> Vec<FileMapping> {
             enabled: true,
             is_pattern: false,
             is_directory: false,
+            strip_cmd_c: true,
+        },
+        // Cursor
+        FileMapping {
+            id: "cursor-mcp".to_string(),
+            name: "Cursor MCP 配置".to_string(),
+            module: "cursor".to_string(),
+            windows_path: "~/.cursor/mcp.json".to_string(),
+            wsl_path: "~/.cursor/mcp.json".to_string(),
+            enabled: true,
+            is_pattern: false,
+            is_directory: false,
+            strip_cmd_c: true,
+        },
+        FileMapping {
+            id: "cursor-rules".to_string(),
+            name: "Cursor 规则目录".to_string(),
+            module: "cursor".to_string(),
+            windows_path: "~/.cursor/rules".to_string(),
+            wsl_path: "~/.cursor/rules".to_string(),
+            enabled: true,
+            is_pattern: false,
+            is_directory: true,
+            strip_cmd_c: true,
         },
     ]
 }
@@ -0,0 +1,70 @@
+//! WSL distro running state
+//!
+//! A background poller emits `wsl-distro-state-changed` when the configured distro starts
+//! or stops, and `ensure_distro_running` starts a stopped distro before a sync when the
+//! `auto_start_distro` option is on.
+
+use std::time::Duration;
+
+use tauri::{Emitter, Manager};
+
+use super::commands::wsl_get_config;
+use super::sync;
+use super::types::WSLDistroStateChanged;
+use crate::DbState;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Start the distro if it is not running (`wsl -d <distro> -e true`)
+pub fn ensure_distro_running(distro: &str) -> Result<(), String> {
+    if sync::get_wsl_distro_state(distro) == "Running" {
+        return Ok(());
+    }
+    log::info!("Starting WSL distro before sync: {}", distro);
+    sync::start_wsl_distro(distro)
+}
+
+/// Poll the state of the configured distro while WSL sync is enabled
+pub async fn run_distro_state_poller(app: tauri::AppHandle) {
+    let mut last: Option<(String, String)> = None;
+    loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+
+        let Ok(config) = wsl_get_config(app.state::<DbState>()).await else {
+            continue;
+        };
+        if !config.enabled {
+            last = None;
+            continue;
+        }
+        let Ok(distro) = sync::get_effective_distro(&config.distro) else {
+            continue;
+        };
+
+        let state = sync::get_wsl_distro_state(&distro);
+        let previous_state = match &last {
+            Some((last_distro, last_state)) if *last_distro == distro => {
+                if *last_state == state {
+                    continue;
+                }
+                Some(last_state.clone())
+            }
+            _ => None,
+        };
+        log::debug!(
+            "WSL distro state: distro={}, state={}, previous={:?}",
+            distro,
+            state,
+            previous_state
+        );
+        let _ = app.emit(
+            "wsl-distro-state-changed",
+            WSLDistroStateChanged {
+                distro: distro.clone(),
+                state: state.clone(),
+                previous_state,
+            },
+        );
+        last = Some((distro, state));
+    }
+}
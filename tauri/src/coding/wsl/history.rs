@@ -0,0 +1,168 @@
+//! WSL sync history and per-distro status
+//!
+//! Every full sync writes one `wsl_sync_log` record; only the latest `MAX_SYNC_LOG_ENTRIES`
+//! are kept. The outcome of each sync (full or MCP/Skills only) is also stored per distro in
+//! `wsl_distro_status`, so switching distros does not lose the other one's last sync.
+
+use serde_json::{json, Value};
+
+use super::types::{SyncResult, WSLDistroSyncStatus, WSLSyncLogEntry};
+use crate::coding::db_id::{db_extract_id, db_new_id, db_record_id};
+use crate::db_timestamp::{with_datetimes, Timestamp};
+
+type Db = surrealdb::Surreal<surrealdb::engine::local::Db>;
+
+/// Number of sync runs kept in the log
+const MAX_SYNC_LOG_ENTRIES: usize = 200;
+
+fn string_list(record: &Value, key: &str) -> Vec<String> {
+    record
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn str_field(record: &Value, key: &str) -> Option<String> {
+    record
+        .get(key)
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+}
+
+fn entry_from_db_value(record: &Value) -> WSLSyncLogEntry {
+    WSLSyncLogEntry {
+        id: db_extract_id(record),
+        timestamp: Timestamp::from_db(record, "timestamp"),
+        distro: str_field(record, "distro").unwrap_or_default(),
+        module: str_field(record, "module"),
+        success: record
+            .get("success")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        synced_files: string_list(record, "synced_files"),
+        skipped_files: string_list(record, "skipped_files"),
+        errors: string_list(record, "errors"),
+        duration_ms: record
+            .get("duration_ms")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0),
+    }
+}
+
+fn distro_status_from_db_value(record: &Value) -> WSLDistroSyncStatus {
+    WSLDistroSyncStatus {
+        distro: str_field(record, "distro").unwrap_or_default(),
+        last_sync_time: Timestamp::from_db(record, "last_sync_time"),
+        last_sync_status: str_field(record, "last_sync_status")
+            .unwrap_or_else(|| "never".to_string()),
+        last_sync_error: str_field(record, "last_sync_error"),
+    }
+}
+
+/// Record a full sync run and drop the entries beyond the retention limit
+pub async fn record_sync_run(
+    db: &Db,
+    distro: &str,
+    module: Option<&str>,
+    result: &SyncResult,
+    duration_ms: u64,
+) -> Result<(), String> {
+    let record_id = db_record_id("wsl_sync_log", &db_new_id());
+    db.query(&format!("CREATE {} CONTENT $data", record_id))
+        .bind((
+            "data",
+            with_datetimes(json!({
+                "timestamp": Timestamp::now().to_string(),
+                "distro": distro,
+                "module": module,
+                "success": result.success,
+                "synced_files": result.synced_files,
+                "skipped_files": result.skipped_files,
+                "errors": result.errors,
+                "duration_ms": duration_ms,
+            })),
+        ))
+        .await
+        .map_err(|e| format!("Failed to record WSL sync history: {}", e))?;
+
+    prune_sync_log(db).await
+}
+
+async fn prune_sync_log(db: &Db) -> Result<(), String> {
+    let stale: Vec<Value> = db
+        .query("SELECT type::string(id) as id, timestamp FROM wsl_sync_log ORDER BY timestamp DESC START $keep")
+        .bind(("keep", MAX_SYNC_LOG_ENTRIES))
+        .await
+        .map_err(|e| format!("Failed to query WSL sync history: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to parse WSL sync history: {}", e))?;
+
+    for record in &stale {
+        let id = db_extract_id(record);
+        if id.is_empty() {
+            continue;
+        }
+        db.query(&format!("DELETE {}", db_record_id("wsl_sync_log", &id)))
+            .await
+            .map_err(|e| format!("Failed to prune WSL sync history: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Recent sync runs, newest first
+pub async fn get_sync_history(db: &Db, limit: usize) -> Result<Vec<WSLSyncLogEntry>, String> {
+    let records: Vec<Value> = db
+        .query("SELECT *, type::string(id) as id FROM wsl_sync_log ORDER BY timestamp DESC LIMIT $limit")
+        .bind(("limit", limit))
+        .await
+        .map_err(|e| format!("Failed to query WSL sync history: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to parse WSL sync history: {}", e))?;
+    Ok(records.iter().map(entry_from_db_value).collect())
+}
+
+/// Store the outcome of a sync as the distro's last sync status
+pub async fn record_distro_status(
+    db: &Db,
+    distro: &str,
+    result: &SyncResult,
+) -> Result<(), String> {
+    let (status, error) = if result.success {
+        ("success", None)
+    } else {
+        ("error", Some(result.errors.join("; ")))
+    };
+    db.query(&format!(
+        "UPSERT {} CONTENT $data",
+        db_record_id("wsl_distro_status", distro)
+    ))
+    .bind((
+        "data",
+        with_datetimes(json!({
+            "distro": distro,
+            "last_sync_time": Timestamp::now().to_string(),
+            "last_sync_status": status,
+            "last_sync_error": error,
+        })),
+    ))
+    .await
+    .map_err(|e| format!("Failed to update WSL distro sync status: {}", e))?;
+    Ok(())
+}
+
+/// Last sync status of every distro synced so far, most recent first
+pub async fn get_distro_statuses(db: &Db) -> Result<Vec<WSLDistroSyncStatus>, String> {
+    let records: Vec<Value> = db
+        .query("SELECT * OMIT id FROM wsl_distro_status ORDER BY last_sync_time DESC")
+        .await
+        .map_err(|e| format!("Failed to query WSL distro sync status: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to parse WSL distro sync status: {}", e))?;
+    Ok(records.iter().map(distro_status_from_db_value).collect())
+}
@@ -14,7 +14,9 @@ use super::sync::{read_wsl_file, sync_mappings, write_wsl_file};
 use super::types::{FileMapping, SyncProgress, WSLSyncConfig};
 use crate::coding::mcp::command_normalize;
 use crate::coding::mcp::mcp_store;
+use crate::coding::mcp::secrets;
 use crate::coding::runtime_location;
+use crate::locale;
 use crate::DbState;
 
 /// Read WSL sync config directly from database (without tauri::State wrapper)
@@ -66,12 +68,17 @@ pub async fn sync_mcp_to_wsl(state: &DbState, app: AppHandle) -> Result<(), Stri
         return Ok(());
     }
 
+    super::sync::set_wsl_user(&config.wsl_user);
+
     // Get effective distro (auto-resolve if configured one doesn't exist)
     let distro = match super::sync::get_effective_distro(&config.distro) {
         Ok(d) => d,
         Err(e) => {
             log::warn!("WSL MCP sync skipped: {}", e);
-            let _ = app.emit("wsl-sync-warning", format!("WSL MCP 同步已跳过：{}", e));
+            let _ = app.emit(
+                "wsl-sync-warning",
+                locale::format("sync.wsl_mcp_skipped", &[("error", &e)]),
+            );
             return Ok(());
         }
     };
@@ -97,7 +104,7 @@ pub async fn sync_mcp_to_wsl(state: &DbState, app: AppHandle) -> Result<(), Stri
             current_item: "Claude Code MCP".to_string(),
             current: 1,
             total: 2,
-            message: "MCP 同步: Claude Code...".to_string(),
+            message: locale::text("sync.mcp_claude").to_string(),
         },
     );
 
@@ -106,6 +113,8 @@ pub async fn sync_mcp_to_wsl(state: &DbState, app: AppHandle) -> Result<(), Stri
     let claude_servers: Vec<_> = servers
         .iter()
         .filter(|s| s.enabled_tools.contains(&"claude_code".to_string()))
+        // Project-scoped servers belong to local project paths, not the WSL global config
+        .filter(|s| s.project_paths.is_empty())
         .collect();
 
     if !skip_claude {
@@ -114,9 +123,9 @@ pub async fn sync_mcp_to_wsl(state: &DbState, app: AppHandle) -> Result<(), Stri
             all_errors.push(format!("Claude Code: {}", e));
             let _ = app.emit(
                 "wsl-sync-warning",
-                format!(
-                    "WSL ~/.claude.json 同步已跳过：文件解析失败，请检查该文件格式是否正确。({})",
-                    e
+                locale::format(
+                    "sync.claude_json_skipped",
+                    &[("target", &"WSL"), ("error", &e)],
                 ),
             );
         }
@@ -130,7 +139,7 @@ pub async fn sync_mcp_to_wsl(state: &DbState, app: AppHandle) -> Result<(), Stri
             current_item: "OpenCode/Codex MCP".to_string(),
             current: 2,
             total: 2,
-            message: "MCP 同步: OpenCode/Codex...".to_string(),
+            message: locale::text("sync.mcp_opencode_codex").to_string(),
         },
     );
 
@@ -156,7 +165,7 @@ pub async fn sync_mcp_to_wsl(state: &DbState, app: AppHandle) -> Result<(), Stri
                     all_errors.push(format!("OpenCode/Codex: {}", msg));
                     let _ = app.emit(
                         "wsl-sync-warning",
-                        format!("OpenCode/Codex 配置同步部分失败：{}", msg),
+                        locale::format("sync.config_partially_failed", &[("error", &msg)]),
                     );
                 }
 
@@ -169,6 +178,7 @@ pub async fn sync_mcp_to_wsl(state: &DbState, app: AppHandle) -> Result<(), Stri
                     .collect();
                 for mapping in &resolved {
                     if mapping.enabled
+                        && mapping.strip_cmd_c
                         && is_mcp_config_file(&mapping.id)
                         && synced_paths.contains(&mapping.wsl_path)
                     {
@@ -188,7 +198,7 @@ pub async fn sync_mcp_to_wsl(state: &DbState, app: AppHandle) -> Result<(), Stri
             all_errors.push(format!("OpenCode/Codex: {}", e));
             let _ = app.emit(
                 "wsl-sync-warning",
-                format!("OpenCode/Codex MCP 同步已跳过：{}", e),
+                locale::format("sync.opencode_codex_mcp_skipped", &[("error", &e)]),
             );
         }
     }
@@ -206,6 +216,7 @@ pub async fn sync_mcp_to_wsl(state: &DbState, app: AppHandle) -> Result<(), Stri
         errors: all_errors,
     };
     let _ = super::commands::update_sync_status(state, &sync_result).await;
+    let _ = super::history::record_distro_status(&state.db(), &distro, &sync_result).await;
 
     // Emit event for UI feedback
     let _ = app.emit("wsl-mcp-sync-completed", ());
@@ -237,7 +248,8 @@ async fn sync_mcp_to_wsl_claude(
     // 3. Build mcpServers object
     let mut mcp_servers = serde_json::Map::new();
     for server in servers {
-        let server_config = build_standard_server_config(server);
+        let server = secrets::resolve_server_secrets(server)?;
+        let server_config = build_standard_server_config(&server);
         mcp_servers.insert(server.name.clone(), server_config);
     }
 
@@ -321,13 +333,30 @@ fn build_standard_server_config(server: &crate::coding::mcp::types::McpServer) -
 
 /// Check if a file mapping ID corresponds to a file that contains MCP server configurations.
 /// Only these files need cmd /c stripping; auth files, slim configs, etc. do not.
-fn is_mcp_config_file(mapping_id: &str) -> bool {
+pub(super) fn is_mcp_config_file(mapping_id: &str) -> bool {
     matches!(
         mapping_id,
         "opencode-main" | "opencode-oh-my" | "codex-config"
     )
 }
 
+/// Strip cmd /c from MCP config content, None when the file type is not processed
+pub(super) fn strip_cmd_c_from_content(
+    content: &str,
+    path: &str,
+    module: &str,
+) -> Result<Option<String>, String> {
+    match module {
+        "opencode" => command_normalize::process_opencode_json(content, false).map(Some),
+        // Determine parser by file extension: only .toml files use TOML parser,
+        // JSON files in codex module (e.g. auth.json) should not be processed
+        "codex" if path.ends_with(".toml") => {
+            command_normalize::process_codex_toml(content, false).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
 /// Strip cmd /c from WSL MCP config file after sync.
 /// Selects the correct parser based on file extension rather than module name,
 /// so that JSON files are not accidentally parsed as TOML.
@@ -337,18 +366,8 @@ fn strip_cmd_c_from_wsl_mcp_file(distro: &str, wsl_path: &str, module: &str) ->
         return Ok(());
     }
 
-    let processed = match module {
-        "opencode" => command_normalize::process_opencode_json(&content, false)?,
-        "codex" => {
-            // Determine parser by file extension: only .toml files use TOML parser
-            if wsl_path.ends_with(".toml") {
-                command_normalize::process_codex_toml(&content, false)?
-            } else {
-                // JSON files in codex module (e.g. auth.json) should not be processed
-                return Ok(());
-            }
-        }
-        _ => return Ok(()),
+    let Some(processed) = strip_cmd_c_from_content(&content, wsl_path, module)? else {
+        return Ok(());
     };
 
     // Only write back if content changed
@@ -1,9 +1,13 @@
 mod adapter;
 mod commands;
+pub mod distro_state;
+mod history;
 mod mcp_sync;
+mod plan;
 mod skills_sync;
 mod sync;
 mod types;
+pub mod watcher;
 
 pub use commands::*;
 pub use mcp_sync::sync_mcp_to_wsl;
@@ -0,0 +1,131 @@
+use super::sync::{expand_env_vars, read_wsl_file_raw, wsl_path_exists};
+use super::types::{FileMapping, SyncResult};
+use crate::coding::line_diff;
+use std::path::Path;
+
+// ============================================================================
+// Dry Run (只评估，不写入 WSL)
+// ============================================================================
+
+/// Text files up to this size are compared with their WSL copy and diffed
+const MAX_DIFF_BYTES: u64 = 256 * 1024;
+
+enum PlannedAction {
+    Copy(String),
+    Skip(String),
+}
+
+fn plan_file(windows_file: &Path, wsl_path: &str, distro: &str) -> Result<PlannedAction, String> {
+    let display = format!("{} -> {}", windows_file.display(), wsl_path);
+    let size = std::fs::metadata(windows_file)
+        .map_err(|e| format!("Failed to read {}: {}", windows_file.display(), e))?
+        .len();
+    if !wsl_path_exists(distro, wsl_path) {
+        return Ok(PlannedAction::Copy(format!(
+            "[create] {} ({} bytes)",
+            display, size
+        )));
+    }
+
+    let text = (size <= MAX_DIFF_BYTES)
+        .then(|| std::fs::read_to_string(windows_file).ok())
+        .flatten();
+    let Some(local) = text else {
+        // Binary or large file: cp always overwrites it
+        return Ok(PlannedAction::Copy(format!(
+            "[update] {} ({} bytes)",
+            display, size
+        )));
+    };
+    let remote = read_wsl_file_raw(distro, wsl_path)?;
+    if remote == local {
+        return Ok(PlannedAction::Skip(format!("[unchanged] {}", display)));
+    }
+    Ok(PlannedAction::Copy(format!(
+        "[update] {}\n{}",
+        display,
+        line_diff(&remote, &local)
+    )))
+}
+
+fn plan_mapping(mapping: &FileMapping, distro: &str) -> Result<Vec<PlannedAction>, String> {
+    let expanded = expand_env_vars(&mapping.windows_path)?;
+
+    if mapping.is_pattern {
+        let matches: Vec<_> = glob::glob(&expanded)
+            .map_err(|e| format!("Invalid pattern '{}': {}", mapping.windows_path, e))?
+            .filter_map(|entry| entry.ok())
+            .filter(|path| path.is_file())
+            .collect();
+        if matches.is_empty() {
+            return Ok(vec![PlannedAction::Skip(format!(
+                "[skip] {}: no files match {}",
+                mapping.name, mapping.windows_path
+            ))]);
+        }
+        return matches
+            .iter()
+            .map(|file| {
+                let file_name = file
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let wsl_dest = format!("{}/{}", mapping.wsl_path.trim_end_matches('/'), file_name);
+                plan_file(file, &wsl_dest, distro)
+            })
+            .collect();
+    }
+
+    let source = Path::new(&expanded);
+    if !source.exists() {
+        return Ok(vec![PlannedAction::Skip(format!(
+            "[skip] {}: source {} does not exist",
+            mapping.name, mapping.windows_path
+        ))]);
+    }
+    if mapping.is_directory {
+        let files = walkdir::WalkDir::new(source)
+            .follow_links(true)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .count();
+        // 目录同步是整体替换（rm -rf + cp -rL）
+        return Ok(vec![PlannedAction::Copy(format!(
+            "[replace] {} -> {} ({} files)",
+            expanded, mapping.wsl_path, files
+        ))]);
+    }
+    Ok(vec![plan_file(source, &mapping.wsl_path, distro)?])
+}
+
+/// 评估映射的同步计划，不写入 WSL
+///
+/// `synced_files` lists the planned copies (text updates followed by a line diff),
+/// `skipped_files` the mappings that would be skipped or are already up to date.
+pub fn plan_mappings(mappings: &[&FileMapping], distro: &str) -> SyncResult {
+    let mut synced_files = vec![];
+    let mut skipped_files = vec![];
+    let mut errors = vec![];
+
+    for mapping in mappings {
+        match plan_mapping(mapping, distro) {
+            Ok(actions) => {
+                for action in actions {
+                    match action {
+                        PlannedAction::Copy(line) => synced_files.push(line),
+                        PlannedAction::Skip(line) => skipped_files.push(line),
+                    }
+                }
+            }
+            Err(e) => errors.push(format!("{}: {}", mapping.name, e)),
+        }
+    }
+
+    SyncResult {
+        success: errors.is_empty(),
+        synced_files,
+        skipped_files,
+        errors,
+    }
+}
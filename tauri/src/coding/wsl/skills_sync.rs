@@ -16,9 +16,11 @@ use super::sync::{
 };
 use super::types::{SyncProgress, WSLSyncConfig};
 use crate::coding::runtime_location;
+use crate::coding::skills::adapter::target_dir_name;
 use crate::coding::skills::central_repo::{resolve_central_repo_path, resolve_skill_central_path};
 use crate::coding::skills::skill_store;
 use crate::coding::tools::builtin::BUILTIN_TOOLS;
+use crate::locale;
 use crate::DbState;
 
 const WSL_CENTRAL_DIR: &str = "~/.ai-toolbox/skills";
@@ -106,6 +108,8 @@ pub async fn sync_skills_to_wsl(state: &DbState, app: AppHandle) -> Result<(), S
         return Ok(());
     }
 
+    super::sync::set_wsl_user(&config.wsl_user);
+
     // Get effective distro (auto-resolve if configured one doesn't exist)
     let distro = match super::sync::get_effective_distro(&config.distro) {
         Ok(d) => d,
@@ -146,10 +150,13 @@ pub async fn sync_skills_to_wsl(state: &DbState, app: AppHandle) -> Result<(), S
         "wsl-sync-progress",
         SyncProgress {
             phase: "skills".to_string(),
-            current_item: "准备中...".to_string(),
+            current_item: locale::text("sync.preparing").to_string(),
             current: 0,
             total: total_skills,
-            message: format!("Skills 同步: 0/{}", total_skills),
+            message: locale::format(
+                "sync.skills_progress",
+                &[("current", &0), ("total", &total_skills)],
+            ),
         },
     );
 
@@ -191,9 +198,13 @@ pub async fn sync_skills_to_wsl(state: &DbState, app: AppHandle) -> Result<(), S
                 current_item: skill.name.clone(),
                 current: current_idx,
                 total: total_skills,
-                message: format!(
-                    "Skills 同步: {}/{} - {}",
-                    current_idx, total_skills, skill.name
+                message: locale::format(
+                    "sync.skills_progress_item",
+                    &[
+                        ("current", &current_idx),
+                        ("total", &total_skills),
+                        ("name", &skill.name),
+                    ],
                 ),
             },
         );
@@ -243,6 +254,9 @@ pub async fn sync_skills_to_wsl(state: &DbState, app: AppHandle) -> Result<(), S
                         errors: vec![error_message.clone()],
                     };
                     let _ = super::commands::update_sync_status(state, &sync_result).await;
+                    let _ =
+                        super::history::record_distro_status(&state.db(), &distro, &sync_result)
+                            .await;
                     let _ = app.emit("wsl-sync-completed", &sync_result);
                     return Err(error_message);
                 }
@@ -255,7 +269,7 @@ pub async fn sync_skills_to_wsl(state: &DbState, app: AppHandle) -> Result<(), S
                 continue;
             }
             if let Some(wsl_skills_dir) = get_wsl_tool_skills_dir_with_db(&db, tool_key).await {
-                let link_path = format!("{}/{}", wsl_skills_dir, skill.name);
+                let link_path = format!("{}/{}", wsl_skills_dir, target_dir_name(skill, tool_key));
                 if !check_wsl_symlink_exists(&distro, &link_path, &wsl_target) {
                     if let Err(error) = create_wsl_symlink(&distro, &wsl_target, &link_path) {
                         log::warn!(
@@ -284,7 +298,8 @@ pub async fn sync_skills_to_wsl(state: &DbState, app: AppHandle) -> Result<(), S
             }
             if !enabled_set.contains(tool_key) {
                 if let Some(wsl_skills_dir) = get_wsl_tool_skills_dir_with_db(&db, tool_key).await {
-                    let link_path = format!("{}/{}", wsl_skills_dir, skill.name);
+                    let link_path =
+                        format!("{}/{}", wsl_skills_dir, target_dir_name(skill, tool_key));
                     if let Err(error) = remove_wsl_path(&distro, &link_path) {
                         log::warn!(
                             "Skills WSL sync: failed to remove stale symlink for skill '{}' tool '{}' at '{}': {}",
@@ -313,6 +328,7 @@ pub async fn sync_skills_to_wsl(state: &DbState, app: AppHandle) -> Result<(), S
         errors: vec![],
     };
     let _ = super::commands::update_sync_status(state, &sync_result).await;
+    let _ = super::history::record_distro_status(&state.db(), &distro, &sync_result).await;
 
     // Emit event for UI feedback
     let _ = app.emit("wsl-skills-sync-completed", ());
@@ -1,6 +1,7 @@
 use super::types::{FileMapping, SyncResult, WSLDetectResult};
 use std::path::Path;
 use std::process::Command;
+use std::sync::RwLock;
 
 #[cfg(target_os = "windows")]
 use std::os::windows::process::CommandExt;
@@ -19,6 +20,42 @@ fn create_wsl_command() -> Command {
     cmd
 }
 
+/// Linux user that sync commands run as (`wsl -u`); None uses the distro's default user
+static WSL_USER: RwLock<Option<String>> = RwLock::new(None);
+
+/// Set the Linux user for WSL sync commands (empty for the distro's default user)
+pub fn set_wsl_user(user: &str) {
+    let user = user.trim();
+    let user = (!user.is_empty() && is_valid_wsl_user(user)).then(|| user.to_string());
+    if let Ok(mut current) = WSL_USER.write() {
+        *current = user;
+    }
+}
+
+fn current_wsl_user() -> Option<String> {
+    WSL_USER.read().ok().and_then(|user| user.clone())
+}
+
+/// Whether `user` is a plausible Linux user name (it ends up in shell commands)
+pub fn is_valid_wsl_user(user: &str) -> bool {
+    let mut chars = user.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+        && user.len() <= 32
+}
+
+/// Create a WSL command targeting `distro`, running as the configured sync user
+fn wsl_distro_command(distro: &str) -> Command {
+    let mut cmd = create_wsl_command();
+    cmd.args(["-d", distro]);
+    if let Some(user) = current_wsl_user() {
+        cmd.args(["-u", &user]);
+    }
+    cmd
+}
+
 /// Check if bytes look like UTF-16 LE encoding.
 ///
 /// WSL on Windows outputs UTF-16 LE for commands like `wsl --list`.
@@ -232,6 +269,68 @@ pub fn get_wsl_distro_state(distro: &str) -> String {
     "Unknown".to_string()
 }
 
+/// Start a stopped distro by running a no-op command in it
+pub fn start_wsl_distro(distro: &str) -> Result<(), String> {
+    let output = create_wsl_command()
+        .args(["-d", distro, "-e", "true"])
+        .output()
+        .map_err(|e| format!("Failed to start WSL distro: {}", e))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = decode_wsl_output(&output.stderr);
+        Err(format!(
+            "Failed to start WSL distro '{}': {}",
+            distro,
+            stderr.trim()
+        ))
+    }
+}
+
+/// Give `user` ownership of synced targets under their home directory
+///
+/// Runs as root so targets left root-owned by earlier syncs (run as the distro's default
+/// user) become writable again. Each existing path is chowned recursively, plus its parent
+/// directory; paths outside the user's home are left alone.
+pub fn fix_wsl_ownership(distro: &str, user: &str, wsl_paths: &[String]) -> Result<(), String> {
+    if !is_valid_wsl_user(user) {
+        return Err(format!("Invalid WSL user name: {}", user));
+    }
+    if wsl_paths.is_empty() {
+        return Ok(());
+    }
+
+    let script = format!(
+        "home=$(getent passwd {user} | cut -d: -f6); \
+         [ -n \"$home\" ] || {{ echo \"user {user} not found\" >&2; exit 1; }}; \
+         for p in \"$@\"; do \
+             p=\"${{p/#\\~/$home}}\"; \
+             case \"$p\" in \"$home\"/*) ;; *) continue ;; esac; \
+             [ -e \"$p\" ] && chown -R {user}: \"$p\"; \
+             d=$(dirname \"$p\"); \
+             [ -d \"$d\" ] && [ \"$d\" != \"$home\" ] && chown {user}: \"$d\"; \
+         done; true"
+    );
+    let output = create_wsl_command()
+        .args([
+            "-d", distro, "-u", "root", "--exec", "bash", "-c", &script, "_",
+        ])
+        .args(wsl_paths)
+        .output()
+        .map_err(|e| format!("Failed to execute WSL command: {}", e))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = decode_wsl_output(&output.stderr);
+        Err(format!(
+            "Failed to fix WSL file ownership: {}",
+            stderr.trim()
+        ))
+    }
+}
+
 /// Expand environment variables in a path
 pub fn expand_env_vars(path: &str) -> Result<String, String> {
     super::super::expand_local_path(path)
@@ -294,8 +393,8 @@ pub fn sync_single_file(
         wsl_target_path, wsl_source_path, wsl_target_path
     );
 
-    let output = create_wsl_command()
-        .args(["-d", distro, "--exec", "bash", "-c", &command])
+    let output = wsl_distro_command(distro)
+        .args(["--exec", "bash", "-c", &command])
         .output()
         .map_err(|e| format!("Failed to execute WSL command: {}", e))?;
 
@@ -323,8 +422,8 @@ pub fn sync_directory(
         "if [ -e \"{}\" ]; then echo exists; else echo notfound; fi",
         wsl_source_path
     );
-    let check_output = create_wsl_command()
-        .args(["-d", distro, "--exec", "bash", "-c", &check_command])
+    let check_output = wsl_distro_command(distro)
+        .args(["--exec", "bash", "-c", &check_command])
         .output()
         .map_err(|e| format!("Failed to check WSL source path: {}", e))?;
 
@@ -351,8 +450,8 @@ pub fn sync_directory(
         wsl_target_path, wsl_target_path, wsl_source_path, wsl_target_path
     );
 
-    let output = create_wsl_command()
-        .args(["-d", distro, "--exec", "bash", "-c", &command])
+    let output = wsl_distro_command(distro)
+        .args(["--exec", "bash", "-c", &command])
         .output()
         .map_err(|e| format!("Failed to execute WSL directory command: {}", e))?;
 
@@ -429,8 +528,8 @@ pub fn sync_pattern_files(
         wsl_target_dir_expanded
     );
 
-    let output = create_wsl_command()
-        .args(["-d", distro, "--exec", "bash", "-c", &command])
+    let output = wsl_distro_command(distro)
+        .args(["--exec", "bash", "-c", &command])
         .output()
         .map_err(|e| format!("Failed to execute WSL pattern command: {}", e))?;
 
@@ -556,8 +655,8 @@ pub fn read_wsl_file_raw(distro: &str, wsl_path: &str) -> Result<String, String>
         wsl_target, wsl_target
     );
 
-    let output = create_wsl_command()
-        .args(["-d", distro, "--exec", "bash", "-c", &command])
+    let output = wsl_distro_command(distro)
+        .args(["--exec", "bash", "-c", &command])
         .output()
         .map_err(|e| format!("Failed to read WSL file: {}", e))?;
 
@@ -601,8 +700,8 @@ pub fn read_wsl_file(distro: &str, wsl_path: &str) -> Result<String, String> {
     let wsl_target = wsl_path.replace("~", "$HOME");
     let convert_command = format!("iconv -f GBK -t UTF-8 \"{}\" 2>/dev/null", wsl_target);
 
-    let convert_output = create_wsl_command()
-        .args(["-d", distro, "--exec", "bash", "-c", &convert_command])
+    let convert_output = wsl_distro_command(distro)
+        .args(["--exec", "bash", "-c", &convert_command])
         .output()
         .map_err(|e| format!("Failed to run iconv: {}", e))?;
 
@@ -642,8 +741,8 @@ pub fn write_wsl_file(distro: &str, wsl_path: &str, content: &str) -> Result<(),
         wsl_target, wsl_target
     );
 
-    let mut child = create_wsl_command()
-        .args(["-d", distro, "--exec", "bash", "-c", &command])
+    let mut child = wsl_distro_command(distro)
+        .args(["--exec", "bash", "-c", &command])
         .stdin(std::process::Stdio::piped())
         .spawn()
         .map_err(|e| format!("Failed to spawn WSL command: {}", e))?;
@@ -676,8 +775,8 @@ pub fn create_wsl_symlink(distro: &str, target: &str, link_path: &str) -> Result
         link_expanded, link_expanded, target_expanded, link_expanded
     );
 
-    let output = create_wsl_command()
-        .args(["-d", distro, "--exec", "bash", "-c", &command])
+    let output = wsl_distro_command(distro)
+        .args(["--exec", "bash", "-c", &command])
         .output()
         .map_err(|e| format!("Failed to create symlink: {}", e))?;
 
@@ -700,8 +799,8 @@ pub fn remove_wsl_path(distro: &str, wsl_path: &str) -> Result<(), String> {
     let wsl_target = wsl_path.replace("~", "$HOME");
     let command = format!("rm -rf \"{}\"", wsl_target);
 
-    let output = create_wsl_command()
-        .args(["-d", distro, "--exec", "bash", "-c", &command])
+    let output = wsl_distro_command(distro)
+        .args(["--exec", "bash", "-c", &command])
         .output()
         .map_err(|e| format!("Failed to remove WSL path: {}", e))?;
 
@@ -721,8 +820,8 @@ pub fn list_wsl_dir(distro: &str, wsl_path: &str) -> Result<Vec<String>, String>
         wsl_target, wsl_target
     );
 
-    let output = create_wsl_command()
-        .args(["-d", distro, "--exec", "bash", "-c", &command])
+    let output = wsl_distro_command(distro)
+        .args(["--exec", "bash", "-c", &command])
         .output()
         .map_err(|e| format!("Failed to list WSL dir: {}", e))?;
 
@@ -742,8 +841,8 @@ pub fn check_wsl_symlink_exists(distro: &str, link_path: &str, expected_target:
         link_expanded, link_expanded, target_expanded
     );
 
-    if let Ok(output) = create_wsl_command()
-        .args(["-d", distro, "--exec", "bash", "-c", &command])
+    if let Ok(output) = wsl_distro_command(distro)
+        .args(["--exec", "bash", "-c", &command])
         .output()
     {
         decode_wsl_output(&output.stdout).trim() == "yes"
@@ -756,8 +855,8 @@ pub fn wsl_path_exists(distro: &str, wsl_path: &str) -> bool {
     let wsl_target = wsl_path.replace("~", "$HOME");
     let command = format!("[ -e \"{}\" ] && echo yes || echo no", wsl_target);
 
-    if let Ok(output) = create_wsl_command()
-        .args(["-d", distro, "--exec", "bash", "-c", &command])
+    if let Ok(output) = wsl_distro_command(distro)
+        .args(["--exec", "bash", "-c", &command])
         .output()
     {
         decode_wsl_output(&output.stdout).trim() == "yes"
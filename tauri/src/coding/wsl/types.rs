@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::coding::runtime_location::WslDirectModuleStatus;
+use crate::db_timestamp::Timestamp;
 
 // ============================================================================
 // File Mapping Types
@@ -18,6 +19,21 @@ pub struct FileMapping {
     pub enabled: bool,
     pub is_pattern: bool,
     pub is_directory: bool,
+    /// Strip `cmd /c` wrappers from MCP servers in the synced copy (MCP config files only)
+    #[serde(default = "default_true")]
+    pub strip_cmd_c: bool,
+}
+
+/// cmd /c normalization preview of one mapping (Windows content -> WSL content)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSLTransformPreview {
+    pub mapping_id: String,
+    pub windows_path: String,
+    pub wsl_path: String,
+    pub original: String,
+    pub transformed: String,
+    pub changed: bool,
 }
 
 // ============================================================================
@@ -36,8 +52,18 @@ pub struct WSLSyncConfig {
     /// Sync Skills to WSL (default: true)
     #[serde(default = "default_true")]
     pub sync_skills: bool,
+    /// Sync automatically when watched Windows-side config files change (default: false)
+    #[serde(default)]
+    pub watch_config_files: bool,
+    /// Start the distro before syncing if it is stopped (default: false)
+    #[serde(default)]
+    pub auto_start_distro: bool,
+    /// Linux user to run sync commands as (`wsl -u`); empty uses the distro's default user
+    #[serde(default)]
+    pub wsl_user: String,
     pub file_mappings: Vec<FileMapping>,
-    pub last_sync_time: Option<String>,
+    #[serde(default, deserialize_with = "crate::db_timestamp::lenient")]
+    pub last_sync_time: Option<Timestamp>,
     pub last_sync_status: String, // "success" | "error" | "never"
     pub last_sync_error: Option<String>,
     #[serde(default)]
@@ -51,6 +77,9 @@ impl Default for WSLSyncConfig {
             distro: String::new(),
             sync_mcp: true,
             sync_skills: true,
+            watch_config_files: false,
+            auto_start_distro: false,
+            wsl_user: String::new(),
             file_mappings: vec![],
             last_sync_time: None,
             last_sync_status: "never".to_string(),
@@ -100,11 +129,49 @@ pub struct WSLDetectResult {
 #[serde(rename_all = "camelCase")]
 pub struct WSLStatusResult {
     pub wsl_available: bool,
-    pub last_sync_time: Option<String>,
+    pub last_sync_time: Option<Timestamp>,
     pub last_sync_status: String,
     pub last_sync_error: Option<String>,
     #[serde(default)]
     pub module_statuses: Vec<WslDirectModuleStatus>,
+    /// Last sync of every distro synced so far, most recent first
+    #[serde(default)]
+    pub distro_statuses: Vec<WSLDistroSyncStatus>,
+}
+
+/// Last sync status of one distro (`wsl_distro_status` table)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSLDistroSyncStatus {
+    pub distro: String,
+    pub last_sync_time: Option<Timestamp>,
+    pub last_sync_status: String,
+    pub last_sync_error: Option<String>,
+}
+
+/// One recorded full sync run (`wsl_sync_log` table)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSLSyncLogEntry {
+    pub id: String,
+    pub timestamp: Option<Timestamp>,
+    pub distro: String,
+    pub module: Option<String>,
+    pub success: bool,
+    pub synced_files: Vec<String>,
+    pub skipped_files: Vec<String>,
+    pub errors: Vec<String>,
+    pub duration_ms: u64,
+}
+
+/// Payload of the `wsl-distro-state-changed` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSLDistroStateChanged {
+    pub distro: String,
+    pub state: String, // "Running" | "Stopped" | "Unknown"
+    /// None for the first report after startup or a distro change
+    pub previous_state: Option<String>,
 }
 
 // ============================================================================
@@ -0,0 +1,138 @@
+//! Watches the Windows-side config files of WSL file mappings
+//!
+//! When `watch_config_files` is on, edits made outside the app (an editor, the CLI itself)
+//! emit the same `wsl-sync-request-<module>` events as in-app saves, so the existing
+//! listeners run the module sync. Parent directories are watched non-recursively because
+//! editors often replace a file instead of writing it in place.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use tauri::{Emitter, Manager};
+
+use super::commands::{resolve_dynamic_paths_with_db, wsl_get_config};
+use super::sync;
+use crate::DbState;
+
+/// Wait for edits to settle before syncing (editors write several events per save)
+const DEBOUNCE: Duration = Duration::from_millis(1500);
+
+/// Modules whose config files are watched
+const WATCHED_MODULES: [&str; 3] = ["opencode", "claude", "codex"];
+
+struct ConfigWatcher {
+    /// Watched file -> module
+    files: HashMap<PathBuf, String>,
+    _watcher: RecommendedWatcher,
+}
+
+static CONFIG_WATCHER: Mutex<Option<ConfigWatcher>> = Mutex::new(None);
+
+fn stop_watcher() {
+    if let Ok(mut guard) = CONFIG_WATCHER.lock() {
+        if guard.take().is_some() {
+            log::info!("[wsl] config file watcher stopped");
+        }
+    }
+}
+
+/// Single-file mappings of the watched modules, skipping modules that already live in WSL
+async fn watched_files(app: &tauri::AppHandle) -> Result<Option<HashMap<PathBuf, String>>, String> {
+    let state = app.state::<DbState>();
+    let config = wsl_get_config(state.clone()).await?;
+    if !config.enabled || !config.watch_config_files {
+        return Ok(None);
+    }
+
+    let direct_modules: HashSet<&str> = config
+        .module_statuses
+        .iter()
+        .filter(|status| status.is_wsl_direct)
+        .map(|status| status.module.as_str())
+        .collect();
+    let db = state.db();
+    let mappings = resolve_dynamic_paths_with_db(&db, config.file_mappings.clone()).await;
+
+    let files = mappings
+        .into_iter()
+        .filter(|m| m.enabled && !m.is_pattern && !m.is_directory)
+        .filter(|m| WATCHED_MODULES.contains(&m.module.as_str()))
+        .filter(|m| !direct_modules.contains(m.module.as_str()))
+        .filter_map(|m| {
+            let path = sync::expand_env_vars(&m.windows_path).ok()?;
+            Some((PathBuf::from(path), m.module))
+        })
+        .collect();
+    Ok(Some(files))
+}
+
+/// Start, re-arm or stop the config file watcher according to the WSL sync config
+pub async fn refresh_config_watcher(app: &tauri::AppHandle) -> Result<(), String> {
+    let Some(files) = watched_files(app).await? else {
+        stop_watcher();
+        return Ok(());
+    };
+
+    let mut guard = CONFIG_WATCHER
+        .lock()
+        .map_err(|_| "WSL config watcher lock poisoned".to_string())?;
+    if guard.as_ref().map(|w| &w.files) == Some(&files) {
+        return Ok(());
+    }
+
+    let (tx, rx) = mpsc::channel::<String>();
+    let watched = files.clone();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        let Ok(event) = res else {
+            return;
+        };
+        if event.kind.is_access() {
+            return;
+        }
+        for path in &event.paths {
+            if let Some(module) = watched.get(path) {
+                let _ = tx.send(module.clone());
+            }
+        }
+    })
+    .map_err(|e| format!("Failed to create WSL config watcher: {}", e))?;
+
+    let dirs: HashSet<PathBuf> = files
+        .keys()
+        .filter_map(|path| path.parent().map(PathBuf::from))
+        .collect();
+    for dir in dirs.iter().filter(|dir| dir.is_dir()) {
+        if let Err(e) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+            log::warn!("[wsl] failed to watch {:?}: {}", dir, e);
+        }
+    }
+
+    // The sender lives in the watcher callback, so this thread ends once the watcher is dropped
+    let app_handle = app.clone();
+    std::thread::spawn(move || {
+        while let Ok(first) = rx.recv() {
+            let mut modules = HashSet::from([first]);
+            while let Ok(module) = rx.recv_timeout(DEBOUNCE) {
+                modules.insert(module);
+            }
+            for module in modules {
+                log::info!(
+                    "[wsl] config of {} changed on disk, requesting sync",
+                    module
+                );
+                let _ = app_handle.emit(&format!("wsl-sync-request-{}", module), ());
+            }
+        }
+    });
+
+    log::info!("[wsl] watching {} config files", files.len());
+    *guard = Some(ConfigWatcher {
+        files,
+        _watcher: watcher,
+    });
+    Ok(())
+}
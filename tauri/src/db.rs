@@ -2,6 +2,12 @@ use std::path::Path;
 use surrealdb::engine::local::SurrealKv;
 use surrealdb::Surreal;
 
+/// Shared database handle.
+///
+/// There is deliberately no lock around the connection: the embedded engine
+/// handles concurrent queries itself, so commands never wait on each other to
+/// reach the database. Keep long blocking work (file walks, compression) off the
+/// async runtime with `spawn_blocking` instead of serializing access here.
 pub struct DbState(pub Surreal<surrealdb::engine::local::Db>);
 
 impl DbState {
@@ -11,6 +17,25 @@ impl DbState {
     }
 }
 
+/// Open the database at `db_path` and run pending migrations.
+///
+/// Used where the database is needed without the GUI setup (headless CLI runs).
+pub async fn open_database(
+    db_path: &Path,
+) -> Result<Surreal<surrealdb::engine::local::Db>, String> {
+    let db = Surreal::new::<SurrealKv>(db_path.to_path_buf())
+        .await
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    db.use_ns("ai_toolbox")
+        .use_db("main")
+        .await
+        .map_err(|e| format!("Failed to select ns/db: {}", e))?;
+    crate::db_migration::run_all_db_migrations(&db)
+        .await
+        .map_err(|e| format!("Failed to run database migrations: {}", e))?;
+    Ok(db)
+}
+
 /// clog 压缩阈值（字节）
 const COMPACT_THRESHOLD: u64 = 1 * 1024 * 1024;
 
@@ -0,0 +1,99 @@
+//! Database Dump Module
+//!
+//! Exports every table to a single readable file, separate from the zip backup:
+//! - `json`: `{ format, version, schema_version, tables: { name: [records] } }` with record
+//!   ids as strings, suitable for inspection or diffing two machines
+//! - `surql`: a SurrealQL script from the engine's own export, which can be imported
+//!   into a fresh database or another storage engine
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+use crate::db::DbState;
+
+const DUMP_FORMAT: &str = "ai-toolbox-db-dump";
+const DUMP_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DumpFormat {
+    Json,
+    Surql,
+}
+
+/// Dump all tables to `target_path`, returning the written path
+#[tauri::command]
+pub async fn dump_database(
+    state: tauri::State<'_, DbState>,
+    format: DumpFormat,
+    target_path: String,
+) -> Result<String, String> {
+    let target = PathBuf::from(&target_path);
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory: {}", e))?;
+    }
+    let db = state.db();
+
+    match format {
+        DumpFormat::Surql => {
+            db.export(&target)
+                .await
+                .map_err(|e| format!("Failed to export database: {}", e))?;
+        }
+        DumpFormat::Json => {
+            let mut tables = Map::new();
+            for table in list_tables(&db).await? {
+                let records: Vec<Value> = db
+                    .query(format!(
+                        "SELECT *, type::string(id) as id FROM `{}` ORDER BY id",
+                        table
+                    ))
+                    .await
+                    .map_err(|e| format!("Failed to read {}: {}", table, e))?
+                    .take(0)
+                    .map_err(|e| format!("Failed to read {}: {}", table, e))?;
+                tables.insert(table, Value::Array(records));
+            }
+
+            let dump = json!({
+                "format": DUMP_FORMAT,
+                "version": DUMP_VERSION,
+                "app_version": env!("CARGO_PKG_VERSION"),
+                "schema_version": crate::db_migration::get_schema_version(&db).await?,
+                "exported_at": chrono::Local::now().to_rfc3339(),
+                "tables": tables,
+            });
+            let content = serde_json::to_string_pretty(&dump)
+                .map_err(|e| format!("Failed to serialize database dump: {}", e))?;
+            std::fs::write(&target, content)
+                .map_err(|e| format!("Failed to write database dump: {}", e))?;
+        }
+    }
+
+    log::info!("Database dumped ({:?}) to {:?}", format, target);
+    Ok(target.to_string_lossy().to_string())
+}
+
+/// Names of all tables in the current database, sorted
+async fn list_tables(
+    db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
+) -> Result<Vec<String>, String> {
+    let info: Option<Value> = db
+        .query("INFO FOR DB")
+        .await
+        .map_err(|e| format!("Failed to read database info: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to read database info: {}", e))?;
+
+    let mut tables: Vec<String> = info
+        .as_ref()
+        .and_then(|info| info.get("tables"))
+        .and_then(Value::as_object)
+        .map(|tables| tables.keys().cloned().collect())
+        .unwrap_or_default();
+    tables.sort();
+    Ok(tables)
+}
@@ -0,0 +1,138 @@
+//! Database Change Events
+//!
+//! A live query on each watched table turns every create / update / delete into a
+//! `db-changed` event carrying the table, the clean record id and the action, no matter
+//! which command, sync or migration made the change. The frontend can refresh the one
+//! affected list instead of reloading everything, and the tray menu is rebuilt when a
+//! table it shows changes.
+//!
+//! The existing `config-changed` / `mcp-changed` / `skills-changed` events are kept:
+//! they also trigger file syncs and carry the change source, which a live query cannot.
+//!
+//! A live query lives and dies with the database handle it was registered on, so each
+//! table's query is registered again on the current handle whenever its stream ends
+//! (e.g. after the database was reopened).
+
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Thing;
+use surrealdb::{Action, Notification};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use crate::coding::db_clean_id;
+use crate::DbState;
+
+pub const DB_CHANGED_EVENT: &str = "db-changed";
+
+/// Wait before registering a live query again after its stream ended or failed
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(5);
+
+/// Tables with change events, and whether the tray menu shows their records
+const WATCHED_TABLES: &[(&str, bool)] = &[
+    ("claude_provider", true),
+    ("codex_provider", true),
+    ("aider_provider", true),
+    ("claude_prompt_config", true),
+    ("codex_prompt_config", true),
+    ("opencode_prompt_config", true),
+    ("opencode_favorite_provider", true),
+    ("opencode_favorite_plugin", false),
+    ("prompt_library", false),
+    ("oh_my_openagent_config", true),
+    ("oh_my_opencode_slim_config", true),
+    ("mcp_server", true),
+    ("mcp_profile", false),
+    ("skill", true),
+    ("skill_repo", false),
+    ("settings", false),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DbChangeAction {
+    Create,
+    Update,
+    Delete,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbChangeEvent {
+    pub table: String,
+    pub id: String,
+    pub action: DbChangeAction,
+}
+
+/// The part of a changed record the event needs; deletes carry the record as it was
+#[derive(Debug, Deserialize)]
+struct ChangedRecord {
+    id: Thing,
+}
+
+/// Start one live query per watched table; changes are emitted until the app exits
+pub async fn init<R: Runtime>(app: &AppHandle<R>) {
+    for (table, shown_in_tray) in WATCHED_TABLES {
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            loop {
+                watch_table(&app, table, *shown_in_tray).await;
+                tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+            }
+        });
+    }
+}
+
+/// Register a live query on the current database handle and emit its changes until the
+/// stream ends
+async fn watch_table<R: Runtime>(app: &AppHandle<R>, table: &str, shown_in_tray: bool) {
+    let db = app.state::<DbState>().db();
+    let response = db
+        .query(format!("LIVE SELECT * FROM {}", table))
+        .await
+        .and_then(|mut response| response.stream::<Notification<ChangedRecord>>(0));
+    let mut stream = match response {
+        Ok(stream) => stream,
+        Err(e) => {
+            log::warn!("订阅 {} 表变更失败: {}", table, e);
+            return;
+        }
+    };
+
+    while let Some(notification) = stream.next().await {
+        let notification = match notification {
+            Ok(notification) => notification,
+            Err(e) => {
+                log::warn!("读取 {} 表变更失败: {}", table, e);
+                continue;
+            }
+        };
+        let Some(event) = to_change_event(table, &notification) else {
+            continue;
+        };
+        let _ = app.emit(DB_CHANGED_EVENT, &event);
+        if shown_in_tray {
+            crate::tray::schedule_tray_refresh(app);
+        }
+    }
+    log::info!("{} 表变更订阅已结束，稍后重新订阅", table);
+}
+
+fn to_change_event(
+    table: &str,
+    notification: &Notification<ChangedRecord>,
+) -> Option<DbChangeEvent> {
+    let action = match notification.action {
+        Action::Create => DbChangeAction::Create,
+        Action::Update => DbChangeAction::Update,
+        Action::Delete => DbChangeAction::Delete,
+        _ => return None,
+    };
+
+    Some(DbChangeEvent {
+        table: table.to_string(),
+        id: db_clean_id(&notification.data.id.to_string()),
+        action,
+    })
+}
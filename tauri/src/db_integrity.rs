@@ -0,0 +1,380 @@
+//! Database Integrity Module
+//!
+//! Finds records that no longer line up with each other and optionally repairs them:
+//! - Duplicate records: two records of a table with the same clean id (e.g. `⟨uuid⟩`
+//!   and `` `uuid` ``) or the same natural key (skill name, MCP server name, favorite
+//!   provider id). The most recently updated record is kept. Duplicate skills are deleted
+//!   with the tool and central directories that only they use.
+//! - Dangling `source_provider_id`: Claude Code / Codex providers imported from an
+//!   OpenCode provider that exists neither in the OpenCode config nor in the
+//!   favorites. The reference is cleared.
+//! - Stale skill targets: tools listed in a skill's `enabled_tools` without a target
+//!   in its `sync_details`, which the skills page shows as synced but nothing backs.
+//!   Only reported: the tool may not be installed yet, and dropping it would forget that
+//!   the skill should be synced to it.
+//!
+//! Models are stored inside their provider records, so they cannot be orphaned.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::coding::db_clean_id;
+use crate::coding::open_code::{read_opencode_config, ReadConfigResult};
+use crate::coding::skills::adapter::from_db_skill;
+use crate::coding::skills::delete_duplicate_skill;
+use crate::DbState;
+
+/// Tables checked for duplicates, with the field that must be unique ("id" means the clean record id)
+const UNIQUE_KEYS: &[(&str, &str)] = &[
+    ("claude_provider", "id"),
+    ("codex_provider", "id"),
+    ("claude_prompt_config", "id"),
+    ("codex_prompt_config", "id"),
+    ("opencode_prompt_config", "id"),
+    ("mcp_server", "id"),
+    ("mcp_server", "name"),
+    ("skill", "id"),
+    ("skill", "name"),
+    ("opencode_favorite_provider", "provider_id"),
+    ("opencode_favorite_plugin", "plugin_name"),
+];
+/// Tables whose records may reference an OpenCode provider through `source_provider_id`
+const SOURCE_PROVIDER_TABLES: &[&str] = &["claude_provider", "codex_provider"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IntegrityIssueKind {
+    DuplicateRecord,
+    DanglingSourceProvider,
+    StaleSkillTarget,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityIssue {
+    pub kind: IntegrityIssueKind,
+    pub table: String,
+    pub record_id: String,
+    pub detail: String,
+    /// Whether the issue was fixed by this run
+    pub repaired: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityReport {
+    pub issues: Vec<IntegrityIssue>,
+    pub repaired_count: usize,
+}
+
+/// Report integrity issues without changing anything
+#[tauri::command]
+pub async fn check_database_integrity(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, DbState>,
+) -> Result<IntegrityReport, String> {
+    run_integrity_check(app, state, false).await
+}
+
+/// Report integrity issues and fix or remove the affected records
+#[tauri::command]
+pub async fn repair_database_integrity(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, DbState>,
+) -> Result<IntegrityReport, String> {
+    run_integrity_check(app, state, true).await
+}
+
+async fn run_integrity_check(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, DbState>,
+    repair: bool,
+) -> Result<IntegrityReport, String> {
+    let db = state.db();
+    let mut tables: HashMap<&str, Vec<Value>> = HashMap::new();
+    for table in UNIQUE_KEYS.iter().map(|(table, _)| *table) {
+        if !tables.contains_key(table) {
+            tables.insert(table, load_records(&db, table).await?);
+        }
+    }
+
+    // Skills that stay, whose directories must survive deleting their duplicates
+    let duplicate_skills: HashSet<String> = ["id", "name"]
+        .iter()
+        .flat_map(|key| find_duplicates(&tables["skill"], key))
+        .map(raw_id)
+        .collect();
+    let remaining_skills: Vec<_> = tables["skill"]
+        .iter()
+        .filter(|record| !duplicate_skills.contains(&raw_id(record)))
+        .map(|record| from_db_skill(record.clone()))
+        .collect();
+
+    let mut issues = Vec::new();
+    let mut removed: HashSet<String> = HashSet::new();
+    for (table, key) in UNIQUE_KEYS {
+        for duplicate in find_duplicates(&tables[table], key) {
+            let raw_id = raw_id(duplicate);
+            if !removed.insert(raw_id.clone()) {
+                continue;
+            }
+            let repaired = if !repair {
+                false
+            } else if *table == "skill" {
+                let skill = from_db_skill(duplicate.clone());
+                match delete_duplicate_skill(&app, &state, &raw_id, &skill, &remaining_skills).await
+                {
+                    Ok(()) => true,
+                    Err(e) => {
+                        log::warn!("删除重复 Skill {} 失败: {}", raw_id, e);
+                        false
+                    }
+                }
+            } else {
+                run_repair(
+                    &db,
+                    &format!("DELETE {} WHERE type::string(id) = $raw_id", table),
+                    &raw_id,
+                )
+                .await
+            };
+            issues.push(IntegrityIssue {
+                kind: IntegrityIssueKind::DuplicateRecord,
+                table: table.to_string(),
+                record_id: db_clean_id(&raw_id),
+                detail: format!(
+                    "Duplicate {} \"{}\"",
+                    key,
+                    key_value(duplicate, key).unwrap_or_default()
+                ),
+                repaired,
+            });
+        }
+    }
+
+    // Without a readable OpenCode config every reference would look dangling
+    if let Some(known_providers) = known_opencode_provider_ids(&state, &tables).await {
+        for table in SOURCE_PROVIDER_TABLES {
+            for record in &tables[table] {
+                let raw_id = raw_id(record);
+                let Some(source_id) = record
+                    .get("source_provider_id")
+                    .and_then(Value::as_str)
+                    .filter(|id| !id.is_empty())
+                else {
+                    continue;
+                };
+                if removed.contains(&raw_id) || known_providers.contains(source_id) {
+                    continue;
+                }
+
+                let repaired = repair
+                    && run_repair(
+                        &db,
+                        &format!(
+                            "UPDATE {} SET source_provider_id = NONE WHERE type::string(id) = $raw_id",
+                            table
+                        ),
+                        &raw_id,
+                    )
+                    .await;
+                issues.push(IntegrityIssue {
+                    kind: IntegrityIssueKind::DanglingSourceProvider,
+                    table: table.to_string(),
+                    record_id: db_clean_id(&raw_id),
+                    detail: format!("OpenCode provider \"{}\" no longer exists", source_id),
+                    repaired,
+                });
+            }
+        }
+    }
+
+    for record in &tables["skill"] {
+        let raw_id = raw_id(record);
+        if removed.contains(&raw_id) {
+            continue;
+        }
+        let stale = stale_targets(record);
+        if stale.is_empty() {
+            continue;
+        }
+
+        issues.push(IntegrityIssue {
+            kind: IntegrityIssueKind::StaleSkillTarget,
+            table: "skill".to_string(),
+            record_id: db_clean_id(&raw_id),
+            detail: format!("Enabled without a target: {}", stale.join(", ")),
+            repaired: false,
+        });
+    }
+
+    let repaired_count = issues.iter().filter(|issue| issue.repaired).count();
+    if !issues.is_empty() {
+        log::info!(
+            "数据库完整性检查发现 {} 个问题，已修复 {} 个",
+            issues.len(),
+            repaired_count
+        );
+    }
+
+    Ok(IntegrityReport {
+        issues,
+        repaired_count,
+    })
+}
+
+async fn load_records(
+    db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
+    table: &str,
+) -> Result<Vec<Value>, String> {
+    db.query(format!("SELECT *, type::string(id) as id FROM {}", table))
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", table, e))?
+        .take(0)
+        .map_err(|e| format!("Failed to read {}: {}", table, e))
+}
+
+async fn run_repair(
+    db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
+    statement: &str,
+    raw_id: &str,
+) -> bool {
+    let result = db
+        .query(statement)
+        .bind(("raw_id", raw_id.to_string()))
+        .await
+        .and_then(|response| response.check());
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            log::warn!("修复记录 {} 失败: {}", raw_id, e);
+            false
+        }
+    }
+}
+
+fn raw_id(record: &Value) -> String {
+    record
+        .get("id")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn key_value(record: &Value, key: &str) -> Option<String> {
+    if key == "id" {
+        return Some(db_clean_id(&raw_id(record)));
+    }
+    record
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+/// Millisecond timestamp of the last update; tables store either epoch millis or RFC 3339
+fn updated_at_millis(record: &Value) -> i64 {
+    match record.get("updated_at") {
+        Some(Value::Number(number)) => number.as_i64().unwrap_or_default(),
+        Some(Value::String(text)) => chrono::DateTime::parse_from_rfc3339(text)
+            .map(|time| time.timestamp_millis())
+            .unwrap_or_default(),
+        _ => 0,
+    }
+}
+
+/// Records sharing a key with a more recently updated record
+fn find_duplicates<'a>(records: &'a [Value], key: &str) -> Vec<&'a Value> {
+    let mut groups: HashMap<String, Vec<&Value>> = HashMap::new();
+    for record in records {
+        if let Some(value) = key_value(record, key) {
+            groups.entry(value).or_default().push(record);
+        }
+    }
+
+    let mut duplicates = Vec::new();
+    for mut group in groups.into_values().filter(|group| group.len() > 1) {
+        group.sort_by_key(|record| std::cmp::Reverse(updated_at_millis(record)));
+        duplicates.extend(group.into_iter().skip(1));
+    }
+    duplicates.sort_by_key(|record| raw_id(record));
+    duplicates
+}
+
+/// Enabled tools of a skill that are not backed by a sync target
+fn stale_targets(record: &Value) -> Vec<String> {
+    let targets = record.get("sync_details").and_then(Value::as_object);
+    record
+        .get("enabled_tools")
+        .and_then(Value::as_array)
+        .map(|tools| {
+            tools
+                .iter()
+                .filter_map(Value::as_str)
+                .filter(|tool| !targets.is_some_and(|targets| targets.contains_key(*tool)))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Provider ids from the OpenCode config and favorites, or None if the config cannot be read
+async fn known_opencode_provider_ids(
+    state: &tauri::State<'_, DbState>,
+    tables: &HashMap<&str, Vec<Value>>,
+) -> Option<HashSet<String>> {
+    let mut known: HashSet<String> = match read_opencode_config(state.clone()).await {
+        Ok(ReadConfigResult::Success { config }) => {
+            config.provider.unwrap_or_default().into_keys().collect()
+        }
+        Ok(ReadConfigResult::NotFound { .. }) => HashSet::new(),
+        _ => return None,
+    };
+    known.extend(
+        tables["opencode_favorite_provider"]
+            .iter()
+            .filter_map(|record| key_value(record, "provider_id")),
+    );
+    Some(known)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn keeps_the_most_recently_updated_duplicate() {
+        let records = vec![
+            json!({ "id": "skill:⟨a⟩", "name": "pdf", "updated_at": 100 }),
+            json!({ "id": "skill:`b`", "name": "pdf", "updated_at": 200 }),
+            json!({ "id": "skill:`c`", "name": "docx", "updated_at": 50 }),
+        ];
+        let duplicates = find_duplicates(&records, "name");
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(raw_id(duplicates[0]), "skill:⟨a⟩");
+    }
+
+    #[test]
+    fn detects_ids_that_differ_only_in_format() {
+        let records = vec![
+            json!({ "id": "claude_provider:⟨p1⟩", "updated_at": "2026-01-01T00:00:00+08:00" }),
+            json!({ "id": "claude_provider:`p1`", "updated_at": "2026-02-01T00:00:00+08:00" }),
+        ];
+        let duplicates = find_duplicates(&records, "id");
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(raw_id(duplicates[0]), "claude_provider:⟨p1⟩");
+    }
+
+    #[test]
+    fn finds_enabled_tools_without_targets() {
+        let record = json!({
+            "enabled_tools": ["claude_code", "codex"],
+            "sync_details": { "claude_code": { "target_path": "/tmp/x" } },
+        });
+        assert_eq!(stale_targets(&record), vec!["codex"]);
+    }
+}
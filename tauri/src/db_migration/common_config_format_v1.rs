@@ -0,0 +1,59 @@
+use serde_json::Value;
+
+use super::{mark_migration_applied, MigrationOutcome};
+
+pub const MIGRATION_ID: &str = "2026_10_16_common_config_format_v1";
+
+/// Single-record common config tables, each holding a `common` record
+const COMMON_CONFIG_TABLES: &[&str] = &[
+    "opencode_common_config",
+    "claude_common_config",
+    "codex_common_config",
+    "openclaw_common_config",
+];
+
+/// Remove common config records that older versions stored in a shape the
+/// current code can no longer deserialize.
+///
+/// The `get_*_common_config` commands used to detect this on every read and
+/// delete the record as a side effect; doing it once at startup keeps the
+/// read paths free of writes and records what happened.
+pub fn run_migration<'a>(
+    db: &'a surrealdb::Surreal<surrealdb::engine::local::Db>,
+) -> super::MigrationFuture<'a> {
+    Box::pin(async move {
+        let mut removed = Vec::new();
+
+        for table in COMMON_CONFIG_TABLES {
+            let result: Result<Vec<Value>, _> = db
+                .query(format!(
+                    "SELECT *, type::string(id) as id FROM {}:`common` LIMIT 1",
+                    table
+                ))
+                .await
+                .map_err(|error| format!("Failed to query {}: {}", table, error))?
+                .take(0);
+
+            if let Err(error) = result {
+                log::warn!(
+                    "Removing {} record with incompatible format: {}",
+                    table,
+                    error
+                );
+                db.query(format!("DELETE {}:`common`", table))
+                    .await
+                    .map_err(|error| format!("Failed to delete {} record: {}", table, error))?;
+                removed.push(*table);
+            }
+        }
+
+        let status = format!("removed={}", removed.join(","));
+        mark_migration_applied(db, MIGRATION_ID, &status).await?;
+
+        Ok(if removed.is_empty() {
+            MigrationOutcome::SkippedNoOp
+        } else {
+            MigrationOutcome::Applied
+        })
+    })
+}
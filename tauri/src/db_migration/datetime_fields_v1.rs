@@ -0,0 +1,113 @@
+use serde_json::Value;
+
+use super::{mark_migration_applied, MigrationOutcome};
+
+pub const MIGRATION_ID: &str = "2026_10_16_datetime_fields_v1";
+
+/// Timestamp fields written as RFC 3339 strings, as (table, field).
+/// MCP servers, skills and custom tools store epoch milliseconds and are left alone.
+const DATETIME_FIELDS: &[(&str, &str)] = &[
+    ("claude_provider", "created_at"),
+    ("claude_provider", "updated_at"),
+    ("codex_provider", "created_at"),
+    ("codex_provider", "updated_at"),
+    ("claude_prompt_config", "created_at"),
+    ("claude_prompt_config", "updated_at"),
+    ("codex_prompt_config", "created_at"),
+    ("codex_prompt_config", "updated_at"),
+    ("opencode_prompt_config", "created_at"),
+    ("opencode_prompt_config", "updated_at"),
+    ("opencode_favorite_provider", "created_at"),
+    ("opencode_favorite_provider", "updated_at"),
+    ("opencode_favorite_plugin", "created_at"),
+    ("oh_my_openagent_config", "created_at"),
+    ("oh_my_openagent_config", "updated_at"),
+    ("oh_my_openagent_global_config", "updated_at"),
+    ("oh_my_opencode_slim_config", "created_at"),
+    ("oh_my_opencode_slim_config", "updated_at"),
+    ("oh_my_opencode_slim_global_config", "updated_at"),
+    ("claude_common_config", "updated_at"),
+    ("codex_common_config", "updated_at"),
+    ("opencode_common_config", "updated_at"),
+    ("openclaw_common_config", "updated_at"),
+    ("ssh_sync_config", "updated_at"),
+    ("ssh_sync_config", "last_sync_time"),
+    ("wsl_sync_config", "updated_at"),
+    ("wsl_sync_config", "last_sync_time"),
+    ("ssh_sync_log", "timestamp"),
+    ("wsl_sync_log", "timestamp"),
+    ("wsl_distro_status", "last_sync_time"),
+    ("ssh_known_host", "accepted_at"),
+    ("secret", "updated_at"),
+    ("settings", "last_backup_time"),
+];
+
+/// Store existing timestamps as datetime values so they sort and compare by time.
+///
+/// New records already get datetimes from the write paths (see `db_timestamp`). Strings
+/// that are not valid timestamps are left in place, so nothing is lost; they read back
+/// as missing timestamps.
+pub fn run_migration<'a>(
+    db: &'a surrealdb::Surreal<surrealdb::engine::local::Db>,
+) -> super::MigrationFuture<'a> {
+    Box::pin(async move {
+        for (table, field) in DATETIME_FIELDS {
+            let error = |error: surrealdb::Error| {
+                format!(
+                    "Failed to migrate {}.{} to datetime: {}",
+                    table, field, error
+                )
+            };
+
+            let records: Vec<Value> = db
+                .query(format!(
+                    "SELECT type::string(id) AS id, {field} AS value FROM {table} WHERE type::is::string({field})"
+                ))
+                .await
+                .map_err(error)?
+                .take(0)
+                .map_err(error)?;
+            let mut valid = Vec::new();
+            let mut invalid = Vec::new();
+            for record in &records {
+                let (Some(id), Some(value)) = (
+                    record.get("id").and_then(Value::as_str),
+                    record.get("value").and_then(Value::as_str),
+                ) else {
+                    continue;
+                };
+                if is_valid_timestamp(value) {
+                    valid.push(id.to_string());
+                } else {
+                    invalid.push(id.to_string());
+                }
+            }
+
+            db.query(format!(
+                "UPDATE {table} SET {field} = <datetime> {field} WHERE type::string(id) IN $valid"
+            ))
+            .bind(("valid", valid))
+            .await
+            .map_err(error)?
+            .check()
+            .map_err(error)?;
+
+            if !invalid.is_empty() {
+                log::warn!(
+                    "{}.{}: 保留了 {} 个无法解析的时间值: {}",
+                    table,
+                    field,
+                    invalid.len(),
+                    invalid.join(", ")
+                );
+            }
+        }
+
+        mark_migration_applied(db, MIGRATION_ID, "applied").await?;
+        Ok(MigrationOutcome::Applied)
+    })
+}
+
+fn is_valid_timestamp(value: &str) -> bool {
+    crate::db_timestamp::Timestamp::parse(value).is_some()
+}
@@ -0,0 +1,25 @@
+use super::{mark_migration_applied, MigrationOutcome};
+
+pub const MIGRATION_ID: &str = "2026_10_16_define_indexes_v1";
+
+/// Indexes for tables that grow over time and are queried by time range
+const INDEX_DEFINITIONS: &[&str] =
+    &["DEFINE INDEX IF NOT EXISTS audit_log_timestamp ON TABLE audit_log FIELDS timestamp"];
+
+/// Define indexes used by time-ordered queries
+pub fn run_migration<'a>(
+    db: &'a surrealdb::Surreal<surrealdb::engine::local::Db>,
+) -> super::MigrationFuture<'a> {
+    Box::pin(async move {
+        for definition in INDEX_DEFINITIONS {
+            db.query(*definition)
+                .await
+                .map_err(|error| format!("Failed to define index: {}", error))?
+                .check()
+                .map_err(|error| format!("Failed to define index: {}", error))?;
+        }
+
+        mark_migration_applied(db, MIGRATION_ID, "applied").await?;
+        Ok(MigrationOutcome::Applied)
+    })
+}
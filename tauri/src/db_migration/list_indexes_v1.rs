@@ -0,0 +1,38 @@
+use super::{mark_migration_applied, MigrationOutcome};
+
+pub const MIGRATION_ID: &str = "2026_10_16_list_indexes_v1";
+
+/// Fields list and lookup commands sort or filter on, as (table, field)
+const INDEXED_FIELDS: &[(&str, &str)] = &[
+    ("claude_provider", "sort_index"),
+    ("claude_provider", "is_applied"),
+    ("codex_provider", "sort_index"),
+    ("codex_provider", "is_applied"),
+    ("mcp_server", "name"),
+    ("mcp_server", "sort_index"),
+    ("skill", "name"),
+    ("skill", "sort_index"),
+    ("opencode_favorite_provider", "provider_id"),
+    ("opencode_favorite_plugin", "plugin_name"),
+];
+
+/// Define indexes so list commands stop scanning whole tables as data grows
+pub fn run_migration<'a>(
+    db: &'a surrealdb::Surreal<surrealdb::engine::local::Db>,
+) -> super::MigrationFuture<'a> {
+    Box::pin(async move {
+        for (table, field) in INDEXED_FIELDS {
+            db.query(format!(
+                "DEFINE INDEX IF NOT EXISTS {}_{} ON TABLE {} FIELDS {}",
+                table, field, table, field
+            ))
+            .await
+            .map_err(|error| format!("Failed to define index {}.{}: {}", table, field, error))?
+            .check()
+            .map_err(|error| format!("Failed to define index {}.{}: {}", table, field, error))?;
+        }
+
+        mark_migration_applied(db, MIGRATION_ID, "applied").await?;
+        Ok(MigrationOutcome::Applied)
+    })
+}
@@ -1,5 +1,22 @@
+//! Database Migrations
+//!
+//! Ordered migrations run at startup before any command can touch the database.
+//! The database records the schema version it has been migrated to
+//! (`app_migration:schema`), and each migration also writes its own marker so
+//! databases migrated before versioning existed are recognized.
+//!
+//! To change the stored format (field renames, record id formats, indexes),
+//! add a module and append it to `REGISTERED_MIGRATIONS` with the next version
+//! instead of repairing records lazily in the commands that read them.
+
+mod common_config_format_v1;
+mod datetime_fields_v1;
+mod define_indexes_v1;
+mod list_indexes_v1;
 mod oh_my_openagent_rename_v1;
+pub mod search_indexes_v1;
 mod skills_restore_name_normalization_v1;
+mod usage_indexes_v1;
 
 use serde_json::Value;
 use std::future::Future;
@@ -16,6 +33,8 @@ type MigrationRunner =
     for<'a> fn(&'a surrealdb::Surreal<surrealdb::engine::local::Db>) -> MigrationFuture<'a>;
 
 struct DbMigration {
+    /// Schema version reached once this migration has run; strictly increasing
+    version: u32,
     id: &'static str,
     description: &'static str,
     runner: MigrationRunner,
@@ -23,17 +42,65 @@ struct DbMigration {
 
 const REGISTERED_MIGRATIONS: &[DbMigration] = &[
     DbMigration {
+        version: 1,
         id: oh_my_openagent_rename_v1::MIGRATION_ID,
         description: "Rename Oh My OpenAgent main persistence contracts",
         runner: oh_my_openagent_rename_v1::run_migration,
     },
     DbMigration {
+        version: 2,
         id: skills_restore_name_normalization_v1::MIGRATION_ID,
         description: "Normalize restored skill names and central paths for cross-platform sync",
         runner: skills_restore_name_normalization_v1::run_migration,
     },
+    DbMigration {
+        version: 3,
+        id: common_config_format_v1::MIGRATION_ID,
+        description: "Remove common config records with an incompatible format",
+        runner: common_config_format_v1::run_migration,
+    },
+    DbMigration {
+        version: 4,
+        id: define_indexes_v1::MIGRATION_ID,
+        description: "Define indexes for time-ordered tables",
+        runner: define_indexes_v1::run_migration,
+    },
+    DbMigration {
+        version: 5,
+        id: search_indexes_v1::MIGRATION_ID,
+        description: "Define full-text search indexes for global search",
+        runner: search_indexes_v1::run_migration,
+    },
+    DbMigration {
+        version: 6,
+        id: list_indexes_v1::MIGRATION_ID,
+        description: "Define indexes for sorted and filtered list queries",
+        runner: list_indexes_v1::run_migration,
+    },
+    DbMigration {
+        version: 7,
+        id: datetime_fields_v1::MIGRATION_ID,
+        description: "Store RFC 3339 timestamp strings as datetime values",
+        runner: datetime_fields_v1::run_migration,
+    },
+    DbMigration {
+        version: 8,
+        id: usage_indexes_v1::MIGRATION_ID,
+        description: "Define indexes for usage summaries",
+        runner: usage_indexes_v1::run_migration,
+    },
 ];
 
+const SCHEMA_VERSION_RECORD: &str = "app_migration:`schema`";
+
+/// Schema version of a fully migrated database
+pub fn latest_schema_version() -> u32 {
+    REGISTERED_MIGRATIONS
+        .last()
+        .map(|migration| migration.version)
+        .unwrap_or(0)
+}
+
 /// Execute all registered database migrations in order.
 ///
 /// Every app startup goes through this entry point so migration behavior stays
@@ -42,8 +109,24 @@ const REGISTERED_MIGRATIONS: &[DbMigration] = &[
 pub async fn run_all_db_migrations(
     db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
 ) -> Result<(), String> {
+    let current_version = get_schema_version(db).await?;
+    let latest_version = latest_schema_version();
+    if current_version > latest_version {
+        log::warn!(
+            "Database schema version {} is newer than this app supports ({}); it was written by a newer version",
+            current_version,
+            latest_version
+        );
+        return Ok(());
+    }
+
     for migration in REGISTERED_MIGRATIONS {
+        if migration.version <= current_version {
+            continue;
+        }
+        // Databases migrated before schema versions existed only have the marker
         if has_migration(db, migration.id).await? {
+            set_schema_version(db, migration.version).await?;
             continue;
         }
 
@@ -63,11 +146,48 @@ pub async fn run_all_db_migrations(
                 );
             }
         }
+        set_schema_version(db, migration.version).await?;
     }
 
     Ok(())
 }
 
+/// Schema version recorded in the database, 0 for new or pre-versioning databases
+pub async fn get_schema_version(
+    db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
+) -> Result<u32, String> {
+    let records: Vec<Value> = db
+        .query(format!(
+            "SELECT version FROM {} LIMIT 1",
+            SCHEMA_VERSION_RECORD
+        ))
+        .await
+        .map_err(|error| format!("Failed to query schema version: {}", error))?
+        .take(0)
+        .map_err(|error| format!("Failed to read schema version: {}", error))?;
+
+    Ok(records
+        .first()
+        .and_then(|record| record.get("version"))
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as u32)
+}
+
+async fn set_schema_version(
+    db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
+    version: u32,
+) -> Result<(), String> {
+    db.query(format!(
+        "UPSERT {} CONTENT {{ version: $version, updated_at: time::now() }}",
+        SCHEMA_VERSION_RECORD
+    ))
+    .bind(("version", version))
+    .await
+    .map_err(|error| format!("Failed to write schema version {}: {}", version, error))?;
+
+    Ok(())
+}
+
 pub async fn has_migration(
     db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
     migration_id: &str,
@@ -145,3 +265,18 @@ pub async fn load_table_records(
         )
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migration_versions_are_strictly_increasing() {
+        let mut previous = 0;
+        for migration in REGISTERED_MIGRATIONS {
+            assert!(migration.version > previous, "{}", migration.id);
+            previous = migration.version;
+        }
+        assert_eq!(latest_schema_version(), previous);
+    }
+}
@@ -0,0 +1,54 @@
+use super::{mark_migration_applied, MigrationOutcome};
+
+pub const MIGRATION_ID: &str = "2026_10_16_search_indexes_v1";
+
+/// Text fields covered by `search_all`, as (table, field)
+pub const SEARCH_FIELDS: &[(&str, &str)] = &[
+    ("opencode_favorite_provider", "provider_id"),
+    ("opencode_favorite_provider", "provider_config.name"),
+    ("claude_provider", "name"),
+    ("claude_provider", "notes"),
+    ("codex_provider", "name"),
+    ("codex_provider", "notes"),
+    ("mcp_server", "name"),
+    ("mcp_server", "description"),
+    ("skill", "name"),
+    ("skill", "description"),
+    ("skill", "notes"),
+];
+
+/// Prefix-friendly analyzer so partially typed words still match
+const ANALYZER_DEFINITION: &str = "DEFINE ANALYZER IF NOT EXISTS search_text TOKENIZERS blank,class,punct FILTERS lowercase,ascii,edgengram(1,32)";
+
+/// Name of the search index for a table field
+pub fn search_index_name(table: &str, field: &str) -> String {
+    format!("{}_{}_search", table, field.replace('.', "_"))
+}
+
+/// Define the analyzer and full-text indexes used by the global search
+pub fn run_migration<'a>(
+    db: &'a surrealdb::Surreal<surrealdb::engine::local::Db>,
+) -> super::MigrationFuture<'a> {
+    Box::pin(async move {
+        let mut definitions = vec![ANALYZER_DEFINITION.to_string()];
+        definitions.extend(SEARCH_FIELDS.iter().map(|(table, field)| {
+            format!(
+                "DEFINE INDEX IF NOT EXISTS {} ON TABLE {} FIELDS {} SEARCH ANALYZER search_text BM25",
+                search_index_name(table, field),
+                table,
+                field
+            )
+        }));
+
+        for definition in definitions {
+            db.query(definition.as_str())
+                .await
+                .map_err(|error| format!("Failed to define search index: {}", error))?
+                .check()
+                .map_err(|error| format!("Failed to define search index: {}", error))?;
+        }
+
+        mark_migration_applied(db, MIGRATION_ID, "applied").await?;
+        Ok(MigrationOutcome::Applied)
+    })
+}
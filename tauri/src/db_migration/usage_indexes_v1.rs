@@ -0,0 +1,30 @@
+use super::{mark_migration_applied, MigrationOutcome};
+
+pub const MIGRATION_ID: &str = "2026_10_16_usage_indexes_v1";
+
+/// Usage summaries filter by day and tool, as (name, fields)
+const USAGE_INDEXES: &[(&str, &str)] = &[
+    ("usage_record_day", "day"),
+    ("usage_record_tool_day", "tool, day"),
+];
+
+/// Define indexes for the usage table, which grows with every model request
+pub fn run_migration<'a>(
+    db: &'a surrealdb::Surreal<surrealdb::engine::local::Db>,
+) -> super::MigrationFuture<'a> {
+    Box::pin(async move {
+        for (name, fields) in USAGE_INDEXES {
+            db.query(format!(
+                "DEFINE INDEX IF NOT EXISTS {} ON TABLE usage_record FIELDS {}",
+                name, fields
+            ))
+            .await
+            .map_err(|error| format!("Failed to define index {}: {}", name, error))?
+            .check()
+            .map_err(|error| format!("Failed to define index {}: {}", name, error))?;
+        }
+
+        mark_migration_applied(db, MIGRATION_ID, "applied").await?;
+        Ok(MigrationOutcome::Applied)
+    })
+}
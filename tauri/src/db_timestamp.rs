@@ -0,0 +1,165 @@
+//! Database Timestamps
+//!
+//! Provider, prompt, config and sync timestamps are stored as SurrealDB datetimes so they
+//! sort and compare by time. The DTOs carry them as [`Timestamp`], which the frontend sees
+//! as a UTC RFC 3339 string.
+//!
+//! Records are still built as JSON by the adapters, and JSON has no datetime type, so the
+//! write paths bind them through [`with_datetimes`] (whole records) or [`Timestamp::to_db`]
+//! (single fields). Datetimes are read back as RFC 3339 strings.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// Record fields written as datetimes by [`with_datetimes`]
+const TIMESTAMP_FIELDS: [&str; 6] = [
+    "created_at",
+    "updated_at",
+    "last_sync_time",
+    "last_backup_time",
+    "timestamp",
+    "accepted_at",
+];
+
+/// A point in time, stored as a datetime and serialized as a UTC RFC 3339 string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(DateTime<Utc>);
+
+impl Timestamp {
+    pub fn now() -> Self {
+        Self(Utc::now())
+    }
+
+    /// Parse an RFC 3339 timestamp with any offset
+    pub fn parse(value: &str) -> Option<Self> {
+        DateTime::parse_from_rfc3339(value)
+            .ok()
+            .map(|time| Self(time.with_timezone(&Utc)))
+    }
+
+    /// Read a timestamp field of a database record
+    pub fn from_db(value: &Value, key: &str) -> Option<Self> {
+        value.get(key).and_then(Value::as_str).and_then(Self::parse)
+    }
+
+    /// Value to bind for a single timestamp field (`SET updated_at = $now`)
+    pub fn to_db(self) -> surrealdb::sql::Datetime {
+        self.0.into()
+    }
+
+    pub fn as_datetime(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(time: DateTime<Utc>) -> Self {
+        Self(time)
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.to_rfc3339_opts(SecondsFormat::Millis, true))
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Self::parse(&value).ok_or_else(|| {
+            serde::de::Error::custom(format!("invalid RFC 3339 timestamp: {}", value))
+        })
+    }
+}
+
+/// Deserialize an optional timestamp sent by the frontend, treating empty or unparsable
+/// strings (`createdAt: ''` on new records) as missing.
+/// Use with `#[serde(default, deserialize_with = "crate::db_timestamp::lenient")]`.
+pub fn lenient<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Timestamp>, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?
+        .as_deref()
+        .and_then(Timestamp::parse))
+}
+
+/// A record with its timestamp fields as datetimes, bound in place of the JSON record
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum DbContent {
+    Datetime(surrealdb::sql::Datetime),
+    Object(BTreeMap<String, DbContent>),
+    Json(Value),
+}
+
+/// Convert the timestamp fields of a record about to be written into datetimes.
+///
+/// Strings that are not RFC 3339 timestamps are written unchanged, like every other field.
+/// Queries that inline a record as JSON text get strings there instead; they set the
+/// timestamp fields with [`Timestamp::to_db`] in the same transaction.
+pub fn with_datetimes(value: Value) -> DbContent {
+    match value {
+        Value::Object(map) => DbContent::Object(
+            map.into_iter()
+                .map(|(key, field)| {
+                    let time = TIMESTAMP_FIELDS
+                        .contains(&key.as_str())
+                        .then(|| field.as_str().and_then(Timestamp::parse))
+                        .flatten();
+                    let field = match time {
+                        Some(time) => DbContent::Datetime(time.to_db()),
+                        None => DbContent::Json(field),
+                    };
+                    (key, field)
+                })
+                .collect(),
+        ),
+        value => DbContent::Json(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_any_offset_and_serializes_as_utc() {
+        let time = Timestamp::parse("2026-10-16T20:30:00.5+08:00").unwrap();
+        assert_eq!(
+            serde_json::to_value(time).unwrap(),
+            "2026-10-16T12:30:00.500Z"
+        );
+        assert!(Timestamp::parse("2026-10-16 20:30").is_none());
+        assert!(Timestamp::parse("").is_none());
+        assert!(
+            Timestamp::parse("2026-10-16T12:00:00Z")
+                < Timestamp::parse("2026-10-16T20:00:00+07:00")
+        );
+    }
+
+    #[test]
+    fn converts_only_valid_timestamp_fields() {
+        let content = with_datetimes(serde_json::json!({
+            "name": "2026-10-16T12:00:00Z",
+            "created_at": "2026-10-16T12:00:00Z",
+            "updated_at": "yesterday",
+        }));
+        let DbContent::Object(fields) = content else {
+            panic!("expected an object");
+        };
+        assert!(matches!(fields["created_at"], DbContent::Datetime(_)));
+        assert!(
+            matches!(&fields["updated_at"], DbContent::Json(Value::String(s)) if s == "yesterday")
+        );
+        assert!(matches!(fields["name"], DbContent::Json(_)));
+    }
+}
@@ -0,0 +1,255 @@
+//! Deep Link Module
+//!
+//! Handles `ai-toolbox://` links so docs and teammates can share one-click setup links:
+//! - `ai-toolbox://skill/install?repo=<git url>[&branch=<branch>]`
+//! - `ai-toolbox://provider/import?payload=<base64url JSON>`, where the JSON is
+//!   `{"tool": "claudecode" | "codex", "provider": {...provider fields...}}`
+//!
+//! Links are only parsed and validated here. They are queued and announced with the
+//! `deep-link` event; the frontend drains the queue with `take_pending_deep_links` and
+//! asks the user to confirm before anything is installed or imported.
+
+use std::sync::Mutex;
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Runtime};
+
+use crate::coding::claude_code::types::ClaudeCodeProviderInput;
+use crate::coding::codex::types::CodexProviderInput;
+
+pub const DEEP_LINK_SCHEME: &str = "ai-toolbox";
+/// Event telling the frontend that new links are waiting in the queue
+const DEEP_LINK_EVENT: &str = "deep-link";
+/// Upper bound for the encoded provider payload
+const MAX_PAYLOAD_LEN: usize = 64 * 1024;
+
+/// Provider shared through a link, tagged with the tool it belongs to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "tool", content = "provider")]
+pub enum SharedProvider {
+    #[serde(rename = "claudecode")]
+    ClaudeCode(ClaudeCodeProviderInput),
+    #[serde(rename = "codex")]
+    Codex(CodexProviderInput),
+}
+
+/// A parsed link waiting for the user's confirmation
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "action", rename_all = "camelCase")]
+pub enum DeepLinkRequest {
+    #[serde(rename_all = "camelCase")]
+    SkillInstall {
+        repo_url: String,
+        branch: Option<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    ProviderImport { provider: SharedProvider },
+    /// The link could not be parsed; shown to the user instead of being dropped silently
+    #[serde(rename_all = "camelCase")]
+    Invalid { url: String, error: String },
+}
+
+static PENDING: Mutex<Vec<DeepLinkRequest>> = Mutex::new(Vec::new());
+
+/// Parse an `ai-toolbox://` link
+pub fn parse_deep_link(link: &str) -> Result<DeepLinkRequest, String> {
+    let url = reqwest::Url::parse(link.trim()).map_err(|e| format!("Invalid link: {}", e))?;
+    if url.scheme() != DEEP_LINK_SCHEME {
+        return Err(format!("Unsupported link scheme: {}", url.scheme()));
+    }
+
+    let query = |name: &str| {
+        url.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+
+    // ai-toolbox://skill/install -> host "skill", path "/install"
+    let action = format!(
+        "{}{}",
+        url.host_str().unwrap_or_default(),
+        url.path().trim_end_matches('/')
+    );
+    match action.as_str() {
+        "skill/install" => {
+            let repo_url = query("repo").ok_or("Missing repo parameter")?;
+            if !is_remote_repo(&repo_url) {
+                return Err(format!("Not a remote Git repository: {}", repo_url));
+            }
+            Ok(DeepLinkRequest::SkillInstall {
+                repo_url,
+                branch: query("branch"),
+            })
+        }
+        "provider/import" => {
+            let payload = query("payload").ok_or("Missing payload parameter")?;
+            Ok(DeepLinkRequest::ProviderImport {
+                provider: decode_provider_payload(&payload)?,
+            })
+        }
+        _ => Err(format!("Unsupported link action: {}", action)),
+    }
+}
+
+fn decode_provider_payload(payload: &str) -> Result<SharedProvider, String> {
+    if payload.len() > MAX_PAYLOAD_LEN {
+        return Err("Provider payload is too large".to_string());
+    }
+    let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .map_err(|e| format!("Invalid provider payload: {}", e))?;
+    let mut provider: SharedProvider =
+        serde_json::from_slice(&bytes).map_err(|e| format!("Invalid provider payload: {}", e))?;
+
+    // Links always create new providers
+    match &mut provider {
+        SharedProvider::ClaudeCode(input) => input.id = None,
+        SharedProvider::Codex(input) => input.id = None,
+    }
+    Ok(provider)
+}
+
+/// Links may only point at remote repositories, never at local paths
+fn is_remote_repo(repo: &str) -> bool {
+    let lower = repo.to_ascii_lowercase();
+    if ["https://", "http://", "ssh://", "git@", "github.com/"]
+        .iter()
+        .any(|prefix| lower.starts_with(prefix))
+    {
+        return true;
+    }
+
+    // GitHub shorthand: owner/repo
+    let parts: Vec<&str> = repo.split('/').collect();
+    parts.len() == 2
+        && parts.iter().all(|part| {
+            !part.is_empty()
+                && !part.starts_with('.')
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        })
+}
+
+/// Whether this process was started to open a link
+pub fn launched_with_link() -> bool {
+    let prefix = format!("{}://", DEEP_LINK_SCHEME);
+    std::env::args().skip(1).any(|arg| arg.starts_with(&prefix))
+}
+
+/// Queue links received by the app and notify the frontend
+pub fn handle_links<R: Runtime>(app: &AppHandle<R>, links: &[String], show_window: bool) {
+    let requests: Vec<DeepLinkRequest> = links
+        .iter()
+        .map(|link| {
+            parse_deep_link(link).unwrap_or_else(|error| {
+                log::warn!("无法解析深度链接 {}: {}", link, error);
+                DeepLinkRequest::Invalid {
+                    url: link.clone(),
+                    error,
+                }
+            })
+        })
+        .collect();
+    if requests.is_empty() {
+        return;
+    }
+
+    log::info!("收到 {} 个深度链接", requests.len());
+    if let Ok(mut pending) = PENDING.lock() {
+        pending.extend(requests);
+    }
+    if show_window {
+        crate::tray::show_main_window(app);
+    }
+    let _ = app.emit(DEEP_LINK_EVENT, ());
+}
+
+/// Hand the queued links to the frontend, emptying the queue
+#[tauri::command]
+pub fn take_pending_deep_links() -> Vec<DeepLinkRequest> {
+    PENDING
+        .lock()
+        .map(|mut pending| std::mem::take(&mut *pending))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(json: &str) -> String {
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(json)
+    }
+
+    #[test]
+    fn parses_skill_install_links() {
+        let request = parse_deep_link(
+            "ai-toolbox://skill/install?repo=https%3A%2F%2Fgithub.com%2Facme%2Fskills&branch=dev",
+        )
+        .unwrap();
+        match request {
+            DeepLinkRequest::SkillInstall { repo_url, branch } => {
+                assert_eq!(repo_url, "https://github.com/acme/skills");
+                assert_eq!(branch.as_deref(), Some("dev"));
+            }
+            other => panic!("unexpected request: {:?}", other),
+        }
+
+        assert!(parse_deep_link("ai-toolbox://skill/install?repo=acme/skills").is_ok());
+        assert!(parse_deep_link("ai-toolbox://skill/install").is_err());
+    }
+
+    #[test]
+    fn rejects_local_repositories() {
+        for repo in ["/etc", "file:///tmp/repo", "../repo", "C:\\repo", "~/repo"] {
+            let link = format!("ai-toolbox://skill/install?repo={}", url_encode(repo));
+            assert!(parse_deep_link(&link).is_err(), "{}", repo);
+        }
+    }
+
+    #[test]
+    fn parses_provider_import_links() {
+        let payload = encode(
+            r#"{"tool":"claudecode","provider":{"id":"abc","name":"Team","category":"custom","settingsConfig":"{}"}}"#,
+        );
+        let request =
+            parse_deep_link(&format!("ai-toolbox://provider/import?payload={}", payload)).unwrap();
+        match request {
+            DeepLinkRequest::ProviderImport {
+                provider: SharedProvider::ClaudeCode(input),
+            } => {
+                assert_eq!(input.name, "Team");
+                assert!(input.id.is_none());
+            }
+            other => panic!("unexpected request: {:?}", other),
+        }
+
+        let unknown_tool = encode(r#"{"tool":"other","provider":{}}"#);
+        assert!(parse_deep_link(&format!(
+            "ai-toolbox://provider/import?payload={}",
+            unknown_tool
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn rejects_other_schemes_and_actions() {
+        assert!(parse_deep_link("https://skill/install?repo=acme/skills").is_err());
+        assert!(parse_deep_link("ai-toolbox://settings/reset").is_err());
+    }
+
+    fn url_encode(value: &str) -> String {
+        value
+            .bytes()
+            .map(|b| match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' => {
+                    (b as char).to_string()
+                }
+                _ => format!("%{:02X}", b),
+            })
+            .collect()
+    }
+}
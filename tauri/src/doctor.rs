@@ -0,0 +1,514 @@
+//! Environment Doctor Module
+//!
+//! Runs a set of read-only checks on the machine and reports what is broken:
+//! - The binaries the app shells out to (git, node/npm and the claude, codex, opencode CLIs)
+//! - Whether the managed tools are detected, using the same adapters as Skills
+//! - Whether the tools' config files still parse
+//! - Whether the configured proxy is reachable
+//!
+//! Every check that does not pass carries a suggested fix.
+
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::process::Command;
+
+use crate::coding::claude_code::plugin_cli::resolve_claude_binary_path;
+use crate::coding::open_code::resolve_opencode_binary_path;
+use crate::coding::runtime_location;
+use crate::coding::skills::git_fetcher::resolve_git_bin;
+use crate::coding::skills::tool_adapters::{
+    adapter_by_key, is_tool_installed_async, RuntimeToolAdapter,
+};
+use crate::db::DbState;
+use crate::http_client::{self, ProxyMode, ProxyRoute};
+
+const VERSION_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DoctorStatus {
+    Ok,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DoctorCategory {
+    Binary,
+    Tool,
+    Config,
+    Network,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorCheck {
+    /// Stable identifier such as `binary.git` or `config.codex`
+    pub id: String,
+    pub category: DoctorCategory,
+    pub status: DoctorStatus,
+    pub message: String,
+    /// What the user can do about a warning or error
+    pub fix: Option<String>,
+}
+
+impl DoctorCheck {
+    fn ok(id: &str, category: DoctorCategory, message: String) -> Self {
+        Self {
+            id: id.to_string(),
+            category,
+            status: DoctorStatus::Ok,
+            message,
+            fix: None,
+        }
+    }
+
+    fn problem(
+        id: &str,
+        category: DoctorCategory,
+        status: DoctorStatus,
+        message: String,
+        fix: &str,
+    ) -> Self {
+        Self {
+            id: id.to_string(),
+            category,
+            status,
+            message,
+            fix: Some(fix.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorReport {
+    pub checks: Vec<DoctorCheck>,
+    pub warnings: usize,
+    pub errors: usize,
+    pub generated_at: String,
+}
+
+impl DoctorReport {
+    fn new(checks: Vec<DoctorCheck>) -> Self {
+        let count = |status| checks.iter().filter(|c| c.status == status).count();
+        Self {
+            warnings: count(DoctorStatus::Warning),
+            errors: count(DoctorStatus::Error),
+            generated_at: chrono::Local::now().to_rfc3339(),
+            checks,
+        }
+    }
+}
+
+/// Name of a PATH-resolved Node.js shim; npm and friends are batch files on Windows
+fn node_shim(name: &str) -> String {
+    if cfg!(target_os = "windows") {
+        format!("{}.cmd", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// First line of `<binary> --version`, or why it could not be run
+async fn binary_version(binary: &Path) -> Result<String, String> {
+    let mut command = Command::new(binary);
+    command
+        .arg("--version")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    #[cfg(target_os = "windows")]
+    {
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = tokio::time::timeout(VERSION_TIMEOUT, command.output())
+        .await
+        .map_err(|_| "Timed out".to_string())?
+        .map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr
+            .lines()
+            .next()
+            .unwrap_or("Exited with an error")
+            .to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_string())
+}
+
+async fn check_binary(
+    id: &str,
+    label: &str,
+    binary: PathBuf,
+    missing_status: DoctorStatus,
+    fix: &str,
+) -> DoctorCheck {
+    match binary_version(&binary).await {
+        Ok(version) => DoctorCheck::ok(
+            id,
+            DoctorCategory::Binary,
+            format!("{}: {} ({})", label, version, binary.display()),
+        ),
+        Err(e) => DoctorCheck::problem(
+            id,
+            DoctorCategory::Binary,
+            missing_status,
+            format!("{} is not usable: {}", label, e),
+            fix,
+        ),
+    }
+}
+
+async fn binary_checks() -> Vec<DoctorCheck> {
+    let mut checks = Vec::new();
+
+    // The first lookup runs `git --version` synchronously
+    let git_bin = tokio::task::spawn_blocking(resolve_git_bin)
+        .await
+        .ok()
+        .flatten();
+    checks.push(match git_bin {
+        Some(git) => check_binary(
+            "binary.git",
+            "Git",
+            PathBuf::from(git),
+            DoctorStatus::Error,
+            "Reinstall Git or set SKILLS_GIT_BIN to a working git binary",
+        )
+        .await,
+        None => DoctorCheck::problem(
+            "binary.git",
+            DoctorCategory::Binary,
+            DoctorStatus::Error,
+            "Git was not found".to_string(),
+            "Install Git (https://git-scm.com) or set SKILLS_GIT_BIN; installing skills from repositories needs it",
+        ),
+    });
+    checks.push(
+        check_binary(
+            "binary.node",
+            "Node.js",
+            PathBuf::from("node"),
+            DoctorStatus::Warning,
+            "Install Node.js (https://nodejs.org); npm-based tools and MCP servers need it",
+        )
+        .await,
+    );
+    checks.push(
+        check_binary(
+            "binary.npm",
+            "npm",
+            PathBuf::from(node_shim("npm")),
+            DoctorStatus::Warning,
+            "npm ships with Node.js; reinstall Node.js or add npm to PATH",
+        )
+        .await,
+    );
+    checks.push(
+        check_binary(
+            "binary.claude",
+            "Claude Code",
+            resolve_claude_binary_path(),
+            DoctorStatus::Warning,
+            "Install with `npm install -g @anthropic-ai/claude-code`",
+        )
+        .await,
+    );
+    checks.push(
+        check_binary(
+            "binary.codex",
+            "Codex",
+            PathBuf::from(node_shim("codex")),
+            DoctorStatus::Warning,
+            "Install with `npm install -g @openai/codex`",
+        )
+        .await,
+    );
+    checks.push(
+        check_binary(
+            "binary.opencode",
+            "OpenCode",
+            resolve_opencode_binary_path(),
+            DoctorStatus::Warning,
+            "Install with `npm install -g opencode-ai` or the script from https://opencode.ai",
+        )
+        .await,
+    );
+
+    checks
+}
+
+/// Whether the Skills adapters detect each managed tool
+async fn tool_checks(state: &DbState) -> Vec<DoctorCheck> {
+    // Detection honours custom tool paths stored in the database
+    crate::coding::skills::tool_adapters::set_runtime_db(state.db());
+    let mut checks = Vec::new();
+    for key in ["claude_code", "codex", "opencode"] {
+        let id = format!("tool.{}", key);
+        let Some(adapter) = adapter_by_key(key) else {
+            continue;
+        };
+        let runtime_adapter = RuntimeToolAdapter::from(&adapter);
+        let check = match is_tool_installed_async(&runtime_adapter).await {
+            Ok(true) => DoctorCheck::ok(
+                &id,
+                DoctorCategory::Tool,
+                format!("{} detected", adapter.display_name),
+            ),
+            Ok(false) => DoctorCheck::problem(
+                &id,
+                DoctorCategory::Tool,
+                DoctorStatus::Warning,
+                format!("{} was not detected", adapter.display_name),
+                "Run the tool once so it creates its config directory, or set a custom path in its settings",
+            ),
+            Err(e) => DoctorCheck::problem(
+                &id,
+                DoctorCategory::Tool,
+                DoctorStatus::Error,
+                format!("Failed to detect {}: {}", adapter.display_name, e),
+                "Check the tool's path settings",
+            ),
+        };
+        checks.push(check);
+    }
+    checks
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ConfigFormat {
+    Json,
+    Json5,
+    Toml,
+}
+
+fn parse_config(content: &str, format: ConfigFormat) -> Result<(), String> {
+    match format {
+        ConfigFormat::Json => serde_json::from_str::<serde_json::Value>(content)
+            .map(|_| ())
+            .map_err(|e| e.to_string()),
+        ConfigFormat::Json5 => json5::from_str::<serde_json::Value>(content)
+            .map(|_| ())
+            .map_err(|e| e.to_string()),
+        ConfigFormat::Toml => toml::from_str::<toml::Value>(content)
+            .map(|_| ())
+            .map_err(|e| e.to_string()),
+    }
+}
+
+fn check_config(
+    id: &str,
+    label: &str,
+    path: Result<PathBuf, String>,
+    format: ConfigFormat,
+) -> DoctorCheck {
+    let path = match path {
+        Ok(path) => path,
+        Err(e) => {
+            return DoctorCheck::problem(
+                id,
+                DoctorCategory::Config,
+                DoctorStatus::Error,
+                format!("Failed to resolve the {} path: {}", label, e),
+                "Check the tool's path settings",
+            )
+        }
+    };
+    if !path.exists() {
+        return DoctorCheck::ok(
+            id,
+            DoctorCategory::Config,
+            format!("{} not created yet ({})", label, path.display()),
+        );
+    }
+
+    let result = std::fs::read_to_string(&path)
+        .map_err(|e| format!("cannot be read: {}", e))
+        .and_then(|content| {
+            parse_config(&content, format).map_err(|e| format!("is invalid: {}", e))
+        });
+    match result {
+        Ok(()) => DoctorCheck::ok(
+            id,
+            DoctorCategory::Config,
+            format!("{} is valid ({})", label, path.display()),
+        ),
+        Err(e) => DoctorCheck::problem(
+            id,
+            DoctorCategory::Config,
+            DoctorStatus::Error,
+            format!("{} {} ({})", label, e, path.display()),
+            "Fix the file by hand or restore it from a backup; the app cannot apply settings to it until then",
+        ),
+    }
+}
+
+async fn config_checks(state: &tauri::State<'_, DbState>) -> Vec<DoctorCheck> {
+    let db = state.db();
+    let opencode_path = crate::coding::open_code::get_opencode_config_path(state.clone())
+        .await
+        .map(PathBuf::from);
+
+    vec![
+        check_config(
+            "config.claude_code",
+            "Claude Code settings.json",
+            runtime_location::get_claude_settings_path_async(&db).await,
+            ConfigFormat::Json,
+        ),
+        check_config(
+            "config.codex",
+            "Codex config.toml",
+            runtime_location::get_codex_config_path_async(&db).await,
+            ConfigFormat::Toml,
+        ),
+        check_config(
+            "config.opencode",
+            "OpenCode config",
+            opencode_path,
+            ConfigFormat::Json5,
+        ),
+    ]
+}
+
+/// Every distinct proxy URL requests may go through: the custom proxy and rule targets
+fn proxy_urls(settings: &http_client::ProxySettings) -> Vec<String> {
+    let mut urls = Vec::new();
+    if let ProxyRoute::Proxy(url) = settings.default_route() {
+        urls.push(url);
+    }
+    if settings.uses_rules()
+        && settings.rules.iter().any(|rule| rule.action == "proxy")
+        && !urls.contains(&settings.url)
+    {
+        urls.push(settings.url.clone());
+    }
+    urls
+}
+
+async fn proxy_checks(state: &DbState) -> Vec<DoctorCheck> {
+    let settings = match http_client::get_proxy_settings(state).await {
+        Ok(settings) => settings,
+        Err(e) => {
+            return vec![DoctorCheck::problem(
+                "network.proxy",
+                DoctorCategory::Network,
+                DoctorStatus::Error,
+                format!("Failed to read proxy settings: {}", e),
+                "Open Settings and save the network settings again",
+            )]
+        }
+    };
+
+    let urls = proxy_urls(&settings);
+    if urls.is_empty() {
+        let message = match settings.mode {
+            ProxyMode::Direct => "Direct connection, no proxy configured",
+            _ => "Using the system proxy settings",
+        };
+        return vec![DoctorCheck::ok(
+            "network.proxy",
+            DoctorCategory::Network,
+            message.to_string(),
+        )];
+    }
+
+    let mut checks = Vec::new();
+    for url in urls {
+        let check = if url.trim().is_empty() {
+            DoctorCheck::problem(
+                "network.proxy",
+                DoctorCategory::Network,
+                DoctorStatus::Error,
+                "Custom proxy mode is selected but no proxy URL is set".to_string(),
+                "Enter a proxy URL in Settings or switch the proxy mode",
+            )
+        } else {
+            match http_client::test_proxy(&url).await {
+                Ok(()) => DoctorCheck::ok(
+                    "network.proxy",
+                    DoctorCategory::Network,
+                    "Proxy is reachable".to_string(),
+                ),
+                Err(e) => DoctorCheck::problem(
+                    "network.proxy",
+                    DoctorCategory::Network,
+                    DoctorStatus::Error,
+                    e,
+                    "Make sure the proxy is running and the URL, port and credentials are correct",
+                ),
+            }
+        };
+        checks.push(check);
+    }
+    checks
+}
+
+/// Check the local environment and return every result with suggested fixes
+#[tauri::command]
+pub async fn run_doctor(state: tauri::State<'_, DbState>) -> Result<DoctorReport, String> {
+    let mut checks = binary_checks().await;
+    checks.extend(tool_checks(&state).await);
+    checks.extend(config_checks(&state).await);
+    checks.extend(proxy_checks(&state).await);
+
+    let report = DoctorReport::new(checks);
+    log::info!(
+        "Doctor finished: {} checks, {} warnings, {} errors",
+        report.checks.len(),
+        report.warnings,
+        report.errors
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_config_formats() {
+        assert!(parse_config("{\"a\": 1}", ConfigFormat::Json).is_ok());
+        assert!(parse_config("{a: 1, // note\n}", ConfigFormat::Json5).is_ok());
+        assert!(parse_config("{a: 1}", ConfigFormat::Json).is_err());
+        assert!(parse_config(
+            "model = \"o3\"\n[mcp_servers.x]\ncommand = \"y\"",
+            ConfigFormat::Toml
+        )
+        .is_ok());
+        assert!(parse_config("model = ", ConfigFormat::Toml).is_err());
+    }
+
+    #[test]
+    fn counts_problems_in_report() {
+        let report = DoctorReport::new(vec![
+            DoctorCheck::ok("a", DoctorCategory::Binary, String::new()),
+            DoctorCheck::problem(
+                "b",
+                DoctorCategory::Tool,
+                DoctorStatus::Warning,
+                String::new(),
+                "x",
+            ),
+            DoctorCheck::problem(
+                "c",
+                DoctorCategory::Config,
+                DoctorStatus::Error,
+                String::new(),
+                "y",
+            ),
+        ]);
+        assert_eq!((report.warnings, report.errors), (1, 1));
+    }
+}
@@ -0,0 +1,550 @@
+//! Local Gateway Module
+//!
+//! An opt-in HTTP server on `127.0.0.1:<gateway_port>` that lets any OpenAI-compatible
+//! tool use the OpenCode favorite providers through one base URL (`http://127.0.0.1:<port>/v1`):
+//! - `GET /v1/models` lists every routable model as `provider_id/model_id`
+//! - `POST /v1/chat/completions` forwards the request to the provider owning the model,
+//!   with its base URL, API key and headers applied; streamed responses are passed through
+//!
+//! Routing: `provider_id/model_id` picks one provider, a bare model id tries every
+//! favorite provider that lists it. The `gateway_fallback_models` are tried next when a
+//! target fails before answering (connection error, 408, 429 or 5xx).
+//! Only providers speaking the chat completions format are routed: `@ai-sdk/openai-compatible`,
+//! `@ai-sdk/openai` and providers without an SDK set.
+//!
+//! Browser requests (with an `Origin` header) are rejected, and a non-empty
+//! `gateway_api_key` must be sent as `Authorization: Bearer <key>`. The key is not
+//! encrypted by the app lock, so clients keep working while the app is locked. Settings
+//! and providers are read per request, so edits apply without a restart.
+
+use std::convert::Infallible;
+use std::sync::{Mutex, OnceLock};
+
+use bytes::Bytes;
+use futures_util::TryStreamExt;
+use http_body_util::combinators::UnsyncBoxBody;
+use http_body_util::{BodyExt, Full, Limited, StreamBody};
+use hyper::body::{Frame, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{header, Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use regex::Regex;
+use serde::Serialize;
+use serde_json::{json, Value};
+use tauri::{AppHandle, Manager, Runtime};
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+
+use crate::coding::open_code::{
+    list_opencode_favorite_providers, resolve_auth_credential, resolve_provider_api_base_url,
+    OpenCodeFavoriteProvider,
+};
+use crate::settings::AppSettings;
+use crate::{http_client, DbState};
+
+pub const DEFAULT_PORT: u32 = 11435;
+/// Generations can stream for minutes
+const UPSTREAM_TIMEOUT_SECS: u64 = 600;
+const MAX_REQUEST_BYTES: usize = 32 * 1024 * 1024;
+/// SDK packages whose providers accept chat completions requests ("" = not set)
+const CHAT_COMPLETIONS_SDKS: &[&str] = &["", "@ai-sdk/openai-compatible", "@ai-sdk/openai"];
+
+type GatewayBody = UnsyncBoxBody<Bytes, std::io::Error>;
+
+struct RunningGateway {
+    port: u16,
+    shutdown: oneshot::Sender<()>,
+}
+
+struct GatewayState {
+    running: Option<RunningGateway>,
+    /// Why the last start failed
+    error: Option<String>,
+}
+
+static GATEWAY: Mutex<GatewayState> = Mutex::new(GatewayState {
+    running: None,
+    error: None,
+});
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GatewayStatus {
+    pub running: bool,
+    /// Base URL to configure in clients
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// A provider and model a request can be sent to
+#[derive(Debug, Clone, PartialEq)]
+struct RouteTarget {
+    provider_id: String,
+    model_id: String,
+}
+
+/// Start the gateway at startup if it is enabled
+pub async fn init<R: Runtime>(app: &AppHandle<R>) {
+    let state = app.state::<DbState>();
+    if let Ok(settings) = crate::settings::get_settings(state).await {
+        apply_settings(app, &settings).await;
+    }
+}
+
+/// Start, stop or move the gateway to match the settings
+pub async fn apply_settings<R: Runtime>(app: &AppHandle<R>, settings: &AppSettings) {
+    let port = u16::try_from(settings.gateway_port).unwrap_or(0);
+    let wanted = (settings.gateway_enabled && port != 0).then_some(port);
+    {
+        let mut gateway = GATEWAY.lock().unwrap();
+        if gateway.running.as_ref().map(|running| running.port) == wanted {
+            if wanted.is_none() {
+                gateway.error = None;
+            }
+            return;
+        }
+        if let Some(running) = gateway.running.take() {
+            let _ = running.shutdown.send(());
+        }
+        gateway.error = None;
+    }
+    let Some(port) = wanted else {
+        return;
+    };
+
+    let listener = match TcpListener::bind(("127.0.0.1", port)).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            log::warn!("本地网关启动失败 (端口 {}): {}", port, e);
+            GATEWAY.lock().unwrap().error =
+                Some(format!("Failed to listen on port {}: {}", port, e));
+            return;
+        }
+    };
+
+    let (shutdown, mut shutdown_rx) = oneshot::channel();
+    GATEWAY.lock().unwrap().running = Some(RunningGateway { port, shutdown });
+    log::info!("本地网关已启动: http://127.0.0.1:{}/v1", port);
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = &mut shutdown_rx => break,
+                accepted = listener.accept() => {
+                    let stream = match accepted {
+                        Ok((stream, _)) => stream,
+                        Err(e) => {
+                            log::warn!("本地网关接受连接失败: {}", e);
+                            continue;
+                        }
+                    };
+                    let app = app.clone();
+                    tauri::async_runtime::spawn(async move {
+                        let service = service_fn(move |request| {
+                            let app = app.clone();
+                            async move { Ok::<_, Infallible>(handle_request(&app, request).await) }
+                        });
+                        if let Err(e) = http1::Builder::new()
+                            .serve_connection(TokioIo::new(stream), service)
+                            .await
+                        {
+                            log::debug!("本地网关连接中断: {}", e);
+                        }
+                    });
+                }
+            }
+        }
+        log::info!("本地网关已停止 (端口 {})", port);
+    });
+}
+
+/// Whether the gateway is running and the URL clients should use
+#[tauri::command]
+pub fn get_gateway_status() -> GatewayStatus {
+    let gateway = GATEWAY.lock().unwrap();
+    GatewayStatus {
+        running: gateway.running.is_some(),
+        base_url: gateway
+            .running
+            .as_ref()
+            .map(|running| format!("http://127.0.0.1:{}/v1", running.port)),
+        error: gateway.error.clone(),
+    }
+}
+
+/// Compare API keys in constant time, so a key can't be guessed byte by byte from how
+/// long a rejection takes
+fn keys_match(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+async fn handle_request<R: Runtime>(
+    app: &AppHandle<R>,
+    request: Request<Incoming>,
+) -> Response<GatewayBody> {
+    if request.headers().contains_key(header::ORIGIN) {
+        return error_response(StatusCode::FORBIDDEN, "Browser requests are not allowed");
+    }
+
+    let state = app.state::<DbState>();
+    let settings = crate::settings::get_settings(state.clone())
+        .await
+        .unwrap_or_default();
+    if !settings.gateway_api_key.is_empty() {
+        let bearer = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        if !bearer.is_some_and(|bearer| keys_match(bearer, &settings.gateway_api_key)) {
+            return error_response(StatusCode::UNAUTHORIZED, "Invalid gateway API key");
+        }
+    }
+
+    let providers = match list_opencode_favorite_providers(state.clone()).await {
+        Ok(providers) => providers
+            .into_iter()
+            .filter(|provider| CHAT_COMPLETIONS_SDKS.contains(&provider.npm.as_str()))
+            .collect::<Vec<_>>(),
+        Err(e) => return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e),
+    };
+
+    let path = request.uri().path().trim_end_matches('/').to_string();
+    match (request.method(), path.as_str()) {
+        (&Method::GET, "/v1/models") => json_response(StatusCode::OK, &list_models(&providers)),
+        (&Method::POST, "/v1/chat/completions") => {
+            let body = match Limited::new(request.into_body(), MAX_REQUEST_BYTES)
+                .collect()
+                .await
+            {
+                Ok(body) => body.to_bytes(),
+                Err(e) => return error_response(StatusCode::BAD_REQUEST, &e.to_string()),
+            };
+            let client = match http_client::client_with_timeout(&state, UPSTREAM_TIMEOUT_SECS).await
+            {
+                Ok(client) => client,
+                Err(e) => return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e),
+            };
+            chat_completions(
+                &client,
+                &providers,
+                &settings.gateway_fallback_models,
+                &body,
+            )
+            .await
+        }
+        _ => error_response(StatusCode::NOT_FOUND, "Not found"),
+    }
+}
+
+fn list_models(providers: &[OpenCodeFavoriteProvider]) -> Value {
+    let data: Vec<Value> = providers
+        .iter()
+        .flat_map(|provider| {
+            provider.provider_config.models.keys().map(move |model_id| {
+                json!({
+                    "id": format!("{}/{}", provider.provider_id, model_id),
+                    "object": "model",
+                    "owned_by": provider.provider_id,
+                })
+            })
+        })
+        .collect();
+    json!({ "object": "list", "data": data })
+}
+
+async fn chat_completions(
+    client: &reqwest::Client,
+    providers: &[OpenCodeFavoriteProvider],
+    fallbacks: &[String],
+    body: &[u8],
+) -> Response<GatewayBody> {
+    let mut payload: Value = match serde_json::from_slice(body) {
+        Ok(Value::Object(payload)) => Value::Object(payload),
+        _ => {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                "Request body must be a JSON object",
+            )
+        }
+    };
+    let Some(model) = payload.get("model").and_then(Value::as_str) else {
+        return error_response(StatusCode::BAD_REQUEST, "Missing model");
+    };
+    let targets = resolve_targets(providers, model, fallbacks);
+    if targets.is_empty() {
+        return error_response(
+            StatusCode::NOT_FOUND,
+            &format!("No favorite provider offers model '{}'", model),
+        );
+    }
+    let stream = payload.get("stream").and_then(Value::as_bool) == Some(true);
+
+    let mut last_error = String::new();
+    for (index, target) in targets.iter().enumerate() {
+        let Some(provider) = providers
+            .iter()
+            .find(|provider| provider.provider_id == target.provider_id)
+        else {
+            continue;
+        };
+        payload["model"] = json!(target.model_id);
+
+        let is_last = index + 1 == targets.len();
+        match send_upstream(client, provider, &payload, stream).await {
+            Ok(response) if is_last || !is_retryable(response.status()) => {
+                return forward_response(response);
+            }
+            Ok(response) => {
+                last_error = format!("{}: HTTP {}", provider.provider_id, response.status());
+            }
+            Err(e) => last_error = format!("{}: {}", provider.provider_id, e),
+        }
+        log::warn!("本地网关请求失败，尝试下一个目标: {}", last_error);
+    }
+
+    error_response(StatusCode::BAD_GATEWAY, &last_error)
+}
+
+/// Targets for a requested model followed by the fallbacks, without duplicates
+fn resolve_targets(
+    providers: &[OpenCodeFavoriteProvider],
+    model: &str,
+    fallbacks: &[String],
+) -> Vec<RouteTarget> {
+    let mut targets: Vec<RouteTarget> = Vec::new();
+    for model in std::iter::once(model).chain(fallbacks.iter().map(String::as_str)) {
+        for target in targets_for_model(providers, model.trim()) {
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+    }
+    targets
+}
+
+fn targets_for_model(providers: &[OpenCodeFavoriteProvider], model: &str) -> Vec<RouteTarget> {
+    // Model ids may contain '/', so only the first segment can name a provider
+    if let Some((provider_id, model_id)) = model.split_once('/') {
+        if providers
+            .iter()
+            .any(|provider| provider.provider_id == provider_id)
+        {
+            return vec![RouteTarget {
+                provider_id: provider_id.to_string(),
+                model_id: model_id.to_string(),
+            }];
+        }
+    }
+
+    providers
+        .iter()
+        .filter(|provider| provider.provider_config.models.contains_key(model))
+        .map(|provider| RouteTarget {
+            provider_id: provider.provider_id.clone(),
+            model_id: model.to_string(),
+        })
+        .collect()
+}
+
+/// Base URL, API key and extra headers for calling a favorite provider
+pub(crate) struct ProviderEndpoint {
+    pub base_url: String,
+    pub api_key: Option<String>,
+    /// Headers from the provider options, with references resolved
+    pub headers: Vec<(String, String)>,
+}
+
+/// Resolve where and how to call a provider, from its config or the OpenCode auth file
+pub(crate) fn provider_endpoint(
+    provider: &OpenCodeFavoriteProvider,
+) -> Result<ProviderEndpoint, String> {
+    let options = provider.provider_config.options.as_ref();
+    let base_url = Some(provider.base_url.trim())
+        .filter(|url| !url.is_empty())
+        .map(str::to_string)
+        .or_else(|| options.and_then(|options| options.base_url.clone()))
+        .or_else(|| resolve_provider_api_base_url(&provider.provider_id))
+        .ok_or_else(|| "Missing base URL".to_string())?;
+    let api_key = match options
+        .and_then(|options| options.api_key.as_deref())
+        .filter(|key| !key.trim().is_empty())
+    {
+        Some(key) => Some(resolve_reference(key)?),
+        None => resolve_auth_credential(&provider.provider_id),
+    };
+
+    let mut headers = Vec::new();
+    if let Some(Value::Object(options_headers)) =
+        options.and_then(|options| options.headers.as_ref())
+    {
+        for (name, value) in options_headers {
+            if let Some(value) = value.as_str() {
+                headers.push((name.clone(), resolve_reference(value)?));
+            }
+        }
+    }
+
+    Ok(ProviderEndpoint {
+        base_url: base_url.trim_end_matches('/').to_string(),
+        api_key: api_key.filter(|key| !key.is_empty()),
+        headers,
+    })
+}
+
+async fn send_upstream(
+    client: &reqwest::Client,
+    provider: &OpenCodeFavoriteProvider,
+    payload: &Value,
+    stream: bool,
+) -> Result<reqwest::Response, String> {
+    let endpoint = provider_endpoint(provider)?;
+    let mut request = client
+        .post(format!("{}/chat/completions", endpoint.base_url))
+        .json(payload);
+    if let Some(api_key) = endpoint.api_key {
+        request = request.bearer_auth(api_key);
+    }
+    if stream {
+        request = request.header(header::ACCEPT, "text/event-stream");
+    }
+    for (name, value) in endpoint.headers {
+        request = request.header(name, value);
+    }
+
+    request.send().await.map_err(|e| e.to_string())
+}
+
+/// Resolve `{{secret:NAME}}` and OpenCode's `{env:NAME}` references in a config value
+fn resolve_reference(value: &str) -> Result<String, String> {
+    static ENV_RE: OnceLock<Regex> = OnceLock::new();
+    let env_re = ENV_RE.get_or_init(|| Regex::new(r"\{env:([^}]+)\}").unwrap());
+    let value = crate::secrets::resolve_text(value)?;
+    Ok(env_re
+        .replace_all(&value, |caps: &regex::Captures| {
+            std::env::var(caps[1].trim()).unwrap_or_default()
+        })
+        .into_owned())
+}
+
+fn is_retryable(status: reqwest::StatusCode) -> bool {
+    status.is_server_error()
+        || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        || status == reqwest::StatusCode::REQUEST_TIMEOUT
+}
+
+fn forward_response(upstream: reqwest::Response) -> Response<GatewayBody> {
+    let mut builder = Response::builder().status(upstream.status());
+    for name in [header::CONTENT_TYPE, header::CACHE_CONTROL] {
+        if let Some(value) = upstream.headers().get(&name) {
+            builder = builder.header(name, value.clone());
+        }
+    }
+    let body = StreamBody::new(
+        upstream
+            .bytes_stream()
+            .map_ok(Frame::data)
+            .map_err(std::io::Error::other),
+    );
+    builder
+        .body(BodyExt::boxed_unsync(body))
+        .unwrap_or_else(|e| error_response(StatusCode::BAD_GATEWAY, &e.to_string()))
+}
+
+fn json_response(status: StatusCode, value: &Value) -> Response<GatewayBody> {
+    let body = Full::new(Bytes::from(value.to_string()))
+        .map_err(|never| match never {})
+        .boxed_unsync();
+    let mut response = Response::new(body);
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static("application/json"),
+    );
+    response
+}
+
+/// Error in the OpenAI response format
+fn error_response(status: StatusCode, message: &str) -> Response<GatewayBody> {
+    json_response(
+        status,
+        &json!({ "error": { "message": message, "type": "gateway_error" } }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn provider(provider_id: &str, models: &[&str]) -> OpenCodeFavoriteProvider {
+        let models: serde_json::Map<String, Value> = models
+            .iter()
+            .map(|model| (model.to_string(), json!({})))
+            .collect();
+        OpenCodeFavoriteProvider {
+            id: provider_id.to_string(),
+            provider_id: provider_id.to_string(),
+            npm: "@ai-sdk/openai-compatible".to_string(),
+            base_url: String::new(),
+            provider_config: serde_json::from_value(json!({ "models": models })).unwrap(),
+            diagnostics: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn target(provider_id: &str, model_id: &str) -> RouteTarget {
+        RouteTarget {
+            provider_id: provider_id.to_string(),
+            model_id: model_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn routes_provider_prefixed_and_bare_model_ids() {
+        let providers = vec![
+            provider("a", &["gpt-5", "org/model"]),
+            provider("b", &["gpt-5"]),
+        ];
+        assert_eq!(
+            resolve_targets(&providers, "b/gpt-5", &[]),
+            vec![target("b", "gpt-5")]
+        );
+        assert_eq!(
+            resolve_targets(&providers, "gpt-5", &[]),
+            vec![target("a", "gpt-5"), target("b", "gpt-5")]
+        );
+        assert_eq!(
+            resolve_targets(&providers, "org/model", &[]),
+            vec![target("a", "org/model")]
+        );
+    }
+
+    #[test]
+    fn appends_fallbacks_without_duplicates() {
+        let providers = vec![provider("a", &["x"]), provider("b", &["y"])];
+        let fallbacks = vec!["b/y".to_string(), "a/x".to_string()];
+        assert_eq!(
+            resolve_targets(&providers, "a/x", &fallbacks),
+            vec![target("a", "x"), target("b", "y")]
+        );
+        assert!(resolve_targets(&providers, "missing", &[]).is_empty());
+    }
+
+    #[test]
+    fn matches_only_identical_keys() {
+        assert!(keys_match("sk-local", "sk-local"));
+        assert!(!keys_match("sk-locaL", "sk-local"));
+        assert!(!keys_match("sk-local-2", "sk-local"));
+        assert!(!keys_match("", "sk-local"));
+    }
+}
@@ -0,0 +1,316 @@
+//! Global Hotkeys Module
+//!
+//! Registers the global shortcuts configured in settings (`hotkeys`) and runs their actions:
+//! - Cycle the applied Claude Code provider
+//! - Cycle the OpenCode main / small model
+//! - Open the quick-switch palette window
+//!
+//! Two bindings with the same accelerator are rejected before saving; accelerators already
+//! taken by another application are reported per binding when registering.
+
+use crate::coding::claude_code::tray_support as claude_tray;
+use crate::coding::open_code::tray_support as opencode_tray;
+use crate::db::DbState;
+use crate::db_timestamp::with_datetimes;
+use crate::quick_switch;
+use crate::settings::{self, adapter, HotkeyBinding};
+use crate::tray;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutEvent, ShortcutState};
+
+pub const ACTION_CLAUDE_NEXT_PROVIDER: &str = "claude_code_next_provider";
+pub const ACTION_OPENCODE_NEXT_MAIN_MODEL: &str = "opencode_next_main_model";
+pub const ACTION_OPENCODE_NEXT_SMALL_MODEL: &str = "opencode_next_small_model";
+pub const ACTION_QUICK_SWITCH: &str = "quick_switch";
+
+/// All actions a hotkey can be bound to
+pub const HOTKEY_ACTIONS: [&str; 4] = [
+    ACTION_CLAUDE_NEXT_PROVIDER,
+    ACTION_OPENCODE_NEXT_MAIN_MODEL,
+    ACTION_OPENCODE_NEXT_SMALL_MODEL,
+    ACTION_QUICK_SWITCH,
+];
+
+/// Registered shortcut id -> action
+static REGISTERED: Mutex<Option<HashMap<u32, String>>> = Mutex::new(None);
+/// Result of the latest registration, shown in settings
+static LAST_STATUS: Mutex<Vec<HotkeyStatus>> = Mutex::new(Vec::new());
+
+/// Registration result of one binding
+#[derive(Debug, Clone, Serialize)]
+pub struct HotkeyStatus {
+    pub action: String,
+    pub shortcut: String,
+    pub registered: bool,
+    pub error: Option<String>,
+}
+
+/// Parse the enabled bindings, flagging unknown actions, invalid accelerators and
+/// accelerators bound twice
+fn check_bindings(bindings: &[HotkeyBinding]) -> Vec<(HotkeyStatus, Option<Shortcut>)> {
+    let mut seen: HashMap<u32, &str> = HashMap::new();
+    bindings
+        .iter()
+        .map(|binding| {
+            let mut status = HotkeyStatus {
+                action: binding.action.clone(),
+                shortcut: binding.shortcut.trim().to_string(),
+                registered: false,
+                error: None,
+            };
+            if !binding.enabled || status.shortcut.is_empty() {
+                return (status, None);
+            }
+            if !HOTKEY_ACTIONS.contains(&binding.action.as_str()) {
+                status.error = Some(format!("Unknown hotkey action: {}", binding.action));
+                return (status, None);
+            }
+            let shortcut = match status.shortcut.parse::<Shortcut>() {
+                Ok(shortcut) => shortcut,
+                Err(e) => {
+                    status.error = Some(format!("Invalid shortcut '{}': {}", status.shortcut, e));
+                    return (status, None);
+                }
+            };
+            if let Some(other) = seen.get(&shortcut.id()) {
+                status.error = Some(format!(
+                    "Shortcut '{}' is already bound to {}",
+                    status.shortcut, other
+                ));
+                return (status, None);
+            }
+            seen.insert(shortcut.id(), &binding.action);
+            (status, Some(shortcut))
+        })
+        .collect()
+}
+
+/// Conflicts between the given bindings, as error messages
+pub fn validate_bindings(bindings: &[HotkeyBinding]) -> Vec<String> {
+    check_bindings(bindings)
+        .into_iter()
+        .filter_map(|(status, _)| status.error)
+        .collect()
+}
+
+/// Replace the registered global shortcuts with the given bindings
+pub fn register_hotkeys<R: Runtime>(
+    app: &AppHandle<R>,
+    bindings: &[HotkeyBinding],
+) -> Vec<HotkeyStatus> {
+    let global_shortcut = app.global_shortcut();
+    if let Err(e) = global_shortcut.unregister_all() {
+        log::warn!("Failed to unregister global shortcuts: {}", e);
+    }
+
+    let mut registered = HashMap::new();
+    let mut statuses = Vec::new();
+    for (mut status, shortcut) in check_bindings(bindings) {
+        if let Some(shortcut) = shortcut {
+            match global_shortcut.register(shortcut) {
+                Ok(()) => {
+                    status.registered = true;
+                    registered.insert(shortcut.id(), status.action.clone());
+                }
+                Err(e) => {
+                    status.error = Some(format!(
+                        "Failed to register '{}', it may be used by another application: {}",
+                        status.shortcut, e
+                    ));
+                }
+            }
+        }
+        if let Some(error) = &status.error {
+            log::warn!("Hotkey {}: {}", status.action, error);
+        }
+        statuses.push(status);
+    }
+
+    if let Ok(mut guard) = REGISTERED.lock() {
+        *guard = Some(registered);
+    }
+    if let Ok(mut guard) = LAST_STATUS.lock() {
+        *guard = statuses.clone();
+    }
+    statuses
+}
+
+/// Register the hotkeys stored in settings (called on startup)
+pub async fn register_saved_hotkeys<R: Runtime>(app: &AppHandle<R>) {
+    let state = app.state::<DbState>();
+    let settings = match settings::get_settings(state).await {
+        Ok(settings) => settings,
+        Err(e) => {
+            log::warn!("Failed to load hotkeys from settings: {}", e);
+            return;
+        }
+    };
+    register_hotkeys(app, &settings.hotkeys);
+}
+
+/// Global shortcut handler passed to the plugin builder
+pub fn handle_shortcut<R: Runtime>(app: &AppHandle<R>, shortcut: &Shortcut, event: ShortcutEvent) {
+    if event.state() != ShortcutState::Pressed {
+        return;
+    }
+    let action = REGISTERED
+        .lock()
+        .ok()
+        .and_then(|guard| guard.as_ref()?.get(&shortcut.id()).cloned());
+    let Some(action) = action else {
+        return;
+    };
+
+    if action == ACTION_QUICK_SWITCH {
+        if let Err(e) = quick_switch::open_quick_switch(app) {
+            log::warn!("Failed to open quick switch: {}", e);
+        }
+        return;
+    }
+
+    let app_handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let result = match action.as_str() {
+            ACTION_CLAUDE_NEXT_PROVIDER => cycle_claude_provider(&app_handle).await,
+            ACTION_OPENCODE_NEXT_MAIN_MODEL => cycle_opencode_model(&app_handle, "main").await,
+            ACTION_OPENCODE_NEXT_SMALL_MODEL => cycle_opencode_model(&app_handle, "small").await,
+            _ => Ok(()),
+        };
+        if let Err(e) = result {
+            log::warn!("Hotkey action {} failed: {}", action, e);
+        }
+        let _ = tray::refresh_tray_menus(&app_handle).await;
+    });
+}
+
+/// Index of the item after the selected one (the first item when none is selected)
+fn next_index(selected: Option<usize>, len: usize) -> usize {
+    selected.map_or(0, |index| (index + 1) % len)
+}
+
+async fn cycle_claude_provider<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let data = claude_tray::get_claude_code_tray_data(app).await?;
+    let items: Vec<_> = data.items.iter().filter(|item| !item.is_disabled).collect();
+    if items.is_empty() {
+        return Ok(());
+    }
+    let selected = items.iter().position(|item| item.is_selected);
+    let next = items[next_index(selected, items.len())];
+    if next.is_selected {
+        return Ok(());
+    }
+    claude_tray::apply_claude_code_provider(app, &next.id).await
+}
+
+async fn cycle_opencode_model<R: Runtime>(
+    app: &AppHandle<R>,
+    model_type: &str,
+) -> Result<(), String> {
+    let (main, small) = opencode_tray::get_opencode_tray_model_data(app).await?;
+    let data = if model_type == "main" { main } else { small };
+    if data.items.is_empty() {
+        return Ok(());
+    }
+    let selected = data.items.iter().position(|item| item.is_selected);
+    let next = &data.items[next_index(selected, data.items.len())];
+    if next.is_selected {
+        return Ok(());
+    }
+    opencode_tray::apply_opencode_model(app, model_type, &next.id).await
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Registration status of the saved hotkeys
+#[tauri::command]
+pub fn get_hotkey_status() -> Vec<HotkeyStatus> {
+    LAST_STATUS
+        .lock()
+        .map(|guard| guard.clone())
+        .unwrap_or_default()
+}
+
+/// Save hotkeys to settings and register them
+///
+/// Bindings that conflict with each other are rejected without saving; registration
+/// failures (e.g. a shortcut taken by another application) are returned in the status.
+#[tauri::command]
+pub async fn save_hotkeys(
+    state: tauri::State<'_, DbState>,
+    app: AppHandle,
+    hotkeys: Vec<HotkeyBinding>,
+) -> Result<Vec<HotkeyStatus>, String> {
+    let conflicts = validate_bindings(&hotkeys);
+    if !conflicts.is_empty() {
+        return Err(conflicts.join("\n"));
+    }
+
+    let mut current = settings::get_settings(state.clone()).await?;
+    current.hotkeys = hotkeys;
+
+    let db = state.db();
+    db.query("UPSERT settings:`app` CONTENT $data")
+        .bind(("data", with_datetimes(adapter::to_db_value(&current)?)))
+        .await
+        .map_err(|e| format!("Failed to save settings: {}", e))?;
+    drop(db);
+
+    Ok(register_hotkeys(&app, &current.hotkeys))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binding(action: &str, shortcut: &str) -> HotkeyBinding {
+        HotkeyBinding {
+            action: action.to_string(),
+            shortcut: shortcut.to_string(),
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn detects_duplicate_and_invalid_shortcuts() {
+        let bindings = vec![
+            binding(ACTION_CLAUDE_NEXT_PROVIDER, "Ctrl+Shift+K"),
+            binding(ACTION_QUICK_SWITCH, "Shift+Ctrl+K"),
+            binding(ACTION_OPENCODE_NEXT_MAIN_MODEL, "Ctrl+Shift+NotAKey"),
+            binding(ACTION_OPENCODE_NEXT_SMALL_MODEL, ""),
+        ];
+        let checked = check_bindings(&bindings);
+        assert!(checked[0].1.is_some());
+        assert!(checked[1]
+            .0
+            .error
+            .as_deref()
+            .unwrap()
+            .contains(ACTION_CLAUDE_NEXT_PROVIDER));
+        assert!(checked[2].0.error.is_some());
+        assert!(checked[3].0.error.is_none() && checked[3].1.is_none());
+        assert_eq!(validate_bindings(&bindings).len(), 2);
+    }
+
+    #[test]
+    fn disabled_bindings_do_not_conflict() {
+        let mut disabled = binding(ACTION_QUICK_SWITCH, "Ctrl+Shift+K");
+        disabled.enabled = false;
+        let bindings = vec![
+            binding(ACTION_CLAUDE_NEXT_PROVIDER, "Ctrl+Shift+K"),
+            disabled,
+        ];
+        assert!(validate_bindings(&bindings).is_empty());
+    }
+
+    #[test]
+    fn cycles_to_the_item_after_the_selected_one() {
+        assert_eq!(next_index(Some(0), 3), 1);
+        assert_eq!(next_index(Some(2), 3), 0);
+        assert_eq!(next_index(None, 3), 0);
+    }
+}
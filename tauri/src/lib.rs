@@ -9,9 +9,6 @@ use surrealdb::engine::local::SurrealKv;
 use surrealdb::Surreal;
 
 use log::{error, info, warn};
-use simplelog::{
-    ColorChoice, CombinedLogger, ConfigBuilder, LevelFilter, TermLogger, TerminalMode, WriteLogger,
-};
 
 #[cfg(target_os = "linux")]
 use std::sync::Arc;
@@ -19,13 +16,33 @@ use std::sync::Arc;
 use std::sync::Mutex as StdMutex;
 
 // Module declarations
+pub mod app_lock;
+pub mod audit_log;
 pub mod auto_launch;
+pub mod cli;
 pub mod coding;
 pub mod db;
+pub mod db_dump;
+pub mod db_events;
+pub mod db_integrity;
 pub mod db_migration;
+pub mod db_timestamp;
+pub mod deep_link;
+pub mod diagnostics;
+pub mod doctor;
+pub mod gateway;
+pub mod hotkeys;
 pub mod http_client;
+pub mod locale;
+pub mod logging;
+pub mod playground;
+pub mod portable;
+pub mod quick_switch;
+pub mod search;
+pub mod secrets;
 pub mod settings;
 pub mod single_instance;
+pub mod token_count;
 pub mod tray;
 pub mod update;
 
@@ -90,91 +107,6 @@ fn open_folder(path: String) -> Result<(), String> {
     Ok(())
 }
 
-/// 初始化日志系统
-/// debug 模式下日志输出到控制台，release 模式下写入文件
-fn init_logging() -> Option<std::path::PathBuf> {
-    if cfg!(debug_assertions) {
-        // 开发模式：日志输出到控制台
-        // 只输出本 crate 的 Debug 日志，第三方库只输出 Warn 以上
-        let config = ConfigBuilder::new()
-            .set_max_level(LevelFilter::Warn)
-            .add_filter_allow_str("ai_toolbox")
-            .build();
-        if TermLogger::init(
-            LevelFilter::Debug,
-            config,
-            TerminalMode::Mixed,
-            ColorChoice::Auto,
-        )
-        .is_err()
-        {
-            eprintln!("日志系统初始化失败");
-        }
-        return None;
-    }
-
-    // 正式版本：日志写入文件
-    let log_dir = dirs::data_dir()
-        .map(|p| p.join("com.ai-toolbox").join("logs"))
-        .or_else(|| dirs::home_dir().map(|p| p.join(".ai-toolbox").join("logs")));
-
-    let log_dir = match log_dir {
-        Some(dir) => dir,
-        None => return None,
-    };
-
-    if let Err(e) = fs::create_dir_all(&log_dir) {
-        eprintln!("无法创建日志目录: {}", e);
-        return None;
-    }
-
-    let date = chrono::Local::now().format("%Y%m%d");
-    let log_file = log_dir.join(format!("ai-toolbox_{}.log", date));
-
-    let file = match std::fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&log_file)
-    {
-        Ok(f) => f,
-        Err(e) => {
-            eprintln!("无法打开日志文件: {}", e);
-            return None;
-        }
-    };
-
-    let file_config = ConfigBuilder::new()
-        .set_max_level(LevelFilter::Warn)
-        .add_filter_allow_str("ai_toolbox")
-        .build();
-
-    if CombinedLogger::init(vec![WriteLogger::new(LevelFilter::Info, file_config, file)]).is_err() {
-        eprintln!("日志系统初始化失败");
-        return None;
-    }
-
-    // 清理旧日志文件（保留最近 7 天）
-    if let Ok(entries) = fs::read_dir(&log_dir) {
-        let mut log_files: Vec<_> = entries
-            .filter_map(|e| e.ok())
-            .filter(|e| {
-                e.path()
-                    .file_name()
-                    .map(|n| n.to_string_lossy().starts_with("ai-toolbox_"))
-                    .unwrap_or(false)
-            })
-            .collect();
-
-        log_files.sort_by_key(|e| std::cmp::Reverse(e.path()));
-
-        for old_log in log_files.into_iter().skip(7) {
-            let _ = fs::remove_file(old_log.path());
-        }
-    }
-
-    Some(log_file)
-}
-
 /// 设置 panic hook，将 panic 信息写入日志
 fn setup_panic_hook() {
     let default_hook = std::panic::take_hook();
@@ -196,10 +128,7 @@ fn setup_panic_hook() {
         error!("PANIC 发生: {} at {}", msg, location);
 
         // 尝试将错误写入单独的崩溃日志文件
-        if let Some(log_dir) = dirs::data_dir()
-            .map(|p| p.join("com.ai-toolbox").join("logs"))
-            .or_else(|| dirs::home_dir().map(|p| p.join(".ai-toolbox").join("logs")))
-        {
+        if let Some(log_dir) = logging::log_dir() {
             let crash_file = log_dir.join("CRASH.log");
             let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
             let crash_msg = format!("[{}] PANIC: {} at {}\n", timestamp, msg, location);
@@ -239,9 +168,7 @@ const WAYLAND_WEBVIEW_WORKAROUND_MAX_LEVEL: u8 = 4;
 
 #[cfg(target_os = "linux")]
 fn wayland_webview_workaround_level_path() -> Option<std::path::PathBuf> {
-    let base_dir = dirs::data_dir()
-        .map(|p| p.join("com.ai-toolbox"))
-        .or_else(|| dirs::home_dir().map(|p| p.join(".ai-toolbox")))?;
+    let base_dir = portable::data_dir()?;
     Some(
         base_dir
             .join("runtime")
@@ -564,10 +491,24 @@ fn setup_linux_wayland_webview_workaround() -> u8 {
     level
 }
 
+/// 命令处理包装：先检查应用锁，再记录审计日志
+fn command_handler<R, H>(
+    handler: H,
+) -> impl Fn(tauri::ipc::Invoke<R>) -> bool + Send + Sync + 'static
+where
+    R: tauri::Runtime,
+    H: Fn(tauri::ipc::Invoke<R>) -> bool + Send + Sync + 'static,
+{
+    app_lock::guarded(audit_log::audited(handler))
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // 便携模式：数据目录改为可执行文件旁的 data 目录
+    portable::init();
+
     // 初始化日志系统
-    let log_file = init_logging();
+    let log_file = logging::init_logging();
     if let Some(ref path) = log_file {
         eprintln!("日志文件: {:?}", path);
     }
@@ -580,8 +521,20 @@ pub fn run() {
     info!("版本: {}", env!("CARGO_PKG_VERSION"));
     info!("操作系统: {}", std::env::consts::OS);
     info!("架构: {}", std::env::consts::ARCH);
+    if let Some(dir) = portable::portable_data_dir() {
+        info!("便携模式: {:?}", dir);
+    }
     info!("========================================");
 
+    let context = tauri::generate_context!();
+
+    // 命令行模式：执行一个操作后直接退出，不创建窗口
+    match cli::parse_args(std::env::args().skip(1)) {
+        Ok(Some(command)) => std::process::exit(cli::run(command, context)),
+        Ok(None) => {}
+        Err(e) => std::process::exit(cli::report_error(&e)),
+    }
+
     #[cfg(target_os = "linux")]
     let wayland_webview_workaround_level = setup_linux_wayland_webview_workaround();
 
@@ -614,7 +567,12 @@ pub fn run() {
         let lock = match try_acquire_single_instance_lock_with_optional_retry() {
             Ok(lock) => {
                 info!("文件锁单实例检测成功");
-                lock
+                Some(lock)
+            }
+            // Let the single-instance plugin forward the link to the running instance
+            Err(e) if deep_link::launched_with_link() => {
+                warn!("单实例检测失败，尝试转发深度链接: {}", e);
+                None
             }
             Err(e) => {
                 error!("单实例检测失败: {}", e);
@@ -625,7 +583,7 @@ pub fn run() {
         };
 
         if let Ok(mut guard) = single_instance_lock_holder.lock() {
-            *guard = Some(lock);
+            *guard = lock;
         }
     }
 
@@ -644,16 +602,32 @@ pub fn run() {
                 let _ = window.set_focus();
             }
         }))
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_os::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(hotkeys::handle_shortcut)
+                .build(),
+        )
         .setup(move |app| {
             info!("开始执行 setup()...");
             let app_handle = app.handle().clone();
 
+            // Still running without the file lock: the deep link could not be forwarded
+            #[cfg(target_os = "linux")]
+            if single_instance_lock_holder
+                .lock()
+                .map_or(false, |guard| guard.is_none())
+            {
+                error!("AI Toolbox 已经在运行中，深度链接转发失败");
+                std::process::exit(1);
+            }
+
             #[cfg(target_os = "linux")]
             if auto_downgrade_enabled {
                 start_linux_wayland_webview_auto_downgrade_watchdog(
@@ -698,7 +672,7 @@ pub fn run() {
 
             // Create app data directory
             info!("正在获取应用数据目录...");
-            let app_data_dir = match app_handle.path().app_data_dir() {
+            let app_data_dir = match portable::app_data_dir(&app_handle) {
                 Ok(dir) => {
                     info!("应用数据目录: {:?}", dir);
                     dir
@@ -793,9 +767,35 @@ pub fn run() {
                     tokio::sync::Mutex::new(coding::ssh::SshSession::new()),
                 ));
                 app.manage(ssh_session);
+                app.manage(coding::ssh::PortForwardState::default());
+                app.manage(coding::ssh::ExecState::default());
                 info!("SSH 会话状态已注册到应用");
+
+                // Load the app lock before the frontend can call any command
+                app_lock::init_app_lock(app.handle()).await;
             });
 
+            // Audit log retention
+            let app_handle_clone = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                audit_log::init(&app_handle_clone).await;
+            });
+
+            // Emit db-changed events for every record change
+            let app_handle_clone = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                db_events::init(&app_handle_clone).await;
+            });
+
+            // Local OpenAI-compatible gateway, if enabled
+            let app_handle_clone = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                gateway::init(&app_handle_clone).await;
+            });
+
+            // Lock again after the configured idle time
+            app_lock::start_auto_lock_timer(app_handle.clone());
+
             // Create system tray
             info!("正在创建系统托盘...");
             if let Err(e) = tray::create_tray(&app_handle) {
@@ -804,26 +804,59 @@ pub fn run() {
             }
             info!("系统托盘创建成功");
 
+            // ai-toolbox:// links: the one that launched the app, then links forwarded later
+            {
+                use tauri_plugin_deep_link::DeepLinkExt;
+
+                // Installers register the scheme; dev builds and AppImages register at runtime
+                #[cfg(any(target_os = "windows", target_os = "linux"))]
+                if let Err(e) = app.deep_link().register_all() {
+                    warn!("注册深度链接协议失败: {}", e);
+                }
+
+                if let Ok(Some(urls)) = app.deep_link().get_current() {
+                    let links: Vec<String> = urls.iter().map(|url| url.to_string()).collect();
+                    deep_link::handle_links(&app_handle, &links, false);
+                }
+
+                let app_handle_clone = app_handle.clone();
+                app.deep_link().on_open_url(move |event| {
+                    let links: Vec<String> =
+                        event.urls().iter().map(|url| url.to_string()).collect();
+                    deep_link::handle_links(&app_handle_clone, &links, true);
+                });
+            }
+
+            // Register global hotkeys from settings
+            let app_handle_clone = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                hotkeys::register_saved_hotkeys(&app_handle_clone).await;
+            });
+
             // Listen for config changes to refresh tray menu
             let app_handle_clone = app_handle.clone();
             tauri::async_runtime::spawn(async move {
                 let value = app_handle_clone.clone();
                 let value_for_closure = value.clone();
                 let _listener = value.listen("config-changed", move |_event| {
-                    let app = value_for_closure.app_handle().clone();
-                    let _ = tauri::async_runtime::spawn(async move {
-                        let _ = tray::refresh_tray_menus(&app).await;
-                    });
+                    tray::schedule_tray_refresh(value_for_closure.app_handle());
                 });
+                // Sync results are shown in the tray status section
+                for event in ["ssh-sync-completed", "wsl-sync-completed"] {
+                    let app = value.clone();
+                    value.listen(event, move |_event| {
+                        tray::schedule_tray_refresh(&app);
+                    });
+                }
 
                 // Keep this async block alive forever to prevent listener from being dropped
                 std::future::pending::<()>().await;
             });
 
-            // Enable auto-launch if setting is true, and handle start_minimized
+            // Keep the autostart entry in sync with the setting, and handle start_minimized
             let app_handle_clone = app_handle.clone();
             tauri::async_runtime::spawn(async move {
-                let start_minimized = {
+                {
                     let db_state = app_handle_clone.state::<DbState>();
                     let db = db_state.db();
 
@@ -832,8 +865,6 @@ pub fn run() {
                         .await
                         .ok();
 
-                    let mut start_minimized = false;
-
                     if let Some(ref mut res) = result {
                         let records: Result<Vec<serde_json::Value>, _> = res.take(0);
                         if let Ok(records) = records {
@@ -843,40 +874,59 @@ pub fn run() {
                                     .and_then(|v| v.as_bool())
                                     .unwrap_or(true);
 
-                                if launch_on_startup {
-                                    let _ = auto_launch::enable_auto_launch();
+                                if let Err(e) = auto_launch::sync_auto_launch(launch_on_startup) {
+                                    warn!("同步开机自启状态失败: {}", e);
                                 }
-
-                                start_minimized = record
-                                    .get("start_minimized")
-                                    .and_then(|v| v.as_bool())
-                                    .unwrap_or(false);
                             }
                         }
                     }
+                } // db lock released here
 
-                    start_minimized
-                }; // db lock released here
+                // Load the window behavior used by the close / exit handlers
+                let behavior =
+                    settings::window_behavior::load_window_behavior(&app_handle_clone).await;
+                // Backend strings (tray, sync events) follow the UI language
+                locale::set_language(&behavior.language);
 
-                // Show window unless start_minimized is enabled
-                if !start_minimized {
+                // Show window unless this is a login launch with start_minimized enabled
+                if !auto_launch::should_start_minimized(behavior.start_minimized) {
                     if let Some(window) = app_handle_clone.get_webview_window("main") {
                         let _ = window.show();
                         let _ = window.set_focus();
                     }
                 } else {
-                    // macOS: Switch to Accessory mode to hide from Dock
-                    #[cfg(target_os = "macos")]
-                    {
-                        use tauri::ActivationPolicy;
-                        let _ = app_handle_clone.set_activation_policy(ActivationPolicy::Accessory);
-                    }
+                    settings::window_behavior::hide_to_tray(&app_handle_clone);
                 }
             });
 
             // Listen for WSL sync requests (Windows only)
             #[cfg(target_os = "windows")]
             {
+                // Watch Windows-side config files if enabled
+                let app_watch = app_handle.clone();
+                tauri::async_runtime::spawn(async move {
+                    tokio::time::sleep(Duration::from_secs(3)).await;
+                    if let Err(e) = coding::wsl::watcher::refresh_config_watcher(&app_watch).await {
+                        warn!("Failed to start WSL config watcher: {}", e);
+                    }
+                });
+
+                // Full sync requested from the tray status section (manual, not gated
+                // by the auto sync setting)
+                let app_wsl_all = app_handle.clone();
+                let app_wsl_all_clone = app_wsl_all.clone();
+                tauri::async_runtime::spawn(async move {
+                    let _ = app_wsl_all.listen("wsl-sync-request-all", move |_event| {
+                        let app = app_wsl_all_clone.clone();
+                        tauri::async_runtime::spawn(async move {
+                            let db_state = app.state::<crate::DbState>();
+                            let _ = coding::wsl::wsl_sync(db_state, app.clone(), None, None, None)
+                                .await;
+                        });
+                    });
+                    std::future::pending::<()>().await;
+                });
+
                 // OpenCode sync listener
                 let app1 = app_handle.clone();
                 let app1_clone = app1.clone();
@@ -895,6 +945,7 @@ pub fn run() {
                                 app.clone(),
                                 Some("opencode".to_string()),
                                 None,
+                                None,
                             )
                             .await;
                             // Ignore result - fire and forget
@@ -924,6 +975,7 @@ pub fn run() {
                                 app.clone(),
                                 Some("claude".to_string()),
                                 None,
+                                None,
                             )
                             .await;
                             // Ignore result - fire and forget
@@ -953,6 +1005,7 @@ pub fn run() {
                                 app.clone(),
                                 Some("codex".to_string()),
                                 None,
+                                None,
                             )
                             .await;
                             // Ignore result - fire and forget
@@ -982,6 +1035,7 @@ pub fn run() {
                                 app.clone(),
                                 Some("openclaw".to_string()),
                                 None,
+                                None,
                             )
                             .await;
                             // Ignore result - fire and forget
@@ -1047,12 +1101,41 @@ pub fn run() {
                     }
                     let app = app_clone.clone();
 
-                    let _ = coding::wsl::wsl_sync(db_state, app, None, None).await;
+                    let _ = coding::wsl::wsl_sync(db_state, app, None, None, None).await;
+                });
+
+                // Report distro start/stop to the UI
+                let app_state = app_handle.clone();
+                tauri::async_runtime::spawn(async move {
+                    coding::wsl::distro_state::run_distro_state_poller(app_state).await;
                 });
             }
 
             // SSH sync listeners (all platforms)
             {
+                // Full sync requested from the tray status section
+                let app_ssh_all = app_handle.clone();
+                let app_ssh_all_clone = app_ssh_all.clone();
+                tauri::async_runtime::spawn(async move {
+                    let _ = app_ssh_all.listen("ssh-sync-request-all", move |_event| {
+                        let app = app_ssh_all_clone.clone();
+                        tauri::async_runtime::spawn(async move {
+                            let db_state = app.state::<crate::DbState>();
+                            let session_state = app.state::<coding::ssh::SshSessionState>();
+                            let _ = coding::ssh::ssh_sync(
+                                db_state,
+                                session_state,
+                                app.clone(),
+                                None,
+                                None,
+                                None,
+                            )
+                            .await;
+                        });
+                    });
+                    std::future::pending::<()>().await;
+                });
+
                 // SSH sync request listeners (module-specific)
                 let app_ssh1 = app_handle.clone();
                 let app_ssh1_clone = app_ssh1.clone();
@@ -1068,6 +1151,7 @@ pub fn run() {
                                 app.clone(),
                                 Some("opencode".to_string()),
                                 None,
+                                None,
                             )
                             .await;
                         });
@@ -1089,6 +1173,7 @@ pub fn run() {
                                 app.clone(),
                                 Some("claude".to_string()),
                                 None,
+                                None,
                             )
                             .await;
                         });
@@ -1110,6 +1195,7 @@ pub fn run() {
                                 app.clone(),
                                 Some("codex".to_string()),
                                 None,
+                                None,
                             )
                             .await;
                         });
@@ -1234,21 +1320,42 @@ pub fn run() {
                             continue;
                         }
 
+                        let was_lost = coding::ssh::is_connection_lost();
                         if !session.is_alive() {
                             log::info!("SSH 健康检查：连接已断开，尝试重连...");
                             if let Err(e) = session.ensure_connected().await {
                                 log::warn!("SSH 重连失败: {}", e);
+                                coding::ssh::set_connection_lost(true);
                                 let _ =
                                     app_ssh_health.emit("ssh-connection-status", "disconnected");
                             } else {
                                 log::info!("SSH 重连成功");
+                                coding::ssh::set_connection_lost(false);
                                 let _ = app_ssh_health.emit("ssh-connection-status", "connected");
                             }
+                        } else {
+                            coding::ssh::set_connection_lost(false);
+                        }
+                        if was_lost != coding::ssh::is_connection_lost() {
+                            tray::schedule_tray_refresh(&app_ssh_health);
                         }
                     }
                 });
             }
 
+            // Start the central skills repo watcher if auto-resync is enabled
+            {
+                let app_clone = app_handle.clone();
+                tauri::async_runtime::spawn(async move {
+                    tokio::time::sleep(Duration::from_secs(3)).await;
+                    if let Err(e) =
+                        coding::skills::central_watcher::refresh_central_watcher(&app_clone).await
+                    {
+                        warn!("Failed to start central skills repo watcher: {}", e);
+                    }
+                });
+            }
+
             // Git cache auto-cleanup task (checks every hour)
             {
                 let app_clone = app_handle.clone();
@@ -1278,6 +1385,10 @@ pub fn run() {
                                 _ => {}
                             }
                         }
+                        coding::skills::cache_cleanup::enforce_git_cache_limit_from_settings(
+                            &app_clone, None,
+                        )
+                        .await;
 
                         // Check every hour
                         tokio::time::sleep(Duration::from_secs(3600)).await;
@@ -1292,7 +1403,7 @@ pub fn run() {
                     // Delay to ensure database is fully initialized
                     tokio::time::sleep(Duration::from_secs(3)).await;
 
-                    let app_data_dir = match app_clone.path().app_data_dir() {
+                    let app_data_dir = match portable::app_data_dir(&app_clone) {
                         Ok(dir) => dir,
                         Err(_) => return,
                     };
@@ -1345,6 +1456,9 @@ pub fn run() {
             // Start auto-backup scheduler
             settings::backup::auto_backup::start_auto_backup_scheduler(app_handle.clone());
 
+            // Start scheduled update checks
+            update::start_update_check_scheduler(app_handle.clone());
+
             info!("setup() 完成，应用即将启动");
             Ok(())
         })
@@ -1354,73 +1468,113 @@ pub fn run() {
                     return;
                 }
 
-                let app_handle = window.app_handle().clone();
-
-                // Check minimize_to_tray_on_close setting with default value
-                let minimize_to_tray = {
-                    let db_state = app_handle.state::<DbState>();
-                    let db = db_state.db();
-
-                    // Query settings synchronously using block_on
-                    let query_result = tauri::async_runtime::block_on(async {
-                        db.query("SELECT * OMIT id FROM settings:`app` LIMIT 1")
-                            .await
-                    });
-
-                    match query_result {
-                        Ok(mut res) => {
-                            let records: Result<Vec<serde_json::Value>, surrealdb::Error> =
-                                res.take(0);
-                            match records {
-                                Ok(records) => {
-                                    if let Some(record) = records.first() {
-                                        record
-                                            .get("minimize_to_tray_on_close")
-                                            .and_then(|v| v.as_bool())
-                                            .unwrap_or(true)
-                                    } else {
-                                        true
-                                    }
-                                }
-                                Err(_) => true,
-                            }
-                        }
-                        Err(_) => true,
-                    }
-                };
+                // The quick-switch palette is only hidden, so it reopens instantly
+                if window.label() == quick_switch::QUICK_SWITCH_WINDOW {
+                    let _ = window.hide();
+                    api.prevent_close();
+                    return;
+                }
 
-                if minimize_to_tray {
-                    // Hide window instead of closing
-                    if let Some(window) = app_handle.get_webview_window("main") {
-                        let _ = window.hide();
+                let app_handle = window.app_handle();
+                // The window is never closed directly: it is either hidden to the tray or
+                // the app quits (after confirmation when enabled). Closing it would leave
+                // the hidden palette window as the only window and keep the app running.
+                api.prevent_close();
 
-                        // macOS: Switch to Accessory mode to hide from Dock
-                        #[cfg(target_os = "macos")]
-                        {
-                            use tauri::ActivationPolicy;
-                            let _ = app_handle.set_activation_policy(ActivationPolicy::Accessory);
-                        }
-                    }
-                    // Prevent default close behavior
-                    api.prevent_close();
+                if settings::window_behavior::current().minimize_to_tray_on_close {
+                    settings::window_behavior::hide_to_tray(app_handle);
+                } else {
+                    settings::window_behavior::request_quit(app_handle);
                 }
-                // If minimize_to_tray is false, do nothing - window will close normally
             }
         })
-        .invoke_handler(tauri::generate_handler![
+        .invoke_handler(command_handler(tauri::generate_handler![
             // Common
             open_folder,
             set_window_background_color,
             // Update
             update::check_for_updates,
             update::install_update,
+            update::skip_update_version,
+            update::install_update_from_file,
+            update::rollback_update,
+            update::get_previous_version,
+            // App lock
+            app_lock::get_app_lock_status,
+            app_lock::unlock_app,
+            app_lock::lock_app,
+            app_lock::enable_app_lock,
+            app_lock::disable_app_lock,
+            app_lock::set_app_lock_timeout,
+            secrets::list_secrets,
+            secrets::set_secret,
+            secrets::delete_secret,
             // Settings
             settings::get_settings,
             settings::save_settings,
+            settings::export_settings,
+            settings::import_settings,
             settings::set_auto_launch,
             settings::get_auto_launch_status,
             settings::restart_app,
             settings::test_proxy_connection,
+            settings::test_proxy,
+            // Logs & diagnostics
+            logging::get_recent_logs,
+            diagnostics::export_diagnostics_bundle,
+            doctor::run_doctor,
+            // Deep links
+            deep_link::take_pending_deep_links,
+            // Audit log
+            audit_log::get_audit_log,
+            // Global search
+            search::search_all,
+            // Database integrity
+            db_integrity::check_database_integrity,
+            db_integrity::repair_database_integrity,
+            db_dump::dump_database,
+            // Local gateway
+            gateway::get_gateway_status,
+            // Chat playground
+            playground::playground_send,
+            // Token counting
+            token_count::count_tokens,
+            // Prompt library
+            coding::prompt_library::list_prompt_library_items,
+            coding::prompt_library::create_prompt_library_item,
+            coding::prompt_library::update_prompt_library_item,
+            coding::prompt_library::delete_prompt_library_item,
+            coding::prompt_library::reorder_prompt_library_items,
+            coding::prompt_library::render_prompt_library_item,
+            coding::prompt_library::export_prompt_library_items,
+            // Usage
+            coding::usage::refresh_usage_records,
+            coding::usage::get_usage_summary,
+            // Cursor
+            coding::cursor::get_cursor_paths,
+            coding::cursor::list_cursor_rules,
+            coding::cursor::save_cursor_rule,
+            coding::cursor::delete_cursor_rule,
+            // GitHub Copilot CLI
+            coding::copilot_cli::get_copilot_cli_paths,
+            coding::copilot_cli::get_copilot_cli_model,
+            coding::copilot_cli::set_copilot_cli_model,
+            // Aider
+            coding::aider::get_aider_paths,
+            coding::aider::list_aider_providers,
+            coding::aider::create_aider_provider,
+            coding::aider::update_aider_provider,
+            coding::aider::delete_aider_provider,
+            coding::aider::reorder_aider_providers,
+            coding::aider::apply_aider_provider,
+            coding::aider::get_aider_show_in_tray,
+            coding::aider::set_aider_show_in_tray,
+            hotkeys::get_hotkey_status,
+            hotkeys::save_hotkeys,
+            quick_switch::open_quick_switch_window,
+            quick_switch::hide_quick_switch_window,
+            quick_switch::quick_switch_search,
+            quick_switch::apply_quick_switch_item,
             // Backup - Local
             settings::backup::backup_database,
             settings::backup::restore_database,
@@ -1509,6 +1663,12 @@ pub fn run() {
             coding::open_code::apply_opencode_prompt_config,
             coding::open_code::reorder_opencode_prompt_configs,
             coding::open_code::save_opencode_local_prompt_config,
+            coding::open_code::launch_opencode,
+            coding::open_code::check_opencode_update,
+            coding::open_code::get_opencode_model_variants,
+            coding::open_code::validate_opencode_model_variant,
+            coding::open_code::upsert_opencode_model_variant,
+            coding::open_code::delete_opencode_model_variant,
             coding::session_manager::list_tool_sessions,
             coding::session_manager::list_tool_session_paths,
             coding::session_manager::get_tool_session_detail,
@@ -1535,6 +1695,15 @@ pub fn run() {
             coding::codex::uninstall_codex_plugin,
             coding::codex::enable_codex_plugins_feature,
             coding::codex::reveal_codex_config_folder,
+            coding::codex::get_codex_auth_status,
+            coding::codex::codex_logout,
+            coding::codex::get_codex_provider_runtime_settings,
+            coding::codex::update_codex_provider_runtime_settings,
+            coding::codex::get_codex_notify_status,
+            coding::codex::save_codex_notify_config,
+            coding::codex::enable_codex_bundled_notify,
+            coding::codex::preview_codex_config,
+            coding::codex::import_codex_config_as_providers,
             coding::codex::list_codex_providers,
             coding::codex::create_codex_provider,
             coding::codex::update_codex_provider,
@@ -1608,6 +1777,7 @@ pub fn run() {
             coding::wsl::wsl_detect,
             coding::wsl::wsl_check_distro,
             coding::wsl::wsl_get_distro_state,
+            coding::wsl::wsl_preview_mcp_transform,
             coding::wsl::wsl_get_config,
             coding::wsl::wsl_save_config,
             coding::wsl::wsl_add_file_mapping,
@@ -1616,12 +1786,15 @@ pub fn run() {
             coding::wsl::wsl_reset_file_mappings,
             coding::wsl::wsl_sync,
             coding::wsl::wsl_get_status,
+            coding::wsl::wsl_get_sync_history,
             coding::wsl::wsl_test_path,
             coding::wsl::wsl_get_default_mappings,
             coding::wsl::wsl_open_terminal,
             coding::wsl::wsl_open_folder,
             // SSH Sync
             coding::ssh::ssh_test_connection,
+            coding::ssh::ssh_trust_host_key,
+            coding::ssh::ssh_forget_host_key,
             coding::ssh::ssh_get_config,
             coding::ssh::ssh_save_config,
             coding::ssh::ssh_list_connections,
@@ -1634,7 +1807,19 @@ pub fn run() {
             coding::ssh::ssh_delete_file_mapping,
             coding::ssh::ssh_reset_file_mappings,
             coding::ssh::ssh_sync,
+            coding::ssh::ssh_pull,
+            coding::ssh::ssh_pull_apply,
+            coding::ssh::ssh_list_dir,
+            coding::ssh::ssh_read_file,
+            coding::ssh::ssh_bootstrap_remote,
             coding::ssh::ssh_get_status,
+            coding::ssh::ssh_get_sync_history,
+            coding::ssh::ssh_retry_failed,
+            coding::ssh::ssh_port_forward_start,
+            coding::ssh::ssh_port_forward_stop,
+            coding::ssh::ssh_port_forward_status,
+            coding::ssh::ssh_exec,
+            coding::ssh::ssh_exec_cancel,
             coding::ssh::ssh_test_local_path,
             coding::ssh::ssh_get_default_mappings,
             // Skills Hub
@@ -1642,27 +1827,48 @@ pub fn run() {
             coding::skills::skills_get_central_repo_path,
             coding::skills::skills_set_central_repo_path,
             coding::skills::skills_get_managed_skills,
+            coding::skills::skills_update_metadata,
+            coding::skills::skills_get_all_tags,
             coding::skills::skills_install_local,
             coding::skills::skills_list_local_skills,
             coding::skills::skills_install_local_selection,
             coding::skills::skills_install_git,
             coding::skills::skills_list_git_skills,
+            coding::skills::skills_list_git_skills_batch,
             coding::skills::skills_install_git_selection,
+            coding::skills::skills_install_git_selections,
+            coding::skills::skills_export_bundle,
+            coding::skills::skills_import_bundle,
+            coding::skills::skills_check_name_conflict,
             coding::skills::skills_sync_to_tool,
             coding::skills::skills_unsync_from_tool,
+            coding::skills::skills_set_target_enabled,
+            coding::skills::skills_check_drift,
+            coding::skills::skills_get_usage,
+            coding::skills::skills_lint,
+            coding::skills::skills_fix_drift,
             coding::skills::skills_update_managed,
+            coding::skills::skills_check_updates,
+            coding::skills::skills_update_all,
             coding::skills::skills_delete_managed,
+            coding::skills::skills_get_history,
+            coding::skills::skills_restore_version,
             coding::skills::skills_get_onboarding_plan,
             coding::skills::skills_import_existing,
             coding::skills::skills_get_git_cache_cleanup_days,
             coding::skills::skills_set_git_cache_cleanup_days,
             coding::skills::skills_get_git_cache_ttl_secs,
+            coding::skills::skills_get_git_cache_max_size_mb,
+            coding::skills::skills_set_git_cache_max_size_mb,
+            coding::skills::skills_get_git_cache_stats,
             coding::skills::skills_clear_git_cache,
             coding::skills::skills_get_git_cache_path,
             coding::skills::skills_get_preferred_tools,
             coding::skills::skills_set_preferred_tools,
             coding::skills::skills_get_show_in_tray,
             coding::skills::skills_set_show_in_tray,
+            coding::skills::skills_get_auto_resync,
+            coding::skills::skills_set_auto_resync,
             // Skills Hub - Custom Tools
             coding::skills::skills_get_custom_tools,
             coding::skills::skills_add_custom_tool,
@@ -1673,6 +1879,9 @@ pub fn run() {
             coding::skills::skills_get_repos,
             coding::skills::skills_add_repo,
             coding::skills::skills_remove_repo,
+            coding::skills::skills_get_repo_credentials,
+            coding::skills::skills_set_repo_credential,
+            coding::skills::skills_delete_repo_credential,
             coding::skills::skills_init_default_repos,
             // Skills Hub - Reorder
             coding::skills::skills_reorder,
@@ -1683,13 +1892,23 @@ pub fn run() {
             coding::mcp::mcp_create_server,
             coding::mcp::mcp_update_server,
             coding::mcp::mcp_delete_server,
+            coding::mcp::mcp_test_server,
+            coding::mcp::mcp_get_server_logs,
             coding::mcp::mcp_toggle_tool,
             coding::mcp::mcp_reorder_servers,
             coding::mcp::mcp_sync_to_tool,
             coding::mcp::mcp_sync_all,
+            coding::mcp::mcp_preview_export,
             coding::mcp::mcp_import_from_tool,
             coding::mcp::mcp_get_tools,
             coding::mcp::mcp_scan_servers,
+            coding::mcp::mcp_browse_registry,
+            coding::mcp::mcp_list_secrets,
+            coding::mcp::mcp_set_secret,
+            coding::mcp::mcp_list_profiles,
+            coding::mcp::mcp_save_profile,
+            coding::mcp::mcp_delete_profile,
+            coding::mcp::mcp_apply_profile,
             coding::mcp::mcp_get_show_in_tray,
             coding::mcp::mcp_set_show_in_tray,
             coding::mcp::mcp_get_preferred_tools,
@@ -1703,8 +1922,8 @@ pub fn run() {
             coding::mcp::mcp_upsert_favorite,
             coding::mcp::mcp_delete_favorite,
             coding::mcp::mcp_init_default_favorites,
-        ])
-        .build(tauri::generate_context!())
+        ]))
+        .build(context)
         .map_err(|e| {
             error!("构建 Tauri 应用失败: {}", e);
             e
@@ -1724,6 +1943,18 @@ pub fn run() {
                     }
                 }
 
+                // Exit not started by the app itself (e.g. Cmd+Q on macOS; `app.exit` and
+                // restarts carry an exit code): ask first when confirm_before_quit is enabled
+                tauri::RunEvent::ExitRequested { code, api, .. } => {
+                    if code.is_none()
+                        && !APP_EXIT_REQUESTED.load(Ordering::SeqCst)
+                        && settings::window_behavior::current().confirm_before_quit
+                    {
+                        api.prevent_exit();
+                        settings::window_behavior::request_quit(app_handle);
+                    }
+                }
+
                 _ => {}
             }
 
@@ -0,0 +1,301 @@
+//! Backend Locale Catalog
+//!
+//! User-facing strings produced by the backend (tray labels, the quit dialog, sync
+//! progress events and warnings, sync error prefixes) are looked up here by key in the
+//! language chosen in the settings (`AppSettings.language`).
+//!
+//! - Supported: Simplified Chinese (default), English, Japanese
+//! - Placeholders are written as `{name}` and filled by [`format`]
+//! - A key missing from a catalog falls back to Simplified Chinese, then to the key
+
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    ZhCn,
+    EnUs,
+    JaJp,
+}
+
+impl Locale {
+    /// Locale for an `AppSettings.language` value such as "en-US"; unknown values map to Chinese
+    pub fn from_language(language: &str) -> Self {
+        let language = language.to_ascii_lowercase();
+        if language.starts_with("en") {
+            Locale::EnUs
+        } else if language.starts_with("ja") {
+            Locale::JaJp
+        } else {
+            Locale::ZhCn
+        }
+    }
+
+    fn catalog(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::ZhCn => ZH_CN,
+            Locale::EnUs => EN_US,
+            Locale::JaJp => JA_JP,
+        }
+    }
+}
+
+/// Locale of the saved settings, stored as the enum discriminant
+static CURRENT: AtomicU8 = AtomicU8::new(Locale::ZhCn as u8);
+
+/// Switch the backend language (called at startup and whenever settings are saved)
+pub fn set_language(language: &str) {
+    CURRENT.store(Locale::from_language(language) as u8, Ordering::Relaxed);
+}
+
+/// Locale of the saved settings
+pub fn current() -> Locale {
+    match CURRENT.load(Ordering::Relaxed) {
+        x if x == Locale::EnUs as u8 => Locale::EnUs,
+        x if x == Locale::JaJp as u8 => Locale::JaJp,
+        _ => Locale::ZhCn,
+    }
+}
+
+fn lookup(catalog: &'static [(&'static str, &'static str)], key: &str) -> Option<&'static str> {
+    catalog.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+}
+
+/// String for `key` in `locale`
+pub fn text_in(locale: Locale, key: &'static str) -> &'static str {
+    lookup(locale.catalog(), key)
+        .or_else(|| lookup(ZH_CN, key))
+        .unwrap_or(key)
+}
+
+/// String for `key` in the current locale
+pub fn text(key: &'static str) -> &'static str {
+    text_in(current(), key)
+}
+
+/// String for `key` in the current locale with its `{name}` placeholders filled
+pub fn format(key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+    let mut out = text(key).to_string();
+    for (name, value) in args {
+        out = out.replace(&format!("{{{}}}", name), &value.to_string());
+    }
+    out
+}
+
+const ZH_CN: &[(&str, &str)] = &[
+    // Tray
+    ("tray.show_window", "打开主界面"),
+    ("tray.quick_switch", "快速切换..."),
+    ("tray.quit", "退出"),
+    ("tray.main_model", "主模型"),
+    ("tray.small_model", "小模型"),
+    ("tray.global_prompt", "全局提示词"),
+    ("tray.opencode_plugins", "OpenCode 插件"),
+    ("tray.no_config", "  暂无配置"),
+    ("tray.no_model", "  暂无模型"),
+    ("tray.no_tools", "  暂无工具"),
+    ("tray.recent_models", "最近使用"),
+    ("tray.opencode_update_available", "  有可用更新"),
+    ("tray.status", "状态"),
+    ("tray.ssh_sync", "SSH 同步"),
+    ("tray.wsl_sync", "WSL 同步"),
+    ("tray.sync_never", "从未同步"),
+    ("tray.sync_failed", "失败"),
+    ("tray.connection_lost", "连接已断开"),
+    ("tray.sync_now", "立即同步"),
+    ("tray.open_settings", "打开设置"),
+    ("tray.app_update_available", "  有新版本"),
+    // Quit confirmation
+    ("quit_dialog.title", "退出 AI Toolbox"),
+    ("quit_dialog.message", "确定要退出 AI Toolbox 吗？"),
+    ("quit_dialog.ok", "退出"),
+    ("quit_dialog.cancel", "取消"),
+    // Sync progress and errors
+    ("sync.preparing", "准备中..."),
+    ("sync.files_progress", "文件同步: {current}/{total}"),
+    (
+        "sync.files_progress_item",
+        "文件同步: {current}/{total} - {name}",
+    ),
+    ("sync.skills_progress", "Skills 同步: {current}/{total}"),
+    (
+        "sync.skills_progress_item",
+        "Skills 同步: {current}/{total} - {name}",
+    ),
+    ("sync.mcp_claude", "MCP 同步: Claude Code..."),
+    ("sync.mcp_opencode_codex", "MCP 同步: OpenCode/Codex..."),
+    ("sync.ssh_not_enabled", "SSH 同步未启用"),
+    ("sync.already_running", "另一个同步操作正在进行中"),
+    ("sync.ssh_connect_failed", "SSH 连接失败: {error}"),
+    (
+        "sync.claude_json_skipped",
+        "{target} ~/.claude.json 同步已跳过：文件解析失败，请检查该文件格式是否正确。({error})",
+    ),
+    (
+        "sync.config_partially_failed",
+        "OpenCode/Codex 配置同步部分失败：{error}",
+    ),
+    (
+        "sync.opencode_codex_mcp_skipped",
+        "OpenCode/Codex MCP 同步已跳过：{error}",
+    ),
+    ("sync.wsl_mcp_skipped", "WSL MCP 同步已跳过：{error}"),
+];
+
+const EN_US: &[(&str, &str)] = &[
+    // Tray
+    ("tray.show_window", "Open Main Window"),
+    ("tray.quick_switch", "Quick Switch..."),
+    ("tray.quit", "Quit"),
+    ("tray.main_model", "Main Model"),
+    ("tray.small_model", "Small Model"),
+    ("tray.global_prompt", "Global Prompt"),
+    ("tray.opencode_plugins", "OpenCode Plugins"),
+    ("tray.no_config", "  No configs"),
+    ("tray.no_model", "  No models"),
+    ("tray.no_tools", "  No tools"),
+    ("tray.recent_models", "Recent"),
+    ("tray.opencode_update_available", "  Update available"),
+    ("tray.status", "Status"),
+    ("tray.ssh_sync", "SSH Sync"),
+    ("tray.wsl_sync", "WSL Sync"),
+    ("tray.sync_never", "never synced"),
+    ("tray.sync_failed", "failed"),
+    ("tray.connection_lost", "connection lost"),
+    ("tray.sync_now", "Sync Now"),
+    ("tray.open_settings", "Open Settings"),
+    ("tray.app_update_available", "  App update available"),
+    // Quit confirmation
+    ("quit_dialog.title", "Quit AI Toolbox"),
+    (
+        "quit_dialog.message",
+        "Are you sure you want to quit AI Toolbox?",
+    ),
+    ("quit_dialog.ok", "Quit"),
+    ("quit_dialog.cancel", "Cancel"),
+    // Sync progress and errors
+    ("sync.preparing", "Preparing..."),
+    ("sync.files_progress", "Syncing files: {current}/{total}"),
+    (
+        "sync.files_progress_item",
+        "Syncing files: {current}/{total} - {name}",
+    ),
+    ("sync.skills_progress", "Syncing skills: {current}/{total}"),
+    (
+        "sync.skills_progress_item",
+        "Syncing skills: {current}/{total} - {name}",
+    ),
+    ("sync.mcp_claude", "Syncing MCP: Claude Code..."),
+    ("sync.mcp_opencode_codex", "Syncing MCP: OpenCode/Codex..."),
+    ("sync.ssh_not_enabled", "SSH sync is not enabled"),
+    ("sync.already_running", "Another sync is already in progress"),
+    ("sync.ssh_connect_failed", "SSH connection failed: {error}"),
+    (
+        "sync.claude_json_skipped",
+        "Skipped syncing {target} ~/.claude.json: the file could not be parsed, please check its format. ({error})",
+    ),
+    (
+        "sync.config_partially_failed",
+        "OpenCode/Codex config sync partially failed: {error}",
+    ),
+    (
+        "sync.opencode_codex_mcp_skipped",
+        "Skipped OpenCode/Codex MCP sync: {error}",
+    ),
+    ("sync.wsl_mcp_skipped", "Skipped WSL MCP sync: {error}"),
+];
+
+const JA_JP: &[(&str, &str)] = &[
+    // Tray
+    ("tray.show_window", "メイン画面を開く"),
+    ("tray.quick_switch", "クイック切り替え..."),
+    ("tray.quit", "終了"),
+    ("tray.main_model", "メインモデル"),
+    ("tray.small_model", "スモールモデル"),
+    ("tray.global_prompt", "グローバルプロンプト"),
+    ("tray.opencode_plugins", "OpenCode プラグイン"),
+    ("tray.no_config", "  設定がありません"),
+    ("tray.no_model", "  モデルがありません"),
+    ("tray.no_tools", "  ツールがありません"),
+    ("tray.recent_models", "最近使用"),
+    ("tray.opencode_update_available", "  アップデートがあります"),
+    ("tray.status", "ステータス"),
+    ("tray.ssh_sync", "SSH 同期"),
+    ("tray.wsl_sync", "WSL 同期"),
+    ("tray.sync_never", "未同期"),
+    ("tray.sync_failed", "失敗"),
+    ("tray.connection_lost", "接続が切断されました"),
+    ("tray.sync_now", "今すぐ同期"),
+    ("tray.open_settings", "設定を開く"),
+    ("tray.app_update_available", "  新しいバージョンがあります"),
+    // Quit confirmation
+    ("quit_dialog.title", "AI Toolbox を終了"),
+    ("quit_dialog.message", "AI Toolbox を終了しますか？"),
+    ("quit_dialog.ok", "終了"),
+    ("quit_dialog.cancel", "キャンセル"),
+    // Sync progress and errors
+    ("sync.preparing", "準備中..."),
+    ("sync.files_progress", "ファイル同期: {current}/{total}"),
+    (
+        "sync.files_progress_item",
+        "ファイル同期: {current}/{total} - {name}",
+    ),
+    ("sync.skills_progress", "Skills 同期: {current}/{total}"),
+    (
+        "sync.skills_progress_item",
+        "Skills 同期: {current}/{total} - {name}",
+    ),
+    ("sync.mcp_claude", "MCP 同期: Claude Code..."),
+    ("sync.mcp_opencode_codex", "MCP 同期: OpenCode/Codex..."),
+    ("sync.ssh_not_enabled", "SSH 同期が有効になっていません"),
+    ("sync.already_running", "別の同期処理が実行中です"),
+    ("sync.ssh_connect_failed", "SSH 接続に失敗しました: {error}"),
+    (
+        "sync.claude_json_skipped",
+        "{target} ~/.claude.json の同期をスキップしました：ファイルを解析できません。形式を確認してください。({error})",
+    ),
+    (
+        "sync.config_partially_failed",
+        "OpenCode/Codex 設定の同期が一部失敗しました：{error}",
+    ),
+    (
+        "sync.opencode_codex_mcp_skipped",
+        "OpenCode/Codex MCP の同期をスキップしました：{error}",
+    ),
+    ("sync.wsl_mcp_skipped", "WSL MCP の同期をスキップしました：{error}"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placeholders(text: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = text
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn every_catalog_has_the_same_keys_and_placeholders() {
+        for catalog in [EN_US, JA_JP] {
+            assert_eq!(catalog.len(), ZH_CN.len());
+            for (key, text) in ZH_CN {
+                let translated = lookup(catalog, key).unwrap_or_else(|| panic!("missing {}", key));
+                assert_eq!(placeholders(text), placeholders(translated), "{}", key);
+            }
+        }
+    }
+
+    #[test]
+    fn maps_setting_languages() {
+        assert_eq!(Locale::from_language("en-US"), Locale::EnUs);
+        assert_eq!(Locale::from_language("ja-JP"), Locale::JaJp);
+        assert_eq!(Locale::from_language("zh-CN"), Locale::ZhCn);
+        assert_eq!(Locale::from_language(""), Locale::ZhCn);
+        assert_eq!(text_in(Locale::EnUs, "no.such.key"), "no.such.key");
+    }
+}
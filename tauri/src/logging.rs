@@ -0,0 +1,352 @@
+//! Logging Module
+//!
+//! - Release builds write to rotating files in the app data `logs` directory: a new file
+//!   every day and whenever the current one grows past `MAX_FILE_BYTES`; files older than
+//!   `RETENTION_DAYS` are removed
+//! - Debug builds log to the terminal
+//! - Recent records are also kept in memory for the in-app log viewer
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use serde::Serialize;
+use simplelog::{
+    ColorChoice, CombinedLogger, Config, ConfigBuilder, SharedLogger, TermLogger, TerminalMode,
+    WriteLogger,
+};
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+const LOG_FILE_PREFIX: &str = "ai-toolbox_";
+/// Size after which the day's log continues in a new file
+const MAX_FILE_BYTES: u64 = 10 * 1024 * 1024;
+const RETENTION_DAYS: u64 = 7;
+/// Records kept in memory for `get_recent_logs`
+const RECENT_CAPACITY: usize = 2000;
+
+/// Log record as shown in the log viewer
+#[derive(Debug, Clone, Serialize)]
+pub struct LogEntry {
+    pub timestamp: String,
+    pub level: String,
+    pub target: String,
+    pub message: String,
+}
+
+static RECENT: Mutex<VecDeque<LogEntry>> = Mutex::new(VecDeque::new());
+
+/// Directory holding the log files (and `CRASH.log`)
+pub fn log_dir() -> Option<PathBuf> {
+    crate::portable::data_dir().map(|p| p.join("logs"))
+}
+
+/// Only this crate logs below Warn
+fn is_enabled(metadata: &Metadata) -> bool {
+    metadata.level() <= Level::Warn
+        || (metadata.target().starts_with("ai_toolbox") && metadata.level() <= Level::Debug)
+}
+
+/// Keeps the latest records in memory
+struct MemoryLogger;
+
+impl Log for MemoryLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        is_enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let entry = LogEntry {
+            timestamp: chrono::Local::now().to_rfc3339(),
+            level: record.level().to_string(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+        };
+        if let Ok(mut recent) = RECENT.lock() {
+            if recent.len() >= RECENT_CAPACITY {
+                recent.pop_front();
+            }
+            recent.push_back(entry);
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+impl SharedLogger for MemoryLogger {
+    fn level(&self) -> LevelFilter {
+        LevelFilter::Debug
+    }
+
+    fn config(&self) -> Option<&Config> {
+        None
+    }
+
+    fn as_log(self: Box<Self>) -> Box<dyn Log> {
+        Box::new(*self)
+    }
+}
+
+fn today() -> String {
+    chrono::Local::now().format("%Y%m%d").to_string()
+}
+
+fn log_file_path(dir: &Path, date: &str, index: u32) -> PathBuf {
+    if index == 0 {
+        dir.join(format!("{}{}.log", LOG_FILE_PREFIX, date))
+    } else {
+        dir.join(format!("{}{}.{}.log", LOG_FILE_PREFIX, date, index))
+    }
+}
+
+/// Log file writer that rolls over by date and size
+struct RotatingFile {
+    dir: PathBuf,
+    date: String,
+    index: u32,
+    file: File,
+    written: u64,
+}
+
+impl RotatingFile {
+    fn open(dir: &Path) -> io::Result<Self> {
+        let date = today();
+        let mut index = 0;
+        // Continue the day's last file unless it is already full
+        while fs::metadata(log_file_path(dir, &date, index))
+            .map(|m| m.len() >= MAX_FILE_BYTES)
+            .unwrap_or(false)
+        {
+            index += 1;
+        }
+        let (file, written) = Self::open_file(dir, &date, index)?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            date,
+            index,
+            file,
+            written,
+        })
+    }
+
+    fn open_file(dir: &Path, date: &str, index: u32) -> io::Result<(File, u64)> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(log_file_path(dir, date, index))?;
+        let written = file.metadata().map(|m| m.len()).unwrap_or(0);
+        Ok((file, written))
+    }
+
+    fn current_path(&self) -> PathBuf {
+        log_file_path(&self.dir, &self.date, self.index)
+    }
+
+    fn rotate_if_needed(&mut self) -> io::Result<()> {
+        let date = today();
+        if date != self.date {
+            self.date = date;
+            self.index = 0;
+            prune_old_logs(&self.dir);
+        } else if self.written >= MAX_FILE_BYTES {
+            self.index += 1;
+        } else {
+            return Ok(());
+        }
+        let (file, written) = Self::open_file(&self.dir, &self.date, self.index)?;
+        self.file = file;
+        self.written = written;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.rotate_if_needed()?;
+        let written = self.file.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Log files in the log directory, oldest first
+pub fn log_files() -> Vec<PathBuf> {
+    let Some(dir) = log_dir() else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = fs::read_dir(&dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|path| {
+                    path.file_name()
+                        .map(|n| n.to_string_lossy().starts_with(LOG_FILE_PREFIX))
+                        .unwrap_or(false)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+fn prune_old_logs(dir: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let cutoff = SystemTime::now() - Duration::from_secs(RETENTION_DAYS * 24 * 60 * 60);
+    for entry in entries.filter_map(|e| e.ok()) {
+        let is_log = entry
+            .file_name()
+            .to_string_lossy()
+            .starts_with(LOG_FILE_PREFIX);
+        let expired = entry
+            .metadata()
+            .and_then(|m| m.modified())
+            .map(|modified| modified < cutoff)
+            .unwrap_or(false);
+        if is_log && expired {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+/// 初始化日志系统
+/// debug 模式下日志输出到控制台，release 模式下写入按日期和大小轮转的文件；
+/// 两种模式都会在内存中保留最近的日志供应用内查看
+pub fn init_logging() -> Option<PathBuf> {
+    if cfg!(debug_assertions) {
+        // 开发模式：日志输出到控制台
+        // 只输出本 crate 的 Debug 日志，第三方库只输出 Warn 以上
+        let config = ConfigBuilder::new()
+            .set_max_level(LevelFilter::Warn)
+            .add_filter_allow_str("ai_toolbox")
+            .build();
+        if CombinedLogger::init(vec![
+            TermLogger::new(
+                LevelFilter::Debug,
+                config,
+                TerminalMode::Mixed,
+                ColorChoice::Auto,
+            ),
+            Box::new(MemoryLogger),
+        ])
+        .is_err()
+        {
+            eprintln!("日志系统初始化失败");
+        }
+        return None;
+    }
+
+    // 正式版本：日志写入文件
+    let log_dir = log_dir()?;
+
+    if let Err(e) = fs::create_dir_all(&log_dir) {
+        eprintln!("无法创建日志目录: {}", e);
+        return None;
+    }
+
+    // 清理超过保留天数的旧日志文件
+    prune_old_logs(&log_dir);
+
+    let file = match RotatingFile::open(&log_dir) {
+        Ok(f) => f,
+        Err(e) => {
+            eprintln!("无法打开日志文件: {}", e);
+            return None;
+        }
+    };
+    let log_file = file.current_path();
+
+    let file_config = ConfigBuilder::new()
+        .set_max_level(LevelFilter::Warn)
+        .add_filter_allow_str("ai_toolbox")
+        .build();
+
+    if CombinedLogger::init(vec![
+        WriteLogger::new(LevelFilter::Info, file_config, file),
+        Box::new(MemoryLogger),
+    ])
+    .is_err()
+    {
+        eprintln!("日志系统初始化失败");
+        return None;
+    }
+
+    Some(log_file)
+}
+
+fn parse_level(level: &str) -> LevelFilter {
+    match level.to_ascii_lowercase().as_str() {
+        "error" => LevelFilter::Error,
+        "warn" => LevelFilter::Warn,
+        "info" => LevelFilter::Info,
+        _ => LevelFilter::Debug,
+    }
+}
+
+/// Recent in-memory records at `min_level` or more severe, oldest first
+pub fn recent_logs(min_level: LevelFilter, limit: usize) -> Vec<LogEntry> {
+    let Ok(recent) = RECENT.lock() else {
+        return Vec::new();
+    };
+    let mut entries: Vec<LogEntry> = recent
+        .iter()
+        .rev()
+        .filter(|entry| {
+            entry
+                .level
+                .parse::<Level>()
+                .map(|level| level <= min_level)
+                .unwrap_or(true)
+        })
+        .take(limit)
+        .cloned()
+        .collect();
+    entries.reverse();
+    entries
+}
+
+/// Get the most recent log records for the in-app log viewer
+///
+/// `level` is the least severe level to include ("error", "warn", "info", "debug";
+/// default "info"), `limit` caps the number of records (default 500).
+#[tauri::command]
+pub fn get_recent_logs(level: Option<String>, limit: Option<usize>) -> Vec<LogEntry> {
+    let min_level = parse_level(level.as_deref().unwrap_or("info"));
+    recent_logs(min_level, limit.unwrap_or(500).min(RECENT_CAPACITY))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_log_files_by_date_and_index() {
+        let dir = Path::new("logs");
+        assert_eq!(
+            log_file_path(dir, "20260101", 0),
+            dir.join("ai-toolbox_20260101.log")
+        );
+        assert_eq!(
+            log_file_path(dir, "20260101", 2),
+            dir.join("ai-toolbox_20260101.2.log")
+        );
+    }
+
+    #[test]
+    fn parses_viewer_levels() {
+        assert_eq!(parse_level("ERROR"), LevelFilter::Error);
+        assert_eq!(parse_level("warn"), LevelFilter::Warn);
+        assert_eq!(parse_level("unknown"), LevelFilter::Debug);
+    }
+}
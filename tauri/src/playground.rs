@@ -0,0 +1,429 @@
+//! Chat Playground Module
+//!
+//! Sends a conversation to one OpenCode favorite provider and model so a combination
+//! can be checked inside the app before coding tools are pointed at it. The reply is
+//! streamed as `playground-delta` events while the command runs, and the full text is
+//! returned at the end.
+//!
+//! Providers using `@ai-sdk/anthropic` are called with the Anthropic Messages API,
+//! all others except Google with OpenAI chat completions. Base URL, API key and headers
+//! are resolved the same way as for the local gateway.
+
+use std::time::Instant;
+
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use tauri::{AppHandle, Emitter};
+
+use crate::coding::open_code::{list_opencode_favorite_providers, OpenCodeFavoriteProvider};
+use crate::gateway::provider_endpoint;
+use crate::{http_client, DbState};
+
+pub const PLAYGROUND_DELTA_EVENT: &str = "playground-delta";
+const REQUEST_TIMEOUT_SECS: u64 = 300;
+/// Anthropic requires `max_tokens`; capped further by the model's output limit
+const DEFAULT_MAX_TOKENS: i64 = 4096;
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WireFormat {
+    OpenAi,
+    Anthropic,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaygroundMessage {
+    /// "system", "user" or "assistant"
+    pub role: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaygroundDelta {
+    /// Echo of the id passed to `playground_send`, to tell concurrent sends apart
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaygroundReply {
+    pub content: String,
+    /// Milliseconds until the first text arrived
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_token_ms: Option<u64>,
+    pub total_ms: u64,
+    /// Token usage as reported by the provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<Value>,
+}
+
+/// One parsed piece of a streamed response
+#[derive(Debug, Clone, PartialEq)]
+enum StreamEvent {
+    Text(String),
+    Usage(Value),
+    Error(String),
+    Done,
+}
+
+/// Send `messages` to `model_id` of a favorite provider, streaming the reply as events
+#[tauri::command]
+pub async fn playground_send(
+    app: AppHandle,
+    state: tauri::State<'_, DbState>,
+    provider_id: String,
+    model_id: String,
+    messages: Vec<PlaygroundMessage>,
+    request_id: Option<String>,
+) -> Result<PlaygroundReply, String> {
+    if messages.is_empty() {
+        return Err("No messages to send".to_string());
+    }
+    let provider = list_opencode_favorite_providers(state.clone())
+        .await?
+        .into_iter()
+        .find(|provider| provider.provider_id == provider_id)
+        .ok_or_else(|| format!("Favorite provider '{}' not found", provider_id))?;
+    let format = match provider.npm.as_str() {
+        "@ai-sdk/anthropic" => WireFormat::Anthropic,
+        "@ai-sdk/google" => {
+            return Err("The playground supports OpenAI and Anthropic providers only".to_string())
+        }
+        _ => WireFormat::OpenAi,
+    };
+
+    let client = http_client::client_with_timeout(&state, REQUEST_TIMEOUT_SECS).await?;
+    let request = build_request(&client, &provider, format, &model_id, &messages)?;
+
+    let start_time = Instant::now();
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("HTTP {}: {}", status, error_message(&body)));
+    }
+    let is_stream = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.contains("text/event-stream"));
+
+    let mut reply = PlaygroundReply {
+        content: String::new(),
+        first_token_ms: None,
+        total_ms: 0,
+        usage: None,
+    };
+    let mut on_event = |event: StreamEvent| -> Result<bool, String> {
+        match event {
+            StreamEvent::Text(text) => {
+                reply
+                    .first_token_ms
+                    .get_or_insert_with(|| start_time.elapsed().as_millis() as u64);
+                reply.content.push_str(&text);
+                let _ = app.emit(
+                    PLAYGROUND_DELTA_EVENT,
+                    PlaygroundDelta {
+                        request_id: request_id.clone(),
+                        text,
+                    },
+                );
+            }
+            StreamEvent::Usage(usage) => merge_usage(&mut reply.usage, usage),
+            StreamEvent::Error(message) => return Err(message),
+            StreamEvent::Done => return Ok(false),
+        }
+        Ok(true)
+    };
+
+    if is_stream {
+        let mut stream = response.bytes_stream();
+        let mut buffer: Vec<u8> = Vec::new();
+        'read: while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| format!("Stream interrupted: {}", e))?;
+            buffer.extend_from_slice(&chunk);
+            while let Some(end) = buffer.iter().position(|byte| *byte == b'\n') {
+                let line: Vec<u8> = buffer.drain(..=end).collect();
+                let line = String::from_utf8_lossy(&line);
+                let Some(data) = line.trim().strip_prefix("data:") else {
+                    continue;
+                };
+                for event in parse_stream_data(format, data.trim()) {
+                    if !on_event(event)? {
+                        break 'read;
+                    }
+                }
+            }
+        }
+    } else {
+        // Some relays ignore `stream` and answer with a single JSON body
+        let body: Value = response
+            .json()
+            .await
+            .map_err(|e| format!("Invalid response: {}", e))?;
+        for event in parse_full_response(format, &body) {
+            on_event(event)?;
+        }
+    }
+
+    reply.total_ms = start_time.elapsed().as_millis() as u64;
+    Ok(reply)
+}
+
+fn build_request(
+    client: &reqwest::Client,
+    provider: &OpenCodeFavoriteProvider,
+    format: WireFormat,
+    model_id: &str,
+    messages: &[PlaygroundMessage],
+) -> Result<reqwest::RequestBuilder, String> {
+    let endpoint = provider_endpoint(provider)?;
+    let mut request = match format {
+        WireFormat::OpenAi => {
+            let mut request = client
+                .post(format!("{}/chat/completions", endpoint.base_url))
+                .json(&json!({
+                    "model": model_id,
+                    "messages": messages,
+                    "stream": true,
+                    "stream_options": { "include_usage": true },
+                }));
+            if let Some(api_key) = &endpoint.api_key {
+                request = request.bearer_auth(api_key);
+            }
+            request
+        }
+        WireFormat::Anthropic => {
+            let max_tokens = provider
+                .provider_config
+                .models
+                .get(model_id)
+                .and_then(|model| model.limit.as_ref())
+                .and_then(|limit| limit.output)
+                .map_or(DEFAULT_MAX_TOKENS, |output| output.min(DEFAULT_MAX_TOKENS));
+            let mut body = anthropic_body(model_id, messages);
+            body["max_tokens"] = json!(max_tokens);
+            let mut request = client
+                .post(format!("{}/messages", endpoint.base_url))
+                .header("anthropic-version", ANTHROPIC_VERSION)
+                .json(&body);
+            if let Some(api_key) = &endpoint.api_key {
+                request = request.header("x-api-key", api_key);
+            }
+            request
+        }
+    };
+    request = request.header(reqwest::header::ACCEPT, "text/event-stream");
+    for (name, value) in endpoint.headers {
+        request = request.header(name, value);
+    }
+    Ok(request)
+}
+
+/// Anthropic takes system prompts as a separate field, not as messages
+fn anthropic_body(model_id: &str, messages: &[PlaygroundMessage]) -> Value {
+    let system: Vec<&str> = messages
+        .iter()
+        .filter(|message| message.role == "system")
+        .map(|message| message.content.as_str())
+        .collect();
+    let conversation: Vec<&PlaygroundMessage> = messages
+        .iter()
+        .filter(|message| message.role != "system")
+        .collect();
+
+    let mut body = json!({
+        "model": model_id,
+        "messages": conversation,
+        "stream": true,
+    });
+    if !system.is_empty() {
+        body["system"] = json!(system.join("\n\n"));
+    }
+    body
+}
+
+fn parse_stream_data(format: WireFormat, data: &str) -> Vec<StreamEvent> {
+    if data == "[DONE]" {
+        return vec![StreamEvent::Done];
+    }
+    let Ok(value) = serde_json::from_str::<Value>(data) else {
+        return Vec::new();
+    };
+    if let Some(error) = value.get("error").filter(|error| !error.is_null()) {
+        return vec![StreamEvent::Error(error_text(error))];
+    }
+
+    let mut events = Vec::new();
+    match format {
+        WireFormat::OpenAi => {
+            if let Some(text) = value
+                .pointer("/choices/0/delta/content")
+                .and_then(Value::as_str)
+                .filter(|text| !text.is_empty())
+            {
+                events.push(StreamEvent::Text(text.to_string()));
+            }
+            if let Some(usage) = value.get("usage").filter(|usage| usage.is_object()) {
+                events.push(StreamEvent::Usage(usage.clone()));
+            }
+        }
+        WireFormat::Anthropic => match value.get("type").and_then(Value::as_str) {
+            Some("content_block_delta") => {
+                if let Some(text) = value
+                    .pointer("/delta/text")
+                    .and_then(Value::as_str)
+                    .filter(|text| !text.is_empty())
+                {
+                    events.push(StreamEvent::Text(text.to_string()));
+                }
+            }
+            Some("message_start") => {
+                if let Some(usage) = value.pointer("/message/usage") {
+                    events.push(StreamEvent::Usage(usage.clone()));
+                }
+            }
+            Some("message_delta") => {
+                if let Some(usage) = value.get("usage") {
+                    events.push(StreamEvent::Usage(usage.clone()));
+                }
+            }
+            Some("message_stop") => events.push(StreamEvent::Done),
+            _ => {}
+        },
+    }
+    events
+}
+
+fn parse_full_response(format: WireFormat, body: &Value) -> Vec<StreamEvent> {
+    if let Some(error) = body.get("error").filter(|error| !error.is_null()) {
+        return vec![StreamEvent::Error(error_text(error))];
+    }
+    let text = match format {
+        WireFormat::OpenAi => body
+            .pointer("/choices/0/message/content")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        WireFormat::Anthropic => body
+            .get("content")
+            .and_then(Value::as_array)
+            .map(|blocks| {
+                blocks
+                    .iter()
+                    .filter_map(|block| block.get("text").and_then(Value::as_str))
+                    .collect::<String>()
+            })
+            .unwrap_or_default(),
+    };
+
+    let mut events = vec![StreamEvent::Text(text)];
+    if let Some(usage) = body.get("usage").filter(|usage| usage.is_object()) {
+        events.push(StreamEvent::Usage(usage.clone()));
+    }
+    events
+}
+
+/// Anthropic reports input tokens at the start and output tokens at the end
+fn merge_usage(current: &mut Option<Value>, usage: Value) {
+    match (current.as_mut().and_then(Value::as_object_mut), usage) {
+        (Some(current), Value::Object(usage)) => current.extend(usage),
+        (_, usage) => *current = Some(usage),
+    }
+}
+
+fn error_text(error: &Value) -> String {
+    error
+        .get("message")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .unwrap_or_else(|| error.to_string())
+}
+
+/// Message of a JSON error body, or the body itself
+fn error_message(body: &str) -> String {
+    serde_json::from_str::<Map<String, Value>>(body)
+        .ok()
+        .and_then(|value| value.get("error").map(error_text))
+        .unwrap_or_else(|| body.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_openai_stream_chunks() {
+        assert_eq!(
+            parse_stream_data(
+                WireFormat::OpenAi,
+                r#"{"choices":[{"delta":{"content":"Hi"}}]}"#
+            ),
+            vec![StreamEvent::Text("Hi".to_string())]
+        );
+        assert_eq!(
+            parse_stream_data(
+                WireFormat::OpenAi,
+                r#"{"choices":[],"usage":{"prompt_tokens":3}}"#
+            ),
+            vec![StreamEvent::Usage(json!({ "prompt_tokens": 3 }))]
+        );
+        assert_eq!(
+            parse_stream_data(WireFormat::OpenAi, "[DONE]"),
+            vec![StreamEvent::Done]
+        );
+    }
+
+    #[test]
+    fn parses_anthropic_stream_events() {
+        assert_eq!(
+            parse_stream_data(
+                WireFormat::Anthropic,
+                r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hi"}}"#
+            ),
+            vec![StreamEvent::Text("Hi".to_string())]
+        );
+        assert_eq!(
+            parse_stream_data(
+                WireFormat::Anthropic,
+                r#"{"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#
+            ),
+            vec![StreamEvent::Error("Overloaded".to_string())]
+        );
+
+        let mut usage = None;
+        merge_usage(&mut usage, json!({ "input_tokens": 5, "output_tokens": 1 }));
+        merge_usage(&mut usage, json!({ "output_tokens": 9 }));
+        assert_eq!(
+            usage,
+            Some(json!({ "input_tokens": 5, "output_tokens": 9 }))
+        );
+    }
+
+    #[test]
+    fn moves_system_messages_out_of_anthropic_messages() {
+        let messages = vec![
+            PlaygroundMessage {
+                role: "system".to_string(),
+                content: "Be brief".to_string(),
+            },
+            PlaygroundMessage {
+                role: "user".to_string(),
+                content: "Hello".to_string(),
+            },
+        ];
+        let body = anthropic_body("claude", &messages);
+        assert_eq!(body["system"], json!("Be brief"));
+        assert_eq!(
+            body["messages"],
+            json!([{ "role": "user", "content": "Hello" }])
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Portable Mode Module
+//!
+//! When a file named `portable` sits next to the executable (next to the `.app` bundle on
+//! macOS), or the app is started with `--portable`, everything the app stores lives in a
+//! `data` directory beside it instead of the per-user app data directory:
+//! - `data/database`, `data/logs`, `data/skills` (default central repo), caches in `data/cache`
+//! - On Windows the WebView2 profile is kept in `data/webview` as well
+//!
+//! The OS keychain (secrets vault, app lock) and autostart entries stay per user.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tauri::{AppHandle, Manager, Runtime};
+
+/// Marker file enabling portable mode
+const PORTABLE_MARKER: &str = "portable";
+/// Command line flag enabling portable mode for one run
+const PORTABLE_FLAG: &str = "--portable";
+const PORTABLE_DATA_DIR: &str = "data";
+
+static PORTABLE_ROOT: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+/// Directory holding the executable, or the `.app` bundle on macOS
+fn install_dir() -> Option<PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    let exe_dir = exe.parent()?.to_path_buf();
+
+    // App.app/Contents/MacOS/binary -> directory containing App.app
+    if cfg!(target_os = "macos") {
+        if let Some(bundle) = exe_dir
+            .parent()
+            .and_then(Path::parent)
+            .filter(|p| p.extension().map_or(false, |ext| ext == "app"))
+        {
+            return bundle.parent().map(Path::to_path_buf);
+        }
+    }
+
+    // AppImage runs from a read-only mount; use the directory of the .AppImage file
+    if let Some(appimage) = std::env::var_os("APPIMAGE") {
+        return PathBuf::from(appimage).parent().map(Path::to_path_buf);
+    }
+
+    Some(exe_dir)
+}
+
+fn detect_portable_root() -> Option<PathBuf> {
+    let dir = install_dir()?;
+    let by_flag = std::env::args().skip(1).any(|arg| arg == PORTABLE_FLAG);
+    (by_flag || dir.join(PORTABLE_MARKER).is_file()).then(|| dir.join(PORTABLE_DATA_DIR))
+}
+
+/// Data directory of the portable installation, `None` when not portable
+pub fn portable_data_dir() -> Option<&'static Path> {
+    PORTABLE_ROOT.get_or_init(detect_portable_root).as_deref()
+}
+
+pub fn is_portable() -> bool {
+    portable_data_dir().is_some()
+}
+
+/// App data directory for code running without an `AppHandle` (logging, early startup)
+pub fn data_dir() -> Option<PathBuf> {
+    if let Some(dir) = portable_data_dir() {
+        return Some(dir.to_path_buf());
+    }
+    dirs::data_dir()
+        .map(|p| p.join("com.ai-toolbox"))
+        .or_else(|| dirs::home_dir().map(|p| p.join(".ai-toolbox")))
+}
+
+/// App data directory: the portable `data` directory or Tauri's `app_data_dir`
+pub fn app_data_dir<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<PathBuf> {
+    match portable_data_dir() {
+        Some(dir) => Ok(dir.to_path_buf()),
+        None => app.path().app_data_dir(),
+    }
+}
+
+/// App cache directory: `data/cache` when portable, otherwise Tauri's `app_cache_dir`
+pub fn app_cache_dir<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<PathBuf> {
+    match portable_data_dir() {
+        Some(dir) => Ok(dir.join("cache")),
+        None => app.path().app_cache_dir(),
+    }
+}
+
+/// Prepare the portable data directory before any window is created
+pub fn init() {
+    let Some(dir) = portable_data_dir() else {
+        return;
+    };
+    if let Err(e) = std::fs::create_dir_all(dir) {
+        eprintln!("无法创建便携数据目录 {:?}: {}", dir, e);
+        return;
+    }
+
+    // WebView2 reads its profile location from the environment
+    #[cfg(target_os = "windows")]
+    if std::env::var_os("WEBVIEW2_USER_DATA_FOLDER").is_none() {
+        std::env::set_var("WEBVIEW2_USER_DATA_FOLDER", dir.join("webview"));
+    }
+}
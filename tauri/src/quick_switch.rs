@@ -0,0 +1,292 @@
+//! Quick Switch Module
+//!
+//! A small always-on-top palette window (label `quick-switch`) opened from the tray or a
+//! global hotkey. It fuzzy-searches Claude Code providers, OpenCode models, Codex providers
+//! and skill/tool sync toggles, and applies the picked entry through the same functions
+//! the tray menu uses.
+
+use crate::coding::claude_code::tray_support as claude_tray;
+use crate::coding::codex::tray_support as codex_tray;
+use crate::coding::open_code::tray_support as opencode_tray;
+use crate::coding::skills::tray_support as skills_tray;
+use crate::tray;
+use serde::Serialize;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, Runtime, WebviewUrl, WebviewWindowBuilder};
+
+/// Label of the palette window
+pub const QUICK_SWITCH_WINDOW: &str = "quick-switch";
+/// Event sent to the palette window each time it is shown
+const QUICK_SWITCH_SHOWN_EVENT: &str = "quick-switch-shown";
+/// Separator between skill id and tool key in a "skill_tool" item id
+const SKILL_TOOL_SEPARATOR: char = '\x01';
+/// Results returned for an empty query / per search
+const DEFAULT_RESULT_LIMIT: usize = 50;
+
+/// Entries loaded when the palette was last opened; searches run against this list
+static ITEMS_CACHE: Mutex<Vec<QuickSwitchItem>> = Mutex::new(Vec::new());
+
+/// Entry of the quick-switch palette
+#[derive(Debug, Clone, Serialize)]
+pub struct QuickSwitchItem {
+    /// "claude_provider", "opencode_main", "opencode_small", "codex_provider" or "skill_tool"
+    pub kind: String,
+    pub id: String,
+    pub label: String,
+    /// Applied provider / model, or skill synced to the tool
+    pub is_selected: bool,
+}
+
+/// Show the palette window, creating it on first use
+pub fn open_quick_switch<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let window = match app.get_webview_window(QUICK_SWITCH_WINDOW) {
+        Some(window) => window,
+        None => WebviewWindowBuilder::new(
+            app,
+            QUICK_SWITCH_WINDOW,
+            WebviewUrl::App("index.html?window=quick-switch".into()),
+        )
+        .title("Quick Switch")
+        .inner_size(560.0, 420.0)
+        .resizable(false)
+        .decorations(false)
+        .always_on_top(true)
+        .skip_taskbar(true)
+        .center()
+        .visible(false)
+        .build()
+        .map_err(|e| format!("Failed to create quick switch window: {}", e))?,
+    };
+
+    let _ = window.center();
+    window.show().map_err(|e| e.to_string())?;
+    let _ = window.set_focus();
+    let _ = window.emit(QUICK_SWITCH_SHOWN_EVENT, ());
+    Ok(())
+}
+
+async fn load_items<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<QuickSwitchItem>, String> {
+    let mut items = Vec::new();
+
+    let providers = claude_tray::get_claude_code_tray_data(app).await?;
+    items.extend(
+        providers
+            .items
+            .into_iter()
+            .filter(|item| !item.is_disabled)
+            .map(|item| QuickSwitchItem {
+                kind: "claude_provider".to_string(),
+                id: item.id,
+                label: item.display_name,
+                is_selected: item.is_selected,
+            }),
+    );
+
+    let (main, small) = opencode_tray::get_opencode_tray_model_data(app).await?;
+    for (kind, data) in [("opencode_main", main), ("opencode_small", small)] {
+        items.extend(data.items.into_iter().map(|item| QuickSwitchItem {
+            kind: kind.to_string(),
+            id: item.id,
+            label: item.display_name,
+            is_selected: item.is_selected,
+        }));
+    }
+
+    let codex = codex_tray::get_codex_tray_data(app).await?;
+    items.extend(
+        codex
+            .items
+            .into_iter()
+            .filter(|item| !item.is_disabled)
+            .map(|item| QuickSwitchItem {
+                kind: "codex_provider".to_string(),
+                id: item.id,
+                label: item.display_name,
+                is_selected: item.is_selected,
+            }),
+    );
+
+    // Skills are optional: a broken skills store should not hide the other entries
+    match skills_tray::get_skills_tray_data(app).await {
+        Ok(skills) => {
+            for skill in skills.items {
+                for tool in skill.tools.into_iter().filter(|tool| tool.is_installed) {
+                    items.push(QuickSwitchItem {
+                        kind: "skill_tool".to_string(),
+                        id: format!("{}{}{}", skill.id, SKILL_TOOL_SEPARATOR, tool.tool_key),
+                        label: format!("{} / {}", skill.display_name, tool.display_name),
+                        is_selected: tool.is_synced,
+                    });
+                }
+            }
+        }
+        Err(e) => log::warn!("Failed to load skills for quick switch: {}", e),
+    }
+
+    Ok(items)
+}
+
+/// Fuzzy match score of `query` against `text`, `None` when not all query characters
+/// appear in order
+///
+/// Consecutive matches and matches at word starts score higher, so "gpt4" ranks
+/// "OpenAI / gpt-4o" above "groq / llama-3-70b-tool-use-preview".
+fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0i64;
+    let mut position = 0usize;
+    let mut previous_match: Option<usize> = None;
+
+    for query_char in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let offset = text[position..].iter().position(|c| *c == query_char)?;
+        let index = position + offset;
+
+        score += 1;
+        if previous_match.is_some_and(|previous| previous + 1 == index) {
+            score += 5;
+        }
+        if index == 0 || !text[index - 1].is_alphanumeric() {
+            score += 3;
+        }
+        score -= offset.min(10) as i64 / 2;
+
+        previous_match = Some(index);
+        position = index + 1;
+    }
+
+    Some(score)
+}
+
+/// Items matching `query`, best first (the cached order for an empty query)
+fn search_items(items: &[QuickSwitchItem], query: &str, limit: usize) -> Vec<QuickSwitchItem> {
+    let query = query.trim();
+    if query.is_empty() {
+        return items.iter().take(limit).cloned().collect();
+    }
+
+    let mut scored: Vec<(i64, &QuickSwitchItem)> = items
+        .iter()
+        .filter_map(|item| fuzzy_score(query, &item.label).map(|score| (score, item)))
+        .collect();
+    // Stable sort keeps the cached order between equal scores
+    scored.sort_by(|a, b| b.0.cmp(&a.0));
+    scored
+        .into_iter()
+        .take(limit)
+        .map(|(_, item)| item.clone())
+        .collect()
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Open the quick-switch palette window
+///
+/// Async so the window is not created from a blocking command (deadlocks on Windows).
+#[tauri::command]
+pub async fn open_quick_switch_window(app: AppHandle) -> Result<(), String> {
+    open_quick_switch(&app)
+}
+
+/// Hide the palette window (Escape, focus lost or after applying an entry)
+#[tauri::command]
+pub fn hide_quick_switch_window(app: AppHandle) -> Result<(), String> {
+    if let Some(window) = app.get_webview_window(QUICK_SWITCH_WINDOW) {
+        window.hide().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Fuzzy-search the palette entries
+///
+/// `refresh` reloads providers, models and skills (done once when the palette opens);
+/// otherwise the entries loaded last time are searched.
+#[tauri::command]
+pub async fn quick_switch_search(
+    app: AppHandle,
+    query: String,
+    refresh: Option<bool>,
+    limit: Option<usize>,
+) -> Result<Vec<QuickSwitchItem>, String> {
+    let cached = ITEMS_CACHE
+        .lock()
+        .map(|guard| guard.clone())
+        .unwrap_or_default();
+    let items = if refresh.unwrap_or(false) || cached.is_empty() {
+        let items = load_items(&app).await?;
+        if let Ok(mut guard) = ITEMS_CACHE.lock() {
+            *guard = items.clone();
+        }
+        items
+    } else {
+        cached
+    };
+
+    Ok(search_items(
+        &items,
+        &query,
+        limit.unwrap_or(DEFAULT_RESULT_LIMIT),
+    ))
+}
+
+/// Apply an entry picked in the palette
+#[tauri::command]
+pub async fn apply_quick_switch_item(
+    app: AppHandle,
+    kind: String,
+    id: String,
+) -> Result<(), String> {
+    match kind.as_str() {
+        "claude_provider" => claude_tray::apply_claude_code_provider(&app, &id).await?,
+        "opencode_main" => opencode_tray::apply_opencode_model(&app, "main", &id).await?,
+        "opencode_small" => opencode_tray::apply_opencode_model(&app, "small", &id).await?,
+        "codex_provider" => codex_tray::apply_codex_provider(&app, &id).await?,
+        "skill_tool" => {
+            let (skill_id, tool_key) = id
+                .split_once(SKILL_TOOL_SEPARATOR)
+                .ok_or_else(|| format!("Invalid skill item id: {}", id))?;
+            skills_tray::apply_skills_tool_toggle(&app, skill_id, tool_key).await?
+        }
+        _ => return Err(format!("Unknown quick switch item kind: {}", kind)),
+    }
+    tray::schedule_tray_refresh(&app);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(label: &str) -> QuickSwitchItem {
+        QuickSwitchItem {
+            kind: "opencode_main".to_string(),
+            id: label.to_string(),
+            label: label.to_string(),
+            is_selected: false,
+        }
+    }
+
+    #[test]
+    fn fuzzy_score_requires_ordered_characters() {
+        assert!(fuzzy_score("gpt4", "OpenAI / gpt-4o").is_some());
+        assert!(fuzzy_score("4gpt", "OpenAI / gpt-4o").is_none());
+        assert!(fuzzy_score("", "anything").is_some());
+    }
+
+    #[test]
+    fn search_ranks_word_start_and_consecutive_matches_first() {
+        let items = vec![
+            item("groq / llama-3-70b-tool-use-preview"),
+            item("OpenAI / gpt-4o"),
+            item("Anthropic / claude-sonnet"),
+        ];
+        let results = search_items(&items, "gpt4", 10);
+        assert_eq!(results[0].label, "OpenAI / gpt-4o");
+        assert!(results
+            .iter()
+            .all(|item| item.label != "Anthropic / claude-sonnet"));
+
+        assert_eq!(search_items(&items, "  ", 2).len(), 2);
+    }
+}
@@ -0,0 +1,289 @@
+//! Global Search Module
+//!
+//! `search_all` backs the universal search box: one query runs against OpenCode
+//! favorite providers and their models, Claude Code / Codex providers, MCP servers
+//! and skills, and returns typed hits grouped by kind.
+//!
+//! Text fields are matched through the full-text indexes defined by the
+//! `search_indexes_v1` migration. Model ids live as keys inside the provider
+//! config, which an index cannot cover, so models are matched in memory.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::coding::db_extract_id;
+use crate::db_migration::search_indexes_v1::SEARCH_FIELDS;
+use crate::DbState;
+
+const DEFAULT_LIMIT_PER_KIND: usize = 10;
+const MAX_LIMIT_PER_KIND: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SearchHitKind {
+    OpencodeProvider,
+    OpencodeModel,
+    ClaudeProvider,
+    CodexProvider,
+    McpServer,
+    Skill,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHit {
+    pub kind: SearchHitKind,
+    /// Id the owning page uses for the item (provider id for OpenCode, model id for models)
+    pub id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// OpenCode provider a model belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// Relevance within the hit's kind
+    pub score: f64,
+}
+
+/// Table searched through its full-text indexes
+struct IndexedTarget {
+    kind: SearchHitKind,
+    table: &'static str,
+}
+
+/// Result groups in the order they are returned
+const INDEXED_TARGETS: &[IndexedTarget] = &[
+    IndexedTarget {
+        kind: SearchHitKind::OpencodeProvider,
+        table: "opencode_favorite_provider",
+    },
+    IndexedTarget {
+        kind: SearchHitKind::ClaudeProvider,
+        table: "claude_provider",
+    },
+    IndexedTarget {
+        kind: SearchHitKind::CodexProvider,
+        table: "codex_provider",
+    },
+    IndexedTarget {
+        kind: SearchHitKind::McpServer,
+        table: "mcp_server",
+    },
+    IndexedTarget {
+        kind: SearchHitKind::Skill,
+        table: "skill",
+    },
+];
+
+/// Search providers, models, MCP servers and skills in one call
+#[tauri::command]
+pub async fn search_all(
+    state: tauri::State<'_, DbState>,
+    query: String,
+    limit: Option<usize>,
+) -> Result<Vec<SearchHit>, String> {
+    let query = query.trim().to_string();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let limit = limit
+        .unwrap_or(DEFAULT_LIMIT_PER_KIND)
+        .clamp(1, MAX_LIMIT_PER_KIND);
+    let db = state.db();
+
+    let mut hits = Vec::new();
+    for target in INDEXED_TARGETS {
+        let records = search_table(&db, target.table, &query, limit).await?;
+        hits.extend(
+            records
+                .iter()
+                .map(|record| indexed_hit(target.kind, record)),
+        );
+
+        if target.kind == SearchHitKind::OpencodeProvider {
+            hits.extend(search_models(&db, &query, limit).await?);
+        }
+    }
+
+    Ok(hits)
+}
+
+async fn search_table(
+    db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
+    table: &str,
+    query: &str,
+    limit: usize,
+) -> Result<Vec<Value>, String> {
+    let fields: Vec<&str> = SEARCH_FIELDS
+        .iter()
+        .filter(|(field_table, _)| *field_table == table)
+        .map(|(_, field)| *field)
+        .collect();
+    let conditions: Vec<String> = fields
+        .iter()
+        .enumerate()
+        .map(|(reference, field)| format!("{} @{}@ $query", field, reference))
+        .collect();
+    let score: Vec<String> = (0..fields.len())
+        .map(|reference| format!("(search::score({}) ?? 0)", reference))
+        .collect();
+
+    let statement = format!(
+        "SELECT *, type::string(id) as id, {} AS search_score FROM {} WHERE {} ORDER BY search_score DESC LIMIT {}",
+        score.join(" + "),
+        table,
+        conditions.join(" OR "),
+        limit
+    );
+
+    db.query(statement)
+        .bind(("query", query.to_string()))
+        .await
+        .map_err(|e| format!("Failed to search {}: {}", table, e))?
+        .take(0)
+        .map_err(|e| format!("Failed to read {} search results: {}", table, e))
+}
+
+fn indexed_hit(kind: SearchHitKind, record: &Value) -> SearchHit {
+    let text = |pointer: &str| {
+        record
+            .pointer(pointer)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+    let score = record
+        .get("search_score")
+        .and_then(Value::as_f64)
+        .unwrap_or_default();
+
+    let (id, title, subtitle) = match kind {
+        SearchHitKind::OpencodeProvider => {
+            let provider_id = text("/provider_id").unwrap_or_default();
+            let title = text("/provider_config/name").unwrap_or_else(|| provider_id.clone());
+            (provider_id, title, text("/base_url"))
+        }
+        SearchHitKind::ClaudeProvider | SearchHitKind::CodexProvider => (
+            db_extract_id(record),
+            text("/name").unwrap_or_default(),
+            text("/notes"),
+        ),
+        // MCP servers and skills
+        _ => (
+            db_extract_id(record),
+            text("/name").unwrap_or_default(),
+            text("/description").or_else(|| text("/notes")),
+        ),
+    };
+
+    SearchHit {
+        kind,
+        id,
+        title,
+        subtitle,
+        parent_id: None,
+        score,
+    }
+}
+
+/// Match model ids and names of OpenCode favorite providers
+async fn search_models(
+    db: &surrealdb::Surreal<surrealdb::engine::local::Db>,
+    query: &str,
+    limit: usize,
+) -> Result<Vec<SearchHit>, String> {
+    let records: Vec<Value> = db
+        .query("SELECT provider_id, provider_config FROM opencode_favorite_provider")
+        .await
+        .map_err(|e| format!("Failed to query favorite providers: {}", e))?
+        .take(0)
+        .map_err(|e| format!("Failed to read favorite providers: {}", e))?;
+
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .map(|term| term.to_lowercase())
+        .collect();
+    let mut hits = Vec::new();
+    for record in &records {
+        let provider_id = record
+            .get("provider_id")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let Some(models) = record
+            .pointer("/provider_config/models")
+            .and_then(Value::as_object)
+        else {
+            continue;
+        };
+
+        for (model_id, model) in models {
+            let name = model.get("name").and_then(Value::as_str);
+            let score = model_match_score(&terms, model_id, name);
+            if score > 0.0 {
+                hits.push(SearchHit {
+                    kind: SearchHitKind::OpencodeModel,
+                    id: model_id.clone(),
+                    title: name.unwrap_or(model_id).to_string(),
+                    subtitle: Some(provider_id.to_string()),
+                    parent_id: Some(provider_id.to_string()),
+                    score,
+                });
+            }
+        }
+    }
+
+    hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+    hits.truncate(limit);
+    Ok(hits)
+}
+
+/// Every term must appear in the model id or name; terms starting a word rank higher
+fn model_match_score(terms: &[String], model_id: &str, name: Option<&str>) -> f64 {
+    let haystack = format!("{} {}", model_id, name.unwrap_or_default()).to_lowercase();
+    let words: Vec<&str> = haystack
+        .split(|c: char| !c.is_alphanumeric() && c != '.')
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    let mut score = 0.0;
+    for term in terms {
+        if words.iter().any(|word| word.starts_with(term.as_str())) {
+            score += 2.0;
+        } else if haystack.contains(term.as_str()) {
+            score += 1.0;
+        } else {
+            return 0.0;
+        }
+    }
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(query: &str) -> Vec<String> {
+        query.split_whitespace().map(str::to_lowercase).collect()
+    }
+
+    #[test]
+    fn model_match_requires_every_term() {
+        assert!(model_match_score(&terms("claude sonnet"), "claude-sonnet-4", None) > 0.0);
+        assert_eq!(
+            model_match_score(&terms("claude opus"), "claude-sonnet-4", None),
+            0.0
+        );
+        assert!(
+            model_match_score(&terms("Sonnet"), "anthropic/model-x", Some("Claude Sonnet")) > 0.0
+        );
+    }
+
+    #[test]
+    fn word_prefix_matches_rank_above_substrings() {
+        let prefix = model_match_score(&terms("gpt"), "gpt-5", None);
+        let substring = model_match_score(&terms("pt"), "gpt-5", None);
+        assert!(prefix > substring);
+        assert!(substring > 0.0);
+    }
+}
@@ -0,0 +1,318 @@
+//! Secrets Vault Module
+//!
+//! Named secrets shared by every feature that writes credentials somewhere:
+//! - Values live in the OS keychain; the database only keeps an index of the names
+//!   (table `secret`) because keychains cannot be enumerated
+//! - Config values reference a secret as `{{secret:NAME}}`, also inside a longer value
+//!   (e.g. `Bearer {{secret:API_KEY}}`)
+//! - References are resolved only when a config is applied: Claude Code and Codex
+//!   provider configs, MCP `env` / `headers`, SSH passwords and passphrases, and the
+//!   WebDAV password
+//! - Features that keep their own credentials in the keychain (SSH connections, skill
+//!   repo credentials) use the same entry helpers under their own service name
+
+use crate::coding::db_id::db_record_id;
+use crate::db::DbState;
+use crate::db_timestamp::{with_datetimes, Timestamp};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::OnceLock;
+
+/// Keychain service name; the account is the secret name
+const KEYCHAIN_SERVICE: &str = "ai-toolbox.secrets";
+
+/// Index record stored in `secret:`NAME``
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SecretRecord {
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default, deserialize_with = "crate::db_timestamp::lenient")]
+    updated_at: Option<Timestamp>,
+}
+
+/// Secret as shown in the vault (never includes the value)
+#[derive(Debug, Clone, Serialize)]
+pub struct SecretInfo {
+    pub name: String,
+    pub description: String,
+    /// Whether the OS keychain holds a value for the secret
+    pub has_value: bool,
+    pub updated_at: Option<Timestamp>,
+}
+
+/// Matches `{{secret:NAME}}`, allowing spaces inside the braces
+pub fn secret_ref_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"\{\{\s*secret:([A-Za-z0-9_.\-]+)\s*\}\}").unwrap())
+}
+
+/// Secret names may only use letters, digits, '_', '.' and '-'
+pub fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+fn validate_name(name: &str) -> Result<&str, String> {
+    let name = name.trim();
+    if is_valid_name(name) {
+        Ok(name)
+    } else {
+        Err(format!(
+            "Invalid secret name '{}': use letters, digits, '_', '.' or '-'",
+            name
+        ))
+    }
+}
+
+fn keychain_entry(service: &str, name: &str) -> Result<keyring::Entry, String> {
+    keyring::Entry::new(service, name).map_err(|e| format!("Failed to open keychain entry: {}", e))
+}
+
+/// Read a keychain entry of any service
+pub fn read_entry(service: &str, name: &str) -> Result<Option<String>, String> {
+    match keychain_entry(service, name)?.get_password() {
+        Ok(secret) => Ok(Some(secret)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(format!(
+            "Failed to read secret '{}' from keychain: {}",
+            name, e
+        )),
+    }
+}
+
+/// Delete a keychain entry of any service; a missing entry is not an error
+pub fn delete_entry(service: &str, name: &str) -> Result<(), String> {
+    match keychain_entry(service, name)?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(format!(
+            "Failed to delete secret '{}' from keychain: {}",
+            name, e
+        )),
+    }
+}
+
+/// Write a keychain entry of any service; an empty value removes it
+pub fn write_entry(service: &str, name: &str, value: &str) -> Result<(), String> {
+    if value.is_empty() {
+        return delete_entry(service, name);
+    }
+    keychain_entry(service, name)?
+        .set_password(value)
+        .map_err(|e| format!("Failed to save secret '{}' to keychain: {}", name, e))
+}
+
+/// Read a secret value from the keychain
+pub fn load_secret(name: &str) -> Result<Option<String>, String> {
+    read_entry(KEYCHAIN_SERVICE, name)
+}
+
+/// Write a secret value to the keychain; an empty value removes it
+pub fn store_secret(name: &str, value: &str) -> Result<(), String> {
+    let name = validate_name(name)?;
+    write_entry(KEYCHAIN_SERVICE, name, value)
+}
+
+/// Names of the secrets referenced in a text
+pub fn referenced_secrets(text: &str) -> Vec<String> {
+    let mut names: Vec<String> = secret_ref_regex()
+        .captures_iter(text)
+        .map(|caps| caps[1].to_string())
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Replace the secret references in a text using `lookup`
+pub fn resolve_text_with(
+    text: &str,
+    lookup: &dyn Fn(&str) -> Result<Option<String>, String>,
+) -> Result<String, String> {
+    if !secret_ref_regex().is_match(text) {
+        return Ok(text.to_string());
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for caps in secret_ref_regex().captures_iter(text) {
+        let whole = caps.get(0).unwrap();
+        let secret =
+            lookup(&caps[1])?.ok_or_else(|| format!("Secret '{}' is not set", &caps[1]))?;
+        out.push_str(&text[last..whole.start()]);
+        out.push_str(&secret);
+        last = whole.end();
+    }
+    out.push_str(&text[last..]);
+    Ok(out)
+}
+
+/// Replace the secret references in a text with the keychain values
+pub fn resolve_text(text: &str) -> Result<String, String> {
+    resolve_text_with(text, &load_secret)
+}
+
+fn resolve_value_with(
+    value: &Value,
+    lookup: &dyn Fn(&str) -> Result<Option<String>, String>,
+) -> Result<Value, String> {
+    Ok(match value {
+        Value::String(text) => Value::String(resolve_text_with(text, lookup)?),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| resolve_value_with(item, lookup))
+                .collect::<Result<_, _>>()?,
+        ),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, item)| Ok((key.clone(), resolve_value_with(item, lookup)?)))
+                .collect::<Result<_, String>>()?,
+        ),
+        other => other.clone(),
+    })
+}
+
+/// Replace the secret references in every string of a JSON value
+pub fn resolve_value(value: &Value) -> Result<Value, String> {
+    resolve_value_with(value, &load_secret)
+}
+
+async fn load_index(db_state: &DbState) -> Result<Vec<SecretRecord>, String> {
+    let db = db_state.db();
+    let mut result = db
+        .query("SELECT * OMIT id FROM secret ORDER BY name")
+        .await
+        .map_err(|e| format!("Failed to query secrets: {}", e))?;
+    let records: Vec<Value> = result
+        .take(0)
+        .map_err(|e| format!("Failed to parse secrets: {}", e))?;
+    Ok(records
+        .into_iter()
+        .filter_map(|record| serde_json::from_value(record).ok())
+        .collect())
+}
+
+/// Add or refresh a name in the vault index
+pub async fn register_secret(
+    db_state: &DbState,
+    name: &str,
+    description: Option<String>,
+) -> Result<(), String> {
+    let name = validate_name(name)?;
+    let existing = load_index(db_state)
+        .await?
+        .into_iter()
+        .find(|record| record.name == name);
+    let record = SecretRecord {
+        name: name.to_string(),
+        description: description
+            .or(existing.map(|record| record.description))
+            .unwrap_or_default(),
+        updated_at: Some(Timestamp::now()),
+    };
+    let data =
+        serde_json::to_value(&record).map_err(|e| format!("Failed to serialize secret: {}", e))?;
+    db_state
+        .db()
+        .query(&format!(
+            "UPSERT {} CONTENT $data",
+            db_record_id("secret", name)
+        ))
+        .bind(("data", with_datetimes(data)))
+        .await
+        .map_err(|e| format!("Failed to save secret: {}", e))?;
+    Ok(())
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// List the secrets in the vault and whether the keychain holds their values
+#[tauri::command]
+pub async fn list_secrets(state: tauri::State<'_, DbState>) -> Result<Vec<SecretInfo>, String> {
+    Ok(load_index(&state)
+        .await?
+        .into_iter()
+        .map(|record| SecretInfo {
+            has_value: matches!(load_secret(&record.name), Ok(Some(_))),
+            name: record.name,
+            description: record.description,
+            updated_at: record.updated_at,
+        })
+        .collect())
+}
+
+/// Create or update a secret
+///
+/// An empty value keeps the stored one, so the description can be edited alone.
+/// Configs already applied keep the old value until they are applied again.
+#[tauri::command]
+pub async fn set_secret(
+    state: tauri::State<'_, DbState>,
+    name: String,
+    value: String,
+    description: Option<String>,
+) -> Result<(), String> {
+    let name = validate_name(&name)?;
+    if !value.is_empty() {
+        store_secret(name, &value)?;
+    }
+    register_secret(&state, name, description).await
+}
+
+/// Delete a secret from the keychain and the vault index
+#[tauri::command]
+pub async fn delete_secret(state: tauri::State<'_, DbState>, name: String) -> Result<(), String> {
+    let name = validate_name(&name)?;
+    store_secret(name, "")?;
+    state
+        .db()
+        .query(&format!("DELETE {}", db_record_id("secret", name)))
+        .await
+        .map_err(|e| format!("Failed to delete secret: {}", e))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn lookup(name: &str) -> Result<Option<String>, String> {
+        Ok((name != "MISSING").then(|| format!("<{}>", name)))
+    }
+
+    #[test]
+    fn resolves_references_in_nested_values() {
+        let config = json!({
+            "env": { "ANTHROPIC_AUTH_TOKEN": "{{secret:ANTHROPIC_KEY}}", "MODE": "ro" },
+            "headers": ["Bearer {{ secret:API_KEY }}"],
+            "timeout": 30
+        });
+        let resolved = resolve_value_with(&config, &lookup).unwrap();
+        assert_eq!(resolved["env"]["ANTHROPIC_AUTH_TOKEN"], "<ANTHROPIC_KEY>");
+        assert_eq!(resolved["env"]["MODE"], "ro");
+        assert_eq!(resolved["headers"][0], "Bearer <API_KEY>");
+        assert_eq!(resolved["timeout"], 30);
+    }
+
+    #[test]
+    fn missing_secret_is_an_error() {
+        let err = resolve_text_with("{{secret:MISSING}}", &lookup).unwrap_err();
+        assert!(err.contains("MISSING"));
+        assert_eq!(resolve_text_with("plain", &lookup).unwrap(), "plain");
+    }
+
+    #[test]
+    fn lists_referenced_names_once() {
+        assert_eq!(
+            referenced_secrets("{{secret:B}} {{secret:A}} {{secret:B}}"),
+            vec!["A", "B"]
+        );
+        assert!(!is_valid_name("bad name"));
+    }
+}
@@ -1,10 +1,14 @@
-use super::types::{default_sidebar_hidden_by_page, AppSettings, S3Config, WebDAVConfig};
+use super::types::{
+    default_sidebar_hidden_by_page, AppSettings, HotkeyBinding, ProxyRule, S3Config, WebDAVConfig,
+};
 /**
  * Settings Adapter Layer
  *
  * Provides fault-tolerant conversion between database JSON and Rust types.
  * This layer ensures backward compatibility and eliminates version conflicts.
  */
+use crate::app_lock;
+use crate::db_timestamp::Timestamp;
 use serde_json::{json, Value};
 
 /// Convert database JSON Value to AppSettings with fault tolerance
@@ -20,35 +24,74 @@ pub fn from_db_value(value: Value) -> AppSettings {
         webdav: get_webdav(&value),
         s3: get_s3(&value),
 
-        last_backup_time: get_opt_str(&value, "last_backup_time"),
+        last_backup_time: Timestamp::from_db(&value, "last_backup_time"),
         launch_on_startup: get_bool(&value, "launch_on_startup", true),
         minimize_to_tray_on_close: get_bool(&value, "minimize_to_tray_on_close", true),
         start_minimized: get_bool(&value, "start_minimized", false),
+        confirm_before_quit: get_bool(&value, "confirm_before_quit", false),
         proxy_mode: get_proxy_mode(&value),
         proxy_url: get_str(&value, "proxy_url", ""),
+        proxy_rules: get_proxy_rules(&value),
         theme: get_str(&value, "theme", "system"),
         auto_backup_enabled: get_bool(&value, "auto_backup_enabled", false),
         auto_backup_interval_days: get_u32(&value, "auto_backup_interval_days", 7),
         auto_backup_max_keep: get_u32(&value, "auto_backup_max_keep", 10),
         last_auto_backup_time: get_opt_str(&value, "last_auto_backup_time"),
         auto_check_update: get_bool(&value, "auto_check_update", true),
+        update_channel: get_update_channel(&value),
+        update_check_interval: get_update_check_interval(&value),
         visible_tabs: get_string_array(
             &value,
             "visible_tabs",
             &["opencode", "claudecode", "codex", "openclaw", "ssh", "wsl"],
         ),
         sidebar_hidden_by_page: get_sidebar_hidden_by_page(&value),
+        hotkeys: get_hotkeys(&value),
+        audit_log_retention_days: get_u32(
+            &value,
+            "audit_log_retention_days",
+            crate::audit_log::DEFAULT_RETENTION_DAYS,
+        ),
+        gateway_enabled: get_bool(&value, "gateway_enabled", false),
+        gateway_port: get_u32(&value, "gateway_port", crate::gateway::DEFAULT_PORT),
+        gateway_api_key: get_str(&value, "gateway_api_key", ""),
+        gateway_fallback_models: get_string_array(&value, "gateway_fallback_models", &[]),
     }
 }
 
 /// Convert AppSettings to database JSON Value
-pub fn to_db_value(settings: &AppSettings) -> Value {
+///
+/// Fails when a new credential would have to be encrypted while the app is locked.
+pub fn to_db_value(settings: &AppSettings) -> Result<Value, String> {
+    to_db_value_with(settings, app_lock::encrypt_field)
+}
+
+/// Convert AppSettings to database JSON Value, passing credentials through `encrypt`
+pub fn to_db_value_with(
+    settings: &AppSettings,
+    encrypt: impl Fn(&str) -> Result<String, String>,
+) -> Result<Value, String> {
     // Use serde to serialize the entire structure
     // This ensures all types are properly converted
-    serde_json::to_value(settings).unwrap_or_else(|e| {
+    let mut value = serde_json::to_value(settings).unwrap_or_else(|e| {
         eprintln!("Failed to serialize settings: {}", e);
         json!({})
-    })
+    });
+
+    // Credentials are encrypted at rest when the app lock is enabled
+    for (section, key) in [
+        ("webdav", "password"),
+        ("s3", "access_key"),
+        ("s3", "secret_key"),
+    ] {
+        if let Some(field) = value.get_mut(section).and_then(|v| v.get_mut(key)) {
+            if let Some(plain) = field.as_str() {
+                *field = json!(encrypt(plain)?);
+            }
+        }
+    }
+    // gateway_api_key stays in plaintext: the local gateway checks it while the app is locked
+    Ok(value)
 }
 
 // Helper functions for safe field extraction
@@ -78,6 +121,24 @@ fn get_proxy_mode(value: &Value) -> String {
         .to_string()
 }
 
+fn get_update_channel(value: &Value) -> String {
+    value
+        .get("update_channel")
+        .and_then(|v| v.as_str())
+        .filter(|channel| matches!(*channel, "stable" | "beta" | "nightly"))
+        .unwrap_or("stable")
+        .to_string()
+}
+
+fn get_update_check_interval(value: &Value) -> String {
+    value
+        .get("update_check_interval")
+        .and_then(|v| v.as_str())
+        .filter(|interval| matches!(*interval, "daily" | "weekly" | "never"))
+        .unwrap_or("daily")
+        .to_string()
+}
+
 fn get_u32(value: &Value, key: &str, default: u32) -> u32 {
     value
         .get(key)
@@ -105,7 +166,7 @@ fn get_webdav(value: &Value) -> WebDAVConfig {
         WebDAVConfig {
             url: get_str(webdav, "url", ""),
             username: get_str(webdav, "username", ""),
-            password: get_str(webdav, "password", ""),
+            password: app_lock::decrypt_field(&get_str(webdav, "password", "")),
             remote_path: get_str(webdav, "remote_path", ""),
             host_label: get_str(webdav, "host_label", ""),
         }
@@ -119,8 +180,8 @@ fn get_s3(value: &Value) -> S3Config {
 
     if let Some(s3) = s3 {
         S3Config {
-            access_key: get_str(s3, "access_key", ""),
-            secret_key: get_str(s3, "secret_key", ""),
+            access_key: app_lock::decrypt_field(&get_str(s3, "access_key", "")),
+            secret_key: app_lock::decrypt_field(&get_str(s3, "secret_key", "")),
             bucket: get_str(s3, "bucket", ""),
             region: get_str(s3, "region", ""),
             prefix: get_str(s3, "prefix", ""),
@@ -136,6 +197,47 @@ fn get_s3(value: &Value) -> S3Config {
     }
 }
 
+fn get_hotkeys(value: &Value) -> Vec<HotkeyBinding> {
+    value
+        .get("hotkeys")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|item| {
+                    let action = item.get("action")?.as_str()?;
+                    Some(HotkeyBinding {
+                        action: action.to_string(),
+                        shortcut: get_str(item, "shortcut", ""),
+                        enabled: get_bool(item, "enabled", true),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn get_proxy_rules(value: &Value) -> Vec<ProxyRule> {
+    value
+        .get("proxy_rules")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|item| {
+                    let pattern = item.get("pattern")?.as_str()?.trim();
+                    let action = item.get("action")?.as_str()?;
+                    if pattern.is_empty() || !matches!(action, "proxy" | "direct") {
+                        return None;
+                    }
+                    Some(ProxyRule {
+                        pattern: pattern.to_string(),
+                        action: action.to_string(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 fn get_sidebar_hidden_by_page(value: &Value) -> std::collections::HashMap<String, bool> {
     let mut sidebar_hidden = default_sidebar_hidden_by_page();
 
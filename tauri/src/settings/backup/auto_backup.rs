@@ -4,7 +4,9 @@ use std::time::Duration;
 use tauri::{Emitter, Manager};
 
 use super::utils::{create_backup_zip, get_db_path};
-use super::webdav::{delete_webdav_backup_internal, list_webdav_backups_internal};
+use super::webdav::{
+    delete_webdav_backup_internal, list_webdav_backups_internal, resolve_password,
+};
 use crate::db::DbState;
 use crate::http_client;
 use crate::settings::adapter;
@@ -50,11 +52,18 @@ async fn check_and_perform_backup(app_handle: &tauri::AppHandle) -> Result<(), S
             if settings.webdav.url.is_empty() {
                 return Ok(());
             }
+            // Still due after unlocking; skipping keeps the schedule from counting it
+            if crate::app_lock::ensure_decrypted(&settings.webdav.password).is_err() {
+                info!(
+                    "Auto-backup skipped: the app is locked and the WebDAV password is encrypted"
+                );
+                return Ok(());
+            }
 
             info!("Auto-backup is due, performing WebDAV backup...");
 
             match perform_webdav_backup(app_handle, &db_state, &settings).await {
-                Ok(()) => {
+                Ok(_) => {
                     info!("Auto-backup completed successfully");
 
                     let now = Utc::now().to_rfc3339();
@@ -96,7 +105,7 @@ async fn check_and_perform_backup(app_handle: &tauri::AppHandle) -> Result<(), S
             info!("Auto-backup is due, performing local backup...");
 
             match perform_local_backup(app_handle, &settings).await {
-                Ok(()) => {
+                Ok(_) => {
                     info!("Auto-backup (local) completed successfully");
 
                     let now = Utc::now().to_rfc3339();
@@ -128,6 +137,23 @@ async fn check_and_perform_backup(app_handle: &tauri::AppHandle) -> Result<(), S
     }
 }
 
+/// Back up to the configured destination now, regardless of the schedule.
+/// Returns the uploaded URL or the written file.
+pub async fn backup_now(app_handle: &tauri::AppHandle) -> Result<String, String> {
+    let db_state = app_handle.state::<DbState>();
+    let settings = read_settings(&db_state).await?;
+
+    match settings.backup_type.as_str() {
+        "webdav" if !settings.webdav.url.is_empty() => {
+            perform_webdav_backup(app_handle, &db_state, &settings).await
+        }
+        "local" if !settings.local_backup_path.is_empty() => {
+            perform_local_backup(app_handle, &settings).await
+        }
+        _ => Err("No backup destination is configured".to_string()),
+    }
+}
+
 /// Read AppSettings from database
 async fn read_settings(db_state: &DbState) -> Result<crate::settings::types::AppSettings, String> {
     let db = db_state.db();
@@ -164,12 +190,12 @@ fn is_backup_due(last_time: &Option<String>, interval_days: u32) -> bool {
     elapsed >= interval
 }
 
-/// Perform a WebDAV backup
+/// Perform a WebDAV backup, returning the uploaded URL
 async fn perform_webdav_backup(
     app_handle: &tauri::AppHandle,
     db_state: &DbState,
     settings: &crate::settings::types::AppSettings,
-) -> Result<(), String> {
+) -> Result<String, String> {
     let db_path = get_db_path(app_handle)?;
     let zip_data = create_backup_zip(app_handle, &db_path).await?;
 
@@ -190,6 +216,7 @@ async fn perform_webdav_backup(
     };
 
     info!("Auto-backup: uploading to {}", full_url);
+    let password = resolve_password(&settings.webdav.password)?;
 
     let client = http_client::client_with_timeout(db_state, 300)
         .await
@@ -200,14 +227,14 @@ async fn perform_webdav_backup(
 
     let response = client
         .put(&full_url)
-        .basic_auth(&settings.webdav.username, Some(&settings.webdav.password))
+        .basic_auth(&settings.webdav.username, Some(&password))
         .body(zip_data)
         .send()
         .await
         .map_err(|e| format!("Auto-backup upload failed: {}", e))?;
 
     if response.status().is_success() {
-        Ok(())
+        Ok(full_url)
     } else {
         Err(format!(
             "Auto-backup upload failed with status: {}",
@@ -216,11 +243,11 @@ async fn perform_webdav_backup(
     }
 }
 
-/// Perform a local backup
+/// Perform a local backup, returning the written file
 async fn perform_local_backup(
     app_handle: &tauri::AppHandle,
     settings: &crate::settings::types::AppSettings,
-) -> Result<(), String> {
+) -> Result<String, String> {
     let db_path = get_db_path(app_handle)?;
     let zip_data = create_backup_zip(app_handle, &db_path).await?;
 
@@ -238,7 +265,7 @@ async fn perform_local_backup(
         .map_err(|e| format!("Failed to write backup file: {}", e))?;
 
     info!("Auto-backup: saved to {:?}", backup_file_path);
-    Ok(())
+    Ok(backup_file_path.to_string_lossy().to_string())
 }
 
 /// Update last_auto_backup_time in database directly
@@ -3,7 +3,6 @@ use std::fs::{self, File};
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 use tauri::Manager;
-use walkdir::WalkDir;
 use zip::write::SimpleFileOptions;
 use zip::{ZipArchive, ZipWriter};
 
@@ -16,7 +15,7 @@ use super::utils::{
     get_opencode_auth_restore_path, get_opencode_config_path_from_db,
     get_opencode_prompt_path_from_db, get_opencode_restore_dir, get_preset_models_cache_file,
     get_skills_dir, push_restore_warning, read_root_dir_override, resolve_restore_dir_override,
-    resolve_skills_restore_output_path, RestoreResult,
+    resolve_skills_restore_output_path, zip_dir_blocking, RestoreResult,
 };
 
 fn get_home_dir() -> Result<PathBuf, String> {
@@ -77,48 +76,11 @@ pub async fn backup_database(
     // Create zip file
     let file = File::create(&backup_file_path)
         .map_err(|e| format!("Failed to create backup file: {}", e))?;
-    let mut zip = ZipWriter::new(file);
+    let zip = ZipWriter::new(file);
     let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
 
-    // Walk through the database directory and add files to zip under "db/" prefix
-    let mut has_files = false;
-    for entry in WalkDir::new(&db_path) {
-        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
-        let path = entry.path();
-        let relative_path = path
-            .strip_prefix(&db_path)
-            .map_err(|e| format!("Failed to get relative path: {}", e))?;
-
-        if path.is_file() {
-            // Skip system files like .DS_Store
-            if let Some(file_name) = path.file_name() {
-                let name_str = file_name.to_string_lossy();
-                if name_str == ".DS_Store" || name_str.starts_with("._") {
-                    continue;
-                }
-            }
-
-            has_files = true;
-            // Use forward slashes for cross-platform compatibility in zip files
-            let relative_str = relative_path.to_string_lossy().replace('\\', "/");
-            let name = format!("db/{}", relative_str);
-            zip.start_file(name, options)
-                .map_err(|e| format!("Failed to start file in zip: {}", e))?;
-
-            let mut file = File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
-            let mut buffer = Vec::new();
-            file.read_to_end(&mut buffer)
-                .map_err(|e| format!("Failed to read file: {}", e))?;
-            zip.write_all(&buffer)
-                .map_err(|e| format!("Failed to write to zip: {}", e))?;
-        } else if path.is_dir() && !relative_path.as_os_str().is_empty() {
-            // Use forward slashes for cross-platform compatibility in zip files
-            let relative_str = relative_path.to_string_lossy().replace('\\', "/");
-            let name = format!("db/{}/", relative_str);
-            zip.add_directory(name, options)
-                .map_err(|e| format!("Failed to add directory to zip: {}", e))?;
-        }
-    }
+    // Add database files under "db/" prefix
+    let (mut zip, has_files) = zip_dir_blocking(zip, db_path.clone(), "db", options).await?;
 
     // If no database files, add a placeholder to ensure valid zip
     if !has_files {
@@ -290,32 +252,9 @@ pub async fn backup_database(
         zip.add_directory("skills/", options)
             .map_err(|e| format!("Failed to add skills directory: {}", e))?;
 
-        for entry in WalkDir::new(&skills_dir) {
-            let entry = entry.map_err(|e| format!("Failed to read skills entry: {}", e))?;
-            let path = entry.path();
-            let relative_path = path
-                .strip_prefix(&skills_dir)
-                .map_err(|e| format!("Failed to get relative path: {}", e))?;
-
-            if path.is_file() {
-                // Skip system files
-                if let Some(file_name) = path.file_name() {
-                    let name_str = file_name.to_string_lossy();
-                    if name_str == ".DS_Store" || name_str.starts_with("._") {
-                        continue;
-                    }
-                }
-
-                let relative_str = relative_path.to_string_lossy().replace('\\', "/");
-                let name = format!("skills/{}", relative_str);
-                add_file_to_zip(&mut zip, path, &name, options)?;
-            } else if path.is_dir() && !relative_path.as_os_str().is_empty() {
-                let relative_str = relative_path.to_string_lossy().replace('\\', "/");
-                let name = format!("skills/{}/", relative_str);
-                zip.add_directory(name, options)
-                    .map_err(|e| format!("Failed to add skills subdirectory: {}", e))?;
-            }
-        }
+        zip = zip_dir_blocking(zip, skills_dir, "skills", options)
+            .await?
+            .0;
     }
 
     zip.finish()
@@ -641,9 +580,7 @@ pub async fn restore_database(
     }
 
     // Create resync flag file to trigger skills and MCP resync on next startup
-    let app_data_dir = app_handle
-        .path()
-        .app_data_dir()
+    let app_data_dir = crate::portable::app_data_dir(&app_handle)
         .map_err(|e| format!("Failed to get app data dir: {}", e))?;
     let resync_flag = app_data_dir.join(".resync_required");
     let _ = fs::write(&resync_flag, "1");
@@ -661,9 +598,7 @@ pub fn get_database_path(app_handle: tauri::AppHandle) -> Result<String, String>
 /// Open the app data directory in the file explorer
 #[tauri::command]
 pub fn open_app_data_dir(app_handle: tauri::AppHandle) -> Result<(), String> {
-    let app_data_dir = app_handle
-        .path()
-        .app_data_dir()
+    let app_data_dir = crate::portable::app_data_dir(&app_handle)
         .map_err(|e| format!("Failed to get app data dir: {}", e))?;
 
     // Ensure directory exists
@@ -13,10 +13,7 @@ use crate::coding::{claude_code, codex, runtime_location};
 
 /// Get database directory path
 pub fn get_db_path(app_handle: &tauri::AppHandle) -> Result<std::path::PathBuf, String> {
-    use tauri::Manager;
-    let app_data_dir = app_handle
-        .path()
-        .app_data_dir()
+    let app_data_dir = crate::portable::app_data_dir(app_handle)
         .map_err(|e| format!("Failed to get app data dir: {}", e))?;
     Ok(app_data_dir.join("database"))
 }
@@ -535,10 +532,7 @@ pub async fn get_custom_root_dir_path_info(
 
 /// Get skills directory path
 pub fn get_skills_dir(app_handle: &tauri::AppHandle) -> Result<PathBuf, String> {
-    use tauri::Manager;
-    let app_data_dir = app_handle
-        .path()
-        .app_data_dir()
+    let app_data_dir = crate::portable::app_data_dir(app_handle)
         .map_err(|e| format!("Failed to get app data dir: {}", e))?;
     Ok(app_data_dir.join("skills"))
 }
@@ -573,6 +567,60 @@ fn add_file_to_zip<W: Write + std::io::Seek>(
     Ok(())
 }
 
+/// Add every file and folder under `dir` to the zip below `prefix/`, skipping
+/// OS metadata files. Returns whether any file was added.
+fn add_dir_to_zip<W: Write + std::io::Seek>(
+    zip: &mut ZipWriter<W>,
+    dir: &Path,
+    prefix: &str,
+    options: SimpleFileOptions,
+) -> Result<bool, String> {
+    let mut has_files = false;
+    for entry in WalkDir::new(dir) {
+        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+        let path = entry.path();
+        let relative_path = path
+            .strip_prefix(dir)
+            .map_err(|e| format!("Failed to get relative path: {}", e))?;
+        // Use forward slashes for cross-platform compatibility in zip files
+        let relative_str = relative_path.to_string_lossy().replace('\\', "/");
+
+        if path.is_file() {
+            // Skip system files like .DS_Store
+            if let Some(file_name) = path.file_name() {
+                let name_str = file_name.to_string_lossy();
+                if name_str == ".DS_Store" || name_str.starts_with("._") {
+                    continue;
+                }
+            }
+
+            has_files = true;
+            add_file_to_zip(zip, path, &format!("{}/{}", prefix, relative_str), options)?;
+        } else if path.is_dir() && !relative_path.as_os_str().is_empty() {
+            zip.add_directory(format!("{}/{}/", prefix, relative_str), options)
+                .map_err(|e| format!("Failed to add directory to zip: {}", e))?;
+        }
+    }
+    Ok(has_files)
+}
+
+/// Zip a directory tree on the blocking thread pool. The database and skills
+/// folders can be large, and reading and compressing them inline would stall the
+/// async runtime that every other command (tray refresh, settings reads) runs on.
+pub async fn zip_dir_blocking<W: Write + std::io::Seek + Send + 'static>(
+    mut zip: ZipWriter<W>,
+    dir: PathBuf,
+    prefix: &'static str,
+    options: SimpleFileOptions,
+) -> Result<(ZipWriter<W>, bool), String> {
+    tokio::task::spawn_blocking(move || {
+        let has_files = add_dir_to_zip(&mut zip, &dir, prefix, options)?;
+        Ok((zip, has_files))
+    })
+    .await
+    .map_err(|e| format!("Failed to zip {}: {}", prefix, e))?
+}
+
 pub fn add_text_to_zip<W: Write + std::io::Seek>(
     zip: &mut ZipWriter<W>,
     zip_path: &str,
@@ -593,56 +641,17 @@ pub async fn create_backup_zip(
 ) -> Result<Vec<u8>, String> {
     use std::io::Cursor;
 
-    let mut buffer = Cursor::new(Vec::new());
     let db_state = app_handle.state::<crate::DbState>();
     let db = db_state.db();
 
-    {
-        let mut zip = ZipWriter::new(&mut buffer);
+    let buffer = {
+        let zip = ZipWriter::new(Cursor::new(Vec::new()));
         let options =
             SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
 
-        let mut has_files = false;
-
         // Add database files under db/ prefix
-        for entry in WalkDir::new(db_path) {
-            let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
-            let path = entry.path();
-            let relative_path = path
-                .strip_prefix(db_path)
-                .map_err(|e| format!("Failed to get relative path: {}", e))?;
-
-            if path.is_file() {
-                // Skip system files like .DS_Store
-                if let Some(file_name) = path.file_name() {
-                    let name_str = file_name.to_string_lossy();
-                    if name_str == ".DS_Store" || name_str.starts_with("._") {
-                        continue;
-                    }
-                }
-
-                has_files = true;
-                // Use forward slashes for cross-platform compatibility in zip files
-                let relative_str = relative_path.to_string_lossy().replace('\\', "/");
-                let name = format!("db/{}", relative_str);
-                zip.start_file(name, options)
-                    .map_err(|e| format!("Failed to start file in zip: {}", e))?;
-
-                let mut file =
-                    File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
-                let mut file_buffer = Vec::new();
-                file.read_to_end(&mut file_buffer)
-                    .map_err(|e| format!("Failed to read file: {}", e))?;
-                zip.write_all(&file_buffer)
-                    .map_err(|e| format!("Failed to write to zip: {}", e))?;
-            } else if path.is_dir() && !relative_path.as_os_str().is_empty() {
-                // Use forward slashes for cross-platform compatibility in zip files
-                let relative_str = relative_path.to_string_lossy().replace('\\', "/");
-                let name = format!("db/{}/", relative_str);
-                zip.add_directory(name, options)
-                    .map_err(|e| format!("Failed to add directory to zip: {}", e))?;
-            }
-        }
+        let (mut zip, has_files) =
+            zip_dir_blocking(zip, db_path.to_path_buf(), "db", options).await?;
 
         if !has_files {
             zip.start_file("db/.backup_marker", options)
@@ -810,37 +819,14 @@ pub async fn create_backup_zip(
             zip.add_directory("skills/", options)
                 .map_err(|e| format!("Failed to add skills directory: {}", e))?;
 
-            for entry in WalkDir::new(&skills_dir) {
-                let entry = entry.map_err(|e| format!("Failed to read skills entry: {}", e))?;
-                let path = entry.path();
-                let relative_path = path
-                    .strip_prefix(&skills_dir)
-                    .map_err(|e| format!("Failed to get relative path: {}", e))?;
-
-                if path.is_file() {
-                    // Skip system files
-                    if let Some(file_name) = path.file_name() {
-                        let name_str = file_name.to_string_lossy();
-                        if name_str == ".DS_Store" || name_str.starts_with("._") {
-                            continue;
-                        }
-                    }
-
-                    let relative_str = relative_path.to_string_lossy().replace('\\', "/");
-                    let name = format!("skills/{}", relative_str);
-                    add_file_to_zip(&mut zip, path, &name, options)?;
-                } else if path.is_dir() && !relative_path.as_os_str().is_empty() {
-                    let relative_str = relative_path.to_string_lossy().replace('\\', "/");
-                    let name = format!("skills/{}/", relative_str);
-                    zip.add_directory(name, options)
-                        .map_err(|e| format!("Failed to add skills subdirectory: {}", e))?;
-                }
-            }
+            zip = zip_dir_blocking(zip, skills_dir, "skills", options)
+                .await?
+                .0;
         }
 
         zip.finish()
-            .map_err(|e| format!("Failed to finish zip: {}", e))?;
-    }
+            .map_err(|e| format!("Failed to finish zip: {}", e))?
+    };
 
     Ok(buffer.into_inner())
 }
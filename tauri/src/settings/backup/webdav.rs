@@ -2,7 +2,6 @@ use chrono::Local;
 use log::{error, info};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use tauri::Manager;
 use zip::ZipArchive;
 
 use super::utils::{
@@ -120,6 +119,14 @@ fn analyze_reqwest_error(err: &reqwest::Error, url: &str) -> WebDAVError {
     }
 }
 
+/// Resolve a vault secret reference (`{{secret:NAME}}`) in the WebDAV password
+pub(super) fn resolve_password(password: &str) -> Result<String, String> {
+    // Read while the app is locked: never send the ciphertext as the password
+    crate::app_lock::ensure_decrypted(password)
+        .map_err(|_| "WebDAV password is encrypted while the app is locked".to_string())?;
+    crate::secrets::resolve_text(password)
+}
+
 /// Test WebDAV connection
 #[tauri::command]
 pub async fn test_webdav_connection(
@@ -130,6 +137,7 @@ pub async fn test_webdav_connection(
     remote_path: String,
 ) -> Result<(), String> {
     info!("Testing WebDAV connection to: {}", url);
+    let password = resolve_password(&password)?;
 
     // Build WebDAV URL
     let base_url = url.trim_end_matches('/');
@@ -187,6 +195,7 @@ pub async fn backup_to_webdav(
     host_label: String,
 ) -> Result<String, String> {
     info!("Starting WebDAV backup to: {}", url);
+    let password = resolve_password(&password)?;
 
     let db_path = get_db_path(&app_handle)?;
 
@@ -264,6 +273,7 @@ pub(crate) async fn list_webdav_backups_internal(
     remote_path: &str,
 ) -> Result<Vec<BackupFileInfo>, String> {
     info!("Listing WebDAV backups from: {}", url);
+    let password = resolve_password(password)?;
 
     // Build WebDAV URL
     let base_url = url.trim_end_matches('/');
@@ -290,7 +300,7 @@ pub(crate) async fn list_webdav_backups_internal(
             reqwest::Method::from_bytes(b"PROPFIND").unwrap(),
             &folder_url,
         )
-        .basic_auth(username, Some(password))
+        .basic_auth(username, Some(&password))
         .header("Depth", "1")
         .header("Content-Type", "application/xml; charset=utf-8")
         .body(propfind_body)
@@ -387,6 +397,7 @@ pub(crate) async fn delete_webdav_backup_internal(
     filename: &str,
 ) -> Result<(), String> {
     info!("Deleting WebDAV backup: {}", filename);
+    let password = resolve_password(password)?;
 
     // Build WebDAV URL
     let base_url = url.trim_end_matches('/');
@@ -405,7 +416,7 @@ pub(crate) async fn delete_webdav_backup_internal(
 
     let response = client
         .delete(&full_url)
-        .basic_auth(username, Some(password))
+        .basic_auth(username, Some(&password))
         .send()
         .await;
 
@@ -453,6 +464,7 @@ pub async fn restore_from_webdav(
     filename: String,
 ) -> Result<RestoreResult, String> {
     info!("Starting WebDAV restore from: {}/{}", url, filename);
+    let password = resolve_password(&password)?;
 
     let db_path = get_db_path(&app_handle)?;
 
@@ -812,9 +824,7 @@ pub async fn restore_from_webdav(
     }
 
     // Create resync flag file to trigger skills and MCP resync on next startup
-    let app_data_dir = app_handle
-        .path()
-        .app_data_dir()
+    let app_data_dir = crate::portable::app_data_dir(&app_handle)
         .map_err(|e| format!("Failed to get app data dir: {}", e))?;
     let resync_flag = app_data_dir.join(".resync_required");
     let _ = fs::write(&resync_flag, "1");
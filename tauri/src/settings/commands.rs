@@ -1,7 +1,9 @@
 use super::adapter;
 use super::types::AppSettings;
+use super::window_behavior;
 use crate::auto_launch;
 use crate::db::DbState;
+use crate::db_timestamp::with_datetimes;
 use crate::tray;
 
 /// Get settings from database using adapter layer for fault tolerance
@@ -38,16 +40,21 @@ pub async fn save_settings(
     let db = state.db();
 
     // Convert to JSON using adapter
-    let json = adapter::to_db_value(&settings);
+    let json = adapter::to_db_value(&settings)?;
 
     // Use UPSERT to handle both create and update
     db.query("UPSERT settings:`app` CONTENT $data")
-        .bind(("data", json))
+        .bind(("data", with_datetimes(json)))
         .await
         .map_err(|e| format!("Failed to save settings: {}", e))?;
 
     drop(db);
 
+    window_behavior::update_from_settings(&settings);
+    crate::locale::set_language(&settings.language);
+    crate::audit_log::set_retention_days(&state, settings.audit_log_retention_days).await;
+    crate::gateway::apply_settings(&app, &settings).await;
+
     if let Err(err) = tray::refresh_tray_menus(&app).await {
         log::warn!("Failed to refresh tray after saving settings: {err}");
     }
@@ -55,6 +62,109 @@ pub async fn save_settings(
     Ok(())
 }
 
+/// Marks a file written by `export_settings`
+const SETTINGS_EXPORT_FORMAT: &str = "ai-toolbox-settings";
+const SETTINGS_EXPORT_VERSION: u32 = 1;
+
+/// Keep a credential only when it is a `{{secret:NAME}}` reference, which carries no secret
+fn export_credential(value: &str) -> String {
+    let value = value.trim();
+    let is_reference = crate::secrets::secret_ref_regex()
+        .find(value)
+        .map(|m| m.start() == 0 && m.end() == value.len())
+        .unwrap_or(false);
+    if is_reference {
+        value.to_string()
+    } else {
+        String::new()
+    }
+}
+
+/// Settings as written to an export: credentials and per-machine timestamps removed
+fn exportable_settings(settings: &AppSettings) -> AppSettings {
+    let mut settings = settings.clone();
+    settings.webdav.password = export_credential(&settings.webdav.password);
+    settings.s3.access_key = export_credential(&settings.s3.access_key);
+    settings.s3.secret_key = export_credential(&settings.s3.secret_key);
+    settings.gateway_api_key = export_credential(&settings.gateway_api_key);
+    settings.last_backup_time = None;
+    settings.last_auto_backup_time = None;
+    settings
+}
+
+/// Settings from an import, keeping this machine's credentials where the import has none
+fn merge_imported_settings(imported: AppSettings, current: &AppSettings) -> AppSettings {
+    fn keep_if_empty(imported: String, current: &str) -> String {
+        if imported.is_empty() {
+            current.to_string()
+        } else {
+            imported
+        }
+    }
+
+    let mut settings = imported;
+    settings.webdav.password = keep_if_empty(settings.webdav.password, &current.webdav.password);
+    settings.s3.access_key = keep_if_empty(settings.s3.access_key, &current.s3.access_key);
+    settings.s3.secret_key = keep_if_empty(settings.s3.secret_key, &current.s3.secret_key);
+    settings.gateway_api_key = keep_if_empty(settings.gateway_api_key, &current.gateway_api_key);
+    settings.last_backup_time = current.last_backup_time;
+    settings.last_auto_backup_time = current.last_auto_backup_time.clone();
+    settings
+}
+
+/// Export the app settings (not the database) as JSON to set up another machine
+///
+/// Passwords and keys are left out unless they are secret references.
+#[tauri::command]
+pub async fn export_settings(state: tauri::State<'_, DbState>) -> Result<String, String> {
+    let settings = exportable_settings(&get_settings(state).await?);
+    let export = serde_json::json!({
+        "format": SETTINGS_EXPORT_FORMAT,
+        "version": SETTINGS_EXPORT_VERSION,
+        "app_version": env!("CARGO_PKG_VERSION"),
+        "exported_at": chrono::Local::now().to_rfc3339(),
+        "settings": settings,
+    });
+    serde_json::to_string_pretty(&export).map_err(|e| format!("Failed to export settings: {}", e))
+}
+
+/// Import app settings exported by `export_settings`
+///
+/// Missing fields fall back to defaults, credentials left out of the export keep
+/// their current values. Auto launch and hotkeys are applied right away.
+#[tauri::command]
+pub async fn import_settings(
+    state: tauri::State<'_, DbState>,
+    app: tauri::AppHandle,
+    json: String,
+) -> Result<AppSettings, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(&json).map_err(|e| format!("Invalid settings file: {}", e))?;
+    let settings_value = match value.get("format").and_then(|f| f.as_str()) {
+        Some(SETTINGS_EXPORT_FORMAT) => value.get("settings").cloned().unwrap_or_default(),
+        Some(other) => return Err(format!("Unsupported settings file format: {}", other)),
+        // Also accept a bare settings object
+        None => value,
+    };
+    if !settings_value.is_object() {
+        return Err("Invalid settings file: settings must be an object".to_string());
+    }
+
+    let current = get_settings(state.clone()).await?;
+    let settings = merge_imported_settings(adapter::from_db_value(settings_value), &current);
+    save_settings(state, app.clone(), settings.clone()).await?;
+
+    if settings.launch_on_startup != current.launch_on_startup {
+        if let Err(e) = set_auto_launch(settings.launch_on_startup) {
+            log::warn!("Failed to apply imported auto launch setting: {}", e);
+        }
+    }
+    crate::hotkeys::register_hotkeys(&app, &settings.hotkeys);
+
+    log::info!("Imported app settings");
+    Ok(settings)
+}
+
 /// Set auto launch on startup
 #[tauri::command]
 pub fn set_auto_launch(enabled: bool) -> Result<(), String> {
@@ -140,3 +250,59 @@ pub fn restart_app() -> Result<(), String> {
 pub async fn test_proxy_connection(proxy_url: String) -> Result<(), String> {
     crate::http_client::test_proxy(&proxy_url).await
 }
+
+/// Fallback canary when no Claude provider with a base URL is applied
+const DEFAULT_PROVIDER_CANARY: &str = "https://api.anthropic.com";
+
+/// Base URL of the applied Claude Code provider
+async fn provider_canary_url(state: &DbState) -> String {
+    let records: Vec<serde_json::Value> = match state
+        .db()
+        .query(
+            "SELECT *, type::string(id) as id FROM claude_provider WHERE is_applied = true LIMIT 1",
+        )
+        .await
+    {
+        Ok(mut result) => result.take(0).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+    records
+        .into_iter()
+        .next()
+        .map(crate::coding::claude_code::adapter::from_db_value_provider)
+        .and_then(|provider| {
+            serde_json::from_str::<serde_json::Value>(&provider.settings_config).ok()
+        })
+        .and_then(|config| {
+            config
+                .pointer("/env/ANTHROPIC_BASE_URL")
+                .and_then(|v| v.as_str())
+                .map(|url| url.trim().to_string())
+        })
+        .filter(|url| url.starts_with("http"))
+        .unwrap_or_else(|| DEFAULT_PROVIDER_CANARY.to_string())
+}
+
+/// Check a proxy by fetching canary URLs through it: the GitHub update feed of the
+/// selected channel and the applied provider's base URL
+///
+/// `url` defaults to the configured proxy URL. Each canary reports its status,
+/// latency and error, so a misconfigured proxy shows which destination fails.
+#[tauri::command]
+pub async fn test_proxy(
+    state: tauri::State<'_, DbState>,
+    url: Option<String>,
+) -> Result<Vec<crate::http_client::ProxyProbeResult>, String> {
+    let settings = get_settings(state.clone()).await?;
+    let proxy_url = url
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty())
+        .unwrap_or(settings.proxy_url);
+
+    let update_channel = crate::update::UpdateChannel::from_setting(&settings.update_channel);
+    let canaries = vec![
+        ("GitHub".to_string(), update_channel.latest_json_url()),
+        ("Provider".to_string(), provider_canary_url(&state).await),
+    ];
+    crate::http_client::probe_proxy(&proxy_url, &canaries).await
+}
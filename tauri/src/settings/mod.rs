@@ -2,6 +2,7 @@ pub(crate) mod adapter;
 pub mod backup;
 pub mod commands;
 pub mod types;
+pub mod window_behavior;
 
 pub use commands::*;
 pub use types::*;
@@ -0,0 +1,121 @@
+//! Window behavior settings
+//!
+//! Keeps the window-related settings (minimize to tray on close, confirm before quit,
+//! start hidden in tray) in memory so the window and run event handlers can read them
+//! without querying the database on the event loop. The cache is loaded at startup and
+//! updated whenever settings are saved.
+
+use super::AppSettings;
+use crate::db::DbState;
+use crate::locale::{self, Locale};
+use std::sync::atomic::Ordering;
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+/// Window behavior options taken from `AppSettings`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowBehavior {
+    pub minimize_to_tray_on_close: bool,
+    pub confirm_before_quit: bool,
+    pub start_minimized: bool,
+    /// UI language, used for the quit confirmation dialog
+    pub language: String,
+}
+
+impl Default for WindowBehavior {
+    fn default() -> Self {
+        Self::from_settings(&AppSettings::default())
+    }
+}
+
+impl WindowBehavior {
+    pub fn from_settings(settings: &AppSettings) -> Self {
+        Self {
+            minimize_to_tray_on_close: settings.minimize_to_tray_on_close,
+            confirm_before_quit: settings.confirm_before_quit,
+            start_minimized: settings.start_minimized,
+            language: settings.language.clone(),
+        }
+    }
+}
+
+static WINDOW_BEHAVIOR: RwLock<Option<WindowBehavior>> = RwLock::new(None);
+
+/// Current window behavior (defaults until settings are loaded)
+pub fn current() -> WindowBehavior {
+    WINDOW_BEHAVIOR
+        .read()
+        .ok()
+        .and_then(|guard| guard.clone())
+        .unwrap_or_default()
+}
+
+/// Update the cached window behavior after settings were saved
+pub fn update_from_settings(settings: &AppSettings) {
+    if let Ok(mut guard) = WINDOW_BEHAVIOR.write() {
+        *guard = Some(WindowBehavior::from_settings(settings));
+    }
+}
+
+/// Load the window behavior from the database into the cache
+pub async fn load_window_behavior<R: Runtime>(app: &AppHandle<R>) -> WindowBehavior {
+    match super::get_settings(app.state::<DbState>()).await {
+        Ok(settings) => update_from_settings(&settings),
+        Err(e) => log::warn!("Failed to load window behavior settings: {}", e),
+    }
+    current()
+}
+
+/// Hide the main window to the tray
+pub fn hide_to_tray<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.hide();
+    }
+
+    // macOS: Switch to Accessory mode to hide from Dock
+    #[cfg(target_os = "macos")]
+    {
+        use tauri::ActivationPolicy;
+        let _ = app.set_activation_policy(ActivationPolicy::Accessory);
+    }
+}
+
+/// Exit the application without asking
+pub fn exit_app<R: Runtime>(app: &AppHandle<R>) {
+    crate::APP_EXIT_REQUESTED.store(true, Ordering::SeqCst);
+    app.exit(0);
+}
+
+/// Quit the application, asking first when `confirm_before_quit` is enabled
+pub fn request_quit<R: Runtime>(app: &AppHandle<R>) {
+    let behavior = current();
+    if !behavior.confirm_before_quit {
+        exit_app(app);
+        return;
+    }
+
+    let locale = Locale::from_language(&behavior.language);
+    let text = |key| locale::text_in(locale, key);
+    let (title, message, ok, cancel) = (
+        text("quit_dialog.title"),
+        text("quit_dialog.message"),
+        text("quit_dialog.ok"),
+        text("quit_dialog.cancel"),
+    );
+
+    let app_handle = app.clone();
+    app.dialog()
+        .message(message)
+        .title(title)
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(
+            ok.to_string(),
+            cancel.to_string(),
+        ))
+        .show(move |confirmed| {
+            if confirmed {
+                exit_app(&app_handle);
+            }
+        });
+}
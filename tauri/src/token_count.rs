@@ -0,0 +1,116 @@
+//! Token Counting Module
+//!
+//! Counts prompt tokens with the bundled tiktoken BPE tables so editors can show the
+//! size of CLAUDE.md, skills and common configs against a model's context limit:
+//! - OpenAI models use their own encoding (`o200k_base` or `cl100k_base`), exact
+//! - Claude models use `cl100k_base`, an approximation since Anthropic's tokenizer is
+//!   not published
+//! - Other models use `o200k_base`, also an approximation
+
+use std::sync::OnceLock;
+
+use serde::Serialize;
+use tiktoken_rs::CoreBPE;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenEncoding {
+    O200kBase,
+    Cl100kBase,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenCount {
+    pub tokens: usize,
+    pub encoding: TokenEncoding,
+    /// Whether the encoding is only close to the model's real tokenizer
+    pub approximate: bool,
+}
+
+/// Count the tokens of `text` for `model` (a model id, optionally `provider/model`)
+#[tauri::command]
+pub async fn count_tokens(model: String, text: String) -> Result<TokenCount, String> {
+    tauri::async_runtime::spawn_blocking(move || count(&model, &text))
+        .await
+        .map_err(|e| format!("Failed to count tokens: {}", e))?
+}
+
+pub fn count(model: &str, text: &str) -> Result<TokenCount, String> {
+    let (encoding, approximate) = encoding_for_model(model);
+    let tokens = bpe(encoding)?.encode_ordinary(text).len();
+    Ok(TokenCount {
+        tokens,
+        encoding,
+        approximate,
+    })
+}
+
+/// Encoding for a model id and whether it is an approximation
+fn encoding_for_model(model: &str) -> (TokenEncoding, bool) {
+    let model = model.rsplit('/').next().unwrap_or(model).to_lowercase();
+    if model.starts_with("claude") {
+        return (TokenEncoding::Cl100kBase, true);
+    }
+    if [
+        "gpt-4o", "gpt-4.1", "gpt-4.5", "gpt-5", "chatgpt", "codex", "o1", "o3", "o4",
+    ]
+    .iter()
+    .any(|prefix| model.starts_with(prefix))
+    {
+        return (TokenEncoding::O200kBase, false);
+    }
+    if ["gpt-4", "gpt-3.5", "text-embedding"]
+        .iter()
+        .any(|prefix| model.starts_with(prefix))
+    {
+        return (TokenEncoding::Cl100kBase, false);
+    }
+    (TokenEncoding::O200kBase, true)
+}
+
+/// Loading a BPE table takes a moment, so each is built once
+fn bpe(encoding: TokenEncoding) -> Result<&'static CoreBPE, String> {
+    static O200K: OnceLock<Result<CoreBPE, String>> = OnceLock::new();
+    static CL100K: OnceLock<Result<CoreBPE, String>> = OnceLock::new();
+    let cell = match encoding {
+        TokenEncoding::O200kBase => O200K.get_or_init(|| {
+            tiktoken_rs::o200k_base().map_err(|e| format!("Failed to load o200k_base: {}", e))
+        }),
+        TokenEncoding::Cl100kBase => CL100K.get_or_init(|| {
+            tiktoken_rs::cl100k_base().map_err(|e| format!("Failed to load cl100k_base: {}", e))
+        }),
+    };
+    cell.as_ref().map_err(Clone::clone)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_encoding_by_model_family() {
+        assert_eq!(
+            encoding_for_model("openai/gpt-5-codex"),
+            (TokenEncoding::O200kBase, false)
+        );
+        assert_eq!(
+            encoding_for_model("gpt-4-turbo"),
+            (TokenEncoding::Cl100kBase, false)
+        );
+        assert_eq!(
+            encoding_for_model("anthropic/claude-sonnet-4-5"),
+            (TokenEncoding::Cl100kBase, true)
+        );
+        assert_eq!(
+            encoding_for_model("deepseek-chat"),
+            (TokenEncoding::O200kBase, true)
+        );
+    }
+
+    #[test]
+    fn counts_tokens() {
+        assert_eq!(count("gpt-4", "hello world").unwrap().tokens, 2);
+        assert_eq!(count("gpt-5", "").unwrap().tokens, 0);
+    }
+}
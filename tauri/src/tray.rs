@@ -14,6 +14,7 @@
 //! - MCP server options (with submenus for tool selection)
 //! - Quit
 
+use crate::coding::aider::tray_support as aider_tray;
 use crate::coding::claude_code::tray_support as claude_tray;
 use crate::coding::codex::tray_support as codex_tray;
 use crate::coding::mcp::tray_support as mcp_tray;
@@ -22,16 +23,20 @@ use crate::coding::oh_my_opencode_slim::tray_support as omo_slim_tray;
 use crate::coding::open_claw::tray_support as openclaw_tray;
 use crate::coding::open_code::tray_support as opencode_tray;
 use crate::coding::skills::tray_support as skills_tray;
-use std::sync::atomic::{AtomicBool, Ordering};
+use crate::locale::{self, Locale};
+use crate::settings::window_behavior;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
 use tauri::{
     menu::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu},
     tray::TrayIconBuilder,
-    AppHandle, Manager, Runtime,
+    AppHandle, Emitter, Manager, Runtime,
 };
 
 #[derive(Clone, Copy)]
 struct TrayTexts {
     show_window: &'static str,
+    quick_switch: &'static str,
     quit: &'static str,
     main_model: &'static str,
     small_model: &'static str,
@@ -43,58 +48,59 @@ struct TrayTexts {
     claude_header: &'static str,
     codex_header: &'static str,
     openclaw_header: &'static str,
+    aider_header: &'static str,
     skills_header: &'static str,
     mcp_header: &'static str,
     no_config: &'static str,
     no_model: &'static str,
     no_tools: &'static str,
-}
-
-fn is_english_language(language: &str) -> bool {
-    language.eq_ignore_ascii_case("en-US") || language.to_ascii_lowercase().starts_with("en")
+    recent_models: &'static str,
+    opencode_update_available: &'static str,
+    status_header: &'static str,
+    ssh_sync: &'static str,
+    wsl_sync: &'static str,
+    sync_never: &'static str,
+    sync_failed: &'static str,
+    connection_lost: &'static str,
+    sync_now: &'static str,
+    open_settings: &'static str,
+    app_update_available: &'static str,
 }
 
 fn tray_texts(language: &str) -> TrayTexts {
-    if is_english_language(language) {
-        TrayTexts {
-            show_window: "Open Main Window",
-            quit: "Quit",
-            main_model: "Main Model",
-            small_model: "Small Model",
-            global_prompt: "Global Prompt",
-            opencode_header: "OpenCode",
-            opencode_plugins_header: "OpenCode Plugins",
-            omo_header: "Oh My OpenAgent",
-            omo_slim_header: "Oh My OpenCode Slim",
-            claude_header: "Claude Code",
-            codex_header: "Codex",
-            openclaw_header: "OpenClaw",
-            skills_header: "Skills",
-            mcp_header: "MCP Servers",
-            no_config: "  No configs",
-            no_model: "  No models",
-            no_tools: "  No tools",
-        }
-    } else {
-        TrayTexts {
-            show_window: "打开主界面",
-            quit: "退出",
-            main_model: "主模型",
-            small_model: "小模型",
-            global_prompt: "全局提示词",
-            opencode_header: "OpenCode",
-            opencode_plugins_header: "OpenCode 插件",
-            omo_header: "Oh My OpenAgent",
-            omo_slim_header: "Oh My OpenCode Slim",
-            claude_header: "Claude Code",
-            codex_header: "Codex",
-            openclaw_header: "OpenClaw",
-            skills_header: "Skills",
-            mcp_header: "MCP Servers",
-            no_config: "  暂无配置",
-            no_model: "  暂无模型",
-            no_tools: "  暂无工具",
-        }
+    let locale = Locale::from_language(language);
+    let text = |key| locale::text_in(locale, key);
+    TrayTexts {
+        show_window: text("tray.show_window"),
+        quick_switch: text("tray.quick_switch"),
+        quit: text("tray.quit"),
+        main_model: text("tray.main_model"),
+        small_model: text("tray.small_model"),
+        global_prompt: text("tray.global_prompt"),
+        opencode_header: "OpenCode",
+        opencode_plugins_header: text("tray.opencode_plugins"),
+        omo_header: "Oh My OpenAgent",
+        omo_slim_header: "Oh My OpenCode Slim",
+        claude_header: "Claude Code",
+        codex_header: "Codex",
+        openclaw_header: "OpenClaw",
+        aider_header: "Aider",
+        skills_header: "Skills",
+        mcp_header: "MCP Servers",
+        no_config: text("tray.no_config"),
+        no_model: text("tray.no_model"),
+        no_tools: text("tray.no_tools"),
+        recent_models: text("tray.recent_models"),
+        opencode_update_available: text("tray.opencode_update_available"),
+        status_header: text("tray.status"),
+        ssh_sync: text("tray.ssh_sync"),
+        wsl_sync: text("tray.wsl_sync"),
+        sync_never: text("tray.sync_never"),
+        sync_failed: text("tray.sync_failed"),
+        connection_lost: text("tray.connection_lost"),
+        sync_now: text("tray.sync_now"),
+        open_settings: text("tray.open_settings"),
+        app_update_available: text("tray.app_update_available"),
     }
 }
 
@@ -102,12 +108,28 @@ fn tray_texts(language: &str) -> TrayTexts {
 static TRAY_REFRESHING: AtomicBool = AtomicBool::new(false);
 /// Signals that another refresh was requested during the current one
 static TRAY_REFRESH_PENDING: AtomicBool = AtomicBool::new(false);
+/// Bumped by every scheduled refresh; only the latest one within the debounce window runs
+static TRAY_REFRESH_GENERATION: AtomicU64 = AtomicU64::new(0);
+/// Quiet period before a scheduled refresh rebuilds the menu
+const TRAY_REFRESH_DEBOUNCE: Duration = Duration::from_millis(300);
 const TRAY_SHOW_MENU_ID: &str = "show";
 const TRAY_QUIT_MENU_ID: &str = "app_quit";
+const TRAY_QUICK_SWITCH_MENU_ID: &str = "quick_switch";
+/// Event asking the main window to open a screen ("ssh", "wsl" or "settings")
+const TRAY_OPEN_SCREEN_EVENT: &str = "tray-open-screen";
 
-fn request_app_exit<R: Runtime>(app: &AppHandle<R>) {
-    crate::APP_EXIT_REQUESTED.store(true, Ordering::SeqCst);
-    app.exit(0);
+pub(crate) fn show_main_window<R: Runtime>(app: &AppHandle<R>) {
+    // macOS: Switch back to Regular mode to show in Dock
+    #[cfg(target_os = "macos")]
+    {
+        use tauri::ActivationPolicy;
+        let _ = app.set_activation_policy(ActivationPolicy::Regular);
+    }
+
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
 }
 
 #[cfg(target_os = "macos")]
@@ -157,20 +179,37 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::er
         .on_menu_event(move |app, event| {
             let event_id = event.id().as_ref().to_string();
 
-            if event_id == TRAY_SHOW_MENU_ID {
-                // macOS: Switch back to Regular mode to show in Dock
-                #[cfg(target_os = "macos")]
-                {
-                    use tauri::ActivationPolicy;
-                    let _ = app.set_activation_policy(ActivationPolicy::Regular);
-                }
+            // Everything except opening windows changes a config or starts a sync
+            if ![
+                TRAY_SHOW_MENU_ID,
+                TRAY_QUICK_SWITCH_MENU_ID,
+                TRAY_QUIT_MENU_ID,
+            ]
+            .contains(&event_id.as_str())
+                && !event_id.starts_with("status_open_")
+            {
+                crate::audit_log::record(
+                    app,
+                    "tray_menu",
+                    "tray",
+                    &serde_json::json!({ "item": event_id }),
+                );
+            }
 
-                if let Some(window) = app.get_webview_window("main") {
-                    let _ = window.show();
-                    let _ = window.set_focus();
+            if event_id == TRAY_SHOW_MENU_ID {
+                show_main_window(app);
+            } else if event_id == TRAY_QUICK_SWITCH_MENU_ID {
+                if let Err(e) = crate::quick_switch::open_quick_switch(app) {
+                    log::warn!("Failed to open quick switch: {}", e);
                 }
             } else if event_id == TRAY_QUIT_MENU_ID {
-                request_app_exit(app);
+                window_behavior::request_quit(app);
+            } else if let Some(target) = event_id.strip_prefix("status_sync_") {
+                // Handled by the sync listeners registered in lib.rs
+                let _ = app.emit(&format!("{}-sync-request-all", target), ());
+            } else if let Some(screen) = event_id.strip_prefix("status_open_") {
+                show_main_window(app);
+                let _ = app.emit(TRAY_OPEN_SCREEN_EVENT, screen);
             } else if let Some(config_id) = event_id.strip_prefix("omo_config_") {
                 let config_id = config_id.to_string();
                 let app_handle = app.clone();
@@ -181,7 +220,7 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::er
                         eprintln!("Failed to apply Oh My OpenAgent config: {}", e);
                     }
                     // Refresh tray menu to update checkmarks
-                    let _ = refresh_tray_menus(&app_handle).await;
+                    schedule_tray_refresh(&app_handle);
                 });
             } else if let Some(config_id) = event_id.strip_prefix("omo_slim_config_") {
                 let config_id = config_id.to_string();
@@ -194,7 +233,7 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::er
                         eprintln!("Failed to apply Oh My OpenCode Slim config: {}", e);
                     }
                     // Refresh tray menu to update checkmarks
-                    let _ = refresh_tray_menus(&app_handle).await;
+                    schedule_tray_refresh(&app_handle);
                 });
             } else if let Some(provider_id) = event_id.strip_prefix("claude_provider_") {
                 let provider_id = provider_id.to_string();
@@ -206,7 +245,7 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::er
                         eprintln!("Failed to apply Claude provider: {}", e);
                     }
                     // Refresh tray menu to update checkmarks
-                    let _ = refresh_tray_menus(&app_handle).await;
+                    schedule_tray_refresh(&app_handle);
                 });
             } else if let Some(config_id) = event_id.strip_prefix("claude_prompt_") {
                 let config_id = config_id.to_string();
@@ -217,10 +256,14 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::er
                     {
                         eprintln!("Failed to apply Claude prompt config: {}", e);
                     }
-                    let _ = refresh_tray_menus(&app_handle).await;
+                    schedule_tray_refresh(&app_handle);
                 });
-            } else if let Some(remaining) = event_id.strip_prefix("opencode_model_") {
+            } else if let Some(remaining) = event_id
+                .strip_prefix("opencode_model_")
+                .or_else(|| event_id.strip_prefix("opencode_recent_"))
+            {
                 // Parse: opencode_model_main|small_provider/model_id
+                // (opencode_recent_* entries come from the "Recent" group)
                 if let Some((model_type, item_id)) = remaining.split_once('_') {
                     let model_type = model_type.to_string();
                     let item_id = item_id.to_string();
@@ -233,7 +276,7 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::er
                             eprintln!("Failed to apply OpenCode model: {}", e);
                         }
                         // Refresh tray menu to update checkmarks
-                        let _ = refresh_tray_menus(&app_handle).await;
+                        schedule_tray_refresh(&app_handle);
                     });
                 }
             } else if let Some(plugin_name) = event_id.strip_prefix("opencode_plugin_") {
@@ -246,7 +289,7 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::er
                         eprintln!("Failed to apply OpenCode plugin: {}", e);
                     }
                     // Refresh tray menu to update checkmarks
-                    let _ = refresh_tray_menus(&app_handle).await;
+                    schedule_tray_refresh(&app_handle);
                 });
             } else if let Some(config_id) = event_id.strip_prefix("opencode_prompt_") {
                 let config_id = config_id.to_string();
@@ -257,7 +300,7 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::er
                     {
                         eprintln!("Failed to apply OpenCode prompt config: {}", e);
                     }
-                    let _ = refresh_tray_menus(&app_handle).await;
+                    schedule_tray_refresh(&app_handle);
                 });
             } else if let Some(provider_id) = event_id.strip_prefix("codex_provider_") {
                 let provider_id = provider_id.to_string();
@@ -268,7 +311,7 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::er
                     {
                         eprintln!("Failed to apply Codex provider: {}", e);
                     }
-                    let _ = refresh_tray_menus(&app_handle).await;
+                    schedule_tray_refresh(&app_handle);
                 });
             } else if let Some(config_id) = event_id.strip_prefix("codex_prompt_") {
                 let config_id = config_id.to_string();
@@ -279,7 +322,18 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::er
                     {
                         eprintln!("Failed to apply Codex prompt config: {}", e);
                     }
-                    let _ = refresh_tray_menus(&app_handle).await;
+                    schedule_tray_refresh(&app_handle);
+                });
+            } else if let Some(provider_id) = event_id.strip_prefix("aider_provider_") {
+                let provider_id = provider_id.to_string();
+                let app_handle = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) =
+                        aider_tray::apply_aider_provider(&app_handle, &provider_id).await
+                    {
+                        eprintln!("Failed to apply Aider provider: {}", e);
+                    }
+                    schedule_tray_refresh(&app_handle);
                 });
             } else if let Some(item_id) = event_id.strip_prefix("openclaw_model_") {
                 let item_id = item_id.to_string();
@@ -289,7 +343,7 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::er
                     {
                         eprintln!("Failed to apply OpenClaw model: {}", e);
                     }
-                    let _ = refresh_tray_menus(&app_handle).await;
+                    schedule_tray_refresh(&app_handle);
                 });
             } else if let Some(remaining) = event_id.strip_prefix("skill_tool_") {
                 // Parse: skill_tool_{skill_id}\x01{tool_key}
@@ -304,7 +358,7 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::er
                         {
                             eprintln!("Failed to toggle skill tool: {}", e);
                         }
-                        let _ = refresh_tray_menus(&app_handle).await;
+                        schedule_tray_refresh(&app_handle);
                     });
                 }
             } else if let Some(remaining) = event_id.strip_prefix("mcp_tool_") {
@@ -320,7 +374,7 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::er
                         {
                             eprintln!("Failed to toggle MCP tool: {}", e);
                         }
-                        let _ = refresh_tray_menus(&app_handle).await;
+                        schedule_tray_refresh(&app_handle);
                     });
                 }
             }
@@ -363,6 +417,26 @@ pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> Result<(), Box<dyn std::er
     Ok(())
 }
 
+/// Schedule a debounced tray refresh without waiting for it
+///
+/// Rebuilding the menu re-reads configs, auth.json, the free-model cache and several DB
+/// tables, so bursts of changes (e.g. a sync emitting several `config-changed` events)
+/// are collapsed into a single rebuild once things settle. The built menu stays attached
+/// to the tray icon in the meantime, so opening it never waits on a rebuild.
+pub fn schedule_tray_refresh<R: Runtime>(app: &AppHandle<R>) {
+    let generation = TRAY_REFRESH_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(TRAY_REFRESH_DEBOUNCE).await;
+        if TRAY_REFRESH_GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        if let Err(e) = refresh_tray_menus(&app).await {
+            log::warn!("Failed to refresh tray menu: {}", e);
+        }
+    });
+}
+
 /// Refresh tray menus with deduplication (coalescing pattern)
 pub async fn refresh_tray_menus<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
     // If already refreshing, mark pending and return
@@ -420,6 +494,8 @@ async fn refresh_tray_menus_inner<R: Runtime>(app: &AppHandle<R>) -> Result<(),
     let opencode_plugins_enabled =
         is_tab_visible("opencode") && opencode_tray::is_plugins_enabled_for_tray(app).await;
     let skills_enabled = skills_tray::is_skills_enabled_for_tray(app).await;
+    // Aider has no tab; its section is opt-in from the Aider preferences
+    let aider_enabled = aider_tray::is_enabled_for_tray(app).await;
 
     // Get data from modules (only if enabled)
     let (mut main_model_data, mut small_model_data) = if opencode_enabled {
@@ -430,16 +506,19 @@ async fn refresh_tray_menus_inner<R: Runtime>(app: &AppHandle<R>) -> Result<(),
                 title: texts.main_model.to_string(),
                 current_display: String::new(),
                 items: vec![],
+                recent: vec![],
             },
             opencode_tray::TrayModelData {
                 title: texts.small_model.to_string(),
                 current_display: String::new(),
                 items: vec![],
+                recent: vec![],
             },
         )
     };
     main_model_data.title = texts.main_model.to_string();
     small_model_data.title = texts.small_model.to_string();
+    let status_data = load_status_data(app).await;
 
     let mut opencode_plugin_data = if opencode_plugins_enabled {
         opencode_tray::get_opencode_tray_plugin_data(app).await?
@@ -524,6 +603,16 @@ async fn refresh_tray_menus_inner<R: Runtime>(app: &AppHandle<R>) -> Result<(),
     };
     codex_prompt_data.title = texts.global_prompt.to_string();
 
+    let mut aider_data = if aider_enabled {
+        aider_tray::get_aider_tray_data(app).await?
+    } else {
+        aider_tray::TrayProviderData {
+            title: texts.aider_header.to_string(),
+            items: vec![],
+        }
+    };
+    aider_data.title = texts.aider_header.to_string();
+
     let mut openclaw_model_data = if openclaw_enabled {
         openclaw_tray::get_openclaw_tray_model_data(app).await?
     } else {
@@ -566,6 +655,14 @@ async fn refresh_tray_menus_inner<R: Runtime>(app: &AppHandle<R>) -> Result<(),
         None::<&str>,
     )
     .map_err(|e| e.to_string())?;
+    let quick_switch_item = MenuItem::with_id(
+        app,
+        TRAY_QUICK_SWITCH_MENU_ID,
+        texts.quick_switch,
+        true,
+        None::<&str>,
+    )
+    .map_err(|e| e.to_string())?;
     // OpenCode Model section (only if enabled)
     let opencode_model_header = if opencode_enabled {
         Some(
@@ -582,6 +679,26 @@ async fn refresh_tray_menus_inner<R: Runtime>(app: &AppHandle<R>) -> Result<(),
         None
     };
 
+    // OpenCode update hint from the last check_opencode_update result (no network here)
+    let opencode_update_item = match crate::coding::open_code::cached_opencode_update() {
+        Some(update) if opencode_enabled && update.has_update => Some(
+            MenuItem::with_id(
+                app,
+                "opencode_update_available",
+                format!(
+                    "{}: {} → {}",
+                    texts.opencode_update_available,
+                    update.current_version.unwrap_or_default(),
+                    update.latest_version
+                ),
+                false,
+                None::<&str>,
+            )
+            .map_err(|e| e.to_string())?,
+        ),
+        _ => None,
+    };
+
     let main_model_submenu = if opencode_enabled {
         Some(build_model_submenu(app, &main_model_data, "main", texts).await?)
     } else {
@@ -868,6 +985,36 @@ async fn refresh_tray_menus_inner<R: Runtime>(app: &AppHandle<R>) -> Result<(),
         }
     }
 
+    // Aider section (only if enabled and has items)
+    let aider_has_items = aider_enabled && !aider_data.items.is_empty();
+    let aider_header = if aider_has_items {
+        Some(
+            MenuItem::with_id(app, "aider_header", &aider_data.title, false, None::<&str>)
+                .map_err(|e| e.to_string())?,
+        )
+    } else {
+        None
+    };
+
+    let mut aider_items: Vec<Box<dyn tauri::menu::IsMenuItem<R>>> = Vec::new();
+    if aider_has_items {
+        for item in aider_data.items {
+            let item_id = format!("aider_provider_{}", item.id);
+            let menu_item: Box<dyn tauri::menu::IsMenuItem<R>> = Box::new(
+                CheckMenuItem::with_id(
+                    app,
+                    &item_id,
+                    &item.display_name,
+                    true,
+                    item.is_selected,
+                    None::<&str>,
+                )
+                .map_err(|e| e.to_string())?,
+            );
+            aider_items.push(menu_item);
+        }
+    }
+
     // OpenClaw section (only if enabled and has items)
     let openclaw_header = if openclaw_has_items {
         Some(
@@ -884,6 +1031,16 @@ async fn refresh_tray_menus_inner<R: Runtime>(app: &AppHandle<R>) -> Result<(),
         None
     };
 
+    let status_items = build_status_items(app, &status_data, texts)?;
+    let status_header = MenuItem::with_id(
+        app,
+        "status_header",
+        texts.status_header,
+        false,
+        None::<&str>,
+    )
+    .map_err(|e| e.to_string())?;
+
     let menu = Menu::new(app).map_err(|e| e.to_string())?;
     let append_separator = |menu: &Menu<R>| -> Result<(), String> {
         let separator = PredefinedMenuItem::separator(app).map_err(|e| e.to_string())?;
@@ -891,6 +1048,7 @@ async fn refresh_tray_menus_inner<R: Runtime>(app: &AppHandle<R>) -> Result<(),
     };
 
     menu.append(&show_item).map_err(|e| e.to_string())?;
+    menu.append(&quick_switch_item).map_err(|e| e.to_string())?;
     append_separator(&menu)?;
 
     // Add OpenCode section if enabled
@@ -898,6 +1056,9 @@ async fn refresh_tray_menus_inner<R: Runtime>(app: &AppHandle<R>) -> Result<(),
         if let Some(ref header) = opencode_model_header {
             menu.append(header).map_err(|e| e.to_string())?;
         }
+        if let Some(ref item) = opencode_update_item {
+            menu.append(item).map_err(|e| e.to_string())?;
+        }
         if let Some(ref submenu) = main_model_submenu {
             menu.append(submenu).map_err(|e| e.to_string())?;
         }
@@ -985,6 +1146,16 @@ async fn refresh_tray_menus_inner<R: Runtime>(app: &AppHandle<R>) -> Result<(),
         }
         append_separator(&menu)?;
     }
+    // Add Aider section if enabled
+    if aider_has_items {
+        if let Some(ref header) = aider_header {
+            menu.append(header).map_err(|e| e.to_string())?;
+        }
+        for item in &aider_items {
+            menu.append(item.as_ref()).map_err(|e| e.to_string())?;
+        }
+        append_separator(&menu)?;
+    }
     // Add OpenClaw section if enabled
     if openclaw_has_items {
         if let Some(ref header) = openclaw_header {
@@ -995,6 +1166,14 @@ async fn refresh_tray_menus_inner<R: Runtime>(app: &AppHandle<R>) -> Result<(),
         }
         append_separator(&menu)?;
     }
+    // Add Status section (sync results, connection health, app update)
+    if !status_items.is_empty() {
+        menu.append(&status_header).map_err(|e| e.to_string())?;
+        for item in &status_items {
+            menu.append(item.as_ref()).map_err(|e| e.to_string())?;
+        }
+        append_separator(&menu)?;
+    }
     menu.append(&quit_item).map_err(|e| e.to_string())?;
 
     // Update tray menu
@@ -1004,6 +1183,165 @@ async fn refresh_tray_menus_inner<R: Runtime>(app: &AppHandle<R>) -> Result<(),
     Ok(())
 }
 
+/// Last sync result of SSH or WSL, read from its sync config record
+struct SyncStatusSummary {
+    status: String,
+    time: Option<String>,
+    error: Option<String>,
+}
+
+async fn load_sync_status<R: Runtime>(
+    app: &AppHandle<R>,
+    table: &str,
+) -> Option<SyncStatusSummary> {
+    let state = app.state::<crate::db::DbState>();
+    let db = state.db();
+    let records: Vec<serde_json::Value> = db
+        .query(format!(
+            "SELECT enabled, last_sync_status, last_sync_time, last_sync_error FROM {}:`config` LIMIT 1",
+            table
+        ))
+        .await
+        .ok()?
+        .take(0)
+        .ok()?;
+    let record = records.into_iter().next()?;
+    if !record
+        .get("enabled")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+    {
+        return None;
+    }
+    let get = |key: &str| record.get(key).and_then(|v| v.as_str()).map(String::from);
+    Some(SyncStatusSummary {
+        status: get("last_sync_status").unwrap_or_else(|| "never".to_string()),
+        time: get("last_sync_time"),
+        error: get("last_sync_error"),
+    })
+}
+
+/// Title of a sync status submenu, e.g. "SSH 同步: ✓ 05-12 14:03"
+fn sync_status_title(label: &str, summary: &SyncStatusSummary, texts: TrayTexts) -> String {
+    let time = summary
+        .time
+        .as_deref()
+        .and_then(|time| chrono::DateTime::parse_from_rfc3339(time).ok())
+        .map(|time| {
+            time.with_timezone(&chrono::Local)
+                .format("%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_default();
+    match summary.status.as_str() {
+        "success" => format!("{}: ✓ {}", label, time),
+        "error" => {
+            let error: String = summary
+                .error
+                .as_deref()
+                .unwrap_or_default()
+                .chars()
+                .take(40)
+                .collect();
+            format!("{}: ✗ {} {} {}", label, texts.sync_failed, time, error)
+        }
+        _ => format!("{}: {}", label, texts.sync_never),
+    }
+}
+
+/// Submenu with the sync status as title and "sync now" / "open settings" actions
+fn build_sync_status_submenu<R: Runtime>(
+    app: &AppHandle<R>,
+    target: &str, // "ssh" or "wsl"
+    title: &str,
+    texts: TrayTexts,
+) -> Result<Submenu<R>, String> {
+    let submenu = Submenu::with_id(app, format!("status_{}_submenu", target), title, true)
+        .map_err(|e| e.to_string())?;
+    let sync_item = MenuItem::with_id(
+        app,
+        format!("status_sync_{}", target),
+        texts.sync_now,
+        true,
+        None::<&str>,
+    )
+    .map_err(|e| e.to_string())?;
+    let open_item = MenuItem::with_id(
+        app,
+        format!("status_open_{}", target),
+        texts.open_settings,
+        true,
+        None::<&str>,
+    )
+    .map_err(|e| e.to_string())?;
+    submenu.append(&sync_item).map_err(|e| e.to_string())?;
+    submenu.append(&open_item).map_err(|e| e.to_string())?;
+    Ok(submenu)
+}
+
+/// Data shown in the status section
+struct TrayStatusData {
+    ssh: Option<SyncStatusSummary>,
+    wsl: Option<SyncStatusSummary>,
+    app_update: Option<String>,
+}
+
+async fn load_status_data<R: Runtime>(app: &AppHandle<R>) -> TrayStatusData {
+    #[cfg(target_os = "windows")]
+    let wsl = load_sync_status(app, "wsl_sync_config").await;
+    #[cfg(not(target_os = "windows"))]
+    let wsl = None;
+
+    TrayStatusData {
+        ssh: load_sync_status(app, "ssh_sync_config").await,
+        wsl,
+        // From the last check_for_updates result (no network here)
+        app_update: crate::update::cached_app_update(),
+    }
+}
+
+/// Items of the status section; empty when there is nothing to report
+fn build_status_items<R: Runtime>(
+    app: &AppHandle<R>,
+    data: &TrayStatusData,
+    texts: TrayTexts,
+) -> Result<Vec<Box<dyn tauri::menu::IsMenuItem<R>>>, String> {
+    let mut items: Vec<Box<dyn tauri::menu::IsMenuItem<R>>> = Vec::new();
+
+    if let Some(summary) = &data.ssh {
+        let mut title = sync_status_title(texts.ssh_sync, summary, texts);
+        // Reported by the SSH health check in lib.rs
+        if crate::coding::ssh::is_connection_lost() {
+            title = format!("{} ({})", title, texts.connection_lost);
+        }
+        items.push(Box::new(build_sync_status_submenu(
+            app, "ssh", &title, texts,
+        )?));
+    }
+
+    if let Some(summary) = &data.wsl {
+        let title = sync_status_title(texts.wsl_sync, summary, texts);
+        items.push(Box::new(build_sync_status_submenu(
+            app, "wsl", &title, texts,
+        )?));
+    }
+
+    if let Some(version) = &data.app_update {
+        items.push(Box::new(
+            MenuItem::with_id(
+                app,
+                "status_open_settings",
+                format!("{}: v{}", texts.app_update_available, version),
+                true,
+                None::<&str>,
+            )
+            .map_err(|e| e.to_string())?,
+        ));
+    }
+
+    Ok(items)
+}
+
 /// Build a model selection submenu from tray data
 async fn build_model_submenu<R: Runtime>(
     app: &AppHandle<R>,
@@ -1031,6 +1369,35 @@ async fn build_model_submenu<R: Runtime>(
         .map_err(|e| e.to_string())?;
         submenu.append(&empty_item).map_err(|e| e.to_string())?;
     } else {
+        // Recently applied models first, so frequent switches don't need scrolling
+        if !data.recent.is_empty() {
+            let recent_header = MenuItem::with_id(
+                app,
+                &format!("opencode_{}_recent_header", model_type),
+                texts.recent_models,
+                false,
+                None::<&str>,
+            )
+            .map_err(|e| e.to_string())?;
+            submenu.append(&recent_header).map_err(|e| e.to_string())?;
+
+            for item in &data.recent {
+                let menu_item = CheckMenuItem::with_id(
+                    app,
+                    &format!("opencode_recent_{}_{}", model_type, item.id),
+                    &item.display_name,
+                    true,
+                    item.is_selected,
+                    None::<&str>,
+                )
+                .map_err(|e| e.to_string())?;
+                submenu.append(&menu_item).map_err(|e| e.to_string())?;
+            }
+
+            let separator = PredefinedMenuItem::separator(app).map_err(|e| e.to_string())?;
+            submenu.append(&separator).map_err(|e| e.to_string())?;
+        }
+
         // Group by provider so the tray menu is easier to scan.
         // - Parent submenu: 主模型/小模型
         // - 2nd level: provider name
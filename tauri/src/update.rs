@@ -1,12 +1,100 @@
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use minisign_verify::{PublicKey, Signature};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
 use std::time::{Duration, Instant};
-use tauri::Emitter;
+use tauri::{Emitter, Manager};
 use tauri_plugin_updater::UpdaterExt;
 
 use crate::db::DbState;
 use crate::http_client;
+use crate::settings;
+
+const GITHUB_REPO: &str = "coulsontl/ai-toolbox";
+/// Event emitted by the scheduled check when a version that was not skipped is found
+const UPDATE_AVAILABLE_EVENT: &str = "update-available";
+
+/// Release channel used for update checks (`update_channel` setting)
+///
+/// Each channel has its own `latest.json`: stable uses the latest GitHub release,
+/// beta and nightly use a fixed pre-release tag that CI overwrites on every build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateChannel {
+    Stable,
+    Beta,
+    Nightly,
+}
+
+impl UpdateChannel {
+    pub fn from_setting(value: &str) -> Self {
+        match value {
+            "beta" => Self::Beta,
+            "nightly" => Self::Nightly,
+            _ => Self::Stable,
+        }
+    }
+
+    /// Channel a version was built for, from its pre-release tag
+    /// ("1.2.0" -> stable, "1.2.0-beta.1" -> beta, "1.2.0-nightly.20250101" -> nightly)
+    fn of_version(version: &str) -> Self {
+        match version.split_once('-') {
+            None => Self::Stable,
+            Some((_, pre)) if pre.starts_with("nightly") => Self::Nightly,
+            Some(_) => Self::Beta,
+        }
+    }
+
+    /// Higher is less stable
+    fn rank(self) -> u8 {
+        match self {
+            Self::Stable => 0,
+            Self::Beta => 1,
+            Self::Nightly => 2,
+        }
+    }
+
+    pub fn latest_json_url(self) -> String {
+        match self {
+            Self::Stable => format!(
+                "https://github.com/{}/releases/latest/download/latest.json",
+                GITHUB_REPO
+            ),
+            Self::Beta => format!(
+                "https://github.com/{}/releases/download/updater-beta/latest.json",
+                GITHUB_REPO
+            ),
+            Self::Nightly => format!(
+                "https://github.com/{}/releases/download/updater-nightly/latest.json",
+                GITHUB_REPO
+            ),
+        }
+    }
+}
+
+/// Update channel from settings (stable when settings cannot be read)
+async fn get_update_channel(state: &tauri::State<'_, DbState>) -> UpdateChannel {
+    settings::get_settings(state.clone())
+        .await
+        .map(|settings| UpdateChannel::from_setting(&settings.update_channel))
+        .unwrap_or(UpdateChannel::Stable)
+}
+
+/// Whether `latest` from the `channel` feed should be offered over `current`
+///
+/// Newer versions are always offered. Switching to a more stable channel (e.g. from a
+/// nightly build to stable) also offers that channel's latest release even when its
+/// version is lower, so the channel can be changed without reinstalling.
+fn is_update_for_channel(channel: UpdateChannel, current: &str, latest: &str) -> bool {
+    match compare_release_versions(latest, current) {
+        1 => true,
+        0 => false,
+        _ => UpdateChannel::of_version(current).rank() > channel.rank(),
+    }
+}
 
 /// Response from GitHub latest.json
 #[derive(Debug, Serialize, Deserialize)]
@@ -33,6 +121,58 @@ pub struct UpdateCheckResult {
     pub release_notes: String,
     pub signature: Option<String>,
     pub url: Option<String>,
+    /// The latest version is in the "skip this version" list
+    pub skipped: bool,
+}
+
+/// Update check state persisted in `update_state:`app``
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct UpdateState {
+    #[serde(default)]
+    last_check_time: Option<String>,
+    #[serde(default)]
+    skipped_versions: Vec<String>,
+    /// Version that ran before the current one (rollback target)
+    #[serde(default)]
+    previous_version: Option<String>,
+    #[serde(default)]
+    last_run_version: Option<String>,
+}
+
+async fn load_update_state(db_state: &DbState) -> Result<UpdateState, String> {
+    let db = db_state.db();
+    let mut result = db
+        .query("SELECT * OMIT id FROM update_state:`app` LIMIT 1")
+        .await
+        .map_err(|e| format!("Failed to query update state: {}", e))?;
+    let records: Vec<serde_json::Value> = result
+        .take(0)
+        .map_err(|e| format!("Failed to parse update state: {}", e))?;
+
+    Ok(records
+        .into_iter()
+        .next()
+        .and_then(|record| serde_json::from_value(record).ok())
+        .unwrap_or_default())
+}
+
+async fn save_update_state(db_state: &DbState, update_state: &UpdateState) -> Result<(), String> {
+    let db = db_state.db();
+    let data = serde_json::to_value(update_state)
+        .map_err(|e| format!("Failed to serialize update state: {}", e))?;
+    db.query("UPSERT update_state:`app` CONTENT $data")
+        .bind(("data", data))
+        .await
+        .map_err(|e| format!("Failed to save update state: {}", e))?;
+    Ok(())
+}
+
+/// Latest version found by the last update check, if newer than the running one
+static AVAILABLE_UPDATE: Mutex<Option<String>> = Mutex::new(None);
+
+/// Pending app update from the last check, used by the tray status section
+pub fn cached_app_update() -> Option<String> {
+    AVAILABLE_UPDATE.lock().ok()?.clone()
 }
 
 /// Check for updates from GitHub releases
@@ -41,11 +181,15 @@ pub async fn check_for_updates(
     app_handle: tauri::AppHandle,
     state: tauri::State<'_, DbState>,
 ) -> Result<UpdateCheckResult, String> {
-    const GITHUB_REPO: &str = "coulsontl/ai-toolbox";
-    let latest_json_url = format!(
-        "https://github.com/{}/releases/latest/download/latest.json",
-        GITHUB_REPO
-    );
+    check_for_updates_internal(&app_handle, &state).await
+}
+
+async fn check_for_updates_internal(
+    app_handle: &tauri::AppHandle,
+    state: &tauri::State<'_, DbState>,
+) -> Result<UpdateCheckResult, String> {
+    let channel = get_update_channel(state).await;
+    let latest_json_url = channel.latest_json_url();
 
     // Get current version from package info
     let current_version = app_handle.package_info().version.to_string();
@@ -54,7 +198,7 @@ pub async fn check_for_updates(
     let current_platform = detect_current_platform();
 
     // Fetch latest.json using http_client with proxy support
-    let client = http_client::client(&state).await?;
+    let client = http_client::client(state).await?;
     let response = client
         .get(&latest_json_url)
         .send()
@@ -75,7 +219,26 @@ pub async fn check_for_updates(
 
     let latest_version = release.version.trim_start_matches('v').to_string();
 
-    let has_update = compare_versions(&latest_version, &current_version) > 0;
+    let has_update = is_update_for_channel(channel, &current_version, &latest_version);
+
+    let skipped = load_update_state(state)
+        .await
+        .map(|update_state| update_state.skipped_versions.contains(&latest_version))
+        .unwrap_or(false);
+
+    // Skipped versions are not shown in the tray
+    let available = (has_update && !skipped).then(|| latest_version.clone());
+    let changed = match AVAILABLE_UPDATE.lock() {
+        Ok(mut guard) => {
+            let changed = *guard != available;
+            *guard = available;
+            changed
+        }
+        Err(_) => false,
+    };
+    if changed {
+        crate::tray::schedule_tray_refresh(app_handle);
+    }
 
     // Get signature and url for current platform
     let platform_info = release.platforms.get(&current_platform);
@@ -97,9 +260,103 @@ pub async fn check_for_updates(
         release_notes: release.notes.unwrap_or_default(),
         signature,
         url,
+        skipped,
     })
 }
 
+/// Add a version to the "skip this version" list
+#[tauri::command]
+pub async fn skip_update_version(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, DbState>,
+    version: String,
+) -> Result<(), String> {
+    let version = version.trim_start_matches('v').to_string();
+    let mut update_state = load_update_state(&state).await?;
+    if !update_state.skipped_versions.contains(&version) {
+        update_state.skipped_versions.push(version.clone());
+        save_update_state(&state, &update_state).await?;
+    }
+
+    let cleared = match AVAILABLE_UPDATE.lock() {
+        Ok(mut guard) if guard.as_deref() == Some(version.as_str()) => {
+            *guard = None;
+            true
+        }
+        _ => false,
+    };
+    if cleared {
+        crate::tray::schedule_tray_refresh(&app_handle);
+    }
+    Ok(())
+}
+
+/// Start the scheduled update check as a background task
+///
+/// Checks at the `update_check_interval` setting ("daily", "weekly" or "never"); the
+/// last check time is kept in the database so restarts do not reset the schedule.
+/// Also records the running version for rollback.
+pub fn start_update_check_scheduler(app_handle: tauri::AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = track_running_version(&app_handle).await {
+            log::warn!("Failed to record running version: {}", e);
+        }
+
+        // Initial delay: let startup (and the frontend's own startup check) finish first
+        tokio::time::sleep(Duration::from_secs(60)).await;
+
+        loop {
+            if let Err(e) = run_scheduled_check(&app_handle).await {
+                log::warn!("Scheduled update check failed: {}", e);
+            }
+
+            tokio::time::sleep(Duration::from_secs(1800)).await;
+        }
+    });
+}
+
+async fn run_scheduled_check(app_handle: &tauri::AppHandle) -> Result<(), String> {
+    let state = app_handle.state::<DbState>();
+    let settings = settings::get_settings(state.clone()).await?;
+    let Some(interval) = check_interval(&settings.update_check_interval) else {
+        return Ok(());
+    };
+
+    let mut update_state = load_update_state(&state).await?;
+    if !is_check_due(update_state.last_check_time.as_deref(), interval) {
+        return Ok(());
+    }
+
+    // Record the attempt even when it fails, so an offline machine does not retry
+    // every 30 minutes
+    update_state.last_check_time = Some(Utc::now().to_rfc3339());
+    save_update_state(&state, &update_state).await?;
+
+    let result = check_for_updates_internal(app_handle, &state).await?;
+    if result.has_update && !result.skipped {
+        log::info!("Update {} is available", result.latest_version);
+        let _ = app_handle.emit(UPDATE_AVAILABLE_EVENT, &result);
+    }
+    Ok(())
+}
+
+/// Interval of the `update_check_interval` setting, `None` for "never"
+fn check_interval(setting: &str) -> Option<chrono::Duration> {
+    match setting {
+        "never" => None,
+        "weekly" => Some(chrono::Duration::days(7)),
+        _ => Some(chrono::Duration::days(1)),
+    }
+}
+
+fn is_check_due(last_check_time: Option<&str>, interval: chrono::Duration) -> bool {
+    let Some(last_dt) = last_check_time.and_then(|time| DateTime::parse_from_rfc3339(time).ok())
+    else {
+        return true;
+    };
+    Utc::now().signed_duration_since(last_dt) >= interval
+}
+
 /// Detect current platform string for matching latest.json
 #[allow(unreachable_code)]
 fn detect_current_platform() -> String {
@@ -134,8 +391,88 @@ pub async fn install_update(
     app: tauri::AppHandle,
     state: tauri::State<'_, DbState>,
 ) -> Result<bool, String> {
-    // Get proxy settings from database
-    let (proxy_mode, proxy_url) = http_client::get_proxy_from_settings(&state).await?;
+    let channel = get_update_channel(&state).await;
+    let endpoint = parse_endpoint(&channel.latest_json_url())?;
+
+    // Check against the selected channel's feed
+    download_and_install_from(&app, &state, endpoint, move |current, latest| {
+        is_update_for_channel(channel, current, latest)
+    })
+    .await
+}
+
+/// Roll back to an older release (or reinstall a specific one)
+///
+/// Uses the `latest.json` attached to the `v{version}` GitHub release, so the artifact
+/// is signature-checked by the updater plugin exactly like a regular update.
+#[tauri::command]
+pub async fn rollback_update(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, DbState>,
+    version: String,
+) -> Result<bool, String> {
+    let version = version.trim().trim_start_matches('v').to_string();
+    if version.is_empty() {
+        return Err("Version is required".to_string());
+    }
+    let endpoint = parse_endpoint(&format!(
+        "https://github.com/{}/releases/download/v{}/latest.json",
+        GITHUB_REPO, version
+    ))?;
+
+    download_and_install_from(&app, &state, endpoint, move |current, latest| {
+        compare_release_versions(latest, current) != 0
+            && compare_release_versions(latest, &version) == 0
+    })
+    .await
+}
+
+/// Version the app was updated from, offered as the rollback target
+#[tauri::command]
+pub async fn get_previous_version(
+    state: tauri::State<'_, DbState>,
+) -> Result<Option<String>, String> {
+    Ok(load_update_state(&state).await?.previous_version)
+}
+
+fn parse_endpoint(url: &str) -> Result<tauri::Url, String> {
+    url.parse()
+        .map_err(|e| format!("Invalid update endpoint: {}", e))
+}
+
+/// Remember the version that ran before this one, so it can be rolled back to
+///
+/// Done on startup by comparing with the last running version, which also covers
+/// installs that exit the app (Windows installers) and manual reinstalls.
+async fn track_running_version(app: &tauri::AppHandle) -> Result<(), String> {
+    let state = app.state::<DbState>();
+    let current_version = app.package_info().version.to_string();
+    let mut update_state = load_update_state(&state).await?;
+    if update_state.last_run_version.as_deref() == Some(current_version.as_str()) {
+        return Ok(());
+    }
+    if let Some(last_run_version) = update_state.last_run_version.take() {
+        update_state.previous_version = Some(last_run_version);
+    }
+    update_state.last_run_version = Some(current_version);
+    save_update_state(&state, &update_state).await
+}
+
+/// Download and install the release described by the `latest.json` at `endpoint`
+///
+/// `should_install(current, remote)` decides whether the remote release is installed.
+async fn download_and_install_from<F>(
+    app: &tauri::AppHandle,
+    state: &DbState,
+    endpoint: tauri::Url,
+    should_install: F,
+) -> Result<bool, String>
+where
+    F: Fn(&str, &str) -> bool + Send + Sync + 'static,
+{
+    // Get proxy settings from database; the route follows the rules for the endpoint host
+    let proxy_settings = http_client::get_proxy_settings(state).await?;
+    let proxy_route = proxy_settings.route_for_url(endpoint.as_str());
 
     // Set proxy environment variables for the updater plugin
     // (tauri-plugin-updater reads these env vars for proxy configuration)
@@ -144,14 +481,14 @@ pub async fn install_update(
     let old_http_proxy_lower = std::env::var("http_proxy").ok();
     let old_https_proxy_lower = std::env::var("https_proxy").ok();
 
-    match proxy_mode {
-        http_client::ProxyMode::Direct => {
+    match proxy_route {
+        http_client::ProxyRoute::Direct => {
             std::env::remove_var("HTTP_PROXY");
             std::env::remove_var("HTTPS_PROXY");
             std::env::remove_var("http_proxy");
             std::env::remove_var("https_proxy");
         }
-        http_client::ProxyMode::Custom => {
+        http_client::ProxyRoute::Proxy(proxy_url) => {
             if !proxy_url.is_empty() {
                 std::env::set_var("HTTP_PROXY", &proxy_url);
                 std::env::set_var("HTTPS_PROXY", &proxy_url);
@@ -159,12 +496,26 @@ pub async fn install_update(
                 std::env::set_var("https_proxy", &proxy_url);
             }
         }
-        http_client::ProxyMode::System => {}
+        http_client::ProxyRoute::System => {}
     }
 
     // Check for updates using the updater plugin
-    let updater = app.updater().map_err(|e| e.to_string())?;
-    let result = match updater.check().await {
+    let updater = app
+        .updater_builder()
+        .endpoints(vec![endpoint])
+        .and_then(|builder| {
+            builder
+                .version_comparator(move |current, release| {
+                    should_install(&current.to_string(), &release.version.to_string())
+                })
+                .build()
+        })
+        .map_err(|e| e.to_string());
+    let check_result = match updater {
+        Ok(updater) => updater.check().await.map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    };
+    let result = match check_result {
         Ok(Some(update)) => {
             // Emit download started event
             let _ = app.emit(
@@ -285,9 +636,259 @@ pub async fn install_update(
     result
 }
 
+/// Install an update artifact from a local file, for machines without network access
+///
+/// The artifact (NSIS / MSI installer on Windows, `.app.tar.gz` on macOS, AppImage on
+/// Linux) must come with the `.sig` file published next to it in the release (by default
+/// `<path>.sig`). The signature is checked against the updater public key bundled in the
+/// app before anything is installed. The verified bytes are copied to an app-owned staging
+/// file and installed from there, so the file picked by the user can't be swapped between
+/// the check and the install. On Windows the installer is started and the app exits, like
+/// `install_update`; elsewhere the app must be restarted afterwards.
+#[tauri::command]
+pub async fn install_update_from_file(
+    app: tauri::AppHandle,
+    path: String,
+    signature_path: Option<String>,
+) -> Result<bool, String> {
+    let artifact_path = PathBuf::from(&path);
+    let signature_path = signature_path
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(format!("{}.sig", path)));
+
+    let data = tokio::fs::read(&artifact_path)
+        .await
+        .map_err(|e| format!("Failed to read update file: {}", e))?;
+    let signature = tokio::fs::read_to_string(&signature_path)
+        .await
+        .map_err(|e| {
+            format!(
+                "Failed to read signature file {}: {}",
+                signature_path.display(),
+                e
+            )
+        })?;
+    verify_signature(&data, &signature, &updater_pubkey(&app)?)?;
+
+    let _ = app.emit(
+        "update-download-progress",
+        serde_json::json!({
+            "status": "installing",
+            "progress": 100,
+            "downloaded": data.len(),
+            "total": data.len(),
+            "speed": 0
+        }),
+    );
+
+    let staging_dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| format!("Failed to resolve cache dir: {}", e))?
+        .join("updates");
+    tokio::task::spawn_blocking(move || {
+        let staged = stage_artifact(&staging_dir, &artifact_path, &data)?;
+        let result = install_artifact(&staged, &data);
+        // The Windows installer is still running from the staged file
+        #[cfg(not(target_os = "windows"))]
+        let _ = std::fs::remove_file(&staged);
+        result
+    })
+    .await
+    .map_err(|e| format!("Failed to install update: {}", e))??;
+
+    // The Windows installer replaces the running executable, so quit and let it finish
+    #[cfg(target_os = "windows")]
+    crate::settings::window_behavior::exit_app(&app);
+
+    Ok(true)
+}
+
+/// Updater public key from `plugins.updater.pubkey` in tauri.conf.json
+fn updater_pubkey(app: &tauri::AppHandle) -> Result<String, String> {
+    app.config()
+        .plugins
+        .0
+        .get("updater")
+        .and_then(|updater| updater.get("pubkey"))
+        .and_then(|pubkey| pubkey.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| "Updater public key is not configured".to_string())
+}
+
+/// Verify a minisign signature (`.sig` file content) with the updater public key,
+/// both base64-encoded as produced by `tauri signer`
+fn verify_signature(data: &[u8], signature: &str, pubkey: &str) -> Result<(), String> {
+    let decode = |value: &str, what: &str| -> Result<String, String> {
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(value.trim())
+            .map_err(|e| format!("Invalid {}: {}", what, e))?;
+        String::from_utf8(bytes).map_err(|e| format!("Invalid {}: {}", what, e))
+    };
+
+    let public_key = PublicKey::decode(&decode(pubkey, "public key")?)
+        .map_err(|e| format!("Invalid public key: {}", e))?;
+    let signature = Signature::decode(&decode(signature, "signature")?)
+        .map_err(|e| format!("Invalid signature: {}", e))?;
+    public_key
+        .verify(data, &signature, true)
+        .map_err(|e| format!("Signature verification failed: {}", e))
+}
+
+/// Write the verified artifact bytes to a fresh file in the app's cache dir, keeping the
+/// original file name (it picks the install method)
+fn stage_artifact(
+    staging_dir: &Path,
+    artifact_path: &Path,
+    data: &[u8],
+) -> Result<PathBuf, String> {
+    let file_name = artifact_path
+        .file_name()
+        .ok_or_else(|| format!("Invalid update file {}", artifact_path.display()))?;
+    std::fs::create_dir_all(staging_dir)
+        .map_err(|e| format!("Failed to create update staging dir: {}", e))?;
+    let staged = staging_dir.join(file_name);
+    // Never write through a file or link left behind by an earlier run
+    if staged.symlink_metadata().is_ok() {
+        std::fs::remove_file(&staged)
+            .map_err(|e| format!("Failed to remove stale staged update: {}", e))?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&staged)
+        .map_err(|e| format!("Failed to stage update: {}", e))?;
+    std::io::Write::write_all(&mut file, data)
+        .and_then(|_| file.sync_all())
+        .map_err(|e| format!("Failed to stage update: {}", e))?;
+    Ok(staged)
+}
+
+/// Lowercased file name of the artifact, used to pick the install method
+#[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+fn artifact_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+}
+
+/// Run the NSIS / MSI installer with the same arguments the updater plugin uses
+#[cfg(target_os = "windows")]
+fn install_artifact(path: &Path, _data: &[u8]) -> Result<(), String> {
+    let name = artifact_name(path);
+    let mut command = if name.ends_with(".msi") {
+        let mut command = std::process::Command::new("msiexec.exe");
+        command
+            .arg("/i")
+            .arg(path)
+            .args(["/promptrestart", "/passive", "AUTOLAUNCHAPP=True"]);
+        command
+    } else if name.ends_with(".exe") {
+        let mut command = std::process::Command::new(path);
+        command.args(["/P", "/R", "/UPDATE"]);
+        command
+    } else {
+        return Err(format!(
+            "Unsupported update file {}, expected an .exe or .msi installer",
+            name
+        ));
+    };
+
+    command
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to start installer: {}", e))
+}
+
+/// Replace the running .app bundle with the one in the `.app.tar.gz` archive
+#[cfg(target_os = "macos")]
+fn install_artifact(path: &Path, _data: &[u8]) -> Result<(), String> {
+    let name = artifact_name(path);
+    if !name.ends_with(".tar.gz") {
+        return Err(format!(
+            "Unsupported update file {}, expected an .app.tar.gz archive",
+            name
+        ));
+    }
+
+    // .../AI Toolbox.app/Contents/MacOS/<exe>
+    let current_exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let bundle = current_exe
+        .ancestors()
+        .nth(3)
+        .filter(|bundle| bundle.extension().is_some_and(|ext| ext == "app"))
+        .ok_or_else(|| "The app is not running from an .app bundle".to_string())?
+        .to_path_buf();
+
+    let temp_dir = tempfile::tempdir().map_err(|e| e.to_string())?;
+    let status = std::process::Command::new("tar")
+        .arg("-xzf")
+        .arg(path)
+        .arg("-C")
+        .arg(temp_dir.path())
+        .status()
+        .map_err(|e| format!("Failed to extract update: {}", e))?;
+    if !status.success() {
+        return Err(format!(
+            "Failed to extract update: tar exited with {}",
+            status
+        ));
+    }
+
+    let new_bundle = std::fs::read_dir(temp_dir.path())
+        .map_err(|e| e.to_string())?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .find(|path| path.extension().is_some_and(|ext| ext == "app"))
+        .ok_or_else(|| "No .app bundle found in the update archive".to_string())?;
+
+    // Keep the old bundle until the new one is in place so a failed move can be undone
+    let backup = temp_dir.path().join("previous.app");
+    std::fs::rename(&bundle, &backup)
+        .map_err(|e| format!("Failed to move the current app bundle: {}", e))?;
+    if let Err(e) = std::fs::rename(&new_bundle, &bundle) {
+        let _ = std::fs::rename(&backup, &bundle);
+        return Err(format!("Failed to install the new app bundle: {}", e));
+    }
+    Ok(())
+}
+
+/// Replace the running AppImage with the new one
+#[cfg(target_os = "linux")]
+fn install_artifact(path: &Path, data: &[u8]) -> Result<(), String> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let name = artifact_name(path);
+    if !name.ends_with(".appimage") {
+        return Err(format!(
+            "Unsupported update file {}, expected an AppImage",
+            name
+        ));
+    }
+    let appimage = std::env::var_os("APPIMAGE")
+        .map(PathBuf::from)
+        .ok_or_else(|| {
+            "Installing from a file is only supported for AppImage builds".to_string()
+        })?;
+
+    // Write next to the current AppImage so the final rename stays on one filesystem
+    let staged = appimage.with_extension("new");
+    std::fs::write(&staged, data).map_err(|e| format!("Failed to write update: {}", e))?;
+    std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o755))
+        .map_err(|e| format!("Failed to make the update executable: {}", e))?;
+    std::fs::rename(&staged, &appimage).map_err(|e| {
+        let _ = std::fs::remove_file(&staged);
+        format!("Failed to replace the AppImage: {}", e)
+    })
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+fn install_artifact(_path: &Path, _data: &[u8]) -> Result<(), String> {
+    Err("Installing from a file is not supported on this platform".to_string())
+}
+
 /// Compare two version strings (e.g., "1.2.3" vs "1.2.4")
 /// Returns: 1 if v1 > v2, -1 if v1 < v2, 0 if equal
-fn compare_versions(v1: &str, v2: &str) -> i32 {
+pub(crate) fn compare_versions(v1: &str, v2: &str) -> i32 {
     let parts1: Vec<i32> = v1.split('.').filter_map(|s| s.parse().ok()).collect();
     let parts2: Vec<i32> = v2.split('.').filter_map(|s| s.parse().ok()).collect();
 
@@ -307,3 +908,153 @@ fn compare_versions(v1: &str, v2: &str) -> i32 {
 
     0
 }
+
+/// Compare two release versions, taking pre-release tags into account
+/// ("1.2.0-beta.2" < "1.2.0-beta.10" < "1.2.0")
+/// Returns: 1 if v1 > v2, -1 if v1 < v2, 0 if equal
+pub(crate) fn compare_release_versions(v1: &str, v2: &str) -> i32 {
+    let (core1, pre1) = split_pre_release(v1);
+    let (core2, pre2) = split_pre_release(v2);
+
+    match compare_versions(core1, core2) {
+        0 => {}
+        ordering => return ordering,
+    }
+
+    match (pre1, pre2) {
+        (None, None) => 0,
+        (None, Some(_)) => 1,
+        (Some(_), None) => -1,
+        (Some(pre1), Some(pre2)) => {
+            let mut parts1 = pre1.split('.');
+            let mut parts2 = pre2.split('.');
+            loop {
+                let ordering = match (parts1.next(), parts2.next()) {
+                    (None, None) => return 0,
+                    (None, Some(_)) => return -1,
+                    (Some(_), None) => return 1,
+                    (Some(a), Some(b)) => match (a.parse::<u64>(), b.parse::<u64>()) {
+                        (Ok(a), Ok(b)) => a.cmp(&b),
+                        (Ok(_), Err(_)) => std::cmp::Ordering::Less,
+                        (Err(_), Ok(_)) => std::cmp::Ordering::Greater,
+                        (Err(_), Err(_)) => a.cmp(b),
+                    },
+                };
+                match ordering {
+                    std::cmp::Ordering::Greater => return 1,
+                    std::cmp::Ordering::Less => return -1,
+                    std::cmp::Ordering::Equal => {}
+                }
+            }
+        }
+    }
+}
+
+/// Split "v1.2.0-beta.1+build" into ("1.2.0", Some("beta.1"))
+fn split_pre_release(version: &str) -> (&str, Option<&str>) {
+    let version = version.trim_start_matches('v');
+    let version = version.split('+').next().unwrap_or(version);
+    match version.split_once('-') {
+        Some((core, pre)) => (core, Some(pre)),
+        None => (version, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_pre_release_versions() {
+        assert_eq!(compare_release_versions("1.2.0", "1.2.0-beta.3"), 1);
+        assert_eq!(compare_release_versions("1.2.0-beta.10", "1.2.0-beta.2"), 1);
+        assert_eq!(
+            compare_release_versions("1.2.0-nightly.20250101", "1.1.9"),
+            1
+        );
+        assert_eq!(compare_release_versions("v1.2.0", "1.2.0"), 0);
+        assert_eq!(compare_release_versions("1.1.0", "1.2.0-beta.1"), -1);
+    }
+
+    #[test]
+    fn scheduled_check_is_due_after_the_interval() {
+        let day = check_interval("daily").unwrap();
+        assert!(is_check_due(None, day));
+        assert!(is_check_due(Some("not a date"), day));
+        let two_days_ago = (Utc::now() - chrono::Duration::days(2)).to_rfc3339();
+        assert!(is_check_due(Some(&two_days_ago), day));
+        assert!(!is_check_due(
+            Some(&two_days_ago),
+            check_interval("weekly").unwrap()
+        ));
+        assert!(check_interval("never").is_none());
+    }
+
+    #[test]
+    fn rejects_malformed_signatures() {
+        const PUBKEY: &str = "dW50cnVzdGVkIGNvbW1lbnQ6IG1pbmlzaWduIHB1YmxpYyBrZXk6IDFENkM1QTI3OTA0MkE3M0YKUldRL3AwS1FKMXBzSGVCZUJqSWNvcXNLcExuRGhlQkovcXhyTEpOTU96K2JpQytGbkZQbDZHV20K";
+        assert!(verify_signature(b"data", "not base64!", PUBKEY).is_err());
+        assert!(verify_signature(b"data", "", PUBKEY).is_err());
+        assert!(verify_signature(b"data", "", "").is_err());
+    }
+
+    #[test]
+    fn verifies_signatures_from_the_matching_key() {
+        // Test key pair in `tauri signer` format, signing DATA
+        const PUBKEY: &str = "dW50cnVzdGVkIGNvbW1lbnQ6IG1pbmlzaWduIHB1YmxpYyBrZXk6IDA4MDcwNjA1MDQwMzAyMDEKUldRQkFnTUVCUVlIQ0FPaEI3L3p6aEMrSFhEZEdPZEx3SmxuNU5Zd202VU5YeDNjaG1RU1ZURzQK";
+        const SIGNATURE: &str = "dW50cnVzdGVkIGNvbW1lbnQ6IHNpZ25hdHVyZSBmcm9tIHRhdXJpIHNlY3JldCBrZXkKUlVRQkFnTUVCUVlIQ08xbjRtU2pUTEMyeExwelVqYjlMeDhwbEFaSm9LaWZNenFKd0tvc05jR1NmV0RuSVVVS0dDdTJHSDR1Tm0rcHV3QU5RaFlybFZZUnBqUlJhYXFMR1E4PQp0cnVzdGVkIGNvbW1lbnQ6IHRpbWVzdGFtcDoxNzAwMDAwMDAwCWZpbGU6dXBkYXRlLmJpbgpKMEkwRDVjYy8xS1dueU1HMFVCbThJUFl6RERCOVk4ekErUXloK3VlRnZIZ0RwamF4WjBnVjNXQjZ5dmRQZnBiaXNwZVp6UmgyYStlajViTk4vWDZBZz09Cg==";
+        const DATA: &[u8] = b"ai-toolbox update artifact";
+        const OTHER_PUBKEY: &str = "dW50cnVzdGVkIGNvbW1lbnQ6IG1pbmlzaWduIHB1YmxpYyBrZXk6IDFENkM1QTI3OTA0MkE3M0YKUldRL3AwS1FKMXBzSGVCZUJqSWNvcXNLcExuRGhlQkovcXhyTEpOTU96K2JpQytGbkZQbDZHV20K";
+
+        assert!(verify_signature(DATA, SIGNATURE, PUBKEY).is_ok());
+        assert!(verify_signature(b"ai-toolbox update artifacT", SIGNATURE, PUBKEY).is_err());
+        assert!(verify_signature(DATA, SIGNATURE, OTHER_PUBKEY).is_err());
+    }
+
+    #[test]
+    fn stages_the_verified_bytes_instead_of_the_original_file() {
+        let source_dir = tempfile::tempdir().unwrap();
+        let staging_dir = tempfile::tempdir().unwrap();
+        let source = source_dir.path().join("AI Toolbox_1.0.0_x64-setup.exe");
+        std::fs::write(&source, b"swapped after verification").unwrap();
+        std::fs::write(
+            staging_dir.path().join("AI Toolbox_1.0.0_x64-setup.exe"),
+            b"stale",
+        )
+        .unwrap();
+
+        let staged = stage_artifact(staging_dir.path(), &source, b"verified").unwrap();
+        assert_eq!(staged.parent(), Some(staging_dir.path()));
+        assert_eq!(artifact_name(&staged), "ai toolbox_1.0.0_x64-setup.exe");
+        assert_eq!(std::fs::read(&staged).unwrap(), b"verified");
+    }
+
+    #[test]
+    fn offers_older_release_only_when_moving_to_a_more_stable_channel() {
+        assert!(is_update_for_channel(
+            UpdateChannel::Stable,
+            "1.3.0-nightly.20250101",
+            "1.2.5"
+        ));
+        assert!(is_update_for_channel(
+            UpdateChannel::Beta,
+            "1.3.0-nightly.20250101",
+            "1.3.0-beta.1"
+        ));
+        assert!(!is_update_for_channel(
+            UpdateChannel::Beta,
+            "1.3.0",
+            "1.3.0-beta.4"
+        ));
+        assert!(!is_update_for_channel(
+            UpdateChannel::Stable,
+            "1.2.5",
+            "1.2.5"
+        ));
+        assert!(is_update_for_channel(
+            UpdateChannel::Nightly,
+            "1.2.5",
+            "1.3.0-nightly.20250101"
+        ));
+    }
+}
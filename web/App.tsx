@@ -1,12 +1,15 @@
 import { RouterProvider } from 'react-router-dom';
 import { router } from '@/app/routes';
 import { Providers } from '@/app/providers';
+import { AppLockGate } from '@/features/settings/components';
 
 function App() {
   return (
-    <Providers>
-      <RouterProvider router={router} />
-    </Providers>
+    <AppLockGate>
+      <Providers>
+        <RouterProvider router={router} />
+      </Providers>
+    </AppLockGate>
   );
 }
 
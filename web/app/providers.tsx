@@ -2,10 +2,11 @@ import React from 'react';
 import { ConfigProvider, Spin, App, theme as antdTheme, Button, Modal, Progress, Typography, Space } from 'antd';
 import zhCN from 'antd/locale/zh_CN';
 import enUS from 'antd/locale/en_US';
+import jaJP from 'antd/locale/ja_JP';
 import { emit, listen } from '@tauri-apps/api/event';
 import { useAppStore, useSettingsStore } from '@/stores';
 import { useThemeStore } from '@/stores/themeStore';
-import { checkForUpdates, openExternalUrl, setWindowBackgroundColor, installUpdate, loadCachedPresetModels, fetchRemotePresetModels, GITHUB_REPO, type UpdateInfo } from '@/services';
+import { checkForUpdates, openExternalUrl, setWindowBackgroundColor, installUpdate, skipUpdateVersion, toUpdateInfo, loadCachedPresetModels, fetchRemotePresetModels, GITHUB_REPO, type UpdateCheckResult, type UpdateInfo } from '@/services';
 import { restartApp } from '@/services/settingsApi';
 import i18n from '@/i18n';
 
@@ -16,6 +17,7 @@ interface ProvidersProps {
 const antdLocales = {
   'zh-CN': zhCN,
   'en-US': enUS,
+  'ja-JP': jaJP,
 };
 
 /**
@@ -131,6 +133,56 @@ const AppInitializer: React.FC<{ children: React.ReactNode }> = ({ children }) =
     }
   };
 
+  const showUpdateNotification = (info: UpdateInfo) => {
+    notification.info({
+      key: 'update-available',
+      message: i18n.t('settings.about.newVersion'),
+      description: i18n.t('settings.about.updateAvailable', { version: info.latestVersion }),
+      btn: (
+        <Space>
+          <Button
+            size="small"
+            onClick={() => {
+              skipUpdateVersion(info.latestVersion).catch(console.error);
+              notification.destroy();
+            }}
+          >
+            {i18n.t('settings.about.skipVersion')}
+          </Button>
+          <Button
+            size="small"
+            onClick={() => {
+              openExternalUrl(info.releaseUrl);
+              notification.destroy();
+            }}
+          >
+            {i18n.t('settings.about.viewReleaseNotes')}
+          </Button>
+          <Button
+            type="primary"
+            size="small"
+            onClick={() => handleInstallUpdate(info)}
+          >
+            {i18n.t('settings.about.goToDownload')}
+          </Button>
+        </Space>
+      ),
+      duration: 10,
+    });
+  };
+
+  // Scheduled update checks run in the backend and report new versions here
+  React.useEffect(() => {
+    const unlisten = listen<UpdateCheckResult>('update-available', (event) => {
+      showUpdateNotification(toUpdateInfo(event.payload));
+    });
+
+    return () => {
+      unlisten.then((fn) => fn()).catch(console.error);
+    };
+  // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [notification]);
+
   // Check for updates on app startup (at most once per hour)
   React.useEffect(() => {
     if (hasCheckedUpdate.current) return;
@@ -146,32 +198,8 @@ const AppInitializer: React.FC<{ children: React.ReactNode }> = ({ children }) =
       try {
         const info = await checkForUpdates();
         localStorage.setItem(LAST_CHECK_KEY, String(now));
-        if (info.hasUpdate) {
-          notification.info({
-            message: i18n.t('settings.about.newVersion'),
-            description: i18n.t('settings.about.updateAvailable', { version: info.latestVersion }),
-            btn: (
-              <Space>
-                <Button
-                  size="small"
-                  onClick={() => {
-                    openExternalUrl(info.releaseUrl);
-                    notification.destroy();
-                  }}
-                >
-                  {i18n.t('settings.about.viewReleaseNotes')}
-                </Button>
-                <Button
-                  type="primary"
-                  size="small"
-                  onClick={() => handleInstallUpdate(info)}
-                >
-                  {i18n.t('settings.about.goToDownload')}
-                </Button>
-              </Space>
-            ),
-            duration: 10,
-          });
+        if (info.hasUpdate && !info.skipped) {
+          showUpdateNotification(info);
         }
       } catch (error) {
         console.error('Auto check update failed:', error);
@@ -4,6 +4,7 @@ import { useNavigate, useLocation } from 'react-router-dom';
 import { useTranslation } from 'react-i18next';
 import { CodeOutlined, SettingOutlined } from '@ant-design/icons';
 import { platform } from '@tauri-apps/plugin-os';
+import { listen } from '@tauri-apps/api/event';
 import { MODULES } from '@/constants';
 import { useAppStore, useSettingsStore } from '@/stores';
 import { useThemeStore } from '@/stores/themeStore';
@@ -12,6 +13,7 @@ import { WSLSyncModal } from '@/features/settings/components/WSLSyncModal';
 import { useWSLSync } from '@/features/settings/hooks/useWSLSync';
 import { SSHStatusIndicator } from '@/features/settings/components/SSHStatusIndicator';
 import { SSHSyncModal } from '@/features/settings/components/SSHSyncModal';
+import DeepLinkModal from '@/features/settings/components/DeepLinkModal';
 import { useSSHSync } from '@/features/settings/hooks/useSSHSync';
 import { SkillsButton } from '@/features/coding/skills';
 import { McpButton } from '@/features/coding/mcp';
@@ -65,6 +67,22 @@ const MainLayout: React.FC = () => {
     };
   }, []);
 
+  // Screens opened from the tray status section
+  React.useEffect(() => {
+    const unlisten = listen<string>('tray-open-screen', (event) => {
+      if (event.payload === 'ssh') {
+        setSSHModalOpen(true);
+      } else if (event.payload === 'wsl') {
+        setWslModalOpen(true);
+      } else if (event.payload === 'settings') {
+        navigate('/settings');
+      }
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [navigate]);
+
   const isSettingsPage = location.pathname.startsWith('/settings');
   const isSkillsPage = location.pathname.startsWith('/skills');
   const isMcpPage = location.pathname.startsWith('/mcp');
@@ -253,6 +271,9 @@ const MainLayout: React.FC = () => {
 
       {/* SSH Sync Modal - all platforms */}
       <SSHSyncModal open={sshModalOpen} onClose={() => setSSHModalOpen(false)} />
+
+      {/* Confirmation for ai-toolbox:// links */}
+      <DeepLinkModal />
     </div>
   );
 };
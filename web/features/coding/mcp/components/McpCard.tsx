@@ -1,6 +1,7 @@
 import React from 'react';
-import { Button, Tooltip, Dropdown, Tag } from 'antd';
+import { Button, Tooltip, Dropdown, Tag, Modal } from 'antd';
 import {
+  ApiOutlined,
   DeleteOutlined,
   EditOutlined,
   PlusOutlined,
@@ -11,6 +12,7 @@ import {
 import { useTranslation } from 'react-i18next';
 import { useSortable } from '@dnd-kit/sortable';
 import { CSS } from '@dnd-kit/utilities';
+import { getMcpServerLogs, testMcpServer } from '../services/mcpApi';
 import type { McpServer, McpTool } from '../types';
 import styles from './McpCard.module.less';
 
@@ -42,6 +44,55 @@ const McpCardContent: React.FC<McpCardContentProps> = ({
   containerStyle,
 }) => {
   const { t } = useTranslation();
+  const [testing, setTesting] = React.useState(false);
+
+  const handleTest = async () => {
+    setTesting(true);
+    try {
+      const result = await testMcpServer(server.id);
+      if (!result.success) {
+        const logs = server.server_type === 'stdio' ? await getMcpServerLogs(server.id).catch(() => []) : [];
+        Modal.error({
+          title: t('mcp.testFailed'),
+          content: (
+            <div>
+              <pre style={{ whiteSpace: 'pre-wrap', margin: 0 }}>{result.error_message}</pre>
+              {logs.length > 0 && (
+                <details style={{ marginTop: 8 }}>
+                  <summary>{t('mcp.testLogs', { count: logs.length })}</summary>
+                  <pre style={{ whiteSpace: 'pre-wrap', maxHeight: 300, overflow: 'auto', fontSize: 12, margin: 0 }}>
+                    {logs.map((log) => `[${log.stream}] ${log.line}`).join('\n')}
+                  </pre>
+                </details>
+              )}
+            </div>
+          ),
+          width: 560,
+        });
+        return;
+      }
+      const serverInfo = [result.server_name, result.server_version].filter(Boolean).join(' ');
+      Modal.success({
+        title: t('mcp.testSuccess', { ms: result.latency_ms }),
+        content: (
+          <div>
+            {serverInfo && <div>{t('mcp.testServerInfo', { info: serverInfo, protocol: result.protocol_version ?? '-' })}</div>}
+            <div style={{ marginTop: 8 }}>{t('mcp.testTools', { count: result.tools.length })}</div>
+            <ul style={{ maxHeight: 240, overflow: 'auto', paddingLeft: 20, margin: 0 }}>
+              {result.tools.map((tool) => (
+                <li key={tool.name} title={tool.description}>{tool.name}</li>
+              ))}
+            </ul>
+          </div>
+        ),
+        width: 560,
+      });
+    } catch (error) {
+      Modal.error({ title: t('mcp.testFailed'), content: String(error) });
+    } finally {
+      setTesting(false);
+    }
+  };
 
   const iconNode = React.useMemo(() => (
     server.server_type === 'stdio' ? (
@@ -150,6 +201,14 @@ const McpCardContent: React.FC<McpCardContentProps> = ({
           </div>
         </div>
         <div className={styles.actions}>
+          <Button
+            type="text"
+            icon={<ApiOutlined />}
+            onClick={handleTest}
+            loading={testing}
+            disabled={loading}
+            title={t('mcp.test')}
+          />
           <Button
             type="text"
             icon={<EditOutlined />}
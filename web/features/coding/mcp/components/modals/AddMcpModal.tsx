@@ -108,6 +108,8 @@ export const AddMcpModal: React.FC<AddMcpModalProps> = ({
           env: envList,
           description: editingServer.description,
           timeout: editingServer.timeout,
+          project_paths: editingServer.project_paths ?? [],
+          project_shared: editingServer.project_shared ?? false,
         });
       } else {
         const httpConfig = config as HttpConfig;
@@ -131,6 +133,8 @@ export const AddMcpModal: React.FC<AddMcpModalProps> = ({
           headers: headersList,
           description: editingServer.description,
           timeout: editingServer.timeout,
+          project_paths: editingServer.project_paths ?? [],
+          project_shared: editingServer.project_shared ?? false,
         });
       }
     } else {
@@ -280,6 +284,8 @@ export const AddMcpModal: React.FC<AddMcpModalProps> = ({
             enabled_tools: selectedTools,
             description: values.description,
             timeout: values.timeout ?? null,
+            project_paths: values.project_paths ?? [],
+            project_shared: values.project_shared ?? false,
           });
           // Sync all tools after overwrite
           if (onSyncAll) {
@@ -293,6 +299,8 @@ export const AddMcpModal: React.FC<AddMcpModalProps> = ({
             enabled_tools: selectedTools,
             description: values.description,
             timeout: values.timeout ?? null,
+            project_paths: values.project_paths ?? [],
+            project_shared: values.project_shared ?? false,
           });
         } else {
           await onSubmit({
@@ -303,6 +311,8 @@ export const AddMcpModal: React.FC<AddMcpModalProps> = ({
             description: values.description,
             tags: values.tags?.filter((t: string) => t) || [],
             timeout: values.timeout ?? null,
+            project_paths: values.project_paths ?? [],
+            project_shared: values.project_shared ?? false,
           });
         }
         // Upsert favorite
@@ -627,6 +637,14 @@ export const AddMcpModal: React.FC<AddMcpModalProps> = ({
             <span style={{ fontSize: 12, color: '#999', fontStyle: 'italic' }}>{t('mcp.timeoutScope')}</span>
           </Space>
         </Form.Item>
+
+        <Form.Item label={t('mcp.projectPaths')} name="project_paths" extra={t('mcp.projectPathsHint')}>
+          <Select mode="tags" open={false} tokenSeparators={['\n']} placeholder={t('mcp.projectPathsPlaceholder')} />
+        </Form.Item>
+
+        <Form.Item name="project_shared" valuePropName="checked" wrapperCol={{ offset: 6, span: 18 }}>
+          <Checkbox>{t('mcp.projectShared')}</Checkbox>
+        </Form.Item>
       </Form>
 
       <div className={styles.toolsSection}>
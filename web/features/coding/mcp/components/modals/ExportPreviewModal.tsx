@@ -0,0 +1,123 @@
+import React from 'react';
+import { Modal, Select, Alert, Empty, Spin, Tag, Space, Button, message } from 'antd';
+import { useTranslation } from 'react-i18next';
+import * as mcpApi from '../../services/mcpApi';
+import type { McpExportPreview, McpTool } from '../../types';
+
+interface ExportPreviewModalProps {
+  open: boolean;
+  tools: McpTool[];
+  onClose: () => void;
+}
+
+export const ExportPreviewModal: React.FC<ExportPreviewModalProps> = ({ open, tools, onClose }) => {
+  const { t } = useTranslation();
+  const exportTools = React.useMemo(() => tools.filter((tool) => tool.installed && tool.supports_mcp), [tools]);
+  const [toolKey, setToolKey] = React.useState<string | undefined>(exportTools[0]?.key);
+  const [preview, setPreview] = React.useState<McpExportPreview | null>(null);
+  const [loading, setLoading] = React.useState(false);
+  const [syncing, setSyncing] = React.useState(false);
+
+  const loadPreview = React.useCallback(async (key: string) => {
+    setLoading(true);
+    try {
+      setPreview(await mcpApi.previewMcpExport(key));
+    } catch (error) {
+      setPreview(null);
+      message.error(String(error));
+    } finally {
+      setLoading(false);
+    }
+  }, []);
+
+  React.useEffect(() => {
+    if (toolKey) {
+      loadPreview(toolKey);
+    }
+  }, [toolKey, loadPreview]);
+
+  const handleSync = async () => {
+    if (!toolKey) return;
+    setSyncing(true);
+    try {
+      await mcpApi.syncMcpToTool(toolKey);
+      message.success(t('common.success'));
+      await loadPreview(toolKey);
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setSyncing(false);
+    }
+  };
+
+  return (
+    <Modal
+      title={t('mcp.exportPreview.title')}
+      open={open}
+      onCancel={onClose}
+      width={760}
+      footer={
+        <Space>
+          <Button onClick={onClose}>{t('common.close')}</Button>
+          <Button type="primary" onClick={handleSync} loading={syncing} disabled={!preview?.changed}>
+            {t('mcp.exportPreview.sync')}
+          </Button>
+        </Space>
+      }
+    >
+      <Select
+        style={{ width: 260, marginBottom: 12 }}
+        value={toolKey}
+        onChange={setToolKey}
+        options={exportTools.map((tool) => ({ label: tool.display_name, value: tool.key }))}
+      />
+      <Spin spinning={loading}>
+        {preview && (
+          <Space direction="vertical" style={{ width: '100%' }}>
+            <div style={{ fontSize: 12, color: 'var(--color-text-tertiary)' }}>{preview.config_path}</div>
+            {preview.errors.length > 0 && (
+              <Alert type="error" showIcon message={preview.errors.join('\n')} style={{ whiteSpace: 'pre-wrap' }} />
+            )}
+            {preview.unmanaged_servers.length > 0 && (
+              <div>
+                <span style={{ marginRight: 8 }}>{t('mcp.exportPreview.unmanaged')}</span>
+                {preview.unmanaged_servers.map((name) => (
+                  <Tag key={name}>{name}</Tag>
+                ))}
+              </div>
+            )}
+            {preview.project_files.length > 0 && (
+              <Alert
+                type="info"
+                showIcon
+                message={t('mcp.exportPreview.projectFiles')}
+                description={preview.project_files.join('\n')}
+                style={{ whiteSpace: 'pre-wrap' }}
+              />
+            )}
+            {preview.changed ? (
+              <pre style={{ maxHeight: 420, overflow: 'auto', fontSize: 12, margin: 0 }}>
+                {preview.diff.split('\n').map((line, index) => (
+                  <div
+                    key={index}
+                    style={{
+                      color: line.startsWith('+ ')
+                        ? 'var(--color-success, #52c41a)'
+                        : line.startsWith('- ')
+                          ? 'var(--color-error, #ff4d4f)'
+                          : undefined,
+                    }}
+                  >
+                    {line}
+                  </div>
+                ))}
+              </pre>
+            ) : (
+              <Empty image={Empty.PRESENTED_IMAGE_SIMPLE} description={t('mcp.exportPreview.unchanged')} />
+            )}
+          </Space>
+        )}
+      </Spin>
+    </Modal>
+  );
+};
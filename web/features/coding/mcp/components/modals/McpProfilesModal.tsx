@@ -0,0 +1,182 @@
+import React from 'react';
+import { Modal, Form, Input, Select, Checkbox, Button, Space, List, Tag, Popconfirm, message } from 'antd';
+import { DeleteOutlined, EditOutlined, PlusOutlined, ThunderboltOutlined } from '@ant-design/icons';
+import { useTranslation } from 'react-i18next';
+import * as mcpApi from '../../services/mcpApi';
+import { useMcpStore } from '../../stores/mcpStore';
+import type { McpProfile, McpProfileInput, McpServer, McpTool } from '../../types';
+
+interface McpProfilesModalProps {
+  open: boolean;
+  servers: McpServer[];
+  tools: McpTool[];
+  onClose: () => void;
+}
+
+export const McpProfilesModal: React.FC<McpProfilesModalProps> = ({ open, servers, tools, onClose }) => {
+  const { t } = useTranslation();
+  const { fetchServers } = useMcpStore();
+  const [form] = Form.useForm<McpProfileInput>();
+  const [profiles, setProfiles] = React.useState<McpProfile[]>([]);
+  const [editing, setEditing] = React.useState<McpProfile | 'new' | null>(null);
+  const [saving, setSaving] = React.useState(false);
+  const [applying, setApplying] = React.useState<string | null>(null);
+
+  const mcpTools = React.useMemo(() => tools.filter((tool) => tool.supports_mcp), [tools]);
+  const serverNames = React.useMemo(() => new Map(servers.map((s) => [s.id, s.name])), [servers]);
+  const toolNames = React.useMemo(() => new Map(tools.map((tool) => [tool.key, tool.display_name])), [tools]);
+
+  const loadProfiles = React.useCallback(async () => {
+    try {
+      setProfiles(await mcpApi.listMcpProfiles());
+    } catch (error) {
+      message.error(String(error));
+    }
+  }, []);
+
+  React.useEffect(() => {
+    loadProfiles();
+  }, [loadProfiles]);
+
+  const startEdit = (profile: McpProfile | 'new') => {
+    setEditing(profile);
+    form.setFieldsValue(
+      profile === 'new'
+        ? { name: '', server_ids: [], tool_keys: mcpTools.filter((tool) => tool.installed).map((tool) => tool.key) }
+        : { name: profile.name, server_ids: profile.server_ids, tool_keys: profile.tool_keys }
+    );
+  };
+
+  const handleSave = async () => {
+    const values = await form.validateFields();
+    setSaving(true);
+    try {
+      if (editing && editing !== 'new' && editing.name !== values.name.trim()) {
+        await mcpApi.deleteMcpProfile(editing.name);
+      }
+      await mcpApi.saveMcpProfile(values);
+      setEditing(null);
+      await loadProfiles();
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleDelete = async (name: string) => {
+    try {
+      await mcpApi.deleteMcpProfile(name);
+      await loadProfiles();
+    } catch (error) {
+      message.error(String(error));
+    }
+  };
+
+  const handleApply = async (name: string) => {
+    setApplying(name);
+    try {
+      const results = await mcpApi.applyMcpProfile(name);
+      const failed = results.filter((r) => !r.success);
+      if (failed.length > 0) {
+        message.error(failed.map((r) => `${toolNames.get(r.tool) ?? r.tool}: ${r.error_message}`).join('\n'));
+      } else {
+        message.success(t('mcp.profiles.applied', { name }));
+      }
+      await fetchServers();
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setApplying(null);
+    }
+  };
+
+  return (
+    <Modal title={t('mcp.profiles.title')} open={open} onCancel={onClose} footer={null} width={640}>
+      {editing ? (
+        <Form form={form} layout="vertical">
+          <Form.Item
+            name="name"
+            label={t('mcp.profiles.name')}
+            rules={[{ required: true, whitespace: true, message: t('mcp.profiles.nameRequired') }]}
+          >
+            <Input placeholder={t('mcp.profiles.namePlaceholder')} />
+          </Form.Item>
+          <Form.Item name="server_ids" label={t('mcp.profiles.servers')}>
+            <Select
+              mode="multiple"
+              optionFilterProp="label"
+              options={servers.map((server) => ({ label: server.name, value: server.id }))}
+            />
+          </Form.Item>
+          <Form.Item name="tool_keys" label={t('mcp.profiles.tools')} extra={t('mcp.profiles.toolsHint')}>
+            <Checkbox.Group
+              options={mcpTools.map((tool) => ({ label: tool.display_name, value: tool.key, disabled: !tool.installed }))}
+            />
+          </Form.Item>
+          <div style={{ textAlign: 'right' }}>
+            <Space>
+              <Button onClick={() => setEditing(null)}>{t('common.cancel')}</Button>
+              <Button type="primary" onClick={handleSave} loading={saving}>
+                {t('common.save')}
+              </Button>
+            </Space>
+          </div>
+        </Form>
+      ) : (
+        <>
+          <List
+            dataSource={profiles}
+            locale={{ emptyText: t('mcp.profiles.empty') }}
+            renderItem={(profile) => (
+              <List.Item
+                actions={[
+                  <Button
+                    key="apply"
+                    type="link"
+                    size="small"
+                    icon={<ThunderboltOutlined />}
+                    loading={applying === profile.name}
+                    onClick={() => handleApply(profile.name)}
+                  >
+                    {t('mcp.profiles.apply')}
+                  </Button>,
+                  <Button key="edit" type="text" size="small" icon={<EditOutlined />} onClick={() => startEdit(profile)} />,
+                  <Popconfirm
+                    key="delete"
+                    title={t('mcp.profiles.deleteConfirm', { name: profile.name })}
+                    onConfirm={() => handleDelete(profile.name)}
+                  >
+                    <Button type="text" size="small" danger icon={<DeleteOutlined />} />
+                  </Popconfirm>,
+                ]}
+              >
+                <List.Item.Meta
+                  title={profile.name}
+                  description={
+                    <div>
+                      <div>
+                        {profile.server_ids.length === 0
+                          ? t('mcp.profiles.noServers')
+                          : profile.server_ids.map((id) => serverNames.get(id) ?? id).join(', ')}
+                      </div>
+                      <div style={{ marginTop: 4 }}>
+                        {profile.tool_keys.map((key) => (
+                          <Tag key={key}>{toolNames.get(key) ?? key}</Tag>
+                        ))}
+                      </div>
+                    </div>
+                  }
+                />
+              </List.Item>
+            )}
+          />
+          <p style={{ fontSize: 12, color: 'var(--color-text-tertiary)' }}>{t('mcp.profiles.hint')}</p>
+          <Button type="dashed" block icon={<PlusOutlined />} onClick={() => startEdit('new')}>
+            {t('mcp.profiles.add')}
+          </Button>
+        </>
+      )}
+    </Modal>
+  );
+};
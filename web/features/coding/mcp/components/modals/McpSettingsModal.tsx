@@ -1,8 +1,8 @@
 import React from 'react';
-import { Modal, Button, Checkbox, message, Form, Input, Space, Tooltip, Switch, Radio } from 'antd';
+import { Modal, Button, Checkbox, message, Form, Input, Space, Tooltip, Switch, Radio, Tag } from 'antd';
 import { ClearOutlined, DeleteOutlined, PlusOutlined } from '@ant-design/icons';
 import { useTranslation } from 'react-i18next';
-import type { McpServer, McpTool, StdioConfig, HttpConfig } from '../../types';
+import type { McpServer, McpTool, McpSecret, StdioConfig, HttpConfig } from '../../types';
 import * as mcpApi from '../../services/mcpApi';
 import { useMcpStore } from '../../stores/mcpStore';
 import { refreshTrayMenu } from '@/services/appApi';
@@ -39,13 +39,24 @@ export const McpSettingsModal: React.FC<McpSettingsModalProps> = ({
   const [showClearAllModal, setShowClearAllModal] = React.useState(false);
   const [clearAllConfirmText, setClearAllConfirmText] = React.useState('');
   const [clearingAll, setClearingAll] = React.useState(false);
+  const [secrets, setSecrets] = React.useState<McpSecret[]>([]);
+  const [secretInputs, setSecretInputs] = React.useState<Record<string, string>>({});
 
   // Load settings on mount
   React.useEffect(() => {
     loadData();
   }, []);
 
+  const loadSecrets = async () => {
+    try {
+      setSecrets(await mcpApi.listMcpSecrets());
+    } catch (error) {
+      console.error('Failed to load MCP secrets:', error);
+    }
+  };
+
   const loadData = async () => {
+    loadSecrets();
     try {
       const [tools, trayEnabled, savedPreferredTools, syncDisabled] = await Promise.all([
         mcpApi.getMcpTools(),
@@ -86,6 +97,17 @@ export const McpSettingsModal: React.FC<McpSettingsModalProps> = ({
     }
   };
 
+  const handleSaveSecret = async (name: string) => {
+    try {
+      await mcpApi.setMcpSecret(name, secretInputs[name] ?? '');
+      setSecretInputs((prev) => ({ ...prev, [name]: '' }));
+      await loadSecrets();
+      message.success(t('mcp.secrets.saved'));
+    } catch (error) {
+      message.error(String(error));
+    }
+  };
+
   const handleToolToggle = (toolKey: string, checked: boolean) => {
     setPreferredTools((prev) =>
       checked ? [...prev, toolKey] : prev.filter((k) => k !== toolKey)
@@ -333,6 +355,41 @@ export const McpSettingsModal: React.FC<McpSettingsModalProps> = ({
         </div>
       </div>
 
+      <div className={styles.section}>
+        <div className={styles.labelArea}>
+          <label className={styles.label}>{t('mcp.secrets.title')}</label>
+        </div>
+        <div className={styles.inputArea}>
+          {secrets.length === 0 ? (
+            <span className={styles.hint}>{t('mcp.secrets.empty')}</span>
+          ) : (
+            <Space direction="vertical" style={{ width: '100%' }}>
+              {secrets.map((secret) => (
+                <Space key={secret.name} wrap>
+                  <Tooltip title={t('mcp.secrets.usedBy', { servers: secret.used_by.join(', ') })}>
+                    <code>{secret.name}</code>
+                  </Tooltip>
+                  <Tag color={secret.has_value ? 'green' : 'red'}>
+                    {secret.has_value ? t('mcp.secrets.set') : t('mcp.secrets.missing')}
+                  </Tag>
+                  <Input.Password
+                    size="small"
+                    style={{ width: 220 }}
+                    value={secretInputs[secret.name] ?? ''}
+                    onChange={(e) => setSecretInputs((prev) => ({ ...prev, [secret.name]: e.target.value }))}
+                    placeholder={t('mcp.secrets.placeholder')}
+                  />
+                  <Button size="small" onClick={() => handleSaveSecret(secret.name)}>
+                    {t('common.save')}
+                  </Button>
+                </Space>
+              ))}
+            </Space>
+          )}
+          <p className={styles.hint}>{t('mcp.secrets.hint', { example: '{{secret:NAME}}', interpolation: { escapeValue: false } })}</p>
+        </div>
+      </div>
+
       <div className={styles.section}>
         <div className={styles.labelArea}>
           <label className={styles.label}>{t('mcp.clearAll.title')}</label>
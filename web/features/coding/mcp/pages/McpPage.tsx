@@ -1,6 +1,6 @@
 import React, { useState, useCallback } from 'react';
 import { Typography, Button, Space, Modal, Tooltip } from 'antd';
-import { PlusOutlined, EllipsisOutlined, ImportOutlined, FileTextOutlined, LinkOutlined, DragOutlined } from '@ant-design/icons';
+import { PlusOutlined, EllipsisOutlined, ImportOutlined, FileTextOutlined, LinkOutlined, DragOutlined, DiffOutlined, AppstoreOutlined } from '@ant-design/icons';
 import { useTranslation } from 'react-i18next';
 import { openUrl } from '@tauri-apps/plugin-opener';
 import { arrayMove } from '@dnd-kit/sortable';
@@ -14,6 +14,8 @@ import { AddMcpModal } from '../components/modals/AddMcpModal';
 import { McpSettingsModal } from '../components/modals/McpSettingsModal';
 import { ImportMcpModal } from '../components/modals/ImportMcpModal';
 import { ImportJsonModal } from '../components/modals/ImportJsonModal';
+import { ExportPreviewModal } from '../components/modals/ExportPreviewModal';
+import { McpProfilesModal } from '../components/modals/McpProfilesModal';
 import type { McpServer, CreateMcpServerInput, UpdateMcpServerInput } from '../types';
 import styles from './McpPage.module.less';
 
@@ -37,6 +39,8 @@ const McpPage: React.FC = () => {
   const [editingServer, setEditingServer] = useState<McpServer | null>(null);
   const [actionLoading, setActionLoading] = useState(false);
   const [reorderMode, setReorderMode] = useState(false);
+  const [exportPreviewOpen, setExportPreviewOpen] = useState(false);
+  const [profilesOpen, setProfilesOpen] = useState(false);
 
   const handleAddServer = async (input: CreateMcpServerInput) => {
     setActionLoading(true);
@@ -164,6 +168,22 @@ const McpPage: React.FC = () => {
           >
             {t('mcp.importJson.button')}
           </Button>
+          <Button
+            type="text"
+            icon={<DiffOutlined />}
+            onClick={() => setExportPreviewOpen(true)}
+            style={{ color: 'var(--color-text-tertiary)' }}
+          >
+            {t('mcp.exportPreview.button')}
+          </Button>
+          <Button
+            type="text"
+            icon={<AppstoreOutlined />}
+            onClick={() => setProfilesOpen(true)}
+            style={{ color: 'var(--color-text-tertiary)' }}
+          >
+            {t('mcp.profiles.button')}
+          </Button>
           <Button
             type="link"
             icon={<PlusOutlined />}
@@ -230,6 +250,23 @@ const McpPage: React.FC = () => {
         />
       )}
 
+      {exportPreviewOpen && (
+        <ExportPreviewModal
+          open={exportPreviewOpen}
+          tools={tools}
+          onClose={() => setExportPreviewOpen(false)}
+        />
+      )}
+
+      {profilesOpen && (
+        <McpProfilesModal
+          open={profilesOpen}
+          servers={servers}
+          tools={tools}
+          onClose={() => setProfilesOpen(false)}
+        />
+      )}
+
       {isImportJsonModalOpen && (
         <ImportJsonModal
           open={isImportJsonModalOpen}
@@ -7,6 +7,13 @@ import type {
   McpImportResult,
   McpTool,
   McpScanResult,
+  McpTestResult,
+  McpRegistryPage,
+  McpExportPreview,
+  McpProfile,
+  McpProfileInput,
+  McpSecret,
+  McpServerLogLine,
 } from '../types';
 
 // Server CRUD
@@ -26,6 +33,14 @@ export const deleteMcpServer = async (serverId: string): Promise<void> => {
   return invoke('mcp_delete_server', { serverId });
 };
 
+export const testMcpServer = async (serverId: string): Promise<McpTestResult> => {
+  return invoke<McpTestResult>('mcp_test_server', { serverId });
+};
+
+export const getMcpServerLogs = async (serverId: string): Promise<McpServerLogLine[]> => {
+  return invoke<McpServerLogLine[]>('mcp_get_server_logs', { serverId });
+};
+
 export const toggleMcpTool = async (serverId: string, toolKey: string): Promise<boolean> => {
   return invoke<boolean>('mcp_toggle_tool', { serverId, toolKey });
 };
@@ -43,6 +58,10 @@ export const syncMcpAll = async (): Promise<McpSyncResult[]> => {
   return invoke<McpSyncResult[]>('mcp_sync_all');
 };
 
+export const previewMcpExport = async (toolKey: string): Promise<McpExportPreview> => {
+  return invoke<McpExportPreview>('mcp_preview_export', { toolKey });
+};
+
 export const importMcpFromTool = async (toolKey: string, enabledTools?: string[]): Promise<McpImportResult> => {
   return invoke<McpImportResult>('mcp_import_from_tool', { toolKey, enabledTools });
 };
@@ -57,6 +76,19 @@ export const scanMcpServers = async (): Promise<McpScanResult> => {
   return invoke<McpScanResult>('mcp_scan_servers');
 };
 
+// Search the public MCP registry
+export const browseMcpRegistry = async (query: string, cursor?: string): Promise<McpRegistryPage> => {
+  return invoke<McpRegistryPage>('mcp_browse_registry', { query, cursor });
+};
+
+export const listMcpSecrets = async (): Promise<McpSecret[]> => {
+  return invoke<McpSecret[]>('mcp_list_secrets');
+};
+
+export const setMcpSecret = async (name: string, value: string): Promise<void> => {
+  return invoke('mcp_set_secret', { name, value });
+};
+
 // Preferences
 export const getMcpShowInTray = async (): Promise<boolean> => {
   return invoke<boolean>('mcp_get_show_in_tray');
@@ -136,3 +168,20 @@ export const deleteMcpFavorite = async (favoriteId: string): Promise<void> => {
 export const initMcpDefaultFavorites = async (): Promise<number> => {
   return invoke<number>('mcp_init_default_favorites');
 };
+
+// MCP Profile API
+export const listMcpProfiles = async (): Promise<McpProfile[]> => {
+  return invoke<McpProfile[]>('mcp_list_profiles');
+};
+
+export const saveMcpProfile = async (input: McpProfileInput): Promise<McpProfile> => {
+  return invoke<McpProfile>('mcp_save_profile', { input });
+};
+
+export const deleteMcpProfile = async (name: string): Promise<void> => {
+  return invoke('mcp_delete_profile', { name });
+};
+
+export const applyMcpProfile = async (name: string): Promise<McpSyncResult[]> => {
+  return invoke<McpSyncResult[]>('mcp_apply_profile', { name });
+};
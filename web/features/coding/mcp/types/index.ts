@@ -10,6 +10,10 @@ export interface McpServer {
   description: string | null;
   tags: string[];
   timeout: number | null;
+  /** Project directories the server is scoped to (Claude Code); empty means global */
+  project_paths: string[];
+  /** Write project entries to the shared <project>/.mcp.json instead of ~/.claude.json */
+  project_shared: boolean;
   sort_index: number;
   created_at: number;
   updated_at: number;
@@ -41,6 +45,8 @@ export interface CreateMcpServerInput {
   description?: string;
   tags?: string[];
   timeout?: number;
+  project_paths?: string[];
+  project_shared?: boolean;
 }
 
 export interface UpdateMcpServerInput {
@@ -51,6 +57,8 @@ export interface UpdateMcpServerInput {
   description?: string;
   tags?: string[];
   timeout?: number;
+  project_paths?: string[];
+  project_shared?: boolean;
 }
 
 export interface McpSyncResult {
@@ -80,6 +88,83 @@ export interface McpScanResult {
   servers: McpDiscoveredServer[];
 }
 
+export interface McpToolInfo {
+  name: string;
+  description?: string;
+}
+
+export interface McpTestResult {
+  server_id: string;
+  success: boolean;
+  latency_ms: number;
+  server_name?: string;
+  server_version?: string;
+  protocol_version?: string;
+  tools: McpToolInfo[];
+  error_message?: string;
+}
+
+export interface McpProfile {
+  id: string;
+  name: string;
+  server_ids: string[];
+  tool_keys: string[];
+  created_at: number;
+  updated_at: number;
+}
+
+export interface McpProfileInput {
+  name: string;
+  server_ids: string[];
+  tool_keys: string[];
+}
+
+export interface McpExportPreview {
+  tool: string;
+  config_path: string;
+  changed: boolean;
+  diff: string;
+  unmanaged_servers: string[];
+  project_files: string[];
+  errors: string[];
+}
+
+export interface McpServerLogLine {
+  timestamp: number;
+  stream: 'stdout' | 'stderr' | 'info';
+  line: string;
+}
+
+export interface McpSecret {
+  name: string;
+  has_value: boolean;
+  used_by: string[];
+}
+
+export interface McpRegistryInstallOption {
+  label: string;
+  server_type: 'stdio' | 'http' | 'sse';
+  server_config: Record<string, unknown>;
+  /** Env vars (stdio) or headers (http/sse) that still need a value */
+  required_env: string[];
+}
+
+export interface McpRegistryEntry {
+  name: string;
+  suggested_name: string;
+  title?: string;
+  description?: string;
+  version?: string;
+  repository_url?: string;
+  website_url?: string;
+  install_options: McpRegistryInstallOption[];
+}
+
+export interface McpRegistryPage {
+  servers: McpRegistryEntry[];
+  next_cursor?: string;
+}
+
 export interface McpTool {
   key: string;
   display_name: string;
@@ -185,7 +185,7 @@ export function dedupeFavoriteProvidersByPayload(
     const shouldReplaceExisting =
       (!existingIsCurrent && nextIsCurrent) ||
       (existingIsCurrent === nextIsCurrent &&
-        favoriteProvider.updatedAt > existingProvider.updatedAt);
+        (favoriteProvider.updatedAt ?? '') > (existingProvider.updatedAt ?? ''));
 
     if (shouldReplaceExisting) {
       duplicateIds.push(existingProvider.providerId);
@@ -232,7 +232,7 @@ export function dedupeOpenCodeFavoriteProviders(
         nextMatchesConfig) ||
       (existingIsCurrent === nextIsCurrent &&
         existingMatchesConfig === nextMatchesConfig &&
-        favoriteProvider.updatedAt > existingProvider.updatedAt);
+        (favoriteProvider.updatedAt ?? '') > (existingProvider.updatedAt ?? ''));
 
     if (shouldReplaceExisting) {
       duplicateIds.push(existingProvider.providerId);
@@ -273,7 +273,7 @@ export function findDiagnosticsForProvider(
       return leftIsCurrent ? -1 : 1;
     }
 
-    return right.updatedAt.localeCompare(left.updatedAt);
+    return (right.updatedAt ?? '').localeCompare(left.updatedAt ?? '');
   });
 
   return matchedProviders[0]?.diagnostics;
@@ -1,5 +1,5 @@
 import React from 'react';
-import { listen } from '@tauri-apps/api/event';
+import { listenDbChanges } from '@/services';
 import { useSkillsStore } from '../stores/skillsStore';
 import * as api from '../services/skillsApi';
 import type { ManagedSkill } from '../types';
@@ -14,12 +14,10 @@ export function useSkills() {
     }
   }, [store.isModalOpen]);
 
-  // Listen for skills-changed events from tray
+  // Reload when skill records change anywhere (tray, syncs, other windows)
   React.useEffect(() => {
-    const unlisten = listen<string>('skills-changed', (event) => {
-      if (event.payload === 'tray') {
-        store.loadSkills();
-      }
+    const unlisten = listenDbChanges(['skill'], () => {
+      store.loadSkills();
     });
 
     return () => {
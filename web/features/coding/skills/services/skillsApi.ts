@@ -3,11 +3,24 @@ import type {
   ManagedSkill,
   ToolStatus,
   InstallResult,
+  BundleImportResult,
+  SelectionInstallResult,
   SyncResult,
   UpdateResult,
+  SkillUpdateCheck,
+  BatchUpdateResult,
+  SkillDrift,
+  SkillUsage,
+  SkillLintReport,
+  SkillHistoryEntry,
+  GitCacheStats,
+  SkillNameConflict,
   GitSkillCandidate,
+  GitRepoListRequest,
+  GitRepoListResult,
   OnboardingPlan,
   SkillRepo,
+  SkillRepoCredential,
   CustomTool,
 } from '../types';
 
@@ -26,16 +39,31 @@ export const setCentralRepoPath = async (path: string): Promise<string> => {
 };
 
 // Managed Skills
-export const getManagedSkills = async (): Promise<ManagedSkill[]> => {
-  return invoke<ManagedSkill[]>('skills_get_managed_skills');
+/** Pass tags to only get skills carrying all of them */
+export const getManagedSkills = async (tags?: string[]): Promise<ManagedSkill[]> => {
+  return invoke<ManagedSkill[]>('skills_get_managed_skills', { tags });
+};
+
+export const updateSkillMetadata = async (
+  skillId: string,
+  description: string | null,
+  tags: string[],
+  notes: string | null
+): Promise<void> => {
+  return invoke('skills_update_metadata', { skillId, description, tags, notes });
+};
+
+export const getAllSkillTags = async (): Promise<string[]> => {
+  return invoke<string[]>('skills_get_all_tags');
 };
 
 // Install Skills
 export const installLocalSkill = async (
   sourcePath: string,
-  overwrite?: boolean
+  overwrite?: boolean,
+  installName?: string
 ): Promise<InstallResult> => {
-  return invoke<InstallResult>('skills_install_local', { sourcePath, overwrite });
+  return invoke<InstallResult>('skills_install_local', { sourcePath, overwrite, installName });
 };
 
 export const listLocalSkills = async (sourcePath: string): Promise<GitSkillCandidate[]> => {
@@ -45,30 +73,93 @@ export const listLocalSkills = async (sourcePath: string): Promise<GitSkillCandi
 export const installLocalSelection = async (
   sourcePath: string,
   subpath: string,
-  overwrite?: boolean
+  overwrite?: boolean,
+  installName?: string
 ): Promise<InstallResult> => {
-  return invoke<InstallResult>('skills_install_local_selection', { sourcePath, subpath, overwrite });
+  return invoke<InstallResult>('skills_install_local_selection', {
+    sourcePath,
+    subpath,
+    overwrite,
+    installName,
+  });
 };
 
 export const installGitSkill = async (
   repoUrl: string,
   branch?: string,
-  overwrite?: boolean
+  overwrite?: boolean,
+  pinnedRef?: string,
+  installName?: string
 ): Promise<InstallResult> => {
-  return invoke<InstallResult>('skills_install_git', { repoUrl, branch, overwrite });
+  return invoke<InstallResult>('skills_install_git', {
+    repoUrl,
+    branch,
+    pinnedRef,
+    overwrite,
+    installName,
+  });
 };
 
 export const listGitSkills = async (repoUrl: string, branch?: string): Promise<GitSkillCandidate[]> => {
   return invoke<GitSkillCandidate[]>('skills_list_git_skills', { repoUrl, branch });
 };
 
+/** List several repos concurrently; progress arrives via `skills-git-progress` */
+export const listGitSkillsBatch = async (
+  repos: GitRepoListRequest[]
+): Promise<GitRepoListResult[]> => {
+  return invoke<GitRepoListResult[]>('skills_list_git_skills_batch', { repos });
+};
+
 export const installGitSelection = async (
   repoUrl: string,
   subpath: string,
   branch?: string,
-  overwrite?: boolean
+  overwrite?: boolean,
+  pinnedRef?: string,
+  installName?: string
 ): Promise<InstallResult> => {
-  return invoke<InstallResult>('skills_install_git_selection', { repoUrl, subpath, branch, overwrite });
+  return invoke<InstallResult>('skills_install_git_selection', {
+    repoUrl,
+    subpath,
+    branch,
+    pinnedRef,
+    overwrite,
+    installName,
+  });
+};
+
+/** Check a skill name against managed skills and every tool's skills directory */
+export const checkSkillNameConflict = async (name: string): Promise<SkillNameConflict> => {
+  return invoke<SkillNameConflict>('skills_check_name_conflict', { name });
+};
+
+export const installGitSelections = async (
+  repoUrl: string,
+  subpaths: string[],
+  branch?: string,
+  overwrite?: boolean,
+  pinnedRef?: string
+): Promise<SelectionInstallResult[]> => {
+  return invoke<SelectionInstallResult[]>('skills_install_git_selections', {
+    repoUrl,
+    subpaths,
+    branch,
+    pinnedRef,
+    overwrite,
+  });
+};
+
+// Skill Bundles
+export const exportSkillBundle = async (ids: string[], outputPath: string): Promise<number> => {
+  return invoke<number>('skills_export_bundle', { ids, outputPath });
+};
+
+export const importSkillBundle = async (
+  path: string,
+  overwrite?: boolean
+): Promise<BundleImportResult> => {
+  return invoke<BundleImportResult>('skills_import_bundle', { path, overwrite });
 };
 
 // Sync Skills
@@ -95,15 +186,83 @@ export const unsyncSkillFromTool = async (
   return invoke('skills_unsync_from_tool', { skillId, tool });
 };
 
+/**
+ * Turn a synced target off without forgetting it, or back on.
+ * Disabled targets are removed from disk but keep their recorded path and mode.
+ */
+export const setSkillTargetEnabled = async (
+  skillId: string,
+  tool: string,
+  enabled: boolean
+): Promise<void> => {
+  return invoke('skills_set_target_enabled', { skillId, tool, enabled });
+};
+
+// Drift Detection (copy-mode targets)
+export const checkSkillDrift = async (skillId: string): Promise<SkillDrift> => {
+  return invoke<SkillDrift>('skills_check_drift', { skillId });
+};
+
+export const fixSkillDrift = async (skillId: string, tools?: string[]): Promise<SkillDrift> => {
+  return invoke<SkillDrift>('skills_fix_drift', { skillId, tools });
+};
+
+// Lint
+/**
+ * Validate SKILL.md frontmatter, oversized bundled files and Windows-incompatible
+ * path names. Run before syncing to a tool to surface problems early.
+ */
+export const lintSkill = async (skillId: string): Promise<SkillLintReport> => {
+  return invoke<SkillLintReport>('skills_lint', { skillId });
+};
+
+// Usage Insight
+/**
+ * Sync history and last read time of each target. Read times come from file atime
+ * and are best-effort: empty or stale values do not prove a skill is unused.
+ */
+export const getSkillUsage = async (skillId: string): Promise<SkillUsage> => {
+  return invoke<SkillUsage>('skills_get_usage', { skillId });
+};
+
 // Update/Delete Skills
-export const updateManagedSkill = async (skillId: string): Promise<UpdateResult> => {
-  return invoke<UpdateResult>('skills_update_managed', { skillId });
+/**
+ * Update a managed skill from its source. Pinned git skills stay on their pin;
+ * pass `movePin` to re-pin to `pinnedRef` (or unpin when it is omitted).
+ */
+export const updateManagedSkill = async (
+  skillId: string,
+  movePin?: boolean,
+  pinnedRef?: string
+): Promise<UpdateResult> => {
+  return invoke<UpdateResult>('skills_update_managed', { skillId, movePin, pinnedRef });
+};
+
+export const checkSkillUpdates = async (force?: boolean): Promise<SkillUpdateCheck[]> => {
+  return invoke<SkillUpdateCheck[]>('skills_check_updates', { force });
+};
+
+export const updateAllSkills = async (ids: string[]): Promise<BatchUpdateResult> => {
+  return invoke<BatchUpdateResult>('skills_update_all', { ids });
 };
 
 export const deleteManagedSkill = async (skillId: string): Promise<void> => {
   return invoke('skills_delete_managed', { skillId });
 };
 
+// Central Repo History
+export const getSkillHistory = async (skillId: string): Promise<SkillHistoryEntry[]> => {
+  return invoke<SkillHistoryEntry[]>('skills_get_history', { skillId });
+};
+
+/**
+ * Restore a skill to the given commit. Returns the tools whose copy-mode
+ * targets were re-copied.
+ */
+export const restoreSkillVersion = async (skillId: string, commit: string): Promise<string[]> => {
+  return invoke<string[]>('skills_restore_version', { skillId, commit });
+};
+
 // Onboarding
 export const getOnboardingPlan = async (): Promise<OnboardingPlan> => {
   return invoke<OnboardingPlan>('skills_get_onboarding_plan');
@@ -129,6 +288,19 @@ export const getGitCacheTtlSecs = async (): Promise<number> => {
   return invoke<number>('skills_get_git_cache_ttl_secs');
 };
 
+export const getGitCacheMaxSizeMb = async (): Promise<number> => {
+  return invoke<number>('skills_get_git_cache_max_size_mb');
+};
+
+/** Save the cache size cap (0 = unlimited); least recently used repos are evicted right away */
+export const setGitCacheMaxSizeMb = async (mb: number): Promise<number> => {
+  return invoke<number>('skills_set_git_cache_max_size_mb', { mb });
+};
+
+export const getGitCacheStats = async (): Promise<GitCacheStats> => {
+  return invoke<GitCacheStats>('skills_get_git_cache_stats');
+};
+
 export const clearGitCache = async (): Promise<number> => {
   return invoke<number>('skills_clear_git_cache');
 };
@@ -155,6 +327,15 @@ export const setShowSkillsInTray = async (enabled: boolean): Promise<void> => {
   return invoke('skills_set_show_in_tray', { enabled });
 };
 
+// Auto resync copy-mode targets when the central repo changes
+export const getAutoResync = async (): Promise<boolean> => {
+  return invoke<boolean>('skills_get_auto_resync');
+};
+
+export const setAutoResync = async (enabled: boolean): Promise<void> => {
+  return invoke('skills_set_auto_resync', { enabled });
+};
+
 // Skill Repos
 export const getSkillRepos = async (): Promise<SkillRepo[]> => {
   return invoke<SkillRepo[]>('skills_get_repos');
@@ -172,6 +353,29 @@ export const initDefaultRepos = async (): Promise<number> => {
   return invoke<number>('skills_init_default_repos');
 };
 
+// Private Repo Credentials (secrets live in the OS keychain)
+export const getRepoCredentials = async (): Promise<SkillRepoCredential[]> => {
+  return invoke<SkillRepoCredential[]>('skills_get_repo_credentials');
+};
+
+export const setRepoCredential = async (
+  repoUrl: string,
+  authType: 'token' | 'ssh_key',
+  secret: string,
+  username?: string
+): Promise<SkillRepoCredential> => {
+  return invoke<SkillRepoCredential>('skills_set_repo_credential', {
+    repoUrl,
+    authType,
+    username,
+    secret,
+  });
+};
+
+export const deleteRepoCredential = async (repoUrl: string): Promise<void> => {
+  return invoke('skills_delete_repo_credential', { repoUrl });
+};
+
 // Custom Tools
 export const getCustomTools = async (): Promise<CustomTool[]> => {
   return invoke<CustomTool[]>('skills_get_custom_tools');
@@ -5,11 +5,16 @@ export interface ManagedSkill {
   name: string;
   source_type: 'local' | 'git' | 'import';
   source_ref: string | null;
+  source_revision: string | null;
+  pinned_ref: string | null; // Tag or commit SHA a git skill is pinned to
   central_path: string;
   created_at: number;
   updated_at: number;
   last_sync_at: number | null;
   status: string;
+  description: string | null;
+  tags: string[];
+  notes: string | null;
   sort_index: number;
 
   // New fields
@@ -22,9 +27,12 @@ export interface ManagedSkill {
 export interface SkillTarget {
   tool: string;
   mode: string;
+  /** 'ok' | 'error' | 'pending' | 'disabled' */
   status: string;
   target_path: string;
   synced_at: number | null;
+  /** Folder name in the tool dir when synced under a name other than the skill's */
+  target_name: string | null;
 }
 
 export interface SkillRepo {
@@ -36,6 +44,14 @@ export interface SkillRepo {
   created_at: number;
 }
 
+export interface SkillRepoCredential {
+  repo_key: string; // e.g. "github.com/owner/repo"
+  auth_type: 'token' | 'ssh_key';
+  username: string | null;
+  has_secret: boolean;
+  updated_at: number;
+}
+
 export interface SkillPreferences {
   central_repo_path: string;
   preferred_tools: string[] | null;
@@ -64,6 +80,17 @@ export interface InstallResult {
   content_hash: string | null;
 }
 
+export interface SelectionInstallResult {
+  subpath: string;
+  result: InstallResult | null;
+  error: string | null;
+}
+
+export interface BundleImportResult {
+  imported: InstallResult[];
+  skipped: string[];
+}
+
 export interface SyncResult {
   mode_used: string;
   target_path: string;
@@ -74,9 +101,99 @@ export interface UpdateResult {
   name: string;
   content_hash: string | null;
   source_revision: string | null;
+  pinned_ref: string | null;
   updated_targets: string[];
 }
 
+export interface SkillUpdateCheck {
+  skill_id: string;
+  name: string;
+  current_revision: string | null;
+  remote_revision: string | null;
+  pinned_ref: string | null;
+  has_update: boolean;
+  error: string | null;
+}
+
+export interface TargetDrift {
+  tool: string;
+  target_path: string;
+  status: 'in_sync' | 'drifted' | 'missing' | 'error';
+  modified_files: string[];
+  missing_files: string[];
+  extra_files: string[];
+  error: string | null;
+}
+
+export interface SkillDrift {
+  skill_id: string;
+  name: string;
+  targets: TargetDrift[];
+}
+
+export interface GitCacheEntry {
+  key: string;
+  repo_url: string | null;
+  ref_name: string | null; // branch, or "pin:<ref>"
+  size_bytes: number;
+  last_fetched_at: number | null;
+  last_used_at: number;
+}
+
+export interface GitCacheStats {
+  path: string;
+  total_bytes: number;
+  max_size_mb: number; // 0 = unlimited
+  entries: GitCacheEntry[];
+}
+
+export interface SkillHistoryEntry {
+  commit: string;
+  short_commit: string;
+  message: string;
+  committed_at: number;
+}
+
+export interface SkillLintIssue {
+  severity: 'error' | 'warning';
+  code: string;
+  message: string;
+  path: string | null; // relative to the skill dir
+}
+
+export interface SkillLintReport {
+  skill_id: string;
+  name: string;
+  error_count: number;
+  warning_count: number;
+  issues: SkillLintIssue[];
+}
+
+export interface TargetUsage {
+  tool: string;
+  target_path: string;
+  mode: string;
+  status: string;
+  synced_at: number | null;
+  sync_count: number;
+  last_read_at: number | null;
+  read_since_sync: boolean;
+  shared_with_central: boolean; // symlink/junction: read time is not tool-specific
+}
+
+export interface SkillUsage {
+  skill_id: string;
+  name: string;
+  last_sync_at: number | null;
+  last_read_at: number | null;
+  targets: TargetUsage[];
+}
+
+export interface BatchUpdateResult {
+  updated: UpdateResult[];
+  failed: { skill_id: string; error: string }[];
+}
+
 export interface GitSkillCandidate {
   name: string;
   description: string | null;
@@ -127,3 +244,47 @@ export interface CustomTool {
   created_at: number;
   force_copy: boolean;
 }
+
+/** Payload of the `skills-auto-synced` event */
+export interface SkillAutoSyncedEvent {
+  skill_id: string;
+  name: string;
+  tools: string[];
+}
+
+export interface GitRepoListRequest {
+  repo_url: string;
+  branch?: string | null;
+}
+
+export interface GitRepoListResult {
+  repo_url: string;
+  branch: string | null;
+  skills: GitSkillCandidate[];
+  error: string | null;
+}
+
+/** Payload of the `skills-git-progress` event */
+export interface SkillGitProgressEvent {
+  repo_url: string;
+  operation: 'list' | 'install' | 'update';
+  stage: 'queued' | 'running' | 'done' | 'error';
+  error: string | null;
+}
+
+export interface ToolNameConflict {
+  tool: string;
+  target_path: string;
+  /** Managed skill occupying the path; null for unmanaged folders */
+  owner_skill_id: string | null;
+  owner_skill_name: string | null;
+}
+
+export interface SkillNameConflict {
+  name: string;
+  has_conflict: boolean;
+  existing_skill_id: string | null;
+  central_path_exists: boolean;
+  tools: ToolNameConflict[];
+  suggested_name: string | null;
+}
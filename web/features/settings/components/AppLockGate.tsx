@@ -0,0 +1,123 @@
+import React from 'react';
+import { ConfigProvider, Input, Button, Typography, theme as antdTheme } from 'antd';
+import { LockOutlined } from '@ant-design/icons';
+import { useTranslation } from 'react-i18next';
+import { listen } from '@tauri-apps/api/event';
+import { getAppLockStatus, getSettings, unlockApp, type AppLockStatus } from '@/services';
+
+const { Title, Text } = Typography;
+
+const UNLOCKED: AppLockStatus = { enabled: false, locked: false, auto_lock_minutes: 0 };
+
+const LockScreen: React.FC<{ onUnlocked: (status: AppLockStatus) => void }> = ({ onUnlocked }) => {
+  const { t } = useTranslation();
+  const [password, setPassword] = React.useState('');
+  const [error, setError] = React.useState<string | null>(null);
+  const [unlocking, setUnlocking] = React.useState(false);
+
+  const handleUnlock = async () => {
+    if (!password) return;
+    setUnlocking(true);
+    setError(null);
+    try {
+      const status = await unlockApp(password);
+      setPassword('');
+      onUnlocked(status);
+    } catch (err) {
+      setError(String(err));
+    } finally {
+      setUnlocking(false);
+    }
+  };
+
+  return (
+    <div
+      style={{
+        position: 'fixed',
+        inset: 0,
+        zIndex: 2000,
+        display: 'flex',
+        alignItems: 'center',
+        justifyContent: 'center',
+        background: 'var(--color-bg-layout, #f5f5f5)',
+      }}
+    >
+      <div style={{ width: 320, textAlign: 'center' }}>
+        <LockOutlined style={{ fontSize: 40, color: '#1677ff' }} />
+        <Title level={4} style={{ marginTop: 16 }}>
+          {t('settings.appLock.locked')}
+        </Title>
+        <Input.Password
+          autoFocus
+          value={password}
+          placeholder={t('settings.appLock.password')}
+          onChange={(event) => setPassword(event.target.value)}
+          onPressEnter={handleUnlock}
+        />
+        {error && (
+          <Text type="danger" style={{ display: 'block', marginTop: 8 }}>
+            {error}
+          </Text>
+        )}
+        <Button type="primary" block style={{ marginTop: 12 }} loading={unlocking} onClick={handleUnlock}>
+          {t('settings.appLock.unlock')}
+        </Button>
+      </div>
+    </div>
+  );
+};
+
+/**
+ * Shows the lock screen while the app lock is engaged
+ *
+ * The app itself is only mounted after the first unlock, because its initialization
+ * calls commands the backend rejects while locked. Later locks (idle timeout, "lock
+ * now") cover the mounted app instead, so its state is kept.
+ */
+const AppLockGate: React.FC<{ children: React.ReactNode }> = ({ children }) => {
+  const { i18n } = useTranslation();
+  const [status, setStatus] = React.useState<AppLockStatus | null>(null);
+  const [mounted, setMounted] = React.useState(false);
+  const prefersDark = React.useMemo(() => window.matchMedia('(prefers-color-scheme: dark)').matches, []);
+
+  React.useEffect(() => {
+    getAppLockStatus()
+      .then(setStatus)
+      .catch(() => setStatus(UNLOCKED));
+    getSettings()
+      .then((settings) => {
+        if (settings.language) {
+          i18n.changeLanguage(settings.language);
+        }
+      })
+      .catch(() => {});
+
+    const unlisten = listen('app-locked', () => {
+      getAppLockStatus().then(setStatus).catch(console.error);
+    });
+    return () => {
+      unlisten.then((fn) => fn()).catch(console.error);
+    };
+  }, [i18n]);
+
+  React.useEffect(() => {
+    if (status && !status.locked) {
+      setMounted(true);
+    }
+  }, [status]);
+
+  if (!status) return null;
+
+  return (
+    <>
+      {mounted && children}
+      {status.locked && (
+        <ConfigProvider theme={{ algorithm: prefersDark ? antdTheme.darkAlgorithm : antdTheme.defaultAlgorithm }}>
+          <LockScreen onUnlocked={setStatus} />
+        </ConfigProvider>
+      )}
+    </>
+  );
+};
+
+export default AppLockGate;
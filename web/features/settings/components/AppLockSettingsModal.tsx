@@ -0,0 +1,127 @@
+import React from 'react';
+import { Modal, Input, InputNumber, Button, Typography, Divider, Space, message } from 'antd';
+import { useTranslation } from 'react-i18next';
+import {
+  getAppLockStatus,
+  enableAppLock,
+  disableAppLock,
+  lockApp,
+  setAppLockTimeout,
+  type AppLockStatus,
+} from '@/services';
+
+const { Text } = Typography;
+
+interface AppLockSettingsModalProps {
+  open: boolean;
+  onClose: () => void;
+}
+
+const AppLockSettingsModal: React.FC<AppLockSettingsModalProps> = ({ open, onClose }) => {
+  const { t } = useTranslation();
+  const [status, setStatus] = React.useState<AppLockStatus | null>(null);
+  const [password, setPassword] = React.useState('');
+  const [confirmPassword, setConfirmPassword] = React.useState('');
+  const [autoLockMinutes, setAutoLockMinutes] = React.useState<number>(15);
+  const [busy, setBusy] = React.useState(false);
+
+  React.useEffect(() => {
+    if (!open) return;
+    setPassword('');
+    setConfirmPassword('');
+    getAppLockStatus()
+      .then((result) => {
+        setStatus(result);
+        if (result.enabled) {
+          setAutoLockMinutes(result.auto_lock_minutes);
+        }
+      })
+      .catch((error) => message.error(String(error)));
+  }, [open]);
+
+  const run = async (action: () => Promise<AppLockStatus>, closeAfter = false) => {
+    setBusy(true);
+    try {
+      setStatus(await action());
+      setPassword('');
+      setConfirmPassword('');
+      message.success(t('common.success'));
+      if (closeAfter) onClose();
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const handleEnable = () => {
+    if (!password) {
+      message.warning(t('settings.appLock.passwordRequired'));
+      return;
+    }
+    if (password !== confirmPassword) {
+      message.warning(t('settings.appLock.passwordMismatch'));
+      return;
+    }
+    run(() => enableAppLock(password, autoLockMinutes));
+  };
+
+  return (
+    <Modal title={t('settings.appLock.title')} open={open} onCancel={onClose} footer={null} width={480}>
+      <Text type="secondary" style={{ fontSize: 12 }}>
+        {t('settings.appLock.hint')}
+      </Text>
+
+      <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center', marginTop: 16 }}>
+        <Text>{t('settings.appLock.autoLockMinutes')}</Text>
+        <Space>
+          <InputNumber min={0} max={1440} value={autoLockMinutes} onChange={(value) => setAutoLockMinutes(value ?? 0)} />
+          {status?.enabled && (
+            <Button loading={busy} onClick={() => run(() => setAppLockTimeout(autoLockMinutes))}>
+              {t('common.save')}
+            </Button>
+          )}
+        </Space>
+      </div>
+      <Text type="secondary" style={{ fontSize: 12 }}>
+        {t('settings.appLock.autoLockHint')}
+      </Text>
+
+      <Divider />
+
+      {status?.enabled ? (
+        <div style={{ display: 'flex', flexDirection: 'column', gap: 8 }}>
+          <Button block loading={busy} onClick={() => run(lockApp, true)}>
+            {t('settings.appLock.lockNow')}
+          </Button>
+          <Input.Password
+            value={password}
+            placeholder={t('settings.appLock.currentPassword')}
+            onChange={(event) => setPassword(event.target.value)}
+          />
+          <Button danger block loading={busy} disabled={!password} onClick={() => run(() => disableAppLock(password))}>
+            {t('settings.appLock.disable')}
+          </Button>
+        </div>
+      ) : (
+        <div style={{ display: 'flex', flexDirection: 'column', gap: 8 }}>
+          <Input.Password
+            value={password}
+            placeholder={t('settings.appLock.password')}
+            onChange={(event) => setPassword(event.target.value)}
+          />
+          <Input.Password
+            value={confirmPassword}
+            placeholder={t('settings.appLock.confirmPassword')}
+            onChange={(event) => setConfirmPassword(event.target.value)}
+          />
+          <Button type="primary" block loading={busy} onClick={handleEnable}>
+            {t('settings.appLock.enable')}
+          </Button>
+        </div>
+      )}
+    </Modal>
+  );
+};
+
+export default AppLockSettingsModal;
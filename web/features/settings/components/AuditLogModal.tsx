@@ -0,0 +1,145 @@
+import React from 'react';
+import { Modal, Table, Input, Select, InputNumber, Button, Space, Typography, message } from 'antd';
+import { ReloadOutlined } from '@ant-design/icons';
+import { useTranslation } from 'react-i18next';
+import { getAuditLog, getSettings, saveSettings, type AuditLogEntry } from '@/services';
+
+const { Text } = Typography;
+
+const RANGE_HOURS: Record<string, number | undefined> = {
+  all: undefined,
+  day: 24,
+  week: 24 * 7,
+};
+
+interface AuditLogModalProps {
+  open: boolean;
+  onClose: () => void;
+}
+
+const AuditLogModal: React.FC<AuditLogModalProps> = ({ open, onClose }) => {
+  const { t } = useTranslation();
+  const [entries, setEntries] = React.useState<AuditLogEntry[]>([]);
+  const [loading, setLoading] = React.useState(false);
+  const [command, setCommand] = React.useState('');
+  const [range, setRange] = React.useState('all');
+  const [retentionDays, setRetentionDays] = React.useState<number | null>(null);
+
+  const loadEntries = React.useCallback(async () => {
+    setLoading(true);
+    try {
+      const hours = RANGE_HOURS[range];
+      const since = hours ? new Date(Date.now() - hours * 3600 * 1000).toISOString() : undefined;
+      setEntries(await getAuditLog({ command: command || undefined, since, limit: 500 }));
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setLoading(false);
+    }
+  }, [command, range]);
+
+  React.useEffect(() => {
+    if (open) {
+      loadEntries();
+    }
+  }, [open, loadEntries]);
+
+  React.useEffect(() => {
+    if (!open) return;
+    getSettings()
+      .then((settings) => setRetentionDays(settings.audit_log_retention_days))
+      .catch(console.error);
+  }, [open]);
+
+  const handleRetentionChange = async (days: number | null) => {
+    if (days === null) return;
+    setRetentionDays(days);
+    try {
+      const current = await getSettings();
+      await saveSettings({ ...current, audit_log_retention_days: days });
+    } catch (error) {
+      message.error(String(error));
+    }
+  };
+
+  return (
+    <Modal title={t('settings.auditLog.title')} open={open} onCancel={onClose} footer={null} width={960}>
+      <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center', marginBottom: 12 }}>
+        <Space>
+          <Input.Search
+            allowClear
+            placeholder={t('settings.auditLog.commandPlaceholder')}
+            onSearch={setCommand}
+            style={{ width: 240 }}
+          />
+          <Select
+            value={range}
+            onChange={setRange}
+            style={{ width: 140 }}
+            options={[
+              { value: 'all', label: t('settings.auditLog.rangeAll') },
+              { value: 'day', label: t('settings.auditLog.rangeDay') },
+              { value: 'week', label: t('settings.auditLog.rangeWeek') },
+            ]}
+          />
+          <Button icon={<ReloadOutlined />} loading={loading} onClick={loadEntries}>
+            {t('settings.logs.refresh')}
+          </Button>
+        </Space>
+        <Space>
+          <Text>{t('settings.auditLog.retention')}</Text>
+          <InputNumber
+            min={0}
+            max={3650}
+            value={retentionDays}
+            onChange={handleRetentionChange}
+            addonAfter={t('settings.auditLog.days')}
+            style={{ width: 120 }}
+          />
+        </Space>
+      </div>
+
+      <Table<AuditLogEntry>
+        size="small"
+        rowKey={(entry) => `${entry.timestamp}-${entry.command}`}
+        loading={loading}
+        dataSource={entries}
+        pagination={{ pageSize: 20, showSizeChanger: false }}
+        scroll={{ y: 420 }}
+        locale={{ emptyText: t('settings.auditLog.empty') }}
+        columns={[
+          {
+            title: t('settings.auditLog.time'),
+            dataIndex: 'timestamp',
+            width: 170,
+            render: (value: string) => new Date(value).toLocaleString(),
+          },
+          {
+            title: t('settings.auditLog.command'),
+            dataIndex: 'command',
+            width: 240,
+            render: (value: string) => <Text code>{value}</Text>,
+          },
+          {
+            title: t('settings.auditLog.source'),
+            dataIndex: 'source',
+            width: 90,
+          },
+          {
+            title: t('settings.auditLog.payload'),
+            dataIndex: 'payload',
+            render: (value: string) => (
+              <Text style={{ fontFamily: 'monospace', fontSize: 12, wordBreak: 'break-all' }}>{value}</Text>
+            ),
+          },
+        ]}
+      />
+
+      <Text type="secondary" style={{ fontSize: 12 }}>
+        {t('settings.auditLog.hint')}
+      </Text>
+    </Modal>
+  );
+};
+
+export default AuditLogModal;
@@ -0,0 +1,173 @@
+import React from 'react';
+import { Modal, Table, Button, Dropdown, Space, Tag, Typography, Alert, message } from 'antd';
+import { ExportOutlined, ReloadOutlined, ToolOutlined } from '@ant-design/icons';
+import { useTranslation } from 'react-i18next';
+import { save } from '@tauri-apps/plugin-dialog';
+import {
+  checkDatabaseIntegrity,
+  dumpDatabase,
+  repairDatabaseIntegrity,
+  type DumpFormat,
+  type IntegrityIssue,
+  type IntegrityReport,
+} from '@/services';
+
+const { Text } = Typography;
+
+interface DatabaseIntegrityModalProps {
+  open: boolean;
+  onClose: () => void;
+}
+
+const DatabaseIntegrityModal: React.FC<DatabaseIntegrityModalProps> = ({ open, onClose }) => {
+  const { t } = useTranslation();
+  const [report, setReport] = React.useState<IntegrityReport | null>(null);
+  const [loading, setLoading] = React.useState(false);
+  const [repairing, setRepairing] = React.useState(false);
+  const [dumping, setDumping] = React.useState(false);
+
+  const runCheck = React.useCallback(async () => {
+    setLoading(true);
+    try {
+      setReport(await checkDatabaseIntegrity());
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setLoading(false);
+    }
+  }, []);
+
+  React.useEffect(() => {
+    if (open) {
+      runCheck();
+    }
+  }, [open, runCheck]);
+
+  const handleRepair = () => {
+    Modal.confirm({
+      title: t('settings.dbIntegrity.repairConfirmTitle'),
+      content: t('settings.dbIntegrity.repairConfirmContent'),
+      okButtonProps: { danger: true },
+      onOk: async () => {
+        setRepairing(true);
+        try {
+          const result = await repairDatabaseIntegrity();
+          setReport(result);
+          message.success(t('settings.dbIntegrity.repaired', { count: result.repairedCount }));
+        } catch (error) {
+          message.error(String(error));
+        } finally {
+          setRepairing(false);
+        }
+      },
+    });
+  };
+
+  const handleDump = async (format: DumpFormat) => {
+    const timestamp = new Date().toISOString().slice(0, 19).replace(/[-:T]/g, '');
+    const extension = format === 'json' ? 'json' : 'surql';
+    const targetPath = await save({
+      title: t('settings.dbIntegrity.dump'),
+      defaultPath: `ai-toolbox-db-${timestamp}.${extension}`,
+      filters: [{ name: format === 'json' ? 'JSON' : 'SurrealQL', extensions: [extension] }],
+    });
+    if (!targetPath) {
+      return;
+    }
+
+    setDumping(true);
+    try {
+      const path = await dumpDatabase(format, targetPath);
+      message.success(t('settings.dbIntegrity.dumped', { path }));
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setDumping(false);
+    }
+  };
+
+  const issues = report?.issues ?? [];
+  const hasOpenIssues = issues.some((issue) => !issue.repaired);
+
+  return (
+    <Modal
+      title={t('settings.dbIntegrity.title')}
+      open={open}
+      onCancel={onClose}
+      width={860}
+      footer={
+        <Dropdown
+          menu={{
+            items: [
+              { key: 'json', label: t('settings.dbIntegrity.dumpJson') },
+              { key: 'surql', label: t('settings.dbIntegrity.dumpSurql') },
+            ],
+            onClick: ({ key }) => handleDump(key as DumpFormat),
+          }}
+        >
+          <Button icon={<ExportOutlined />} loading={dumping}>
+            {t('settings.dbIntegrity.dump')}
+          </Button>
+        </Dropdown>
+      }
+    >
+      <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center', marginBottom: 12 }}>
+        <Text type="secondary">{t('settings.dbIntegrity.hint')}</Text>
+        <Space>
+          <Button icon={<ReloadOutlined />} loading={loading} onClick={runCheck}>
+            {t('settings.dbIntegrity.recheck')}
+          </Button>
+          <Button icon={<ToolOutlined />} danger disabled={!hasOpenIssues} loading={repairing} onClick={handleRepair}>
+            {t('settings.dbIntegrity.repair')}
+          </Button>
+        </Space>
+      </div>
+
+      {report && issues.length === 0 ? (
+        <Alert type="success" showIcon message={t('settings.dbIntegrity.healthy')} />
+      ) : (
+        <Table<IntegrityIssue>
+          size="small"
+          rowKey={(issue) => `${issue.kind}-${issue.table}-${issue.recordId}`}
+          loading={loading}
+          dataSource={issues}
+          pagination={{ pageSize: 20, showSizeChanger: false }}
+          columns={[
+            {
+              title: t('settings.dbIntegrity.kind'),
+              dataIndex: 'kind',
+              width: 170,
+              render: (kind: IntegrityIssue['kind']) => t(`settings.dbIntegrity.kinds.${kind}`),
+            },
+            {
+              title: t('settings.dbIntegrity.record'),
+              width: 260,
+              render: (_, issue) => (
+                <Text code style={{ wordBreak: 'break-all' }}>
+                  {issue.table}:{issue.recordId}
+                </Text>
+              ),
+            },
+            {
+              title: t('settings.dbIntegrity.detail'),
+              dataIndex: 'detail',
+            },
+            {
+              title: t('settings.dbIntegrity.status'),
+              dataIndex: 'repaired',
+              width: 90,
+              render: (repaired: boolean) =>
+                repaired ? (
+                  <Tag color="success">{t('settings.dbIntegrity.fixed')}</Tag>
+                ) : (
+                  <Tag color="warning">{t('settings.dbIntegrity.open')}</Tag>
+                ),
+            },
+          ]}
+        />
+      )}
+    </Modal>
+  );
+};
+
+export default DatabaseIntegrityModal;
@@ -0,0 +1,181 @@
+import React from 'react';
+import { Modal, Descriptions, Alert, Typography, message } from 'antd';
+import { useTranslation } from 'react-i18next';
+import { listen } from '@tauri-apps/api/event';
+import { takePendingDeepLinks, refreshTrayMenu, type DeepLinkRequest } from '@/services';
+import { createClaudeProvider } from '@/services/claudeCodeApi';
+import { createCodexProvider } from '@/services/codexApi';
+import { useRefreshStore } from '@/stores';
+import {
+  skillsApi,
+  useSkillsStore,
+  isSkillExistsError,
+  extractSkillName,
+  confirmSkillOverwrite,
+  showGitError,
+} from '@/features/coding/skills';
+
+const { Text } = Typography;
+
+const TOOL_LABELS: Record<string, string> = {
+  claudecode: 'Claude Code',
+  codex: 'Codex',
+};
+
+/**
+ * Confirms `ai-toolbox://` links before installing skills or importing providers
+ */
+const DeepLinkModal: React.FC = () => {
+  const { t } = useTranslation();
+  const [queue, setQueue] = React.useState<DeepLinkRequest[]>([]);
+  const [loading, setLoading] = React.useState(false);
+  const current = queue[0];
+
+  React.useEffect(() => {
+    const takeLinks = () => {
+      takePendingDeepLinks()
+        .then((links) => {
+          if (links.length > 0) {
+            setQueue((previous) => [...previous, ...links]);
+          }
+        })
+        .catch(console.error);
+    };
+
+    // Links that arrived before this window was ready, then new ones as they come in
+    takeLinks();
+    const unlisten = listen('deep-link', takeLinks);
+    return () => {
+      unlisten.then((fn) => fn()).catch(console.error);
+    };
+  }, []);
+
+  const next = () => setQueue((previous) => previous.slice(1));
+
+  const installSkill = async (repoUrl: string, branch?: string | null, overwrite = false) => {
+    setLoading(true);
+    try {
+      const result = await skillsApi.installGitSkill(repoUrl, branch ?? undefined, overwrite);
+      await useSkillsStore.getState().refresh();
+      message.success(t('settings.deepLink.skillInstalled', { name: result.name }));
+      next();
+    } catch (error) {
+      const errMsg = String(error);
+      if (!overwrite && isSkillExistsError(errMsg)) {
+        confirmSkillOverwrite(extractSkillName(errMsg), t, () => installSkill(repoUrl, branch, true));
+      } else {
+        showGitError(errMsg, t);
+      }
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  const handleConfirm = async () => {
+    if (!current) return;
+
+    if (current.action === 'skillInstall') {
+      await installSkill(current.repoUrl, current.branch);
+      return;
+    }
+    if (current.action !== 'providerImport') {
+      next();
+      return;
+    }
+
+    setLoading(true);
+    try {
+      const shared = current.provider;
+      if (shared.tool === 'claudecode') {
+        await createClaudeProvider(shared.provider);
+        useRefreshStore.getState().incrementClaudeProviderRefresh();
+      } else {
+        await createCodexProvider(shared.provider);
+      }
+      await refreshTrayMenu();
+      message.success(t('settings.deepLink.providerImported', { name: shared.provider.name }));
+      next();
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  const renderContent = () => {
+    if (!current) return null;
+
+    if (current.action === 'invalid') {
+      return (
+        <Alert
+          type="error"
+          showIcon
+          message={t('settings.deepLink.invalid')}
+          description={
+            <>
+              <Text code style={{ wordBreak: 'break-all' }}>{current.url}</Text>
+              <div>{current.error}</div>
+            </>
+          }
+        />
+      );
+    }
+
+    return (
+      <>
+        <Descriptions column={1} size="small" bordered>
+          {current.action === 'skillInstall' ? (
+            <>
+              <Descriptions.Item label={t('settings.deepLink.repository')}>
+                <Text style={{ wordBreak: 'break-all' }}>{current.repoUrl}</Text>
+              </Descriptions.Item>
+              {current.branch && (
+                <Descriptions.Item label={t('settings.deepLink.branch')}>{current.branch}</Descriptions.Item>
+              )}
+            </>
+          ) : (
+            <>
+              <Descriptions.Item label={t('settings.deepLink.tool')}>
+                {TOOL_LABELS[current.provider.tool] ?? current.provider.tool}
+              </Descriptions.Item>
+              <Descriptions.Item label={t('settings.deepLink.providerName')}>
+                {current.provider.provider.name}
+              </Descriptions.Item>
+              {current.provider.provider.websiteUrl && (
+                <Descriptions.Item label={t('settings.deepLink.website')}>
+                  {current.provider.provider.websiteUrl}
+                </Descriptions.Item>
+              )}
+            </>
+          )}
+        </Descriptions>
+        <Alert type="warning" showIcon message={t('settings.deepLink.trustHint')} style={{ marginTop: 12 }} />
+      </>
+    );
+  };
+
+  const title = !current
+    ? ''
+    : current.action === 'skillInstall'
+      ? t('settings.deepLink.installSkill')
+      : current.action === 'providerImport'
+        ? t('settings.deepLink.importProvider')
+        : t('settings.deepLink.title');
+
+  return (
+    <Modal
+      title={title}
+      open={!!current}
+      onOk={handleConfirm}
+      onCancel={next}
+      okText={current?.action === 'invalid' ? t('common.close') : t('common.confirm')}
+      cancelButtonProps={{ style: current?.action === 'invalid' ? { display: 'none' } : undefined }}
+      confirmLoading={loading}
+      destroyOnClose
+    >
+      {renderContent()}
+    </Modal>
+  );
+};
+
+export default DeepLinkModal;
@@ -4,11 +4,11 @@
  * Modal for adding/editing file mappings
  */
 
-import React, { useEffect } from 'react';
+import React, { useEffect, useState } from 'react';
 import { Modal, Form, Input, Select, Switch, Divider, Button, Modal as AntdModal } from 'antd';
 import { useTranslation } from 'react-i18next';
-import { wslAddFileMapping, wslUpdateFileMapping } from '@/services/wslSyncApi';
-import type { FileMapping } from '@/types/wslsync';
+import { wslAddFileMapping, wslUpdateFileMapping, wslPreviewMcpTransform } from '@/services/wslSyncApi';
+import type { FileMapping, WSLTransformPreview } from '@/types/wslsync';
 
 interface FileMappingModalProps {
   open: boolean;
@@ -58,13 +58,15 @@ const detectPathIssues = (windowsPath: string, wslPath: string): PathIssue => {
 export const FileMappingModal: React.FC<FileMappingModalProps> = ({ open, onClose, mapping }) => {
   const { t } = useTranslation();
   const [form] = Form.useForm();
+  const [preview, setPreview] = useState<WSLTransformPreview | null>(null);
+  const [previewLoading, setPreviewLoading] = useState(false);
 
   const isEdit = mapping !== null;
 
   useEffect(() => {
     if (open) {
       if (mapping && mapping.id) {
-        form.setFieldsValue(mapping);
+        form.setFieldsValue({ stripCmdC: true, ...mapping });
       } else {
         form.resetFields();
         form.setFieldsValue({
@@ -72,6 +74,7 @@ export const FileMappingModal: React.FC<FileMappingModalProps> = ({ open, onClos
           enabled: true,
           isPattern: false,
           isDirectory: false,
+          stripCmdC: true,
         });
       }
     }
@@ -137,6 +140,19 @@ export const FileMappingModal: React.FC<FileMappingModalProps> = ({ open, onClos
     }
   };
 
+  const handlePreview = async () => {
+    if (!mapping?.id) return;
+    setPreviewLoading(true);
+    try {
+      setPreview(await wslPreviewMcpTransform(mapping.id));
+    } catch (error) {
+      console.error('Failed to preview transform:', error);
+      AntdModal.error({ title: t('settings.wsl.previewTransform'), content: String(error) });
+    } finally {
+      setPreviewLoading(false);
+    }
+  };
+
   const saveMapping = async (values: any) => {
     try {
       // Generate ID if new
@@ -239,7 +255,46 @@ export const FileMappingModal: React.FC<FileMappingModalProps> = ({ open, onClos
         >
           <Switch />
         </Form.Item>
+
+        <Form.Item
+          name="stripCmdC"
+          label={t('settings.wsl.stripCmdC')}
+          valuePropName="checked"
+          extra={t('settings.wsl.stripCmdCHint')}
+        >
+          <Switch />
+        </Form.Item>
+
+        {isEdit && mapping?.id && (
+          <Form.Item wrapperCol={{ offset: 6, span: 18 }}>
+            <Button size="small" loading={previewLoading} onClick={handlePreview}>
+              {t('settings.wsl.previewTransform')}
+            </Button>
+          </Form.Item>
+        )}
       </Form>
+
+      <Modal
+        title={t('settings.wsl.previewTransform')}
+        open={preview !== null}
+        onCancel={() => setPreview(null)}
+        footer={null}
+        width={720}
+      >
+        {preview && (
+          <>
+            <div style={{ marginBottom: 8, fontSize: 12 }}>
+              {preview.windowsPath} → {preview.wslPath}
+            </div>
+            {!preview.changed && (
+              <div style={{ marginBottom: 8, fontSize: 12 }}>{t('settings.wsl.transformUnchanged')}</div>
+            )}
+            <pre style={{ maxHeight: 420, overflow: 'auto', fontSize: 12, margin: 0 }}>
+              {preview.transformed}
+            </pre>
+          </>
+        )}
+      </Modal>
     </Modal>
   );
 };
@@ -0,0 +1,131 @@
+import React from 'react';
+import { Modal, Switch, InputNumber, Input, Select, Button, Typography, Alert, message } from 'antd';
+import { useTranslation } from 'react-i18next';
+import { getGatewayStatus, getSettings, saveSettings, type AppSettings, type GatewayStatus } from '@/services';
+
+const { Text, Paragraph } = Typography;
+
+type GatewaySettings = Pick<
+  AppSettings,
+  'gateway_enabled' | 'gateway_port' | 'gateway_api_key' | 'gateway_fallback_models'
+>;
+
+interface GatewayModalProps {
+  open: boolean;
+  onClose: () => void;
+}
+
+const GatewayModal: React.FC<GatewayModalProps> = ({ open, onClose }) => {
+  const { t } = useTranslation();
+  const [form, setForm] = React.useState<GatewaySettings | null>(null);
+  const [status, setStatus] = React.useState<GatewayStatus | null>(null);
+  const [saving, setSaving] = React.useState(false);
+
+  React.useEffect(() => {
+    if (!open) return;
+    getSettings()
+      .then((settings) =>
+        setForm({
+          gateway_enabled: settings.gateway_enabled,
+          gateway_port: settings.gateway_port,
+          gateway_api_key: settings.gateway_api_key,
+          gateway_fallback_models: settings.gateway_fallback_models,
+        }),
+      )
+      .catch(console.error);
+    getGatewayStatus().then(setStatus).catch(console.error);
+  }, [open]);
+
+  const update = (patch: Partial<GatewaySettings>) => {
+    setForm((prev) => (prev ? { ...prev, ...patch } : prev));
+  };
+
+  const handleSave = async () => {
+    if (!form) return;
+    setSaving(true);
+    try {
+      const current = await getSettings();
+      await saveSettings({ ...current, ...form });
+      setStatus(await getGatewayStatus());
+      message.success(t('common.success'));
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <Modal
+      title={t('settings.gateway.title')}
+      open={open}
+      onCancel={onClose}
+      width={560}
+      footer={[
+        <Button key="cancel" onClick={onClose}>
+          {t('common.cancel')}
+        </Button>,
+        <Button key="save" type="primary" loading={saving} disabled={!form} onClick={handleSave}>
+          {t('common.save')}
+        </Button>,
+      ]}
+    >
+      {form && (
+        <div style={{ display: 'flex', flexDirection: 'column', gap: 12 }}>
+          <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center' }}>
+            <Text>{t('settings.gateway.enabled')}</Text>
+            <Switch checked={form.gateway_enabled} onChange={(checked) => update({ gateway_enabled: checked })} />
+          </div>
+          <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center' }}>
+            <Text>{t('settings.gateway.port')}</Text>
+            <InputNumber
+              min={1024}
+              max={65535}
+              value={form.gateway_port}
+              onChange={(value) => value !== null && update({ gateway_port: value })}
+              style={{ width: 160 }}
+            />
+          </div>
+          <div>
+            <Text>{t('settings.gateway.apiKey')}</Text>
+            <Input.Password
+              value={form.gateway_api_key}
+              onChange={(e) => update({ gateway_api_key: e.target.value })}
+              placeholder={t('settings.gateway.apiKeyPlaceholder')}
+              style={{ marginTop: 4 }}
+            />
+          </div>
+          <div>
+            <Text>{t('settings.gateway.fallbackModels')}</Text>
+            <Select
+              mode="tags"
+              value={form.gateway_fallback_models}
+              onChange={(values: string[]) => update({ gateway_fallback_models: values })}
+              placeholder={t('settings.gateway.fallbackPlaceholder')}
+              open={false}
+              style={{ width: '100%', marginTop: 4 }}
+            />
+          </div>
+
+          {status?.running && status.baseUrl ? (
+            <div>
+              <Text type="success">{t('settings.gateway.running')}</Text>
+              <Paragraph copyable={{ text: status.baseUrl }} style={{ marginBottom: 0 }}>
+                {t('settings.gateway.baseUrl')}: <Text code>{status.baseUrl}</Text>
+              </Paragraph>
+            </div>
+          ) : (
+            <Text type="secondary">{t('settings.gateway.stopped')}</Text>
+          )}
+          {status?.error && <Alert type="error" showIcon message={status.error} />}
+
+          <Text type="secondary" style={{ fontSize: 12 }}>
+            {t('settings.gateway.hint')}
+          </Text>
+        </div>
+      )}
+    </Modal>
+  );
+};
+
+export default GatewayModal;
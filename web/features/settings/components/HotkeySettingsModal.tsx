@@ -0,0 +1,145 @@
+import React from 'react';
+import { Modal, Input, Switch, Button, Typography, message } from 'antd';
+import { CloseCircleOutlined } from '@ant-design/icons';
+import { useTranslation } from 'react-i18next';
+import {
+  getSettings,
+  getHotkeyStatus,
+  saveHotkeys,
+  HOTKEY_ACTIONS,
+  type HotkeyBinding,
+  type HotkeyStatus,
+} from '@/services';
+
+const { Text } = Typography;
+
+interface HotkeySettingsModalProps {
+  open: boolean;
+  onClose: () => void;
+}
+
+const MODIFIER_CODES = ['ControlLeft', 'ControlRight', 'ShiftLeft', 'ShiftRight', 'AltLeft', 'AltRight', 'MetaLeft', 'MetaRight'];
+
+/**
+ * Convert a keydown event into an accelerator string, e.g. "Ctrl+Shift+K"
+ */
+const toAccelerator = (event: React.KeyboardEvent): string | null => {
+  if (MODIFIER_CODES.includes(event.code)) return null;
+  const parts: string[] = [];
+  if (event.ctrlKey) parts.push('Ctrl');
+  if (event.metaKey) parts.push('Super');
+  if (event.altKey) parts.push('Alt');
+  if (event.shiftKey) parts.push('Shift');
+  if (parts.length === 0) return null;
+  parts.push(event.code.replace(/^Key/, '').replace(/^Digit/, ''));
+  return parts.join('+');
+};
+
+const HotkeySettingsModal: React.FC<HotkeySettingsModalProps> = ({ open, onClose }) => {
+  const { t } = useTranslation();
+  const [bindings, setBindings] = React.useState<HotkeyBinding[]>([]);
+  const [statuses, setStatuses] = React.useState<HotkeyStatus[]>([]);
+  const [saving, setSaving] = React.useState(false);
+
+  React.useEffect(() => {
+    if (!open) return;
+    Promise.all([getSettings(), getHotkeyStatus()])
+      .then(([settings, status]) => {
+        setBindings(
+          HOTKEY_ACTIONS.map(
+            (action) =>
+              settings.hotkeys.find((binding) => binding.action === action) ?? { action, shortcut: '', enabled: true }
+          )
+        );
+        setStatuses(status);
+      })
+      .catch((error) => message.error(String(error)));
+  }, [open]);
+
+  const updateBinding = (action: string, patch: Partial<HotkeyBinding>) => {
+    setBindings((prev) => prev.map((binding) => (binding.action === action ? { ...binding, ...patch } : binding)));
+  };
+
+  const handleSave = async () => {
+    setSaving(true);
+    try {
+      const result = await saveHotkeys(bindings);
+      setStatuses(result);
+      if (result.some((status) => status.error)) {
+        message.warning(t('settings.hotkeys.partiallyRegistered'));
+      } else {
+        message.success(t('common.success'));
+        onClose();
+      }
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <Modal
+      title={t('settings.hotkeys.title')}
+      open={open}
+      onCancel={onClose}
+      onOk={handleSave}
+      confirmLoading={saving}
+      okText={t('common.save')}
+      cancelText={t('common.cancel')}
+      width={560}
+    >
+      <Text type="secondary" style={{ fontSize: 12 }}>
+        {t('settings.hotkeys.hint')}
+      </Text>
+      <div style={{ display: 'flex', flexDirection: 'column', gap: 12, marginTop: 12 }}>
+        {bindings.map((binding) => {
+          const status = statuses.find((item) => item.action === binding.action);
+          return (
+            <div key={binding.action}>
+              <div style={{ display: 'flex', alignItems: 'center', gap: 8 }}>
+                <Text style={{ flex: 1 }}>{t(`settings.hotkeys.actions.${binding.action}`)}</Text>
+                <Input
+                  style={{ width: 200 }}
+                  readOnly
+                  value={binding.shortcut}
+                  placeholder={t('settings.hotkeys.placeholder')}
+                  onKeyDown={(event) => {
+                    if (event.key === 'Tab') return;
+                    event.preventDefault();
+                    const accelerator = toAccelerator(event);
+                    if (accelerator) {
+                      updateBinding(binding.action, { shortcut: accelerator });
+                    }
+                  }}
+                  suffix={
+                    binding.shortcut ? (
+                      <Button
+                        type="text"
+                        size="small"
+                        icon={<CloseCircleOutlined />}
+                        onClick={() => updateBinding(binding.action, { shortcut: '' })}
+                      />
+                    ) : null
+                  }
+                />
+                <Switch
+                  size="small"
+                  checked={binding.enabled}
+                  onChange={(checked) => updateBinding(binding.action, { enabled: checked })}
+                />
+              </div>
+              {status?.error && status.shortcut === binding.shortcut && (
+                <Text type="danger" style={{ fontSize: 12 }}>
+                  {status.error}
+                </Text>
+              )}
+            </div>
+          );
+        })}
+      </div>
+    </Modal>
+  );
+};
+
+export default HotkeySettingsModal;
@@ -0,0 +1,135 @@
+import React from 'react';
+import { Modal, Select, Button, Space, Tag, Typography, Empty, message } from 'antd';
+import { ReloadOutlined, ExportOutlined } from '@ant-design/icons';
+import { useTranslation } from 'react-i18next';
+import { save } from '@tauri-apps/plugin-dialog';
+import { getRecentLogs, exportDiagnosticsBundle, type LogEntry, type LogLevel } from '@/services';
+
+const { Text } = Typography;
+
+const LEVEL_COLORS: Record<string, string> = {
+  ERROR: 'red',
+  WARN: 'orange',
+  INFO: 'blue',
+  DEBUG: 'default',
+};
+
+interface LogViewerModalProps {
+  open: boolean;
+  onClose: () => void;
+}
+
+const LogViewerModal: React.FC<LogViewerModalProps> = ({ open, onClose }) => {
+  const { t } = useTranslation();
+  const [level, setLevel] = React.useState<LogLevel>('info');
+  const [logs, setLogs] = React.useState<LogEntry[]>([]);
+  const [loading, setLoading] = React.useState(false);
+  const [exporting, setExporting] = React.useState(false);
+
+  const loadLogs = React.useCallback(async () => {
+    setLoading(true);
+    try {
+      setLogs(await getRecentLogs(level, 500));
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setLoading(false);
+    }
+  }, [level]);
+
+  React.useEffect(() => {
+    if (open) {
+      loadLogs();
+    }
+  }, [open, loadLogs]);
+
+  const handleExport = async () => {
+    const timestamp = new Date().toISOString().slice(0, 19).replace(/[-:T]/g, '');
+    const targetPath = await save({
+      title: t('settings.logs.exportDiagnostics'),
+      defaultPath: `ai-toolbox-diagnostics-${timestamp}.zip`,
+      filters: [{ name: 'Zip', extensions: ['zip'] }],
+    });
+    if (!targetPath) {
+      return;
+    }
+
+    setExporting(true);
+    try {
+      const path = await exportDiagnosticsBundle(targetPath);
+      message.success(t('settings.logs.exported', { path }));
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setExporting(false);
+    }
+  };
+
+  return (
+    <Modal
+      title={t('settings.logs.title')}
+      open={open}
+      onCancel={onClose}
+      width={860}
+      footer={
+        <Button icon={<ExportOutlined />} loading={exporting} onClick={handleExport}>
+          {t('settings.logs.exportDiagnostics')}
+        </Button>
+      }
+    >
+      <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center', marginBottom: 12 }}>
+        <Space>
+          <Text>{t('settings.logs.level')}</Text>
+          <Select
+            value={level}
+            onChange={(value: LogLevel) => setLevel(value)}
+            style={{ width: 120 }}
+            options={[
+              { value: 'error', label: 'Error' },
+              { value: 'warn', label: 'Warn' },
+              { value: 'info', label: 'Info' },
+              { value: 'debug', label: 'Debug' },
+            ]}
+          />
+        </Space>
+        <Button size="small" icon={<ReloadOutlined />} loading={loading} onClick={loadLogs}>
+          {t('settings.logs.refresh')}
+        </Button>
+      </div>
+
+      <div
+        style={{
+          maxHeight: 420,
+          overflow: 'auto',
+          fontFamily: 'monospace',
+          fontSize: 12,
+          display: 'flex',
+          flexDirection: 'column',
+          gap: 4,
+        }}
+      >
+        {logs.length === 0 ? (
+          <Empty image={Empty.PRESENTED_IMAGE_SIMPLE} description={t('settings.logs.empty')} />
+        ) : (
+          logs.map((entry, index) => (
+            <div key={index} style={{ display: 'flex', gap: 8, alignItems: 'flex-start' }}>
+              <Text type="secondary" style={{ whiteSpace: 'nowrap', fontSize: 12 }}>
+                {new Date(entry.timestamp).toLocaleTimeString()}
+              </Text>
+              <Tag color={LEVEL_COLORS[entry.level] ?? 'default'} style={{ marginInlineEnd: 0 }}>
+                {entry.level}
+              </Tag>
+              <Text style={{ fontSize: 12, wordBreak: 'break-all', whiteSpace: 'pre-wrap' }}>{entry.message}</Text>
+            </div>
+          ))
+        )}
+      </div>
+
+      <Text type="secondary" style={{ fontSize: 12, display: 'block', marginTop: 12 }}>
+        {t('settings.logs.diagnosticsHint')}
+      </Text>
+    </Modal>
+  );
+};
+
+export default LogViewerModal;
@@ -0,0 +1,122 @@
+import React from 'react';
+import { Modal, Input, Select, Button, Typography, Empty, message } from 'antd';
+import { ArrowUpOutlined, ArrowDownOutlined, DeleteOutlined, PlusOutlined } from '@ant-design/icons';
+import { useTranslation } from 'react-i18next';
+import { useSettingsStore } from '@/stores';
+import type { ProxyRule, ProxyRuleAction } from '@/services';
+
+const { Text } = Typography;
+
+interface ProxyRulesModalProps {
+  open: boolean;
+  onClose: () => void;
+}
+
+const ProxyRulesModal: React.FC<ProxyRulesModalProps> = ({ open, onClose }) => {
+  const { t } = useTranslation();
+  const { proxyRules, setProxyRules } = useSettingsStore();
+  const [rules, setRules] = React.useState<ProxyRule[]>([]);
+  const [saving, setSaving] = React.useState(false);
+
+  React.useEffect(() => {
+    if (open) {
+      setRules(proxyRules);
+    }
+  }, [open, proxyRules]);
+
+  const updateRule = (index: number, patch: Partial<ProxyRule>) => {
+    setRules((prev) => prev.map((rule, i) => (i === index ? { ...rule, ...patch } : rule)));
+  };
+
+  const moveRule = (index: number, offset: number) => {
+    setRules((prev) => {
+      const target = index + offset;
+      if (target < 0 || target >= prev.length) return prev;
+      const next = [...prev];
+      [next[index], next[target]] = [next[target], next[index]];
+      return next;
+    });
+  };
+
+  const handleSave = async () => {
+    setSaving(true);
+    try {
+      await setProxyRules(
+        rules.map((rule) => ({ ...rule, pattern: rule.pattern.trim() })).filter((rule) => rule.pattern)
+      );
+      message.success(t('common.success'));
+      onClose();
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  return (
+    <Modal
+      title={t('settings.proxy.rulesTitle')}
+      open={open}
+      onCancel={onClose}
+      onOk={handleSave}
+      confirmLoading={saving}
+      okText={t('common.save')}
+      cancelText={t('common.cancel')}
+      width={600}
+    >
+      <Text type="secondary" style={{ fontSize: 12 }}>
+        {t('settings.proxy.rulesHint')}
+      </Text>
+
+      <div style={{ display: 'flex', flexDirection: 'column', gap: 8, marginTop: 12 }}>
+        {rules.length === 0 ? (
+          <Empty image={Empty.PRESENTED_IMAGE_SIMPLE} description={t('settings.proxy.rulesEmpty')} />
+        ) : (
+          rules.map((rule, index) => (
+            <div key={index} style={{ display: 'flex', gap: 8, alignItems: 'center' }}>
+              <Input
+                value={rule.pattern}
+                placeholder={t('settings.proxy.rulePatternPlaceholder')}
+                onChange={(event) => updateRule(index, { pattern: event.target.value })}
+                style={{ flex: 1 }}
+              />
+              <Select
+                value={rule.action}
+                onChange={(action: ProxyRuleAction) => updateRule(index, { action })}
+                style={{ width: 120 }}
+                options={[
+                  { value: 'proxy', label: t('settings.proxy.ruleActionProxy') },
+                  { value: 'direct', label: t('settings.proxy.ruleActionDirect') },
+                ]}
+              />
+              <Button size="small" type="text" icon={<ArrowUpOutlined />} disabled={index === 0} onClick={() => moveRule(index, -1)} />
+              <Button
+                size="small"
+                type="text"
+                icon={<ArrowDownOutlined />}
+                disabled={index === rules.length - 1}
+                onClick={() => moveRule(index, 1)}
+              />
+              <Button
+                size="small"
+                type="text"
+                danger
+                icon={<DeleteOutlined />}
+                onClick={() => setRules((prev) => prev.filter((_, i) => i !== index))}
+              />
+            </div>
+          ))
+        )}
+        <Button
+          type="dashed"
+          icon={<PlusOutlined />}
+          onClick={() => setRules((prev) => [...prev, { pattern: '', action: 'proxy' }])}
+        >
+          {t('settings.proxy.addRule')}
+        </Button>
+      </div>
+    </Modal>
+  );
+};
+
+export default ProxyRulesModal;
@@ -0,0 +1,164 @@
+import React from 'react';
+import { ConfigProvider, Input, Empty, Tag, Typography, message, theme as antdTheme, type InputRef } from 'antd';
+import { CheckOutlined } from '@ant-design/icons';
+import zhCN from 'antd/locale/zh_CN';
+import enUS from 'antd/locale/en_US';
+import { useTranslation } from 'react-i18next';
+import { listen } from '@tauri-apps/api/event';
+import { getCurrentWindow } from '@tauri-apps/api/window';
+import {
+  getSettings,
+  searchQuickSwitch,
+  applyQuickSwitchItem,
+  hideQuickSwitchWindow,
+  type QuickSwitchItem,
+  type QuickSwitchKind,
+} from '@/services';
+
+const { Text } = Typography;
+
+const KIND_LABEL_KEYS: Record<QuickSwitchKind, string> = {
+  claude_provider: 'settings.quickSwitch.claudeProviders',
+  opencode_main: 'settings.quickSwitch.opencodeMainModels',
+  opencode_small: 'settings.quickSwitch.opencodeSmallModels',
+  codex_provider: 'settings.quickSwitch.codexProviders',
+  skill_tool: 'settings.quickSwitch.skills',
+};
+
+const QuickSwitchPalette: React.FC = () => {
+  const { t } = useTranslation();
+  const inputRef = React.useRef<InputRef>(null);
+  const [query, setQuery] = React.useState('');
+  const [items, setItems] = React.useState<QuickSwitchItem[]>([]);
+  const [activeIndex, setActiveIndex] = React.useState(0);
+  const [applying, setApplying] = React.useState(false);
+
+  const search = React.useCallback(async (value: string, refresh = false) => {
+    try {
+      setItems(await searchQuickSwitch(value, refresh));
+      setActiveIndex(0);
+    } catch (error) {
+      message.error(String(error));
+    }
+  }, []);
+
+  React.useEffect(() => {
+    search('', true);
+    const unlistenShown = listen('quick-switch-shown', () => {
+      setQuery('');
+      search('', true);
+      inputRef.current?.focus();
+    });
+    // Hide when the palette loses focus, like other launcher palettes
+    const unlistenFocus = getCurrentWindow().onFocusChanged(({ payload: focused }) => {
+      if (!focused) {
+        hideQuickSwitchWindow();
+      }
+    });
+    return () => {
+      unlistenShown.then((fn) => fn());
+      unlistenFocus.then((fn) => fn());
+    };
+  }, [search]);
+
+  const handleApply = async (item: QuickSwitchItem) => {
+    setApplying(true);
+    try {
+      await applyQuickSwitchItem(item.kind, item.id);
+      await hideQuickSwitchWindow();
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setApplying(false);
+    }
+  };
+
+  const handleKeyDown = (event: React.KeyboardEvent) => {
+    if (event.key === 'Escape') {
+      hideQuickSwitchWindow();
+    } else if (event.key === 'ArrowDown') {
+      event.preventDefault();
+      setActiveIndex((index) => Math.min(index + 1, items.length - 1));
+    } else if (event.key === 'ArrowUp') {
+      event.preventDefault();
+      setActiveIndex((index) => Math.max(index - 1, 0));
+    } else if (event.key === 'Enter' && items[activeIndex] && !applying) {
+      handleApply(items[activeIndex]);
+    }
+  };
+
+  return (
+    <div style={{ display: 'flex', flexDirection: 'column', height: '100vh', padding: 12, boxSizing: 'border-box' }}>
+      <Input
+        ref={inputRef}
+        autoFocus
+        size="large"
+        value={query}
+        placeholder={t('settings.quickSwitch.placeholder')}
+        onChange={(event) => {
+          setQuery(event.target.value);
+          search(event.target.value);
+        }}
+        onKeyDown={handleKeyDown}
+      />
+      <div style={{ flex: 1, overflowY: 'auto', marginTop: 8 }}>
+        {items.length === 0 ? (
+          <Empty image={Empty.PRESENTED_IMAGE_SIMPLE} description={t('settings.quickSwitch.noResults')} />
+        ) : (
+          items.map((item, index) => (
+            <div
+              key={`${item.kind}:${item.id}`}
+              onMouseEnter={() => setActiveIndex(index)}
+              onClick={() => !applying && handleApply(item)}
+              style={{
+                display: 'flex',
+                alignItems: 'center',
+                gap: 8,
+                padding: '6px 8px',
+                borderRadius: 6,
+                cursor: 'pointer',
+                background: index === activeIndex ? 'var(--ant-control-item-bg-active, rgba(22, 119, 255, 0.1))' : undefined,
+              }}
+            >
+              <Tag style={{ margin: 0 }}>{t(KIND_LABEL_KEYS[item.kind])}</Tag>
+              <Text ellipsis style={{ flex: 1 }}>
+                {item.label}
+              </Text>
+              {item.is_selected && <CheckOutlined style={{ color: '#52c41a' }} />}
+            </div>
+          ))
+        )}
+      </div>
+      <Text type="secondary" style={{ fontSize: 12, marginTop: 8 }}>
+        {t('settings.quickSwitch.hint')}
+      </Text>
+    </div>
+  );
+};
+
+/**
+ * Root of the always-on-top quick-switch window (label "quick-switch")
+ */
+const QuickSwitchWindow: React.FC = () => {
+  const { i18n } = useTranslation();
+  const prefersDark = React.useMemo(() => window.matchMedia('(prefers-color-scheme: dark)').matches, []);
+
+  React.useEffect(() => {
+    getSettings().then((settings) => {
+      if (settings.language) {
+        i18n.changeLanguage(settings.language);
+      }
+    });
+  }, [i18n]);
+
+  return (
+    <ConfigProvider
+      locale={i18n.language === 'en-US' ? enUS : zhCN}
+      theme={{ algorithm: prefersDark ? antdTheme.darkAlgorithm : antdTheme.defaultAlgorithm }}
+    >
+      <QuickSwitchPalette />
+    </ConfigProvider>
+  );
+};
+
+export default QuickSwitchWindow;
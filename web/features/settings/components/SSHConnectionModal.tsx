@@ -5,9 +5,10 @@
  */
 
 import React, { useEffect } from 'react';
-import { Modal, Form, Input, InputNumber, Radio, Space } from 'antd';
+import { Modal, Form, Input, InputNumber, Radio, Space, Switch, Button, Checkbox } from 'antd';
+import { DeleteOutlined, PlusOutlined } from '@ant-design/icons';
 import { useTranslation } from 'react-i18next';
-import type { SSHConnection } from '@/types/sshsync';
+import type { SSHConnection, SSHPathRewrite } from '@/types/sshsync';
 
 // Check if the value looks like PEM private key content (not a file path)
 const isPrivateKeyContent = (value: string) => value.trim().startsWith('-----BEGIN');
@@ -41,12 +42,15 @@ export const SSHConnectionModal: React.FC<SSHConnectionModalProps> = ({
         password: connection.password,
         privateKeyPath: connection.privateKeyContent || connection.privateKeyPath,
         passphrase: connection.passphrase,
+        redactSecrets: connection.redactSecrets ?? false,
+        pathRewrites: connection.pathRewrites ?? [],
       });
     } else if (open) {
       form.resetFields();
       form.setFieldsValue({
         port: 22,
         authMethod: 'key',
+        redactSecrets: false,
       });
     }
   }, [open, connection, form]);
@@ -69,6 +73,14 @@ export const SSHConnectionModal: React.FC<SSHConnectionModalProps> = ({
         privateKeyContent: isContent ? keyInput : '',
         passphrase: values.authMethod === 'key' ? values.passphrase || '' : '',
         sortOrder: connection?.sortOrder || 0,
+        redactSecrets: values.redactSecrets ?? false,
+        pathRewrites: (values.pathRewrites ?? [])
+          .filter((rule: SSHPathRewrite) => rule?.pattern?.trim())
+          .map((rule: SSHPathRewrite) => ({
+            pattern: rule.pattern.trim(),
+            replacement: rule.replacement ?? '',
+            isRegex: rule.isRegex ?? false,
+          })),
       });
       onClose();
     } catch {
@@ -149,6 +161,41 @@ export const SSHConnectionModal: React.FC<SSHConnectionModalProps> = ({
             <Input.Password />
           </Form.Item>
         )}
+
+        <Form.Item
+          name="redactSecrets"
+          label={t('settings.ssh.redactSecrets')}
+          valuePropName="checked"
+          extra={t('settings.ssh.redactSecretsHint')}
+        >
+          <Switch />
+        </Form.Item>
+
+        <Form.Item label={t('settings.ssh.pathRewrites')} extra={t('settings.ssh.pathRewritesHint')}>
+          <Form.List name="pathRewrites">
+            {(fields, { add, remove }) => (
+              <>
+                {fields.map((field) => (
+                  <Space key={field.key} align="baseline" style={{ display: 'flex' }}>
+                    <Form.Item name={[field.name, 'pattern']} noStyle>
+                      <Input placeholder="~/Library/Application Support/" />
+                    </Form.Item>
+                    <Form.Item name={[field.name, 'replacement']} noStyle>
+                      <Input placeholder="~/.config/" />
+                    </Form.Item>
+                    <Form.Item name={[field.name, 'isRegex']} valuePropName="checked" noStyle>
+                      <Checkbox>{t('settings.ssh.pathRewriteRegex')}</Checkbox>
+                    </Form.Item>
+                    <Button type="text" danger icon={<DeleteOutlined />} onClick={() => remove(field.name)} />
+                  </Space>
+                ))}
+                <Button type="dashed" size="small" icon={<PlusOutlined />} onClick={() => add({ pattern: '', replacement: '', isRegex: false })}>
+                  {t('settings.ssh.addPathRewrite')}
+                </Button>
+              </>
+            )}
+          </Form.List>
+        </Form.Item>
       </Form>
     </Modal>
   );
@@ -35,6 +35,7 @@ export const SSHFileMappingModal: React.FC<SSHFileMappingModalProps> = ({ open,
           enabled: true,
           isPattern: false,
           isDirectory: false,
+          excludePatterns: [],
         });
       }
     }
@@ -145,6 +146,14 @@ export const SSHFileMappingModal: React.FC<SSHFileMappingModalProps> = ({ open,
         >
           <Switch />
         </Form.Item>
+
+        <Form.Item
+          name="excludePatterns"
+          label={t('settings.ssh.excludePatterns')}
+          extra={t('settings.ssh.excludePatternsHint')}
+        >
+          <Select mode="tags" open={false} tokenSeparators={[',', ' ']} placeholder="*.bak.*, cache" />
+        </Form.Item>
       </Form>
     </Modal>
   );
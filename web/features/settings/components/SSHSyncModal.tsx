@@ -21,6 +21,7 @@ import {
   sshDeleteFileMapping,
   sshResetFileMappings,
   sshTestConnection,
+  sshTrustHostKey,
   sshCreateConnection,
   sshUpdateConnection,
   sshDeleteConnection,
@@ -37,6 +38,7 @@ const MODULE_NAMES: Record<string, string> = {
   claude: 'Claude Code',
   codex: 'Codex',
   openclaw: 'OpenClaw',
+  cursor: 'Cursor',
 };
 
 // Module tag colors
@@ -45,6 +47,7 @@ const MODULE_COLORS: Record<string, string> = {
   claude: 'purple',
   codex: 'orange',
   openclaw: 'green',
+  cursor: 'cyan',
 };
 
 // Map sync module keys to visibleTabs keys
@@ -253,6 +256,21 @@ export const SSHSyncModal: React.FC<SSHSyncModalProps> = ({ open, onClose }) =>
     }
   }, [activeConnectionId, config?.connections]);
 
+  const handleTrustHostKey = useCallback(async () => {
+    const hostKey = testResult?.hostKey;
+    if (!hostKey) return;
+    try {
+      await sshTrustHostKey(hostKey.host, hostKey.port, hostKey.keyType, hostKey.fingerprint);
+      await handleTestConnection();
+    } catch (error) {
+      setTestResult({
+        connected: false,
+        error: String(error),
+        hostKey,
+      });
+    }
+  }, [handleTestConnection, testResult?.hostKey]);
+
   // Auto test connection when modal opens or active connection changes
   useEffect(() => {
     if (open && enabled && activeConnectionId && config?.connections.length) {
@@ -274,6 +292,8 @@ export const SSHSyncModal: React.FC<SSHSyncModalProps> = ({ open, onClose }) =>
       privateKeyContent: '',
       passphrase: '',
       sortOrder: (config?.connections.length || 0),
+      redactSecrets: false,
+      pathRewrites: [],
     });
     setConnectionModalOpen(true);
   };
@@ -590,6 +610,18 @@ export const SSHSyncModal: React.FC<SSHSyncModalProps> = ({ open, onClose }) =>
                   <Text type="danger" style={{ fontSize: 12 }}>{translateSyncMessage(testResult.error, 'ssh', t)}</Text>
                 </div>
               )}
+              {!testing && testResult?.hostKey && testResult.hostKey.status !== 'trusted' && (
+                <div style={{ marginTop: 4 }}>
+                  <Space wrap>
+                    <Text type="secondary" style={{ fontSize: 12 }}>
+                      {testResult.hostKey.keyType} {testResult.hostKey.fingerprint}
+                    </Text>
+                    <Button size="small" danger={testResult.hostKey.status === 'changed'} onClick={handleTrustHostKey}>
+                      {testResult.hostKey.status === 'changed' ? t('settings.ssh.retrustHostKey') : t('settings.ssh.trustHostKey')}
+                    </Button>
+                  </Space>
+                </div>
+              )}
             </div>
           )}
 
@@ -0,0 +1,163 @@
+import React from 'react';
+import { Modal, Table, Input, Button, Tag, Typography, Popconfirm, Space, message } from 'antd';
+import { DeleteOutlined, PlusOutlined } from '@ant-design/icons';
+import { useTranslation } from 'react-i18next';
+import { listSecrets, setSecret, deleteSecret, type SecretInfo } from '@/services';
+
+const { Text } = Typography;
+
+interface SecretsVaultModalProps {
+  open: boolean;
+  onClose: () => void;
+}
+
+const SecretsVaultModal: React.FC<SecretsVaultModalProps> = ({ open, onClose }) => {
+  const { t } = useTranslation();
+  const [secrets, setSecrets] = React.useState<SecretInfo[]>([]);
+  const [loading, setLoading] = React.useState(false);
+  const [name, setName] = React.useState('');
+  const [value, setValue] = React.useState('');
+  const [description, setDescription] = React.useState('');
+  const [saving, setSaving] = React.useState(false);
+
+  const loadSecrets = React.useCallback(async () => {
+    setLoading(true);
+    try {
+      setSecrets(await listSecrets());
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setLoading(false);
+    }
+  }, []);
+
+  React.useEffect(() => {
+    if (open) {
+      loadSecrets();
+    }
+  }, [open, loadSecrets]);
+
+  const resetForm = () => {
+    setName('');
+    setValue('');
+    setDescription('');
+  };
+
+  const handleSave = async () => {
+    if (!name.trim()) {
+      message.warning(t('settings.secrets.nameRequired'));
+      return;
+    }
+    setSaving(true);
+    try {
+      await setSecret(name.trim(), value, description);
+      resetForm();
+      await loadSecrets();
+      message.success(t('settings.secrets.saved'));
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleDelete = async (secretName: string) => {
+    try {
+      await deleteSecret(secretName);
+      await loadSecrets();
+    } catch (error) {
+      message.error(String(error));
+    }
+  };
+
+  const columns = [
+    {
+      title: t('settings.secrets.name'),
+      dataIndex: 'name',
+      key: 'name',
+      render: (secretName: string) => <code>{secretName}</code>,
+    },
+    {
+      title: t('settings.secrets.description'),
+      dataIndex: 'description',
+      key: 'description',
+      ellipsis: true,
+    },
+    {
+      title: t('settings.secrets.status'),
+      dataIndex: 'has_value',
+      key: 'has_value',
+      width: 90,
+      render: (hasValue: boolean) => (
+        <Tag color={hasValue ? 'green' : 'red'}>{hasValue ? t('settings.secrets.set') : t('settings.secrets.missing')}</Tag>
+      ),
+    },
+    {
+      key: 'actions',
+      width: 110,
+      render: (_: unknown, record: SecretInfo) => (
+        <Space size={4}>
+          <Button
+            size="small"
+            type="link"
+            onClick={() => {
+              setName(record.name);
+              setDescription(record.description);
+              setValue('');
+            }}
+          >
+            {t('common.edit')}
+          </Button>
+          <Popconfirm title={t('settings.secrets.deleteConfirm', { name: record.name })} onConfirm={() => handleDelete(record.name)}>
+            <Button size="small" type="text" danger icon={<DeleteOutlined />} />
+          </Popconfirm>
+        </Space>
+      ),
+    },
+  ];
+
+  return (
+    <Modal title={t('settings.secrets.title')} open={open} onCancel={onClose} footer={null} width={640}>
+      <Text type="secondary" style={{ fontSize: 12 }}>
+        {t('settings.secrets.hint', { example: '{{secret:NAME}}', interpolation: { escapeValue: false } })}
+      </Text>
+
+      <Table
+        style={{ marginTop: 12 }}
+        size="small"
+        rowKey="name"
+        loading={loading}
+        columns={columns}
+        dataSource={secrets}
+        pagination={false}
+        locale={{ emptyText: t('settings.secrets.empty') }}
+      />
+
+      <div style={{ display: 'flex', flexDirection: 'column', gap: 8, marginTop: 16 }}>
+        <Space.Compact style={{ width: '100%' }}>
+          <Input
+            style={{ width: '40%' }}
+            value={name}
+            placeholder={t('settings.secrets.name')}
+            onChange={(event) => setName(event.target.value)}
+          />
+          <Input.Password
+            value={value}
+            placeholder={t('settings.secrets.valuePlaceholder')}
+            onChange={(event) => setValue(event.target.value)}
+          />
+        </Space.Compact>
+        <Input
+          value={description}
+          placeholder={t('settings.secrets.description')}
+          onChange={(event) => setDescription(event.target.value)}
+        />
+        <Button type="primary" icon={<PlusOutlined />} loading={saving} onClick={handleSave}>
+          {t('settings.secrets.save')}
+        </Button>
+      </div>
+    </Modal>
+  );
+};
+
+export default SecretsVaultModal;
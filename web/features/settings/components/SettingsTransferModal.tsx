@@ -0,0 +1,95 @@
+import React from 'react';
+import { Modal, Input, Button, Space, Typography, message } from 'antd';
+import { CopyOutlined, ImportOutlined } from '@ant-design/icons';
+import { useTranslation } from 'react-i18next';
+import i18n, { type Language } from '@/i18n';
+import { useAppStore, useSettingsStore } from '@/stores';
+import { useThemeStore } from '@/stores/themeStore';
+import { exportSettings, importSettings } from '@/services';
+
+const { Text } = Typography;
+
+interface SettingsTransferModalProps {
+  open: boolean;
+  onClose: () => void;
+}
+
+const SettingsTransferModal: React.FC<SettingsTransferModalProps> = ({ open, onClose }) => {
+  const { t } = useTranslation();
+  const [exported, setExported] = React.useState('');
+  const [importText, setImportText] = React.useState('');
+  const [importing, setImporting] = React.useState(false);
+
+  React.useEffect(() => {
+    if (!open) return;
+    setImportText('');
+    exportSettings()
+      .then(setExported)
+      .catch((error) => message.error(String(error)));
+  }, [open]);
+
+  const handleCopy = async () => {
+    try {
+      await navigator.clipboard.writeText(exported);
+      message.success(t('settings.transfer.copied'));
+    } catch (error) {
+      message.error(String(error));
+    }
+  };
+
+  const handleImport = async () => {
+    setImporting(true);
+    try {
+      const settings = await importSettings(importText);
+
+      // Reload the stores that mirror the saved settings
+      useSettingsStore.setState({ isInitialized: false });
+      await useSettingsStore.getState().initSettings();
+      await useThemeStore.getState().initTheme();
+      const language = settings.language as Language;
+      useAppStore.setState({ language });
+      i18n.changeLanguage(language);
+
+      message.success(t('settings.transfer.imported'));
+      onClose();
+    } catch (error) {
+      message.error(String(error));
+    } finally {
+      setImporting(false);
+    }
+  };
+
+  return (
+    <Modal title={t('settings.transfer.title')} open={open} onCancel={onClose} footer={null} width={600}>
+      <Text type="secondary" style={{ fontSize: 12 }}>
+        {t('settings.transfer.hint')}
+      </Text>
+
+      <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center', marginTop: 16, marginBottom: 8 }}>
+        <Text strong>{t('settings.transfer.export')}</Text>
+        <Button size="small" icon={<CopyOutlined />} disabled={!exported} onClick={handleCopy}>
+          {t('settings.transfer.copy')}
+        </Button>
+      </div>
+      <Input.TextArea value={exported} readOnly autoSize={{ minRows: 4, maxRows: 8 }} style={{ fontFamily: 'monospace', fontSize: 12 }} />
+
+      <Text strong style={{ display: 'block', marginTop: 16, marginBottom: 8 }}>
+        {t('settings.transfer.import')}
+      </Text>
+      <Space direction="vertical" style={{ width: '100%' }}>
+        <Input.TextArea
+          value={importText}
+          placeholder={t('settings.transfer.importPlaceholder')}
+          onChange={(event) => setImportText(event.target.value)}
+          autoSize={{ minRows: 4, maxRows: 8 }}
+          style={{ fontFamily: 'monospace', fontSize: 12 }}
+        />
+        <Button type="primary" block icon={<ImportOutlined />} loading={importing} disabled={!importText.trim()} onClick={handleImport}>
+          {t('settings.transfer.import')}
+        </Button>
+      </Space>
+    </Modal>
+  );
+};
+
+export default SettingsTransferModal;
@@ -5,9 +5,10 @@
  */
 
 import React, { useState, useEffect, useCallback } from 'react';
-import { Modal, Form, Switch, Select, Button, List, Space, Typography, Alert, Spin, Tag, Modal as AntdModal, Tabs, Tooltip, Progress } from 'antd';
+import { Modal, Form, Switch, Select, Input, Button, List, Space, Typography, Alert, Spin, Tag, Modal as AntdModal, Tabs, Tooltip, Progress } from 'antd';
 import { CheckCircleOutlined, CloseCircleOutlined, ReloadOutlined, DeleteOutlined, EditOutlined, PlusOutlined, ClearOutlined, CodeOutlined, FolderOpenOutlined } from '@ant-design/icons';
 import { useTranslation } from 'react-i18next';
+import { listen } from '@tauri-apps/api/event';
 import { useWSLSync } from '@/features/settings/hooks/useWSLSync';
 import { useSettingsStore } from '@/stores';
 import {
@@ -17,7 +18,7 @@ import {
 } from '@/features/settings/utils/syncMessageTranslator';
 import { FileMappingModal } from './FileMappingModal';
 import { wslDeleteFileMapping, wslResetFileMappings, wslOpenTerminal, wslOpenFolder, wslGetDistroState } from '@/services/wslSyncApi';
-import type { FileMapping, WslDirectModuleStatus } from '@/types/wslsync';
+import type { FileMapping, WSLDistroStateChanged, WslDirectModuleStatus } from '@/types/wslsync';
 
 const { Text } = Typography;
 
@@ -27,6 +28,7 @@ const MODULE_NAMES: Record<string, string> = {
   claude: 'Claude Code',
   codex: 'Codex',
   openclaw: 'OpenClaw',
+  cursor: 'Cursor',
 };
 
 // Module tag colors
@@ -35,6 +37,7 @@ const MODULE_COLORS: Record<string, string> = {
   claude: 'purple',
   codex: 'orange',
   openclaw: 'green',
+  cursor: 'cyan',
 };
 
 // Map sync module keys to visibleTabs keys
@@ -78,6 +81,9 @@ export const WSLSyncModal: React.FC<WSLSyncModalProps> = ({ open, onClose }) =>
   const [form] = Form.useForm();
   const [enabled, setEnabled] = useState(false);
   const [distro, setDistro] = useState('Ubuntu');
+  const [watchConfigFiles, setWatchConfigFiles] = useState(false);
+  const [autoStartDistro, setAutoStartDistro] = useState(false);
+  const [wslUser, setWslUser] = useState('');
   const [distros, setDistros] = useState<string[]>([]);
   const [distroStatus, setDistroStatus] = useState<'checking' | 'available' | 'unavailable'>('checking');
   const [distroState, setDistroState] = useState<'Running' | 'Stopped' | 'Unknown'>('Unknown');
@@ -160,6 +166,9 @@ export const WSLSyncModal: React.FC<WSLSyncModalProps> = ({ open, onClose }) =>
     if (config) {
       setEnabled(config.enabled);
       setDistro(config.distro);
+      setWatchConfigFiles(!!config.watchConfigFiles);
+      setAutoStartDistro(!!config.autoStartDistro);
+      setWslUser(config.wslUser ?? '');
       form.setFieldsValue({
         enabled: config.enabled,
         distro: config.distro,
@@ -213,6 +222,19 @@ export const WSLSyncModal: React.FC<WSLSyncModalProps> = ({ open, onClose }) =>
     }
   }, [open, distro, checkDistroAvailability]);
 
+  // Follow distro start/stop reported by the backend poller
+  useEffect(() => {
+    if (!open) return;
+    const unlisten = listen<WSLDistroStateChanged>('wsl-distro-state-changed', (event) => {
+      if (event.payload.distro === distro) {
+        setDistroState(event.payload.state);
+      }
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [open, distro]);
+
   // Handle enabled switch change - save immediately
   const handleEnabledChange = async (checked: boolean) => {
     if (!config) return;
@@ -228,6 +250,54 @@ export const WSLSyncModal: React.FC<WSLSyncModalProps> = ({ open, onClose }) =>
     }
   };
 
+  // Handle config file watcher switch change - save immediately
+  const handleWatchConfigFilesChange = async (checked: boolean) => {
+    if (!config) return;
+    setWatchConfigFiles(checked);
+    try {
+      await saveConfig({
+        ...config,
+        enabled,
+        distro,
+        watchConfigFiles: checked,
+      });
+    } catch (error) {
+      console.error('Failed to save watch config files state:', error);
+    }
+  };
+
+  // Handle auto-start switch change - save immediately
+  const handleAutoStartDistroChange = async (checked: boolean) => {
+    if (!config) return;
+    setAutoStartDistro(checked);
+    try {
+      await saveConfig({
+        ...config,
+        enabled,
+        distro,
+        autoStartDistro: checked,
+      });
+    } catch (error) {
+      console.error('Failed to save auto start distro state:', error);
+    }
+  };
+
+  // Handle WSL user change - save on blur
+  const handleWslUserSave = async () => {
+    if (!config || wslUser.trim() === (config.wslUser ?? '')) return;
+    try {
+      await saveConfig({
+        ...config,
+        enabled,
+        distro,
+        wslUser: wslUser.trim(),
+      });
+    } catch (error) {
+      console.error('Failed to save WSL user:', error);
+      setWslUser(config.wslUser ?? '');
+    }
+  };
+
   // Handle distro change - save immediately
   const handleDistroChange = async (value: string) => {
     if (!config) return;
@@ -470,6 +540,49 @@ export const WSLSyncModal: React.FC<WSLSyncModalProps> = ({ open, onClose }) =>
               </Select>
             </div>
 
+            {/* WSL user - left-right layout */}
+            <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center', marginBottom: 4 }}>
+              <Text>{t('settings.wsl.wslUser')}</Text>
+              <Input
+                value={wslUser}
+                onChange={(e) => setWslUser(e.target.value)}
+                onBlur={handleWslUserSave}
+                onPressEnter={handleWslUserSave}
+                placeholder={t('settings.wsl.wslUserPlaceholder')}
+                disabled={!enabled}
+                style={{ width: 200 }}
+              />
+            </div>
+            <Text type="secondary" style={{ fontSize: 12, marginBottom: 16, display: 'block' }}>
+              {t('settings.wsl.wslUserSubtitle')}
+            </Text>
+
+            {/* Auto-start distro - left-right layout */}
+            <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center', marginBottom: 4 }}>
+              <Text>{t('settings.wsl.autoStartDistro')}</Text>
+              <Switch
+                checked={autoStartDistro}
+                onChange={handleAutoStartDistroChange}
+                disabled={!enabled}
+              />
+            </div>
+            <Text type="secondary" style={{ fontSize: 12, marginBottom: 16, display: 'block' }}>
+              {t('settings.wsl.autoStartDistroSubtitle')}
+            </Text>
+
+            {/* Watch config files - left-right layout */}
+            <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center', marginBottom: 4 }}>
+              <Text>{t('settings.wsl.watchConfigFiles')}</Text>
+              <Switch
+                checked={watchConfigFiles}
+                onChange={handleWatchConfigFilesChange}
+                disabled={!enabled}
+              />
+            </div>
+            <Text type="secondary" style={{ fontSize: 12, marginBottom: 16, display: 'block' }}>
+              {t('settings.wsl.watchConfigFilesSubtitle')}
+            </Text>
+
             {/* Connection Status - left-right layout */}
             <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center', marginBottom: 16 }}>
               <Space>
@@ -1,3 +1,14 @@
+export { default as AppLockGate } from './AppLockGate';
+export { default as AppLockSettingsModal } from './AppLockSettingsModal';
+export { default as AuditLogModal } from './AuditLogModal';
 export { default as BackupSettingsModal } from './BackupSettingsModal';
+export { default as DatabaseIntegrityModal } from './DatabaseIntegrityModal';
+export { default as DeepLinkModal } from './DeepLinkModal';
+export { default as GatewayModal } from './GatewayModal';
+export { default as HotkeySettingsModal } from './HotkeySettingsModal';
+export { default as LogViewerModal } from './LogViewerModal';
+export { default as ProxyRulesModal } from './ProxyRulesModal';
 export { default as S3SettingsModal } from './S3SettingsModal';
+export { default as SecretsVaultModal } from './SecretsVaultModal';
+export { default as SettingsTransferModal } from './SettingsTransferModal';
 export { default as WebDAVRestoreModal } from './WebDAVRestoreModal';
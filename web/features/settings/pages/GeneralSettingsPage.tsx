@@ -16,7 +16,15 @@ import {
   BulbOutlined,
   EyeOutlined,
   HolderOutlined,
-  DragOutlined
+  DragOutlined,
+  FileZipOutlined,
+  RollbackOutlined,
+  LockOutlined,
+  KeyOutlined,
+  FileTextOutlined,
+  HistoryOutlined,
+  DatabaseOutlined,
+  SwapOutlined
 } from '@ant-design/icons';
 import { useTranslation } from 'react-i18next';
 import {
@@ -39,8 +47,21 @@ import { useAppStore, useSettingsStore } from '@/stores';
 import { useThemeStore, type ThemeMode } from '@/stores/themeStore';
 import { languages, type Language } from '@/i18n';
 import i18n from '@/i18n';
-import { BackupSettingsModal, WebDAVRestoreModal } from '../components';
+import {
+  AppLockSettingsModal,
+  AuditLogModal,
+  BackupSettingsModal,
+  DatabaseIntegrityModal,
+  GatewayModal,
+  HotkeySettingsModal,
+  LogViewerModal,
+  ProxyRulesModal,
+  SecretsVaultModal,
+  SettingsTransferModal,
+  WebDAVRestoreModal,
+} from '../components';
 import { platform } from '@tauri-apps/plugin-os';
+import { open as openFileDialog } from '@tauri-apps/plugin-dialog';
 import {
   backupDatabase,
   restoreDatabase,
@@ -48,6 +69,8 @@ import {
   backupToWebDAV,
   restoreFromWebDAV,
   type ProxyMode,
+  type UpdateChannel,
+  type UpdateCheckInterval,
   type RestoreResult,
   openAppDataDir,
   getAppVersion,
@@ -55,11 +78,14 @@ import {
   openGitHubPage,
   openExternalUrl,
   installUpdate,
-  testProxyConnection,
+  installUpdateFromFile,
+  rollbackUpdate,
+  getPreviousVersion,
+  testProxy,
   type UpdateInfo,
   GITHUB_REPO,
 } from '@/services';
-import { restartApp } from '@/services/settingsApi';
+import { restartApp, getAutoLaunchStatus } from '@/services/settingsApi';
 import { listen } from '@tauri-apps/api/event';
 import styles from './GeneralSettingsPage.module.less';
 
@@ -128,18 +154,25 @@ const GeneralSettingsPage: React.FC = () => {
     launchOnStartup,
     minimizeToTrayOnClose,
     startMinimized,
+    confirmBeforeQuit,
     setLaunchOnStartup,
     setMinimizeToTrayOnClose,
     setStartMinimized,
+    setConfirmBeforeQuit,
     proxyMode,
     setProxyMode,
     proxyUrl,
     setProxyUrl,
+    proxyRules,
     autoBackupEnabled,
     autoBackupIntervalDays,
     lastAutoBackupTime,
     autoCheckUpdate,
     setAutoCheckUpdate,
+    updateChannel,
+    setUpdateChannel,
+    updateCheckInterval,
+    setUpdateCheckInterval,
     visibleTabs,
     setVisibleTabs,
   } = useSettingsStore();
@@ -147,6 +180,13 @@ const GeneralSettingsPage: React.FC = () => {
   const isWindows = React.useMemo(() => platform() === 'windows', []);
 
   const [backupModalOpen, setBackupModalOpen] = React.useState(false);
+  const [hotkeyModalOpen, setHotkeyModalOpen] = React.useState(false);
+  const [appLockModalOpen, setAppLockModalOpen] = React.useState(false);
+  const [secretsModalOpen, setSecretsModalOpen] = React.useState(false);
+  const [logViewerOpen, setLogViewerOpen] = React.useState(false);
+  const [auditLogOpen, setAuditLogOpen] = React.useState(false);
+  const [dbIntegrityOpen, setDbIntegrityOpen] = React.useState(false);
+  const [transferModalOpen, setTransferModalOpen] = React.useState(false);
   const [webdavRestoreModalOpen, setWebdavRestoreModalOpen] = React.useState(false);
   const [backupLoading, setBackupLoading] = React.useState(false);
   const [restoreLoading, setRestoreLoading] = React.useState(false);
@@ -154,9 +194,12 @@ const GeneralSettingsPage: React.FC = () => {
   // Proxy settings states
   const [proxyInput, setProxyInput] = React.useState(proxyUrl);
   const [proxyTesting, setProxyTesting] = React.useState(false);
+  const [proxyRulesModalOpen, setProxyRulesModalOpen] = React.useState(false);
+  const [gatewayModalOpen, setGatewayModalOpen] = React.useState(false);
 
   // Version and update states
   const [appVersion, setAppVersion] = React.useState<string>('');
+  const [previousVersion, setPreviousVersion] = React.useState<string | null>(null);
   const [checkingUpdate, setCheckingUpdate] = React.useState(false);
   const [updateInfo, setUpdateInfo] = React.useState<UpdateInfo | null>(null);
   const [updateProgress, setUpdateProgress] = React.useState<number>(0);
@@ -165,12 +208,31 @@ const GeneralSettingsPage: React.FC = () => {
   const [updateDownloaded, setUpdateDownloaded] = React.useState<number>(0);
   const [updateTotal, setUpdateTotal] = React.useState<number>(0);
   const [updateModalOpen, setUpdateModalOpen] = React.useState(false);
+  // Whether the system autostart entry exists (it can be removed outside the app)
+  const [autoLaunchRegistered, setAutoLaunchRegistered] = React.useState<boolean | null>(null);
 
   // Load app version on mount
   React.useEffect(() => {
     getAppVersion().then(setAppVersion).catch(console.error);
+    getPreviousVersion().then(setPreviousVersion).catch(console.error);
   }, []);
 
+  React.useEffect(() => {
+    getAutoLaunchStatus().then(setAutoLaunchRegistered).catch(console.error);
+  }, [launchOnStartup]);
+
+  const handleLaunchOnStartupChange = async (checked: boolean) => {
+    try {
+      await setLaunchOnStartup(checked);
+      // Disable start minimized when launch on startup is disabled
+      if (!checked && startMinimized) {
+        await setStartMinimized(false);
+      }
+    } catch (error) {
+      message.error(String(error));
+    }
+  };
+
   // Auto check for updates on mount
   React.useEffect(() => {
     if (autoCheckUpdate) {
@@ -223,7 +285,8 @@ const GeneralSettingsPage: React.FC = () => {
     setProxyInput(proxyUrl);
   }, [proxyUrl]);
 
-  const isCustomProxyMode = proxyMode === 'custom';
+  // Direct mode keeps the proxy URL for rules that route some hosts through it
+  const showProxyUrl = proxyMode !== 'system';
 
   const handleCheckUpdate = async (silent = false) => {
     setCheckingUpdate(true);
@@ -310,6 +373,69 @@ const GeneralSettingsPage: React.FC = () => {
     }
   };
 
+  const handleInstallFromFile = async () => {
+    const currentPlatform = platform();
+    const extensions =
+      currentPlatform === 'windows' ? ['exe', 'msi'] : currentPlatform === 'macos' ? ['gz'] : ['AppImage'];
+    const selected = await openFileDialog({
+      multiple: false,
+      filters: [{ name: t('settings.about.updateFile'), extensions }],
+    });
+    if (!selected || Array.isArray(selected)) return;
+
+    await runManualInstall(() => installUpdateFromFile(selected));
+  };
+
+  const handleRollback = () => {
+    let version = previousVersion ?? '';
+    Modal.confirm({
+      title: t('settings.about.rollback'),
+      content: (
+        <div>
+          <p>{t('settings.about.rollbackHint')}</p>
+          <Input defaultValue={version} placeholder="0.7.0" onChange={(event) => (version = event.target.value)} />
+        </div>
+      ),
+      okText: t('settings.about.rollback'),
+      cancelText: t('common.cancel'),
+      onOk: () => {
+        if (version.trim()) {
+          runManualInstall(() => rollbackUpdate(version.trim()));
+        }
+      },
+    });
+  };
+
+  // Install flow shared by "install from file" and rollback
+  const runManualInstall = async (install: () => Promise<boolean>) => {
+    setUpdateModalOpen(true);
+    setUpdateProgress(0);
+    setUpdateStatus('started');
+    setUpdateSpeed(0);
+    setUpdateDownloaded(0);
+    setUpdateTotal(0);
+
+    try {
+      await install();
+      setUpdateModalOpen(false);
+      Modal.success({
+        title: t('settings.about.updateComplete'),
+        content: t('settings.about.updateCompleteRestart'),
+        okText: t('common.restart'),
+        onOk: () => {
+          restartApp();
+        },
+      });
+    } catch (error) {
+      setUpdateModalOpen(false);
+      Modal.error({
+        title: t('settings.about.updateFailed'),
+        content: String(error),
+        okText: t('common.close'),
+      });
+    }
+  };
+
   const handleLanguageChange = (value: Language) => {
     setLanguage(value);
     i18n.changeLanguage(value);
@@ -572,8 +698,37 @@ const GeneralSettingsPage: React.FC = () => {
 
     setProxyTesting(true);
     try {
-      await testProxyConnection(proxyInput);
-      message.success(t('settings.proxy.testSuccess'));
+      const results = await testProxy(proxyInput);
+      const allPassed = results.every((result) => result.success);
+      const content = (
+        <div style={{ display: 'flex', flexDirection: 'column', gap: 8 }}>
+          {results.map((result) => (
+            <div key={result.name}>
+              <Text strong>{result.name}</Text>{' '}
+              <Text type={result.success ? 'success' : 'danger'}>
+                {result.success ? t('settings.proxy.probeOk') : t('settings.proxy.probeFailed')}
+                {` · ${result.latency_ms} ms`}
+                {result.status !== null && ` · HTTP ${result.status}`}
+              </Text>
+              <div>
+                <Text type="secondary" style={{ fontSize: 12 }} ellipsis>
+                  {result.url}
+                </Text>
+              </div>
+              {result.error && (
+                <Text type="danger" style={{ fontSize: 12 }}>
+                  {result.error}
+                </Text>
+              )}
+            </div>
+          ))}
+        </div>
+      );
+      if (allPassed) {
+        Modal.success({ title: t('settings.proxy.testSuccess'), content });
+      } else {
+        Modal.error({ title: t('settings.proxy.testFailed'), content });
+      }
     } catch (error) {
       console.error('Proxy test failed:', error);
       message.error(t('settings.proxy.testFailed') + ': ' + String(error));
@@ -731,15 +886,14 @@ const GeneralSettingsPage: React.FC = () => {
                 <Text>{t('settings.window.launchOnStartup')}</Text>
                 <Switch
                   checked={launchOnStartup}
-                  onChange={(checked) => {
-                    setLaunchOnStartup(checked);
-                    // Disable start minimized when launch on startup is disabled
-                    if (!checked && startMinimized) {
-                      setStartMinimized(false);
-                    }
-                  }}
+                  onChange={handleLaunchOnStartupChange}
                 />
               </div>
+              {launchOnStartup && autoLaunchRegistered === false && (
+                <Text type="warning" style={{ fontSize: 12 }}>
+                  {t('settings.window.autostartMissing')}
+                </Text>
+              )}
               <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center' }}>
                 <Text>{t('settings.window.startMinimized')}</Text>
                 <Switch
@@ -755,6 +909,37 @@ const GeneralSettingsPage: React.FC = () => {
                   onChange={setMinimizeToTrayOnClose}
                 />
               </div>
+              <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center' }}>
+                <Text>{t('settings.window.confirmBeforeQuit')}</Text>
+                <Switch
+                  checked={confirmBeforeQuit}
+                  onChange={setConfirmBeforeQuit}
+                />
+              </div>
+              <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center' }}>
+                <Text>{t('settings.hotkeys.title')}</Text>
+                <Button size="small" icon={<EditOutlined />} onClick={() => setHotkeyModalOpen(true)}>
+                  {t('settings.hotkeys.configure')}
+                </Button>
+              </div>
+              <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center' }}>
+                <Text>{t('settings.appLock.title')}</Text>
+                <Button size="small" icon={<LockOutlined />} onClick={() => setAppLockModalOpen(true)}>
+                  {t('settings.appLock.configure')}
+                </Button>
+              </div>
+              <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center' }}>
+                <Text>{t('settings.secrets.title')}</Text>
+                <Button size="small" icon={<KeyOutlined />} onClick={() => setSecretsModalOpen(true)}>
+                  {t('settings.secrets.manage')}
+                </Button>
+              </div>
+              <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center' }}>
+                <Text>{t('settings.transfer.title')}</Text>
+                <Button size="small" icon={<SwapOutlined />} onClick={() => setTransferModalOpen(true)}>
+                  {t('settings.transfer.open')}
+                </Button>
+              </div>
             </div>
 
             <Divider />
@@ -773,6 +958,35 @@ const GeneralSettingsPage: React.FC = () => {
                   onChange={setAutoCheckUpdate}
                 />
               </div>
+              <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center' }}>
+                <Text>{t('settings.about.updateChannel')}</Text>
+                <Select
+                  value={updateChannel}
+                  onChange={(value: UpdateChannel) => {
+                    // Re-check right away so the result matches the new channel
+                    setUpdateChannel(value).then(() => handleCheckUpdate(true));
+                  }}
+                  options={[
+                    { value: 'stable', label: t('settings.about.channels.stable') },
+                    { value: 'beta', label: t('settings.about.channels.beta') },
+                    { value: 'nightly', label: t('settings.about.channels.nightly') },
+                  ]}
+                  style={{ width: 160 }}
+                />
+              </div>
+              <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center' }}>
+                <Text>{t('settings.about.updateCheckInterval')}</Text>
+                <Select
+                  value={updateCheckInterval}
+                  onChange={(value: UpdateCheckInterval) => setUpdateCheckInterval(value)}
+                  options={[
+                    { value: 'daily', label: t('settings.about.checkIntervals.daily') },
+                    { value: 'weekly', label: t('settings.about.checkIntervals.weekly') },
+                    { value: 'never', label: t('settings.about.checkIntervals.never') },
+                  ]}
+                  style={{ width: 160 }}
+                />
+              </div>
               <Space wrap>
                 <Button
                   icon={<SyncOutlined spin={checkingUpdate} />}
@@ -786,6 +1000,23 @@ const GeneralSettingsPage: React.FC = () => {
                     {t('settings.about.goToDownload')} (v{updateInfo.latestVersion})
                   </Button>
                 )}
+                <Button icon={<FileZipOutlined />} onClick={handleInstallFromFile}>
+                  {t('settings.about.installFromFile')}
+                </Button>
+                <Button icon={<RollbackOutlined />} onClick={handleRollback}>
+                  {previousVersion
+                    ? t('settings.about.rollbackTo', { version: previousVersion })
+                    : t('settings.about.rollback')}
+                </Button>
+                <Button icon={<FileTextOutlined />} onClick={() => setLogViewerOpen(true)}>
+                  {t('settings.logs.open')}
+                </Button>
+                <Button icon={<HistoryOutlined />} onClick={() => setAuditLogOpen(true)}>
+                  {t('settings.auditLog.open')}
+                </Button>
+                <Button icon={<DatabaseOutlined />} onClick={() => setDbIntegrityOpen(true)}>
+                  {t('settings.dbIntegrity.open')}
+                </Button>
                 <Button icon={<GithubOutlined />} onClick={handleOpenGitHub}>
                   {t('settings.about.github')}
                 </Button>
@@ -881,7 +1112,7 @@ const GeneralSettingsPage: React.FC = () => {
                   ]}
                 />
               </div>
-              {isCustomProxyMode ? (
+              {showProxyUrl ? (
                 <>
                   <div style={{ display: 'flex', gap: 8 }}>
                     <Input
@@ -903,8 +1134,25 @@ const GeneralSettingsPage: React.FC = () => {
                   <Text type="secondary" style={{ fontSize: 12 }}>
                     {t('settings.proxy.hint')}
                   </Text>
+                  <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center' }}>
+                    <Text>
+                      {t('settings.proxy.rulesTitle')}
+                      {proxyRules.length > 0 && (
+                        <Text type="secondary"> ({t('settings.proxy.rulesCount', { count: proxyRules.length })})</Text>
+                      )}
+                    </Text>
+                    <Button size="small" icon={<EditOutlined />} onClick={() => setProxyRulesModalOpen(true)}>
+                      {t('common.edit')}
+                    </Button>
+                  </div>
                 </>
               ) : null}
+              <div style={{ display: 'flex', justifyContent: 'space-between', alignItems: 'center' }}>
+                <Text>{t('settings.gateway.open')}</Text>
+                <Button size="small" icon={<EditOutlined />} onClick={() => setGatewayModalOpen(true)}>
+                  {t('common.edit')}
+                </Button>
+              </div>
             </div>
 
             <Divider />
@@ -962,6 +1210,15 @@ const GeneralSettingsPage: React.FC = () => {
 
       {/* Modals */}
       <BackupSettingsModal open={backupModalOpen} onClose={() => setBackupModalOpen(false)} />
+      <HotkeySettingsModal open={hotkeyModalOpen} onClose={() => setHotkeyModalOpen(false)} />
+      <AppLockSettingsModal open={appLockModalOpen} onClose={() => setAppLockModalOpen(false)} />
+      <SecretsVaultModal open={secretsModalOpen} onClose={() => setSecretsModalOpen(false)} />
+      <LogViewerModal open={logViewerOpen} onClose={() => setLogViewerOpen(false)} />
+      <AuditLogModal open={auditLogOpen} onClose={() => setAuditLogOpen(false)} />
+      <DatabaseIntegrityModal open={dbIntegrityOpen} onClose={() => setDbIntegrityOpen(false)} />
+      <SettingsTransferModal open={transferModalOpen} onClose={() => setTransferModalOpen(false)} />
+      <ProxyRulesModal open={proxyRulesModalOpen} onClose={() => setProxyRulesModalOpen(false)} />
+      <GatewayModal open={gatewayModalOpen} onClose={() => setGatewayModalOpen(false)} />
       <WebDAVRestoreModal
         open={webdavRestoreModalOpen}
         onClose={() => setWebdavRestoreModalOpen(false)}
@@ -3,10 +3,12 @@ import { initReactI18next } from 'react-i18next';
 
 import zhCN from './locales/zh-CN.json';
 import enUS from './locales/en-US.json';
+import jaJP from './locales/ja-JP.json';
 
 export const resources = {
   'zh-CN': { translation: zhCN },
   'en-US': { translation: enUS },
+  'ja-JP': { translation: jaJP },
 } as const;
 
 export type Language = keyof typeof resources;
@@ -14,12 +16,14 @@ export type Language = keyof typeof resources;
 export const languages: { value: Language; label: string }[] = [
   { value: 'zh-CN', label: '简体中文' },
   { value: 'en-US', label: 'English' },
+  { value: 'ja-JP', label: '日本語' },
 ];
 
 i18n.use(initReactI18next).init({
   resources,
   lng: 'zh-CN',
-  fallbackLng: 'zh-CN',
+  // The Japanese catalog only covers the shell so far; the rest falls back to English
+  fallbackLng: { 'ja-JP': ['en-US'], default: ['zh-CN'] },
   interpolation: {
     escapeValue: false,
   },
@@ -1,12 +1,14 @@
 import React from 'react';
 import ReactDOM from 'react-dom/client';
+import { getCurrentWindow } from '@tauri-apps/api/window';
 import App from './app/App';
+import QuickSwitchWindow from '@/features/settings/components/QuickSwitchWindow';
 import './app/monaco';
 import '@/i18n';
 import './App.css';
 
 ReactDOM.createRoot(document.getElementById('root') as HTMLElement).render(
   <React.StrictMode>
-    <App />
+    {getCurrentWindow().label === 'quick-switch' ? <QuickSwitchWindow /> : <App />}
   </React.StrictMode>
 );
@@ -0,0 +1,75 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/** What applying a provider writes to `~/.aider.conf.yml` and `~/.env` */
+export interface AiderProviderSettings {
+  /** Main model, e.g. `openai/gpt-4.1` or `anthropic/claude-sonnet-4-5` */
+  model: string;
+  weakModel?: string;
+  editorModel?: string;
+  /** Environment variable the key is written to, defaults to `OPENAI_API_KEY` */
+  apiKeyEnv?: string;
+  /** Left out of `.env` when empty */
+  apiKey?: string;
+  /** OpenAI compatible endpoint, written as `OPENAI_API_BASE` */
+  apiBase?: string;
+}
+
+export interface AiderProvider {
+  id: string;
+  name: string;
+  settings: AiderProviderSettings;
+  notes?: string;
+  sortIndex?: number;
+  isApplied: boolean;
+  createdAt: string | null;
+  updatedAt: string | null;
+}
+
+export interface AiderProviderInput {
+  id?: string;
+  name: string;
+  settings: AiderProviderSettings;
+  notes?: string;
+}
+
+export interface AiderPaths {
+  configPath: string;
+  envPath: string;
+  configExists: boolean;
+}
+
+export const getAiderPaths = async (): Promise<AiderPaths> => {
+  return await invoke<AiderPaths>('get_aider_paths');
+};
+
+export const listAiderProviders = async (): Promise<AiderProvider[]> => {
+  return await invoke<AiderProvider[]>('list_aider_providers');
+};
+
+export const createAiderProvider = async (provider: AiderProviderInput): Promise<AiderProvider> => {
+  return await invoke<AiderProvider>('create_aider_provider', { provider });
+};
+
+export const updateAiderProvider = async (provider: AiderProviderInput): Promise<AiderProvider> => {
+  return await invoke<AiderProvider>('update_aider_provider', { provider });
+};
+
+export const deleteAiderProvider = async (id: string): Promise<void> => {
+  await invoke('delete_aider_provider', { id });
+};
+
+export const reorderAiderProviders = async (ids: string[]): Promise<void> => {
+  await invoke('reorder_aider_providers', { ids });
+};
+
+export const applyAiderProvider = async (providerId: string): Promise<void> => {
+  await invoke('apply_aider_provider', { providerId });
+};
+
+export const getAiderShowInTray = async (): Promise<boolean> => {
+  return await invoke<boolean>('get_aider_show_in_tray');
+};
+
+export const setAiderShowInTray = async (enabled: boolean): Promise<void> => {
+  await invoke('set_aider_show_in_tray', { enabled });
+};
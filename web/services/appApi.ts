@@ -7,8 +7,11 @@
 import { getVersion } from '@tauri-apps/api/app';
 import { openUrl as openUrlExternal } from '@tauri-apps/plugin-opener';
 import { invoke } from '@tauri-apps/api/core';
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
 import { PRESET_MODELS_REMOTE_URL, updatePresetModels } from '@/constants/presetModels';
 import type { PresetModel } from '@/constants/presetModels';
+import type { ClaudeProviderInput } from '@/types/claudecode';
+import type { CodexProviderInput } from '@/types/codex';
 
 const GITHUB_REPO = 'coulsontl/ai-toolbox';
 export { GITHUB_REPO };
@@ -22,9 +25,10 @@ export interface UpdateInfo {
   releaseNotes: string;
   signature?: string;
   url?: string;
+  skipped: boolean;
 }
 
-interface UpdateCheckResult {
+export interface UpdateCheckResult {
   has_update: boolean;
   current_version: string;
   latest_version: string;
@@ -32,6 +36,7 @@ interface UpdateCheckResult {
   release_notes: string;
   signature?: string;
   url?: string;
+  skipped?: boolean;
 }
 
 /**
@@ -45,8 +50,13 @@ export const getAppVersion = async (): Promise<string> => {
  * Check for updates from GitHub releases (via Tauri backend)
  */
 export const checkForUpdates = async (): Promise<UpdateInfo> => {
-  const result = await invoke<UpdateCheckResult>('check_for_updates');
+  return toUpdateInfo(await invoke<UpdateCheckResult>('check_for_updates'));
+};
 
+/**
+ * Convert a backend update check result (also the `update-available` event payload)
+ */
+export const toUpdateInfo = (result: UpdateCheckResult): UpdateInfo => {
   return {
     hasUpdate: result.has_update,
     currentVersion: result.current_version,
@@ -55,9 +65,39 @@ export const checkForUpdates = async (): Promise<UpdateInfo> => {
     releaseNotes: result.release_notes,
     signature: result.signature,
     url: result.url,
+    skipped: result.skipped ?? false,
   };
 };
 
+/**
+ * Install an update from a local artifact (offline machines)
+ * The matching `.sig` file is expected next to it
+ */
+export const installUpdateFromFile = async (path: string): Promise<boolean> => {
+  return await invoke('install_update_from_file', { path });
+};
+
+/**
+ * Install a specific (usually older) release
+ */
+export const rollbackUpdate = async (version: string): Promise<boolean> => {
+  return await invoke('rollback_update', { version });
+};
+
+/**
+ * Version that ran before the current one, if any
+ */
+export const getPreviousVersion = async (): Promise<string | null> => {
+  return await invoke('get_previous_version');
+};
+
+/**
+ * Stop notifying about this version
+ */
+export const skipUpdateVersion = async (version: string): Promise<void> => {
+  await invoke('skip_update_version', { version });
+};
+
 /**
  * Install the update if available
  */
@@ -79,6 +119,273 @@ export const openExternalUrl = async (url: string): Promise<void> => {
   await openUrlExternal(url);
 };
 
+export type LogLevel = 'error' | 'warn' | 'info' | 'debug';
+
+export interface LogEntry {
+  timestamp: string;
+  level: string;
+  target: string;
+  message: string;
+}
+
+/**
+ * Get the most recent log records (at `level` or more severe) for the log viewer
+ */
+export const getRecentLogs = async (level: LogLevel, limit?: number): Promise<LogEntry[]> => {
+  return await invoke<LogEntry[]>('get_recent_logs', { level, limit });
+};
+
+/**
+ * Export logs, sanitized settings and tool config paths as a zip for bug reports
+ */
+export const exportDiagnosticsBundle = async (targetPath: string): Promise<string> => {
+  return await invoke<string>('export_diagnostics_bundle', { targetPath });
+};
+
+export interface AuditLogEntry {
+  timestamp: string;
+  command: string;
+  /** Redacted, summarized JSON of the command arguments */
+  payload: string;
+  /** Window label the command came from, or "tray" */
+  source: string;
+}
+
+export interface AuditLogFilter {
+  /** Substring of the command name */
+  command?: string;
+  /** RFC 3339 time; only newer entries are returned */
+  since?: string;
+  limit?: number;
+}
+
+/**
+ * Query the audit log of mutating commands, newest first
+ */
+export const getAuditLog = async (filter?: AuditLogFilter): Promise<AuditLogEntry[]> => {
+  return await invoke<AuditLogEntry[]>('get_audit_log', { filter });
+};
+
+export type IntegrityIssueKind = 'duplicateRecord' | 'danglingSourceProvider' | 'staleSkillTarget';
+
+export interface IntegrityIssue {
+  kind: IntegrityIssueKind;
+  table: string;
+  recordId: string;
+  detail: string;
+  /** Whether the issue was fixed by this run */
+  repaired: boolean;
+}
+
+export interface IntegrityReport {
+  issues: IntegrityIssue[];
+  repairedCount: number;
+}
+
+/**
+ * Find duplicate records, dangling provider references and stale skill targets
+ */
+export const checkDatabaseIntegrity = async (): Promise<IntegrityReport> => {
+  return await invoke<IntegrityReport>('check_database_integrity');
+};
+
+/**
+ * Same checks as `checkDatabaseIntegrity`, fixing or removing the affected records
+ */
+export const repairDatabaseIntegrity = async (): Promise<IntegrityReport> => {
+  return await invoke<IntegrityReport>('repair_database_integrity');
+};
+
+export type DumpFormat = 'json' | 'surql';
+
+/**
+ * Dump every table to a JSON file or a SurrealQL script; returns the written path
+ */
+export const dumpDatabase = async (format: DumpFormat, targetPath: string): Promise<string> => {
+  return await invoke<string>('dump_database', { format, targetPath });
+};
+
+export interface GatewayStatus {
+  running: boolean;
+  /** OpenAI base URL clients should use, e.g. `http://127.0.0.1:11435/v1` */
+  baseUrl?: string;
+  /** Last start failure, such as the port being in use */
+  error?: string;
+}
+
+/**
+ * Get whether the local gateway is serving and where
+ */
+export const getGatewayStatus = async (): Promise<GatewayStatus> => {
+  return await invoke<GatewayStatus>('get_gateway_status');
+};
+
+export interface PlaygroundMessage {
+  role: 'system' | 'user' | 'assistant';
+  content: string;
+}
+
+export interface PlaygroundReply {
+  content: string;
+  /** Milliseconds until the first text arrived */
+  firstTokenMs?: number;
+  totalMs: number;
+  /** Token usage as reported by the provider */
+  usage?: Record<string, unknown>;
+}
+
+/**
+ * Send a conversation to a favorite provider's model and wait for the full reply.
+ * Text is streamed as `playground-delta` events tagged with `requestId` meanwhile.
+ */
+export const playgroundSend = async (
+  providerId: string,
+  modelId: string,
+  messages: PlaygroundMessage[],
+  requestId?: string,
+): Promise<PlaygroundReply> => {
+  return await invoke<PlaygroundReply>('playground_send', { providerId, modelId, messages, requestId });
+};
+
+/**
+ * Listen for streamed playground text of one request
+ */
+export const listenPlaygroundDeltas = async (
+  requestId: string,
+  handler: (text: string) => void,
+): Promise<UnlistenFn> => {
+  return await listen<{ requestId?: string; text: string }>('playground-delta', (event) => {
+    if (event.payload.requestId === requestId) {
+      handler(event.payload.text);
+    }
+  });
+};
+
+export interface TokenCount {
+  tokens: number;
+  encoding: 'o200k_base' | 'cl100k_base';
+  /** True when the encoding only approximates the model's tokenizer (e.g. Claude) */
+  approximate: boolean;
+}
+
+/**
+ * Count the tokens of a text for a model id (optionally `provider/model`)
+ */
+export const countTokens = async (model: string, text: string): Promise<TokenCount> => {
+  return await invoke<TokenCount>('count_tokens', { model, text });
+};
+
+export type DoctorStatus = 'ok' | 'warning' | 'error';
+
+export interface DoctorCheck {
+  /** Stable identifier such as `binary.git` or `config.codex` */
+  id: string;
+  category: 'binary' | 'tool' | 'config' | 'network';
+  status: DoctorStatus;
+  message: string;
+  /** Suggested fix for a warning or error */
+  fix?: string;
+}
+
+export interface DoctorReport {
+  checks: DoctorCheck[];
+  warnings: number;
+  errors: number;
+  generated_at: string;
+}
+
+/**
+ * Check binaries, tool detection, config files and the proxy; returns every result
+ */
+export const runDoctor = async (): Promise<DoctorReport> => {
+  return await invoke<DoctorReport>('run_doctor');
+};
+
+export type DbChangeAction = 'create' | 'update' | 'delete';
+
+/** Payload of the `db-changed` event emitted for every record change */
+export interface DbChangeEvent {
+  table: string;
+  /** Clean record id, without the table prefix */
+  id: string;
+  action: DbChangeAction;
+}
+
+/**
+ * Listen for record changes in the given tables.
+ * Changes arriving within `debounceMs` of each other are delivered as one batch,
+ * so bulk operations (reorders, syncs) trigger a single refresh.
+ */
+export const listenDbChanges = async (
+  tables: string[],
+  handler: (changes: DbChangeEvent[]) => void,
+  debounceMs = 200,
+): Promise<UnlistenFn> => {
+  let pending: DbChangeEvent[] = [];
+  let timer: ReturnType<typeof setTimeout> | undefined;
+
+  const unlisten = await listen<DbChangeEvent>('db-changed', (event) => {
+    if (!tables.includes(event.payload.table)) return;
+    pending.push(event.payload);
+    clearTimeout(timer);
+    timer = setTimeout(() => {
+      const changes = pending;
+      pending = [];
+      handler(changes);
+    }, debounceMs);
+  });
+
+  return () => {
+    clearTimeout(timer);
+    unlisten();
+  };
+};
+
+export type SearchHitKind =
+  | 'opencodeProvider'
+  | 'opencodeModel'
+  | 'claudeProvider'
+  | 'codexProvider'
+  | 'mcpServer'
+  | 'skill';
+
+export interface SearchHit {
+  kind: SearchHitKind;
+  /** Id used by the owning page (provider id for OpenCode, model id for models) */
+  id: string;
+  title: string;
+  subtitle?: string;
+  /** OpenCode provider a model belongs to */
+  parentId?: string;
+  score: number;
+}
+
+/**
+ * Search providers, models, MCP servers and skills in one call.
+ * Hits are grouped by kind and ranked within each group.
+ */
+export const searchAll = async (query: string, limit?: number): Promise<SearchHit[]> => {
+  return await invoke<SearchHit[]>('search_all', { query, limit });
+};
+
+/** Provider shared through an `ai-toolbox://provider/import` link */
+export type SharedProvider =
+  | { tool: 'claudecode'; provider: ClaudeProviderInput }
+  | { tool: 'codex'; provider: CodexProviderInput };
+
+/** A parsed `ai-toolbox://` link waiting for confirmation */
+export type DeepLinkRequest =
+  | { action: 'skillInstall'; repoUrl: string; branch?: string | null }
+  | { action: 'providerImport'; provider: SharedProvider }
+  | { action: 'invalid'; url: string; error: string };
+
+/**
+ * Take the deep links received so far; new ones are announced with the `deep-link` event
+ */
+export const takePendingDeepLinks = async (): Promise<DeepLinkRequest[]> => {
+  return await invoke<DeepLinkRequest[]>('take_pending_deep_links');
+};
+
 /**
  * Refresh the system tray menu
  */
@@ -19,6 +19,12 @@ import type {
   CodexPluginRuntimeStatus,
   CodexPluginWorkspaceRoot,
   CodexPluginWorkspaceRootInput,
+  CodexAuthStatus,
+  CodexRuntimeSettings,
+  CodexNotifyStatus,
+  CodexNotifyConfigInput,
+  CodexConfigPreview,
+  CodexImportResult,
 } from '@/types/codex';
 import type { OpenCodeAllApiHubProvider, OpenCodeAllApiHubProvidersResult } from '@/services/opencodeApi';
 
@@ -99,6 +105,87 @@ export const revealCodexConfigFolder = async (): Promise<void> => {
   await invoke('reveal_codex_config_folder');
 };
 
+/**
+ * Get Codex auth status (ChatGPT login vs API key, token expiry, effective auth mode)
+ */
+export const getCodexAuthStatus = async (): Promise<CodexAuthStatus> => {
+  return await invoke<CodexAuthStatus>('get_codex_auth_status');
+};
+
+/**
+ * Log out of the ChatGPT account used by Codex (keeps the managed API key)
+ */
+export const codexLogout = async (): Promise<CodexAuthStatus> => {
+  return await invoke<CodexAuthStatus>('codex_logout');
+};
+
+/**
+ * Get reasoning / approval / sandbox settings of a provider
+ * @param profile Read from [profiles.<profile>] instead of the top level
+ */
+export const getCodexProviderRuntimeSettings = async (
+  providerId: string,
+  profile?: string
+): Promise<CodexRuntimeSettings> => {
+  return await invoke<CodexRuntimeSettings>('get_codex_provider_runtime_settings', {
+    providerId,
+    profile,
+  });
+};
+
+/**
+ * Update reasoning / approval / sandbox settings of a provider (unset keys are removed)
+ * @param profile Write to [profiles.<profile>] instead of the top level
+ */
+export const updateCodexProviderRuntimeSettings = async (
+  providerId: string,
+  settings: CodexRuntimeSettings,
+  profile?: string
+): Promise<CodexProvider> => {
+  return await invoke<CodexProvider>('update_codex_provider_runtime_settings', {
+    providerId,
+    profile,
+    settings,
+  });
+};
+
+/**
+ * Get Codex notify program and TUI notification settings
+ */
+export const getCodexNotifyStatus = async (): Promise<CodexNotifyStatus> => {
+  return await invoke<CodexNotifyStatus>('get_codex_notify_status');
+};
+
+/**
+ * Save Codex notify program and TUI notification settings
+ */
+export const saveCodexNotifyConfig = async (
+  input: CodexNotifyConfigInput
+): Promise<CodexNotifyStatus> => {
+  return await invoke<CodexNotifyStatus>('save_codex_notify_config', { input });
+};
+
+/**
+ * Install the bundled notify script and enable it for Codex
+ */
+export const enableCodexBundledNotify = async (): Promise<CodexNotifyStatus> => {
+  return await invoke<CodexNotifyStatus>('enable_codex_bundled_notify');
+};
+
+/**
+ * Preview the config.toml diff and validation issues of applying a provider
+ */
+export const previewCodexConfig = async (providerId: string): Promise<CodexConfigPreview> => {
+  return await invoke<CodexConfigPreview>('preview_codex_config', { providerId });
+};
+
+/**
+ * Import model_providers entries and profiles from config.toml as managed providers
+ */
+export const importCodexConfigAsProviders = async (): Promise<CodexImportResult> => {
+  return await invoke<CodexImportResult>('import_codex_config_as_providers');
+};
+
 /**
  * List all Codex providers
  */
@@ -0,0 +1,24 @@
+import { invoke } from '@tauri-apps/api/core';
+
+export interface CopilotCliPaths {
+  configDir: string;
+  /** Settings file holding the selected model */
+  configPath: string;
+  mcpConfigPath: string;
+  skillsDir: string;
+  installed: boolean;
+}
+
+export const getCopilotCliPaths = async (): Promise<CopilotCliPaths> => {
+  return await invoke<CopilotCliPaths>('get_copilot_cli_paths');
+};
+
+/** The model the CLI starts with, or null for its default */
+export const getCopilotCliModel = async (): Promise<string | null> => {
+  return await invoke<string | null>('get_copilot_cli_model');
+};
+
+/** An empty model restores the CLI default */
+export const setCopilotCliModel = async (model?: string): Promise<void> => {
+  await invoke('set_copilot_cli_model', { model: model ?? null });
+};
@@ -0,0 +1,46 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/** A `.mdc` rule in the Cursor rules directory */
+export interface CursorRule {
+  /** File name without the `.mdc` extension */
+  name: string;
+  description?: string;
+  /** Comma separated patterns of the files the rule is attached to */
+  globs?: string;
+  alwaysApply: boolean;
+  content: string;
+}
+
+export interface CursorRuleInput {
+  name: string;
+  /** Current name when renaming an existing rule */
+  originalName?: string;
+  description?: string;
+  globs?: string;
+  alwaysApply?: boolean;
+  content: string;
+}
+
+export interface CursorPaths {
+  configDir: string;
+  mcpConfigPath: string;
+  skillsDir: string;
+  rulesDir: string;
+  installed: boolean;
+}
+
+export const getCursorPaths = async (): Promise<CursorPaths> => {
+  return await invoke<CursorPaths>('get_cursor_paths');
+};
+
+export const listCursorRules = async (): Promise<CursorRule[]> => {
+  return await invoke<CursorRule[]>('list_cursor_rules');
+};
+
+export const saveCursorRule = async (input: CursorRuleInput): Promise<CursorRule> => {
+  return await invoke<CursorRule>('save_cursor_rule', { input });
+};
+
+export const deleteCursorRule = async (name: string): Promise<void> => {
+  await invoke('delete_cursor_rule', { name });
+};
@@ -12,6 +12,11 @@ export * from './globalPromptApi';
 export * from './openCodePromptApi';
 export * from './claudeCodePromptApi';
 export * from './codexPromptApi';
+export * from './promptLibraryApi';
+export * from './usageApi';
+export * from './cursorApi';
+export * from './copilotCliApi';
+export * from './aiderApi';
 export * from './appApi';
 export * from './ohMyOpenAgentApi';
 export * from '../features/coding/shared/sessionManager/sessionManagerApi';
@@ -283,7 +283,8 @@ export const getOpenCodeAuthConfigPath = async (): Promise<string> => {
 export interface OpenCodeFavoritePlugin {
   id: string;
   pluginName: string;
-  createdAt: string;
+  /** Null when the stored timestamp could not be read */
+  createdAt: string | null;
 }
 
 /**
@@ -327,8 +328,9 @@ export interface OpenCodeFavoriteProvider {
   providerConfig: OpenCodeProvider;
   /** Last used diagnostics configuration */
   diagnostics?: OpenCodeDiagnosticsConfig;
-  createdAt: string;
-  updatedAt: string;
+  /** Null when the stored timestamp could not be read */
+  createdAt: string | null;
+  updatedAt: string | null;
 }
 
 export interface OpenCodeDiagnosticsConfig {
@@ -463,3 +465,122 @@ export const testProviderModelConnectivity = async (
 ): Promise<ConnectivityTestResponse> => {
   return await invoke<ConnectivityTestResponse>('test_provider_model_connectivity', { request });
 };
+
+// ============================================================================
+// Launcher Functions
+// ============================================================================
+
+/**
+ * Launch an OpenCode session in the system terminal
+ * @param projectPath Working directory for the session (defaults to home directory)
+ * @param modelOverride Model in "provider_id/model_id" format passed via --model
+ */
+export const launchOpenCode = async (
+  projectPath?: string,
+  modelOverride?: string
+): Promise<void> => {
+  await invoke('launch_opencode', { projectPath, modelOverride });
+};
+
+// ============================================================================
+// Update Check Types and Functions
+// ============================================================================
+
+export interface OpenCodeUpdateCheckResult {
+  installed: boolean;
+  hasUpdate: boolean;
+  currentVersion?: string;
+  latestVersion: string;
+  releaseUrl: string;
+}
+
+/**
+ * Compare the installed opencode binary against the latest published release
+ */
+export const checkOpenCodeUpdate = async (): Promise<OpenCodeUpdateCheckResult> => {
+  return await invoke<OpenCodeUpdateCheckResult>('check_opencode_update');
+};
+
+// ============================================================================
+// Model Variant Types and Functions
+// ============================================================================
+
+export interface OpenCodeThinkingVariant {
+  type?: 'enabled' | 'disabled';
+  budgetTokens?: number;
+}
+
+export interface OpenCodeThinkingConfigVariant {
+  includeThoughts?: boolean;
+  thinkingLevel?: 'minimal' | 'low' | 'medium' | 'high';
+  thinkingBudget?: number;
+}
+
+/**
+ * Typed model variant; unknown provider-specific keys are preserved as extra fields
+ */
+export interface OpenCodeTypedModelVariant {
+  reasoningEffort?: 'none' | 'minimal' | 'low' | 'medium' | 'high' | 'xhigh';
+  reasoningSummary?: 'auto' | 'concise' | 'detailed' | 'none';
+  textVerbosity?: 'low' | 'medium' | 'high';
+  effort?: 'low' | 'medium' | 'high' | 'max';
+  thinking?: OpenCodeThinkingVariant;
+  thinkingConfig?: OpenCodeThinkingConfigVariant;
+  disabled?: boolean;
+  [key: string]: unknown;
+}
+
+/**
+ * Get typed variants of a model
+ */
+export const getOpenCodeModelVariants = async (
+  providerId: string,
+  modelId: string
+): Promise<Record<string, OpenCodeTypedModelVariant>> => {
+  return await invoke<Record<string, OpenCodeTypedModelVariant>>('get_opencode_model_variants', {
+    providerId,
+    modelId,
+  });
+};
+
+/**
+ * Validate a variant without saving it
+ * @returns List of validation errors (empty when valid)
+ */
+export const validateOpenCodeModelVariant = async (
+  variantName: string,
+  variant: OpenCodeTypedModelVariant
+): Promise<string[]> => {
+  return await invoke<string[]>('validate_opencode_model_variant', { variantName, variant });
+};
+
+/**
+ * Create or replace a model variant
+ * @param previousName Original variant name when renaming
+ */
+export const upsertOpenCodeModelVariant = async (
+  providerId: string,
+  modelId: string,
+  variantName: string,
+  variant: OpenCodeTypedModelVariant,
+  previousName?: string
+): Promise<void> => {
+  await invoke('upsert_opencode_model_variant', {
+    providerId,
+    modelId,
+    variantName,
+    variant,
+    previousName,
+  });
+};
+
+/**
+ * Delete a model variant
+ */
+export const deleteOpenCodeModelVariant = async (
+  providerId: string,
+  modelId: string,
+  variantName: string
+): Promise<void> => {
+  await invoke('delete_opencode_model_variant', { providerId, modelId, variantName });
+};
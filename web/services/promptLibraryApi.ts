@@ -0,0 +1,78 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/** A `{{name}}` placeholder of a library prompt */
+export interface PromptVariable {
+  name: string;
+  description?: string;
+  /** Used when rendering without a value for this variable */
+  default?: string;
+}
+
+export interface PromptLibraryItem {
+  id: string;
+  name: string;
+  description?: string;
+  content: string;
+  tags: string[];
+  variables: PromptVariable[];
+  sortIndex?: number;
+  /** Epoch milliseconds */
+  createdAt: number;
+  updatedAt: number;
+}
+
+export interface PromptLibraryItemInput {
+  id?: string;
+  name: string;
+  description?: string;
+  content: string;
+  tags?: string[];
+  /** Placeholders found in the content are added on save */
+  variables?: PromptVariable[];
+}
+
+export type PromptExportTarget = 'claude_commands' | 'opencode_commands' | 'markdown';
+
+export interface PromptExportResult {
+  directory: string;
+  written: string[];
+}
+
+export const listPromptLibraryItems = async (tag?: string): Promise<PromptLibraryItem[]> => {
+  return await invoke<PromptLibraryItem[]>('list_prompt_library_items', { tag });
+};
+
+export const createPromptLibraryItem = async (input: PromptLibraryItemInput): Promise<PromptLibraryItem> => {
+  return await invoke<PromptLibraryItem>('create_prompt_library_item', { input });
+};
+
+export const updatePromptLibraryItem = async (input: PromptLibraryItemInput): Promise<PromptLibraryItem> => {
+  return await invoke<PromptLibraryItem>('update_prompt_library_item', { input });
+};
+
+export const deletePromptLibraryItem = async (id: string): Promise<void> => {
+  await invoke('delete_prompt_library_item', { id });
+};
+
+export const reorderPromptLibraryItems = async (ids: string[]): Promise<void> => {
+  await invoke('reorder_prompt_library_items', { ids });
+};
+
+/**
+ * Fill a prompt's placeholders; unset variables fall back to their defaults
+ */
+export const renderPromptLibraryItem = async (id: string, values: Record<string, string>): Promise<string> => {
+  return await invoke<string>('render_prompt_library_item', { id, values });
+};
+
+/**
+ * Write prompts as Claude Code / OpenCode commands or markdown files.
+ * Commands default to the tool's commands folder when no directory is given.
+ */
+export const exportPromptLibraryItems = async (
+  ids: string[],
+  target: PromptExportTarget,
+  directory?: string,
+): Promise<PromptExportResult> => {
+  return await invoke<PromptExportResult>('export_prompt_library_items', { ids, target, directory });
+};
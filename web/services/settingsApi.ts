@@ -34,6 +34,19 @@ export type SidebarHiddenByPage = Record<SidebarPageKey, boolean>;
 
 export type ProxyMode = 'direct' | 'custom' | 'system';
 
+export type ProxyRuleAction = 'proxy' | 'direct';
+
+/** Per-destination proxy rule; the first rule matching a request's host wins */
+export interface ProxyRule {
+  /** e.g. github.com, *.example.com, 192.168.0.0/16, <local>, * */
+  pattern: string;
+  action: ProxyRuleAction;
+}
+
+export type UpdateChannel = 'stable' | 'beta' | 'nightly';
+
+export type UpdateCheckInterval = 'daily' | 'weekly' | 'never';
+
 type LegacySidebarVisibilityValue = boolean | {
   hidden?: boolean;
 };
@@ -65,6 +78,46 @@ export const normalizeSidebarHiddenByPage = (
   return normalizedValue;
 };
 
+export type HotkeyAction =
+  | 'claude_code_next_provider'
+  | 'opencode_next_main_model'
+  | 'opencode_next_small_model'
+  | 'quick_switch';
+
+export const HOTKEY_ACTIONS: HotkeyAction[] = [
+  'claude_code_next_provider',
+  'opencode_next_main_model',
+  'opencode_next_small_model',
+  'quick_switch',
+];
+
+export interface HotkeyBinding {
+  action: HotkeyAction;
+  shortcut: string;
+  enabled: boolean;
+}
+
+export interface HotkeyStatus {
+  action: HotkeyAction;
+  shortcut: string;
+  registered: boolean;
+  error: string | null;
+}
+
+export type QuickSwitchKind =
+  | 'claude_provider'
+  | 'opencode_main'
+  | 'opencode_small'
+  | 'codex_provider'
+  | 'skill_tool';
+
+export interface QuickSwitchItem {
+  kind: QuickSwitchKind;
+  id: string;
+  label: string;
+  is_selected: boolean;
+}
+
 export interface AppSettings {
   language: string;
   current_module: string;
@@ -77,16 +130,30 @@ export interface AppSettings {
   launch_on_startup: boolean;
   minimize_to_tray_on_close: boolean;
   start_minimized: boolean;
+  confirm_before_quit: boolean;
   proxy_mode: ProxyMode;
   proxy_url: string;
+  proxy_rules: ProxyRule[];
   theme: string;
   auto_backup_enabled: boolean;
   auto_backup_interval_days: number;
   auto_backup_max_keep: number;
   last_auto_backup_time: string | null;
   auto_check_update: boolean;
+  update_channel: UpdateChannel;
+  update_check_interval: UpdateCheckInterval;
   visible_tabs: string[];
   sidebar_hidden_by_page: SidebarHiddenByPage;
+  hotkeys: HotkeyBinding[];
+  /** Days to keep audit log entries, 0 = don't record */
+  audit_log_retention_days: number;
+  /** Serve the local OpenAI-compatible gateway on 127.0.0.1 */
+  gateway_enabled: boolean;
+  gateway_port: number;
+  /** Bearer key required by the gateway, empty = no key */
+  gateway_api_key: string;
+  /** Models tried in order when the requested one fails (`provider_id/model_id`) */
+  gateway_fallback_models: string[];
 }
 
 // Default settings
@@ -117,16 +184,26 @@ export const defaultSettings: AppSettings = {
   launch_on_startup: true,
   minimize_to_tray_on_close: true,
   start_minimized: false,
+  confirm_before_quit: false,
   proxy_mode: 'system',
   proxy_url: '',
+  proxy_rules: [],
   theme: 'system',
   auto_backup_enabled: false,
   auto_backup_interval_days: 7,
   auto_backup_max_keep: 10,
   last_auto_backup_time: null,
   auto_check_update: true,
+  update_channel: 'stable',
+  update_check_interval: 'daily',
   visible_tabs: ['opencode', 'claudecode', 'codex', 'openclaw', 'ssh', 'wsl'],
   sidebar_hidden_by_page: createDefaultSidebarHiddenByPage(),
+  hotkeys: [],
+  audit_log_retention_days: 30,
+  gateway_enabled: false,
+  gateway_port: 11435,
+  gateway_api_key: '',
+  gateway_fallback_models: [],
 };
 
 /**
@@ -156,6 +233,20 @@ export const saveSettings = async (settings: AppSettings): Promise<void> => {
   await invoke('save_settings', { settings });
 };
 
+/**
+ * Export the app settings (without passwords and keys) as JSON
+ */
+export const exportSettings = async (): Promise<string> => {
+  return await invoke<string>('export_settings');
+};
+
+/**
+ * Import app settings from JSON produced by exportSettings; returns the saved settings
+ */
+export const importSettings = async (json: string): Promise<AppSettings> => {
+  return await invoke<AppSettings>('import_settings', { json });
+};
+
 /**
  * Update partial settings
  */
@@ -207,3 +298,122 @@ export const restartApp = async (): Promise<void> => {
 export const testProxyConnection = async (proxyUrl: string): Promise<void> => {
   await invoke('test_proxy_connection', { proxyUrl });
 };
+
+export interface ProxyProbeResult {
+  name: string;
+  url: string;
+  success: boolean;
+  status: number | null;
+  latency_ms: number;
+  error: string | null;
+}
+
+/**
+ * Fetch canary URLs (GitHub update feed, applied provider base URL) through a proxy;
+ * `url` defaults to the configured proxy URL
+ */
+export const testProxy = async (url?: string): Promise<ProxyProbeResult[]> => {
+  return await invoke<ProxyProbeResult[]>('test_proxy', { url });
+};
+
+/**
+ * Get the registration status of the global hotkeys
+ */
+export const getHotkeyStatus = async (): Promise<HotkeyStatus[]> => {
+  return await invoke<HotkeyStatus[]>('get_hotkey_status');
+};
+
+/**
+ * Save global hotkeys and register them; rejects when bindings conflict
+ */
+export const saveHotkeys = async (hotkeys: HotkeyBinding[]): Promise<HotkeyStatus[]> => {
+  return await invoke<HotkeyStatus[]>('save_hotkeys', { hotkeys });
+};
+
+export interface AppLockStatus {
+  enabled: boolean;
+  locked: boolean;
+  /** 0 = never auto-lock */
+  auto_lock_minutes: number;
+}
+
+export const getAppLockStatus = async (): Promise<AppLockStatus> => {
+  return await invoke<AppLockStatus>('get_app_lock_status');
+};
+
+export const unlockApp = async (password: string): Promise<AppLockStatus> => {
+  return await invoke<AppLockStatus>('unlock_app', { password });
+};
+
+export const lockApp = async (): Promise<AppLockStatus> => {
+  return await invoke<AppLockStatus>('lock_app');
+};
+
+/**
+ * Enable the master password; WebDAV / S3 credentials are re-saved encrypted
+ */
+export const enableAppLock = async (password: string, autoLockMinutes: number): Promise<AppLockStatus> => {
+  return await invoke<AppLockStatus>('enable_app_lock', { password, autoLockMinutes });
+};
+
+export const disableAppLock = async (password: string): Promise<AppLockStatus> => {
+  return await invoke<AppLockStatus>('disable_app_lock', { password });
+};
+
+export const setAppLockTimeout = async (autoLockMinutes: number): Promise<AppLockStatus> => {
+  return await invoke<AppLockStatus>('set_app_lock_timeout', { autoLockMinutes });
+};
+
+export interface SecretInfo {
+  name: string;
+  description: string;
+  /** Whether the OS keychain holds a value */
+  has_value: boolean;
+  updated_at: string | null;
+}
+
+/**
+ * List the secrets in the vault (values are never returned)
+ */
+export const listSecrets = async (): Promise<SecretInfo[]> => {
+  return await invoke<SecretInfo[]>('list_secrets');
+};
+
+/**
+ * Create or update a secret; an empty value keeps the stored one
+ */
+export const setSecret = async (name: string, value: string, description?: string): Promise<void> => {
+  await invoke('set_secret', { name, value, description });
+};
+
+export const deleteSecret = async (name: string): Promise<void> => {
+  await invoke('delete_secret', { name });
+};
+
+/**
+ * Open the quick-switch palette window
+ */
+export const openQuickSwitchWindow = async (): Promise<void> => {
+  await invoke('open_quick_switch_window');
+};
+
+/**
+ * Hide the quick-switch palette window
+ */
+export const hideQuickSwitchWindow = async (): Promise<void> => {
+  await invoke('hide_quick_switch_window');
+};
+
+/**
+ * Fuzzy-search the quick-switch entries; `refresh` reloads them from the backend first
+ */
+export const searchQuickSwitch = async (query: string, refresh = false): Promise<QuickSwitchItem[]> => {
+  return await invoke<QuickSwitchItem[]>('quick_switch_search', { query, refresh });
+};
+
+/**
+ * Apply an entry picked in the quick-switch palette
+ */
+export const applyQuickSwitchItem = async (kind: QuickSwitchKind, id: string): Promise<void> => {
+  await invoke('apply_quick_switch_item', { kind, id });
+};
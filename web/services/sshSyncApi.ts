@@ -6,11 +6,17 @@
 
 import { invoke } from '@tauri-apps/api/core';
 import type {
+  SSHBootstrapToolResult,
   SSHConnection,
   SSHConnectionResult,
   SSHFileMapping,
+  SSHPortForward,
+  SSHPullItem,
+  SSHRemoteEntry,
+  SSHRemoteFile,
   SSHStatusResult,
   SSHSyncConfig,
+  SSHSyncLogEntry,
   SyncResult,
 } from '@/types/sshsync';
 
@@ -70,6 +76,54 @@ export const sshTestConnection = async (connection: SSHConnection): Promise<SSHC
   return await invoke<SSHConnectionResult>('ssh_test_connection', { connection });
 };
 
+/**
+ * List a remote directory (for the remote path picker)
+ */
+export const sshListDir = async (connectionId: string, path: string): Promise<SSHRemoteEntry[]> => {
+  return await invoke<SSHRemoteEntry[]>('ssh_list_dir', { connectionId, path });
+};
+
+/**
+ * Read a remote file (up to 1 MB) with its metadata
+ */
+export const sshReadFile = async (connectionId: string, path: string): Promise<SSHRemoteFile> => {
+  return await invoke<SSHRemoteFile>('ssh_read_file', { connectionId, path });
+};
+
+/**
+ * Check for (and optionally install) the opencode / claude / codex CLIs on the remote host
+ */
+export const sshBootstrapRemote = async (
+  connectionId: string,
+  tools: string[],
+  install?: boolean,
+): Promise<SSHBootstrapToolResult[]> => {
+  return await invoke<SSHBootstrapToolResult[]>('ssh_bootstrap_remote', {
+    connectionId,
+    tools,
+    install,
+  });
+};
+
+/**
+ * Trust a server host key after the user compared its fingerprint
+ */
+export const sshTrustHostKey = async (
+  host: string,
+  port: number,
+  keyType: string,
+  fingerprint: string,
+): Promise<void> => {
+  await invoke('ssh_trust_host_key', { host, port, keyType, fingerprint });
+};
+
+/**
+ * Forget a trusted host key (the next connection asks again)
+ */
+export const sshForgetHostKey = async (host: string, port: number): Promise<void> => {
+  await invoke('ssh_forget_host_key', { host, port });
+};
+
 /**
  * Add a new SSH file mapping
  */
@@ -100,9 +154,37 @@ export const sshResetFileMappings = async (): Promise<void> => {
 
 /**
  * Sync files to SSH remote
+ *
+ * With `dryRun` nothing is uploaded: syncedFiles lists the planned uploads and
+ * skippedFiles the mappings that are skipped or already up to date.
+ */
+export const sshSync = async (
+  module?: string,
+  skipModules?: string[],
+  dryRun?: boolean,
+): Promise<SyncResult> => {
+  return await invoke<SyncResult>('ssh_sync', { module, skipModules, dryRun });
+};
+
+/**
+ * Re-sync only the file mappings that failed in the last sync
+ */
+export const sshRetryFailed = async (): Promise<SyncResult> => {
+  return await invoke<SyncResult>('ssh_retry_failed');
+};
+
+/**
+ * Preview pulling mapped files from the remote host (diff against local, no writes)
  */
-export const sshSync = async (module?: string, skipModules?: string[]): Promise<SyncResult> => {
-  return await invoke<SyncResult>('ssh_sync', { module, skipModules });
+export const sshPull = async (module?: string): Promise<SSHPullItem[]> => {
+  return await invoke<SSHPullItem[]>('ssh_pull', { module });
+};
+
+/**
+ * Overwrite local files with the remote content of the selected mappings
+ */
+export const sshPullApply = async (mappingIds: string[]): Promise<SyncResult> => {
+  return await invoke<SyncResult>('ssh_pull_apply', { mappingIds });
 };
 
 /**
@@ -112,6 +194,61 @@ export const sshGetStatus = async (): Promise<SSHStatusResult> => {
   return await invoke<SSHStatusResult>('ssh_get_status');
 };
 
+/**
+ * Get recent SSH sync runs, newest first
+ */
+export const sshGetSyncHistory = async (limit?: number): Promise<SSHSyncLogEntry[]> => {
+  return await invoke<SSHSyncLogEntry[]>('ssh_get_sync_history', { limit });
+};
+
+/**
+ * Run a command on the remote host, returns the exec id.
+ * Output arrives as `ssh-exec-output` events, the end as one `ssh-exec-finished` event;
+ * subscribe before calling so early lines are not missed.
+ */
+export const sshExec = async (connectionId: string, command: string): Promise<string> => {
+  return await invoke<string>('ssh_exec', { connectionId, command });
+};
+
+/**
+ * Cancel a running remote command
+ */
+export const sshExecCancel = async (execId: string): Promise<void> => {
+  await invoke('ssh_exec_cancel', { execId });
+};
+
+/**
+ * Forward 127.0.0.1:localPort to remoteHost:remotePort on the remote host
+ * (localPort defaults to a free port, remoteHost to 127.0.0.1)
+ */
+export const sshPortForwardStart = async (
+  connectionId: string,
+  remotePort: number,
+  localPort?: number,
+  remoteHost?: string,
+): Promise<SSHPortForward> => {
+  return await invoke<SSHPortForward>('ssh_port_forward_start', {
+    connectionId,
+    localPort,
+    remoteHost,
+    remotePort,
+  });
+};
+
+/**
+ * Stop a port forward
+ */
+export const sshPortForwardStop = async (forwardId: string): Promise<void> => {
+  await invoke('ssh_port_forward_stop', { forwardId });
+};
+
+/**
+ * Get the status of all port forwards
+ */
+export const sshPortForwardStatus = async (): Promise<SSHPortForward[]> => {
+  return await invoke<SSHPortForward[]>('ssh_port_forward_status');
+};
+
 /**
  * Test if a local path exists
  */
@@ -0,0 +1,50 @@
+import { invoke } from '@tauri-apps/api/core';
+
+export type UsageTool = 'claudecode' | 'codex' | 'opencode';
+export type UsageGroupBy = 'tool' | 'model' | 'day';
+
+export interface UsageToolRefresh {
+  tool: UsageTool;
+  /** Requests found in the logs read this time */
+  records: number;
+  error?: string;
+}
+
+export interface UsageFilter {
+  tool?: UsageTool;
+  model?: string;
+  /** First local day included, `YYYY-MM-DD` */
+  since?: string;
+  /** Last local day included, `YYYY-MM-DD` */
+  until?: string;
+}
+
+export interface UsageAggregate {
+  tool?: UsageTool;
+  model?: string;
+  day?: string;
+  requests: number;
+  inputTokens: number;
+  outputTokens: number;
+  cacheReadTokens: number;
+  cacheWriteTokens: number;
+  reasoningTokens: number;
+  /** Sum over `pricedRequests`; only OpenCode logs a cost */
+  costUsd: number;
+  pricedRequests: number;
+}
+
+/**
+ * Read new usage from the Claude Code, Codex and OpenCode logs.
+ * With `full`, every log is read again instead of only changed ones.
+ */
+export const refreshUsageRecords = async (full?: boolean): Promise<UsageToolRefresh[]> => {
+  return await invoke<UsageToolRefresh[]>('refresh_usage_records', { full });
+};
+
+/**
+ * Token and cost totals grouped by tool, model and/or day (no grouping = one total)
+ */
+export const getUsageSummary = async (groupBy: UsageGroupBy[], filter?: UsageFilter): Promise<UsageAggregate[]> => {
+  return await invoke<UsageAggregate[]>('get_usage_summary', { groupBy, filter });
+};
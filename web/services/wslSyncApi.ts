@@ -12,6 +12,8 @@ import type {
   WSLStatusResult,
   FileMapping,
   SyncResult,
+  WSLTransformPreview,
+  WSLSyncLogEntry,
 } from '@/types/wslsync';
 
 /**
@@ -64,6 +66,13 @@ export const wslUpdateFileMapping = async (mapping: FileMapping): Promise<void>
   await invoke('wsl_update_file_mapping', { mapping });
 };
 
+/**
+ * Preview the cmd /c normalization of a mapping's MCP config
+ */
+export const wslPreviewMcpTransform = async (mappingId: string): Promise<WSLTransformPreview> => {
+  return await invoke<WSLTransformPreview>('wsl_preview_mcp_transform', { mappingId });
+};
+
 /**
  * Delete a file mapping
  */
@@ -80,9 +89,17 @@ export const wslResetFileMappings = async (): Promise<void> => {
 
 /**
  * Sync all files or specific module to WSL
- */
-export const wslSync = async (module?: string, skipModules?: string[]): Promise<SyncResult> => {
-  return await invoke<SyncResult>('wsl_sync', { module, skipModules });
+ *
+ * With `dryRun` nothing is written into the distro: syncedFiles lists the planned copies
+ * (text updates are followed by a line diff) and skippedFiles the mappings that are
+ * skipped or already up to date.
+ */
+export const wslSync = async (
+  module?: string,
+  skipModules?: string[],
+  dryRun?: boolean,
+): Promise<SyncResult> => {
+  return await invoke<SyncResult>('wsl_sync', { module, skipModules, dryRun });
 };
 
 /**
@@ -92,6 +109,13 @@ export const wslGetStatus = async (): Promise<WSLStatusResult> => {
   return await invoke<WSLStatusResult>('wsl_get_status');
 };
 
+/**
+ * Get recent WSL full sync runs, newest first
+ */
+export const wslGetSyncHistory = async (limit?: number): Promise<WSLSyncLogEntry[]> => {
+  return await invoke<WSLSyncLogEntry[]>('wsl_get_sync_history', { limit });
+};
+
 /**
  * Test if a Windows path exists and can be accessed
  */
@@ -5,6 +5,9 @@ import {
   setAutoLaunch,
   type AppSettings,
   type ProxyMode,
+  type ProxyRule,
+  type UpdateChannel,
+  type UpdateCheckInterval,
   type WebDAVConfig,
   type S3Config,
   type SidebarPageKey,
@@ -50,10 +53,12 @@ interface SettingsState {
   launchOnStartup: boolean;
   minimizeToTrayOnClose: boolean;
   startMinimized: boolean;
+  confirmBeforeQuit: boolean;
 
   // Proxy settings
   proxyMode: ProxyMode;
   proxyUrl: string;
+  proxyRules: ProxyRule[];
 
   // Auto backup settings
   autoBackupEnabled: boolean;
@@ -63,6 +68,8 @@ interface SettingsState {
 
   // Update settings
   autoCheckUpdate: boolean;
+  updateChannel: UpdateChannel;
+  updateCheckInterval: UpdateCheckInterval;
 
   // Tab visibility settings
   visibleTabs: string[];
@@ -82,8 +89,10 @@ interface SettingsState {
   setLaunchOnStartup: (enabled: boolean) => Promise<void>;
   setMinimizeToTrayOnClose: (enabled: boolean) => Promise<void>;
   setStartMinimized: (enabled: boolean) => Promise<void>;
+  setConfirmBeforeQuit: (enabled: boolean) => Promise<void>;
   setProxyMode: (mode: ProxyMode) => Promise<void>;
   setProxyUrl: (url: string) => Promise<void>;
+  setProxyRules: (rules: ProxyRule[]) => Promise<void>;
   setAutoBackupSettings: (config: {
     enabled: boolean;
     intervalDays: number;
@@ -91,6 +100,8 @@ interface SettingsState {
   }) => Promise<void>;
   setLastAutoBackupTime: (time: string) => void;
   setAutoCheckUpdate: (enabled: boolean) => Promise<void>;
+  setUpdateChannel: (channel: UpdateChannel) => Promise<void>;
+  setUpdateCheckInterval: (interval: UpdateCheckInterval) => Promise<void>;
   setVisibleTabs: (tabs: string[]) => Promise<void>;
   setSidebarHidden: (page: SidebarPageKey, hidden: boolean) => Promise<void>;
 }
@@ -165,13 +176,17 @@ export const useSettingsStore = create<SettingsState>()((set, get) => ({
   launchOnStartup: true,
   minimizeToTrayOnClose: true,
   startMinimized: false,
+  confirmBeforeQuit: false,
   proxyMode: 'system',
   proxyUrl: '',
+  proxyRules: [],
   autoBackupEnabled: false,
   autoBackupIntervalDays: 7,
   autoBackupMaxKeep: 10,
   lastAutoBackupTime: null,
   autoCheckUpdate: true,
+  updateChannel: 'stable',
+  updateCheckInterval: 'daily',
   visibleTabs: ['opencode', 'claudecode', 'codex', 'openclaw', 'ssh', 'wsl'],
   sidebarHiddenByPage: normalizeSidebarHiddenByPage(),
 
@@ -190,13 +205,17 @@ export const useSettingsStore = create<SettingsState>()((set, get) => ({
         launchOnStartup: settings.launch_on_startup,
         minimizeToTrayOnClose: settings.minimize_to_tray_on_close,
         startMinimized: settings.start_minimized ?? false,
+        confirmBeforeQuit: settings.confirm_before_quit ?? false,
         proxyMode: settings.proxy_mode ?? 'system',
         proxyUrl: settings.proxy_url || '',
+        proxyRules: settings.proxy_rules ?? [],
         autoBackupEnabled: settings.auto_backup_enabled ?? false,
         autoBackupIntervalDays: settings.auto_backup_interval_days ?? 7,
         autoBackupMaxKeep: settings.auto_backup_max_keep ?? 10,
         lastAutoBackupTime: settings.last_auto_backup_time ?? null,
         autoCheckUpdate: settings.auto_check_update ?? true,
+        updateChannel: settings.update_channel ?? 'stable',
+        updateCheckInterval: settings.update_check_interval ?? 'daily',
         visibleTabs: settings.visible_tabs ?? ['opencode', 'claudecode', 'codex', 'openclaw', 'ssh', 'wsl'],
         sidebarHiddenByPage: normalizeSidebarHiddenByPage(settings.sidebar_hidden_by_page),
         isInitialized: true,
@@ -260,10 +279,9 @@ export const useSettingsStore = create<SettingsState>()((set, get) => ({
   },
 
   setLaunchOnStartup: async (enabled) => {
-    set({ launchOnStartup: enabled });
-
-    // Update system auto-launch
+    // Update system auto-launch first so the switch never shows a state the system rejected
     await setAutoLaunch(enabled);
+    set({ launchOnStartup: enabled });
 
     // Update database
     const currentSettings = await getSettings();
@@ -298,6 +316,18 @@ export const useSettingsStore = create<SettingsState>()((set, get) => ({
     await saveSettings(newSettings);
   },
 
+  setConfirmBeforeQuit: async (enabled) => {
+    set({ confirmBeforeQuit: enabled });
+
+    // Update database
+    const currentSettings = await getSettings();
+    const newSettings: AppSettings = {
+      ...currentSettings,
+      confirm_before_quit: enabled,
+    };
+    await saveSettings(newSettings);
+  },
+
   setProxyMode: async (mode) => {
     set({ proxyMode: mode });
 
@@ -322,6 +352,18 @@ export const useSettingsStore = create<SettingsState>()((set, get) => ({
     await saveSettings(newSettings);
   },
 
+  setProxyRules: async (rules) => {
+    set({ proxyRules: rules });
+
+    // Update database
+    const currentSettings = await getSettings();
+    const newSettings: AppSettings = {
+      ...currentSettings,
+      proxy_rules: rules,
+    };
+    await saveSettings(newSettings);
+  },
+
   setAutoBackupSettings: async (config) => {
     set({
       autoBackupEnabled: config.enabled,
@@ -355,6 +397,30 @@ export const useSettingsStore = create<SettingsState>()((set, get) => ({
     await saveSettings(newSettings);
   },
 
+  setUpdateChannel: async (channel) => {
+    set({ updateChannel: channel });
+
+    // Update database
+    const currentSettings = await getSettings();
+    const newSettings: AppSettings = {
+      ...currentSettings,
+      update_channel: channel,
+    };
+    await saveSettings(newSettings);
+  },
+
+  setUpdateCheckInterval: async (interval) => {
+    set({ updateCheckInterval: interval });
+
+    // Update database
+    const currentSettings = await getSettings();
+    const newSettings: AppSettings = {
+      ...currentSettings,
+      update_check_interval: interval,
+    };
+    await saveSettings(newSettings);
+  },
+
   setVisibleTabs: async (tabs) => {
     set({ visibleTabs: tabs });
 
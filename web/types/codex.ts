@@ -192,3 +192,102 @@ export interface ImportConflictInfo {
   newProviderName: string;
   sourceProviderId: string;
 }
+
+/**
+ * Auth mode Codex will use with the current config.toml
+ */
+export type CodexEffectiveAuthMode = 'chatgpt' | 'apikey' | 'provider' | 'none';
+
+/**
+ * Codex auth.json status
+ */
+export interface CodexAuthStatus {
+  authPath: string;
+  authFileExists: boolean;
+  authMode?: string;
+  hasApiKey: boolean;
+  hasChatgptTokens: boolean;
+  email?: string;
+  planType?: string;
+  accountId?: string;
+  tokenExpiresAt?: string;
+  tokenExpired: boolean;
+  lastRefresh?: string;
+  modelProvider?: string;
+  preferredAuthMethod?: string;
+  effectiveAuthMode: CodexEffectiveAuthMode;
+}
+
+export type CodexReasoningEffort = 'none' | 'minimal' | 'low' | 'medium' | 'high' | 'xhigh';
+export type CodexReasoningSummary = 'auto' | 'concise' | 'detailed' | 'none';
+export type CodexApprovalPolicy = 'untrusted' | 'on-failure' | 'on-request' | 'never';
+export type CodexSandboxMode = 'read-only' | 'workspace-write' | 'danger-full-access';
+
+/**
+ * Typed Codex runtime settings stored in a provider's config.toml (unset keys are omitted)
+ */
+export interface CodexRuntimeSettings {
+  modelReasoningEffort?: CodexReasoningEffort;
+  modelReasoningSummary?: CodexReasoningSummary;
+  approvalPolicy?: CodexApprovalPolicy;
+  sandboxMode?: CodexSandboxMode;
+}
+
+/**
+ * Codex notify settings read from config.toml
+ */
+export interface CodexNotifyStatus {
+  command?: string[];
+  tuiNotifications?: boolean;
+  usesBundledScript: boolean;
+  bundledScriptPath: string;
+  bundledScriptInstalled: boolean;
+}
+
+/**
+ * Input for saving Codex notify settings (missing or empty values remove the key)
+ */
+export interface CodexNotifyConfigInput {
+  command?: string[] | null;
+  tuiNotifications?: boolean | null;
+}
+
+/**
+ * Key-level change in config.toml when applying a provider
+ */
+export interface CodexConfigDiffEntry {
+  path: string;
+  kind: 'added' | 'removed' | 'changed';
+  before?: string;
+  after?: string;
+}
+
+export interface CodexConfigIssue {
+  path: string;
+  severity: 'error' | 'warning';
+  message: string;
+  /** Also reported for the current config.toml, so not caused by this provider */
+  preExisting: boolean;
+}
+
+/**
+ * Preview of applying a Codex provider (nothing is written)
+ */
+export interface CodexConfigPreview {
+  providerId: string;
+  providerName: string;
+  configPath: string;
+  currentConfig: string;
+  nextConfig: string;
+  diff: CodexConfigDiffEntry[];
+  issues: CodexConfigIssue[];
+  hasErrors: boolean;
+}
+
+/**
+ * Result of importing config.toml model providers and profiles
+ */
+export interface CodexImportResult {
+  imported: CodexProvider[];
+  skipped: string[];
+}
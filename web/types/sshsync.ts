@@ -19,6 +19,17 @@ export interface SSHConnection {
   privateKeyContent: string;
   passphrase: string;
   sortOrder: number;
+  redactSecrets?: boolean; // replace API keys/tokens in synced configs (shared servers)
+  pathRewrites?: SSHPathRewrite[]; // applied to the remote path of every mapping
+}
+
+/**
+ * Remote path rewrite rule (prefix replacement, or regex with $1 captures)
+ */
+export interface SSHPathRewrite {
+  pattern: string;
+  replacement: string;
+  isRegex: boolean;
 }
 
 /**
@@ -33,6 +44,7 @@ export interface SSHFileMapping {
   enabled: boolean;
   isPattern: boolean;
   isDirectory: boolean;
+  excludePatterns?: string[]; // globs skipped by directory/pattern mappings
 }
 
 /**
@@ -46,6 +58,9 @@ export interface SSHSyncConfig {
   lastSyncTime?: string;
   lastSyncStatus: string; // "success" | "error" | "never"
   lastSyncError?: string;
+  incrementalDirSync?: boolean; // upload only changed files in directory mappings, delete stale ones
+  skillsRemoteBootstrap?: boolean; // skills sync also fixes permissions and re-creates tool symlinks
+  lastFailedMappingIds?: string[]; // file mappings that failed in the last sync
   moduleStatuses: WslDirectModuleStatus[];
 }
 
@@ -54,8 +69,128 @@ export interface SSHSyncConfig {
  */
 export interface SSHConnectionResult {
   connected: boolean;
-  error?: string;
+  error?: string; // starts with "HOST_KEY_UNKNOWN" / "HOST_KEY_CHANGED" when the host key is not trusted
   serverInfo?: string;
+  hostKey?: SSHHostKeyInfo;
+}
+
+/**
+ * Server host key and whether it matches the trusted one
+ */
+export interface SSHHostKeyInfo {
+  host: string;
+  port: number;
+  keyType: string;
+  fingerprint: string; // "SHA256:..."
+  knownFingerprint?: string;
+  status: string; // "trusted" | "unknown" | "changed"
+}
+
+/**
+ * One mapping in a pull preview (remote -> local)
+ */
+export interface SSHPullItem {
+  mappingId: string;
+  name: string;
+  module: string;
+  localPath: string;
+  remotePath: string;
+  status: string; // "unchanged" | "modified" | "remote_only" | "remote_missing" | "unsupported" | "error"
+  localContent?: string;
+  remoteContent?: string;
+  error?: string;
+}
+
+/**
+ * Per-skill outcome of a skills sync (payload of `ssh-skills-sync-completed`)
+ */
+export interface SSHSkillSyncResult {
+  skillId: string;
+  name: string;
+  status: string; // "updated" | "unchanged" | "partial" | "skipped" | "error"
+  linkedTools: string[];
+  errors: string[];
+}
+
+/**
+ * Per-tool outcome of a remote CLI bootstrap
+ */
+export interface SSHBootstrapToolResult {
+  tool: string; // "opencode" | "claude" | "codex"
+  action: string; // "present" | "installed" | "missing" | "failed"
+  version?: string;
+  method?: string; // "npm" | "brew"
+  error?: string;
+}
+
+/**
+ * Entry returned when browsing a remote directory
+ */
+export interface SSHRemoteEntry {
+  name: string;
+  path: string;
+  isDir: boolean;
+  size: number;
+  mtime?: number; // unix millis
+}
+
+/**
+ * Remote file content with metadata
+ */
+export interface SSHRemoteFile {
+  path: string;
+  size: number;
+  mtime?: number; // unix millis
+  content: string;
+}
+
+/**
+ * One recorded SSH sync run
+ */
+export interface SSHSyncLogEntry {
+  id: string;
+  timestamp: string | null;
+  connectionId: string;
+  connectionName?: string;
+  module?: string;
+  success: boolean;
+  syncedFiles: string[];
+  skippedFiles: string[];
+  errors: string[];
+  durationMs: number;
+}
+
+/**
+ * One output line of a remote command (ssh-exec-output event)
+ */
+export interface SSHExecOutput {
+  execId: string;
+  stream: 'stdout' | 'stderr';
+  line: string;
+}
+
+/**
+ * Remote command finished or was cancelled (ssh-exec-finished event)
+ */
+export interface SSHExecFinished {
+  execId: string;
+  exitCode?: number;
+  cancelled: boolean;
+}
+
+/**
+ * Managed local port forward (ssh -L)
+ */
+export interface SSHPortForward {
+  id: string;
+  connectionId: string;
+  connectionName: string;
+  localPort: number;
+  remoteHost: string;
+  remotePort: number;
+  status: 'running' | 'disconnected';
+  activeConnections: number;
+  startedAt: string;
 }
 
 /**
@@ -14,6 +14,20 @@ export interface FileMapping {
   enabled: boolean;
   isPattern: boolean;
   isDirectory: boolean;
+  /** Strip `cmd /c` wrappers from MCP commands when syncing (default true) */
+  stripCmdC?: boolean;
+}
+
+/**
+ * Preview of the cmd /c normalization applied to one mapping
+ */
+export interface WSLTransformPreview {
+  mappingId: string;
+  windowsPath: string;
+  wslPath: string;
+  original: string;
+  transformed: string;
+  changed: boolean;
 }
 
 export interface WslDirectModuleStatus {
@@ -36,6 +50,12 @@ export interface WSLSyncConfig {
   syncMcp: boolean;
   /** Sync Skills to WSL (default: true) */
   syncSkills: boolean;
+  /** Sync automatically when watched Windows-side config files change */
+  watchConfigFiles?: boolean;
+  /** Start the distro before syncing if it is stopped */
+  autoStartDistro?: boolean;
+  /** Linux user to run sync commands as (empty for the distro's default user) */
+  wslUser?: string;
   fileMappings: FileMapping[];
   lastSyncTime?: string;
   lastSyncStatus: string; // "success" | "error" | "never"
@@ -79,6 +99,42 @@ export interface WSLStatusResult {
   lastSyncStatus: string;
   lastSyncError?: string;
   moduleStatuses: WslDirectModuleStatus[];
+  /** Last sync of every distro synced so far, most recent first */
+  distroStatuses: WSLDistroSyncStatus[];
+}
+
+/**
+ * Last sync status of one distro
+ */
+export interface WSLDistroSyncStatus {
+  distro: string;
+  lastSyncTime?: string;
+  lastSyncStatus: string; // "success" | "error" | "never"
+  lastSyncError?: string;
+}
+
+/**
+ * One recorded WSL full sync run
+ */
+export interface WSLSyncLogEntry {
+  id: string;
+  timestamp: string | null;
+  distro: string;
+  module?: string;
+  success: boolean;
+  syncedFiles: string[];
+  skippedFiles: string[];
+  errors: string[];
+  durationMs: number;
+}
+
+/**
+ * Payload of the wsl-distro-state-changed event
+ */
+export interface WSLDistroStateChanged {
+  distro: string;
+  state: 'Running' | 'Stopped' | 'Unknown';
+  previousState?: string;
 }
 
 /**